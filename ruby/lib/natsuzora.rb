@@ -6,18 +6,21 @@ require_relative 'natsuzora/token'
 require_relative 'natsuzora/validator'
 require_relative 'natsuzora/html_escape'
 require_relative 'natsuzora/value'
+require_relative 'natsuzora/filters'
+require_relative 'natsuzora/translations'
 require_relative 'natsuzora/ast'
 require_relative 'natsuzora/lexer'
 require_relative 'natsuzora/parser'
 require_relative 'natsuzora/context'
 require_relative 'natsuzora/template_loader'
+require_relative 'natsuzora/fragment_cache'
 require_relative 'natsuzora/renderer'
 require_relative 'natsuzora/template'
 
 module Natsuzora
   class << self
-    def render(source, data, include_root: nil)
-      Template.new(source, include_root: include_root).render(data)
+    def render(source, data, include_root: nil, **render_options)
+      Template.new(source, include_root: include_root).render(data, **render_options)
     end
 
     def parse(source, include_root: nil)
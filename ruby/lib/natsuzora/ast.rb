@@ -12,11 +12,18 @@ module Natsuzora
     end
 
     class Template < Node
-      attr_reader :nodes
+      # params: the Param list of a {[%params ...]} header, or nil without one
+      attr_reader :nodes, :params
 
-      def initialize(nodes, **)
+      def initialize(nodes, params: nil, **)
         super(**)
         @nodes = nodes
+        @params = params
+      end
+
+      # The layout this template extends, if any
+      def extends
+        nodes.find { |node| node.is_a?(Extends) }
       end
     end
 
@@ -30,28 +37,99 @@ module Natsuzora
     end
 
     class Variable < Node
-      attr_reader :path, :modifier
+      attr_reader :path, :modifier, :filters
 
       # modifier: nil (default), :nullable (?), :required (!)
-      def initialize(path, modifier: nil, **)
+      # filters: Filter nodes applied left to right after the modifier check
+      def initialize(path, modifier: nil, filters: [], **)
         super(**)
         @path = path
         @modifier = modifier
+        @filters = filters
+      end
+    end
+
+    # Display filter: | upper, | lower, | trim, | truncate N, | comma, | group, | count
+    class Filter < Node
+      attr_reader :name, :length
+
+      # name: Symbol; length: Integer for :truncate, nil otherwise
+      def initialize(name:, length: nil, **)
+        super(**)
+        @name = name
+        @length = length
       end
     end
 
     class IfBlock < Node
-      attr_reader :condition, :then_nodes, :else_nodes
+      attr_reader :condition, :then_nodes, :elseif_branches, :else_nodes
 
-      def initialize(condition:, then_nodes:, else_nodes: nil, **)
+      def initialize(condition:, then_nodes:, elseif_branches: [], else_nodes: nil, **)
         super(**)
         @condition = condition
         @then_nodes = then_nodes
+        @elseif_branches = elseif_branches
         @else_nodes = else_nodes
       end
     end
 
-    class UnlessBlock < Node
+    # Condition of the form `left op right`
+    class Comparison < Node
+      attr_reader :left, :operator, :right
+
+      # operator: :eq (==), :ne (!=), :lt (<), :le (<=), :gt (>), :ge (>=); right: Variable or Literal
+      def initialize(left:, operator:, right:, **)
+        super(**)
+        @left = left
+        @operator = operator
+        @right = right
+      end
+    end
+
+    # `not operand`
+    class Not < Node
+      attr_reader :operand
+
+      def initialize(operand, **)
+        super(**)
+        @operand = operand
+      end
+    end
+
+    # `left and right` or `left or right`
+    class Logical < Node
+      attr_reader :operator, :left, :right
+
+      # operator: :and or :or
+      def initialize(operator:, left:, right:, **)
+        super(**)
+        @operator = operator
+        @left = left
+        @right = right
+      end
+    end
+
+    class Literal < Node
+      attr_reader :value
+
+      def initialize(value, **)
+        super(**)
+        @value = value
+      end
+    end
+
+    # Parameter declared by {[%params title, actions? ]}; optional ones are bound to nil when omitted
+    class Param < Node
+      attr_reader :name, :optional
+
+      def initialize(name:, optional:, **)
+        super(**)
+        @name = name
+        @optional = optional
+      end
+    end
+
+    class ElseIfBranch < Node
       attr_reader :condition, :body_nodes
 
       def initialize(condition:, body_nodes:, **)
@@ -61,14 +139,55 @@ module Natsuzora
       end
     end
 
+    # else_nodes is rendered instead of the body when the condition is truthy
+    class UnlessBlock < Node
+      attr_reader :condition, :body_nodes, :else_nodes
+
+      def initialize(condition:, body_nodes:, else_nodes: nil, **)
+        super(**)
+        @condition = condition
+        @body_nodes = body_nodes
+        @else_nodes = else_nodes
+      end
+    end
+
+    # Integers from first to last inclusive; each bound is a Literal or a Variable
+    class EachRange < Node
+      attr_reader :first, :last
+
+      def initialize(first:, last:, **)
+        super(**)
+        @first = first
+        @last = last
+      end
+    end
+
+    # else_nodes is rendered instead of the body when the collection is empty
     class EachBlock < Node
-      attr_reader :collection, :item_name, :body_nodes
+      attr_reader :collection, :item_name, :index_name, :meta_name, :limit, :offset, :body_nodes, :else_nodes
 
-      def initialize(collection:, item_name:, body_nodes:, **)
+      def initialize(collection:, item_name:, body_nodes:, index_name: nil, meta_name: nil, limit: nil, offset: nil,
+                     else_nodes: nil, **)
         super(**)
         @collection = collection
         @item_name = item_name
+        @index_name = index_name
+        @meta_name = meta_name
+        @limit = limit
+        @offset = offset
         @body_nodes = body_nodes
+        @else_nodes = else_nodes
+      end
+    end
+
+    class CacheBlock < Node
+      attr_reader :key, :body_nodes, :fingerprint
+
+      def initialize(key:, body_nodes:, fingerprint:, **)
+        super(**)
+        @key = key
+        @body_nodes = body_nodes
+        @fingerprint = fingerprint
       end
     end
 
@@ -81,14 +200,70 @@ module Natsuzora
       end
     end
 
+    # body_nodes is the content output at {[!yield]}; nil for !include
     class Include < Node
-      attr_reader :name, :args
+      # source: {[!include widget.template ]}, the path whose string value names the partial; name is then nil
+      # spread: ...path, an object whose entries are bound besides args, which take precedence
+      # trim_left/trim_right: the tag's trim markers, which also trim the partial
+      attr_reader :name, :source, :args, :spread, :body_nodes, :trim_left, :trim_right
 
-      def initialize(name:, args:, **)
+      def initialize(name:, args:, source: nil, spread: nil, body_nodes: nil, trim_left: false, trim_right: false, **)
         super(**)
         @name = name
+        @source = source
+        @args = args
+        @spread = spread
+        @body_nodes = body_nodes
+        @trim_left = trim_left
+        @trim_right = trim_right
+      end
+    end
+
+    # Translated message: {[@t greeting.hello name=user.name ]}
+    class Translate < Node
+      attr_reader :key, :args
+
+      def initialize(key:, args:, **)
+        super(**)
+        @key = key
         @args = args
       end
     end
+
+    # Conditional HTML attribute: {[@attr "disabled" form.locked ]}
+    class Attr < Node
+      attr_reader :name, :path
+
+      def initialize(name:, path:, **)
+        super(**)
+        @name = name
+        @path = path
+      end
+    end
+
+    # Insertion point for the body of a block include: {[!yield]}
+    class Yield < Node
+    end
+
+    # Named layout region: {[#block name]} ... {[/block]}
+    class Block < Node
+      attr_reader :name, :body_nodes
+
+      def initialize(name:, body_nodes:, **)
+        super(**)
+        @name = name
+        @body_nodes = body_nodes
+      end
+    end
+
+    # Layout reference: {[!extends /path ]}
+    class Extends < Node
+      attr_reader :name
+
+      def initialize(name:, **)
+        super(**)
+        @name = name
+      end
+    end
   end
 end
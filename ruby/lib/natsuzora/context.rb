@@ -77,6 +77,8 @@ module Natsuzora
     end
 
     def access_property(value, key)
+      return access_index(value, key) if value.is_a?(Array) && key.match?(/\A[0-9]/)
+
       raise TypeError, "Cannot access property '#{key}' on non-object" unless value.is_a?(Hash)
 
       raise UndefinedVariableError, "Undefined property: #{key}" unless value.key?(key)
@@ -84,6 +86,13 @@ module Natsuzora
       value[key]
     end
 
+    def access_index(array, key)
+      index = Integer(key, 10, exception: false)
+      raise UndefinedVariableError, "Undefined index: #{key}" unless index && index < array.size
+
+      array[index]
+    end
+
     def normalize_data(data)
       case data
       when Hash
@@ -108,17 +117,13 @@ module Natsuzora
     def normalize_float(value)
       # Reject NaN and Infinity explicitly
       raise TypeError, "Invalid number: #{value}" unless value.finite?
+      return value unless value == value.to_i
 
       # Convert whole-number floats to integers (for JS compatibility)
-      if value == value.to_i
-        int_value = value.to_i
-        return int_value if int_value.between?(Value::INTEGER_MIN, Value::INTEGER_MAX)
-
-        raise TypeError, "Integer out of range: #{int_value}"
-
-      end
+      int_value = value.to_i
+      return int_value if int_value.between?(Value::INTEGER_MIN, Value::INTEGER_MAX)
 
-      raise TypeError, "Floating point numbers are not supported: #{value}"
+      raise TypeError, "Integer out of range: #{int_value}"
     end
   end
 end
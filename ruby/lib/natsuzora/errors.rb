@@ -34,4 +34,6 @@ module Natsuzora
   class IncludeError < RenderError; end
 
   class ShadowingError < RenderError; end
+
+  class MissingTranslationError < RenderError; end
 end
@@ -0,0 +1,47 @@
+# frozen_string_literal: true
+
+module Natsuzora
+  # Built-in display filters of variable outputs: {[ name | upper ]}
+  module Filters
+    TRUNCATION_MARKER = '…'
+    TRIM_REGEXP = /\A[ \t\r\n]+|[ \t\r\n]+\z/
+    DEFAULT_GROUP_SEPARATOR = ','
+
+    class << self
+      # Apply an AST::Filter to value; every filter but count produces a string.
+      # group_separator is what :group puts between digit groups.
+      def apply(filter, value, group_separator: DEFAULT_GROUP_SEPARATOR)
+        case filter.name
+        when :upper then Value.stringify(value).upcase
+        when :lower then Value.stringify(value).downcase
+        when :trim then Value.stringify(value).gsub(TRIM_REGEXP, '')
+        when :truncate then truncate(Value.stringify(value), filter.length)
+        when :comma then group_thousands(value, ',', 'comma')
+        when :group then group_thousands(value, group_separator, 'group')
+        when :count then count(value)
+        end
+      end
+
+      private
+
+      def truncate(string, length)
+        return string if string.length <= length
+
+        "#{string[0, length]}#{TRUNCATION_MARKER}"
+      end
+
+      def count(value)
+        raise TypeError, "'count' expects an array or object, got #{value.class}" unless value.is_a?(Array) || value.is_a?(Hash)
+
+        value.size
+      end
+
+      def group_thousands(value, separator, name)
+        raise TypeError, "'#{name}' expects an integer, got #{value.class}" unless value.is_a?(Integer)
+
+        digits = value.abs.to_s.reverse.scan(/\d{1,3}/).map(&:reverse).reverse.join(separator)
+        value.negative? ? "-#{digits}" : digits
+      end
+    end
+  end
+end
@@ -0,0 +1,49 @@
+# frozen_string_literal: true
+
+module Natsuzora
+  # In-memory LRU store for {[#cache]} block output.
+  #
+  # Any object responding to #fetch(key) { ... } can be passed as
+  # fragment_cache instead, e.g. Rails.cache.
+  class FragmentCache
+    def initialize(capacity)
+      @capacity = capacity
+      @entries = {}
+      @mutex = Mutex.new
+    end
+
+    def fetch(key)
+      @mutex.synchronize do
+        if @entries.key?(key)
+          value = @entries.delete(key)
+          @entries[key] = value
+          return value
+        end
+      end
+
+      value = yield
+      store(key, value)
+      value
+    end
+
+    def size
+      @mutex.synchronize { @entries.size }
+    end
+
+    def clear
+      @mutex.synchronize { @entries.clear }
+    end
+
+    private
+
+    def store(key, value)
+      return if @capacity.zero?
+
+      @mutex.synchronize do
+        @entries.delete(key)
+        @entries.shift if @entries.size >= @capacity
+        @entries[key] = value
+      end
+    end
+  end
+end
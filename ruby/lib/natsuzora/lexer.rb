@@ -10,20 +10,25 @@ module Natsuzora
   #
   # Responsibilities:
   # - Escape sequence processing ({[{]} -> {[)
-  # - Whitespace control via TokenProcessor ({[- and -]})
+  # - Raw block bodies ({[#raw]} ... {[/raw]}) emitted verbatim as one TEXT token
+  # - Whitespace control via TokenProcessor ({[- -]} and {[~ ~]})
   # - Error handling for invalid characters
   # - EOF token addition
   class Lexer
     ESCAPE_SEQUENCE = '{[{]}'
     ESCAPED_VALUE = '{['
+    RAW_KEYWORD = 'raw'
+    TRIM_MARKERS = %i[DASH TILDE].freeze
+    # Word after '%' that makes a comment-like tag a parameter header
+    PARAMS_KEYWORD = 'params'
+    RAW_CLOSE = %r{\{\[[-~]?/[ \t\r\n]*#{RAW_KEYWORD}(?!\p{XID_Continue})[ \t\r\n]*[-~]?\]\}}
 
     def initialize(source)
       @source = source
     end
 
     def tokenize
-      stream = DSL.instance.stream(@source)
-      mapped_tokens = map_tokens_from_stream(stream)
+      mapped_tokens = map_tokens_from_source(@source, 1, 1)
       processed_tokens = TokenProcessor.new(mapped_tokens).process
       add_eof(processed_tokens)
       processed_tokens
@@ -31,8 +36,15 @@ module Natsuzora
 
     private
 
-    def map_tokens_from_stream(stream)
+    # Tokenize source that starts at line/column of the template; restarts
+    # after each raw block body, which the DSL must not see.
+    def map_tokens_from_source(source, line, column)
+      stream = DSL.instance.stream(source)
       result = []
+      tag_prefix = []
+      shift = lambda do |(token_line, token_column)|
+        token_line == 1 ? [line, column + token_column - 1] : [line + token_line - 1, token_column]
+      end
 
       until stream.eof?
         name = stream.token_name
@@ -40,19 +52,34 @@ module Natsuzora
 
         case name
         when :TEXT
+          tag_prefix.clear
           text_value = process_text_value(text)
           unless text_value.empty?
-            line, col = stream.line_col
-            result << Token.new(:TEXT, text_value, line: line, column: col)
+            line_at, col_at = shift.call(stream.line_col)
+            result << Token.new(:TEXT, text_value, line: line_at, column: col_at)
           end
 
         when :INVALID
-          line, col = stream.line_col
-          raise LexerError.new("Unexpected character: '#{text}'", line: line, column: col)
+          # Comment content is arbitrary; TokenProcessor drops the whole tag
+          unless comment_prefix?(tag_prefix)
+            line_at, col_at = shift.call(stream.line_col)
+            raise LexerError.new("Unexpected character: '#{text}'", line: line_at, column: col_at)
+          end
 
         else
-          line, col = stream.line_col
-          result << Token.new(name, text, line: line, column: col)
+          line_at, col_at = shift.call(stream.line_col)
+          token = Token.new(name, text, line: line_at, column: col_at)
+          result << token
+          if name == :CLOSE
+            keyword = raw_open_keyword(tag_prefix)
+            tag_prefix.clear
+            if keyword
+              rest = source[char_index(source, *stream.line_col) + text.length..]
+              return result.concat(map_raw_block(rest, line_at, col_at + text.length, keyword))
+            end
+          else
+            tag_prefix << token
+          end
         end
 
         stream.advance
@@ -61,6 +88,44 @@ module Natsuzora
       result
     end
 
+    def map_raw_block(source, line, column, keyword)
+      close = RAW_CLOSE.match(source)
+      raise LexerError.new('Unclosed raw block', line: keyword.line, column: keyword.column) unless close
+
+      body = source[0...close.begin(0)]
+      result = []
+      result << Token.new(:TEXT, body, line: line, column: column) unless body.empty?
+      result.concat(map_tokens_from_source(source[close.begin(0)..], *position_after(line, column, body)))
+    end
+
+    # The IDENT token of `-? # ws? raw ws? -?` (`~` may stand for `-`), or nil for any other tag
+    def raw_open_keyword(tag_prefix)
+      types = tag_prefix.reject { |t| t.type == :WHITESPACE }
+      types.shift if TRIM_MARKERS.include?(types.first&.type)
+      types.pop if TRIM_MARKERS.include?(types.last&.type)
+      return unless types.length == 2 && types.first.type == :HASH
+
+      keyword = types.last
+      keyword if keyword.type == :IDENT && keyword.value == RAW_KEYWORD
+    end
+
+    def char_index(source, line, column)
+      source.each_line.first(line - 1).sum(&:length) + column - 1
+    end
+
+    def comment_prefix?(tag_prefix)
+      types = tag_prefix.first(2).map(&:type)
+      return false if params_prefix?(tag_prefix)
+
+      types.first == :PERCENT || (TRIM_MARKERS.include?(types.first) && types.last == :PERCENT)
+    end
+
+    # {[%params ...]} is tokenized like any tag instead of passing as a comment
+    def params_prefix?(tag_prefix)
+      percent, keyword = tag_prefix.reject { |token| TRIM_MARKERS.include?(token.type) }
+      percent&.type == :PERCENT && keyword&.type == :IDENT && keyword.value == PARAMS_KEYWORD
+    end
+
     def process_text_value(text)
       text.gsub(ESCAPE_SEQUENCE, ESCAPED_VALUE)
     end
@@ -76,10 +141,10 @@ module Natsuzora
     end
 
     def position_after_value(token)
-      line = token.line
-      column = token.column
-      value = token.value || ''
+      position_after(token.line, token.column, token.value || '')
+    end
 
+    def position_after(line, column, value)
       value.each_char do |char|
         if char == "\n"
           line += 1
@@ -14,8 +14,9 @@ module Natsuzora
             # Comment marker
             token :PERCENT, '%'
 
-            # Whitespace control marker
+            # Whitespace control markers; `~` also trims line breaks
             token :DASH, '-'
+            token :TILDE, '~'
 
             # Closing delimiter
             token :CLOSE, ']}', pop: true
@@ -27,8 +28,15 @@ module Natsuzora
             # Bang keywords (longest match takes priority over EXCLAMATION)
             token :BANG_UNSECURE, '!unsecure'
             token :BANG_INCLUDE, '!include'
+            token :BANG_EXTENDS, '!extends'
+            token :BANG_YIELD, '!yield'
+            token :NOT_EQUAL, '!='
             token :EXCLAMATION, '!'
 
+            # Translation and attribute tags; `@` is not valid anywhere else
+            token :AT_T, /@t(?!\p{XID_Continue})/
+            token :AT_ATTR, /@attr(?!\p{XID_Continue})/
+
             # Keywords
             token :KW_IF, 'if'
             token :KW_UNLESS, 'unless'
@@ -37,16 +45,30 @@ module Natsuzora
             token :KW_AS, 'as'
 
             # Operators
+            token :ELLIPSIS, '...'
+            token :DOT_DOT, '..'
             token :DOT, '.'
             token :COMMA, ','
+            token :LBRACKET, '['
+            token :RBRACKET, ']'
+            token :EQ_EQ, '=='
             token :EQUAL, '='
+            token :LESS_EQUAL, '<='
+            token :LESS, '<'
+            token :GREATER_EQUAL, '>='
+            token :GREATER, '>'
             token :QUESTION, '?'
+            token :PIPE, '|'
+
+            # Literals (conditions only); INTEGER's '-' wins over DASH by longest match
+            token :STRING, /"(?:[^"\\]|\\["\\])*"/
+            token :INTEGER, /-?[0-9]+/
 
             # Whitespace
             token :WHITESPACE, /[ \t\r\n]+/
 
-            # Identifiers
-            token :IDENT, /[A-Za-z][A-Za-z0-9_]*/
+            # Identifiers: Unicode XID_Start followed by XID_Continue
+            token :IDENT, /\p{XID_Start}\p{XID_Continue}*/
           end
         end.compile
       end
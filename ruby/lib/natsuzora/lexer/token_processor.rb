@@ -5,14 +5,15 @@ module Natsuzora
     # Processes tokens to handle whitespace control and comments.
     #
     # Responsibilities:
-    # - Consume DASH tokens and apply trim rules
-    # - Consume comment tags entirely
+    # - Consume DASH and TILDE tokens and apply trim rules, keeping the dashes
+    #   of include tags for the parser
+    # - Consume comment tags entirely, except {[%params ...]} headers
     # - Detect unclosed comments
     class TokenProcessor
       def initialize(tokens)
         @tokens = tokens
         @result = []
-        @strip_next_text = false
+        @strip_next_text = nil
       end
 
       def process
@@ -41,7 +42,7 @@ module Natsuzora
         apply_left_trim(tag_tokens)
         apply_right_trim(tag_tokens)
 
-        if comment_tag?(tag_tokens)
+        if comment_tag?(tag_tokens) && !params_tag?(tag_tokens)
           raise_unclosed_comment!(tag_tokens) unless close_idx
           return close_idx + 1
         end
@@ -54,8 +55,12 @@ module Natsuzora
         text_value = token.value
 
         if @strip_next_text
-          @strip_next_text = false
-          text_value = strip_leading_whitespace_if_blank_line(text_value)
+          text_value = if @strip_next_text == :TILDE
+                         text_value.sub(/\A[ \t\r\n]+/, '')
+                       else
+                         strip_leading_whitespace_if_blank_line(text_value)
+                       end
+          @strip_next_text = nil
         end
 
         return if text_value.empty?
@@ -74,12 +79,22 @@ module Natsuzora
       end
 
       def apply_left_trim(tag_tokens)
-        strip_trailing_from_last_text_if_blank_line if tag_tokens.first&.type == :DASH
+        case tag_tokens.first&.type
+        when :DASH then strip_trailing_from_last_text_if_blank_line
+        when :TILDE then strip_trailing_whitespace_from_previous_text
+        end
       end
 
+      # Remembers the marker for the next text; a pending `~` wins over `-`
       def apply_right_trim(tag_tokens)
         close_idx = tag_tokens.index { |token| token.type == :CLOSE }
-        @strip_next_text = true if close_idx&.positive? && tag_tokens[close_idx - 1].type == :DASH
+        return unless close_idx&.positive?
+
+        marker = tag_tokens[close_idx - 1].type
+        return unless TRIM_MARKERS.include?(marker)
+
+        @strip_next_text = :TILDE if marker == :TILDE
+        @strip_next_text ||= marker
       end
 
       def comment_tag?(tag_tokens)
@@ -88,17 +103,32 @@ module Natsuzora
 
         return true if first.type == :PERCENT
 
-        first.type == :DASH && tag_tokens[1]&.type == :PERCENT
+        TRIM_MARKERS.include?(first.type) && tag_tokens[1]&.type == :PERCENT
       end
 
       def emit_tag_tokens(tag_tokens)
+        keep_dashes = include_tag?(tag_tokens)
         tag_tokens.each do |token|
-          next if token.type == :DASH
+          next if token.type == :TILDE || (token.type == :DASH && !keep_dashes)
 
           @result << token
         end
       end
 
+      # {[%params ...]}, which the parser reads as the template's parameter header
+      def params_tag?(tag_tokens)
+        keyword = tag_tokens.reject { |token| TRIM_MARKERS.include?(token.type) }[1]
+        keyword&.type == :IDENT && keyword.value == PARAMS_KEYWORD
+      end
+
+      # !include and #include open tags, whose trim markers also apply to the included partial
+      def include_tag?(tag_tokens)
+        first, second = tag_tokens.reject { |token| [*TRIM_MARKERS, :WHITESPACE].include?(token.type) }
+        return true if first&.type == :BANG_INCLUDE
+
+        first&.type == :HASH && second&.type == :IDENT && second.value == 'include'
+      end
+
       def strip_trailing_from_last_text_if_blank_line
         last_idx = @result.rindex { |token| token.type == :TEXT }
         return unless last_idx
@@ -113,6 +143,15 @@ module Natsuzora
         @result[last_idx] = Token.new(:TEXT, stripped, line: last_text.line, column: last_text.column)
       end
 
+      # Strips all whitespace, line breaks included, from the text directly before the tag
+      def strip_trailing_whitespace_from_previous_text
+        last_text = @result.last
+        return unless last_text&.type == :TEXT
+
+        stripped = last_text.value.sub(/[ \t\r\n]+\z/, '')
+        @result[-1] = Token.new(:TEXT, stripped, line: last_text.line, column: last_text.column)
+      end
+
       def strip_leading_whitespace_if_blank_line(text)
         bytes = text.bytes
         idx = skip_leading_horizontal_whitespace(bytes)
@@ -1,33 +1,62 @@
 # frozen_string_literal: true
 
+require 'digest'
+
 module Natsuzora
   class Parser
+    # Block keyword recognized only after '#' and '/', so it stays usable as an identifier
+    CACHE_KEYWORD = 'cache'
+    # Layout region keyword, contextual like CACHE_KEYWORD
+    BLOCK_KEYWORD = 'block'
+    # Block form of !include, opened with '#' and closed with '/'
+    INCLUDE_KEYWORD = 'include'
+    # Clause keyword recognized only after '#' inside an if block
+    ELSEIF_KEYWORD = 'elseif'
+    # Block keyword whose body the lexer passes through as a single TEXT token
+    RAW_KEYWORD = Lexer::RAW_KEYWORD
+    COMPARE_OPERATORS = {
+      EQ_EQ: :eq, NOT_EQUAL: :ne, LESS: :lt, LESS_EQUAL: :le, GREATER: :gt, GREATER_EQUAL: :ge
+    }.freeze
+    ORDERING_OPERATORS = { lt: '<', le: '<=', gt: '>', ge: '>=' }.freeze
+    KEYWORD_LITERALS = { 'true' => true, 'false' => false, 'null' => nil }.freeze
+    # HTML attribute names accepted by @attr
+    ATTRIBUTE_NAME = /\A[A-Za-z_][-A-Za-z0-9_:.]*\z/
+
     def initialize(tokens)
       @tokens = tokens
       @pos = 0
+      # The tag being parsed opened with '{[-'; only include tags keep the marker
+      @trim_left = false
     end
 
     def parse
-      nodes = parse_nodes
-      AST::Template.new(nodes, line: 1, column: 1)
+      nodes = []
+      params = nil
+      until current_type == :EOF
+        if current_type == :PERCENT
+          token = current_token
+          declared = parse_params
+          raise ParseError.new('Duplicate params header', line: token.line, column: token.column) if params
+
+          params = declared
+          next
+        end
+        nodes << parse_node
+      end
+      validate_layout!(nodes)
+      AST::Template.new(nodes, params: params, line: 1, column: 1)
     end
 
     private
 
-    def parse_nodes(stop_types: [:EOF])
-      nodes = []
-      nodes << parse_node until stop_types.include?(current_type)
-      nodes
-    end
-
     def parse_node
       case current_type
       when :TEXT
         node = parse_text
         parse_tag_content_if_present
         node
-      when :HASH, :SLASH, :BANG_UNSECURE, :BANG_INCLUDE, :IDENT, :KW_IF, :KW_UNLESS, :KW_ELSE, :KW_EACH, :KW_AS,
-           :WHITESPACE, :QUESTION, :EXCLAMATION, :DOT, :EQUAL, :COMMA
+      when :HASH, :DASH, :SLASH, :BANG_UNSECURE, :BANG_INCLUDE, :BANG_EXTENDS, :BANG_YIELD, :AT_T, :AT_ATTR, :IDENT, :KW_IF, :KW_UNLESS, :KW_ELSE, :KW_EACH, :KW_AS,
+           :WHITESPACE, :QUESTION, :EXCLAMATION, :DOT, :ELLIPSIS, :DOT_DOT, :EQUAL, :COMMA, :PERCENT
         parse_tag_content
       else
         unexpected_token!
@@ -44,6 +73,7 @@ module Natsuzora
     end
 
     def parse_tag_content
+      @trim_left = consume_trim_marker
       first_token = current_token
 
       check_no_whitespace_before_special(first_token) if current_type == :WHITESPACE
@@ -59,6 +89,16 @@ module Natsuzora
         parse_unsecure_output
       when :BANG_INCLUDE
         parse_include
+      when :BANG_EXTENDS
+        parse_extends
+      when :BANG_YIELD
+        parse_yield
+      when :AT_T
+        parse_translate
+      when :AT_ATTR
+        parse_attr
+      when :PERCENT
+        unexpected_token!('params header must be at the top level of the template')
       else
         parse_variable_node
       end
@@ -67,7 +107,7 @@ module Natsuzora
     def check_no_whitespace_before_special(ws_token)
       saved_pos = @pos
       skip_whitespace
-      if %i[HASH SLASH BANG_UNSECURE BANG_INCLUDE].include?(current_type)
+      if %i[HASH SLASH BANG_UNSECURE BANG_INCLUDE BANG_EXTENDS BANG_YIELD AT_T AT_ATTR].include?(current_type)
         raise ParseError.new(
           "Whitespace not allowed before '#{current_token.value}' after tag open",
           line: ws_token.line,
@@ -88,6 +128,13 @@ module Natsuzora
         parse_unless_block
       when :KW_EACH
         parse_each_block
+      when :IDENT
+        unexpected_token!("Unexpected 'elseif' without 'if'") if elseif_keyword?
+        return parse_raw_block if raw_keyword?
+        return parse_named_block if block_keyword?
+        return parse_include_block if include_keyword?
+
+        cache_keyword? ? parse_cache_block : unexpected_token!
       when :KW_ELSE
         unexpected_token!("Unexpected 'else' without 'if'")
       else
@@ -101,16 +148,20 @@ module Natsuzora
       column = token.column
 
       consume_required_whitespace
-      condition = parse_path
+      condition = parse_condition
       skip_whitespace
       consume(:CLOSE)
 
       then_nodes = parse_if_body
+      elseif_branches = []
       else_nodes = nil
 
+      elseif_branches << parse_elseif_branch while elseif_open?
+
       if else_open?
         consume_else
         else_nodes = parse_if_body
+        unexpected_token!("'elseif' must come before 'else'") if elseif_open?
       end
 
       consume_block_close(:KW_IF)
@@ -118,15 +169,127 @@ module Natsuzora
       AST::IfBlock.new(
         condition: condition,
         then_nodes: then_nodes,
+        elseif_branches: elseif_branches,
         else_nodes: else_nodes,
         line: line,
         column: column
       )
     end
 
+    def parse_elseif_branch
+      consume(:HASH)
+      skip_whitespace
+      token = consume(:IDENT)
+
+      consume_required_whitespace
+      condition = parse_condition
+      skip_whitespace
+      consume(:CLOSE)
+
+      AST::ElseIfBranch.new(
+        condition: condition,
+        body_nodes: parse_if_body,
+        line: token.line,
+        column: token.column
+      )
+    end
+
+    # `or` binds loosest, then `and`, then `not`
+    def parse_condition
+      condition = parse_and_condition
+      while spaced_word?('or')
+        condition = AST::Logical.new(operator: :or, left: condition, right: parse_and_condition,
+                                     line: condition.line, column: condition.column)
+      end
+      condition
+    end
+
+    def parse_and_condition
+      condition = parse_not_condition
+      while spaced_word?('and')
+        condition = AST::Logical.new(operator: :and, left: condition, right: parse_not_condition,
+                                     line: condition.line, column: condition.column)
+      end
+      condition
+    end
+
+    # `not` is contextual: `{[#if not]}` still reads the variable `not`
+    def parse_not_condition
+      token = current_token
+      return parse_comparison unless ident_at?(@pos, 'not') && type_at(@pos + 1) == :WHITESPACE && type_at(@pos + 2) == :IDENT
+
+      advance_token
+      skip_whitespace
+      AST::Not.new(parse_not_condition, line: token.line, column: token.column)
+    end
+
+    # Consumes `<ws>keyword<ws>`, e.g. `and` between two conditions
+    def spaced_word?(keyword)
+      return false unless type_at(@pos) == :WHITESPACE && ident_at?(@pos + 1, keyword) && type_at(@pos + 2) == :WHITESPACE
+
+      @pos += 3
+      true
+    end
+
+    def ident_at?(pos, value)
+      type_at(pos) == :IDENT && @tokens[pos].value == value
+    end
+
+    def type_at(pos)
+      @tokens[pos]&.type
+    end
+
+    def parse_comparison
+      left = parse_path
+
+      saved_pos = @pos
+      skip_whitespace
+      operator = COMPARE_OPERATORS[current_type]
+      unless operator
+        @pos = saved_pos
+        return left
+      end
+
+      advance_token
+      skip_whitespace
+      right_token = current_token
+      right = parse_operand
+      if ORDERING_OPERATORS.key?(operator) && right.is_a?(AST::Literal) && !right.value.is_a?(Integer)
+        raise ParseError.new("'#{ORDERING_OPERATORS[operator]}' compares numbers only", line: right_token.line, column: right_token.column)
+      end
+
+      AST::Comparison.new(left: left, operator: operator, right: right, line: left.line, column: left.column)
+    end
+
+    def parse_operand
+      token = current_token
+      case current_type
+      when :STRING
+        advance_token
+        AST::Literal.new(token.value[1...-1].gsub(/\\(["\\])/, '\\1'), line: token.line, column: token.column)
+      when :INTEGER
+        advance_token
+        AST::Literal.new(parse_integer_literal(token), line: token.line, column: token.column)
+      when :IDENT
+        return parse_path unless KEYWORD_LITERALS.key?(token.value)
+
+        advance_token
+        AST::Literal.new(KEYWORD_LITERALS[token.value], line: token.line, column: token.column)
+      else
+        parse_path
+      end
+    end
+
+    def parse_integer_literal(token)
+      value = Integer(token.value, 10)
+      return value if value.between?(Value::INTEGER_MIN, Value::INTEGER_MAX)
+
+      raise ParseError.new("Integer literal out of range: #{token.value}", line: token.line, column: token.column)
+    end
+
     def parse_if_body
       nodes = []
-      nodes << parse_node until block_close?(:KW_IF) || else_open?
+      nodes << parse_node until block_close?(:KW_IF) || else_open? || elseif_open?
       nodes
     end
 
@@ -136,17 +299,22 @@ module Natsuzora
       column = token.column
 
       consume_required_whitespace
-      condition = parse_path
+      condition = parse_condition
       skip_whitespace
       consume(:CLOSE)
 
       body_nodes = parse_unless_body
+      if else_open?
+        consume_else
+        else_nodes = parse_unless_body
+      end
 
       consume_block_close(:KW_UNLESS)
 
       AST::UnlessBlock.new(
         condition: condition,
         body_nodes: body_nodes,
+        else_nodes: else_nodes,
         line: line,
         column: column
       )
@@ -154,7 +322,7 @@ module Natsuzora
 
     def parse_unless_body
       nodes = []
-      nodes << parse_node until block_close?(:KW_UNLESS)
+      nodes << parse_node until block_close?(:KW_UNLESS) || else_open?
       nodes
     end
 
@@ -169,6 +337,21 @@ module Natsuzora
       result
     end
 
+    def elseif_open?
+      return false unless current_type == :HASH
+
+      saved_pos = @pos
+      advance_token # hash
+      skip_whitespace
+      result = elseif_keyword?
+      @pos = saved_pos
+      result
+    end
+
+    def elseif_keyword?
+      current_type == :IDENT && current_token.value == ELSEIF_KEYWORD
+    end
+
     def consume_else
       consume(:HASH)
       skip_whitespace
@@ -183,34 +366,157 @@ module Natsuzora
       column = token.column
 
       consume_required_whitespace
-      collection = parse_path
-      consume_required_whitespace
-      consume(:KW_AS)
-      consume_required_whitespace
+      in_form = each_in_form?
+      unless in_form
+        collection = parse_each_collection
+        consume_required_whitespace
+        consume(:KW_AS)
+        consume_required_whitespace
+      end
       item_name = parse_identifier_with_validation
 
+      index_name = parse_each_index(item_name)
+      if in_form
+        consume_required_whitespace
+        advance_token # in
+        consume_required_whitespace
+        collection = parse_each_collection
+      end
+      meta_name = parse_loop_variable([item_name, index_name].compact, 'Loop metadata') if spaced_word?('with')
+      window = parse_each_window
       skip_whitespace
       consume(:CLOSE)
 
       body_nodes = parse_each_body
+      if else_open?
+        consume_else
+        else_nodes = parse_each_body
+      end
 
       consume_block_close(:KW_EACH)
 
       AST::EachBlock.new(
         collection: collection,
         item_name: item_name,
+        index_name: index_name,
+        meta_name: meta_name,
+        limit: window['limit'],
+        offset: window['offset'],
         body_nodes: body_nodes,
+        else_nodes: else_nodes,
         line: line,
         column: column
       )
     end
 
+    # Parse the optional limit= and offset= of an each tag
+    # A collection path or an integer range `start..end`
+    def parse_each_collection
+      first = current_type == :INTEGER ? parse_operand : parse_path
+      return first if first.is_a?(AST::Variable) && current_type != :DOT_DOT
+
+      consume(:DOT_DOT)
+      last = current_type == :INTEGER ? parse_operand : parse_path
+      AST::EachRange.new(first: first, last: last, line: first.line, column: first.column)
+    end
+
+    def parse_each_window
+      window = parse_include_args
+      window.each do |name, value|
+        unless %w[limit offset].include?(name)
+          raise ParseError.new("Unknown each option: #{name}", line: value.line, column: value.column)
+        end
+        next unless value.is_a?(AST::Literal)
+        next if value.value.is_a?(Integer) && value.value >= 0
+
+        raise ParseError.new("each #{name} must be a non-negative integer", line: value.line, column: value.column)
+      end
+      window
+    end
+
+    # Whether the each tag is written `item in items` or `item, i in items`
+    def each_in_form?
+      skip_space = ->(pos) { type_at(pos) == :WHITESPACE ? pos + 1 : pos }
+      pos = @pos + 1
+      pos = skip_space.call(skip_space.call(pos) + 1) + 1 if type_at(skip_space.call(pos)) == :COMMA
+      type_at(pos) == :WHITESPACE && ident_at?(pos + 1, 'in')
+    end
+
+    def parse_each_index(item_name)
+      saved_pos = @pos
+      skip_whitespace
+      unless current_type == :COMMA
+        @pos = saved_pos
+        return nil
+      end
+
+      advance_token
+      skip_whitespace
+      parse_loop_variable([item_name], 'Index')
+    end
+
+    # An extra each variable must differ from the ones bound before it
+    def parse_loop_variable(names, role)
+      token = current_token
+      name = parse_identifier_with_validation
+      if names.include?(name)
+        raise ParseError.new("#{role} variable '#{name}' is already bound by this each", line: token.line, column: token.column)
+      end
+
+      name
+    end
+
     def parse_each_body
       nodes = []
-      nodes << parse_node until block_close?(:KW_EACH)
+      nodes << parse_node until block_close?(:KW_EACH) || else_open?
       nodes
     end
 
+    def parse_cache_block
+      start = @pos
+      token = consume(:IDENT)
+      line = token.line
+      column = token.column
+
+      consume_required_whitespace
+      unexpected_token!("Expected 'key=' in cache block") unless current_type == :IDENT && current_token.value == 'key'
+      advance_token
+      skip_whitespace
+      consume(:EQUAL)
+      skip_whitespace
+      key = parse_path
+      skip_whitespace
+      consume(:CLOSE)
+
+      body_nodes = []
+      body_nodes << parse_node until ident_block_close?(CACHE_KEYWORD)
+      fingerprint = fingerprint(start...@pos)
+      consume_ident_block_close(CACHE_KEYWORD)
+
+      AST::CacheBlock.new(
+        key: key,
+        body_nodes: body_nodes,
+        fingerprint: fingerprint,
+        line: line,
+        column: column
+      )
+    end
+
+    def parse_named_block
+      token = consume(:IDENT)
+
+      consume_required_whitespace
+      name = parse_identifier_with_validation
+      skip_whitespace
+      consume(:CLOSE)
+
+      body_nodes = []
+      body_nodes << parse_node until ident_block_close?(BLOCK_KEYWORD)
+      consume_ident_block_close(BLOCK_KEYWORD)
+
+      AST::Block.new(name: name, body_nodes: body_nodes, line: token.line, column: token.column)
+    end
+
     def parse_unsecure_output
       token = consume(:BANG_UNSECURE)
       line = token.line
@@ -229,13 +535,129 @@ module Natsuzora
       line = token.line
       column = token.column
 
+      trim_left = @trim_left
+      @trim_left = false
       consume_required_whitespace
-      name = parse_include_name
+      name, source = parse_include_target
+      args, spread = parse_include_args_with_spread
+      skip_whitespace
+      trim_right = consume_trim_marker
+      consume(:CLOSE)
+
+      AST::Include.new(name: name, source: source, args: args, spread: spread, trim_left: trim_left,
+                       trim_right: trim_right, line: line, column: column)
+    end
+
+    def parse_include_block
+      token = consume(:IDENT)
+
+      trim_left = @trim_left
+      @trim_left = false
+      consume_required_whitespace
+      name, source = parse_include_target
+      args, spread = parse_include_args_with_spread
+      skip_whitespace
+      trim_right = consume_trim_marker
+      consume(:CLOSE)
+
+      body_nodes = []
+      body_nodes << parse_node until ident_block_close?(INCLUDE_KEYWORD)
+      consume_ident_block_close(INCLUDE_KEYWORD)
+
+      AST::Include.new(name: name, source: source, args: args, spread: spread, body_nodes: body_nodes,
+                       trim_left: trim_left, trim_right: trim_right, line: token.line, column: token.column)
+    end
+
+    # {[%params title, body, actions? ]}: names the template expects when included
+    def parse_params
+      consume(:PERCENT)
+      keyword = consume(:IDENT)
+      unless keyword.value == Lexer::PARAMS_KEYWORD
+        raise ParseError.new("Expected 'params'", line: keyword.line, column: keyword.column)
+      end
+
+      params = []
+      skip_whitespace
+      while current_type == :IDENT
+        params << parse_param(params)
+        skip_whitespace
+        break unless current_type == :COMMA
+
+        consume(:COMMA)
+        skip_whitespace
+        unexpected_token!('Expected parameter name') unless current_type == :IDENT
+      end
+      skip_whitespace
+      consume(:CLOSE)
+      params
+    end
+
+    def parse_param(declared)
+      token = consume(:IDENT)
+      Validator.validate_identifier!(token.value, line: token.line, column: token.column)
+      if declared.any? { |param| param.name == token.value }
+        raise ParseError.new("Duplicate parameter: #{token.value}", line: token.line, column: token.column)
+      end
+
+      optional = current_type == :QUESTION
+      advance_token if optional
+      AST::Param.new(name: token.value, optional: optional, line: token.line, column: token.column)
+    end
+
+    def parse_translate
+      token = consume(:AT_T)
+
+      consume_required_whitespace
+      key = parse_path.path.join('.')
       args = parse_include_args
       skip_whitespace
       consume(:CLOSE)
 
-      AST::Include.new(name: name, args: args, line: line, column: column)
+      AST::Translate.new(key: key, args: args, line: token.line, column: token.column)
+    end
+
+    def parse_attr
+      token = consume(:AT_ATTR)
+
+      consume_required_whitespace
+      name_token = current_token
+      unexpected_token!('Expected attribute name string') unless current_type == :STRING
+      name = parse_operand.value
+      unless name.match?(ATTRIBUTE_NAME)
+        raise ParseError.new("Invalid attribute name: #{name.inspect}", line: name_token.line, column: name_token.column)
+      end
+      consume_required_whitespace
+      path = parse_path
+      skip_whitespace
+      consume(:CLOSE)
+
+      AST::Attr.new(name: name, path: path, line: token.line, column: token.column)
+    end
+
+    def parse_yield
+      token = consume(:BANG_YIELD)
+      skip_whitespace
+      consume(:CLOSE)
+
+      AST::Yield.new(line: token.line, column: token.column)
+    end
+
+    def parse_extends
+      token = consume(:BANG_EXTENDS)
+
+      consume_required_whitespace
+      name = parse_include_name
+      skip_whitespace
+      consume(:CLOSE)
+
+      AST::Extends.new(name: name, line: token.line, column: token.column)
+    end
+
+    # A static name such as /components/card, or a path whose value names the partial at render time
+    def parse_include_target
+      return [nil, parse_path] if current_type == :IDENT
+
+      [parse_include_name, nil]
     end
 
     def parse_include_name
@@ -274,21 +696,38 @@ module Natsuzora
     end
 
     def parse_include_args
+      parse_arguments(allow_spread: false).first
+    end
+
+    # Include arguments, which may also spread one object: ...card
+    def parse_include_args_with_spread
+      parse_arguments(allow_spread: true)
+    end
+
+    def parse_arguments(allow_spread:)
       args = {}
+      spread = nil
 
       while current_type == :WHITESPACE
         skip_whitespace
+        if allow_spread && current_type == :ELLIPSIS
+          token = consume(:ELLIPSIS)
+          raise ParseError.new('Only one spread argument is allowed', line: token.line, column: token.column) if spread
+
+          spread = parse_path
+          next
+        end
         break unless current_type == :IDENT
 
         key, value, key_token = parse_include_arg
         if args.key?(key)
-          raise ParseError.new("Duplicate include argument: #{key}", line: key_token.line, column: key_token.column)
+          raise ParseError.new("Duplicate argument: #{key}", line: key_token.line, column: key_token.column)
         end
 
         args[key] = value
       end
 
-      args
+      [args, spread]
     end
 
     def parse_include_arg
@@ -298,11 +737,16 @@ module Natsuzora
       skip_whitespace
       consume(:EQUAL)
       skip_whitespace
-      value = parse_path
+      value = include_literal? ? parse_operand : parse_path
 
       [key_token.value, value, key_token]
     end
 
+    # `null` is left to parse_path, which rejects it as a reserved word
+    def include_literal?
+      %i[STRING INTEGER].include?(current_type) || ident_at?(@pos, 'true') || ident_at?(@pos, 'false')
+    end
+
     def parse_variable_node
       path = parse_path(allow_modifier: true)
       skip_whitespace
@@ -314,15 +758,65 @@ module Natsuzora
       first_token = current_token
       segments = [parse_identifier_with_validation]
 
-      while current_type == :DOT
-        consume(:DOT)
-        segments << parse_identifier_with_validation
+      loop do
+        case current_type
+        when :DOT
+          consume(:DOT)
+          segments << (current_type == :INTEGER ? parse_index : parse_identifier_with_validation)
+        when :LBRACKET
+          # Quoted key: meta["og:title"]
+          consume(:LBRACKET)
+          unexpected_token!('Expected quoted key') unless current_type == :STRING
+          segments << parse_operand.value
+          consume(:RBRACKET)
+        else
+          break
+        end
       end
 
       modifier = nil
-      modifier = parse_modifier if allow_modifier
+      filters = []
+      if allow_modifier
+        modifier = parse_modifier
+        filters = parse_filters
+      end
+
+      AST::Variable.new(segments, modifier: modifier, filters: filters, line: first_token.line, column: first_token.column)
+    end
+
+    # (WS? "|" WS? FILTER)*
+    def parse_filters
+      filters = []
+      loop do
+        saved_pos = @pos
+        skip_whitespace
+        unless current_type == :PIPE
+          @pos = saved_pos
+          return filters
+        end
+        advance_token
+        skip_whitespace
+        filters << parse_filter
+      end
+    end
+
+    def parse_filter
+      token = consume(:IDENT)
+      case token.value
+      when 'upper', 'lower', 'trim', 'comma', 'group', 'count'
+        AST::Filter.new(name: token.value.to_sym, line: token.line, column: token.column)
+      when 'truncate'
+        consume_required_whitespace
+        length_token = consume(:INTEGER)
+        length = length_token.value.to_i
+        unless length.positive?
+          raise ParseError.new("truncate length must be a positive integer: #{length_token.value}", line: length_token.line, column: length_token.column)
+        end
 
-      AST::Variable.new(segments, modifier: modifier, line: first_token.line, column: first_token.column)
+        AST::Filter.new(name: :truncate, length: length, line: token.line, column: token.column)
+      else
+        raise ParseError.new("Unknown filter: #{token.value}", line: token.line, column: token.column)
+      end
     end
 
     def parse_modifier
@@ -336,6 +830,16 @@ module Natsuzora
       end
     end
 
+    # Array index segment: `0` or a digit string without a leading zero
+    def parse_index
+      token = consume(:INTEGER)
+      unless token.value.match?(/\A(?:0|[1-9][0-9]*)\z/)
+        raise ParseError.new("Invalid index: #{token.value}", line: token.line, column: token.column)
+      end
+
+      token.value
+    end
+
     def parse_identifier_with_validation
       token = current_token
 
@@ -381,6 +885,111 @@ module Natsuzora
       consume(:CLOSE)
     end
 
+    def parse_raw_block
+      token = consume(:IDENT)
+      skip_whitespace
+      consume(:CLOSE)
+
+      body = current_type == :TEXT ? consume(:TEXT) : token
+      content = body.type == :TEXT ? body.value : ''
+
+      consume(:SLASH)
+      skip_whitespace
+      unexpected_token!("Expected 'raw'") unless raw_keyword?
+      advance_token
+      skip_whitespace
+      consume(:CLOSE)
+
+      AST::Text.new(content, line: body.line, column: body.column)
+    end
+
+    def raw_keyword?
+      current_type == :IDENT && current_token.value == RAW_KEYWORD
+    end
+
+    def cache_keyword?
+      current_type == :IDENT && current_token.value == CACHE_KEYWORD
+    end
+
+    def block_keyword?
+      current_type == :IDENT && current_token.value == BLOCK_KEYWORD
+    end
+
+    def include_keyword?
+      current_type == :IDENT && current_token.value == INCLUDE_KEYWORD
+    end
+
+    # Whether the next tag closes a block opened by a contextual keyword
+    def ident_block_close?(keyword)
+      return false unless current_type == :SLASH
+
+      saved_pos = @pos
+      advance_token # slash
+      skip_whitespace
+      result = ident_at?(@pos, keyword)
+      @pos = saved_pos
+      result
+    end
+
+    def consume_ident_block_close(keyword)
+      consume(:SLASH)
+      skip_whitespace
+      unexpected_token!("Expected '#{keyword}'") unless ident_at?(@pos, keyword)
+      advance_token
+      skip_whitespace
+      consume(:CLOSE)
+    end
+
+    # extends may appear once, at the top level, where a template using it
+    # holds only blocks and whitespace; block names are unique per template
+    def validate_layout!(nodes)
+      extends = nodes.select { |node| node.is_a?(AST::Extends) }
+      layout_error!('Template extends more than one layout', extends[1]) if extends.size > 1
+      unless extends.empty?
+        nodes.each do |node|
+          next if node.is_a?(AST::Extends) || node.is_a?(AST::Block)
+          next if node.is_a?(AST::Text) && node.content.strip.empty?
+
+          layout_error!('Only blocks may appear at the top level of a template that extends a layout', node)
+        end
+      end
+      names = []
+      nodes.each { |node| check_nested_layout(node, names, top_level: true) }
+    end
+
+    def check_nested_layout(node, names, top_level: false)
+      children =
+        case node
+        when AST::Extends
+          layout_error!("'extends' must be at the top level", node) unless top_level
+          []
+        when AST::Block
+          layout_error!("Duplicate block name '#{node.name}'", node) if names.include?(node.name)
+          names << node.name
+          [node.body_nodes]
+        when AST::IfBlock
+          [node.then_nodes, *node.elseif_branches.map(&:body_nodes), node.else_nodes].compact
+        when AST::EachBlock
+          [node.body_nodes, node.else_nodes].compact
+        when AST::UnlessBlock, AST::CacheBlock
+          [node.body_nodes]
+        when AST::Include
+          [node.body_nodes].compact
+        else
+          []
+        end
+      children.flatten.each { |child| check_nested_layout(child, names) }
+    end
+
+    def layout_error!(message, node)
+      raise ParseError.new(message, line: node.line, column: node.column)
+    end
+
+    # Digest of the tokens in range; stable for a given template source
+    def fingerprint(range)
+      Digest::SHA256.hexdigest(@tokens[range].map { |t| "#{t.type}\0#{t.value}" }.join("\0"))[0, 16]
+    end
+
     def current_token
       @tokens[@pos]
     end
@@ -409,6 +1018,14 @@ module Natsuzora
       advance_token while current_type == :WHITESPACE
     end
 
+    # Consume a '-' trim marker kept by the token processor
+    def consume_trim_marker
+      return false unless current_type == :DASH
+
+      advance_token
+      true
+    end
+
     def unexpected_token!(message = nil)
       token = current_token
       msg = message || 'Unexpected token'
@@ -2,14 +2,24 @@
 
 module Natsuzora
   class Renderer
-    def initialize(ast, template_loader: nil)
+    def initialize(ast, template_loader: nil, fragment_cache: nil, translations: nil, locale: nil,
+                   group_separator: Filters::DEFAULT_GROUP_SEPARATOR, dynamic_includes: [])
       @ast = ast
       @template_loader = template_loader
+      @fragment_cache = fragment_cache
+      @translations = translations || Translations.new({})
+      @locale = locale
+      @group_separator = group_separator
+      @dynamic_includes = dynamic_includes
+      # Block bodies supplied by the templates extending the current layout
+      @block_overrides = {}
+      # Rendered bodies of the enclosing block includes, output by {[!yield]}
+      @yields = []
     end
 
     def render(data)
       @context = Context.new(data)
-      render_nodes(@ast.nodes)
+      render_template(@ast.nodes)
     end
 
     private
@@ -30,23 +40,62 @@ module Natsuzora
         render_unless(node)
       when AST::EachBlock
         render_each(node)
+      when AST::CacheBlock
+        render_cache(node)
       when AST::UnsecureOutput
         render_unsecure_output(node)
       when AST::Include
         render_include(node)
+      when AST::Translate
+        render_translate(node)
+      when AST::Attr
+        render_attr(node)
+      when AST::Block
+        render_nodes(@block_overrides.fetch(node.name, node.body_nodes))
+      when AST::Yield
+        @yields.last.to_s
+      when AST::Extends
+        '' # handled by render_template; the parser keeps it at the top level
       else
         raise RenderError, "Unknown node type: #{node.class}"
       end
     end
 
+    # Render a template's nodes, or the layout it extends with its blocks filled in
+    def render_template(nodes)
+      extends = nodes.find { |node| node.is_a?(AST::Extends) }
+      return render_nodes(nodes) unless extends
+
+      # The most derived template renders first, so its blocks win
+      nodes.grep(AST::Block).each { |block| collect_blocks(block) }
+      raise IncludeError, 'Template loader not configured for include' unless @template_loader
+
+      layout_ast = @template_loader.load(extends.name)
+      @template_loader.with_include(extends.name) do
+        render_template(layout_ast.nodes)
+      end
+    end
+
+    def collect_blocks(block)
+      @block_overrides[block.name] ||= block.body_nodes
+      block.body_nodes.grep(AST::Block).each { |nested| collect_blocks(nested) }
+    end
+
     def render_text(node)
       node.content
     end
 
     def render_variable(node)
       value = @context.resolve(node.path)
-      str = stringify_with_modifier(value, node.modifier)
-      HtmlEscape.escape(str)
+      HtmlEscape.escape(filter_with_modifier(value, node))
+    end
+
+    # Filters transform what the modifier accepted; a null let through by '?' stays empty
+    def filter_with_modifier(value, node)
+      return stringify_with_modifier(value, node.modifier) if node.filters.empty? || value.nil?
+
+      Value.stringify_required(value) if node.modifier == :required && value == ''
+      Value.stringify(node.filters.reduce(value) { |filtered, filter| Filters.apply(filter, filtered, group_separator: @group_separator) })
     end
 
     def stringify_with_modifier(value, modifier)
@@ -61,10 +110,13 @@ module Natsuzora
     end
 
     def render_if(node)
-      value = @context.resolve(node.condition.path)
-      if Value.truthy?(value)
-        render_nodes(node.then_nodes)
-      elsif node.else_nodes
+      return render_nodes(node.then_nodes) if evaluate(node.condition)
+
+      node.elseif_branches.each do |branch|
+        return render_nodes(branch.body_nodes) if evaluate(branch.condition)
+      end
+
+      if node.else_nodes
         render_nodes(node.else_nodes)
       else
         ''
@@ -72,20 +124,67 @@ module Natsuzora
     end
 
     def render_unless(node)
-      value = @context.resolve(node.condition.path)
-      if Value.truthy?(value)
-        ''
+      if evaluate(node.condition)
+        node.else_nodes ? render_nodes(node.else_nodes) : ''
       else
         render_nodes(node.body_nodes)
       end
     end
 
+    # Comparisons are strict: values of different types are never equal
+    def evaluate(condition)
+      case condition
+      when AST::Not then !evaluate(condition.operand)
+      when AST::Logical
+        # Short-circuit: the right side is not resolved when the left decides
+        condition.operator == :and ? evaluate(condition.left) && evaluate(condition.right) : evaluate(condition.left) || evaluate(condition.right)
+      when AST::Comparison then compare(condition)
+      else Value.truthy?(@context.resolve(condition.path))
+      end
+    end
+
+    def compare(condition)
+      left = @context.resolve(condition.left.path)
+      right = condition.right.is_a?(AST::Literal) ? condition.right.value : @context.resolve(condition.right.path)
+      case condition.operator
+      when :eq then left == right
+      when :ne then left != right
+      else compare_numbers(condition.operator, left, right)
+      end
+    end
+
+    # Integers and floats compare by value
+    def compare_numbers(operator, left, right)
+      unless [left, right].all? { |value| value.is_a?(Integer) || value.is_a?(Float) }
+        raise TypeError, "'#{Parser::ORDERING_OPERATORS[operator]}' compares numbers, got #{left.class} and #{right.class}"
+      end
+
+      case operator
+      when :lt then left < right
+      when :le then left <= right
+      when :gt then left > right
+      else left >= right
+      end
+    end
+
+    def loop_meta(index, length)
+      { 'index' => index, 'first' => index.zero?, 'last' => index == length - 1, 'length' => length }
+    end
+
     def render_each(node)
-      collection = @context.resolve(node.collection.path)
-      Value.ensure_array!(collection)
+      if node.collection.is_a?(AST::EachRange)
+        entries = range_entries(node)
+      else
+        entries = Value.loop_entries(@context.resolve(node.collection.path))
+        entries = entries.drop(window_bound(node.offset, 'offset')) if node.offset
+        entries = entries.first(window_bound(node.limit, 'limit')) if node.limit
+      end
+      return node.else_nodes ? render_nodes(node.else_nodes) : '' if entries.empty?
 
-      collection.map do |item|
-        bindings = { node.item_name => item }
+      entries.each_with_index.map do |(first, second), index|
+        bindings = { node.item_name => first }
+        bindings[node.index_name] = second if node.index_name
+        bindings[node.meta_name] = loop_meta(index, entries.size) if node.meta_name
 
         @context.with_scope(bindings) do
           render_nodes(node.body_nodes)
@@ -93,26 +192,161 @@ module Natsuzora
       end.join
     end
 
+    # [n, index] pairs of an each range, windowed without enumerating skipped values
+    def range_entries(node)
+      first = range_bound(node.collection.first)
+      last = range_bound(node.collection.last)
+      skip = node.offset ? window_bound(node.offset, 'offset') : 0
+      last = [last, first + skip + window_bound(node.limit, 'limit') - 1].min if node.limit
+      ((first + skip)..last).each_with_index.map { |n, index| [n, index + skip] }
+    end
+
+    def range_bound(operand)
+      value = operand.is_a?(AST::Literal) ? operand.value : @context.resolve(operand.path)
+      return value if value.is_a?(Integer)
+
+      raise TypeError, "each range bound must be an integer, got #{value.inspect}"
+    end
+
+    # Value of an each limit= or offset=: a non-negative integer
+    def window_bound(operand, name)
+      value = operand.is_a?(AST::Literal) ? operand.value : @context.resolve(operand.path)
+      return value if value.is_a?(Integer) && value >= 0
+
+      raise TypeError, "each #{name} must be a non-negative integer, got #{value.inspect}"
+    end
+
+    def render_cache(node)
+      key = Value.stringify(@context.resolve(node.key.path))
+      return render_nodes(node.body_nodes) unless @fragment_cache
+
+      @fragment_cache.fetch([node.fingerprint, key]) { render_nodes(node.body_nodes) }
+    end
+
     def render_unsecure_output(node)
       value = @context.resolve(node.path.path)
       Value.stringify(value) # No escaping
     end
 
+    def render_translate(node)
+      message = @translations.lookup(@locale, node.key)
+      args = node.args.transform_values do |var|
+        Value.stringify(var.is_a?(AST::Literal) ? var.value : @context.resolve(var.path))
+      end
+      HtmlEscape.escape(Translations.format(message, args))
+    end
+
+    # ' name', ' name="value"' or nothing, per spec section 4.5.11
+    def render_attr(node)
+      value = @context.resolve(node.path.path)
+      return '' if value.nil? || value == false
+      return " #{node.name}" if value == true
+      raise TypeError, "Cannot use object as value of attribute '#{node.name}'" if value.is_a?(Hash)
+
+      text = if value.is_a?(Array)
+               value.map { |item| Value.stringify(item) }.reject(&:empty?).join(' ')
+             else
+               Value.stringify(value)
+             end
+      return '' if text.empty?
+
+      %( #{node.name}="#{HtmlEscape.escape(text)}")
+    end
+
     def render_include(node)
       raise IncludeError, 'Template loader not configured for include' unless @template_loader
 
-      partial_ast = @template_loader.load(node.name)
+      name = node.source ? dynamic_include_name(node.source) : node.name
+      partial_ast = @template_loader.load(name)
 
-      bindings = {}
+      bindings = spread_bindings(node)
       node.args.each do |key, var|
-        bindings[key] = @context.resolve(var.path)
+        bindings[key] = var.is_a?(AST::Literal) ? var.value : @context.resolve(var.path)
       end
+      check_params!(node, name, partial_ast.params, bindings) if partial_ast.params
+
+      # The body is rendered in the caller's scope; a partial included
+      # without one yields nothing, not its includer's body
+      @yields.push(node.body_nodes ? render_nodes(node.body_nodes) : '')
+      pushed = true
 
-      @template_loader.with_include(node.name) do
+      # A partial's blocks belong to its own layout, not to the includer's
+      overrides = @block_overrides
+      @block_overrides = {}
+      @template_loader.with_include(name) do
         @context.with_scope(bindings, include_scope: true) do
-          render_nodes(partial_ast.nodes)
+          render_template(trim_partial(partial_ast.nodes, node))
         end
       end
+    ensure
+      @block_overrides = overrides if overrides
+      @yields.pop if pushed
+    end
+
+    # Check an include's arguments against the partial's {[%params ...]}, binding
+    # omitted optional parameters to nil; spread keys need not be declared
+    def check_params!(node, name, params, bindings)
+      names = params.map(&:name)
+      unknown = node.args.keys.find { |key| !names.include?(key) }
+      if unknown
+        declared = names.empty? ? 'none' : names.join(', ')
+        raise IncludeError, "Unknown argument '#{unknown}' for #{name} (declared: #{declared})"
+      end
+
+      params.each do |param|
+        next if bindings.key?(param.name)
+        raise IncludeError, "Missing argument '#{param.name}' for #{name}" unless param.optional
+
+        bindings[param.name] = nil
+      end
+    end
+
+    # The partial named by the string at source, which must be a valid include
+    # name under one of the prefixes allowed for dynamic includes
+    def dynamic_include_name(source)
+      name = @context.resolve(source.path)
+      raise TypeError, "Dynamic include name '#{source.path.join('.')}' must be a string, got #{name.class}" unless name.is_a?(String)
+
+      Validator.validate_include_name_runtime!(name)
+      return name if @dynamic_includes.any? { |prefix| under?(name, prefix) }
+
+      raise IncludeError, "Dynamic include of '#{name}' is not allowed" if @dynamic_includes.empty?
+
+      raise IncludeError, "Dynamic include of '#{name}' is outside #{@dynamic_includes.join(', ')}"
+    end
+
+    def under?(name, prefix)
+      prefix = prefix.chomp('/')
+      name == prefix || name.start_with?("#{prefix}/")
+    end
+
+    def spread_bindings(node)
+      return {} unless node.spread
+
+      value = @context.resolve(node.spread.path)
+      unless value.is_a?(Hash)
+        raise TypeError, "Spread argument '#{node.spread.path.join('.')}' must be an object, got #{value.class}"
+      end
+
+      value.dup
+    end
+
+    # The partial's nodes with the leading whitespace of a first text node and
+    # the trailing whitespace of a last text node removed, as the include
+    # tag's trim markers ask
+    def trim_partial(nodes, node)
+      return nodes if nodes.empty? || !(node.trim_left || node.trim_right)
+
+      nodes = nodes.dup
+      nodes[0] = trim_text(nodes.first, /\A[ \t\r\n]+/) if node.trim_left
+      nodes[-1] = trim_text(nodes.last, /[ \t\r\n]+\z/) if node.trim_right
+      nodes
+    end
+
+    def trim_text(text, pattern)
+      return text unless text.is_a?(AST::Text)
+
+      AST::Text.new(text.content.sub(pattern, ''), line: text.line, column: text.column)
     end
   end
 end
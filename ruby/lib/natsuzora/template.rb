@@ -10,9 +10,16 @@ module Natsuzora
       @ast = parse_ruby(source)
     end
 
-    def render(data)
+    # translations: a Translations, or the catalog hash to build one from
+    # group_separator: what | group puts between digit groups (never taken from the OS locale)
+    # dynamic_includes: prefixes such as '/widgets' under which {[!include block.template ]} may load partials
+    def render(data, fragment_cache: nil, translations: nil, locale: nil, group_separator: Filters::DEFAULT_GROUP_SEPARATOR,
+               dynamic_includes: [])
       loader = @include_root ? TemplateLoader.new(@include_root) : nil
-      Renderer.new(@ast, template_loader: loader).render(data)
+      translations = Translations.new(translations) if translations.is_a?(Hash)
+      Renderer.new(@ast, template_loader: loader, fragment_cache: fragment_cache,
+                         translations: translations, locale: locale, group_separator: group_separator,
+                         dynamic_includes: dynamic_includes).render(data)
     end
 
     private
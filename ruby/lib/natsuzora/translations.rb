@@ -0,0 +1,59 @@
+# frozen_string_literal: true
+
+module Natsuzora
+  # Message catalog for {[@t key name=value ]}: { 'ja' => { 'greeting' => { 'hello' => '...' } } }
+  #
+  # Nested hashes give dotted keys. A render with locale 'pt-BR' looks a key
+  # up in 'pt-BR', then 'pt', then the fallback locale.
+  class Translations
+    PLACEHOLDER = /\{(\p{XID_Start}\p{XID_Continue}*)\}/
+
+    def initialize(catalog, fallback_locale: nil)
+      raise TypeError, 'Translations must be a hash of locales' unless catalog.is_a?(Hash)
+
+      @locales = catalog.to_h { |locale, messages| [locale.to_s, flatten(messages)] }
+      @fallback_locale = fallback_locale&.to_s
+    end
+
+    def lookup(locale, key)
+      tried = []
+      if locale
+        tried << locale.to_s
+        language = locale.to_s.split(/[-_]/, 2)
+        tried << language.first if language.size > 1
+      end
+      tried << @fallback_locale if @fallback_locale
+      tried.each do |candidate|
+        message = @locales.dig(candidate, key)
+        return message if message
+      end
+      raise MissingTranslationError, "No translation for '#{key}' (tried: #{tried.join(', ')})"
+    end
+
+    # Fill the {name} placeholders of message; other braces are kept as written
+    def self.format(message, args)
+      message.gsub(PLACEHOLDER) do
+        name = Regexp.last_match(1)
+        raise UndefinedVariableError, "Undefined message argument: #{name}" unless args.key?(name)
+
+        args[name]
+      end
+    end
+
+    private
+
+    def flatten(messages, prefix = nil, flat = {})
+      case messages
+      when Hash
+        messages.each { |key, value| flatten(value, prefix ? "#{prefix}.#{key}" : key.to_s, flat) }
+      when String
+        raise TypeError, 'Translations of a locale must be a hash' unless prefix
+
+        flat[prefix] = messages
+      else
+        raise TypeError, "Translation '#{prefix}' must be a string"
+      end
+      flat
+    end
+  end
+end
@@ -40,10 +40,14 @@ module Natsuzora
       def validate_include_name_runtime!(name)
         raise IncludeError, "Include name must start with '/': #{name}" unless name.start_with?('/')
 
-        # These should be impossible with the new lexer, but check anyway
-        return unless name.include?('..') || name.include?('//') || name.include?('\\') || name.include?(':')
-
-        raise IncludeError, "Invalid include name: #{name}"
+        # These should be impossible with the new lexer, but check anyway;
+        # a dynamic include's name comes from data
+        if name.include?('..') || name.include?('//') || name.include?('\\') || name.include?(':')
+          raise IncludeError, "Invalid include name: #{name}"
+        end
+
+        invalid = name.split('/').reject(&:empty?).find { |segment| !segment.match?(/\A[A-Za-z][A-Za-z0-9_]*\z/) }
+        raise IncludeError, "Invalid include segment '#{invalid}' in '#{name}'" if invalid
       end
     end
   end
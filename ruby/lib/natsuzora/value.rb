@@ -14,6 +14,8 @@ module Natsuzora
         when Integer
           validate_integer_range!(value)
           value.to_s
+        when Float
+          format_float(value)
         when NilClass
           raise TypeError, "Cannot stringify null value without '?' modifier"
         when TrueClass, FalseClass
@@ -61,8 +63,33 @@ module Natsuzora
         value
       end
 
+      # Arrays yield [item, index]; objects yield [key, value] in key order
+      def loop_entries(value)
+        case value
+        when Array
+          value.each_with_index.to_a
+        when Hash
+          value.sort_by { |key, _| key }
+        else
+          raise TypeError, "Expected array or object, got #{value.class}"
+        end
+      end
+
       private
 
+      # Shortest round-trip digits without an exponent, as Rust prints them:
+      # 1.0e-07 becomes 0.0000001. Floats with a fractional part are below
+      # 2**52, so only negative exponents occur.
+      def format_float(value)
+        text = value.to_s
+        mantissa, exponent = text.split('e')
+        return text unless exponent
+
+        sign = value.negative? ? '-' : ''
+        digits = mantissa.delete('-.').sub(/0+\z/, '')
+        "#{sign}0.#{'0' * (-exponent.to_i - 1)}#{digits}"
+      end
+
       def validate_integer_range!(value)
         return if value.between?(INTEGER_MIN, INTEGER_MAX)
 
@@ -0,0 +1,27 @@
+# frozen_string_literal: true
+
+RSpec.describe Natsuzora::FragmentCache do
+  let(:cache) { described_class.new(2) }
+
+  it 'reuses block output for the same key' do
+    template = Natsuzora.parse('{[#cache key=id ]}{[ name ]}{[/cache]}')
+    expect(template.render({ id: 1, name: 'a' }, fragment_cache: cache)).to eq('a')
+    expect(template.render({ id: 1, name: 'b' }, fragment_cache: cache)).to eq('a')
+    expect(template.render({ id: 2, name: 'b' }, fragment_cache: cache)).to eq('b')
+  end
+
+  it 'keeps blocks sharing a key apart' do
+    source = '{[#cache key=id ]}<{[ name ]}>{[/cache]}{[#cache key=id ]}[{[ name ]}]{[/cache]}'
+    expect(Natsuzora.render(source, { id: 1, name: 'a' }, fragment_cache: cache)).to eq('<a>[a]')
+    expect(cache.size).to eq(2)
+  end
+
+  it 'evicts the least recently used entry' do
+    cache.fetch(:a) { 'A' }
+    cache.fetch(:b) { 'B' }
+    cache.fetch(:a) { 'unused' }
+    cache.fetch(:c) { 'C' }
+    expect(cache.fetch(:b) { 'B2' }).to eq('B2')
+    expect(cache.fetch(:a) { 'unused' }).to eq('unused')
+  end
+end
@@ -45,6 +45,13 @@ RSpec.describe Natsuzora::Lexer do
         idents = tokens.select { |t| t.type == :IDENT }.map(&:value)
         expect(idents).to eq(%w[a b c d])
       end
+
+      it 'tokenizes unicode identifiers' do
+        tokens = tokenize('{[ 商品.価格2 ]}')
+        idents = tokens.select { |t| t.type == :IDENT }.map(&:value)
+        expect(idents).to eq(%w[商品 価格2])
+        expect { tokenize('{[ １番 ]}') }.to raise_error(Natsuzora::LexerError)
+      end
     end
 
     context 'with if blocks' do
@@ -176,6 +183,13 @@ RSpec.describe Natsuzora::Lexer do
         expect(types).not_to include(:DASH)
         expect(types).to include(:HASH, :KW_IF)
       end
+
+      it 'strips all adjacent whitespace for {[~ ... ~]}' do
+        tokens = tokenize("before \n\n  {[~ name ~]}\n\n\tafter")
+        expect(tokens.map(&:type)).not_to include(:TILDE)
+        texts = tokens.select { |t| t.type == :TEXT }
+        expect(texts.map(&:value)).to eq(%w[before after])
+      end
     end
 
     context 'with delimiter escape' do
@@ -216,6 +230,19 @@ RSpec.describe Natsuzora::Lexer do
       end
     end
 
+    context 'with raw blocks' do
+      it 'emits the body as a single text token' do
+        tokens = tokenize("{[#raw]}{[ a ]}{[{]}{[/ raw -]}\n{[ b ]}")
+        texts = tokens.select { |t| t.type == :TEXT }.map { |t| [t.value, t.column] }
+        expect(texts).to eq([['{[ a ]}{[{]}', 9]])
+        expect(tokens.find { |t| t.type == :IDENT && t.value == 'b' }.line).to eq(2)
+      end
+
+      it 'raises error for unclosed raw block' do
+        expect { tokenize('{[#raw]}{[ a ]}') }.to raise_error(Natsuzora::LexerError, /Unclosed raw block/)
+      end
+    end
+
     context 'with errors' do
       it 'raises error on unexpected character inside tag' do
         expect { tokenize('{[ @ ]}') }.to raise_error(Natsuzora::LexerError, /Unexpected character/)
@@ -26,6 +26,33 @@ RSpec.describe Natsuzora::Parser do
         ast = parse('{[ user.profile.name ]}')
         expect(ast.nodes.first.path).to eq(%w[user profile name])
       end
+
+      it 'parses array index segments' do
+        expect(parse('{[ images.0.url ]}').nodes.first.path).to eq(%w[images 0 url])
+        expect { parse('{[ rows.01 ]}') }.to raise_error(Natsuzora::ParseError, /Invalid index/)
+        expect { parse('{[ rows.-1 ]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses quoted key segments' do
+        expect(parse('{[ meta["og:title"].url ]}').nodes.first.path).to eq(%w[meta og:title url])
+        expect(parse('{[ m["a\\"b"] ]}').nodes.first.path).to eq(['m', 'a"b'])
+        expect { parse('{[ meta[title] ]}') }.to raise_error(Natsuzora::ParseError)
+      end
+    end
+
+    context 'with filters' do
+      it 'parses filters in order' do
+        node = parse('{[ title? | trim|truncate 20 ]}').nodes.first
+        expect(node.modifier).to eq(:nullable)
+        expect(node.filters.map(&:name)).to eq(%i[trim truncate])
+        expect(node.filters.last.length).to eq(20)
+      end
+
+      it 'rejects unknown filters and missing lengths' do
+        expect { parse('{[ a | shout ]}') }.to raise_error(Natsuzora::ParseError, /Unknown filter/)
+        expect { parse('{[ a | truncate ]}') }.to raise_error(Natsuzora::ParseError)
+        expect { parse('{[ a | truncate 0 ]}') }.to raise_error(Natsuzora::ParseError)
+      end
     end
 
     context 'with if blocks' do
@@ -45,6 +72,49 @@ RSpec.describe Natsuzora::Parser do
         expect(node.else_nodes.first.content).to eq('no')
       end
 
+      it 'parses elseif clauses in order' do
+        ast = parse('{[#if a]}1{[#elseif b]}2{[#elseif c.d]}3{[#else]}4{[/if]}')
+        node = ast.nodes.first
+        expect(node.elseif_branches.map { |branch| branch.condition.path }).to eq([['b'], %w[c d]])
+        expect(node.elseif_branches.last.body_nodes.first.content).to eq('3')
+        expect(node.else_nodes.first.content).to eq('4')
+      end
+
+      it 'rejects elseif after else' do
+        expect { parse('{[#if a]}1{[#else]}2{[#elseif b]}3{[/if]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses comparison conditions' do
+        ast = parse('{[#if status == "a \\"b\\""]}{[#elseif n != -1]}{[#elseif x == y.z]}{[/if]}')
+        node = ast.nodes.first
+        expect(node.condition).to be_a(Natsuzora::AST::Comparison)
+        expect(node.condition.left.path).to eq(['status'])
+        expect(node.condition.operator).to eq(:eq)
+        expect(node.condition.right.value).to eq('a "b"')
+        expect(node.elseif_branches.first.condition.operator).to eq(:ne)
+        expect(node.elseif_branches.first.condition.right.value).to eq(-1)
+        expect(node.elseif_branches.last.condition.right.path).to eq(%w[y z])
+      end
+
+      it 'parses ordering comparisons against integer literals only' do
+        ast = parse('{[#if stock>=10]}{[/if]}')
+        expect(ast.nodes.first.condition.operator).to eq(:ge)
+        expect(ast.nodes.first.condition.right.value).to eq(10)
+        expect { parse('{[#if a < "x"]}{[/if]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses and/or/not with precedence' do
+        condition = parse('{[#if not a or b and c]}{[/if]}').nodes.first.condition
+        expect(condition.operator).to eq(:or)
+        expect(condition.left).to be_a(Natsuzora::AST::Not)
+        expect(condition.right.operator).to eq(:and)
+        expect(parse('{[#if not]}{[/if]}').nodes.first.condition.path).to eq(['not'])
+      end
+
+      it 'rejects an integer literal outside the safe range' do
+        expect { parse('{[#if a == 9007199254740992]}{[/if]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
       it 'parses nested if blocks' do
         ast = parse('{[#if a]}{[#if b]}inner{[/if]}{[/if]}')
         outer = ast.nodes.first
@@ -68,6 +138,12 @@ RSpec.describe Natsuzora::Parser do
         inner = outer.body_nodes.first
         expect(inner).to be_a(Natsuzora::AST::UnlessBlock)
       end
+
+      it 'parses unless block with else' do
+        node = parse('{[#unless hidden]}shown{[#else]}hidden{[/unless]}').nodes.first
+        expect(node.body_nodes.first.content).to eq('shown')
+        expect(node.else_nodes.first.content).to eq('hidden')
+      end
     end
 
     context 'with each blocks' do
@@ -77,6 +153,51 @@ RSpec.describe Natsuzora::Parser do
         expect(node).to be_a(Natsuzora::AST::EachBlock)
         expect(node.collection.path).to eq(['items'])
         expect(node.item_name).to eq('item')
+        expect(node.index_name).to be_nil
+      end
+
+      it 'parses an index binding' do
+        node = parse('{[#each items as item , i ]}{[ i ]}{[/each]}').nodes.first
+        expect(node.index_name).to eq('i')
+        expect { parse('{[#each items as item, item]}{[/each]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses a loop metadata binding' do
+        node = parse('{[#each items as item, i with loop]}{[/each]}').nodes.first
+        expect(node.meta_name).to eq('loop')
+        expect { parse('{[#each items as item with item]}{[/each]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses the in form' do
+        node = parse('{[#each item, i in site.items]}{[/each]}').nodes.first
+        expect(node.collection.path).to eq(%w[site items])
+        expect(node.item_name).to eq('item')
+        expect(node.index_name).to eq('i')
+        expect { parse('{[#each item in]}{[/each]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses an integer range' do
+        node = parse('{[#each 1..page.count as n]}{[/each]}').nodes.first
+        expect(node.collection).to be_a(Natsuzora::AST::EachRange)
+        expect(node.collection.first.value).to eq(1)
+        expect(node.collection.last.path).to eq(%w[page count])
+        expect { parse('{[#each 1 as n]}{[/each]}') }.to raise_error(Natsuzora::ParseError)
+        expect { parse('{[#each 1.. as n]}{[/each]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses limit and offset' do
+        node = parse('{[#each posts as post limit=5 offset=page.start ]}{[/each]}').nodes.first
+        expect(node.limit.value).to eq(5)
+        expect(node.offset.path).to eq(%w[page start])
+        expect { parse('{[#each posts as post limit=-1 ]}{[/each]}') }.to raise_error(Natsuzora::ParseError)
+        expect { parse('{[#each posts as post step=2 ]}{[/each]}') }.to raise_error(Natsuzora::ParseError, /Unknown each option/)
+      end
+
+      it 'parses an else branch' do
+        node = parse('{[#each items as item]}{[ item ]}{[#else]}none{[/each]}').nodes.first
+        expect(node.body_nodes.size).to eq(1)
+        expect(node.else_nodes.first.content).to eq('none')
+        expect { parse('{[#each items as item]}{[#else]}{[#else]}{[/each]}') }.to raise_error(Natsuzora::ParseError)
       end
     end
 
@@ -110,6 +231,107 @@ RSpec.describe Natsuzora::Parser do
         node = ast.nodes.first
         expect(node.args.keys).to contain_exactly('title', 'body')
       end
+
+      it 'parses include with a string literal argument' do
+        ast = parse('{[!include /button label="Save" ]}')
+        node = ast.nodes.first
+        expect(node.args['label']).to be_a(Natsuzora::AST::Literal)
+        expect(node.args['label'].value).to eq('Save')
+      end
+
+      it 'parses a params header' do
+        ast = parse("{[%params title, body,actions? ]}\n<h2>{[ title ]}</h2>")
+        expect(ast.params.map { |param| [param.name, param.optional] }).to eq([['title', false], ['body', false], ['actions', true]])
+        expect(ast.nodes.first).to be_a(Natsuzora::AST::Text)
+        expect(parse('{[%params]}').params).to eq([])
+        expect(parse('{[% params title ]}').params).to be_nil
+        expect { parse('{[#if x]}{[%params a ]}{[/if]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses a spread argument' do
+        node = parse('{[!include /card title=t ...card.props ]}').nodes.first
+        expect(node.spread.path).to eq(%w[card props])
+        expect(node.args.keys).to eq(['title'])
+        expect { parse('{[!include /card ...a ...b ]}') }.to raise_error(Natsuzora::ParseError)
+        expect { parse('{[@t k ...a ]}') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'parses include with integer and boolean literal arguments' do
+        ast = parse('{[!include /pager page=1 compact=true ]}')
+        node = ast.nodes.first
+        expect(node.args.transform_values(&:value)).to eq('page' => 1, 'compact' => true)
+      end
+
+      it 'parses a dynamic include named by a path' do
+        node = parse('{[!include block.template text=block.text ]}').nodes.first
+        expect(node.name).to be_nil
+        expect(node.source.path).to eq(%w[block template])
+        expect(node.args.keys).to eq(['text'])
+      end
+    end
+
+    context 'with block includes' do
+      it 'parses the body and arguments' do
+        node = parse('{[#include /card title=t ]}<p>{[ x ]}</p>{[/include]}').nodes.first
+        expect(node).to be_a(Natsuzora::AST::Include)
+        expect(node.args.keys).to eq(['title'])
+        expect(node.body_nodes.size).to eq(3)
+        expect(parse('{[!include /card ]}').nodes.first.body_nodes).to be_nil
+      end
+
+      it 'parses yield' do
+        expect(parse('{[!yield ]}').nodes.first).to be_a(Natsuzora::AST::Yield)
+        expect { parse('{[#include /card ]}x') }.to raise_error(Natsuzora::ParseError)
+      end
+
+      it 'keeps the trim markers of include tags' do
+        nodes = parse('a {[-!include /x -]}{[!include /y -]}{[-#include /z ]}{[/include]}').nodes
+        trims = nodes.grep(Natsuzora::AST::Include).map { |node| [node.trim_left, node.trim_right] }
+        expect(trims).to eq([[true, true], [false, true], [true, false]])
+        expect { parse('{[- !include /x ]}') }.to raise_error(Natsuzora::ParseError)
+      end
+    end
+
+    context 'with translations' do
+      it 'parses the key and arguments' do
+        node = parse('{[@t greeting.hello name=user.name count=3 ]}').nodes.first
+        expect(node).to be_a(Natsuzora::AST::Translate)
+        expect(node.key).to eq('greeting.hello')
+        expect(node.args.keys).to eq(%w[name count])
+        expect { parse('{[@t]}') }.to raise_error(Natsuzora::ParseError)
+        expect { parse('{[@t a x=1 x=2 ]}') }.to raise_error(Natsuzora::ParseError, /Duplicate/)
+      end
+    end
+
+    context 'with attribute tags' do
+      it 'parses the name and value path' do
+        node = parse('<a{[@attr "data-id" post.id ]}>').nodes[1]
+        expect(node).to be_a(Natsuzora::AST::Attr)
+        expect(node.name).to eq('data-id')
+        expect(node.path.path).to eq(%w[post id])
+        expect { parse('{[@attr id x ]}') }.to raise_error(Natsuzora::ParseError)
+        expect { parse('{[@attr "a b" x ]}') }.to raise_error(Natsuzora::ParseError, /Invalid attribute name/)
+      end
+    end
+
+    context 'with layouts' do
+      it 'parses extends and blocks' do
+        ast = parse("{[!extends /layouts/base ]}\n{[#block content]}Hi{[/block]}")
+        expect(ast.extends.name).to eq('/layouts/base')
+        block = ast.nodes.last
+        expect(block).to be_a(Natsuzora::AST::Block)
+        expect(block.name).to eq('content')
+        expect(block.body_nodes.first.content).to eq('Hi')
+      end
+
+      it 'rejects content outside blocks when extending' do
+        expect { parse('{[!extends /base ]}text') }.to raise_error(Natsuzora::ParseError, /Only blocks/)
+      end
+
+      it 'rejects duplicate block names' do
+        expect { parse('{[#block a]}{[/block]}{[#block a]}{[/block]}') }
+          .to raise_error(Natsuzora::ParseError, /Duplicate block name 'a'/)
+      end
     end
 
     context 'with reserved words' do
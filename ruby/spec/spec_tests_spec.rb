@@ -29,7 +29,8 @@ RSpec.describe 'Spec Tests' do # rubocop:disable RSpec/DescribeClass
       'ReservedWordError' => [Natsuzora::ParseError, Natsuzora::ReservedWordError],
       'ShadowingError' => Natsuzora::ShadowingError,
       'SyntaxError' => [Natsuzora::LexerError, Natsuzora::ParseError],
-      'IncludeError' => Natsuzora::IncludeError
+      'IncludeError' => Natsuzora::IncludeError,
+      'MissingTranslation' => Natsuzora::MissingTranslationError
     }.freeze
 
     template = test_case['template']
@@ -37,12 +38,15 @@ RSpec.describe 'Spec Tests' do # rubocop:disable RSpec/DescribeClass
     expected = test_case['expected']
     error_type = test_case['error']
     partials = test_case['partials']
+    options = { translations: test_case['translations'], locale: test_case['locale'] }
+    options[:group_separator] = test_case['group_separator'] if test_case['group_separator']
+    options[:dynamic_includes] = test_case['dynamic_includes'] if test_case['dynamic_includes']
 
     include_root = partials ? setup_partials(partials) : nil
 
     if expected
       # Success case
-      result = Natsuzora.render(template, data, include_root: include_root)
+      result = Natsuzora.render(template, data, include_root: include_root, **options)
       expect(result).to eq(expected), lambda {
         "Template: #{template.inspect}\nData: #{data.inspect}\nExpected: #{expected.inspect}\nGot: #{result.inspect}"
       }
@@ -50,7 +54,7 @@ RSpec.describe 'Spec Tests' do # rubocop:disable RSpec/DescribeClass
       # Error case
       error_classes = error_types[error_type] || Natsuzora::Error
       error_classes = Array(error_classes)
-      expect { Natsuzora.render(template, data, include_root: include_root) }.to(raise_error do |e|
+      expect { Natsuzora.render(template, data, include_root: include_root, **options) }.to(raise_error do |e|
         expect(error_classes.any? { |klass| e.is_a?(klass) }).to be(true),
                                                                  "Expected one of #{error_classes.map(&:name).join(', ')} but got #{e.class.name}\n" \
                                                                  "Template: #{template.inspect}\nData: #{data.inspect}\nError: #{e.message}"
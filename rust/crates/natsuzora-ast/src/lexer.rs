@@ -5,35 +5,76 @@
 //! - Tag mode: tokenizes operators, keywords, identifiers inside `{[` ... `]}`
 //!
 //! Escape: `{[{]}` → `{[` (processed inline as text)
+//!
+//! The delimiters are configurable ([`Delimiters`]); the escape is always the
+//! open delimiter, `{` and the close delimiter.
+//!
+//! Raw blocks: after a `{[#raw]}` tag the lexer emits everything up to the
+//! matching `{[/raw]}` as one text token, without tag mode or unescaping.
+//!
+//! Token values are slices of the shared source; only text runs containing
+//! escapes are copied.
+
+use std::ops::Range;
+use std::sync::Arc;
 
 use crate::token::{Token, TokenType};
-use crate::{Location, ParseError};
+use crate::{is_ident_continue, is_ident_start, Delimiters, Location, ParseError, SourceText};
+
+/// Block keyword whose body the lexer passes through untokenized.
+pub(crate) const RAW_KEYWORD: &str = "raw";
+/// Word after `%` that makes a comment-like tag a parameter header, which
+/// is tokenized instead of skipped.
+pub(crate) const PARAMS_KEYWORD: &str = "params";
 
 /// Tokenize a source string into a sequence of tokens.
+#[cfg(any(test, feature = "unstable"))]
 pub fn tokenize(source: &str) -> Result<Vec<Token>, ParseError> {
-    let mut lexer = Lexer::new(source);
+    tokenize_shared(&Arc::from(source), &Delimiters::default())
+}
+
+/// Tokenize a shared source; token values reference `source` without copying.
+pub(crate) fn tokenize_shared(
+    source: &Arc<str>,
+    delimiters: &Delimiters,
+) -> Result<Vec<Token>, ParseError> {
+    let mut lexer = Lexer::new(source, delimiters);
     lexer.tokenize()
 }
 
 struct Lexer<'a> {
+    shared: &'a Arc<str>,
     source: &'a [u8],
     pos: usize,
     line: usize,
     col: usize,
     in_tag: bool,
+    /// Byte offset just past the `{[` of the current tag.
+    tag_start: usize,
+    /// Index of the first token of the current tag.
+    tag_first_token: usize,
+    open: &'a [u8],
+    close: &'a [u8],
+    /// `open` `{` `close`, which stands for a literal `open`
+    escape: Vec<u8>,
 }
 
 impl<'a> Lexer<'a> {
-    const TAG_OPEN: &'static [u8] = b"{[";
-    const TAG_OPEN_ESCAPE: &'static [u8] = b"{[{]}";
-
-    fn new(source: &'a str) -> Self {
+    fn new(shared: &'a Arc<str>, delimiters: &'a Delimiters) -> Self {
         Self {
-            source: source.as_bytes(),
+            shared,
+            source: shared.as_bytes(),
             pos: 0,
             line: 1,
             col: 1,
             in_tag: false,
+            tag_start: 0,
+            tag_first_token: 0,
+            open: delimiters.open().as_bytes(),
+            close: delimiters.close().as_bytes(),
+            escape: [delimiters.open(), "{", delimiters.close()]
+                .concat()
+                .into_bytes(),
         }
     }
 
@@ -58,14 +99,20 @@ impl<'a> Lexer<'a> {
     /// Tokenize text mode: accumulate text until `{[` delimiter.
     fn tokenize_text(&mut self, tokens: &mut Vec<Token>) {
         let start_loc = Location::new(self.line, self.col, self.pos);
-        let mut text = String::new();
+        let start = self.pos;
+        // Only allocated once an escape makes the text differ from the source.
+        let mut unescaped: Option<String> = None;
+        let mut segment_start = start;
 
         while self.pos < self.source.len() {
-            if self.looking_at(Self::TAG_OPEN) {
+            if self.looking_at(self.open) {
                 // Check for escape sequence: {[{]}
-                if self.looking_at(Self::TAG_OPEN_ESCAPE) {
-                    text.push_str("{[");
-                    self.advance_n(Self::TAG_OPEN_ESCAPE.len()); // skip {[{]}
+                if self.looking_at(&self.escape) {
+                    let text = unescaped.get_or_insert_with(String::new);
+                    text.push_str(&self.shared[segment_start..self.pos]);
+                    text.push_str(&self.shared[self.pos..self.pos + self.open.len()]);
+                    self.advance_n(self.escape.len()); // skip {[{]}
+                    segment_start = self.pos;
                     continue;
                 }
 
@@ -73,19 +120,26 @@ impl<'a> Lexer<'a> {
                 break;
             }
 
-            let ch = self.source[self.pos];
-            text.push(ch as char);
             self.advance_one();
         }
 
+        let text = match unescaped {
+            Some(mut text) => {
+                text.push_str(&self.shared[segment_start..self.pos]);
+                SourceText::from(text)
+            }
+            None => self.slice(start..self.pos),
+        };
         if !text.is_empty() {
             tokens.push(Token::new(TokenType::Text, text, start_loc));
         }
 
         // Enter tag mode if we found {[
-        if self.looking_at(Self::TAG_OPEN) {
-            self.advance_n(Self::TAG_OPEN.len()); // skip {[
+        if self.looking_at(self.open) {
+            self.advance_n(self.open.len()); // skip {[
             self.in_tag = true;
+            self.tag_start = self.pos;
+            self.tag_first_token = tokens.len();
         }
     }
 
@@ -99,21 +153,48 @@ impl<'a> Lexer<'a> {
         let loc = Location::new(self.line, self.col, self.pos);
         let ch = self.source[self.pos];
 
-        match ch {
-            // Closing delimiter ]}
-            b']' if self.looking_at_token(TokenType::Close) => {
-                self.emit_fixed(tokens, TokenType::Close, loc);
-                self.in_tag = false;
+        // Closing delimiter ]}
+        if self.looking_at(self.close) {
+            let raw_open = Self::raw_keyword(&tokens[self.tag_first_token..]);
+            let len = self.close.len();
+            tokens.push(Token::new(
+                TokenType::Close,
+                self.slice(self.pos..self.pos + len),
+                loc,
+            ));
+            self.advance_n(len);
+            self.in_tag = false;
+            if let Some(keyword) = raw_open {
+                self.tokenize_raw_body(tokens, keyword)?;
             }
+            return Ok(());
+        }
 
+        match ch {
             b'%' => {
+                let opens_comment = self.pos == self.tag_start
+                    || (self.pos == self.tag_start + 1
+                        && matches!(self.source[self.tag_start], b'-' | b'~'));
                 self.emit_fixed(tokens, TokenType::Percent, loc);
+                let params = self.source[self.pos..].starts_with(PARAMS_KEYWORD.as_bytes())
+                    && !self.is_ident_continue_at(self.pos + PARAMS_KEYWORD.len());
+                if opens_comment && !params {
+                    self.skip_comment_body();
+                }
+            }
+
+            b'-' if self.pos > self.tag_start && self.is_digit_at(self.pos + 1) => {
+                self.tokenize_integer(tokens, loc);
             }
 
             b'-' => {
                 self.emit_fixed(tokens, TokenType::Dash, loc);
             }
 
+            b'~' => {
+                self.emit_fixed(tokens, TokenType::Tilde, loc);
+            }
+
             b'#' => {
                 self.emit_fixed(tokens, TokenType::Hash, loc);
             }
@@ -126,6 +207,26 @@ impl<'a> Lexer<'a> {
                 self.tokenize_bang(tokens, loc);
             }
 
+            b'@' if self.looking_at_token(TokenType::AtT)
+                && !self.is_ident_continue_at(self.pos + 2) =>
+            {
+                self.emit_fixed(tokens, TokenType::AtT, loc);
+            }
+
+            b'@' if self.looking_at_token(TokenType::AtAttr)
+                && !self.is_ident_continue_at(self.pos + 5) =>
+            {
+                self.emit_fixed(tokens, TokenType::AtAttr, loc);
+            }
+
+            b'.' if self.looking_at_token(TokenType::Ellipsis) => {
+                self.emit_fixed(tokens, TokenType::Ellipsis, loc);
+            }
+
+            b'.' if self.looking_at_token(TokenType::DotDot) => {
+                self.emit_fixed(tokens, TokenType::DotDot, loc);
+            }
+
             b'.' => {
                 self.emit_fixed(tokens, TokenType::Dot, loc);
             }
@@ -134,14 +235,54 @@ impl<'a> Lexer<'a> {
                 self.emit_fixed(tokens, TokenType::Comma, loc);
             }
 
+            b'[' => {
+                self.emit_fixed(tokens, TokenType::LeftBracket, loc);
+            }
+
+            b']' => {
+                self.emit_fixed(tokens, TokenType::RightBracket, loc);
+            }
+
+            b'=' if self.looking_at_token(TokenType::EqualEqual) => {
+                self.emit_fixed(tokens, TokenType::EqualEqual, loc);
+            }
+
             b'=' => {
                 self.emit_fixed(tokens, TokenType::Equal, loc);
             }
 
+            b'<' if self.looking_at_token(TokenType::LessEqual) => {
+                self.emit_fixed(tokens, TokenType::LessEqual, loc);
+            }
+
+            b'<' => {
+                self.emit_fixed(tokens, TokenType::Less, loc);
+            }
+
+            b'>' if self.looking_at_token(TokenType::GreaterEqual) => {
+                self.emit_fixed(tokens, TokenType::GreaterEqual, loc);
+            }
+
+            b'>' => {
+                self.emit_fixed(tokens, TokenType::Greater, loc);
+            }
+
+            b'"' => {
+                self.tokenize_string(tokens, loc)?;
+            }
+
+            b'0'..=b'9' => {
+                self.tokenize_integer(tokens, loc);
+            }
+
             b'?' => {
                 self.emit_fixed(tokens, TokenType::Question, loc);
             }
 
+            b'|' => {
+                self.emit_fixed(tokens, TokenType::Pipe, loc);
+            }
+
             // Whitespace
             b' ' | b'\t' | b'\r' | b'\n' => {
                 let start = self.pos;
@@ -151,18 +292,18 @@ impl<'a> Lexer<'a> {
                         _ => break,
                     }
                 }
-                let ws_text = std::str::from_utf8(&self.source[start..self.pos]).unwrap_or(" ");
+                let ws_text = self.slice(start..self.pos);
                 tokens.push(Token::new(TokenType::Whitespace, ws_text, loc));
             }
 
             // Identifier or keyword
-            b'A'..=b'Z' | b'a'..=b'z' => {
+            _ if self.char_at(self.pos).is_some_and(is_ident_start) => {
                 let start = self.pos;
-                while self.pos < self.source.len() && self.is_ident_continue_at(self.pos) {
-                    self.advance_one();
+                while let Some(c) = self.char_at(self.pos).filter(|c| is_ident_continue(*c)) {
+                    self.advance_n(c.len_utf8());
                 }
-                let ident = std::str::from_utf8(&self.source[start..self.pos]).unwrap_or("");
-                let token_type = match ident {
+                let ident = self.slice(start..self.pos);
+                let token_type = match ident.as_str() {
                     "if" => TokenType::KwIf,
                     "unless" => TokenType::KwUnless,
                     "else" => TokenType::KwElse,
@@ -177,7 +318,8 @@ impl<'a> Lexer<'a> {
                 return Err(ParseError::SyntaxError {
                     line: loc.line,
                     column: loc.column,
-                    byte_range: self.pos..self.pos + 1,
+                    byte_range: self.pos
+                        ..self.pos + self.char_at(self.pos).map_or(1, char::len_utf8),
                 });
             }
         }
@@ -185,6 +327,88 @@ impl<'a> Lexer<'a> {
         Ok(())
     }
 
+    /// Skip comment content up to its `]}` or `-]}` without tokenizing it.
+    fn skip_comment_body(&mut self) {
+        while self.pos < self.source.len()
+            && !self.looking_at(self.close)
+            && !(matches!(self.source[self.pos], b'-' | b'~')
+                && self.source[self.pos + 1..].starts_with(self.close))
+        {
+            self.advance_one();
+        }
+    }
+
+    /// Location of `raw` if `tag` is a raw block open: `-`? `#` ws? `raw` ws? `-`?,
+    /// where `~` may stand for `-`
+    fn raw_keyword(tag: &[Token]) -> Option<Location> {
+        let mut types = tag
+            .iter()
+            .filter(|t| t.token_type != TokenType::Whitespace)
+            .peekable();
+        types.next_if(|t| matches!(t.token_type, TokenType::Dash | TokenType::Tilde));
+        types.next().filter(|t| t.token_type == TokenType::Hash)?;
+        let keyword = types
+            .next()
+            .filter(|t| t.token_type == TokenType::Ident && t.value == RAW_KEYWORD)?;
+        types.next_if(|t| matches!(t.token_type, TokenType::Dash | TokenType::Tilde));
+        types.next().is_none().then_some(keyword.location)
+    }
+
+    /// Emit the body of a raw block verbatim, stopping before its `{[/raw]}`.
+    fn tokenize_raw_body(
+        &mut self,
+        tokens: &mut Vec<Token>,
+        open: Location,
+    ) -> Result<(), ParseError> {
+        let start_loc = Location::new(self.line, self.col, self.pos);
+        let start = self.pos;
+        while !self.looking_at_raw_close() {
+            if self.pos >= self.source.len() {
+                return Err(ParseError::UnexpectedToken {
+                    message: "Unclosed raw block".to_string(),
+                    line: open.line,
+                    column: open.column,
+                });
+            }
+            self.advance_one();
+        }
+        if self.pos > start {
+            tokens.push(Token::new(
+                TokenType::Text,
+                self.slice(start..self.pos),
+                start_loc,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check for `{[` `-`? `/` ws? `raw` ws? `-`? `]}` at the current position,
+    /// where `~` may stand for `-`.
+    fn looking_at_raw_close(&self) -> bool {
+        let rest = &self.source[self.pos..];
+        let Some(rest) = rest.strip_prefix(self.open) else {
+            return false;
+        };
+        let rest = strip_trim_marker(rest);
+        let Some(rest) = rest.strip_prefix(b"/") else {
+            return false;
+        };
+        let Some(rest) = skip_whitespace(rest).strip_prefix(RAW_KEYWORD.as_bytes()) else {
+            return false;
+        };
+        if self.is_ident_continue_at(self.source.len() - rest.len()) {
+            return false;
+        }
+        let rest = skip_whitespace(rest);
+        let rest = strip_trim_marker(rest);
+        rest.starts_with(self.close)
+    }
+
+    /// Shared slice of the source.
+    fn slice(&self, range: Range<usize>) -> SourceText {
+        SourceText::shared(self.shared, range)
+    }
+
     /// Check if the source at current position starts with the given bytes.
     fn looking_at(&self, pattern: &[u8]) -> bool {
         self.source[self.pos..].starts_with(pattern)
@@ -204,14 +428,80 @@ impl<'a> Lexer<'a> {
 
     /// Emit a token with fixed literal text and advance by its byte length.
     fn emit_fixed(&mut self, tokens: &mut Vec<Token>, token_type: TokenType, loc: Location) {
-        let literal = Self::token_literal(token_type);
-        tokens.push(Token::new(token_type, literal, loc));
-        self.advance_n(literal.len());
+        let len = Self::token_literal(token_type).len();
+        tokens.push(Token::new(
+            token_type,
+            self.slice(self.pos..self.pos + len),
+            loc,
+        ));
+        self.advance_n(len);
+    }
+
+    /// Tokenize `"..."`; only `\"` and `\\` escapes are allowed.
+    fn tokenize_string(
+        &mut self,
+        tokens: &mut Vec<Token>,
+        loc: Location,
+    ) -> Result<(), ParseError> {
+        let start = self.pos;
+        self.advance_one(); // opening quote
+        loop {
+            match self.source.get(self.pos) {
+                Some(b'"') => break,
+                Some(b'\\') if matches!(self.source.get(self.pos + 1), Some(b'"' | b'\\')) => {
+                    self.advance_n(2);
+                }
+                Some(b'\\') | None => {
+                    return Err(ParseError::SyntaxError {
+                        line: loc.line,
+                        column: loc.column,
+                        byte_range: start..self.pos,
+                    });
+                }
+                Some(_) => self.advance_one(),
+            }
+        }
+        self.advance_one(); // closing quote
+        tokens.push(Token::new(
+            TokenType::String,
+            self.slice(start..self.pos),
+            loc,
+        ));
+        Ok(())
     }
 
-    /// Tokenize `!`, `!unsecure`, `!include` using longest keyword match.
+    /// Tokenize an integer literal with an optional leading `-`.
+    fn tokenize_integer(&mut self, tokens: &mut Vec<Token>, loc: Location) {
+        let start = self.pos;
+        if self.source[self.pos] == b'-' {
+            self.advance_one();
+        }
+        while self.is_digit_at(self.pos) {
+            self.advance_one();
+        }
+        tokens.push(Token::new(
+            TokenType::Integer,
+            self.slice(start..self.pos),
+            loc,
+        ));
+    }
+
+    fn is_digit_at(&self, pos: usize) -> bool {
+        matches!(self.source.get(pos), Some(b'0'..=b'9'))
+    }
+
+    /// Tokenize `!`, `!=` and the bang keywords using longest keyword match.
     fn tokenize_bang(&mut self, tokens: &mut Vec<Token>, loc: Location) {
-        for token_type in [TokenType::BangUnsecure, TokenType::BangInclude] {
+        if self.looking_at_token(TokenType::NotEqual) {
+            self.emit_fixed(tokens, TokenType::NotEqual, loc);
+            return;
+        }
+        for token_type in [
+            TokenType::BangUnsecure,
+            TokenType::BangInclude,
+            TokenType::BangExtends,
+            TokenType::BangYield,
+        ] {
             let literal = Self::token_literal(token_type);
             if self.looking_at(literal.as_bytes())
                 && !self.is_ident_continue_at(self.pos + literal.len())
@@ -223,21 +513,26 @@ impl<'a> Lexer<'a> {
         self.emit_fixed(tokens, TokenType::Exclamation, loc);
     }
 
-    /// Check if byte at given position is a valid identifier continuation character.
+    /// Check if the character at given position is a valid identifier continuation character.
     fn is_ident_continue_at(&self, pos: usize) -> bool {
-        if pos >= self.source.len() {
-            return false;
-        }
-        matches!(self.source[pos], b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'_')
+        self.char_at(pos).is_some_and(is_ident_continue)
+    }
+
+    /// The character starting at byte `pos`, if `pos` is a character boundary.
+    fn char_at(&self, pos: usize) -> Option<char> {
+        self.shared.get(pos..)?.chars().next()
     }
 
     /// Advance position by one byte, updating line/column tracking.
+    ///
+    /// Columns count characters: UTF-8 continuation bytes do not advance them.
     fn advance_one(&mut self) {
         if self.pos < self.source.len() {
-            if self.source[self.pos] == b'\n' {
+            let byte = self.source[self.pos];
+            if byte == b'\n' {
                 self.line += 1;
                 self.col = 1;
-            } else {
+            } else if byte & 0xC0 != 0x80 {
                 self.col += 1;
             }
             self.pos += 1;
@@ -252,6 +547,22 @@ impl<'a> Lexer<'a> {
     }
 }
 
+fn skip_whitespace(bytes: &[u8]) -> &[u8] {
+    let len = bytes
+        .iter()
+        .take_while(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+        .count();
+    &bytes[len..]
+}
+
+/// Skip a `-` or `~` trim marker.
+fn strip_trim_marker(bytes: &[u8]) -> &[u8] {
+    match bytes.first() {
+        Some(b'-' | b'~') => &bytes[1..],
+        _ => bytes,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,6 +601,33 @@ mod tests {
         assert_eq!(tokens[0].value, "a{[b");
     }
 
+    #[test]
+    fn test_text_references_source() {
+        let tokens = tokenize("日本{[ a ]}語").unwrap();
+        assert_eq!(tokens[0].value, "日本");
+        assert_eq!(tokens[0].value.source_range(), Some(0..6));
+        assert_eq!(tokens[5].value, "語");
+        assert_eq!(tokens[5].location.column, 10);
+    }
+
+    #[test]
+    fn test_unicode_identifier() {
+        let tokens = tokenize("{[#if 在庫あり]}{[ 商品名 ]}{[/if]}").unwrap();
+        assert_eq!(tokens[3].token_type, TokenType::Ident);
+        assert_eq!(tokens[3].value, "在庫あり");
+        assert_eq!(tokens[4].location.column, 11);
+        assert_eq!(tokens[6].value, "商品名");
+
+        let Err(ParseError::SyntaxError { byte_range, .. }) = tokenize("{[ 😀 ]}") else {
+            panic!("emoji must not start an identifier");
+        };
+        assert_eq!(byte_range, 3..7);
+        assert!(tokenize("{[#ifé]}")
+            .unwrap()
+            .iter()
+            .all(|t| t.token_type != TokenType::KwIf));
+    }
+
     #[test]
     fn test_keywords() {
         let tokens = tokenize("{[#if x]}y{[/if]}").unwrap();
@@ -321,4 +659,103 @@ mod tests {
         let tokens = tokenize("{[% this is a comment ]}").unwrap();
         assert!(types(&tokens).contains(&TokenType::Percent));
     }
+
+    #[test]
+    fn test_comparison_literals() {
+        let tokens = tokenize(r#"{[#if a == "x \" y" ]}{[#if b != -12 ]}"#).unwrap();
+        let literals: Vec<_> = tokens
+            .iter()
+            .filter(|t| {
+                matches!(
+                    t.token_type,
+                    TokenType::EqualEqual
+                        | TokenType::NotEqual
+                        | TokenType::String
+                        | TokenType::Integer
+                )
+            })
+            .map(|t| (t.token_type, t.value.as_str()))
+            .collect();
+        assert_eq!(
+            literals,
+            vec![
+                (TokenType::EqualEqual, "=="),
+                (TokenType::String, r#""x \" y""#),
+                (TokenType::NotEqual, "!="),
+                (TokenType::Integer, "-12"),
+            ]
+        );
+        assert!(tokenize(r#"{[#if a == "open ]}"#).is_err());
+    }
+
+    #[test]
+    fn test_ordering_operators() {
+        let tokens = tokenize("{[#if a<1]}{[#if b <= c]}{[#if d>=-2]}{[#if e > 0]}").unwrap();
+        let operators: Vec<_> = types(&tokens)
+            .into_iter()
+            .filter(|t| {
+                matches!(
+                    t,
+                    TokenType::Less
+                        | TokenType::LessEqual
+                        | TokenType::Greater
+                        | TokenType::GreaterEqual
+                )
+            })
+            .collect();
+        assert_eq!(
+            operators,
+            vec![
+                TokenType::Less,
+                TokenType::LessEqual,
+                TokenType::GreaterEqual,
+                TokenType::Greater,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_comment_body_is_not_tokenized() {
+        let tokens = tokenize("{[-% key: [1] -]}").unwrap();
+        assert_eq!(
+            types(&tokens),
+            vec![
+                TokenType::Dash,
+                TokenType::Percent,
+                TokenType::Dash,
+                TokenType::Close,
+                TokenType::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tilde_whitespace_control() {
+        assert_eq!(
+            types(&tokenize("{[~% note ~]}").unwrap()),
+            vec![
+                TokenType::Tilde,
+                TokenType::Percent,
+                TokenType::Tilde,
+                TokenType::Close,
+                TokenType::Eof,
+            ]
+        );
+        let tokens = tokenize("{[~#raw~]}{[ a ]}{[~/raw~]}").unwrap();
+        assert!(tokens
+            .iter()
+            .any(|t| t.token_type == TokenType::Text && t.value.as_str() == "{[ a ]}"));
+    }
+
+    #[test]
+    fn test_raw_body_is_one_text_token() {
+        let tokens = tokenize("{[#raw]}{[ a ]}{[{]}{[/ raw -]}").unwrap();
+        let texts: Vec<_> = tokens
+            .iter()
+            .filter(|t| t.token_type == TokenType::Text)
+            .map(|t| (t.value.as_str(), t.location.column))
+            .collect();
+        assert_eq!(texts, [("{[ a ]}{[{]}", 9)]);
+        assert!(tokenize("{[#raw]}{[/rawx]}").is_err());
+    }
 }
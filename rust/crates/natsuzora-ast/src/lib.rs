@@ -1,17 +1,30 @@
 //! Hand-written Lexer + TokenProcessor + Parser for Natsuzora templates.
 //!
-//! Pipeline: source → Lexer → Token[] → TokenProcessor → Token[] → Parser → AST
+//! Pipeline: source → Lexer → Token[] → TokenProcessor → Token[] → Parser → AST → normalize
 
 mod lexer;
+mod line_index;
+mod node_kind;
+mod normalize;
 mod parser;
+mod text;
+mod text_runs;
 mod token;
 mod token_processor;
 
 use std::error::Error;
+use std::fmt;
 use std::ops::Range;
+use std::sync::Arc;
 
 use thiserror::Error;
 
+pub use line_index::{LineIndex, LspPosition, LspRange};
+pub use node_kind::{grammar_kind, GrammarKind, AST_NODE_KINDS, GRAMMAR_KINDS};
+pub use text::SourceText;
+pub use text_runs::TextRuns;
+pub use token_processor::{Trim, TrimCause};
+
 // ============================================================================
 // Location
 // ============================================================================
@@ -32,6 +45,27 @@ impl Location {
             byte_offset,
         }
     }
+
+    /// The location just past `text`, which starts here
+    pub fn advanced(self, text: &str) -> Self {
+        text.chars().fold(
+            Self {
+                byte_offset: self.byte_offset + text.len(),
+                ..self
+            },
+            |loc, c| match c {
+                '\n' => Self {
+                    line: loc.line + 1,
+                    column: 1,
+                    ..loc
+                },
+                _ => Self {
+                    column: loc.column + 1,
+                    ..loc
+                },
+            },
+        )
+    }
 }
 
 // ============================================================================
@@ -39,15 +73,47 @@ impl Location {
 // ============================================================================
 
 /// A parsed template consisting of the linear list of nodes.
+///
+/// Text nodes of a parsed template reference the shared source instead of
+/// holding their own copies.
 #[derive(Debug, Clone)]
 pub struct Template {
     nodes: Vec<AstNode>,
     location: Location,
+    source: Option<Arc<str>>,
+    params: Option<Vec<Param>>,
 }
 
 impl Template {
     pub fn new(nodes: Vec<AstNode>, location: Location) -> Self {
-        Self { nodes, location }
+        Self {
+            nodes,
+            location,
+            source: None,
+            params: None,
+        }
+    }
+
+    /// Attach the parameters declared by a `{[%params ...]}` header.
+    pub fn with_params(mut self, params: Vec<Param>) -> Self {
+        self.params = Some(params);
+        self
+    }
+
+    /// Parameters the template expects when included, if it declares them.
+    pub fn params(&self) -> Option<&[Param]> {
+        self.params.as_deref()
+    }
+
+    /// Attach the source text the nodes were parsed from.
+    pub fn with_source(mut self, source: Arc<str>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Source text the template was parsed from, if known.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
     }
 
     pub fn nodes(&self) -> &[AstNode] {
@@ -57,9 +123,39 @@ impl Template {
     pub fn location(&self) -> Location {
         self.location
     }
+
+    /// Text outside tags with its location, in source order, including the
+    /// text inside blocks
+    ///
+    /// ```rust
+    /// let template = natsuzora_ast::parse("Hello {[ name ]}!{[#if x]}\nBye{[/if]}").unwrap();
+    /// let runs: Vec<&str> = template.text_runs().map(|(text, _)| text).collect();
+    /// assert_eq!(runs, ["Hello ", "!", "\nBye"]);
+    /// ```
+    pub fn text_runs(&self) -> TextRuns<'_> {
+        TextRuns::new(&self.nodes)
+    }
+
+    /// The layout this template extends, if any.
+    pub fn extends(&self) -> Option<&ExtendsNode> {
+        self.nodes.iter().find_map(|node| match node {
+            AstNode::Extends(n) => Some(n),
+            _ => None,
+        })
+    }
+}
+
+/// A parameter declared by `{[%params title, actions? ]}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    /// Declared with `?`: may be omitted, and is then bound to `null`
+    pub optional: bool,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum AstNode {
     Text(TextNode),
     Variable(VariableNode),
@@ -67,7 +163,13 @@ pub enum AstNode {
     If(IfBlock),
     Unless(UnlessBlock),
     Each(EachBlock),
+    Cache(CacheBlock),
     Include(IncludeNode),
+    Block(BlockNode),
+    Extends(ExtendsNode),
+    Yield(YieldNode),
+    Translate(TranslateNode),
+    Attr(AttrNode),
 }
 
 impl AstNode {
@@ -79,7 +181,32 @@ impl AstNode {
             AstNode::If(n) => n.location,
             AstNode::Unless(n) => n.location,
             AstNode::Each(n) => n.location,
+            AstNode::Cache(n) => n.location,
             AstNode::Include(n) => n.location,
+            AstNode::Block(n) => n.location,
+            AstNode::Extends(n) => n.location,
+            AstNode::Yield(n) => n.location,
+            AstNode::Translate(n) => n.location,
+            AstNode::Attr(n) => n.location,
+        }
+    }
+
+    /// Name of the node kind, one of [`AST_NODE_KINDS`]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AstNode::Text(_) => "text",
+            AstNode::Variable(_) => "variable",
+            AstNode::Unsecure(_) => "unsecure",
+            AstNode::If(_) => "if",
+            AstNode::Unless(_) => "unless",
+            AstNode::Each(_) => "each",
+            AstNode::Cache(_) => "cache",
+            AstNode::Include(_) => "include",
+            AstNode::Block(_) => "block",
+            AstNode::Extends(_) => "extends",
+            AstNode::Yield(_) => "yield",
+            AstNode::Translate(_) => "translate",
+            AstNode::Attr(_) => "attr",
         }
     }
 }
@@ -87,15 +214,18 @@ impl AstNode {
 /// Raw text content.
 #[derive(Debug, Clone)]
 pub struct TextNode {
-    pub content: String,
+    pub content: SourceText,
     pub location: Location,
 }
 
-/// Variable output: {[ path ]} or {[ path? ]} or {[ path! ]}
+/// Variable output: {[ path ]} or {[ path? ]} or {[ path! ]}, optionally
+/// followed by filters: {[ path | upper | truncate 20 ]}
 #[derive(Debug, Clone)]
 pub struct VariableNode {
     pub path: Path,
     pub modifier: Modifier,
+    /// Applied left to right after the modifier check
+    pub filters: Vec<Filter>,
     pub location: Location,
 }
 
@@ -106,45 +236,198 @@ pub struct UnsecureNode {
     pub location: Location,
 }
 
-/// Conditional block: {[#if condition]} ... {[#else]} ... {[/if]}
+/// Conditional block: {[#if condition]} ... {[#elseif other]} ... {[#else]} ... {[/if]}
 #[derive(Debug, Clone)]
 pub struct IfBlock {
-    pub condition: Path,
+    pub condition: Condition,
     pub then_branch: Vec<AstNode>,
+    pub elseif_branches: Vec<ElseIfBranch>,
     pub else_branch: Option<Vec<AstNode>>,
     pub location: Location,
 }
 
-/// Inverse conditional block: {[#unless condition]} ... {[/unless]}
+/// `{[#elseif condition]}` clause of an if block.
+#[derive(Debug, Clone)]
+pub struct ElseIfBranch {
+    pub condition: Condition,
+    pub body: Vec<AstNode>,
+    pub location: Location,
+}
+
+/// Inverse conditional block: {[#unless condition]} ... {[#else]} ... {[/unless]}
 #[derive(Debug, Clone)]
 pub struct UnlessBlock {
-    pub condition: Path,
+    pub condition: Condition,
     pub body: Vec<AstNode>,
+    /// Rendered instead of the body when the condition is truthy
+    pub else_branch: Option<Vec<AstNode>>,
     pub location: Location,
 }
 
-/// Loop block: {[#each collection as item]} ... {[/each]}
+/// Loop block: {[#each collection as item]} ... {[#else]} ... {[/each]}
 #[derive(Debug, Clone)]
 pub struct EachBlock {
-    pub collection: Path,
+    pub collection: Collection,
     pub item_ident: String,
+    /// Zero-based index variable: `{[#each items as item, i]}`
+    pub index_ident: Option<String>,
+    /// Loop metadata variable: `{[#each items as item with loop]}`
+    pub meta_ident: Option<String>,
+    /// Maximum number of entries rendered: `{[#each items as item limit=5 ]}`
+    pub limit: Option<Operand>,
+    /// Number of leading entries skipped: `{[#each items as item offset=n ]}`
+    pub offset: Option<Operand>,
     pub body: Vec<AstNode>,
+    /// Rendered instead of the body when the collection is empty
+    pub else_branch: Option<Vec<AstNode>>,
     pub location: Location,
 }
 
-/// Include directive: {[!include /path key=value ]}
+impl EachBlock {
+    /// Names bound in the body: item, then index and metadata if present.
+    pub fn bindings(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.item_ident.as_str())
+            .chain(self.index_ident.as_deref())
+            .chain(self.meta_ident.as_deref())
+    }
+
+    /// Paths read by `limit=` and `offset=`, resolved in the enclosing scope.
+    pub fn window_paths(&self) -> impl Iterator<Item = &Path> {
+        [&self.limit, &self.offset]
+            .into_iter()
+            .filter_map(|operand| match operand {
+                Some(Operand::Path(path)) => Some(path),
+                _ => None,
+            })
+    }
+}
+
+/// What an each block iterates.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Collection {
+    /// An array or object: `{[#each items as item]}`
+    Path(Path),
+    /// Integers from `start` to `end` inclusive: `{[#each 1..count as n]}`.
+    /// Bounds are integer literals or paths.
+    Range { start: Operand, end: Operand },
+}
+
+impl Collection {
+    /// Paths read by the collection, resolved in the enclosing scope.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        let (path, bounds) = match self {
+            Collection::Path(path) => (Some(path), None),
+            Collection::Range { start, end } => (None, Some([start, end])),
+        };
+        path.into_iter().chain(
+            bounds
+                .into_iter()
+                .flatten()
+                .filter_map(|operand| match operand {
+                    Operand::Path(path) => Some(path),
+                    Operand::Literal(_) => None,
+                }),
+        )
+    }
+
+    /// The iterated path, or `None` for a range
+    pub fn as_path(&self) -> Option<&Path> {
+        match self {
+            Collection::Path(path) => Some(path),
+            Collection::Range { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Collection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Collection::Path(path) => f.write_str(&path.as_str()),
+            Collection::Range { start, end } => write!(f, "{start}..{end}"),
+        }
+    }
+}
+
+/// Fragment cache block: {[#cache key=path ]} ... {[/cache]}
+#[derive(Debug, Clone)]
+pub struct CacheBlock {
+    pub key: Path,
+    pub body: Vec<AstNode>,
+    /// Stable hash of the block's tokens; tells apart cache blocks sharing a key.
+    pub fingerprint: u64,
+    pub location: Location,
+}
+
+/// Include directive: {[!include /path key=value ]}, or with a body:
+/// {[#include /path key=value ]} ... {[/include]}
 #[derive(Debug, Clone)]
 pub struct IncludeNode {
+    /// Empty for a dynamic include
     pub name: String,
+    /// `{[!include widget.template ]}`: the path whose string value names
+    /// the partial at render time
+    pub source: Option<Path>,
     pub args: Vec<IncludeArg>,
+    /// `...path`: an object whose entries are bound besides `args`, which
+    /// take precedence
+    pub spread: Option<Path>,
+    /// Content the partial outputs at `{[!yield]}`; `None` for `!include`
+    pub body: Option<Vec<AstNode>>,
+    /// The tag opened with `{[-`: the partial's leading whitespace is trimmed too
+    pub trim_left: bool,
+    /// The tag closed with `-]}`: the partial's trailing whitespace is trimmed too
+    pub trim_right: bool,
+    pub location: Location,
+}
+
+/// Insertion point for the body of a block include: {[!yield]}
+#[derive(Debug, Clone)]
+pub struct YieldNode {
+    pub location: Location,
+}
+
+/// Translated message: {[@t greeting.hello name=user.name ]}
+#[derive(Debug, Clone)]
+pub struct TranslateNode {
+    /// Message key looked up in the catalog of the render locale
+    pub key: String,
+    /// Values for the message's `{name}` placeholders
+    pub args: Vec<IncludeArg>,
+    pub location: Location,
+}
+
+/// Conditional HTML attribute: {[@attr "disabled" form.locked ]}
+#[derive(Debug, Clone)]
+pub struct AttrNode {
+    /// Attribute name, validated at parse time
+    pub name: String,
+    pub path: Path,
+    pub location: Location,
+}
+
+/// Named layout region: {[#block name]} ... {[/block]}
+#[derive(Debug, Clone)]
+pub struct BlockNode {
+    pub name: String,
+    pub body: Vec<AstNode>,
+    pub location: Location,
+}
+
+/// Layout reference: {[!extends /path ]}
+#[derive(Debug, Clone)]
+pub struct ExtendsNode {
+    pub name: String,
     pub location: Location,
 }
 
-/// Include argument: key=value
+/// Include or translation argument: key=path, or a string, integer or
+/// boolean literal
 #[derive(Debug, Clone)]
 pub struct IncludeArg {
     pub name: String,
-    pub value: Path,
+    /// A path, or a literal other than `null`
+    pub value: Operand,
     pub location: Location,
 }
 
@@ -160,7 +443,46 @@ pub enum Modifier {
     Required,
 }
 
-/// A dot-separated path (e.g., user.profile.name).
+/// A display filter of a variable output: `| name` or `| truncate 20`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Filter {
+    /// `upper`: uppercase
+    Upper,
+    /// `lower`: lowercase
+    Lower,
+    /// `trim`: strip leading and trailing spaces, tabs and newlines
+    Trim,
+    /// `truncate N`: keep the first N characters, marking a cut with `…`
+    Truncate(usize),
+    /// `comma`: group the digits of an integer by thousands
+    Comma,
+    /// `group`: like `comma`, with the separator chosen by the render options
+    Group,
+    /// `count`: the number of elements of an array or entries of an object
+    Count,
+    /// A filter named in [`ParseOptions::filters`], applied by the host
+    Custom(String),
+}
+
+impl Filter {
+    /// The filter's name in templates
+    pub fn name(&self) -> &str {
+        match self {
+            Filter::Upper => "upper",
+            Filter::Lower => "lower",
+            Filter::Trim => "trim",
+            Filter::Truncate(_) => "truncate",
+            Filter::Comma => "comma",
+            Filter::Group => "group",
+            Filter::Count => "count",
+            Filter::Custom(name) => name,
+        }
+    }
+}
+
+/// A dot-separated path (e.g., user.profile.name), whose keys that are
+/// neither identifiers nor indexes are quoted in brackets (`meta["og:title"]`).
 #[derive(Debug, Clone)]
 pub struct Path {
     segments: Vec<String>,
@@ -184,9 +506,180 @@ impl Path {
         self.location
     }
 
-    /// Returns the path as a dot-separated string.
+    /// Returns the path as written in templates, e.g. `meta["og:title"].url`.
     pub fn as_str(&self) -> String {
-        self.segments.join(".")
+        Self::join(&self.segments)
+    }
+
+    /// Write `segments` as a path, quoting the keys that need it.
+    pub fn join(segments: &[String]) -> String {
+        let mut out = String::new();
+        for (i, segment) in segments.iter().enumerate() {
+            let identifier = segment.starts_with(is_ident_start)
+                && segment.chars().all(is_ident_continue)
+                && !is_reserved_word(segment);
+            if identifier || (i > 0 && is_index(segment)) {
+                if i > 0 {
+                    out.push('.');
+                }
+                out.push_str(segment);
+            } else {
+                out.push('[');
+                out.push_str(&Literal::String(segment.clone()).to_string());
+                out.push(']');
+            }
+        }
+        out
+    }
+}
+
+/// `0` or digits without a leading zero
+fn is_index(segment: &str) -> bool {
+    segment == "0"
+        || (segment.starts_with(|c: char| matches!(c, '1'..='9'))
+            && segment.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Condition of an `if`, `elseif` or `unless` tag.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Condition {
+    /// Truthiness of a path: `{[#if user]}`
+    Path(Path),
+    /// Comparison: `{[#if status == "active"]}`, `{[#if stock > 0]}`
+    Comparison(Comparison),
+    /// `not c`
+    Not(Box<Condition>),
+    /// `a and b`; `b` is only evaluated when `a` holds
+    And(Box<Condition>, Box<Condition>),
+    /// `a or b`; `b` is only evaluated when `a` does not hold
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Condition {
+    /// Paths the condition reads, left to right.
+    pub fn paths(&self) -> Vec<&Path> {
+        match self {
+            Condition::Path(path) => vec![path],
+            Condition::Comparison(c) => match &c.right {
+                Operand::Path(right) => vec![&c.left, right],
+                Operand::Literal(_) => vec![&c.left],
+            },
+            Condition::Not(c) => c.paths(),
+            Condition::And(left, right) | Condition::Or(left, right) => {
+                let mut paths = left.paths();
+                paths.extend(right.paths());
+                paths
+            }
+        }
+    }
+}
+
+/// Formats the condition as template source.
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::Path(path) => f.write_str(&path.as_str()),
+            Condition::Comparison(c) => {
+                write!(f, "{} {} {}", c.left.as_str(), c.op.as_str(), c.right)
+            }
+            Condition::Not(c) => write!(f, "not {c}"),
+            Condition::And(left, right) => write!(f, "{left} and {right}"),
+            Condition::Or(left, right) => write!(f, "{left} or {right}"),
+        }
+    }
+}
+
+/// `left op right` in a condition.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub left: Path,
+    pub op: CompareOp,
+    pub right: Operand,
+}
+
+/// Comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+impl CompareOp {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CompareOp::Eq => "==",
+            CompareOp::Ne => "!=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        }
+    }
+
+    /// Whether the operator orders integers rather than testing equality
+    pub fn is_ordering(self) -> bool {
+        !matches!(self, CompareOp::Eq | CompareOp::Ne)
+    }
+}
+
+/// Right-hand side of a comparison.
+#[derive(Debug, Clone)]
+pub enum Operand {
+    Path(Path),
+    Literal(Literal),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Path(path) => f.write_str(&path.as_str()),
+            Operand::Literal(literal) => literal.fmt(f),
+        }
+    }
+}
+
+/// Literal value written in a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Literal {
+    /// `"text"`, with `\"` and `\\` escapes
+    String(String),
+    /// Integer in the safe range, e.g. `0` or `-1`
+    Integer(i64),
+    /// `true` or `false`
+    Bool(bool),
+    /// `null`
+    Null,
+}
+
+/// Formats the literal as template source.
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Literal::String(s) => {
+                f.write_str("\"")?;
+                for ch in s.chars() {
+                    if matches!(ch, '"' | '\\') {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{ch}")?;
+                }
+                f.write_str("\"")
+            }
+            Literal::Integer(n) => write!(f, "{n}"),
+            Literal::Bool(b) => write!(f, "{b}"),
+            Literal::Null => f.write_str("null"),
+        }
     }
 }
 
@@ -195,6 +688,7 @@ impl Path {
 // ============================================================================
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ParseError {
     #[error("template contains syntax errors at line {line}, column {column}")]
     SyntaxError {
@@ -226,6 +720,49 @@ pub enum ParseError {
 
     #[error("unclosed comment at line {line}, column {column}")]
     UnclosedComment { line: usize, column: usize },
+
+    #[error("template is {bytes} bytes, over the limit of {max}")]
+    SourceTooLarge { bytes: usize, max: usize },
+
+    #[error("template has more than {max} nodes at line {line}, column {column}")]
+    TooManyNodes {
+        max: usize,
+        line: usize,
+        column: usize,
+    },
+
+    #[error("blocks nested more than {max} deep at line {line}, column {column}")]
+    NestedTooDeep {
+        max: usize,
+        line: usize,
+        column: usize,
+    },
+}
+
+impl ParseError {
+    /// Line and column (1-indexed) the error points at.
+    pub fn line_column(&self) -> (usize, usize) {
+        match self {
+            ParseError::SyntaxError { line, column, .. }
+            | ParseError::UnexpectedToken { line, column, .. }
+            | ParseError::ReservedWord { line, column, .. }
+            | ParseError::InvalidIdentifier { line, column, .. }
+            | ParseError::UnclosedComment { line, column }
+            | ParseError::TooManyNodes { line, column, .. }
+            | ParseError::NestedTooDeep { line, column, .. } => (*line, *column),
+            ParseError::SourceTooLarge { .. } => (1, 1),
+        }
+    }
+
+    /// Whether the template broke a [`ParseLimits`] bound rather than the syntax
+    pub fn is_limit(&self) -> bool {
+        matches!(
+            self,
+            ParseError::SourceTooLarge { .. }
+                | ParseError::TooManyNodes { .. }
+                | ParseError::NestedTooDeep { .. }
+        )
+    }
 }
 
 /// Reserved words that cannot be used as identifiers.
@@ -262,11 +799,174 @@ fn validate_identifier(name: &str, location: Location) -> Result<(), ParseError>
 // Parsing (public API)
 // ============================================================================
 
+/// Options changing how a template source is parsed.
+///
+/// `trim_blocks` and `lstrip_blocks` act as if the trim markers of
+/// whitespace control were written on every block tag (`{[#...]}`,
+/// `{[/...]}`, `{[#else]}`, `{[#elseif ...]}`) and comment; variable,
+/// unsecure and include tags are unaffected.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Implicit `-]}`: remove the line break after a block tag that ends its line
+    pub trim_blocks: bool,
+    /// Implicit `{[-`: remove the indentation before a block tag that starts its line
+    pub lstrip_blocks: bool,
+    /// Tag delimiters used instead of `{[` and `]}`
+    pub delimiters: Delimiters,
+    /// Names of host-defined filters accepted besides the built-ins
+    pub filters: Vec<String>,
+    /// Bounds on the work parsing may do, for templates from untrusted users
+    pub limits: ParseLimits,
+}
+
+/// Bounds checked while parsing; `None` leaves a dimension unbounded.
+///
+/// The source size is checked before lexing, and the node count and
+/// nesting depth as nodes are parsed, so an oversized template fails
+/// without being parsed in full.
+///
+/// ```rust
+/// use natsuzora_ast::{parse_with_options, ParseError, ParseLimits, ParseOptions};
+///
+/// let options = ParseOptions {
+///     limits: ParseLimits {
+///         max_depth: Some(1),
+///         ..ParseLimits::default()
+///     },
+///     ..ParseOptions::default()
+/// };
+/// assert!(parse_with_options("{[#if a]}{[ a ]}{[/if]}", &options).is_ok());
+/// let error = parse_with_options("{[#if a]}{[#if b]}{[ b ]}{[/if]}{[/if]}", &options);
+/// assert!(matches!(error, Err(ParseError::NestedTooDeep { max: 1, .. })));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseLimits {
+    /// Largest source accepted, in bytes
+    pub max_source_bytes: Option<usize>,
+    /// Most AST nodes accepted, counting text, tags and blocks
+    pub max_nodes: Option<usize>,
+    /// Most blocks a node may be nested in
+    pub max_depth: Option<usize>,
+}
+
+/// Open and close tag delimiters.
+///
+/// With custom delimiters the delimiter escape becomes the open delimiter,
+/// `{` and the close delimiter (`<%{%>` for `<%` / `%>`), and `{[` is plain
+/// text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delimiters {
+    open: String,
+    close: String,
+}
+
+impl Delimiters {
+    /// Fails unless both delimiters are non-empty, distinct and free of
+    /// whitespace and the trim markers `-` and `~`.
+    pub fn new(
+        open: impl Into<String>,
+        close: impl Into<String>,
+    ) -> Result<Self, InvalidDelimiters> {
+        let (open, close) = (open.into(), close.into());
+        for delimiter in [&open, &close] {
+            if delimiter.is_empty()
+                || delimiter
+                    .chars()
+                    .any(|c| matches!(c, '-' | '~') || c.is_ascii_whitespace())
+            {
+                return Err(InvalidDelimiters(format!(
+                    "'{delimiter}' must be non-empty without whitespace, '-' or '~'"
+                )));
+            }
+        }
+        if open == close {
+            return Err(InvalidDelimiters(format!(
+                "open and close are both '{open}'"
+            )));
+        }
+        Ok(Self { open, close })
+    }
+
+    pub fn open(&self) -> &str {
+        &self.open
+    }
+
+    pub fn close(&self) -> &str {
+        &self.close
+    }
+}
+
+impl Default for Delimiters {
+    fn default() -> Self {
+        Self {
+            open: "{[".to_string(),
+            close: "]}".to_string(),
+        }
+    }
+}
+
+/// Error returned by [`Delimiters::new`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid delimiters: {0}")]
+pub struct InvalidDelimiters(String);
+
+/// Whether `c` can start an identifier (Unicode `XID_Start`)
+pub fn is_ident_start(c: char) -> bool {
+    unicode_ident::is_xid_start(c)
+}
+
+/// Whether `c` can follow the first character of an identifier (Unicode
+/// `XID_Continue`, which includes digits and `_`)
+pub fn is_ident_continue(c: char) -> bool {
+    unicode_ident::is_xid_continue(c)
+}
+
 /// Parse a template source string into an AST.
 pub fn parse(source: &str) -> Result<Template, ParseError> {
-    let tokens = lexer::tokenize(source)?;
-    let processed = token_processor::process(tokens)?;
-    parser::parse(processed)
+    parse_with_options(source, &ParseOptions::default())
+}
+
+/// Parse a template source string with [`ParseOptions`].
+pub fn parse_with_options(source: &str, options: &ParseOptions) -> Result<Template, ParseError> {
+    if let Some(max) = options
+        .limits
+        .max_source_bytes
+        .filter(|max| source.len() > *max)
+    {
+        return Err(ParseError::SourceTooLarge {
+            bytes: source.len(),
+            max,
+        });
+    }
+    let source: Arc<str> = Arc::from(source);
+    let tokens = lexer::tokenize_shared(&source, &options.delimiters)?;
+    let processed = token_processor::process_with_options(tokens, options)?;
+    parser::parse(processed, &options.filters, options.limits)
+        .map(|template| normalize::normalize(template).with_source(source))
+}
+
+/// Every trim whitespace control requests while parsing `source`, in source
+/// order, including those that removed nothing.
+///
+/// Meant for explaining why a line break did or did not disappear. Trim
+/// markers on include tags also trim the partial when rendering; that part
+/// is not reported here.
+pub fn trace_trims(source: &str, options: &ParseOptions) -> Result<Vec<Trim>, ParseError> {
+    let source: Arc<str> = Arc::from(source);
+    let tokens = lexer::tokenize_shared(&source, &options.delimiters)?;
+    token_processor::trace_with_options(tokens, options)
+}
+
+/// Lexer and token processor internals.
+///
+/// Only available with the `unstable` feature; these items may change in any
+/// release.
+#[cfg(feature = "unstable")]
+#[doc(hidden)]
+pub mod unstable {
+    pub use crate::lexer::tokenize;
+    pub use crate::token::{Token, TokenType};
+    pub use crate::token_processor::{process, process_with_options};
 }
 
 // ============================================================================
@@ -290,6 +990,98 @@ pub trait IncludeLoader {
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_with_custom_delimiters() {
+        let options = ParseOptions {
+            delimiters: Delimiters::new("<%", "%>").unwrap(),
+            ..ParseOptions::default()
+        };
+        let template = parse_with_options(
+            "{[ x ]}<%- name -%> <%{%><%% note %><%#raw%><% y %><%/raw%>",
+            &options,
+        )
+        .unwrap();
+        let texts: Vec<&str> = template
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                AstNode::Text(t) => Some(t.content.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(texts, ["{[ x ]}", " <%<% y %>"]);
+        assert!(matches!(template.nodes()[1], AstNode::Variable(_)));
+
+        assert!(Delimiters::new("<%", "<%").is_err());
+        assert!(Delimiters::new("{{-", "}}").is_err());
+        assert!(Delimiters::new("<%", "~%>").is_err());
+        assert!(Delimiters::new("{~", "~}").is_err());
+        assert!(Delimiters::new("", "}}").is_err());
+    }
+
+    #[test]
+    fn trace_trims_reports_cause_and_removed_text() {
+        let source = "<ul>\n  {[-#each xs as x -]}\n  <li>{[ x -]}</li>\n{[/each]}\n</ul>";
+        let trims = trace_trims(source, &ParseOptions::default()).unwrap();
+        let summary: Vec<(TrimCause, usize, &str)> = trims
+            .iter()
+            .map(|t| (t.cause, t.tag.column, t.removed.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (TrimCause::LeftMarker, 3, "  "),
+                (TrimCause::RightMarker, 3, "\n"),
+                (TrimCause::RightMarker, 7, ""),
+            ]
+        );
+        assert_eq!(&source[trims[0].range.clone().unwrap()], "  ");
+        assert_eq!(trims[0].to_string(), "2:3 {[-: removed \"  \"");
+        assert_eq!(
+            trims[2].to_string(),
+            "3:7 -]}: nothing removed: no blank run between the tag and a line break"
+        );
+
+        let options = ParseOptions {
+            trim_blocks: true,
+            lstrip_blocks: true,
+            ..ParseOptions::default()
+        };
+        let causes: Vec<TrimCause> = trace_trims("a\n  {[#if x]}\nb{[/if]}", &options)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.cause)
+            .collect();
+        assert_eq!(
+            causes,
+            [
+                TrimCause::LstripBlocks,
+                TrimCause::TrimBlocks,
+                TrimCause::LstripBlocks,
+                TrimCause::TrimBlocks
+            ]
+        );
+
+        let trims =
+            trace_trims("a \n\n {[~ x ~]}\n\tb{[ y ~]}c", &ParseOptions::default()).unwrap();
+        let summary: Vec<(TrimCause, &str)> = trims
+            .iter()
+            .map(|t| (t.cause, t.removed.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (TrimCause::LeftTilde, " \n\n "),
+                (TrimCause::RightTilde, "\n\t"),
+                (TrimCause::RightTilde, ""),
+            ]
+        );
+        assert_eq!(
+            trims[2].to_string(),
+            "4:3 ~]}: nothing removed: no whitespace directly after the tag"
+        );
+    }
+
     #[test]
     fn parse_simple_text() {
         let template = parse("Hello, World!").unwrap();
@@ -340,7 +1132,7 @@ mod tests {
         assert_eq!(template.nodes().len(), 1);
         match &template.nodes()[0] {
             AstNode::If(block) => {
-                assert_eq!(block.condition.segments(), &["show"]);
+                assert!(matches!(&block.condition, Condition::Path(p) if p.segments() == ["show"]));
                 assert_eq!(block.then_branch.len(), 1);
                 assert!(block.else_branch.is_some());
             }
@@ -348,17 +1140,166 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_unless_block_with_else() {
+        let template = parse("{[#unless hide]}yes{[#else]}no{[/unless]}").unwrap();
+        match &template.nodes()[0] {
+            AstNode::Unless(block) => {
+                assert_eq!(block.body.len(), 1);
+                assert_eq!(block.else_branch.as_ref().map(Vec::len), Some(1));
+            }
+            _ => panic!("expected unless block"),
+        }
+        assert!(parse("{[#unless a]}{[#else]}{[#else]}{[/unless]}").is_err());
+        assert!(parse("{[#unless a]}{[#elseif b]}{[/unless]}").is_err());
+    }
+
     #[test]
     fn parse_each_block() {
         let template = parse("{[#each items as item]}{[ item.name ]}{[/each]}").unwrap();
         match &template.nodes()[0] {
             AstNode::Each(block) => {
-                assert_eq!(block.collection.segments(), &["items"]);
+                assert_eq!(block.collection.as_path().unwrap().segments(), &["items"]);
                 assert_eq!(block.item_ident, "item");
+                assert_eq!(block.index_ident, None);
+                assert_eq!(block.body.len(), 1);
+                assert!(block.else_branch.is_none());
+            }
+            _ => panic!("expected each block"),
+        }
+    }
+
+    #[test]
+    fn parse_each_block_with_else() {
+        let template = parse("{[#each items as item]}{[ item ]}{[#else]}none{[/each]}").unwrap();
+        match &template.nodes()[0] {
+            AstNode::Each(block) => {
                 assert_eq!(block.body.len(), 1);
+                assert_eq!(block.else_branch.as_ref().map(Vec::len), Some(1));
+            }
+            _ => panic!("expected each block"),
+        }
+        assert!(parse("{[#each items as item]}{[#else]}{[#else]}{[/each]}").is_err());
+        assert!(parse("{[#each items as item]}{[#elseif a]}{[/each]}").is_err());
+    }
+
+    #[test]
+    fn parse_each_block_with_index() {
+        let template = parse("{[#each items as item , idx ]}{[ idx ]}{[/each]}").unwrap();
+        match &template.nodes()[0] {
+            AstNode::Each(block) => {
+                assert_eq!(block.item_ident, "item");
+                assert_eq!(block.index_ident.as_deref(), Some("idx"));
+            }
+            _ => panic!("expected each block"),
+        }
+        assert!(parse("{[#each items as item, item]}{[/each]}").is_err());
+        assert!(parse("{[#each items as item,]}{[/each]}").is_err());
+    }
+
+    #[test]
+    fn parse_each_block_in_form() {
+        let template = parse("{[#each item in site.items]}{[/each]}").unwrap();
+        match &template.nodes()[0] {
+            AstNode::Each(block) => {
+                assert_eq!(
+                    block.collection.as_path().unwrap().segments(),
+                    &["site", "items"]
+                );
+                assert_eq!(block.item_ident, "item");
+            }
+            _ => panic!("expected each block"),
+        }
+        let template = parse("{[#each item , i in items with loop]}{[/each]}").unwrap();
+        match &template.nodes()[0] {
+            AstNode::Each(block) => {
+                assert_eq!(block.collection.as_path().unwrap().segments(), &["items"]);
+                assert_eq!(block.bindings().collect::<Vec<_>>(), ["item", "i", "loop"]);
+            }
+            _ => panic!("expected each block"),
+        }
+        assert!(parse("{[#each item in]}{[/each]}").is_err());
+        assert!(parse("{[#each item, item in items]}{[/each]}").is_err());
+        assert!(parse("{[#each a.b in items]}{[/each]}").is_err());
+    }
+
+    #[test]
+    fn parse_quoted_path_keys() {
+        let source = r#"{[ meta["og:title"].items.0["a\"b"]["if"]["01"].x ]}"#;
+        let template = parse(source).unwrap();
+        let AstNode::Variable(node) = &template.nodes()[0] else {
+            panic!("expected variable");
+        };
+        assert_eq!(
+            node.path.segments(),
+            ["meta", "og:title", "items", "0", "a\"b", "if", "01", "x"]
+        );
+        assert_eq!(&source[3..source.len() - 3], node.path.as_str());
+        assert!(parse(r#"{[ meta[ "x" ] ]}"#).is_err());
+        assert!(parse(r#"{[ meta.["x"] ]}"#).is_err());
+    }
+
+    #[test]
+    fn parse_each_range() {
+        let template =
+            parse("{[#each 1..page.count as n]}{[/each]}{[#each n in -2..3]}{[/each]}").unwrap();
+        let collections: Vec<String> = template
+            .nodes()
+            .iter()
+            .map(|node| match node {
+                AstNode::Each(block) => block.collection.to_string(),
+                _ => panic!("expected each block"),
+            })
+            .collect();
+        assert_eq!(collections, ["1..page.count", "-2..3"]);
+        match &template.nodes()[0] {
+            AstNode::Each(block) => {
+                let paths: Vec<String> = block.collection.paths().map(Path::as_str).collect();
+                assert_eq!(paths, ["page.count"]);
             }
             _ => panic!("expected each block"),
         }
+        assert!(parse("{[#each 1 as n]}{[/each]}").is_err());
+        assert!(parse("{[#each 1.. as n]}{[/each]}").is_err());
+        assert!(parse("{[#each 1 .. 3 as n]}{[/each]}").is_err());
+        assert!(parse("{[#each \"a\"..3 as n]}{[/each]}").is_err());
+    }
+
+    #[test]
+    fn parse_each_block_with_window() {
+        let template =
+            parse("{[#each posts as post with loop limit=5 offset=page.start ]}{[/each]}").unwrap();
+        match &template.nodes()[0] {
+            AstNode::Each(block) => {
+                assert!(matches!(
+                    block.limit,
+                    Some(Operand::Literal(Literal::Integer(5)))
+                ));
+                assert_eq!(
+                    block.window_paths().map(Path::as_str).collect::<Vec<_>>(),
+                    ["page.start"]
+                );
+            }
+            _ => panic!("expected each block"),
+        }
+        assert!(parse("{[#each posts as post limit=-1 ]}{[/each]}").is_err());
+        assert!(parse("{[#each posts as post limit=true ]}{[/each]}").is_err());
+        assert!(parse("{[#each posts as post step=2 ]}{[/each]}").is_err());
+    }
+
+    #[test]
+    fn parse_each_block_with_meta() {
+        let template = parse("{[#each items as item, i with loop ]}{[/each]}").unwrap();
+        match &template.nodes()[0] {
+            AstNode::Each(block) => {
+                assert_eq!(block.meta_ident.as_deref(), Some("loop"));
+                assert_eq!(block.bindings().collect::<Vec<_>>(), ["item", "i", "loop"]);
+            }
+            _ => panic!("expected each block"),
+        }
+        assert!(parse("{[#each items as item with]}{[/each]}").is_err());
+        assert!(parse("{[#each items as item, i with i]}{[/each]}").is_err());
+        assert!(parse("{[#each items as with]}{[ with ]}{[/each]}").is_ok());
     }
 
     #[test]
@@ -369,12 +1310,87 @@ mod tests {
                 assert_eq!(inc.name, "/shared/header");
                 assert_eq!(inc.args.len(), 1);
                 assert_eq!(inc.args[0].name, "title");
-                assert_eq!(inc.args[0].value.segments(), &["page", "title"]);
+                assert!(
+                    matches!(&inc.args[0].value, Operand::Path(p) if p.segments() == ["page", "title"])
+                );
             }
             _ => panic!("expected include node"),
         }
     }
 
+    #[test]
+    fn text_runs_skip_tags() {
+        let template = parse(
+            "{[ a -]}\n  lead {[~ b ~]} c\n<p>Hi {[ name ]}</p>\n{[#each xs as x]}\n  {[-#if x]}Yes{[#else]}No {[ x ]}{[/if]}\n{[/each]}{[% note ]}{[{]}",
+        )
+        .unwrap();
+        let runs: Vec<(&str, usize, usize)> = template
+            .text_runs()
+            .map(|(text, loc)| (text, loc.line, loc.column))
+            .collect();
+        assert_eq!(
+            runs,
+            [
+                ("  lead", 2, 1),
+                ("c\n<p>Hi ", 2, 18),
+                ("</p>\n", 3, 17),
+                ("\n", 4, 18),
+                ("Yes", 5, 13),
+                ("No ", 5, 25),
+                ("\n", 5, 42),
+                ("{[", 6, 21),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_dynamic_include() {
+        let template = parse("{[!include block.template text=block.text ]}").unwrap();
+        let AstNode::Include(inc) = &template.nodes()[0] else {
+            panic!("expected include node");
+        };
+        assert!(inc.name.is_empty());
+        assert_eq!(inc.source.as_ref().unwrap().as_str(), "block.template");
+        assert_eq!(inc.args[0].name, "text");
+    }
+
+    #[test]
+    fn parse_limits() {
+        let limited = |limits| ParseOptions {
+            limits,
+            ..ParseOptions::default()
+        };
+        let source = "a{[#each xs as x]}{[ x ]},{[/each]}";
+        let bytes = limited(ParseLimits {
+            max_source_bytes: Some(10),
+            ..ParseLimits::default()
+        });
+        assert!(matches!(
+            parse_with_options(source, &bytes),
+            Err(ParseError::SourceTooLarge { max: 10, .. })
+        ));
+        let nodes = limited(ParseLimits {
+            max_nodes: Some(3),
+            ..ParseLimits::default()
+        });
+        let error = parse_with_options(source, &nodes).unwrap_err();
+        assert!(error.is_limit());
+        assert!(matches!(
+            error,
+            ParseError::TooManyNodes {
+                max: 3,
+                line: 1,
+                column: 26
+            }
+        ));
+        let all = limited(ParseLimits {
+            max_source_bytes: Some(source.len()),
+            max_nodes: Some(4),
+            max_depth: Some(1),
+        });
+        assert!(parse_with_options(source, &all).is_ok());
+    }
+
     #[test]
     fn parse_delimiter_escape() {
         let template = parse("literal: {[{]}").unwrap();
@@ -385,6 +1401,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_text_references_source() {
+        let template = parse("こんにちは、{[- name ]}さん").unwrap();
+        assert_eq!(template.source(), Some("こんにちは、{[- name ]}さん"));
+        match &template.nodes()[2] {
+            AstNode::Text(t) => {
+                assert_eq!(t.content, "さん");
+                assert_eq!(t.content.source_range(), Some(29..35));
+            }
+            _ => panic!("expected text node"),
+        }
+    }
+
     #[test]
     fn error_location() {
         let result = parse("{[ invalid.. ]}");
@@ -0,0 +1,192 @@
+//! Conversions between byte offsets, [`Location`]s and LSP positions.
+//!
+//! The Language Server Protocol addresses text by zero-based line and UTF-16
+//! code unit, while the AST records byte offsets and character columns.
+//! [`LineIndex`] builds the line-start table once per source so editor tools
+//! can convert every diagnostic without rescanning the text.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::Location;
+
+/// Zero-based line and UTF-16 character offset, as used by LSP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct LspPosition {
+    pub line: u32,
+    pub character: u32,
+}
+
+impl LspPosition {
+    pub fn new(line: u32, character: u32) -> Self {
+        Self { line, character }
+    }
+}
+
+/// Half-open range of LSP positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LspRange {
+    pub start: LspPosition,
+    pub end: LspPosition,
+}
+
+/// Line-start table of a template source.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    source: Arc<str>,
+    /// Byte offset of the first byte of each line
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(source: impl Into<Arc<str>>) -> Self {
+        let source = source.into();
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// LSP position of a byte offset; offsets past the end clamp to it and
+    /// offsets inside a character round down to its start.
+    pub fn position(&self, offset: usize) -> LspPosition {
+        let offset = self.floor_char_boundary(offset);
+        let line = self.line_of(offset);
+        let character = utf16_len(&self.source[self.line_starts[line]..offset]);
+        LspPosition::new(line as u32, character as u32)
+    }
+
+    /// LSP range of a byte range.
+    pub fn range(&self, range: Range<usize>) -> LspRange {
+        LspRange {
+            start: self.position(range.start),
+            end: self.position(range.end),
+        }
+    }
+
+    /// Byte offset of an LSP position, or `None` if the line does not exist.
+    ///
+    /// A character past the end of the line clamps to the line end, as LSP
+    /// prescribes.
+    pub fn offset(&self, position: LspPosition) -> Option<usize> {
+        let start = *self.line_starts.get(position.line as usize)?;
+        let line = self.line_text(position.line as usize);
+        let mut units = 0;
+        for (i, c) in line.char_indices() {
+            if units >= position.character as usize {
+                return Some(start + i);
+            }
+            units += c.len_utf16();
+        }
+        Some(start + line.len())
+    }
+
+    /// AST location (1-indexed line and character column) of a byte offset.
+    pub fn location(&self, offset: usize) -> Location {
+        let offset = self.floor_char_boundary(offset);
+        let line = self.line_of(offset);
+        let column = self.source[self.line_starts[line]..offset].chars().count() + 1;
+        Location::new(line + 1, column, offset)
+    }
+
+    /// Index of the line containing `offset`.
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    /// Text of line `line` without its line terminator.
+    fn line_text(&self, line: usize) -> &str {
+        let start = self.line_starts[line];
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.source.len(), |&next| next - 1);
+        let text = &self.source[start..end];
+        text.strip_suffix('\r').unwrap_or(text)
+    }
+
+    fn floor_char_boundary(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+}
+
+impl Location {
+    /// LSP position of this location in `source`.
+    ///
+    /// Scans back to the start of the line; use a [`LineIndex`] when
+    /// converting many locations in the same source.
+    pub fn to_lsp_position(&self, source: &str) -> LspPosition {
+        let mut offset = self.byte_offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        LspPosition::new(
+            self.line.saturating_sub(1) as u32,
+            utf16_len(&source[line_start..offset]) as u32,
+        )
+    }
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, AstNode};
+
+    #[test]
+    fn utf16_positions_round_trip() {
+        // "😀" is two UTF-16 code units and four bytes
+        let index = LineIndex::new("a\r\n😀é{[ x ]}\nend");
+        assert_eq!(index.line_count(), 3);
+
+        let x = "a\r\n😀é{[ ".len();
+        assert_eq!(index.position(x), LspPosition::new(1, 6));
+        assert_eq!(index.offset(LspPosition::new(1, 6)), Some(x));
+        assert_eq!(index.location(x), Location::new(2, 6, x));
+
+        assert_eq!(index.position(4), LspPosition::new(1, 0));
+        assert_eq!(index.offset(LspPosition::new(0, 9)), Some(1));
+        assert_eq!(index.offset(LspPosition::new(3, 0)), None);
+        assert_eq!(index.position(1000), LspPosition::new(2, 3));
+    }
+
+    #[test]
+    fn location_matches_line_index() {
+        let source = "こんにちは\n{[ name ]}さん{[ title ]}";
+        let template = parse(source).unwrap();
+        let index = LineIndex::new(source);
+        for node in template.nodes() {
+            if let AstNode::Variable(_) = node {
+                let location = node.location();
+                assert_eq!(
+                    location.to_lsp_position(source),
+                    index.position(location.byte_offset)
+                );
+            }
+        }
+        let title = &template.nodes()[3];
+        assert_eq!(
+            title.location().to_lsp_position(source),
+            LspPosition::new(1, 15)
+        );
+    }
+}
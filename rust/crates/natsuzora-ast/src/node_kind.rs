@@ -0,0 +1,205 @@
+//! Registry of node kinds shared with the tree-sitter grammar.
+//!
+//! Editor tooling works on the tree-sitter tree while diagnostics and
+//! refactorings come from the AST. [`GRAMMAR_KINDS`] records, for every named
+//! node of `tree-sitter/grammar.js`, which [`AstNode`](crate::AstNode) kind it
+//! becomes, so a tool can map between the two trees. The tests fail when the
+//! grammar gains a node the registry does not classify.
+
+/// Every kind returned by [`AstNode::kind`](crate::AstNode::kind)
+pub const AST_NODE_KINDS: [&str; 13] = [
+    "text",
+    "variable",
+    "unsecure",
+    "if",
+    "unless",
+    "each",
+    "cache",
+    "include",
+    "block",
+    "extends",
+    "yield",
+    "translate",
+    "attr",
+];
+
+/// How a named tree-sitter node relates to the AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GrammarKind {
+    /// Becomes an AST node of the given kind
+    Node(&'static str),
+    /// Part of an enclosing node, such as a tag delimiter or an argument
+    Part,
+    /// Has no AST counterpart: comments, and constructs only the editor
+    /// grammar accepts
+    Ignored,
+}
+
+/// Named node kinds of the tree-sitter grammar, ordered as in `grammar.js`
+pub const GRAMMAR_KINDS: &[(&str, GrammarKind)] = &[
+    ("template", GrammarKind::Part),
+    ("text", GrammarKind::Node("text")),
+    ("variable", GrammarKind::Node("variable")),
+    ("modifier", GrammarKind::Part),
+    ("filter", GrammarKind::Part),
+    ("if_block", GrammarKind::Node("if")),
+    ("if_open", GrammarKind::Part),
+    ("if_close", GrammarKind::Part),
+    ("else_clause", GrammarKind::Part),
+    ("else_open", GrammarKind::Part),
+    ("unless_block", GrammarKind::Node("unless")),
+    ("unless_open", GrammarKind::Part),
+    ("unless_close", GrammarKind::Part),
+    ("each_block", GrammarKind::Node("each")),
+    ("each_open", GrammarKind::Part),
+    ("each_range", GrammarKind::Part),
+    ("each_index", GrammarKind::Part),
+    ("each_meta", GrammarKind::Part),
+    ("each_window", GrammarKind::Part),
+    ("each_close", GrammarKind::Part),
+    ("unsecure_block", GrammarKind::Ignored),
+    ("unsecure_open", GrammarKind::Ignored),
+    ("unsecure_close", GrammarKind::Ignored),
+    ("raw_block", GrammarKind::Node("text")),
+    ("raw_open", GrammarKind::Part),
+    ("raw_content", GrammarKind::Part),
+    ("raw_close", GrammarKind::Part),
+    ("named_block", GrammarKind::Node("block")),
+    ("block_open", GrammarKind::Part),
+    ("block_close", GrammarKind::Part),
+    ("include_block", GrammarKind::Node("include")),
+    ("include_open", GrammarKind::Part),
+    ("include_close", GrammarKind::Part),
+    ("unsecure_output", GrammarKind::Node("unsecure")),
+    ("include", GrammarKind::Node("include")),
+    ("extends", GrammarKind::Node("extends")),
+    ("yield", GrammarKind::Node("yield")),
+    ("translate", GrammarKind::Node("translate")),
+    ("attr", GrammarKind::Node("attr")),
+    ("include_args", GrammarKind::Part),
+    ("include_call_args", GrammarKind::Part),
+    ("spread_arg", GrammarKind::Part),
+    ("include_arg", GrammarKind::Part),
+    ("not_condition", GrammarKind::Part),
+    ("logical_condition", GrammarKind::Part),
+    ("comparison", GrammarKind::Part),
+    ("comparison_operator", GrammarKind::Part),
+    ("string", GrammarKind::Part),
+    ("integer", GrammarKind::Part),
+    ("boolean", GrammarKind::Part),
+    ("null", GrammarKind::Part),
+    ("delimiter_escape", GrammarKind::Node("text")),
+    ("tag_open", GrammarKind::Part),
+    ("tag_close", GrammarKind::Part),
+    ("path", GrammarKind::Part),
+    ("index", GrammarKind::Part),
+    ("identifier", GrammarKind::Part),
+    ("include_name", GrammarKind::Part),
+    ("params", GrammarKind::Part),
+    ("params_open", GrammarKind::Part),
+    ("param", GrammarKind::Part),
+    ("comment", GrammarKind::Ignored),
+];
+
+/// Classification of the named tree-sitter node `name`, if it is known
+pub fn grammar_kind(name: &str) -> Option<GrammarKind> {
+    GRAMMAR_KINDS
+        .iter()
+        .find(|(kind, _)| *kind == name)
+        .map(|(_, kind)| *kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    const GRAMMAR: &str = include_str!("../../../../tree-sitter/grammar.js");
+    const NODE_TYPES: &str = include_str!("../../../../tree-sitter/src/node-types.json");
+
+    /// Visible rule names: `    name: $ => ...` lines not starting with `_`
+    fn grammar_rules() -> Vec<&'static str> {
+        GRAMMAR
+            .lines()
+            .filter_map(|line| line.strip_prefix("    "))
+            .filter_map(|line| line.split_once(": "))
+            .map(|(name, _)| name)
+            .filter(|name| {
+                name.starts_with(|c: char| c.is_ascii_lowercase())
+                    && name.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_grammar_rules_are_classified() {
+        let rules = grammar_rules();
+        assert!(rules.contains(&"template"));
+        for rule in &rules {
+            assert!(
+                grammar_kind(rule).is_some(),
+                "grammar rule '{}' is missing from GRAMMAR_KINDS",
+                rule
+            );
+        }
+        for (kind, _) in GRAMMAR_KINDS {
+            assert!(rules.contains(kind), "'{}' is not a grammar rule", kind);
+        }
+    }
+
+    /// Named node types at the top level of `node-types.json`
+    fn node_types() -> BTreeSet<&'static str> {
+        let mut types = BTreeSet::new();
+        let mut lines = NODE_TYPES.lines();
+        while let Some(line) = lines.next() {
+            // Top-level entries are indented by four spaces, nested ones deeper
+            let Some(name) = line
+                .strip_prefix("    \"type\": \"")
+                .and_then(|rest| rest.strip_suffix("\","))
+            else {
+                continue;
+            };
+            if lines
+                .next()
+                .is_some_and(|next| next.trim().trim_end_matches(',') == "\"named\": true")
+            {
+                types.insert(name);
+            }
+        }
+        types
+    }
+
+    /// Fails when `src/node-types.json` was not regenerated after a grammar
+    /// change, since its node types then differ from the rules of grammar.js
+    #[test]
+    fn test_node_types_are_classified() {
+        let types = node_types();
+        let rules: BTreeSet<&str> = grammar_rules().into_iter().collect();
+        assert_eq!(
+            types, rules,
+            "src/node-types.json is out of date; run tree-sitter generate"
+        );
+        for name in types {
+            assert!(
+                grammar_kind(name).is_some(),
+                "node type '{}' is missing from GRAMMAR_KINDS",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_registry_targets_ast_kinds() {
+        let names: BTreeSet<&str> = GRAMMAR_KINDS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names.len(), GRAMMAR_KINDS.len());
+        for (name, kind) in GRAMMAR_KINDS {
+            if let GrammarKind::Node(ast) = kind {
+                assert!(AST_NODE_KINDS.contains(ast), "{} -> {}", name, ast);
+            }
+        }
+        let ast = crate::parse("{[#if a]}{[ b ]}{[/if]}").unwrap();
+        let kinds: Vec<&str> = ast.nodes().iter().map(|node| node.kind()).collect();
+        assert_eq!(kinds, vec!["if"]);
+    }
+}
@@ -0,0 +1,99 @@
+//! AST normalization applied after parsing.
+//!
+//! Comments, trimmed-away whitespace and `{[{]}` escapes can leave several
+//! text nodes side by side; they are merged into one so renderers and tools
+//! walk shorter node lists. An empty `{[#raw]}{[/raw]}` leaves an empty text
+//! node, which is dropped.
+
+use crate::{AstNode, Template};
+
+/// Merge adjacent text nodes throughout the template.
+pub(crate) fn normalize(mut template: Template) -> Template {
+    template.nodes = merge_text(std::mem::take(&mut template.nodes));
+    template
+}
+
+/// Merge runs of text nodes, keeping the location of the first one, and drop
+/// empty ones.
+fn merge_text(nodes: Vec<AstNode>) -> Vec<AstNode> {
+    let mut merged: Vec<AstNode> = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        let node = match node {
+            AstNode::Text(text) if text.content.is_empty() => continue,
+            AstNode::Text(text) => {
+                if let Some(AstNode::Text(previous)) = merged.last_mut() {
+                    previous.content = previous.content.concat(&text.content);
+                    continue;
+                }
+                AstNode::Text(text)
+            }
+            AstNode::If(mut n) => {
+                n.then_branch = merge_text(n.then_branch);
+                for branch in &mut n.elseif_branches {
+                    branch.body = merge_text(std::mem::take(&mut branch.body));
+                }
+                n.else_branch = n.else_branch.map(merge_text);
+                AstNode::If(n)
+            }
+            AstNode::Unless(mut n) => {
+                n.body = merge_text(n.body);
+                n.else_branch = n.else_branch.map(merge_text);
+                AstNode::Unless(n)
+            }
+            AstNode::Each(mut n) => {
+                n.body = merge_text(n.body);
+                n.else_branch = n.else_branch.map(merge_text);
+                AstNode::Each(n)
+            }
+            AstNode::Cache(mut n) => {
+                n.body = merge_text(n.body);
+                AstNode::Cache(n)
+            }
+            AstNode::Block(mut n) => {
+                n.body = merge_text(n.body);
+                AstNode::Block(n)
+            }
+            AstNode::Include(mut n) => {
+                n.body = n.body.map(merge_text);
+                AstNode::Include(n)
+            }
+            node @ (AstNode::Variable(_)
+            | AstNode::Unsecure(_)
+            | AstNode::Extends(_)
+            | AstNode::Yield(_)
+            | AstNode::Translate(_)
+            | AstNode::Attr(_)) => node,
+        };
+        merged.push(node);
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, AstNode, Location};
+
+    fn texts(nodes: &[AstNode]) -> Vec<(&str, Location)> {
+        nodes
+            .iter()
+            .filter_map(|node| match node {
+                AstNode::Text(t) => Some((t.content.as_str(), t.location)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn merges_text_around_comments() {
+        let template = parse("a{[% note ]}b\n{[#if x]}c{[{]}d{[% e ]}f{[/if]}").unwrap();
+        assert_eq!(template.nodes().len(), 2);
+        assert_eq!(texts(template.nodes()), [("ab\n", Location::new(1, 1, 0))]);
+        let AstNode::If(block) = &template.nodes()[1] else {
+            panic!("expected if block");
+        };
+        assert_eq!(
+            texts(&block.then_branch),
+            [("c{[df", Location::new(2, 10, 23))]
+        );
+    }
+}
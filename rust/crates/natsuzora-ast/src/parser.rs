@@ -3,51 +3,135 @@
 //! Mirrors Ruby's `Parser` class. Consumes a processed token stream
 //! (after TokenProcessor) and produces an AST.
 
+use std::ops::Range;
+
+use crate::lexer::{PARAMS_KEYWORD, RAW_KEYWORD};
 use crate::token::{Token, TokenType};
 use crate::{
-    validate_identifier, AstNode, EachBlock, IfBlock, IncludeArg, IncludeNode, Location, Modifier,
-    ParseError, Path, Template, TextNode, UnlessBlock, UnsecureNode, VariableNode,
+    validate_identifier, AstNode, AttrNode, BlockNode, CacheBlock, Collection, CompareOp,
+    Comparison, Condition, EachBlock, ElseIfBranch, ExtendsNode, Filter, IfBlock, IncludeArg,
+    IncludeNode, Literal, Location, Modifier, Operand, Param, ParseError, ParseLimits, Path,
+    SourceText, Template, TextNode, TranslateNode, UnlessBlock, UnsecureNode, VariableNode,
+    YieldNode,
 };
 
-/// Parse a processed token stream into an AST Template.
-pub fn parse(tokens: Vec<Token>) -> Result<Template, ParseError> {
-    let mut parser = Parser::new(tokens);
+/// Block keyword recognized only after `#` and `/`, so it stays usable as an
+/// identifier elsewhere.
+const CACHE_KEYWORD: &str = "cache";
+
+/// Layout region keyword, contextual like [`CACHE_KEYWORD`].
+const BLOCK_KEYWORD: &str = "block";
+
+/// Block form of `!include`, opened with `#` and closed with `/`.
+const INCLUDE_KEYWORD: &str = "include";
+
+/// Clause keyword recognized only after `#` inside an if block.
+const ELSEIF_KEYWORD: &str = "elseif";
+
+/// Largest integer literal magnitude (JavaScript safe integers, as for data).
+const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+
+/// Parse a processed token stream into an AST Template, accepting the
+/// host-defined `filters` besides the built-ins, within `limits`.
+pub fn parse(
+    tokens: Vec<Token>,
+    filters: &[String],
+    limits: ParseLimits,
+) -> Result<Template, ParseError> {
+    let mut parser = Parser::new(tokens, filters, limits);
     parser.parse()
 }
 
-struct Parser {
+struct Parser<'a> {
     tokens: Vec<Token>,
     pos: usize,
+    custom_filters: &'a [String],
+    /// The tag being parsed opened with `{[-`; only include tags keep the marker
+    trim_left: bool,
+    limits: ParseLimits,
+    /// Nodes parsed so far
+    nodes: usize,
+    /// Blocks enclosing the node being parsed
+    depth: usize,
 }
 
-impl Parser {
-    fn new(tokens: Vec<Token>) -> Self {
-        Self { tokens, pos: 0 }
+impl<'a> Parser<'a> {
+    fn new(tokens: Vec<Token>, custom_filters: &'a [String], limits: ParseLimits) -> Self {
+        Self {
+            tokens,
+            pos: 0,
+            custom_filters,
+            trim_left: false,
+            limits,
+            nodes: 0,
+            depth: 0,
+        }
     }
 
     fn parse(&mut self) -> Result<Template, ParseError> {
-        let nodes = self.parse_nodes()?;
-        Ok(Template::new(nodes, Location::new(1, 1, 0)))
-    }
-
-    fn parse_nodes(&mut self) -> Result<Vec<AstNode>, ParseError> {
         let mut nodes = Vec::new();
+        let mut params = None;
         while self.current_type() != TokenType::Eof {
+            if self.current_type() == TokenType::Percent {
+                let location = self.current_location();
+                let declared = self.parse_params()?;
+                if params.replace(declared).is_some() {
+                    return Err(ParseError::UnexpectedToken {
+                        message: "Duplicate params header".to_string(),
+                        line: location.line,
+                        column: location.column,
+                    });
+                }
+                continue;
+            }
             nodes.push(self.parse_node()?);
         }
-        Ok(nodes)
+        validate_layout(&nodes)?;
+        let template = Template::new(nodes, Location::new(1, 1, 0));
+        Ok(match params {
+            Some(params) => template.with_params(params),
+            None => template,
+        })
     }
 
     fn parse_node(&mut self) -> Result<AstNode, ParseError> {
+        let location = self.current_location();
+        self.nodes += 1;
+        if let Some(max) = self.limits.max_nodes.filter(|max| self.nodes > *max) {
+            return Err(ParseError::TooManyNodes {
+                max,
+                line: location.line,
+                column: location.column,
+            });
+        }
+        if let Some(max) = self.limits.max_depth.filter(|max| self.depth > *max) {
+            return Err(ParseError::NestedTooDeep {
+                max,
+                line: location.line,
+                column: location.column,
+            });
+        }
+        self.depth += 1;
+        let node = self.parse_node_kind();
+        self.depth -= 1;
+        node
+    }
+
+    fn parse_node_kind(&mut self) -> Result<AstNode, ParseError> {
         match self.current_type() {
             TokenType::Text => {
                 let node = self.parse_text()?;
                 Ok(node)
             }
             TokenType::Hash
+            | TokenType::Dash
             | TokenType::Slash
             | TokenType::BangUnsecure
             | TokenType::BangInclude
+            | TokenType::BangExtends
+            | TokenType::BangYield
+            | TokenType::AtT
+            | TokenType::AtAttr
             | TokenType::Ident
             | TokenType::KwIf
             | TokenType::KwUnless
@@ -58,8 +142,11 @@ impl Parser {
             | TokenType::Question
             | TokenType::Exclamation
             | TokenType::Dot
+            | TokenType::Ellipsis
+            | TokenType::DotDot
             | TokenType::Equal
-            | TokenType::Comma => self.parse_tag_content(),
+            | TokenType::Comma
+            | TokenType::Percent => self.parse_tag_content(),
             _ => self.unexpected_token(None),
         }
     }
@@ -73,6 +160,7 @@ impl Parser {
     }
 
     fn parse_tag_content(&mut self) -> Result<AstNode, ParseError> {
+        self.trim_left = self.consume_trim_marker();
         if self.current_type() == TokenType::Whitespace {
             self.check_no_whitespace_before_special()?;
         }
@@ -84,6 +172,13 @@ impl Parser {
             TokenType::Slash => self.unexpected_token(Some("Unexpected block close")),
             TokenType::BangUnsecure => self.parse_unsecure_output(),
             TokenType::BangInclude => self.parse_include(),
+            TokenType::BangExtends => self.parse_extends(),
+            TokenType::BangYield => self.parse_yield(),
+            TokenType::AtT => self.parse_translate(),
+            TokenType::AtAttr => self.parse_attr(),
+            TokenType::Percent => self.unexpected_token(Some(
+                "params header must be at the top level of the template",
+            )),
             _ => self.parse_variable_node(),
         }
     }
@@ -94,7 +189,14 @@ impl Parser {
         self.skip_whitespace();
         let special = matches!(
             self.current_type(),
-            TokenType::Hash | TokenType::Slash | TokenType::BangUnsecure | TokenType::BangInclude
+            TokenType::Hash
+                | TokenType::Slash
+                | TokenType::BangUnsecure
+                | TokenType::BangInclude
+                | TokenType::BangExtends
+                | TokenType::BangYield
+                | TokenType::AtT
+                | TokenType::AtAttr
         );
         self.pos = saved_pos;
         if special {
@@ -130,7 +232,14 @@ impl Parser {
             TokenType::KwIf => self.parse_if_block(),
             TokenType::KwUnless => self.parse_unless_block(),
             TokenType::KwEach => self.parse_each_block(),
+            TokenType::Ident if self.is_cache_keyword() => self.parse_cache_block(),
+            TokenType::Ident if self.is_block_keyword() => self.parse_named_block(),
+            TokenType::Ident if self.is_include_keyword() => self.parse_include_block(),
+            TokenType::Ident if self.is_raw_keyword() => self.parse_raw_block(),
             TokenType::KwElse => self.unexpected_token(Some("Unexpected 'else' without 'if'")),
+            TokenType::Ident if self.is_elseif_keyword() => {
+                self.unexpected_token(Some("Unexpected 'elseif' without 'if'"))
+            }
             _ => self.unexpected_token(None),
         }
     }
@@ -140,16 +249,24 @@ impl Parser {
         let location = kw_token.location;
 
         self.consume_required_whitespace()?;
-        let condition = self.parse_path()?;
+        let condition = self.parse_condition()?;
         self.skip_whitespace();
         self.consume(TokenType::Close)?;
 
         let then_nodes = self.parse_if_body()?;
+        let mut elseif_branches = Vec::new();
         let mut else_nodes = None;
 
+        while self.is_elseif_open() {
+            elseif_branches.push(self.parse_elseif_branch()?);
+        }
+
         if self.is_else_open() {
             self.consume_else()?;
             else_nodes = Some(self.parse_if_body()?);
+            if self.is_elseif_open() {
+                return self.unexpected_token(Some("'elseif' must come before 'else'"));
+            }
         }
 
         self.consume_block_close(TokenType::KwIf)?;
@@ -157,14 +274,37 @@ impl Parser {
         Ok(AstNode::If(IfBlock {
             condition,
             then_branch: then_nodes,
+            elseif_branches,
             else_branch: else_nodes,
             location,
         }))
     }
 
+    fn parse_elseif_branch(&mut self) -> Result<ElseIfBranch, ParseError> {
+        self.consume(TokenType::Hash)?;
+        self.skip_whitespace();
+        let kw_token = self.consume(TokenType::Ident)?;
+        let location = kw_token.location;
+
+        self.consume_required_whitespace()?;
+        let condition = self.parse_condition()?;
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+
+        let body = self.parse_if_body()?;
+        Ok(ElseIfBranch {
+            condition,
+            body,
+            location,
+        })
+    }
+
     fn parse_if_body(&mut self) -> Result<Vec<AstNode>, ParseError> {
         let mut nodes = Vec::new();
-        while !self.is_block_close(Some(TokenType::KwIf)) && !self.is_else_open() {
+        while !self.is_block_close(Some(TokenType::KwIf))
+            && !self.is_else_open()
+            && !self.is_elseif_open()
+        {
             nodes.push(self.parse_node()?);
         }
         Ok(nodes)
@@ -175,23 +315,30 @@ impl Parser {
         let location = kw_token.location;
 
         self.consume_required_whitespace()?;
-        let condition = self.parse_path()?;
+        let condition = self.parse_condition()?;
         self.skip_whitespace();
         self.consume(TokenType::Close)?;
 
         let body = self.parse_unless_body()?;
+        let else_branch = if self.is_else_open() {
+            self.consume_else()?;
+            Some(self.parse_unless_body()?)
+        } else {
+            None
+        };
         self.consume_block_close(TokenType::KwUnless)?;
 
         Ok(AstNode::Unless(UnlessBlock {
             condition,
             body,
+            else_branch,
             location,
         }))
     }
 
     fn parse_unless_body(&mut self) -> Result<Vec<AstNode>, ParseError> {
         let mut nodes = Vec::new();
-        while !self.is_block_close(Some(TokenType::KwUnless)) {
+        while !self.is_block_close(Some(TokenType::KwUnless)) && !self.is_else_open() {
             nodes.push(self.parse_node()?);
         }
         Ok(nodes)
@@ -202,34 +349,223 @@ impl Parser {
         let location = kw_token.location;
 
         self.consume_required_whitespace()?;
-        let collection = self.parse_path()?;
-        self.consume_required_whitespace()?;
-        self.consume(TokenType::KwAs)?;
-        self.consume_required_whitespace()?;
+        let in_form = self.is_each_in_form();
+        let mut collection = None;
+        if !in_form {
+            collection = Some(self.parse_each_collection()?);
+            self.consume_required_whitespace()?;
+            self.consume(TokenType::KwAs)?;
+            self.consume_required_whitespace()?;
+        }
         let item_name = self.parse_identifier_with_validation()?;
+        let mut names = vec![item_name.clone()];
 
+        let saved_pos = self.pos;
+        self.skip_whitespace();
+        let index_name = if self.current_type() == TokenType::Comma {
+            self.advance();
+            self.skip_whitespace();
+            Some(self.parse_loop_variable(&mut names, "Index")?)
+        } else {
+            self.pos = saved_pos;
+            None
+        };
+        let collection = match collection {
+            Some(collection) => collection,
+            None => {
+                self.consume_required_whitespace()?;
+                self.advance(); // in
+                self.consume_required_whitespace()?;
+                self.parse_each_collection()?
+            }
+        };
+        let meta_name = if self.consume_spaced_word("with") {
+            Some(self.parse_loop_variable(&mut names, "Loop metadata")?)
+        } else {
+            None
+        };
+        let (limit, offset) = self.parse_each_window()?;
         self.skip_whitespace();
         self.consume(TokenType::Close)?;
 
         let body = self.parse_each_body()?;
+        let else_branch = if self.is_else_open() {
+            self.consume_else()?;
+            Some(self.parse_each_body()?)
+        } else {
+            None
+        };
         self.consume_block_close(TokenType::KwEach)?;
 
         Ok(AstNode::Each(EachBlock {
             collection,
             item_ident: item_name,
+            index_ident: index_name,
+            meta_ident: meta_name,
+            limit,
+            offset,
             body,
+            else_branch,
             location,
         }))
     }
 
+    /// Parse a collection path or an integer range `start..end`
+    fn parse_each_collection(&mut self) -> Result<Collection, ParseError> {
+        let start = match self.current_type() {
+            TokenType::Integer => Operand::Literal(self.parse_literal()?),
+            _ => {
+                let path = self.parse_path()?;
+                if self.current_type() != TokenType::DotDot {
+                    return Ok(Collection::Path(path));
+                }
+                Operand::Path(path)
+            }
+        };
+        self.consume(TokenType::DotDot)?;
+        let end = match self.current_type() {
+            TokenType::Integer => Operand::Literal(self.parse_literal()?),
+            _ => Operand::Path(self.parse_path()?),
+        };
+        Ok(Collection::Range { start, end })
+    }
+
+    /// Parse the optional `limit=` and `offset=` of an each tag
+    fn parse_each_window(&mut self) -> Result<(Option<Operand>, Option<Operand>), ParseError> {
+        let (mut limit, mut offset) = (None, None);
+        for arg in self.parse_include_args()? {
+            let slot = match arg.name.as_str() {
+                "limit" => &mut limit,
+                "offset" => &mut offset,
+                name => {
+                    return Err(ParseError::UnexpectedToken {
+                        message: format!("Unknown each option: {name}"),
+                        line: arg.location.line,
+                        column: arg.location.column,
+                    })
+                }
+            };
+            if let Operand::Literal(literal) = &arg.value {
+                if !matches!(literal, Literal::Integer(n) if *n >= 0) {
+                    return Err(ParseError::UnexpectedToken {
+                        message: format!("each {} must be a non-negative integer", arg.name),
+                        line: arg.location.line,
+                        column: arg.location.column,
+                    });
+                }
+            }
+            *slot = Some(arg.value);
+        }
+        Ok((limit, offset))
+    }
+
+    /// Parse an extra `each` variable, which must differ from the earlier ones
+    fn parse_loop_variable(
+        &mut self,
+        names: &mut Vec<String>,
+        role: &str,
+    ) -> Result<String, ParseError> {
+        let loc = self.current_location();
+        let name = self.parse_identifier_with_validation()?;
+        if names.contains(&name) {
+            return Err(ParseError::UnexpectedToken {
+                message: format!("{role} variable '{name}' is already bound by this each"),
+                line: loc.line,
+                column: loc.column,
+            });
+        }
+        names.push(name.clone());
+        Ok(name)
+    }
+
     fn parse_each_body(&mut self) -> Result<Vec<AstNode>, ParseError> {
         let mut nodes = Vec::new();
-        while !self.is_block_close(Some(TokenType::KwEach)) {
+        while !self.is_block_close(Some(TokenType::KwEach)) && !self.is_else_open() {
             nodes.push(self.parse_node()?);
         }
         Ok(nodes)
     }
 
+    fn parse_cache_block(&mut self) -> Result<AstNode, ParseError> {
+        let start = self.pos;
+        let kw_token = self.consume(TokenType::Ident)?;
+        let location = kw_token.location;
+
+        self.consume_required_whitespace()?;
+        if !matches!(self.current_token(), Some(t) if t.token_type == TokenType::Ident && t.value == "key")
+        {
+            return self.unexpected_token(Some("Expected 'key=' in cache block"));
+        }
+        self.advance();
+        self.skip_whitespace();
+        self.consume(TokenType::Equal)?;
+        self.skip_whitespace();
+        let key = self.parse_path()?;
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+
+        let mut body = Vec::new();
+        while !self.is_ident_block_close(CACHE_KEYWORD) {
+            body.push(self.parse_node()?);
+        }
+        let fingerprint = self.fingerprint(start..self.pos);
+        self.consume_ident_block_close(CACHE_KEYWORD)?;
+
+        Ok(AstNode::Cache(CacheBlock {
+            key,
+            body,
+            fingerprint,
+            location,
+        }))
+    }
+
+    fn parse_named_block(&mut self) -> Result<AstNode, ParseError> {
+        let kw_token = self.consume(TokenType::Ident)?;
+        let location = kw_token.location;
+
+        self.consume_required_whitespace()?;
+        let name = self.parse_identifier_with_validation()?;
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+
+        let mut body = Vec::new();
+        while !self.is_ident_block_close(BLOCK_KEYWORD) {
+            body.push(self.parse_node()?);
+        }
+        self.consume_ident_block_close(BLOCK_KEYWORD)?;
+
+        Ok(AstNode::Block(BlockNode {
+            name,
+            body,
+            location,
+        }))
+    }
+
+    /// The lexer has already cut the body into a single text token.
+    fn parse_raw_block(&mut self) -> Result<AstNode, ParseError> {
+        let kw_token = self.consume(TokenType::Ident)?;
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+
+        let (content, location) = if self.current_type() == TokenType::Text {
+            let token = self.consume(TokenType::Text)?;
+            (token.value, token.location)
+        } else {
+            (SourceText::from(""), kw_token.location)
+        };
+
+        self.consume(TokenType::Slash)?;
+        self.skip_whitespace();
+        if !self.is_raw_keyword() {
+            return self.unexpected_token(Some("Expected 'raw'"));
+        }
+        self.advance();
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+
+        Ok(AstNode::Text(TextNode { content, location }))
+    }
+
     fn parse_unsecure_output(&mut self) -> Result<AstNode, ParseError> {
         let token = self.consume(TokenType::BangUnsecure)?;
         let location = token.location;
@@ -246,19 +582,186 @@ impl Parser {
         let token = self.consume(TokenType::BangInclude)?;
         let location = token.location;
 
+        let trim_left = std::mem::take(&mut self.trim_left);
         self.consume_required_whitespace()?;
-        let name = self.parse_include_name()?;
-        let args = self.parse_include_args()?;
+        let (name, source) = self.parse_include_target()?;
+        let (args, spread) = self.parse_include_args_with_spread()?;
         self.skip_whitespace();
+        let trim_right = self.consume_trim_marker();
         self.consume(TokenType::Close)?;
 
         Ok(AstNode::Include(IncludeNode {
             name,
+            source,
+            args,
+            spread,
+            body: None,
+            trim_left,
+            trim_right,
+            location,
+        }))
+    }
+
+    fn parse_include_block(&mut self) -> Result<AstNode, ParseError> {
+        let kw_token = self.consume(TokenType::Ident)?;
+        let location = kw_token.location;
+
+        let trim_left = std::mem::take(&mut self.trim_left);
+        self.consume_required_whitespace()?;
+        let (name, source) = self.parse_include_target()?;
+        let (args, spread) = self.parse_include_args_with_spread()?;
+        self.skip_whitespace();
+        let trim_right = self.consume_trim_marker();
+        self.consume(TokenType::Close)?;
+
+        let mut body = Vec::new();
+        while !self.is_ident_block_close(INCLUDE_KEYWORD) {
+            body.push(self.parse_node()?);
+        }
+        self.consume_ident_block_close(INCLUDE_KEYWORD)?;
+
+        Ok(AstNode::Include(IncludeNode {
+            name,
+            source,
+            args,
+            spread,
+            body: Some(body),
+            trim_left,
+            trim_right,
+            location,
+        }))
+    }
+
+    /// `{[%params title, body, actions? ]}`: names the template expects when
+    /// included, `?` marking optional ones
+    fn parse_params(&mut self) -> Result<Vec<Param>, ParseError> {
+        self.consume(TokenType::Percent)?;
+        if !self.is_ident_at(self.pos, PARAMS_KEYWORD) {
+            return self.unexpected_token(Some("Expected 'params'"));
+        }
+        self.advance();
+
+        let mut params: Vec<Param> = Vec::new();
+        if self.current_type() == TokenType::Whitespace {
+            self.skip_whitespace();
+        }
+        while self.current_type() == TokenType::Ident {
+            let token = self.consume(TokenType::Ident)?;
+            validate_identifier(&token.value, token.location)?;
+            if params
+                .iter()
+                .any(|param| param.name == token.value.as_str())
+            {
+                return Err(ParseError::UnexpectedToken {
+                    message: format!("Duplicate parameter: {}", token.value),
+                    line: token.location.line,
+                    column: token.location.column,
+                });
+            }
+            let optional = self.current_type() == TokenType::Question;
+            if optional {
+                self.advance();
+            }
+            params.push(Param {
+                name: token.value.to_string(),
+                optional,
+                location: token.location,
+            });
+
+            self.skip_whitespace();
+            if self.current_type() != TokenType::Comma {
+                break;
+            }
+            self.advance();
+            self.skip_whitespace();
+            if self.current_type() != TokenType::Ident {
+                return self.unexpected_token(Some("Expected parameter name"));
+            }
+        }
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+        Ok(params)
+    }
+
+    fn parse_yield(&mut self) -> Result<AstNode, ParseError> {
+        let token = self.consume(TokenType::BangYield)?;
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+        Ok(AstNode::Yield(YieldNode {
+            location: token.location,
+        }))
+    }
+
+    fn parse_translate(&mut self) -> Result<AstNode, ParseError> {
+        let token = self.consume(TokenType::AtT)?;
+        let location = token.location;
+
+        self.consume_required_whitespace()?;
+        // Catalog keys are dotted; a quoted segment is one more level
+        let key = self.parse_path()?.segments().join(".");
+        let args = self.parse_include_args()?;
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+
+        Ok(AstNode::Translate(TranslateNode {
+            key,
             args,
             location,
         }))
     }
 
+    fn parse_attr(&mut self) -> Result<AstNode, ParseError> {
+        let token = self.consume(TokenType::AtAttr)?;
+        let location = token.location;
+
+        self.consume_required_whitespace()?;
+        let name_loc = self.current_location();
+        if self.current_type() != TokenType::String {
+            return self.unexpected_token(Some("Expected attribute name string"));
+        }
+        let Literal::String(name) = self.parse_literal()? else {
+            unreachable!("string token parses to a string literal");
+        };
+        if !is_attribute_name(&name) {
+            return Err(ParseError::UnexpectedToken {
+                message: format!("Invalid attribute name: {name:?}"),
+                line: name_loc.line,
+                column: name_loc.column,
+            });
+        }
+        self.consume_required_whitespace()?;
+        let path = self.parse_path()?;
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+
+        Ok(AstNode::Attr(AttrNode {
+            name,
+            path,
+            location,
+        }))
+    }
+
+    fn parse_extends(&mut self) -> Result<AstNode, ParseError> {
+        let token = self.consume(TokenType::BangExtends)?;
+        let location = token.location;
+
+        self.consume_required_whitespace()?;
+        let name = self.parse_include_name()?;
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+
+        Ok(AstNode::Extends(ExtendsNode { name, location }))
+    }
+
+    /// A static name such as `/components/card`, or a path whose value
+    /// names the partial at render time
+    fn parse_include_target(&mut self) -> Result<(String, Option<Path>), ParseError> {
+        if self.current_type() == TokenType::Ident {
+            return Ok((String::new(), Some(self.parse_path()?)));
+        }
+        Ok((self.parse_include_name()?, None))
+    }
+
     fn parse_include_name(&mut self) -> Result<String, ParseError> {
         let first_loc = self.current_location();
         if self.current_type() != TokenType::Slash {
@@ -306,11 +809,38 @@ impl Parser {
     }
 
     fn parse_include_args(&mut self) -> Result<Vec<IncludeArg>, ParseError> {
+        self.parse_arguments(false).map(|(args, _)| args)
+    }
+
+    /// Include arguments, which may also spread one object: `...card`
+    fn parse_include_args_with_spread(
+        &mut self,
+    ) -> Result<(Vec<IncludeArg>, Option<Path>), ParseError> {
+        self.parse_arguments(true)
+    }
+
+    fn parse_arguments(
+        &mut self,
+        allow_spread: bool,
+    ) -> Result<(Vec<IncludeArg>, Option<Path>), ParseError> {
         let mut args = Vec::new();
+        let mut spread = None;
         let mut seen_keys = std::collections::HashSet::new();
 
         while self.current_type() == TokenType::Whitespace {
             self.skip_whitespace();
+            if allow_spread && self.current_type() == TokenType::Ellipsis {
+                let token = self.consume(TokenType::Ellipsis)?;
+                if spread.is_some() {
+                    return Err(ParseError::UnexpectedToken {
+                        message: "Only one spread argument is allowed".to_string(),
+                        line: token.location.line,
+                        column: token.location.column,
+                    });
+                }
+                spread = Some(self.parse_path()?);
+                continue;
+            }
             if self.current_type() != TokenType::Ident {
                 break;
             }
@@ -318,7 +848,7 @@ impl Parser {
             let arg = self.parse_include_arg()?;
             if seen_keys.contains(&arg.name) {
                 return Err(ParseError::UnexpectedToken {
-                    message: format!("Duplicate include argument: {}", arg.name),
+                    message: format!("Duplicate argument: {}", arg.name),
                     line: arg.location.line,
                     column: arg.location.column,
                 });
@@ -327,7 +857,7 @@ impl Parser {
             args.push(arg);
         }
 
-        Ok(args)
+        Ok((args, spread))
     }
 
     fn parse_include_arg(&mut self) -> Result<IncludeArg, ParseError> {
@@ -338,10 +868,19 @@ impl Parser {
         self.skip_whitespace();
         self.consume(TokenType::Equal)?;
         self.skip_whitespace();
-        let value = self.parse_path()?;
+        // `null` is left to parse_path, which rejects it as a reserved word
+        let value = if self.current_type() == TokenType::String
+            || self.current_type() == TokenType::Integer
+            || self.is_ident_at(self.pos, "true")
+            || self.is_ident_at(self.pos, "false")
+        {
+            Operand::Literal(self.parse_literal()?)
+        } else {
+            Operand::Path(self.parse_path()?)
+        };
 
         Ok(IncludeArg {
-            name: key_token.value,
+            name: key_token.value.to_string(),
             value,
             location: key_loc,
         })
@@ -373,10 +912,211 @@ impl Parser {
         Ok(AstNode::Variable(VariableNode {
             path: Path::new(segments, first_loc),
             modifier,
+            filters: self.parse_filters()?,
             location: first_loc,
         }))
     }
 
+    /// `(WS? "|" WS? FILTER)*`
+    fn parse_filters(&mut self) -> Result<Vec<Filter>, ParseError> {
+        let mut filters = Vec::new();
+        loop {
+            let saved_pos = self.pos;
+            self.skip_whitespace();
+            if self.current_type() != TokenType::Pipe {
+                self.pos = saved_pos;
+                return Ok(filters);
+            }
+            self.advance();
+            self.skip_whitespace();
+            filters.push(self.parse_filter()?);
+        }
+    }
+
+    fn parse_filter(&mut self) -> Result<Filter, ParseError> {
+        let loc = self.current_location();
+        let name = self.consume(TokenType::Ident)?;
+        let filter = match name.value.as_str() {
+            "upper" => Filter::Upper,
+            "lower" => Filter::Lower,
+            "trim" => Filter::Trim,
+            "comma" => Filter::Comma,
+            "group" => Filter::Group,
+            "count" => Filter::Count,
+            "truncate" => {
+                self.consume_required_whitespace()?;
+                let length_loc = self.current_location();
+                let length = self.consume(TokenType::Integer)?;
+                match length.value.parse::<usize>() {
+                    Ok(n) if n > 0 => Filter::Truncate(n),
+                    _ => {
+                        return Err(ParseError::UnexpectedToken {
+                            message: format!(
+                                "truncate length must be a positive integer: {}",
+                                length.value
+                            ),
+                            line: length_loc.line,
+                            column: length_loc.column,
+                        })
+                    }
+                }
+            }
+            other if self.custom_filters.iter().any(|name| name == other) => {
+                Filter::Custom(other.to_string())
+            }
+            other => {
+                return Err(ParseError::UnexpectedToken {
+                    message: format!("Unknown filter: {other}"),
+                    line: loc.line,
+                    column: loc.column,
+                })
+            }
+        };
+        Ok(filter)
+    }
+
+    /// `or` binds loosest, then `and`, then `not`
+    fn parse_condition(&mut self) -> Result<Condition, ParseError> {
+        let mut condition = self.parse_and_condition()?;
+        while self.consume_spaced_word("or") {
+            let right = self.parse_and_condition()?;
+            condition = Condition::Or(Box::new(condition), Box::new(right));
+        }
+        Ok(condition)
+    }
+
+    fn parse_and_condition(&mut self) -> Result<Condition, ParseError> {
+        let mut condition = self.parse_not_condition()?;
+        while self.consume_spaced_word("and") {
+            let right = self.parse_not_condition()?;
+            condition = Condition::And(Box::new(condition), Box::new(right));
+        }
+        Ok(condition)
+    }
+
+    fn parse_not_condition(&mut self) -> Result<Condition, ParseError> {
+        // `not` is contextual: `{[#if not]}` still reads the variable `not`
+        let negates = self.is_ident_at(self.pos, "not")
+            && self.token_type_at(self.pos + 1) == TokenType::Whitespace
+            && self.token_type_at(self.pos + 2) == TokenType::Ident;
+        if !negates {
+            return self.parse_comparison();
+        }
+        self.advance();
+        self.skip_whitespace();
+        Ok(Condition::Not(Box::new(self.parse_not_condition()?)))
+    }
+
+    /// Consume `<ws>keyword<ws>`, e.g. `and` between two conditions
+    fn consume_spaced_word(&mut self, keyword: &str) -> bool {
+        let found = self.token_type_at(self.pos) == TokenType::Whitespace
+            && self.is_ident_at(self.pos + 1, keyword)
+            && self.token_type_at(self.pos + 2) == TokenType::Whitespace;
+        if found {
+            self.pos += 3;
+        }
+        found
+    }
+
+    /// Whether the each tag is written `item in items` or `item, i in items`
+    fn is_each_in_form(&self) -> bool {
+        let skip_whitespace = |p: usize| {
+            if self.token_type_at(p) == TokenType::Whitespace {
+                p + 1
+            } else {
+                p
+            }
+        };
+        let mut p = self.pos + 1;
+        if self.token_type_at(skip_whitespace(p)) == TokenType::Comma {
+            p = skip_whitespace(skip_whitespace(p) + 1) + 1;
+        }
+        self.token_type_at(p) == TokenType::Whitespace && self.is_ident_at(p + 1, "in")
+    }
+
+    fn is_ident_at(&self, pos: usize, value: &str) -> bool {
+        self.tokens
+            .get(pos)
+            .is_some_and(|t| t.token_type == TokenType::Ident && t.value.as_str() == value)
+    }
+
+    fn token_type_at(&self, pos: usize) -> TokenType {
+        self.tokens
+            .get(pos)
+            .map(|t| t.token_type)
+            .unwrap_or(TokenType::Eof)
+    }
+
+    fn parse_comparison(&mut self) -> Result<Condition, ParseError> {
+        let left = self.parse_path()?;
+
+        let saved_pos = self.pos;
+        self.skip_whitespace();
+        let op = match self.current_type() {
+            TokenType::EqualEqual => CompareOp::Eq,
+            TokenType::NotEqual => CompareOp::Ne,
+            TokenType::Less => CompareOp::Lt,
+            TokenType::LessEqual => CompareOp::Le,
+            TokenType::Greater => CompareOp::Gt,
+            TokenType::GreaterEqual => CompareOp::Ge,
+            _ => {
+                self.pos = saved_pos;
+                return Ok(Condition::Path(left));
+            }
+        };
+        self.advance();
+        self.skip_whitespace();
+        let loc = self.current_location();
+        let right = self.parse_operand()?;
+        if op.is_ordering()
+            && matches!(&right, Operand::Literal(l) if !matches!(l, Literal::Integer(_)))
+        {
+            return Err(ParseError::UnexpectedToken {
+                message: format!("'{}' compares numbers only", op.as_str()),
+                line: loc.line,
+                column: loc.column,
+            });
+        }
+
+        Ok(Condition::Comparison(Comparison { left, op, right }))
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, ParseError> {
+        match self.current_type() {
+            TokenType::String | TokenType::Integer => Ok(Operand::Literal(self.parse_literal()?)),
+            TokenType::Ident if matches!(self.current_token(), Some(t) if matches!(t.value.as_str(), "true" | "false" | "null")) => {
+                Ok(Operand::Literal(self.parse_literal()?))
+            }
+            _ => Ok(Operand::Path(self.parse_path()?)),
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<Literal, ParseError> {
+        let loc = self.current_location();
+        let Some(token) = self.current_token().cloned() else {
+            return self.unexpected_token(Some("Expected literal"));
+        };
+        let literal = match (token.token_type, token.value.as_str()) {
+            (TokenType::String, value) => Literal::String(unescape_string(value)),
+            (TokenType::Integer, value) => match value.parse::<i64>() {
+                Ok(n) if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&n) => Literal::Integer(n),
+                _ => {
+                    return Err(ParseError::UnexpectedToken {
+                        message: format!("Integer literal out of range: {value}"),
+                        line: loc.line,
+                        column: loc.column,
+                    })
+                }
+            },
+            (TokenType::Ident, "true") => Literal::Bool(true),
+            (TokenType::Ident, "false") => Literal::Bool(false),
+            (TokenType::Ident, "null") => Literal::Null,
+            _ => return self.unexpected_token(Some("Expected literal")),
+        };
+        self.advance();
+        Ok(literal)
+    }
+
     fn parse_path(&mut self) -> Result<Path, ParseError> {
         let first_loc = self.current_location();
         let segments = self.parse_path_segments()?;
@@ -386,12 +1126,41 @@ impl Parser {
     fn parse_path_segments(&mut self) -> Result<Vec<String>, ParseError> {
         let mut segments = vec![self.parse_identifier_with_validation()?];
 
-        while self.current_type() == TokenType::Dot {
-            self.consume(TokenType::Dot)?;
-            segments.push(self.parse_identifier_with_validation()?);
+        loop {
+            match self.current_type() {
+                TokenType::Dot => {
+                    self.advance();
+                    if self.current_type() == TokenType::Integer {
+                        segments.push(self.parse_index()?);
+                    } else {
+                        segments.push(self.parse_identifier_with_validation()?);
+                    }
+                }
+                // Quoted key: meta["og:title"]
+                TokenType::LeftBracket => {
+                    self.advance();
+                    let key = self.consume(TokenType::String)?;
+                    self.consume(TokenType::RightBracket)?;
+                    segments.push(unescape_string(&key.value));
+                }
+                _ => return Ok(segments),
+            }
         }
+    }
 
-        Ok(segments)
+    /// Parse an array index segment: `0` or a digit string without a leading zero
+    fn parse_index(&mut self) -> Result<String, ParseError> {
+        let loc = self.current_location();
+        let token = self.consume(TokenType::Integer)?;
+        let value = token.value.as_str();
+        if value.starts_with('-') || (value.len() > 1 && value.starts_with('0')) {
+            return Err(ParseError::UnexpectedToken {
+                message: format!("Invalid index: {value}"),
+                line: loc.line,
+                column: loc.column,
+            });
+        }
+        Ok(value.to_string())
     }
 
     fn parse_identifier_with_validation(&mut self) -> Result<String, ParseError> {
@@ -400,7 +1169,7 @@ impl Parser {
         // Check if it's a keyword token used as identifier
         if self.is_keyword_token() {
             let token = self.current_token().unwrap();
-            let word = token.value.clone();
+            let word = token.value.to_string();
             self.advance();
             return Err(ParseError::ReservedWord {
                 word,
@@ -411,7 +1180,7 @@ impl Parser {
 
         let token = self.consume(TokenType::Ident)?;
         validate_identifier(&token.value, loc)?;
-        Ok(token.value)
+        Ok(token.value.to_string())
     }
 
     // ========================================================================
@@ -479,6 +1248,85 @@ impl Parser {
         Ok(())
     }
 
+    fn is_cache_keyword(&self) -> bool {
+        matches!(self.current_token(), Some(t) if t.token_type == TokenType::Ident && t.value == CACHE_KEYWORD)
+    }
+
+    fn is_block_keyword(&self) -> bool {
+        matches!(self.current_token(), Some(t) if t.token_type == TokenType::Ident && t.value == BLOCK_KEYWORD)
+    }
+
+    fn is_include_keyword(&self) -> bool {
+        matches!(self.current_token(), Some(t) if t.token_type == TokenType::Ident && t.value == INCLUDE_KEYWORD)
+    }
+
+    fn is_raw_keyword(&self) -> bool {
+        matches!(self.current_token(), Some(t) if t.token_type == TokenType::Ident && t.value == RAW_KEYWORD)
+    }
+
+    fn is_elseif_keyword(&self) -> bool {
+        matches!(self.current_token(), Some(t) if t.token_type == TokenType::Ident && t.value == ELSEIF_KEYWORD)
+    }
+
+    fn is_elseif_open(&self) -> bool {
+        if self.current_type() != TokenType::Hash {
+            return false;
+        }
+
+        let mut p = self.pos + 1;
+        // Skip whitespace
+        while p < self.tokens.len() && self.tokens[p].token_type == TokenType::Whitespace {
+            p += 1;
+        }
+        matches!(self.tokens.get(p), Some(t) if t.token_type == TokenType::Ident && t.value == ELSEIF_KEYWORD)
+    }
+
+    /// Whether the next tag closes a block opened by a contextual keyword
+    fn is_ident_block_close(&self, keyword: &str) -> bool {
+        if self.current_type() != TokenType::Slash {
+            return false;
+        }
+
+        let mut p = self.pos + 1;
+        // Skip whitespace
+        while p < self.tokens.len() && self.tokens[p].token_type == TokenType::Whitespace {
+            p += 1;
+        }
+        matches!(self.tokens.get(p), Some(t) if t.token_type == TokenType::Ident && t.value == keyword)
+    }
+
+    fn consume_ident_block_close(&mut self, keyword: &str) -> Result<(), ParseError> {
+        self.consume(TokenType::Slash)?;
+        self.skip_whitespace();
+        if !matches!(self.current_token(), Some(t) if t.token_type == TokenType::Ident && t.value == keyword)
+        {
+            return self.unexpected_token(Some(&format!("Expected '{keyword}'")));
+        }
+        self.advance();
+        self.skip_whitespace();
+        self.consume(TokenType::Close)?;
+        Ok(())
+    }
+
+    /// Hash the tokens in `range` with 64-bit FNV-1a
+    ///
+    /// Stable across processes and Rust releases, so fingerprints can key an
+    /// external or persistent fragment store.
+    fn fingerprint(&self, range: Range<usize>) -> u64 {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut write = |bytes: &[u8]| {
+            for &byte in bytes.iter().chain(&[0]) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+        };
+        for token in &self.tokens[range] {
+            write(format!("{:?}", token.token_type).as_bytes());
+            write(token.value.as_str().as_bytes());
+        }
+        hash
+    }
+
     fn current_token(&self) -> Option<&Token> {
         self.tokens.get(self.pos)
     }
@@ -530,6 +1378,15 @@ impl Parser {
         Ok(())
     }
 
+    /// Consume a `-` trim marker kept by the token processor
+    fn consume_trim_marker(&mut self) -> bool {
+        let found = self.current_type() == TokenType::Dash;
+        if found {
+            self.advance();
+        }
+        found
+    }
+
     fn skip_whitespace(&mut self) {
         while self.current_type() == TokenType::Whitespace {
             self.advance();
@@ -552,17 +1409,128 @@ impl Parser {
     }
 }
 
+/// Check the placement rules of `extends` and `block`
+///
+/// `extends` may appear once, at the top level; a template using it holds
+/// only blocks and whitespace there. Block names are unique per template.
+fn validate_layout(nodes: &[AstNode]) -> Result<(), ParseError> {
+    let extends = nodes
+        .iter()
+        .filter(|node| matches!(node, AstNode::Extends(_)))
+        .collect::<Vec<_>>();
+    if let Some(second) = extends.get(1) {
+        return Err(layout_error(
+            "Template extends more than one layout",
+            second,
+        ));
+    }
+    if !extends.is_empty() {
+        for node in nodes {
+            match node {
+                AstNode::Extends(_) | AstNode::Block(_) => {}
+                AstNode::Text(n) if n.content.trim().is_empty() => {}
+                other => return Err(layout_error(
+                    "Only blocks may appear at the top level of a template that extends a layout",
+                    other,
+                )),
+            }
+        }
+    }
+    let mut names = Vec::new();
+    for node in nodes {
+        check_nested_layout(node, &mut names, true)?;
+    }
+    Ok(())
+}
+
+fn check_nested_layout<'a>(
+    node: &'a AstNode,
+    names: &mut Vec<&'a str>,
+    top_level: bool,
+) -> Result<(), ParseError> {
+    let children: Vec<&[AstNode]> = match node {
+        AstNode::Text(_)
+        | AstNode::Variable(_)
+        | AstNode::Unsecure(_)
+        | AstNode::Yield(_)
+        | AstNode::Translate(_)
+        | AstNode::Attr(_) => vec![],
+        AstNode::Include(n) => n.body.iter().map(Vec::as_slice).collect(),
+        AstNode::Extends(_) if top_level => vec![],
+        AstNode::Extends(_) => {
+            return Err(layout_error("'extends' must be at the top level", node));
+        }
+        AstNode::Block(n) => {
+            if names.contains(&n.name.as_str()) {
+                return Err(layout_error(
+                    &format!("Duplicate block name '{}'", n.name),
+                    node,
+                ));
+            }
+            names.push(&n.name);
+            vec![&n.body]
+        }
+        AstNode::If(n) => std::iter::once(n.then_branch.as_slice())
+            .chain(n.elseif_branches.iter().map(|b| b.body.as_slice()))
+            .chain(n.else_branch.as_deref())
+            .collect(),
+        AstNode::Unless(n) => std::iter::once(n.body.as_slice())
+            .chain(n.else_branch.as_deref())
+            .collect(),
+        AstNode::Each(n) => std::iter::once(n.body.as_slice())
+            .chain(n.else_branch.as_deref())
+            .collect(),
+        AstNode::Cache(n) => vec![&n.body],
+    };
+    for child in children.into_iter().flatten() {
+        check_nested_layout(child, names, false)?;
+    }
+    Ok(())
+}
+
+fn layout_error(message: &str, node: &AstNode) -> ParseError {
+    let location = node.location();
+    ParseError::UnexpectedToken {
+        message: message.to_string(),
+        line: location.line,
+        column: location.column,
+    }
+}
+
+/// Strip the quotes of a lexed string literal and resolve its escapes.
+/// HTML attribute names accepted by `@attr`: a letter or `_`, then letters,
+/// digits, `-`, `_`, `:` or `.`
+fn is_attribute_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.'))
+}
+
+fn unescape_string(quoted: &str) -> String {
+    let mut unescaped = String::with_capacity(quoted.len());
+    let mut chars = quoted[1..quoted.len() - 1].chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => unescaped.extend(chars.next()),
+            _ => unescaped.push(ch),
+        }
+    }
+    unescaped
+}
+
 fn token_surface(token: &Token) -> String {
     token
         .token_type
         .literal()
         .map(str::to_string)
-        .unwrap_or_else(|| token.value.clone())
+        .unwrap_or_else(|| token.value.to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::parse;
+    use crate::{parse, AstNode, Condition, Filter, Literal, Modifier, Operand};
 
     #[test]
     fn test_parse_simple_variable() {
@@ -570,12 +1538,131 @@ mod tests {
         assert_eq!(tmpl.nodes().len(), 1);
     }
 
+    #[test]
+    fn test_parse_filters() {
+        let tmpl = parse("{[ title? | trim|truncate 20 ]}").unwrap();
+        let AstNode::Variable(node) = &tmpl.nodes()[0] else {
+            panic!("Expected variable");
+        };
+        assert_eq!(node.modifier, Modifier::Nullable);
+        assert_eq!(node.filters, vec![Filter::Trim, Filter::Truncate(20)]);
+        assert!(parse("{[ a | shout ]}").is_err());
+        assert!(parse("{[ a | truncate ]}").is_err());
+        assert!(parse("{[ a | truncate 0 ]}").is_err());
+        assert!(parse("{[ a | ]}").is_err());
+    }
+
+    #[test]
+    fn test_parse_index_segments() {
+        let tmpl = parse("{[ images.0.url ]}{[ rows.12 ]}").unwrap();
+        let AstNode::Variable(node) = &tmpl.nodes()[0] else {
+            panic!("Expected variable");
+        };
+        assert_eq!(node.path.segments(), ["images", "0", "url"]);
+        assert!(parse("{[ 0 ]}").is_err());
+        assert!(parse("{[ rows.01 ]}").is_err());
+        assert!(parse("{[ rows.-1 ]}").is_err());
+    }
+
     #[test]
     fn test_parse_if_else() {
         let tmpl = parse("{[#if x]}a{[#else]}b{[/if]}").unwrap();
         assert_eq!(tmpl.nodes().len(), 1);
     }
 
+    #[test]
+    fn test_parse_elseif_chain() {
+        let tmpl = parse("{[#if a]}1{[#elseif b]}2{[#elseif c.d]}3{[#else]}4{[/if]}").unwrap();
+        let AstNode::If(block) = &tmpl.nodes()[0] else {
+            panic!("expected if block");
+        };
+        let conditions: Vec<String> = block
+            .elseif_branches
+            .iter()
+            .map(|branch| branch.condition.to_string())
+            .collect();
+        assert_eq!(conditions, vec!["b", "c.d"]);
+        assert_eq!(block.elseif_branches[0].location.column, 14);
+        assert!(block.else_branch.is_some());
+
+        assert!(parse("{[#if a]}1{[#else]}2{[#elseif b]}3{[/if]}").is_err());
+    }
+
+    #[test]
+    fn test_parse_comparisons() {
+        let tmpl = parse(
+            r#"{[#if status == "a \"b\" \\"]}{[/if]}{[#unless count!=-1]}{[/unless]}{[#if a == b.c]}{[#elseif x != null]}{[/if]}"#,
+        )
+        .unwrap();
+        let conditions: Vec<String> = tmpl
+            .nodes()
+            .iter()
+            .flat_map(|node| match node {
+                AstNode::If(n) => std::iter::once(&n.condition)
+                    .chain(n.elseif_branches.iter().map(|b| &b.condition))
+                    .map(ToString::to_string)
+                    .collect(),
+                AstNode::Unless(n) => vec![n.condition.to_string()],
+                _ => vec![],
+            })
+            .collect();
+        assert_eq!(
+            conditions,
+            vec![
+                r#"status == "a \"b\" \\""#,
+                "count != -1",
+                "a == b.c",
+                "x != null"
+            ]
+        );
+        let AstNode::If(block) = &tmpl.nodes()[0] else {
+            panic!("expected if block");
+        };
+        let Condition::Comparison(c) = &block.condition else {
+            panic!("expected comparison");
+        };
+        assert!(matches!(&c.right, Operand::Literal(Literal::String(s)) if s == r#"a "b" \"#));
+
+        assert!(parse("{[#if a == ]}{[/if]}").is_err());
+        assert!(parse("{[#if a == 9007199254740992]}{[/if]}").is_err());
+        assert!(parse("{[#if a = 1]}{[/if]}").is_err());
+        assert!(parse(r#"{[ a == "x" ]}"#).is_err());
+
+        let tmpl = parse("{[#if stock>=10]}{[/if]}").unwrap();
+        let AstNode::If(block) = &tmpl.nodes()[0] else {
+            panic!("expected if block");
+        };
+        assert_eq!(block.condition.to_string(), "stock >= 10");
+        assert!(parse(r#"{[#if a < "x"]}{[/if]}"#).is_err());
+        assert!(parse("{[#if a > null]}{[/if]}").is_err());
+    }
+
+    #[test]
+    fn test_parse_logical_conditions() {
+        let tmpl = parse("{[#if not a or b and not c == 1 or d]}{[/if]}").unwrap();
+        let AstNode::If(block) = &tmpl.nodes()[0] else {
+            panic!("expected if block");
+        };
+        let Condition::Or(left, right) = &block.condition else {
+            panic!("expected or");
+        };
+        assert!(matches!(&**right, Condition::Path(p) if p.as_str() == "d"));
+        let Condition::Or(first, second) = &**left else {
+            panic!("expected or");
+        };
+        assert!(matches!(&**first, Condition::Not(c) if matches!(&**c, Condition::Path(_))));
+        assert!(matches!(&**second, Condition::And(_, c) if matches!(&**c, Condition::Not(_))));
+        assert_eq!(
+            block.condition.to_string(),
+            "not a or b and not c == 1 or d"
+        );
+
+        // The operators are contextual, not reserved
+        assert!(parse("{[#if not]}{[ and ]}{[/if]}{[#unless or == 1]}{[/unless]}").is_ok());
+        assert!(parse("{[#if a and]}{[/if]}").is_err());
+        assert!(parse("{[#if a and and b]}{[/if]}").is_err());
+    }
+
     #[test]
     fn test_parse_nested_blocks() {
         let tmpl = parse("{[#each items as item]}{[#if item.show]}{[ item.name ]}{[/if]}{[/each]}")
@@ -583,6 +1670,141 @@ mod tests {
         assert_eq!(tmpl.nodes().len(), 1);
     }
 
+    #[test]
+    fn test_parse_cache_block() {
+        let tmpl = parse("{[#cache key=post.id ]}{[ post.title ]}{[/cache]}").unwrap();
+        let AstNode::Cache(block) = &tmpl.nodes()[0] else {
+            panic!("expected cache block");
+        };
+        assert_eq!(block.key.as_str(), "post.id");
+        assert_eq!(block.body.len(), 1);
+
+        let other = parse("{[#cache key=post.id ]}{[ post.body ]}{[/cache]}").unwrap();
+        let AstNode::Cache(other) = &other.nodes()[0] else {
+            panic!("expected cache block");
+        };
+        assert_ne!(block.fingerprint, other.fingerprint);
+        // Pinned: persistent fragment stores rely on it across releases
+        assert_eq!(block.fingerprint, 0x5148_c868_24b1_f810);
+    }
+
+    #[test]
+    fn test_parse_layout() {
+        let tmpl = parse("{[!extends /layouts/base ]}\n{[#block content]}x{[/block]}").unwrap();
+        assert_eq!(tmpl.extends().unwrap().name, "/layouts/base");
+        let AstNode::Block(block) = &tmpl.nodes()[2] else {
+            panic!("expected block");
+        };
+        assert_eq!(block.name, "content");
+
+        let err = parse("{[!extends /base ]}x").unwrap_err();
+        assert!(err.to_string().contains("Only blocks"));
+        let err =
+            parse("{[#block a]}{[#if x]}{[#block a]}{[/block]}{[/if]}{[/block]}").unwrap_err();
+        assert!(err.to_string().contains("Duplicate block name 'a'"));
+    }
+
+    #[test]
+    fn test_parse_include_block() {
+        let tmpl = parse("{[#include /card title=t ]}<p>{[ x ]}</p>{[/include]}").unwrap();
+        let AstNode::Include(include) = &tmpl.nodes()[0] else {
+            panic!("expected include");
+        };
+        assert_eq!(include.name, "/card");
+        assert_eq!(include.args.len(), 1);
+        assert_eq!(include.body.as_ref().unwrap().len(), 3);
+        assert!(matches!(
+            parse("{[!yield ]}").unwrap().nodes()[0],
+            AstNode::Yield(_)
+        ));
+        assert!(parse("{[#include /card ]}x").is_err());
+        assert!(parse("{[ include ]}").is_err());
+    }
+
+    #[test]
+    fn test_parse_params_header() {
+        let tmpl = parse("{[%params title, body,actions? ]}\n<h2>{[ title ]}</h2>").unwrap();
+        let params: Vec<(&str, bool)> = tmpl
+            .params()
+            .unwrap()
+            .iter()
+            .map(|param| (param.name.as_str(), param.optional))
+            .collect();
+        assert_eq!(
+            params,
+            [("title", false), ("body", false), ("actions", true)]
+        );
+        assert!(matches!(tmpl.nodes()[0], AstNode::Text(_)));
+        assert_eq!(parse("{[%params]}").unwrap().params(), Some(&[][..]));
+        assert!(parse("{[% params title ]}").unwrap().params().is_none());
+        assert!(parse("{[#if x]}{[%params a ]}{[/if]}").is_err());
+    }
+
+    #[test]
+    fn test_include_spread() {
+        let tmpl = parse("{[!include /card title=t ...card.props ]}").unwrap();
+        let AstNode::Include(node) = &tmpl.nodes()[0] else {
+            panic!("expected include");
+        };
+        assert_eq!(node.spread.as_ref().unwrap().as_str(), "card.props");
+        assert_eq!(node.args.len(), 1);
+        assert!(parse("{[!include /card ...a ...b ]}").is_err());
+        assert!(parse("{[!include /card ...\"x\" ]}").is_err());
+        assert!(parse("{[@t k ...a ]}").is_err());
+    }
+
+    #[test]
+    fn test_include_keeps_trim_markers() {
+        let tmpl =
+            parse("a {[-!include /x -]}{[!include /y -]}{[-#include /z ]}{[/include]}").unwrap();
+        let trims: Vec<(bool, bool)> = tmpl
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                AstNode::Include(n) => Some((n.trim_left, n.trim_right)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(trims, [(true, true), (false, true), (true, false)]);
+        assert!(parse("{[- !include /x ]}").is_err());
+    }
+
+    #[test]
+    fn test_parse_translate() {
+        let tmpl = parse("{[@t greeting.hello name=user.name count=3 ]}").unwrap();
+        let AstNode::Translate(node) = &tmpl.nodes()[0] else {
+            panic!("expected translate");
+        };
+        assert_eq!(node.key, "greeting.hello");
+        let names: Vec<&str> = node.args.iter().map(|arg| arg.name.as_str()).collect();
+        assert_eq!(names, ["name", "count"]);
+        assert!(parse("{[@t]}").is_err());
+        assert!(parse("{[@t a x=1 x=2 ]}").is_err());
+        assert!(parse("{[@title ]}").is_err());
+    }
+
+    #[test]
+    fn test_parse_attr() {
+        let tmpl = parse("<button{[@attr \"data-x:y\" form.locked ]}>").unwrap();
+        let AstNode::Attr(node) = &tmpl.nodes()[1] else {
+            panic!("expected attr");
+        };
+        assert_eq!(node.name, "data-x:y");
+        assert_eq!(node.path.as_str(), "form.locked");
+        assert!(parse("{[@attr disabled x ]}").is_err());
+        assert!(parse("{[@attr \"a b\" x ]}").is_err());
+        assert!(parse("{[@attr \"\" x ]}").is_err());
+        assert!(parse("{[@attr \"a\"x ]}").is_err());
+        assert!(parse("{[@attribute ]}").is_err());
+    }
+
+    #[test]
+    fn test_cache_is_contextual_keyword() {
+        assert!(parse("{[ cache ]}").is_ok());
+        assert!(parse("{[#cache post ]}{[/cache]}").is_err());
+        assert!(parse("{[#cache key=a ]}{[/if]}").is_err());
+    }
+
     #[test]
     fn test_reserved_word_error() {
         let result = parse("{[ if ]}");
@@ -0,0 +1,173 @@
+//! Copy-on-write text referencing the template source.
+
+use std::fmt;
+use std::ops::{Deref, Range};
+use std::sync::Arc;
+
+/// Text that is either a byte range of the shared template source or an owned
+/// string (used when the text differs from the source, e.g. after `{[{]}`
+/// escapes were resolved).
+#[derive(Clone)]
+pub struct SourceText(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Shared {
+        source: Arc<str>,
+        range: Range<usize>,
+    },
+    Owned(String),
+}
+
+impl SourceText {
+    /// Reference a byte range of the shared source.
+    pub fn shared(source: &Arc<str>, range: Range<usize>) -> Self {
+        debug_assert!(source.get(range.clone()).is_some());
+        Self(Repr::Shared {
+            source: Arc::clone(source),
+            range,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Shared { source, range } => &source[range.clone()],
+            Repr::Owned(s) => s,
+        }
+    }
+
+    /// Byte range in the template source, if this text was not copied.
+    pub fn source_range(&self) -> Option<Range<usize>> {
+        match &self.0 {
+            Repr::Shared { range, .. } => Some(range.clone()),
+            Repr::Owned(_) => None,
+        }
+    }
+
+    /// Sub-slice by a byte range relative to this text, without copying
+    /// shared text.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        match &self.0 {
+            Repr::Shared {
+                source,
+                range: outer,
+            } => Self(Repr::Shared {
+                source: Arc::clone(source),
+                range: outer.start + range.start..outer.start + range.end,
+            }),
+            Repr::Owned(s) => Self(Repr::Owned(s[range].to_string())),
+        }
+    }
+
+    /// This text followed by `next`; stays shared when the two ranges are
+    /// adjacent in the same source, and is copied otherwise.
+    pub fn concat(&self, next: &SourceText) -> Self {
+        if let (
+            Repr::Shared { source, range },
+            Repr::Shared {
+                source: next_source,
+                range: next_range,
+            },
+        ) = (&self.0, &next.0)
+        {
+            if Arc::ptr_eq(source, next_source) && range.end == next_range.start {
+                return Self::shared(source, range.start..next_range.end);
+            }
+        }
+        Self(Repr::Owned(format!("{}{}", self.as_str(), next.as_str())))
+    }
+}
+
+impl Deref for SourceText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SourceText {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<String> for SourceText {
+    fn from(s: String) -> Self {
+        Self(Repr::Owned(s))
+    }
+}
+
+impl From<&str> for SourceText {
+    fn from(s: &str) -> Self {
+        Self(Repr::Owned(s.to_string()))
+    }
+}
+
+impl fmt::Debug for SourceText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for SourceText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq for SourceText {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SourceText {}
+
+impl PartialEq<str> for SourceText {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SourceText {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_slice_does_not_copy() {
+        let source: Arc<str> = Arc::from("hello, world");
+        let text = SourceText::shared(&source, 7..12);
+        assert_eq!(text, "world");
+
+        let sub = text.slice(1..3);
+        assert_eq!(sub, "or");
+        assert_eq!(sub.source_range(), Some(8..10));
+    }
+
+    #[test]
+    fn owned_text_has_no_source_range() {
+        let text = SourceText::from("a{[b");
+        assert_eq!(text.slice(1..3), "{[");
+        assert_eq!(text.source_range(), None);
+    }
+
+    #[test]
+    fn concat_keeps_adjacent_ranges_shared() {
+        let source: Arc<str> = Arc::from("abc{[% x ]}def");
+        let abc = SourceText::shared(&source, 0..3);
+
+        let joined = SourceText::shared(&source, 0..1).concat(&abc.slice(1..3));
+        assert_eq!(joined.source_range(), Some(0..3));
+
+        let joined = abc.concat(&SourceText::shared(&source, 11..14));
+        assert_eq!(joined, "abcdef");
+        assert_eq!(joined.source_range(), None);
+    }
+}
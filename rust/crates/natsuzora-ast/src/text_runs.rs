@@ -0,0 +1,74 @@
+//! Human-visible text of a template, for spell checkers and i18n tools.
+//!
+//! [`Template::text_runs`](crate::Template::text_runs) yields the text nodes
+//! in source order, descending into block bodies but skipping tags, so tools
+//! can check prose and report positions without walking the AST.
+
+use std::slice;
+
+use crate::{AstNode, Location};
+
+/// Iterator over the text nodes of a template and their locations.
+#[derive(Debug, Clone)]
+pub struct TextRuns<'a> {
+    /// Sibling lists still being walked, innermost last
+    stack: Vec<slice::Iter<'a, AstNode>>,
+}
+
+impl<'a> TextRuns<'a> {
+    pub(crate) fn new(nodes: &'a [AstNode]) -> Self {
+        Self {
+            stack: vec![nodes.iter()],
+        }
+    }
+
+    /// Push the bodies of `node` so they are walked before its next sibling
+    fn descend(&mut self, node: &'a AstNode) {
+        let bodies: Vec<&'a [AstNode]> = match node {
+            AstNode::If(n) => std::iter::once(n.then_branch.as_slice())
+                .chain(n.elseif_branches.iter().map(|b| b.body.as_slice()))
+                .chain(n.else_branch.as_deref())
+                .collect(),
+            AstNode::Unless(n) => std::iter::once(n.body.as_slice())
+                .chain(n.else_branch.as_deref())
+                .collect(),
+            AstNode::Each(n) => std::iter::once(n.body.as_slice())
+                .chain(n.else_branch.as_deref())
+                .collect(),
+            AstNode::Cache(n) => vec![&n.body],
+            AstNode::Block(n) => vec![&n.body],
+            AstNode::Include(n) => n.body.iter().map(Vec::as_slice).collect(),
+            AstNode::Text(_)
+            | AstNode::Variable(_)
+            | AstNode::Unsecure(_)
+            | AstNode::Extends(_)
+            | AstNode::Yield(_)
+            | AstNode::Translate(_)
+            | AstNode::Attr(_) => return,
+        };
+        self.stack
+            .extend(bodies.into_iter().rev().map(|body| body.iter()));
+    }
+}
+
+impl<'a> Iterator for TextRuns<'a> {
+    type Item = (&'a str, Location);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = match self.stack.last_mut()?.next() {
+                Some(node) => node,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            match node {
+                AstNode::Text(n) if !n.content.is_empty() => {
+                    return Some((n.content.as_str(), n.location));
+                }
+                _ => self.descend(node),
+            }
+        }
+    }
+}
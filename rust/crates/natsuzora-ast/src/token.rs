@@ -1,9 +1,9 @@
 //! Token types for the Natsuzora lexer.
 
-use crate::Location;
+use crate::{Location, SourceText};
 
 /// Token types produced by the lexer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TokenType {
     /// Raw text content outside tags.
     Text,
@@ -11,6 +11,8 @@ pub enum TokenType {
     Percent,
     /// `-` - whitespace control marker
     Dash,
+    /// `~` - whitespace control marker that also trims line breaks
+    Tilde,
     /// `]}` - closing delimiter
     Close,
     /// `#` - block open marker
@@ -21,6 +23,14 @@ pub enum TokenType {
     BangUnsecure,
     /// `!include`
     BangInclude,
+    /// `!extends`
+    BangExtends,
+    /// `!yield`
+    BangYield,
+    /// `@t` - translation
+    AtT,
+    /// `@attr` - conditional HTML attribute
+    AtAttr,
     /// `!` - exclamation (modifier)
     Exclamation,
     /// `if`
@@ -35,16 +45,42 @@ pub enum TokenType {
     KwAs,
     /// `.` - dot separator
     Dot,
+    /// `...` - spread of include arguments
+    Ellipsis,
+    /// `..` - inclusive integer range
+    DotDot,
     /// `,` - comma
     Comma,
+    /// `[` - opens a quoted path key
+    LeftBracket,
+    /// `]` - closes a quoted path key
+    RightBracket,
     /// `=` - equals
     Equal,
+    /// `==` - equality operator
+    EqualEqual,
+    /// `!=` - inequality operator
+    NotEqual,
+    /// `<` - less-than operator
+    Less,
+    /// `<=` - less-than-or-equal operator
+    LessEqual,
+    /// `>` - greater-than operator
+    Greater,
+    /// `>=` - greater-than-or-equal operator
+    GreaterEqual,
     /// `?` - nullable modifier
     Question,
+    /// `|` - filter separator
+    Pipe,
     /// Whitespace (spaces, tabs, newlines) inside tags
     Whitespace,
-    /// Identifier: [A-Za-z][A-Za-z0-9_]*
+    /// Identifier: an `XID_Start` character followed by `XID_Continue` characters
     Ident,
+    /// String literal including its quotes: `"..."`
+    String,
+    /// Integer literal: -?[0-9]+
+    Integer,
     /// End of file
     Eof,
 }
@@ -58,11 +94,16 @@ impl TokenType {
         match self {
             TokenType::Percent => Some("%"),
             TokenType::Dash => Some("-"),
+            TokenType::Tilde => Some("~"),
             TokenType::Close => Some("]}"),
             TokenType::Hash => Some("#"),
             TokenType::Slash => Some("/"),
             TokenType::BangUnsecure => Some("!unsecure"),
             TokenType::BangInclude => Some("!include"),
+            TokenType::BangExtends => Some("!extends"),
+            TokenType::BangYield => Some("!yield"),
+            TokenType::AtT => Some("@t"),
+            TokenType::AtAttr => Some("@attr"),
             TokenType::Exclamation => Some("!"),
             TokenType::KwIf => Some("if"),
             TokenType::KwUnless => Some("unless"),
@@ -70,10 +111,26 @@ impl TokenType {
             TokenType::KwEach => Some("each"),
             TokenType::KwAs => Some("as"),
             TokenType::Dot => Some("."),
+            TokenType::Ellipsis => Some("..."),
+            TokenType::DotDot => Some(".."),
             TokenType::Comma => Some(","),
+            TokenType::LeftBracket => Some("["),
+            TokenType::RightBracket => Some("]"),
             TokenType::Equal => Some("="),
+            TokenType::EqualEqual => Some("=="),
+            TokenType::NotEqual => Some("!="),
+            TokenType::Less => Some("<"),
+            TokenType::LessEqual => Some("<="),
+            TokenType::Greater => Some(">"),
+            TokenType::GreaterEqual => Some(">="),
             TokenType::Question => Some("?"),
-            TokenType::Text | TokenType::Whitespace | TokenType::Ident | TokenType::Eof => None,
+            TokenType::Pipe => Some("|"),
+            TokenType::Text
+            | TokenType::Whitespace
+            | TokenType::Ident
+            | TokenType::String
+            | TokenType::Integer
+            | TokenType::Eof => None,
         }
     }
 }
@@ -82,12 +139,12 @@ impl TokenType {
 #[derive(Debug, Clone)]
 pub struct Token {
     pub token_type: TokenType,
-    pub value: String,
+    pub value: SourceText,
     pub location: Location,
 }
 
 impl Token {
-    pub fn new(token_type: TokenType, value: impl Into<String>, location: Location) -> Self {
+    pub fn new(token_type: TokenType, value: impl Into<SourceText>, location: Location) -> Self {
         Self {
             token_type,
             value: value.into(),
@@ -105,11 +162,16 @@ mod tests {
         let cases: Vec<(TokenType, &str)> = vec![
             (TokenType::Percent, "%"),
             (TokenType::Dash, "-"),
+            (TokenType::Tilde, "~"),
             (TokenType::Close, "]}"),
             (TokenType::Hash, "#"),
             (TokenType::Slash, "/"),
             (TokenType::BangUnsecure, "!unsecure"),
             (TokenType::BangInclude, "!include"),
+            (TokenType::BangExtends, "!extends"),
+            (TokenType::BangYield, "!yield"),
+            (TokenType::AtT, "@t"),
+            (TokenType::AtAttr, "@attr"),
             (TokenType::Exclamation, "!"),
             (TokenType::KwIf, "if"),
             (TokenType::KwUnless, "unless"),
@@ -117,8 +179,18 @@ mod tests {
             (TokenType::KwEach, "each"),
             (TokenType::KwAs, "as"),
             (TokenType::Dot, "."),
+            (TokenType::Ellipsis, "..."),
+            (TokenType::DotDot, ".."),
             (TokenType::Comma, ","),
+            (TokenType::LeftBracket, "["),
+            (TokenType::RightBracket, "]"),
             (TokenType::Equal, "="),
+            (TokenType::EqualEqual, "=="),
+            (TokenType::NotEqual, "!="),
+            (TokenType::Less, "<"),
+            (TokenType::LessEqual, "<="),
+            (TokenType::Greater, ">"),
+            (TokenType::GreaterEqual, ">="),
             (TokenType::Question, "?"),
         ];
         for (variant, expected) in cases {
@@ -138,15 +210,12 @@ mod tests {
             TokenType::Text,
             TokenType::Whitespace,
             TokenType::Ident,
+            TokenType::String,
+            TokenType::Integer,
             TokenType::Eof,
         ];
         for variant in dynamic {
-            assert_eq!(
-                variant.literal(),
-                None,
-                "{:?} should return None",
-                variant
-            );
+            assert_eq!(variant.literal(), None, "{:?} should return None", variant);
         }
     }
 }
@@ -1,31 +1,141 @@
 //! Token processor for whitespace control and comment handling.
 //!
 //! Responsibilities:
-//! - Consume DASH tokens and apply trim rules
-//! - Consume comment tags entirely
+//! - Consume DASH and TILDE tokens and apply trim rules, keeping the dashes of
+//!   include tags for the parser
+//! - Apply implicit trims to block tags per [`ParseOptions`]
+//! - Consume comment tags entirely, except `{[%params ...]}` headers
 //! - Detect unclosed comments
+//! - Record each trim for [`crate::trace_trims`]
 
+use crate::lexer::PARAMS_KEYWORD;
 use crate::token::{Token, TokenType};
-use crate::ParseError;
+use crate::{Location, ParseError, ParseOptions};
+use std::fmt;
+use std::ops::Range;
+
+/// A trim requested by whitespace control, as reported by
+/// [`crate::trace_trims`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trim {
+    pub cause: TrimCause,
+    /// Open delimiter of the tag that requested the trim
+    pub tag: Location,
+    /// Whitespace removed; empty when the text beside the tag was not blank
+    /// up to the line boundary, or there was no text to trim
+    pub removed: String,
+    /// Byte range of `removed` in the source, unless the text was rewritten
+    /// by delimiter escapes
+    pub range: Option<Range<usize>>,
+}
+
+/// What requested a [`Trim`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrimCause {
+    /// `{[-` written on the tag
+    LeftMarker,
+    /// `-]}` written on the tag
+    RightMarker,
+    /// `{[~` written on the tag
+    LeftTilde,
+    /// `~]}` written on the tag
+    RightTilde,
+    /// [`ParseOptions::lstrip_blocks`] on a block tag
+    LstripBlocks,
+    /// [`ParseOptions::trim_blocks`] on a block tag
+    TrimBlocks,
+}
+
+impl TrimCause {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TrimCause::LeftMarker => "{[-",
+            TrimCause::RightMarker => "-]}",
+            TrimCause::LeftTilde => "{[~",
+            TrimCause::RightTilde => "~]}",
+            TrimCause::LstripBlocks => "lstrip_blocks",
+            TrimCause::TrimBlocks => "trim_blocks",
+        }
+    }
+
+    /// Whether the trim applies to the text before the tag
+    pub fn is_left(self) -> bool {
+        matches!(
+            self,
+            TrimCause::LeftMarker | TrimCause::LeftTilde | TrimCause::LstripBlocks
+        )
+    }
+}
+
+impl fmt::Display for Trim {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{} {}: ",
+            self.tag.line,
+            self.tag.column,
+            self.cause.as_str()
+        )?;
+        if !self.removed.is_empty() {
+            return write!(f, "removed {:?}", self.removed);
+        }
+        f.write_str(match self.cause {
+            TrimCause::LeftTilde => "nothing removed: no whitespace directly before the tag",
+            TrimCause::RightTilde => "nothing removed: no whitespace directly after the tag",
+            cause if cause.is_left() => {
+                "nothing removed: no blank run between the line start and the tag"
+            }
+            _ => "nothing removed: no blank run between the tag and a line break",
+        })
+    }
+}
 
 /// Process tokens: handle whitespace control and strip comments.
+#[cfg(any(test, feature = "unstable"))]
 pub fn process(tokens: Vec<Token>) -> Result<Vec<Token>, ParseError> {
-    let mut processor = TokenProcessor::new(tokens);
+    process_with_options(tokens, &ParseOptions::default())
+}
+
+/// Process tokens, also trimming around block tags as `options` asks.
+pub fn process_with_options(
+    tokens: Vec<Token>,
+    options: &ParseOptions,
+) -> Result<Vec<Token>, ParseError> {
+    let mut processor = TokenProcessor::new(tokens, options);
     processor.process()
 }
 
-struct TokenProcessor {
+/// Process tokens, returning every trim requested along the way in source
+/// order.
+pub(crate) fn trace_with_options(
+    tokens: Vec<Token>,
+    options: &ParseOptions,
+) -> Result<Vec<Trim>, ParseError> {
+    let mut processor = TokenProcessor::new(tokens, options);
+    processor.process()?;
+    Ok(processor.trims)
+}
+
+struct TokenProcessor<'a> {
     tokens: Vec<Token>,
     result: Vec<Token>,
     strip_next_text: bool,
+    options: &'a ParseOptions,
+    /// Right trims waiting for the next text
+    pending_right: Vec<(TrimCause, Location)>,
+    trims: Vec<Trim>,
 }
 
-impl TokenProcessor {
-    fn new(tokens: Vec<Token>) -> Self {
+impl<'a> TokenProcessor<'a> {
+    fn new(tokens: Vec<Token>, options: &'a ParseOptions) -> Self {
         Self {
             tokens,
             result: Vec::new(),
             strip_next_text: false,
+            options,
+            pending_right: Vec::new(),
+            trims: Vec::new(),
         }
     }
 
@@ -43,6 +153,7 @@ impl TokenProcessor {
             }
         }
 
+        self.record_right_trims("", None);
         Ok(std::mem::take(&mut self.result))
     }
 
@@ -56,7 +167,7 @@ impl TokenProcessor {
         self.apply_left_trim(&tag_tokens);
         self.apply_right_trim(&tag_tokens);
 
-        if comment_tag(&tag_tokens) {
+        if comment_tag(&tag_tokens) && !params_tag(&tag_tokens) {
             if let Some(ci) = close_idx {
                 return Ok(ci + 1);
             }
@@ -78,11 +189,27 @@ impl TokenProcessor {
     }
 
     fn append_text(&mut self, token: Token) {
-        let mut text_value = token.value.clone();
+        let mut text_value = token.value;
+        let mut location = token.location;
 
         if self.strip_next_text {
             self.strip_next_text = false;
-            text_value = strip_leading_whitespace_if_blank_line(&text_value);
+            let aggressive = self
+                .pending_right
+                .iter()
+                .any(|(cause, _)| *cause == TrimCause::RightTilde);
+            let rest = if aggressive {
+                text_value.trim_start_matches(is_trim_whitespace)
+            } else {
+                strip_leading_whitespace_if_blank_line(&text_value)
+            };
+            let stripped = text_value.len() - rest.len();
+            let range = text_value
+                .source_range()
+                .map(|range| range.start..range.start + stripped);
+            self.record_right_trims(&text_value[..stripped], range);
+            location = location.advanced(&text_value[..stripped]);
+            text_value = text_value.slice(stripped..text_value.len());
         }
 
         if text_value.is_empty() {
@@ -90,7 +217,7 @@ impl TokenProcessor {
         }
 
         self.result
-            .push(Token::new(TokenType::Text, text_value, token.location));
+            .push(Token::new(TokenType::Text, text_value, location));
     }
 
     fn find_close_index(&self, start_idx: usize) -> Option<usize> {
@@ -105,38 +232,93 @@ impl TokenProcessor {
     }
 
     fn apply_left_trim(&mut self, tag_tokens: &[Token]) {
-        let is_left_trim = matches!(tag_tokens.first(), Some(t) if t.token_type == TokenType::Dash);
-        if is_left_trim {
-            self.strip_trailing_from_last_text_if_blank_line();
-        }
+        let cause = match tag_tokens.first().map(|t| t.token_type) {
+            Some(TokenType::Dash) => TrimCause::LeftMarker,
+            Some(TokenType::Tilde) => TrimCause::LeftTilde,
+            _ if self.options.lstrip_blocks && block_tag(tag_tokens) => TrimCause::LstripBlocks,
+            _ => return,
+        };
+        let (removed, range) = if cause == TrimCause::LeftTilde {
+            self.strip_trailing_whitespace_from_previous_text()
+        } else {
+            self.strip_trailing_from_last_text_if_blank_line()
+        };
+        self.trims.push(Trim {
+            cause,
+            tag: self.tag_location(tag_tokens),
+            removed,
+            range,
+        });
     }
 
     fn apply_right_trim(&mut self, tag_tokens: &[Token]) {
         let close_idx = tag_tokens
             .iter()
             .position(|token| token.token_type == TokenType::Close);
-        let is_right_trim = matches!(close_idx, Some(ci) if ci > 0 && tag_tokens[ci - 1].token_type == TokenType::Dash);
-        if is_right_trim {
-            self.strip_next_text = true;
+        let marker = close_idx
+            .filter(|ci| *ci > 0)
+            .map(|ci| tag_tokens[ci - 1].token_type);
+        let cause = match marker {
+            Some(TokenType::Dash) => TrimCause::RightMarker,
+            Some(TokenType::Tilde) => TrimCause::RightTilde,
+            _ if close_idx.is_some() && self.options.trim_blocks && block_tag(tag_tokens) => {
+                TrimCause::TrimBlocks
+            }
+            _ => return,
+        };
+        self.strip_next_text = true;
+        let tag = self.tag_location(tag_tokens);
+        self.pending_right.push((cause, tag));
+    }
+
+    /// Record the pending right trims; the first one removed `removed`
+    fn record_right_trims(&mut self, removed: &str, range: Option<Range<usize>>) {
+        let mut removed = Some((removed.to_string(), range));
+        for (cause, tag) in std::mem::take(&mut self.pending_right) {
+            let (removed, range) = removed.take().unwrap_or_default();
+            self.trims.push(Trim {
+                cause,
+                tag,
+                removed,
+                range,
+            });
         }
     }
 
+    /// Location of the open delimiter, which directly precedes the first token
+    fn tag_location(&self, tag_tokens: &[Token]) -> Location {
+        let open = self.options.delimiters.open();
+        let first = tag_tokens.first().map(|t| t.location).unwrap_or_default();
+        Location::new(
+            first.line,
+            first.column.saturating_sub(open.chars().count()),
+            first.byte_offset.saturating_sub(open.len()),
+        )
+    }
+
     fn emit_tag_tokens(&mut self, tag_tokens: &[Token]) {
+        let keep_dashes = include_tag(tag_tokens);
         for token in tag_tokens {
-            if token.token_type == TokenType::Dash {
+            let dropped = match token.token_type {
+                TokenType::Dash => !keep_dashes,
+                TokenType::Tilde => true,
+                _ => false,
+            };
+            if dropped {
                 continue;
             }
             self.result.push(token.clone());
         }
     }
 
-    fn strip_trailing_from_last_text_if_blank_line(&mut self) {
+    /// Returns the removed text and its source range
+    fn strip_trailing_from_last_text_if_blank_line(&mut self) -> (String, Option<Range<usize>>) {
         let last_idx = self
             .result
             .iter()
             .rposition(|token| token.token_type == TokenType::Text);
         let Some(last_idx) = last_idx else {
-            return;
+            return Default::default();
         };
 
         let last_text = &self.result[last_idx];
@@ -144,14 +326,36 @@ impl TokenProcessor {
         let line_start = same_line_start_offset(value);
         let trailing_segment = &value[line_start..];
         if !horizontal_whitespace_only(trailing_segment) {
-            return;
+            return Default::default();
         }
 
-        self.result[last_idx] = Token::new(
-            TokenType::Text,
-            value[..line_start].to_string(),
-            last_text.location,
-        );
+        self.truncate_text(last_idx, line_start)
+    }
+
+    /// Strip all whitespace, line breaks included, from the end of the text
+    /// directly before the tag
+    fn strip_trailing_whitespace_from_previous_text(&mut self) -> (String, Option<Range<usize>>) {
+        let Some(last) = self.result.last() else {
+            return Default::default();
+        };
+        if last.token_type != TokenType::Text {
+            return Default::default();
+        }
+        let keep = last.value.trim_end_matches(is_trim_whitespace).len();
+        self.truncate_text(self.result.len() - 1, keep)
+    }
+
+    /// Cut the text token at `idx` to its first `keep` bytes, returning the
+    /// removed text and its source range
+    fn truncate_text(&mut self, idx: usize, keep: usize) -> (String, Option<Range<usize>>) {
+        let text = &self.result[idx];
+        let value = &text.value;
+        let removed = value[keep..].to_string();
+        let range = value
+            .source_range()
+            .map(|range| range.start + keep..range.end);
+        self.result[idx] = Token::new(TokenType::Text, value.slice(0..keep), text.location);
+        (removed, range)
     }
 }
 
@@ -164,26 +368,72 @@ fn comment_tag(tag_tokens: &[Token]) -> bool {
         return true;
     }
 
-    first.token_type == TokenType::Dash
+    is_trim_marker(first)
         && tag_tokens
             .get(1)
             .is_some_and(|token| token.token_type == TokenType::Percent)
 }
 
+/// `{[%params ...]}`, which the lexer tokenized instead of skipping
+fn params_tag(tag_tokens: &[Token]) -> bool {
+    tag_tokens
+        .iter()
+        .filter(|token| !is_trim_marker(token))
+        .nth(1)
+        .is_some_and(|token| token.token_type == TokenType::Ident && token.value == PARAMS_KEYWORD)
+}
+
+/// Block open/close, `else`/`elseif` and comment tags, but not outputs or includes.
+fn block_tag(tag_tokens: &[Token]) -> bool {
+    tag_tokens
+        .iter()
+        .find(|token| !is_trim_marker(token))
+        .is_some_and(|token| {
+            matches!(
+                token.token_type,
+                TokenType::Hash | TokenType::Slash | TokenType::Percent
+            )
+        })
+}
+
+/// `!include` and `#include` open tags, whose trim markers also apply to
+/// the included partial
+fn include_tag(tag_tokens: &[Token]) -> bool {
+    let mut tokens = tag_tokens
+        .iter()
+        .filter(|token| !is_trim_marker(token) && token.token_type != TokenType::Whitespace);
+    match tokens.next().map(|token| token.token_type) {
+        Some(TokenType::BangInclude) => true,
+        Some(TokenType::Hash) => tokens
+            .next()
+            .is_some_and(|token| token.token_type == TokenType::Ident && token.value == "include"),
+        _ => false,
+    }
+}
+
+fn is_trim_marker(token: &Token) -> bool {
+    matches!(token.token_type, TokenType::Dash | TokenType::Tilde)
+}
+
+/// Whitespace removed by `~`: the characters the tag syntax treats as `WS`
+fn is_trim_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n')
+}
+
 /// Strip leading whitespace/newline only when tag-right side is blank until line end.
-fn strip_leading_whitespace_if_blank_line(text: &str) -> String {
+fn strip_leading_whitespace_if_blank_line(text: &str) -> &str {
     let bytes = text.as_bytes();
     let pos = skip_leading_horizontal_whitespace(bytes);
 
     if pos >= bytes.len() {
-        return String::new();
+        return "";
     }
 
     let Some(advance) = leading_newline_advance(bytes, pos) else {
-        return text.to_string();
+        return text;
     };
 
-    text[(pos + advance)..].to_string()
+    &text[(pos + advance)..]
 }
 
 fn same_line_start_offset(value: &str) -> usize {
@@ -342,4 +592,36 @@ mod tests {
             .unwrap();
         assert_eq!(last_text.value, "  hello");
     }
+
+    #[test]
+    fn test_block_trim_options() {
+        let tokens = || {
+            vec![
+                text_token("<ul>\n  "),
+                tag_token(TokenType::Hash, "#"),
+                tag_token(TokenType::KwEach, "each"),
+                tag_token(TokenType::Close, "]}"),
+                text_token("\n  "),
+                tag_token(TokenType::Ident, "item"),
+                tag_token(TokenType::Close, "]}"),
+                text_token("\n"),
+            ]
+        };
+        let texts = |options| {
+            process_with_options(tokens(), &options)
+                .unwrap()
+                .into_iter()
+                .filter(|token| token.token_type == TokenType::Text)
+                .map(|token| token.value.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(texts(ParseOptions::default()), ["<ul>\n  ", "\n  ", "\n"]);
+        let both = ParseOptions {
+            trim_blocks: true,
+            lstrip_blocks: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(texts(both), ["<ul>\n", "  ", "\n"]);
+    }
 }
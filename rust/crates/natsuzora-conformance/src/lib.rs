@@ -0,0 +1,320 @@
+//! Conformance matrix of the shared spec suites across engine configurations.
+//!
+//! Every case of `tests/*.json` is run by every [`Engine`] that can express
+//! it, and the outcomes are collected into a [`Matrix`] of suites by
+//! engines. A new way of parsing or rendering templates gets a variant here
+//! so that it cannot drift from the spec unnoticed; `tests/matrix.rs` fails
+//! on any case an engine gets wrong.
+//!
+//! ```text
+//! cargo test -p natsuzora-conformance -- --nocapture
+//! ```
+
+use natsuzora::{
+    Natsuzora, NatsuzoraError, RenderOptions, Serialized, StreamRenderer, Translations,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A way of turning a spec case into output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Engine {
+    /// `Natsuzora::render_with_options`, the reference configuration
+    Ast,
+    /// [`Serialized`] data, converted through serde as by `Natsuzora::render_serialize`
+    Serialize,
+    /// Borrowed JSON, converted on first use as by `Natsuzora::render_json`
+    Json,
+    /// Lenient rendering that collects errors instead of stopping at the first
+    CollectedErrors,
+    /// `StreamRenderer` cutting the source into the smallest chunks it can
+    Stream,
+}
+
+impl Engine {
+    pub const ALL: [Engine; 5] = [
+        Engine::Ast,
+        Engine::Serialize,
+        Engine::Json,
+        Engine::CollectedErrors,
+        Engine::Stream,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Engine::Ast => "ast",
+            Engine::Serialize => "serialize",
+            Engine::Json => "json",
+            Engine::CollectedErrors => "collected",
+            Engine::Stream => "stream",
+        }
+    }
+
+    /// Whether the engine supports everything `case` uses
+    pub fn applies(self, case: &Case) -> bool {
+        match self {
+            Engine::Ast | Engine::Serialize | Engine::Json | Engine::CollectedErrors => true,
+            // No loader, catalog or options
+            Engine::Stream => {
+                case.partials.is_none()
+                    && case.translations.is_none()
+                    && case.locale.is_none()
+                    && case.group_separator.is_none()
+                    && case.dynamic_includes.is_empty()
+            }
+        }
+    }
+
+    /// Render `case`, with its partials written under `include_root`
+    fn render(self, case: &Case, include_root: Option<&Path>) -> Result<String, NatsuzoraError> {
+        if self == Engine::Stream {
+            let mut output = Vec::new();
+            StreamRenderer::new().with_chunk_bytes(1).render(
+                case.template.as_bytes(),
+                case.data.clone(),
+                &mut output,
+            )?;
+            return String::from_utf8(output).map_err(|e| NatsuzoraError::TypeError {
+                message: e.to_string(),
+            });
+        }
+
+        let mut tmpl = match include_root {
+            Some(root) => Natsuzora::parse_with_includes(&case.template, root)?,
+            None => Natsuzora::parse(&case.template)?,
+        };
+        if let Some(translations) = &case.translations {
+            tmpl = tmpl.with_translations(Translations::from_json(translations.clone())?);
+        }
+        if let Some(locale) = &case.locale {
+            tmpl = tmpl.with_locale(locale);
+        }
+        let mut options = RenderOptions::new();
+        if let Some(separator) = &case.group_separator {
+            options = options.with_group_separator(separator);
+        }
+        for prefix in &case.dynamic_includes {
+            options = options.with_dynamic_includes_under(prefix);
+        }
+        match self {
+            Engine::Serialize => tmpl
+                .render_builder()
+                .with_options(&options)
+                .render(Serialized(&case.data)),
+            Engine::Json => tmpl
+                .render_builder()
+                .with_options(&options)
+                .render(&case.data),
+            Engine::CollectedErrors => tmpl
+                .render_with_options(case.data.clone(), &options.with_collected_errors())
+                .map_err(|error| match error {
+                    NatsuzoraError::Multiple { mut errors } => errors.remove(0),
+                    error => error,
+                }),
+            _ => tmpl.render_with_options(case.data.clone(), &options),
+        }
+    }
+
+    /// Run `case`, comparing the output or error with the expected one
+    pub fn check(self, case: &Case) -> Outcome {
+        if !self.applies(case) {
+            return Outcome::NotApplicable;
+        }
+        let dir = case.partials.as_ref().map(write_partials);
+        let result = self.render(case, dir.as_ref().map(tempfile::TempDir::path));
+        match (&case.expected, &case.error, result) {
+            (Some(expected), _, Ok(output)) if output == *expected => Outcome::Pass,
+            (Some(expected), _, Ok(output)) => {
+                Outcome::Fail(format!("expected {expected:?}, got {output:?}"))
+            }
+            (Some(expected), _, Err(error)) => {
+                Outcome::Fail(format!("expected {expected:?}, got error {error}"))
+            }
+            (None, Some(kind), Err(error)) if error_matches(&error, kind) => Outcome::Pass,
+            (None, Some(kind), Err(error)) => {
+                Outcome::Fail(format!("expected {kind}, got {error:?}"))
+            }
+            (None, Some(kind), Ok(output)) => {
+                Outcome::Fail(format!("expected {kind}, got output {output:?}"))
+            }
+            (None, None, _) => Outcome::Fail("case has neither expected nor error".to_string()),
+        }
+    }
+}
+
+/// Result of one case on one engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail(String),
+    /// The case uses something the engine does not support
+    NotApplicable,
+}
+
+/// One case of a spec suite, as documented in `tests/README.md`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Case {
+    pub name: String,
+    pub template: String,
+    pub data: serde_json::Value,
+    #[serde(default)]
+    pub expected: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub partials: Option<HashMap<String, String>>,
+    #[serde(default)]
+    pub translations: Option<serde_json::Value>,
+    #[serde(default)]
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub group_separator: Option<String>,
+    #[serde(default)]
+    pub dynamic_includes: Vec<String>,
+}
+
+/// A `tests/*.json` file.
+#[derive(Debug, Clone)]
+pub struct Suite {
+    /// File name, e.g. `basic.json`
+    pub name: String,
+    pub cases: Vec<Case>,
+}
+
+#[derive(Deserialize)]
+struct SuiteFile {
+    tests: Vec<Case>,
+}
+
+/// The shared `tests` directory at the repository root
+pub fn spec_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../tests")
+}
+
+/// Every suite in `dir`, by file name
+pub fn load_suites(dir: &Path) -> Vec<Suite> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("cannot read {}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let content = fs::read_to_string(&path).unwrap();
+            let file: SuiteFile = serde_json::from_str(&content)
+                .unwrap_or_else(|e| panic!("cannot parse {}: {e}", path.display()));
+            Suite {
+                name: path.file_name().unwrap().to_string_lossy().into_owned(),
+                cases: file.tests,
+            }
+        })
+        .collect()
+}
+
+/// Outcomes of every case of `suites` on every engine.
+#[derive(Debug, Clone)]
+pub struct Matrix {
+    /// (suite, case, engine, outcome), in suite and case order
+    pub results: Vec<(String, String, Engine, Outcome)>,
+}
+
+impl Matrix {
+    pub fn run(suites: &[Suite]) -> Self {
+        let mut results = Vec::new();
+        for suite in suites {
+            for case in &suite.cases {
+                for engine in Engine::ALL {
+                    results.push((
+                        suite.name.clone(),
+                        case.name.clone(),
+                        engine,
+                        engine.check(case),
+                    ));
+                }
+            }
+        }
+        Self { results }
+    }
+
+    /// The results that are not passes or skips
+    pub fn failures(&self) -> impl Iterator<Item = &(String, String, Engine, Outcome)> {
+        self.results
+            .iter()
+            .filter(|(_, _, _, outcome)| matches!(outcome, Outcome::Fail(_)))
+    }
+}
+
+/// A table of passed/applicable cases per suite and engine, `-` where no
+/// case applies.
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rows: Vec<(&str, [(usize, usize); Engine::ALL.len()])> = Vec::new();
+        for (suite, _, engine, outcome) in &self.results {
+            if rows.last().map_or(true, |(name, _)| name != suite) {
+                rows.push((suite, Default::default()));
+            }
+            let column = Engine::ALL.iter().position(|e| e == engine).unwrap();
+            let cell = &mut rows.last_mut().unwrap().1[column];
+            match outcome {
+                Outcome::Pass => *cell = (cell.0 + 1, cell.1 + 1),
+                Outcome::Fail(_) => cell.1 += 1,
+                Outcome::NotApplicable => {}
+            }
+        }
+
+        let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        let mut line = format!("{:width$}", "");
+        for engine in Engine::ALL {
+            write!(line, " {:>10}", engine.name())?;
+        }
+        writeln!(f, "{}", line.trim_end())?;
+        for (name, cells) in rows {
+            let mut line = format!("{name:width$}");
+            for (passed, applicable) in cells {
+                let cell = if applicable == 0 {
+                    "-".to_string()
+                } else {
+                    format!("{passed}/{applicable}")
+                };
+                write!(line, " {cell:>10}")?;
+            }
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Write `partials` as include files of a fresh include root
+fn write_partials(partials: &HashMap<String, String>) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().expect("cannot create include root");
+    for (name, content) in partials {
+        let mut segments: Vec<&str> = name.split('/').filter(|s| !s.is_empty()).collect();
+        let file = format!("_{}.ntzr", segments.pop().unwrap_or_default());
+        let path = segments
+            .iter()
+            .fold(dir.path().to_path_buf(), |p, s| p.join(s));
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join(file), content).unwrap();
+    }
+    dir
+}
+
+/// Whether `error` is of the spec error `kind`
+fn error_matches(error: &NatsuzoraError, kind: &str) -> bool {
+    use NatsuzoraError::*;
+    match (error, kind) {
+        (ParseError { .. }, "SyntaxError" | "ParseError") => true,
+        (ParseError { message, .. }, "ReservedWordError") => message.contains("reserved word"),
+        (UndefinedVariable { .. }, "UndefinedVariable") => true,
+        (TypeError { .. }, "TypeError" | "NullValueError" | "EmptyStringError") => true,
+        (ShadowingError { .. }, "ShadowingError") => true,
+        (IncludeError { .. }, "IncludeError") => true,
+        (MissingTranslation { .. }, "MissingTranslation") => true,
+        _ => format!("{error:?}").contains(kind),
+    }
+}
@@ -0,0 +1,28 @@
+//! Every shared spec case on every engine configuration.
+
+use natsuzora_conformance::{load_suites, spec_dir, Engine, Matrix, Outcome};
+
+#[test]
+fn every_engine_conforms() {
+    let matrix = Matrix::run(&load_suites(&spec_dir()));
+    eprintln!("{matrix}");
+
+    let failures: Vec<String> = matrix
+        .failures()
+        .map(|(suite, case, engine, outcome)| {
+            format!("{suite} / {case} on {}: {outcome:?}", engine.name())
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn reference_engine_runs_every_case() {
+    let matrix = Matrix::run(&load_suites(&spec_dir()));
+    assert!(!matrix.results.is_empty());
+    assert!(matrix
+        .results
+        .iter()
+        .filter(|(_, _, engine, _)| *engine == Engine::Ast)
+        .all(|(_, _, _, outcome)| *outcome != Outcome::NotApplicable));
+}
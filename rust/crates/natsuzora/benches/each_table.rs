@@ -0,0 +1,54 @@
+//! Render a 10k-row table, the shape where each-bound paths dominate.
+//!
+//! Run with `cargo bench -p natsuzora --bench each_table`.
+
+use natsuzora::Natsuzora;
+use serde_json::{json, Value};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROWS: usize = 10_000;
+const RUNS: u32 = 20;
+
+const TEMPLATE: &str = "\
+<table>
+{[#each rows as row, i]}
+  <tr class=\"{[#if row.active]}on{[#else]}off{[/if]}\">
+    <td>{[ i ]}</td><td>{[ row.id ]}</td><td>{[ row.name | upper ]}</td>
+    <td>{[ row.email ]}</td><td>{[ row.address.city ]}, {[ row.address.zip ]}</td>
+    <td>{[#each row.tags as tag]}{[ tag ]} {[/each]}</td>
+  </tr>
+{[/each]}
+</table>
+";
+
+fn fixture() -> Value {
+    let rows: Vec<Value> = (0..ROWS)
+        .map(|n| {
+            json!({
+                "id": n,
+                "active": n % 3 != 0,
+                "name": format!("user {n}"),
+                "email": format!("user{n}@example.com"),
+                "address": {"city": "Tokyo", "zip": format!("{:07}", n)},
+                "tags": ["a", "b", "c"],
+            })
+        })
+        .collect();
+    json!({ "rows": rows })
+}
+
+fn main() {
+    let template = Natsuzora::parse(TEMPLATE).unwrap();
+    let data = fixture();
+    let bytes = template.render(data.clone()).unwrap().len();
+
+    let mut best = Duration::MAX;
+    for _ in 0..RUNS {
+        let data = data.clone();
+        let started = Instant::now();
+        black_box(template.render(data).unwrap());
+        best = best.min(started.elapsed());
+    }
+    println!("each_table: {ROWS} rows, {bytes} bytes, best of {RUNS}: {best:?}");
+}
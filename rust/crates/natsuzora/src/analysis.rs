@@ -0,0 +1,502 @@
+//! Static analysis of templates and template directories.
+//!
+//! [`TemplateInfo`] summarizes what a single template needs from its data
+//! and which partials it includes. [`TemplateSet`] loads every `.ntzr` file
+//! under a directory and links them into an include graph, which can be
+//! exported to Graphviz ([`TemplateSet::to_dot`]) or Mermaid
+//! ([`TemplateSet::to_mermaid`]).
+
+use crate::error::{Location, NatsuzoraError, Result};
+use natsuzora_ast::{AstNode, Condition, Operand, Path as AstPath, Template};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An `{[!unsecure ... ]}` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsecureUse {
+    pub path: String,
+    pub location: Location,
+}
+
+/// What a template reads from its data and which partials it includes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateInfo {
+    /// Dotted paths read from the data, excluding `each` loop variables
+    pub required_variables: BTreeSet<String>,
+    /// Names of included partials and of the layout the template extends
+    pub includes: BTreeSet<String>,
+    pub unsecure: Vec<UnsecureUse>,
+    /// Message keys of `{[@t key ]}` tags
+    pub translation_keys: BTreeSet<String>,
+}
+
+impl TemplateInfo {
+    /// Analyze a parsed template
+    pub fn analyze(template: &Template) -> Self {
+        let mut info = Self::default();
+        info.visit(template.nodes(), &mut Vec::new());
+        info
+    }
+
+    fn visit(&mut self, nodes: &[AstNode], bound: &mut Vec<String>) {
+        for node in nodes {
+            match node {
+                AstNode::Variable(n) => self.require(&n.path, bound),
+                AstNode::Attr(n) => self.require(&n.path, bound),
+                AstNode::Unsecure(n) => {
+                    self.require(&n.path, bound);
+                    self.unsecure.push(UnsecureUse {
+                        path: n.path.as_str(),
+                        location: n.location,
+                    });
+                }
+                AstNode::If(n) => {
+                    self.require_all(&n.condition, bound);
+                    self.visit(&n.then_branch, bound);
+                    for branch in &n.elseif_branches {
+                        self.require_all(&branch.condition, bound);
+                        self.visit(&branch.body, bound);
+                    }
+                    if let Some(else_branch) = &n.else_branch {
+                        self.visit(else_branch, bound);
+                    }
+                }
+                AstNode::Unless(n) => {
+                    self.require_all(&n.condition, bound);
+                    self.visit(&n.body, bound);
+                    if let Some(else_branch) = &n.else_branch {
+                        self.visit(else_branch, bound);
+                    }
+                }
+                AstNode::Each(n) => {
+                    for path in n.collection.paths().chain(n.window_paths()) {
+                        self.require(path, bound);
+                    }
+                    let depth = bound.len();
+                    bound.extend(n.bindings().map(String::from));
+                    self.visit(&n.body, bound);
+                    bound.truncate(depth);
+                    if let Some(else_branch) = &n.else_branch {
+                        self.visit(else_branch, bound);
+                    }
+                }
+                AstNode::Cache(n) => {
+                    self.require(&n.key, bound);
+                    self.visit(&n.body, bound);
+                }
+                AstNode::Include(n) => {
+                    for arg in &n.args {
+                        if let Operand::Path(path) = &arg.value {
+                            self.require(path, bound);
+                        }
+                    }
+                    if let Some(spread) = &n.spread {
+                        self.require(spread, bound);
+                    }
+                    match &n.source {
+                        Some(source) => self.require(source, bound),
+                        None => {
+                            self.includes.insert(n.name.clone());
+                        }
+                    }
+                    if let Some(body) = &n.body {
+                        self.visit(body, bound);
+                    }
+                }
+                AstNode::Translate(n) => {
+                    for arg in &n.args {
+                        if let Operand::Path(path) = &arg.value {
+                            self.require(path, bound);
+                        }
+                    }
+                    self.translation_keys.insert(n.key.clone());
+                }
+                AstNode::Block(n) => self.visit(&n.body, bound),
+                AstNode::Extends(n) => {
+                    self.includes.insert(n.name.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn require(&mut self, path: &AstPath, bound: &[String]) {
+        let is_bound = path
+            .segments()
+            .first()
+            .is_some_and(|first| bound.contains(first));
+        if !is_bound {
+            self.required_variables.insert(path.as_str());
+        }
+    }
+
+    fn require_all(&mut self, condition: &Condition, bound: &[String]) {
+        for path in condition.paths() {
+            self.require(path, bound);
+        }
+    }
+}
+
+/// A template file loaded into a [`TemplateSet`].
+#[derive(Debug, Clone)]
+pub struct TemplateEntry {
+    /// Include name for partials (`/components/card`), relative path otherwise
+    pub name: String,
+    /// Path relative to the set's root directory
+    pub path: PathBuf,
+    pub source: String,
+    pub template: Template,
+    pub info: TemplateInfo,
+}
+
+impl TemplateEntry {
+    /// Whether the file is a partial (its file name starts with `_`)
+    pub fn is_partial(&self) -> bool {
+        self.name.starts_with('/')
+    }
+}
+
+/// All templates under a directory, linked by their includes.
+#[derive(Debug, Clone)]
+pub struct TemplateSet {
+    root: PathBuf,
+    entries: BTreeMap<String, TemplateEntry>,
+}
+
+impl TemplateSet {
+    /// Load and parse every `.ntzr` file under `root`
+    pub fn load_dir(root: impl AsRef<Path>) -> Result<Self> {
+        let (set, failures) = Self::load_dir_lenient(root)?;
+        match failures.into_iter().next() {
+            Some((path, e)) => Err(NatsuzoraError::ParseError {
+                message: format!("{}: {e}", path.display()),
+                location: Location::default(),
+            }),
+            None => Ok(set),
+        }
+    }
+
+    /// Like [`Self::load_dir`], but leaves out files that fail to parse and
+    /// returns their errors by relative path
+    pub(crate) fn load_dir_lenient(
+        root: impl AsRef<Path>,
+    ) -> Result<(Self, Vec<(PathBuf, natsuzora_ast::ParseError)>)> {
+        let root = root.as_ref().to_path_buf();
+        let mut files = Vec::new();
+        collect_template_files(&root, &mut files)?;
+        files.sort();
+
+        let mut entries = BTreeMap::new();
+        let mut failures = Vec::new();
+        for file in files {
+            let path = file.strip_prefix(&root).unwrap_or(&file).to_path_buf();
+            let source = fs::read_to_string(&file)?;
+            let template = match natsuzora_ast::parse(&source) {
+                Ok(template) => template,
+                Err(e) => {
+                    failures.push((path, e));
+                    continue;
+                }
+            };
+            let name = template_name(&path);
+            let info = TemplateInfo::analyze(&template);
+            entries.insert(
+                name.clone(),
+                TemplateEntry {
+                    name,
+                    path,
+                    source,
+                    template,
+                    info,
+                },
+            );
+        }
+
+        Ok((Self { root, entries }, failures))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TemplateEntry> {
+        self.entries.get(name)
+    }
+
+    /// Templates ordered by name
+    pub fn iter(&self) -> impl Iterator<Item = &TemplateEntry> {
+        self.entries.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Names of the templates that include `name`
+    pub fn used_by(&self, name: &str) -> Vec<&str> {
+        self.entries
+            .values()
+            .filter(|entry| entry.info.includes.contains(name))
+            .map(|entry| entry.name.as_str())
+            .collect()
+    }
+
+    /// Whether `name` includes itself directly or through other templates
+    pub fn is_cyclic(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|entry| {
+            entry
+                .info
+                .includes
+                .iter()
+                .any(|next| self.reaches(next, name))
+        })
+    }
+
+    /// Graphviz DOT of the include graph
+    ///
+    /// Nodes are labeled with the template size, templates with unsecure
+    /// output are filled, includes of missing templates are dashed and edges
+    /// on an include cycle are red.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph templates {\n    node [shape=box];\n");
+        for entry in self.entries.values() {
+            let mut attrs = format!(
+                "label=\"{}\\n{} bytes\"",
+                dot_escape(&entry.name),
+                entry.source.len()
+            );
+            if !entry.info.unsecure.is_empty() {
+                attrs.push_str(", style=filled, fillcolor=\"#ffe0e0\"");
+            }
+            let _ = writeln!(out, "    \"{}\" [{attrs}];", dot_escape(&entry.name));
+        }
+        for name in self.missing_includes() {
+            let _ = writeln!(out, "    \"{}\" [style=dashed];", dot_escape(name));
+        }
+        for (from, to) in self.edges() {
+            let attrs = if self.on_cycle(from, to) {
+                " [color=red]"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "    \"{}\" -> \"{}\"{attrs};",
+                dot_escape(from),
+                dot_escape(to)
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Mermaid flowchart of the include graph, annotated like [`Self::to_dot`]
+    pub fn to_mermaid(&self) -> String {
+        let mut ids = BTreeMap::new();
+        let mut out = String::from("flowchart LR\n");
+        for entry in self.entries.values() {
+            let id = format!("n{}", ids.len());
+            let _ = write!(
+                out,
+                "    {id}[\"{}<br/>{} bytes\"]",
+                mermaid_escape(&entry.name),
+                entry.source.len()
+            );
+            if !entry.info.unsecure.is_empty() {
+                out.push_str(":::unsecure");
+            }
+            out.push('\n');
+            ids.insert(entry.name.as_str(), id);
+        }
+        for name in self.missing_includes() {
+            let id = format!("n{}", ids.len());
+            let _ = writeln!(out, "    {id}[\"{}\"]:::missing", mermaid_escape(name));
+            ids.insert(name, id);
+        }
+        let mut cycle_links = Vec::new();
+        for (index, (from, to)) in self.edges().enumerate() {
+            let _ = writeln!(out, "    {} --> {}", ids[from], ids[to]);
+            if self.on_cycle(from, to) {
+                cycle_links.push(index.to_string());
+            }
+        }
+        out.push_str("    classDef unsecure fill:#ffe0e0\n");
+        out.push_str("    classDef missing stroke-dasharray: 5 5\n");
+        if !cycle_links.is_empty() {
+            let _ = writeln!(out, "    linkStyle {} stroke:red", cycle_links.join(","));
+        }
+        out
+    }
+
+    pub(crate) fn edges(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.values().flat_map(|entry| {
+            entry
+                .info
+                .includes
+                .iter()
+                .map(move |to| (entry.name.as_str(), to.as_str()))
+        })
+    }
+
+    /// Included names that have no template in the set
+    fn missing_includes(&self) -> BTreeSet<&str> {
+        self.edges()
+            .map(|(_, to)| to)
+            .filter(|to| !self.entries.contains_key(*to))
+            .collect()
+    }
+
+    pub(crate) fn on_cycle(&self, from: &str, to: &str) -> bool {
+        self.reaches(to, from)
+    }
+
+    /// Whether `to` is reachable from `from` by following includes
+    fn reaches(&self, from: &str, to: &str) -> bool {
+        let mut stack = vec![from];
+        let mut seen = BTreeSet::new();
+        while let Some(name) = stack.pop() {
+            if name == to {
+                return true;
+            }
+            if !seen.insert(name) {
+                continue;
+            }
+            if let Some(entry) = self.entries.get(name) {
+                stack.extend(entry.info.includes.iter().map(String::as_str));
+            }
+        }
+        false
+    }
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+fn collect_template_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_template_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "ntzr") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// `components/_card.ntzr` → `/components/card`; other files keep their path.
+pub(crate) fn template_name(path: &Path) -> String {
+    let segments: Vec<String> = path
+        .iter()
+        .map(|segment| segment.to_string_lossy().into_owned())
+        .collect();
+    let Some((file, dirs)) = segments.split_last() else {
+        return String::new();
+    };
+    match file.strip_prefix('_').and_then(|f| f.strip_suffix(".ntzr")) {
+        Some(stem) => {
+            let mut name = String::new();
+            for dir in dirs {
+                name.push('/');
+                name.push_str(dir);
+            }
+            name.push('/');
+            name.push_str(stem);
+            name
+        }
+        None => segments.join("/"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_variables_exclude_loop_bindings() {
+        let template = natsuzora_ast::parse(
+            "{[ title ]}{[#each posts as post]}{[ post.title ]}{[ site.name ]}{[/each]}{[!unsecure body ]}{[!include /card item=featured ]}",
+        )
+        .unwrap();
+        let info = TemplateInfo::analyze(&template);
+        let required: Vec<&str> = info.required_variables.iter().map(String::as_str).collect();
+        assert_eq!(
+            required,
+            vec!["body", "featured", "posts", "site.name", "title"]
+        );
+        assert!(info.includes.contains("/card"));
+        assert_eq!(info.unsecure[0].path, "body");
+    }
+
+    fn load(files: &[(&str, &str)]) -> (tempfile::TempDir, TemplateSet) {
+        let dir = tempfile::TempDir::new().unwrap();
+        for (path, source) in files {
+            let file = dir.path().join(path);
+            fs::create_dir_all(file.parent().unwrap()).unwrap();
+            fs::write(file, source).unwrap();
+        }
+        let set = TemplateSet::load_dir(dir.path()).unwrap();
+        (dir, set)
+    }
+
+    #[test]
+    fn test_graph_exports() {
+        let (_dir, set) = load(&[
+            ("index.ntzr", "{[!include /a ]}{[!include /gone ]}"),
+            ("_a.ntzr", "{[!unsecure html ]}{[!include /b ]}"),
+            ("_b.ntzr", "{[!include /a ]}"),
+        ]);
+        assert!(set.is_cyclic("/a"));
+        assert!(!set.is_cyclic("index.ntzr"));
+        assert_eq!(set.used_by("/a"), vec!["/b", "index.ntzr"]);
+
+        let dot = set.to_dot();
+        assert!(dot.starts_with("digraph templates {\n"));
+        assert!(
+            dot.contains("\"/a\" [label=\"/a\\n35 bytes\", style=filled, fillcolor=\"#ffe0e0\"];")
+        );
+        assert!(dot.contains("\"/gone\" [style=dashed];"));
+        assert!(dot.contains("\"/a\" -> \"/b\" [color=red];"));
+        assert!(dot.contains("\"index.ntzr\" -> \"/a\";\n"));
+
+        let mermaid = set.to_mermaid();
+        assert_eq!(
+            mermaid,
+            "flowchart LR\n\
+             \x20   n0[\"/a<br/>35 bytes\"]:::unsecure\n\
+             \x20   n1[\"/b<br/>16 bytes\"]\n\
+             \x20   n2[\"index.ntzr<br/>35 bytes\"]\n\
+             \x20   n3[\"/gone\"]:::missing\n\
+             \x20   n0 --> n1\n\
+             \x20   n1 --> n0\n\
+             \x20   n2 --> n0\n\
+             \x20   n2 --> n3\n\
+             \x20   classDef unsecure fill:#ffe0e0\n\
+             \x20   classDef missing stroke-dasharray: 5 5\n\
+             \x20   linkStyle 0,1 stroke:red\n"
+        );
+    }
+
+    #[test]
+    fn test_template_names() {
+        assert_eq!(
+            template_name(Path::new("components/_card.ntzr")),
+            "/components/card"
+        );
+        assert_eq!(
+            template_name(Path::new("pages/index.ntzr")),
+            "pages/index.ntzr"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//! Structured audit records of renders.
+//!
+//! With [`Natsuzora::with_audit_sink`], every render emits one
+//! [`AuditEvent`] to an [`AuditSink`], whether it succeeds or fails. The
+//! event identifies what was rendered without holding the template or the
+//! data: both are recorded as hashes. [`AuditEvent::to_json`] gives a
+//! schema-versioned record for an audit log:
+//!
+//! ```rust,ignore
+//! let tmpl = Natsuzora::parse_with_includes(source, "templates")?
+//!     .with_audit_sink("invoice", |event: &AuditEvent| {
+//!         log::info!(target: "audit", "{}", event.to_json());
+//!     });
+//! ```
+//!
+//! [`Natsuzora::with_audit_sink`]: crate::Natsuzora::with_audit_sink
+
+use crate::error::NatsuzoraError;
+use crate::options::RenderOptions;
+use crate::version::VERSION;
+use serde_json::json;
+use std::time::Duration;
+
+/// Version of the JSON layout produced by [`AuditEvent::to_json`]
+///
+/// Bumped whenever a field is removed or changes meaning; new fields may be
+/// added without a bump.
+pub const AUDIT_SCHEMA_VERSION: u32 = 1;
+
+/// Receives the audit event of every render.
+///
+/// Implemented for closures taking the event.
+pub trait AuditSink: Send + Sync {
+    fn record(&self, event: &AuditEvent);
+}
+
+impl<F> AuditSink for F
+where
+    F: Fn(&AuditEvent) + Send + Sync,
+{
+    fn record(&self, event: &AuditEvent) {
+        self(event)
+    }
+}
+
+/// What one render used and how it ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEvent {
+    /// Name given to [`Natsuzora::with_audit_sink`](crate::Natsuzora::with_audit_sink)
+    pub template: String,
+    /// Hash of the template source, see [`stable_hash`]
+    pub template_hash: String,
+    /// Hash of the data serialized as JSON with sorted keys
+    pub data_hash: String,
+    pub options: AuditOptions,
+    pub duration: Duration,
+    /// [`NatsuzoraError::code`] of the failure, `None` on success
+    pub error_code: Option<&'static str>,
+}
+
+/// The render options recorded in an [`AuditEvent`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditOptions {
+    pub truncate_bytes: Option<usize>,
+    pub collect_errors: bool,
+    /// Paths with a fallback value, sorted
+    pub fallbacks: Vec<String>,
+    pub themes: Vec<String>,
+    pub locale: Option<String>,
+}
+
+impl AuditOptions {
+    pub(crate) fn new(
+        options: Option<&RenderOptions>,
+        themes: &[String],
+        locale: Option<&str>,
+    ) -> Self {
+        let mut audit = Self {
+            themes: themes.to_vec(),
+            locale: locale.map(str::to_string),
+            ..Self::default()
+        };
+        if let Some(options) = options {
+            audit.truncate_bytes = options.truncation().map(|(max_bytes, _)| max_bytes);
+            audit.collect_errors = options.collect_errors();
+            audit.fallbacks = options.fallback_paths();
+        }
+        audit
+    }
+}
+
+impl AuditEvent {
+    /// The event as a JSON record
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "schema_version": AUDIT_SCHEMA_VERSION,
+            "natsuzora_version": VERSION,
+            "template": self.template,
+            "template_hash": self.template_hash,
+            "data_hash": self.data_hash,
+            "options": {
+                "truncate_bytes": self.options.truncate_bytes,
+                "collect_errors": self.options.collect_errors,
+                "fallbacks": self.options.fallbacks,
+                "themes": self.options.themes,
+                "locale": self.options.locale,
+            },
+            "duration_us": u64::try_from(self.duration.as_micros()).unwrap_or(u64::MAX),
+            "error_code": self.error_code,
+        })
+    }
+}
+
+/// 64-bit FNV-1a hash of `bytes` as 16 lowercase hex digits
+///
+/// Stable across platforms and releases, so records from different hosts
+/// can be compared. It identifies inputs; it is not a cryptographic digest.
+pub fn stable_hash(bytes: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+/// `value` as compact JSON text with the keys of every object sorted, so
+/// data hashes do not depend on the order entries were inserted in
+pub(crate) fn sorted_json(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Object(object) => {
+            let mut entries: Vec<_> = object.iter().collect();
+            entries.sort_by_key(|(key, _)| *key);
+            let entries: Vec<String> = entries
+                .into_iter()
+                .map(|(key, value)| format!("{}:{}", json!(key), sorted_json(value)))
+                .collect();
+            format!("{{{}}}", entries.join(","))
+        }
+        serde_json::Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(sorted_json).collect();
+            format!("[{}]", items.join(","))
+        }
+        scalar => scalar.to_string(),
+    }
+}
+
+pub(crate) fn error_code(result: &Result<impl Sized, NatsuzoraError>) -> Option<&'static str> {
+    result.as_ref().err().map(NatsuzoraError::code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Natsuzora;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_audit_event_per_render() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let tmpl = Natsuzora::parse("{[ name! ]}").unwrap().with_audit_sink(
+            "greeting",
+            move |event: &AuditEvent| {
+                sink.lock().unwrap().push(event.clone());
+            },
+        );
+
+        tmpl.render(json!({"name": "a", "id": 1})).unwrap();
+        tmpl.render(json!({"id": 1, "name": "a"})).unwrap();
+        let options = RenderOptions::new().with_truncation(10, "…");
+        assert!(tmpl.render_with_options(json!({}), &options).is_err());
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].template, "greeting");
+        assert_eq!(events[0].template_hash, stable_hash(b"{[ name! ]}"));
+        assert_eq!(events[0].data_hash, events[1].data_hash);
+        assert_ne!(events[0].data_hash, events[2].data_hash);
+        assert_eq!(events[0].error_code, None);
+        assert_eq!(events[2].error_code, Some("undefined_variable"));
+        assert_eq!(events[2].options.truncate_bytes, Some(10));
+
+        let record = events[2].to_json();
+        assert_eq!(record["schema_version"], AUDIT_SCHEMA_VERSION);
+        assert_eq!(record["error_code"], "undefined_variable");
+        assert_eq!(record["options"]["truncate_bytes"], 10);
+    }
+
+    #[test]
+    fn test_stable_hash() {
+        assert_eq!(stable_hash(b""), "cbf29ce484222325");
+        assert_eq!(stable_hash(b"a"), "af63dc4c8601ec8c");
+    }
+}
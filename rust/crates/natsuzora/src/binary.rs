@@ -0,0 +1,434 @@
+//! Render data in binary formats: MessagePack (feature `msgpack`) and CBOR
+//! (feature `cbor`).
+//!
+//! Both decode straight into [`Value`], so binary data from another process
+//! never has to be re-encoded as JSON. They accept what JSON can express:
+//! `nil`/`null`, booleans, numbers, text, arrays and maps. Map keys may be
+//! text, integers or booleans, which become their text as in
+//! [`Natsuzora::render_serialize`](crate::Natsuzora::render_serialize).
+//! Byte strings and extension types are type errors, as are trailing bytes.
+
+use crate::error::{NatsuzoraError, Result};
+use crate::value::{IndexMap, IntegerPolicy, IntegerRange, Value};
+
+/// Nesting depth beyond which data is rejected, as `serde_json` does
+const MAX_DEPTH: usize = 128;
+
+/// Cursor over the encoded bytes, with what a decoded number becomes
+struct Reader<'b> {
+    bytes: &'b [u8],
+    format: &'static str,
+    range: IntegerRange,
+    policy: IntegerPolicy,
+    depth: usize,
+}
+
+impl<'b> Reader<'b> {
+    fn new(
+        bytes: &'b [u8],
+        format: &'static str,
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    ) -> Self {
+        Self {
+            bytes,
+            format,
+            range,
+            policy,
+            depth: 0,
+        }
+    }
+
+    fn invalid(&self, message: impl std::fmt::Display) -> NatsuzoraError {
+        NatsuzoraError::TypeError {
+            message: format!("Invalid {} data: {message}", self.format),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'b [u8]> {
+        if self.bytes.len() < len {
+            return Err(self.invalid("unexpected end of data"));
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    /// A big-endian unsigned integer of `len` bytes
+    fn uint(&mut self, len: usize) -> Result<u64> {
+        Ok(self
+            .take(len)?
+            .iter()
+            .fold(0, |n, byte| (n << 8) | u64::from(*byte)))
+    }
+
+    fn text(&mut self, len: u64) -> Result<String> {
+        let len = usize::try_from(len).map_err(|_| self.invalid("text too long"))?;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| self.invalid("text is not UTF-8"))
+    }
+
+    fn integer(&self, wide: i128) -> Result<Value> {
+        Value::from_integer(wide, &wide, self.range, self.policy)
+    }
+
+    fn float(&self, f: f64) -> Result<Value> {
+        // As `render_serialize` does, since JSON has no such numbers
+        if !f.is_finite() {
+            return Ok(Value::Null);
+        }
+        Value::from_number(f, &f, self.range, self.policy)
+    }
+
+    /// Text of a map key
+    fn key(&self, key: Value) -> Result<String> {
+        match key {
+            Value::String(key) => Ok(key),
+            Value::Integer(n) => Ok(n.to_string()),
+            Value::Bool(b) => Ok(b.to_string()),
+            key => Err(self.invalid(format!("{} map key", key.type_name()))),
+        }
+    }
+
+    fn enter(&mut self) -> Result<()> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.invalid("nested too deeply"));
+        }
+        Ok(())
+    }
+
+    fn finish(self, value: Value) -> Result<Value> {
+        if !self.bytes.is_empty() {
+            return Err(self.invalid("trailing bytes"));
+        }
+        Ok(value)
+    }
+}
+
+/// Decode one MessagePack value filling `bytes`
+#[cfg(feature = "msgpack")]
+pub(crate) fn msgpack(bytes: &[u8], range: IntegerRange, policy: IntegerPolicy) -> Result<Value> {
+    let mut reader = Reader::new(bytes, "MessagePack", range, policy);
+    let value = msgpack_value(&mut reader)?;
+    reader.finish(value)
+}
+
+#[cfg(feature = "msgpack")]
+fn msgpack_value(reader: &mut Reader) -> Result<Value> {
+    let marker = reader.byte()?;
+    match marker {
+        0x00..=0x7f => reader.integer(i128::from(marker)),
+        0x80..=0x8f => msgpack_map(reader, u64::from(marker & 0x0f)),
+        0x90..=0x9f => msgpack_array(reader, u64::from(marker & 0x0f)),
+        0xa0..=0xbf => reader.text(u64::from(marker & 0x1f)).map(Value::String),
+        0xc0 => Ok(Value::Null),
+        0xc2 => Ok(Value::Bool(false)),
+        0xc3 => Ok(Value::Bool(true)),
+        0xca => {
+            let n = f32::from_be_bytes(reader.array()?);
+            reader.float(f64::from(n))
+        }
+        0xcb => {
+            let n = f64::from_be_bytes(reader.array()?);
+            reader.float(n)
+        }
+        0xcc..=0xcf => {
+            let n = reader.uint(1 << (marker - 0xcc))?;
+            reader.integer(i128::from(n))
+        }
+        0xd0 => {
+            let n = i8::from_be_bytes(reader.array()?);
+            reader.integer(i128::from(n))
+        }
+        0xd1 => {
+            let n = i16::from_be_bytes(reader.array()?);
+            reader.integer(i128::from(n))
+        }
+        0xd2 => {
+            let n = i32::from_be_bytes(reader.array()?);
+            reader.integer(i128::from(n))
+        }
+        0xd3 => {
+            let n = i64::from_be_bytes(reader.array()?);
+            reader.integer(i128::from(n))
+        }
+        0xd9..=0xdb => {
+            let len = reader.uint(1 << (marker - 0xd9))?;
+            reader.text(len).map(Value::String)
+        }
+        0xdc | 0xdd => {
+            let len = reader.uint(2 << (marker - 0xdc))?;
+            msgpack_array(reader, len)
+        }
+        0xde | 0xdf => {
+            let len = reader.uint(2 << (marker - 0xde))?;
+            msgpack_map(reader, len)
+        }
+        0xe0..=0xff => reader.integer(i128::from(marker as i8)),
+        0xc4..=0xc6 => Err(reader.invalid("binary values are not supported")),
+        0xc7..=0xc9 | 0xd4..=0xd8 => Err(reader.invalid("extension types are not supported")),
+        0xc1 => Err(reader.invalid("reserved marker 0xc1")),
+    }
+}
+
+#[cfg(feature = "msgpack")]
+fn msgpack_array(reader: &mut Reader, len: u64) -> Result<Value> {
+    reader.enter()?;
+    let items = (0..len)
+        .map(|_| msgpack_value(reader))
+        .collect::<Result<_>>()?;
+    reader.depth -= 1;
+    Ok(Value::Array(items))
+}
+
+#[cfg(feature = "msgpack")]
+fn msgpack_map(reader: &mut Reader, len: u64) -> Result<Value> {
+    reader.enter()?;
+    let mut entries = IndexMap::new();
+    for _ in 0..len {
+        let key = msgpack_value(reader)?;
+        let key = reader.key(key)?;
+        entries.insert(key, msgpack_value(reader)?);
+    }
+    reader.depth -= 1;
+    Ok(Value::Object(entries))
+}
+
+/// Decode one CBOR data item filling `bytes`
+#[cfg(feature = "cbor")]
+pub(crate) fn cbor(bytes: &[u8], range: IntegerRange, policy: IntegerPolicy) -> Result<Value> {
+    let mut reader = Reader::new(bytes, "CBOR", range, policy);
+    let value = match cbor_item(&mut reader)? {
+        Some(value) => value,
+        None => return Err(reader.invalid("unexpected break")),
+    };
+    reader.finish(value)
+}
+
+/// The next data item, or `None` for the break ending an indefinite-length
+/// item
+#[cfg(feature = "cbor")]
+fn cbor_item(reader: &mut Reader) -> Result<Option<Value>> {
+    let initial = reader.byte()?;
+    let (major, info) = (initial >> 5, initial & 0x1f);
+    if major == 7 {
+        return cbor_simple(reader, info);
+    }
+    // `None` for an indefinite length
+    let argument = match info {
+        0..=23 => Some(u64::from(info)),
+        24..=27 => Some(reader.uint(1 << (info - 24))?),
+        31 if matches!(major, 2..=5) => None,
+        _ => return Err(reader.invalid(format!("reserved additional information {info}"))),
+    };
+    let value = match (major, argument) {
+        (0, Some(n)) => reader.integer(i128::from(n))?,
+        (1, Some(n)) => reader.integer(-1 - i128::from(n))?,
+        (2, _) => return Err(reader.invalid("byte strings are not supported")),
+        (3, Some(len)) => Value::String(reader.text(len)?),
+        (3, None) => Value::String(cbor_chunks(reader)?),
+        (4, len) => {
+            reader.enter()?;
+            let mut items = Vec::new();
+            while len.map_or(true, |len| (items.len() as u64) < len) {
+                match cbor_item(reader)? {
+                    Some(item) => items.push(item),
+                    None if len.is_none() => break,
+                    None => return Err(reader.invalid("unexpected break")),
+                }
+            }
+            reader.depth -= 1;
+            Value::Array(items)
+        }
+        (5, len) => {
+            reader.enter()?;
+            let mut entries = IndexMap::new();
+            let mut count = 0;
+            while len.map_or(true, |len| count < len) {
+                let key = match cbor_item(reader)? {
+                    Some(key) => reader.key(key)?,
+                    None if len.is_none() => break,
+                    None => return Err(reader.invalid("unexpected break")),
+                };
+                match cbor_item(reader)? {
+                    Some(value) => entries.insert(key, value),
+                    None => return Err(reader.invalid("unexpected break")),
+                };
+                count += 1;
+            }
+            reader.depth -= 1;
+            Value::Object(entries)
+        }
+        // Tags only annotate the item, e.g. a date string stays a string
+        (6, _) => {
+            reader.enter()?;
+            let value = match cbor_item(reader)? {
+                Some(value) => value,
+                None => return Err(reader.invalid("unexpected break")),
+            };
+            reader.depth -= 1;
+            value
+        }
+        _ => unreachable!("major types 0 to 6 have a length"),
+    };
+    Ok(Some(value))
+}
+
+/// The chunks of an indefinite-length text string, joined. Each chunk must
+/// be a definite-length text string (RFC 8949 §3.2.3).
+#[cfg(feature = "cbor")]
+fn cbor_chunks(reader: &mut Reader) -> Result<String> {
+    let mut text = String::new();
+    loop {
+        let initial = reader.byte()?;
+        let len = match (initial >> 5, initial & 0x1f) {
+            (7, 31) => return Ok(text),
+            (3, info @ 0..=23) => u64::from(info),
+            (3, info @ 24..=27) => reader.uint(1 << (info - 24))?,
+            _ => return Err(reader.invalid("text chunk is not definite-length text")),
+        };
+        text.push_str(&reader.text(len)?);
+    }
+}
+
+#[cfg(feature = "cbor")]
+fn cbor_simple(reader: &mut Reader, info: u8) -> Result<Option<Value>> {
+    let value = match info {
+        20 => Value::Bool(false),
+        21 => Value::Bool(true),
+        22 | 23 => Value::Null,
+        25 => {
+            let n = u16::from_be_bytes(reader.array()?);
+            reader.float(half_to_f64(n))?
+        }
+        26 => {
+            let n = f32::from_be_bytes(reader.array()?);
+            reader.float(f64::from(n))?
+        }
+        27 => {
+            let n = f64::from_be_bytes(reader.array()?);
+            reader.float(n)?
+        }
+        31 => return Ok(None),
+        _ => return Err(reader.invalid(format!("unsupported simple value {info}"))),
+    };
+    Ok(Some(value))
+}
+
+/// An IEEE 754 half-precision float
+#[cfg(feature = "cbor")]
+fn half_to_f64(half: u16) -> f64 {
+    let sign = if half & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = i32::from((half >> 10) & 0x1f);
+    let fraction = f64::from(half & 0x03ff);
+    sign * match exponent {
+        0 => fraction * 2f64.powi(-24),
+        31 if fraction == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (1.0 + fraction / 1024.0) * 2f64.powi(exponent - 15),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json(value: serde_json::Value) -> Value {
+        Value::from_json(value).unwrap()
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack() {
+        let decode = |bytes: &[u8]| msgpack(bytes, IntegerRange::Safe, IntegerPolicy::Error);
+        // {"name": "Ann", "tags": [1, -1, 300], "ok": true, "x": nil, "f": 1.5}
+        let bytes = [
+            0x85, 0xa4, b'n', b'a', b'm', b'e', 0xa3, b'A', b'n', b'n', 0xa4, b't', b'a', b'g',
+            b's', 0x93, 0x01, 0xff, 0xcd, 0x01, 0x2c, 0xa2, b'o', b'k', 0xc3, 0xa1, b'x', 0xc0,
+            0xa1, b'f', 0xcb, 0x3f, 0xf8, 0, 0, 0, 0, 0, 0,
+        ];
+        assert_eq!(
+            decode(&bytes).unwrap(),
+            json(serde_json::json!({
+                "name": "Ann", "tags": [1, -1, 300], "ok": true, "x": null, "f": 1.5
+            }))
+        );
+        // Integer keys become text; 2.0 is the integer 2
+        let bytes = [0x81, 0x07, 0xca, 0x40, 0, 0, 0];
+        assert_eq!(decode(&bytes).unwrap(), json(serde_json::json!({"7": 2})));
+
+        let big = [0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+        assert!(decode(&big).is_err());
+        assert_eq!(
+            msgpack(&big, IntegerRange::Full, IntegerPolicy::Stringify).unwrap(),
+            Value::String(u64::MAX.to_string())
+        );
+        for invalid in [
+            &[0xc4, 0x01, 0x00][..],
+            &[0x92, 0x01],
+            &[0x01, 0x02],
+            &[0xc1],
+        ] {
+            assert!(decode(invalid).is_err());
+        }
+        assert!(decode(&[0x91; MAX_DEPTH + 1]).is_err());
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor() {
+        let decode = |bytes: &[u8]| cbor(bytes, IntegerRange::Safe, IntegerPolicy::Error);
+        // {"a": [1, -2, 500], "b": "xy", "c": false, "d": null, "e": 1.5 (half)}
+        let bytes = [
+            0xa5, 0x61, b'a', 0x83, 0x01, 0x21, 0x19, 0x01, 0xf4, 0x61, b'b', 0x62, b'x', b'y',
+            0x61, b'c', 0xf4, 0x61, b'd', 0xf6, 0x61, b'e', 0xf9, 0x3e, 0x00,
+        ];
+        assert_eq!(
+            decode(&bytes).unwrap(),
+            json(serde_json::json!({
+                "a": [1, -2, 500], "b": "xy", "c": false, "d": null, "e": 1.5
+            }))
+        );
+        // Indefinite map holding an indefinite text and array, under tag 0
+        let bytes = [
+            0xc0, 0xbf, 0x61, b'k', 0x7f, 0x61, b'a', 0x61, b'b', 0xff, 0x61, b'l', 0x9f, 0x01,
+            0xff, 0xff,
+        ];
+        assert_eq!(
+            decode(&bytes).unwrap(),
+            json(serde_json::json!({"k": "ab", "l": [1]}))
+        );
+
+        let big = [0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+        assert!(decode(&big).is_err());
+        assert_eq!(
+            cbor(&big, IntegerRange::Full, IntegerPolicy::Saturate).unwrap(),
+            Value::Integer(i64::MAX)
+        );
+        for invalid in [
+            &[0x41, 0x00][..],
+            &[0xff],
+            &[0x82, 0x01],
+            &[0x01, 0x02],
+            &[0x1c],
+        ] {
+            assert!(decode(invalid).is_err());
+        }
+        assert!(decode(&[0x81; MAX_DEPTH + 1]).is_err());
+        let mut tags = vec![0xc0; 200_000];
+        tags.push(0x01);
+        assert!(decode(&tags).is_err());
+        assert!(decode(&[0x7f; 200_000]).is_err());
+    }
+}
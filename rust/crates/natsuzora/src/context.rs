@@ -1,31 +1,175 @@
 //! Context for variable resolution during template rendering.
+//!
+//! Each blocks bind their item as an [`Element`] of the collection rather
+//! than a copy of it: the collection's location is resolved once per block
+//! and an iteration only records its index or key.
+//!
+//! A context made by [`Context::from_json`] borrows the data instead of
+//! converting it: each top-level entry becomes a [`Value`] the first time
+//! the template reaches it, and entries it never touches are not copied.
+//! [`SharedData`] adds top-level values the context borrows as they are.
 
 use crate::error::{Location, NatsuzoraError, Result};
-use crate::value::Value;
+use crate::value::{IndexMap, IntegerPolicy, IntegerRange, SharedData, Value};
+use serde_json::Value as JsonValue;
+use std::cell::OnceCell;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A local name's value: owned, or an element of a collection the context
+/// already holds
+#[derive(Debug, Clone)]
+pub(crate) enum Binding {
+    Value(Value),
+    Element(Element),
+}
+
+/// Element `step` of the collection at `collection`
+#[derive(Debug, Clone)]
+pub(crate) struct Element {
+    collection: Arc<Slot>,
+    step: Step,
+}
+
+impl Element {
+    pub(crate) fn new(collection: &Arc<Slot>, step: Step) -> Self {
+        Self {
+            collection: Arc::clone(collection),
+            step,
+        }
+    }
+}
+
+/// Where a value lives: `name` in the root (`scope: None`) or in local scope
+/// `scope`, followed by `steps`
+#[derive(Debug)]
+pub(crate) struct Slot {
+    scope: Option<usize>,
+    name: String,
+    steps: Vec<Step>,
+}
+
+/// One step from a value into an element of it
+#[derive(Debug, Clone)]
+pub(crate) enum Step {
+    Key(String),
+    Index(usize),
+}
+
+impl Step {
+    fn apply<'v>(&self, value: &'v Value) -> Option<&'v Value> {
+        match (self, value) {
+            (Step::Index(index), Value::Array(items)) => items.get(*index),
+            (Step::Key(key), Value::Object(entries)) => entries.get(key),
+            (Step::Key(key), Value::Array(items)) => {
+                key.parse::<usize>().ok().and_then(|index| items.get(index))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The root data, converted up front or borrowed
+enum Root<'d> {
+    Owned(IndexMap<String, Value>),
+    /// Top-level entries of borrowed JSON, each converted on first use
+    Json {
+        entries: HashMap<&'d str, (&'d JsonValue, OnceCell<Value>)>,
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    },
+}
+
+impl Root<'_> {
+    fn contains(&self, name: &str) -> bool {
+        match self {
+            Root::Owned(root) => root.contains_key(name),
+            Root::Json { entries, .. } => entries.contains_key(name),
+        }
+    }
+
+    /// The value of `name`, converting it when it is borrowed JSON
+    fn get(&self, name: &str) -> Option<Result<&Value>> {
+        match self {
+            Root::Owned(root) => root.get(name).map(Ok),
+            Root::Json {
+                entries,
+                range,
+                policy,
+            } => {
+                let (json, cell) = entries.get(name)?;
+                if let Some(value) = cell.get() {
+                    return Some(Ok(value));
+                }
+                Some(
+                    crate::serialize::to_value(*json, *range, *policy)
+                        .map(|value| cell.get_or_init(|| value)),
+                )
+            }
+        }
+    }
+}
 
 /// Context for variable resolution during template rendering
-pub struct Context {
-    root: HashMap<String, Value>,
-    local_stack: Vec<HashMap<String, Value>>,
+pub struct Context<'d> {
+    root: Root<'d>,
+    /// Consulted for names the root does not have
+    shared: Option<&'d SharedData>,
+    local_stack: Vec<HashMap<String, Binding>>,
+    /// Start of each isolated scope in `local_stack`; scopes below the last
+    /// one are not visible
+    barriers: Vec<usize>,
 }
 
-impl Context {
+impl<'d> Context<'d> {
     /// Create a new context from root data
     pub fn new(root_data: Value) -> Result<Self> {
         let root = match root_data {
             Value::Object(obj) => obj,
-            _ => {
-                return Err(NatsuzoraError::TypeError {
-                    message: "Root data must be an object".to_string(),
-                });
-            }
+            _ => return Err(root_not_object()),
+        };
+
+        Ok(Self::with_root(Root::Owned(root)))
+    }
+
+    /// Create a context over JSON data without converting it up front
+    ///
+    /// Integers are still checked against `range` here, so the render fails
+    /// on out-of-range data whether or not the template reaches it.
+    pub fn from_json(
+        data: &'d JsonValue,
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    ) -> Result<Self> {
+        let JsonValue::Object(object) = data else {
+            return Err(root_not_object());
         };
+        check_numbers(data, range, policy)?;
+
+        let entries = object
+            .iter()
+            .map(|(name, json)| (name.as_str(), (json, OnceCell::new())))
+            .collect();
+        Ok(Self::with_root(Root::Json {
+            entries,
+            range,
+            policy,
+        }))
+    }
+
+    /// Resolve names the root data does not have in `shared`
+    pub fn with_shared(mut self, shared: &'d SharedData) -> Self {
+        self.shared = Some(shared);
+        self
+    }
 
-        Ok(Self {
+    fn with_root(root: Root<'d>) -> Self {
+        Self {
             root,
+            shared: None,
             local_stack: Vec::new(),
-        })
+            barriers: Vec::new(),
+        }
     }
 
     /// Resolve a path (e.g., ["user", "profile", "name"]) with location for error reporting
@@ -48,42 +192,139 @@ impl Context {
 
     /// Push a new scope (for each blocks) with shadowing validation
     pub fn push_scope(&mut self, bindings: HashMap<String, Value>) -> Result<()> {
-        self.validate_no_shadowing(&bindings)?;
-        self.local_stack.push(bindings);
+        self.validate_no_shadowing(bindings.keys())?;
+        self.local_stack.push(owned(bindings));
+        Ok(())
+    }
+
+    /// Push the scope of an each block binding `names`, validated once for
+    /// all iterations; [`Self::rebind`] sets their values
+    pub(crate) fn push_each_scope<'n>(
+        &mut self,
+        names: impl IntoIterator<Item = &'n String> + Clone,
+    ) -> Result<()> {
+        self.validate_no_shadowing(names.clone())?;
+        let scope = names
+            .into_iter()
+            .map(|name| (name.clone(), Binding::Value(Value::Null)))
+            .collect();
+        self.local_stack.push(scope);
         Ok(())
     }
 
+    /// Replace the value of `name` in the innermost scope
+    pub(crate) fn rebind(&mut self, name: &str, binding: Binding) {
+        if let Some(slot) = self
+            .local_stack
+            .last_mut()
+            .and_then(|scope| scope.get_mut(name))
+        {
+            *slot = binding;
+        }
+    }
+
+    /// Where the value at `path` lives, so that its elements can be bound
+    /// without copying them; `None` when the path is not defined here
+    pub(crate) fn slot(&self, path: &[String]) -> Option<Arc<Slot>> {
+        let (name, rest) = path.split_first()?;
+        let offset = self.barriers.last().copied().unwrap_or(0);
+        let scope = match self
+            .visible_scopes()
+            .iter()
+            .rposition(|scope| scope.contains_key(name))
+        {
+            Some(index) => Some(offset + index),
+            None if self.root_contains(name) => None,
+            None => return None,
+        };
+        Some(Arc::new(Slot {
+            scope,
+            name: name.clone(),
+            steps: rest
+                .iter()
+                .map(|segment| Step::Key(segment.clone()))
+                .collect(),
+        }))
+    }
+
     /// Push scope for include (no shadowing validation per spec)
     pub fn push_include_scope(&mut self, bindings: HashMap<String, Value>) {
-        self.local_stack.push(bindings);
+        self.local_stack.push(owned(bindings));
+    }
+
+    /// Push scope for a recursive include: only `bindings` and the root are
+    /// visible until it is popped
+    pub fn push_isolated_scope(&mut self, bindings: HashMap<String, Value>) {
+        self.barriers.push(self.local_stack.len());
+        self.local_stack.push(owned(bindings));
     }
 
     /// Pop the current scope
     pub fn pop_scope(&mut self) {
         self.local_stack.pop();
+        if self.barriers.last() == Some(&self.local_stack.len()) {
+            self.barriers.pop();
+        }
+    }
+
+    /// Local scopes visible from the current one, outermost first
+    fn visible_scopes(&self) -> &[HashMap<String, Binding>] {
+        &self.local_stack[self.barriers.last().copied().unwrap_or(0)..]
     }
 
     /// Resolve a name from the scope stack or root
     fn resolve_name(&self, name: &str, location: Location) -> Result<&Value> {
         // Search local scopes from innermost to outermost
-        for scope in self.local_stack.iter().rev() {
-            if let Some(value) = scope.get(name) {
-                return Ok(value);
+        for scope in self.visible_scopes().iter().rev() {
+            if let Some(binding) = scope.get(name) {
+                // An element's collection cannot change while it is bound
+                return self.binding_value(binding).ok_or_else(|| {
+                    NatsuzoraError::UndefinedVariable {
+                        message: format!("Undefined variable: {name}"),
+                        location,
+                    }
+                });
             }
         }
 
         // Fall back to root
-        self.root
-            .get(name)
+        self.root_get(name)
             .ok_or_else(|| NatsuzoraError::UndefinedVariable {
                 message: format!("Undefined variable: {name}"),
                 location,
-            })
+            })?
+    }
+
+    fn binding_value<'s>(&'s self, binding: &'s Binding) -> Option<&'s Value> {
+        match binding {
+            Binding::Value(value) => Some(value),
+            Binding::Element(element) => element.step.apply(self.slot_value(&element.collection)?),
+        }
+    }
+
+    fn slot_value(&self, slot: &Slot) -> Option<&Value> {
+        let base = match slot.scope {
+            Some(index) => self.binding_value(self.local_stack.get(index)?.get(&slot.name)?)?,
+            None => self.root_get(&slot.name)?.ok()?,
+        };
+        slot.steps
+            .iter()
+            .try_fold(base, |value, step| step.apply(value))
+    }
+
+    fn root_get(&self, name: &str) -> Option<Result<&Value>> {
+        self.root
+            .get(name)
+            .or_else(|| Some(Ok(self.shared?.get(name)?)))
+    }
+
+    fn root_contains(&self, name: &str) -> bool {
+        self.root.contains(name) || self.shared.is_some_and(|shared| shared.contains(name))
     }
 
     /// Validate that bindings don't shadow existing names
-    fn validate_no_shadowing(&self, bindings: &HashMap<String, Value>) -> Result<()> {
-        for name in bindings.keys() {
+    fn validate_no_shadowing<'n>(&self, names: impl IntoIterator<Item = &'n String>) -> Result<()> {
+        for name in names {
             if let Some(origin) = self.binding_origin(name) {
                 return Err(NatsuzoraError::ShadowingError {
                     name: name.to_string(),
@@ -96,10 +337,10 @@ impl Context {
 
     /// Find the origin of a binding for shadowing error messages
     fn binding_origin(&self, name: &str) -> Option<String> {
-        if self.root.contains_key(name) {
+        if self.root_contains(name) {
             return Some("root data".to_string());
         }
-        for scope in &self.local_stack {
+        for scope in self.visible_scopes() {
             if scope.contains_key(name) {
                 return Some("outer local scope".to_string());
             }
@@ -139,7 +380,7 @@ impl Context {
         }
     }
 
-    /// Access a property on an object value
+    /// Access a property on an object value, or an element of an array by index
     fn access_property<'a>(
         &self,
         value: &'a Value,
@@ -153,6 +394,14 @@ impl Context {
                     message: format!("Undefined property: {key}"),
                     location,
                 }),
+            Value::Array(arr) if key.starts_with(|c: char| c.is_ascii_digit()) => key
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| arr.get(index))
+                .ok_or_else(|| NatsuzoraError::UndefinedVariable {
+                    message: format!("Undefined index: {key}"),
+                    location,
+                }),
             _ => Err(NatsuzoraError::TypeError {
                 message: format!("Cannot access property '{key}' on non-object"),
             }),
@@ -160,6 +409,33 @@ impl Context {
     }
 }
 
+fn root_not_object() -> NatsuzoraError {
+    NatsuzoraError::TypeError {
+        message: "Root data must be an object".to_string(),
+    }
+}
+
+/// Fail as converting `json` would, without converting it
+fn check_numbers(json: &JsonValue, range: IntegerRange, policy: IntegerPolicy) -> Result<()> {
+    match json {
+        JsonValue::Number(n) => Value::from_json_number(n, range, policy).map(drop),
+        JsonValue::Array(items) => items
+            .iter()
+            .try_for_each(|item| check_numbers(item, range, policy)),
+        JsonValue::Object(entries) => entries
+            .values()
+            .try_for_each(|entry| check_numbers(entry, range, policy)),
+        _ => Ok(()),
+    }
+}
+
+fn owned(bindings: HashMap<String, Value>) -> HashMap<String, Binding> {
+    bindings
+        .into_iter()
+        .map(|(name, value)| (name, Binding::Value(value)))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,21 +444,18 @@ mod tests {
         Location::new(1, 1, 0)
     }
 
-    fn create_test_context() -> Context {
-        let mut root = HashMap::new();
+    fn create_test_context() -> Context<'static> {
+        let mut root = IndexMap::new();
         root.insert("name".to_string(), Value::String("Alice".to_string()));
 
-        let mut user = HashMap::new();
+        let mut user = IndexMap::new();
         user.insert(
             "email".to_string(),
             Value::String("alice@example.com".to_string()),
         );
         root.insert("user".to_string(), Value::Object(user));
 
-        Context {
-            root,
-            local_stack: Vec::new(),
-        }
+        Context::with_root(Root::Owned(root))
     }
 
     #[test]
@@ -201,6 +474,31 @@ mod tests {
         assert_eq!(value, &Value::String("alice@example.com".to_string()));
     }
 
+    #[test]
+    fn test_resolve_array_index() {
+        let mut ctx = create_test_context();
+        let mut bindings = HashMap::new();
+        bindings.insert(
+            "tags".to_string(),
+            Value::Array(vec![Value::String("a".to_string()), Value::Integer(2)]),
+        );
+        ctx.push_scope(bindings).unwrap();
+
+        let path = |index: &str| ["tags".to_string(), index.to_string()];
+        assert_eq!(
+            ctx.resolve(&path("1"), test_location()).unwrap(),
+            &Value::Integer(2)
+        );
+        assert!(matches!(
+            ctx.resolve(&path("2"), test_location()),
+            Err(NatsuzoraError::UndefinedVariable { .. })
+        ));
+        assert!(matches!(
+            ctx.resolve(&path("size"), test_location()),
+            Err(NatsuzoraError::TypeError { .. })
+        ));
+    }
+
     #[test]
     fn test_undefined_variable() {
         let ctx = create_test_context();
@@ -222,6 +520,39 @@ mod tests {
         assert!(ctx.resolve(&["item".to_string()], test_location()).is_err());
     }
 
+    #[test]
+    fn test_each_scope_binds_elements_in_place() {
+        let mut ctx = create_test_context();
+        let mut bindings = HashMap::new();
+        bindings.insert(
+            "rows".to_string(),
+            Value::Array(vec![Value::Integer(1), Value::Integer(2)]),
+        );
+        ctx.push_include_scope(bindings);
+        let slot = ctx.slot(&["rows".to_string()]).unwrap();
+        assert!(ctx.slot(&["missing".to_string()]).is_none());
+
+        let row = "row".to_string();
+        ctx.push_each_scope([&row]).unwrap();
+        for index in 0..2 {
+            ctx.rebind(
+                "row",
+                Binding::Element(Element::new(&slot, Step::Index(index))),
+            );
+            let value = ctx
+                .resolve(std::slice::from_ref(&row), test_location())
+                .unwrap();
+            assert_eq!(value, &Value::Integer(index as i64 + 1));
+        }
+        ctx.pop_scope();
+
+        let name = "name".to_string();
+        assert!(matches!(
+            ctx.push_each_scope([&name]),
+            Err(NatsuzoraError::ShadowingError { .. })
+        ));
+    }
+
     #[test]
     fn test_shadowing_error() {
         let mut ctx = create_test_context();
@@ -242,4 +573,82 @@ mod tests {
         let value = ctx.resolve(&["name".to_string()], test_location()).unwrap();
         assert_eq!(value, &Value::String("Bob".to_string()));
     }
+
+    #[test]
+    fn test_isolated_scope_hides_outer_locals() {
+        let mut ctx = create_test_context();
+        let item = |v: &str| HashMap::from([("item".to_string(), Value::String(v.to_string()))]);
+        ctx.push_scope(item("outer")).unwrap();
+        ctx.push_isolated_scope(HashMap::new());
+        assert!(ctx.resolve(&["item".to_string()], test_location()).is_err());
+        assert!(ctx.resolve(&["name".to_string()], test_location()).is_ok());
+        ctx.push_scope(item("inner")).unwrap();
+        ctx.pop_scope();
+        ctx.pop_scope();
+        let value = ctx.resolve(&["item".to_string()], test_location()).unwrap();
+        assert_eq!(value, &Value::String("outer".to_string()));
+    }
+
+    #[test]
+    fn test_json_root_converts_entries_on_first_use() {
+        let data = serde_json::json!({"name": "Alice", "rows": [1, 2], "unused": {"a": [true]}});
+        let mut ctx = Context::from_json(&data, IntegerRange::Safe, IntegerPolicy::Error).unwrap();
+        let converted = |ctx: &Context, name: &str| match &ctx.root {
+            Root::Json { entries, .. } => entries[name].1.get().is_some(),
+            Root::Owned(_) => unreachable!(),
+        };
+
+        let value = ctx.resolve(&["name".to_string()], test_location()).unwrap();
+        assert_eq!(value, &Value::String("Alice".to_string()));
+        let slot = ctx.slot(&["rows".to_string()]).unwrap();
+        let row = "row".to_string();
+        ctx.push_each_scope([&row]).unwrap();
+        ctx.rebind("row", Binding::Element(Element::new(&slot, Step::Index(1))));
+        let value = ctx
+            .resolve(std::slice::from_ref(&row), test_location())
+            .unwrap();
+        assert_eq!(value, &Value::Integer(2));
+        assert!(converted(&ctx, "rows"));
+        assert!(!converted(&ctx, "unused"));
+        assert!(matches!(
+            ctx.push_each_scope([&"unused".to_string()]),
+            Err(NatsuzoraError::ShadowingError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_json_root_checks_integers_up_front() {
+        let data = serde_json::json!({"unused": [{"n": 1e20}]});
+        let result = Context::from_json(&data, IntegerRange::Safe, IntegerPolicy::Error);
+        assert!(matches!(result, Err(NatsuzoraError::TypeError { .. })));
+        assert!(Context::from_json(&data, IntegerRange::Safe, IntegerPolicy::Saturate).is_ok());
+        let array = serde_json::json!([1]);
+        assert!(Context::from_json(&array, IntegerRange::Safe, IntegerPolicy::Error).is_err());
+    }
+
+    #[test]
+    fn test_shared_data_is_borrowed_under_the_root() {
+        let site = Arc::new(Value::Object(IndexMap::from([(
+            "name".to_string(),
+            Value::String("Site".to_string()),
+        )])));
+        let shared = SharedData::new()
+            .with("site", Arc::clone(&site))
+            .with("name", Value::String("Shared".to_string()));
+        let root = IndexMap::from([("name".to_string(), Value::String("Alice".to_string()))]);
+        let mut ctx = Context::new(Value::Object(root))
+            .unwrap()
+            .with_shared(&shared);
+
+        let value = ctx.resolve(&["site".to_string()], test_location()).unwrap();
+        assert!(std::ptr::eq(value, site.as_ref()));
+        let value = ctx.resolve(&["name".to_string()], test_location()).unwrap();
+        assert_eq!(value, &Value::String("Alice".to_string()));
+        let bindings = HashMap::from([("site".to_string(), Value::Null)]);
+        assert!(matches!(
+            ctx.push_scope(bindings),
+            Err(NatsuzoraError::ShadowingError { .. })
+        ));
+        assert_eq!(Arc::strong_count(&site), 2);
+    }
 }
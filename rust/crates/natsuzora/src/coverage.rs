@@ -0,0 +1,291 @@
+//! Branch coverage collection for template rendering.
+//!
+//! A [`Coverage`] collector records which `if`/`unless` branches and `each`
+//! bodies were executed. One collector can be shared across many renders
+//! (e.g. a whole test corpus) and then turned into a [`CoverageReport`]
+//! listing the branches that were never taken.
+
+use crate::error::Location;
+use natsuzora_ast::{AstNode, Template};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Kind of a coverable branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum BranchKind {
+    /// Then branch of an `if` block.
+    IfThen,
+    /// An `elseif` clause of an `if` block.
+    IfElseIf,
+    /// Explicit `else` branch of an `if` block.
+    IfElse,
+    /// No branch of an `if` block without `else` taken.
+    IfImplicitElse,
+    /// Body of an `unless` block.
+    UnlessBody,
+    /// `else` branch of an `unless` block.
+    UnlessElse,
+    /// Condition of an `unless` block without `else` true, skipping the body.
+    UnlessImplicitElse,
+    /// Body of an `each` block (at least one iteration).
+    EachBody,
+    /// `else` branch of an `each` block (empty collection).
+    EachElse,
+}
+
+impl BranchKind {
+    fn label(self) -> &'static str {
+        match self {
+            BranchKind::IfThen => "if",
+            BranchKind::IfElseIf => "elseif",
+            BranchKind::IfElse => "else",
+            BranchKind::IfImplicitElse => "implicit-else",
+            BranchKind::UnlessBody => "unless",
+            BranchKind::UnlessElse => "unless-else",
+            BranchKind::UnlessImplicitElse => "unless-implicit-else",
+            BranchKind::EachBody => "each",
+            BranchKind::EachElse => "each-else",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct BranchKey {
+    template: String,
+    line: usize,
+    column: usize,
+    kind: BranchKind,
+}
+
+/// Collector of branch hits, aggregatable across renders.
+#[derive(Debug, Clone, Default)]
+pub struct Coverage {
+    hits: BTreeMap<BranchKey, u64>,
+}
+
+impl Coverage {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register every branch of a template so unexecuted ones are reported
+    pub fn register(&mut self, template_name: &str, template: &Template) {
+        self.register_nodes(template_name, template.nodes());
+    }
+
+    /// Record one execution of a branch
+    pub fn hit(&mut self, template_name: &str, location: Location, kind: BranchKind) {
+        *self
+            .hits
+            .entry(Self::key(template_name, location, kind))
+            .or_insert(0) += 1;
+    }
+
+    /// Merge hits collected by another collector into this one
+    pub fn merge(&mut self, other: &Coverage) {
+        for (key, hits) in &other.hits {
+            *self.hits.entry(key.clone()).or_insert(0) += hits;
+        }
+    }
+
+    /// Build a report of all registered branches
+    pub fn report(&self) -> CoverageReport {
+        let branches = self
+            .hits
+            .iter()
+            .map(|(key, hits)| BranchCoverage {
+                template: key.template.clone(),
+                location: Location::new(key.line, key.column, 0),
+                kind: key.kind,
+                hits: *hits,
+            })
+            .collect();
+        CoverageReport { branches }
+    }
+
+    fn register_nodes(&mut self, template_name: &str, nodes: &[AstNode]) {
+        for node in nodes {
+            match node {
+                AstNode::If(n) => {
+                    self.register_branch(template_name, n.location, BranchKind::IfThen);
+                    self.register_nodes(template_name, &n.then_branch);
+                    for branch in &n.elseif_branches {
+                        self.register_branch(template_name, branch.location, BranchKind::IfElseIf);
+                        self.register_nodes(template_name, &branch.body);
+                    }
+                    match &n.else_branch {
+                        Some(else_branch) => {
+                            self.register_branch(template_name, n.location, BranchKind::IfElse);
+                            self.register_nodes(template_name, else_branch);
+                        }
+                        None => {
+                            self.register_branch(
+                                template_name,
+                                n.location,
+                                BranchKind::IfImplicitElse,
+                            );
+                        }
+                    }
+                }
+                AstNode::Unless(n) => {
+                    self.register_branch(template_name, n.location, BranchKind::UnlessBody);
+                    self.register_nodes(template_name, &n.body);
+                    match &n.else_branch {
+                        Some(else_branch) => {
+                            self.register_branch(template_name, n.location, BranchKind::UnlessElse);
+                            self.register_nodes(template_name, else_branch);
+                        }
+                        None => {
+                            self.register_branch(
+                                template_name,
+                                n.location,
+                                BranchKind::UnlessImplicitElse,
+                            );
+                        }
+                    }
+                }
+                AstNode::Each(n) => {
+                    self.register_branch(template_name, n.location, BranchKind::EachBody);
+                    self.register_nodes(template_name, &n.body);
+                    if let Some(else_branch) = &n.else_branch {
+                        self.register_branch(template_name, n.location, BranchKind::EachElse);
+                        self.register_nodes(template_name, else_branch);
+                    }
+                }
+                AstNode::Cache(n) => self.register_nodes(template_name, &n.body),
+                AstNode::Block(n) => self.register_nodes(template_name, &n.body),
+                AstNode::Include(n) => {
+                    if let Some(body) = &n.body {
+                        self.register_nodes(template_name, body);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn register_branch(&mut self, template_name: &str, location: Location, kind: BranchKind) {
+        self.hits
+            .entry(Self::key(template_name, location, kind))
+            .or_insert(0);
+    }
+
+    fn key(template_name: &str, location: Location, kind: BranchKind) -> BranchKey {
+        BranchKey {
+            template: template_name.to_string(),
+            line: location.line,
+            column: location.column,
+            kind,
+        }
+    }
+}
+
+/// Hit count of a single branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchCoverage {
+    pub template: String,
+    pub location: Location,
+    pub kind: BranchKind,
+    pub hits: u64,
+}
+
+/// Aggregated coverage result, ordered by template and location.
+#[derive(Debug, Clone, Default)]
+pub struct CoverageReport {
+    pub branches: Vec<BranchCoverage>,
+}
+
+impl CoverageReport {
+    /// Number of registered branches
+    pub fn total(&self) -> usize {
+        self.branches.len()
+    }
+
+    /// Number of branches executed at least once
+    pub fn covered(&self) -> usize {
+        self.branches.iter().filter(|b| b.hits > 0).count()
+    }
+
+    /// Branches never executed
+    pub fn uncovered(&self) -> impl Iterator<Item = &BranchCoverage> {
+        self.branches.iter().filter(|b| b.hits == 0)
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "branch coverage: {}/{} covered",
+            self.covered(),
+            self.total()
+        )?;
+        for branch in self.uncovered() {
+            writeln!(
+                f,
+                "  {}:{}:{} {} branch never executed",
+                branch.template,
+                branch.location.line,
+                branch.location.column,
+                branch.kind.label()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_and_hit() {
+        let template =
+            natsuzora_ast::parse("{[#if a]}x{[#else]}y{[/if]}{[#each items as i]}{[/each]}")
+                .unwrap();
+        let mut coverage = Coverage::new();
+        coverage.register("page", &template);
+
+        let if_location = template.nodes()[0].location();
+        coverage.hit("page", if_location, BranchKind::IfThen);
+
+        let report = coverage.report();
+        assert_eq!(report.total(), 3);
+        assert_eq!(report.covered(), 1);
+        let uncovered: Vec<BranchKind> = report.uncovered().map(|b| b.kind).collect();
+        assert_eq!(uncovered, vec![BranchKind::IfElse, BranchKind::EachBody]);
+    }
+
+    #[test]
+    fn test_implicit_else_is_a_branch() {
+        let template = natsuzora_ast::parse("{[#if a]}x{[/if]}{[#unless b]}y{[/unless]}").unwrap();
+        let mut coverage = Coverage::new();
+        coverage.register("page", &template);
+
+        let report = coverage.report();
+        let kinds: Vec<BranchKind> = report.branches.iter().map(|b| b.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                BranchKind::IfThen,
+                BranchKind::IfImplicitElse,
+                BranchKind::UnlessBody,
+                BranchKind::UnlessImplicitElse,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_accumulates_hits() {
+        let location = Location::new(1, 4, 3);
+        let mut a = Coverage::new();
+        a.hit("page", location, BranchKind::UnlessBody);
+        let mut b = Coverage::new();
+        b.hit("page", location, BranchKind::UnlessBody);
+
+        a.merge(&b);
+        assert_eq!(a.report().branches[0].hits, 2);
+    }
+}
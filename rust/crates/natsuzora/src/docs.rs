@@ -0,0 +1,341 @@
+//! Reference documentation for a template directory.
+//!
+//! [`DocGenerator`] writes one Markdown or HTML page per template in a
+//! [`TemplateSet`]: its front matter, required variables, includes used and
+//! used-by, unsecure outputs, and an example rendering when sample data is
+//! available.
+//!
+//! Front matter is a comment at the very start of the template holding
+//! `key: value` lines:
+//!
+//! ```text
+//! {[% title: Product card
+//!     owner: storefront ]}
+//! ```
+
+use crate::analysis::{TemplateEntry, TemplateSet};
+use crate::error::{NatsuzoraError, Result};
+use crate::html_escape;
+use crate::Natsuzora;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Output format of generated documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum DocFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl DocFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Markdown => "md",
+            DocFormat::Html => "html",
+        }
+    }
+}
+
+/// Generates per-template documentation for a [`TemplateSet`].
+pub struct DocGenerator<'a> {
+    set: &'a TemplateSet,
+    samples: HashMap<String, serde_json::Value>,
+}
+
+impl<'a> DocGenerator<'a> {
+    pub fn new(set: &'a TemplateSet) -> Self {
+        Self {
+            set,
+            samples: HashMap::new(),
+        }
+    }
+
+    /// Use `data` to render the example of the template `name`
+    pub fn with_sample(mut self, name: impl Into<String>, data: serde_json::Value) -> Self {
+        self.samples.insert(name.into(), data);
+        self
+    }
+
+    /// Read sample data from `<template>.sample.json` files next to the templates
+    ///
+    /// `components/_card.ntzr` reads `components/_card.sample.json`.
+    pub fn with_sample_files(mut self) -> Result<Self> {
+        for entry in self.set.iter() {
+            let file = self
+                .set
+                .root()
+                .join(&entry.path)
+                .with_extension("sample.json");
+            if !file.is_file() {
+                continue;
+            }
+            let json = fs::read_to_string(&file)?;
+            let data = serde_json::from_str(&json).map_err(|e| NatsuzoraError::TypeError {
+                message: format!("Invalid sample data {}: {e}", file.display()),
+            })?;
+            self.samples.insert(entry.name.clone(), data);
+        }
+        Ok(self)
+    }
+
+    /// Document for the template `name`
+    pub fn document(&self, name: &str, format: DocFormat) -> Option<String> {
+        let entry = self.set.get(name)?;
+        let page = self.page(entry);
+        Some(match format {
+            DocFormat::Markdown => page.to_markdown(),
+            DocFormat::Html => page.to_html(),
+        })
+    }
+
+    /// Write every document under `out_dir`, mirroring the template paths
+    ///
+    /// Returns the written files.
+    pub fn write_all(&self, out_dir: impl AsRef<Path>, format: DocFormat) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        for entry in self.set.iter() {
+            let file = out_dir
+                .as_ref()
+                .join(&entry.path)
+                .with_extension(format.extension());
+            if let Some(parent) = file.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            if let Some(document) = self.document(&entry.name, format) {
+                fs::write(&file, document)?;
+                written.push(file);
+            }
+        }
+        Ok(written)
+    }
+
+    fn page(&self, entry: &TemplateEntry) -> Page {
+        let info = &entry.info;
+        let sections = vec![
+            (
+                "Required variables",
+                info.required_variables.iter().cloned().collect(),
+            ),
+            ("Includes", info.includes.iter().cloned().collect()),
+            (
+                "Used by",
+                self.set
+                    .used_by(&entry.name)
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            ),
+            (
+                "Unsecure output",
+                info.unsecure
+                    .iter()
+                    .map(|u| format!("{} (line {})", u.path, u.location.line))
+                    .collect(),
+            ),
+        ];
+        let example = self.samples.get(&entry.name).map(|data| {
+            Natsuzora::parse_with_includes(&entry.source, self.set.root())
+                .and_then(|template| template.render(data.clone()))
+                .map_err(|e| e.to_string())
+        });
+
+        Page {
+            title: entry.name.clone(),
+            file: entry.path.display().to_string(),
+            metadata: front_matter(&entry.source),
+            sections,
+            example,
+        }
+    }
+}
+
+struct Page {
+    title: String,
+    file: String,
+    metadata: Vec<(String, String)>,
+    sections: Vec<(&'static str, Vec<String>)>,
+    example: Option<std::result::Result<String, String>>,
+}
+
+impl Page {
+    fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# {}\n\n`{}`", self.title, self.file);
+        if !self.metadata.is_empty() {
+            out.push('\n');
+            for (key, value) in &self.metadata {
+                let _ = writeln!(out, "- **{key}**: {value}");
+            }
+        }
+        for (heading, items) in &self.sections {
+            let _ = writeln!(out, "\n## {heading}\n");
+            if items.is_empty() {
+                out.push_str("(none)\n");
+            }
+            for item in items {
+                let _ = writeln!(out, "- `{item}`");
+            }
+        }
+        match &self.example {
+            Some(Ok(output)) => {
+                let _ = writeln!(out, "\n## Example\n\n```html\n{output}\n```");
+            }
+            Some(Err(error)) => {
+                let _ = writeln!(out, "\n## Example\n\nRendering failed: {error}");
+            }
+            None => {}
+        }
+        out
+    }
+
+    fn to_html(&self) -> String {
+        let esc = html_escape::escape;
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "<h1>{}</h1>\n<p><code>{}</code></p>",
+            esc(&self.title),
+            esc(&self.file)
+        );
+        if !self.metadata.is_empty() {
+            out.push_str("<dl>\n");
+            for (key, value) in &self.metadata {
+                let _ = writeln!(out, "<dt>{}</dt><dd>{}</dd>", esc(key), esc(value));
+            }
+            out.push_str("</dl>\n");
+        }
+        for (heading, items) in &self.sections {
+            let _ = writeln!(out, "<h2>{heading}</h2>");
+            if items.is_empty() {
+                out.push_str("<p>(none)</p>\n");
+                continue;
+            }
+            out.push_str("<ul>\n");
+            for item in items {
+                let _ = writeln!(out, "<li><code>{}</code></li>", esc(item));
+            }
+            out.push_str("</ul>\n");
+        }
+        match &self.example {
+            Some(Ok(output)) => {
+                let _ = writeln!(
+                    out,
+                    "<h2>Example</h2>\n<pre><code>{}</code></pre>",
+                    esc(output)
+                );
+            }
+            Some(Err(error)) => {
+                let _ = writeln!(
+                    out,
+                    "<h2>Example</h2>\n<p>Rendering failed: {}</p>",
+                    esc(error)
+                );
+            }
+            None => {}
+        }
+        out
+    }
+}
+
+/// `key: value` lines of a comment at the start of the source
+fn front_matter(source: &str) -> Vec<(String, String)> {
+    let Some(rest) = source
+        .strip_prefix("{[%")
+        .or_else(|| source.strip_prefix("{[-%"))
+    else {
+        return Vec::new();
+    };
+    let Some(end) = rest.find("]}") else {
+        return Vec::new();
+    };
+    let body = rest[..end].strip_suffix('-').unwrap_or(&rest[..end]);
+    body.lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let key = key.trim();
+            (!key.is_empty()).then(|| (key.to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let file = dir.join(path);
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(file, content).unwrap();
+    }
+
+    #[test]
+    fn test_front_matter() {
+        let meta = front_matter("{[% title: Card\n   owner: shop -]}\n<div>");
+        assert_eq!(
+            meta,
+            vec![
+                ("title".to_string(), "Card".to_string()),
+                ("owner".to_string(), "shop".to_string())
+            ]
+        );
+        assert!(front_matter("<div>{[% title: x ]}").is_empty());
+    }
+
+    #[test]
+    fn test_markdown_document() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "components/_card.ntzr",
+            "{[% title: Card -]}\n<b>{[ title ]}</b>{[!unsecure body ]}",
+        );
+        write(
+            dir.path(),
+            "components/_card.sample.json",
+            r#"{"title": "Hi", "body": "<i>x</i>"}"#,
+        );
+        write(
+            dir.path(),
+            "index.ntzr",
+            "{[#each items as item]}{[!include /components/card title=item.name body=item.html ]}{[/each]}",
+        );
+
+        let set = TemplateSet::load_dir(dir.path()).unwrap();
+        let docs = DocGenerator::new(&set).with_sample_files().unwrap();
+
+        let card = docs
+            .document("/components/card", DocFormat::Markdown)
+            .unwrap();
+        assert!(card
+            .starts_with("# /components/card\n\n`components/_card.ntzr`\n\n- **title**: Card\n"));
+        assert!(card.contains("## Required variables\n\n- `body`\n- `title`\n"));
+        assert!(card.contains("## Used by\n\n- `index.ntzr`\n"));
+        assert!(card.contains("## Unsecure output\n\n- `body (line 2)`\n"));
+        assert!(card.contains("```html\n<b>Hi</b><i>x</i>\n```"));
+
+        let index = docs.document("index.ntzr", DocFormat::Html).unwrap();
+        assert!(index.contains("<h2>Includes</h2>\n<ul>\n<li><code>/components/card</code></li>"));
+        assert!(!index.contains("Example"));
+
+        let out = TempDir::new().unwrap();
+        let written = docs.write_all(out.path(), DocFormat::Markdown).unwrap();
+        assert_eq!(written.len(), 2);
+        assert!(out.path().join("components/_card.md").is_file());
+    }
+
+    #[test]
+    fn test_example_render_failure_is_reported() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "page.ntzr", "{[ missing ]}");
+        let set = TemplateSet::load_dir(dir.path()).unwrap();
+        let docs = DocGenerator::new(&set).with_sample("page.ntzr", json!({}));
+        let page = docs.document("page.ntzr", DocFormat::Markdown).unwrap();
+        assert!(page.contains("## Example\n\nRendering failed: "));
+    }
+}
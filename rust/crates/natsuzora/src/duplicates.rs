@@ -0,0 +1,517 @@
+//! Near-duplicate block detection across a template directory.
+//!
+//! Every block (`if`, `unless`, `each`, `cache`) is reduced to a structural
+//! fingerprint that ignores text content and replaces the paths it reads from
+//! outside the block with numbered slots. Blocks sharing a fingerprint are
+//! copies of each other that differ only in wording and in the data they
+//! read, so they can be extracted into one partial: slots whose path differs
+//! between the copies become include arguments.
+//!
+//! ```text
+//! {[#if post.author]}<b>{[ post.author.name ]}</b>{[/if]}
+//! {[#if page.owner]}<b>{[ page.owner.name ]}</b>{[/if]}
+//! → {[!include /byline author=post.author name=post.author.name ]}
+//! ```
+
+use crate::analysis::TemplateSet;
+use crate::error::Location;
+use natsuzora_ast::{AstNode, Collection, Condition, Modifier, Operand, Path};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+
+/// Blocks of one or more templates that share the same structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    /// Number of AST nodes in each copy
+    pub size: usize,
+    pub occurrences: Vec<DuplicateBlock>,
+    /// Paths that differ between the copies, as include arguments
+    pub arguments: Vec<SuggestedArgument>,
+}
+
+/// Where a copy of a duplicated block starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateBlock {
+    pub template: String,
+    pub location: Location,
+}
+
+/// An include argument for an extracted partial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestedArgument {
+    pub name: String,
+    /// Path passed by each occurrence, in occurrence order
+    pub paths: Vec<String>,
+}
+
+impl DuplicateGroup {
+    /// The include tag replacing occurrence `index` once the block is
+    /// extracted into the partial `partial`
+    pub fn include_tag(&self, partial: &str, index: usize) -> String {
+        let mut tag = format!("{{[!include {partial}");
+        for argument in &self.arguments {
+            let _ = write!(tag, " {}={}", argument.name, argument.paths[index]);
+        }
+        tag.push_str(" ]}");
+        tag
+    }
+}
+
+impl TemplateSet {
+    /// Blocks of at least `min_size` nodes that occur more than once
+    ///
+    /// Groups are ordered by size, largest first. A duplicate nested inside
+    /// a larger reported duplicate is not reported again.
+    pub fn duplicates(&self, min_size: usize) -> Vec<DuplicateGroup> {
+        let mut candidates = Vec::new();
+        for entry in self.iter() {
+            collect_blocks(
+                &entry.name,
+                entry.template.nodes(),
+                &mut Vec::new(),
+                &mut candidates,
+            );
+        }
+
+        let mut groups: HashMap<String, Vec<Candidate>> = HashMap::new();
+        for candidate in candidates {
+            if candidate.size >= min_size {
+                groups
+                    .entry(candidate.shape.clone())
+                    .or_default()
+                    .push(candidate);
+            }
+        }
+        let mut groups: Vec<Vec<Candidate>> =
+            groups.into_values().filter(|g| g.len() > 1).collect();
+        groups.sort_by(|a, b| {
+            b[0].size
+                .cmp(&a[0].size)
+                .then_with(|| a[0].key().cmp(&b[0].key()))
+        });
+
+        let mut reported = HashSet::new();
+        let mut result = Vec::new();
+        for group in groups {
+            let nested = group
+                .iter()
+                .all(|c| c.ancestors.iter().any(|a| reported.contains(a)));
+            reported.extend(group.iter().map(Candidate::key));
+            if !nested {
+                result.push(to_group(group));
+            }
+        }
+        result
+    }
+}
+
+struct Candidate {
+    template: String,
+    location: Location,
+    size: usize,
+    shape: String,
+    /// Paths read from outside the block, by slot number
+    slots: Vec<String>,
+    /// Keys of the enclosing blocks
+    ancestors: Vec<(String, usize)>,
+}
+
+impl Candidate {
+    fn key(&self) -> (String, usize) {
+        (self.template.clone(), self.location.byte_offset)
+    }
+}
+
+fn collect_blocks(
+    template: &str,
+    nodes: &[AstNode],
+    ancestors: &mut Vec<(String, usize)>,
+    candidates: &mut Vec<Candidate>,
+) {
+    for node in nodes {
+        let children: Vec<&[AstNode]> = match node {
+            AstNode::If(n) => std::iter::once(n.then_branch.as_slice())
+                .chain(n.elseif_branches.iter().map(|b| b.body.as_slice()))
+                .chain(n.else_branch.as_deref())
+                .collect(),
+            AstNode::Unless(n) => std::iter::once(n.body.as_slice())
+                .chain(n.else_branch.as_deref())
+                .collect(),
+            AstNode::Each(n) => std::iter::once(n.body.as_slice())
+                .chain(n.else_branch.as_deref())
+                .collect(),
+            AstNode::Cache(n) => vec![&n.body],
+            AstNode::Block(n) => vec![&n.body],
+            AstNode::Include(n) => match &n.body {
+                Some(body) => vec![body],
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        let mut shape = Shape::default();
+        let size = shape.node(node);
+        let location = node.location();
+        candidates.push(Candidate {
+            template: template.to_string(),
+            location,
+            size,
+            shape: shape.out,
+            slots: shape.slots,
+            ancestors: ancestors.clone(),
+        });
+
+        ancestors.push((template.to_string(), location.byte_offset));
+        for body in children {
+            collect_blocks(template, body, ancestors, candidates);
+        }
+        ancestors.pop();
+    }
+}
+
+/// Structural fingerprint of a block.
+#[derive(Default)]
+struct Shape {
+    out: String,
+    slots: Vec<String>,
+    /// Names bound by `each` inside the block
+    bound: Vec<String>,
+}
+
+impl Shape {
+    /// Append the fingerprint of `node`, returning its node count
+    fn node(&mut self, node: &AstNode) -> usize {
+        match node {
+            AstNode::Text(_) => {
+                self.out.push_str("T;");
+                1
+            }
+            AstNode::Variable(n) => {
+                let modifier = match n.modifier {
+                    Modifier::None => "",
+                    Modifier::Nullable => "?",
+                    Modifier::Required => "!",
+                };
+                self.out.push_str("V(");
+                self.path(&n.path);
+                let _ = write!(self.out, "){modifier}");
+                for filter in &n.filters {
+                    let _ = write!(self.out, "|{filter:?}");
+                }
+                self.out.push(';');
+                1
+            }
+            AstNode::Attr(n) => {
+                let _ = write!(self.out, "A({} ", n.name);
+                self.path(&n.path);
+                self.out.push_str(");");
+                1
+            }
+            AstNode::Unsecure(n) => {
+                self.out.push_str("U(");
+                self.path(&n.path);
+                self.out.push_str(");");
+                1
+            }
+            AstNode::If(n) => {
+                self.out.push_str("If(");
+                self.condition(&n.condition);
+                let mut size = 1 + self.body(&n.then_branch);
+                for branch in &n.elseif_branches {
+                    self.out.push_str("ElseIf(");
+                    self.condition(&branch.condition);
+                    size += self.body(&branch.body);
+                }
+                if let Some(else_branch) = &n.else_branch {
+                    self.out.push_str("Else(");
+                    size += self.body(else_branch);
+                }
+                size
+            }
+            AstNode::Unless(n) => {
+                self.out.push_str("Unless(");
+                self.condition(&n.condition);
+                let mut size = 1 + self.body(&n.body);
+                if let Some(else_branch) = &n.else_branch {
+                    self.out.push_str("Else(");
+                    size += self.body(else_branch);
+                }
+                size
+            }
+            AstNode::Each(n) => {
+                let depth = self.bound.len();
+                let index = if n.index_ident.is_some() {
+                    "+index"
+                } else {
+                    ""
+                };
+                let meta = if n.meta_ident.is_some() { "+meta" } else { "" };
+                let _ = write!(self.out, "Each{index}{meta}(");
+                match &n.collection {
+                    Collection::Path(path) => self.path(path),
+                    Collection::Range { start, end } => {
+                        self.operand(start);
+                        self.out.push_str("..");
+                        self.operand(end);
+                    }
+                    other => {
+                        let _ = write!(self.out, "{other}");
+                    }
+                }
+                for (name, operand) in [("limit", &n.limit), ("offset", &n.offset)] {
+                    if let Some(operand) = operand {
+                        let _ = write!(self.out, " {name}=");
+                        self.operand(operand);
+                    }
+                }
+                self.bound.extend(n.bindings().map(String::from));
+                let mut size = 1 + self.body(&n.body);
+                self.bound.truncate(depth);
+                if let Some(else_branch) = &n.else_branch {
+                    self.out.push_str("Else(");
+                    size += self.body(else_branch);
+                }
+                size
+            }
+            AstNode::Cache(n) => {
+                self.out.push_str("Cache(");
+                self.path(&n.key);
+                1 + self.body(&n.body)
+            }
+            AstNode::Include(n) => {
+                match &n.source {
+                    Some(source) => {
+                        self.out.push_str("Inc(");
+                        self.path(source);
+                    }
+                    None => {
+                        let _ = write!(self.out, "Inc({}", n.name);
+                    }
+                }
+                for arg in &n.args {
+                    let _ = write!(self.out, " {}=", arg.name);
+                    self.operand(&arg.value);
+                }
+                if let Some(spread) = &n.spread {
+                    self.out.push_str(" ...");
+                    self.path(spread);
+                }
+                match &n.body {
+                    Some(body) => 1 + self.body(body),
+                    None => {
+                        self.out.push_str(");");
+                        1
+                    }
+                }
+            }
+            AstNode::Block(n) => {
+                let _ = write!(self.out, "Block({}", n.name);
+                1 + self.body(&n.body)
+            }
+            AstNode::Extends(n) => {
+                let _ = write!(self.out, "Ext({});", n.name);
+                1
+            }
+            AstNode::Yield(_) => {
+                self.out.push_str("Y;");
+                1
+            }
+            AstNode::Translate(n) => {
+                let _ = write!(self.out, "T({}", n.key);
+                for arg in &n.args {
+                    let _ = write!(self.out, " {}=", arg.name);
+                    self.operand(&arg.value);
+                }
+                self.out.push_str(");");
+                1
+            }
+            other => {
+                let _ = write!(
+                    self.out,
+                    "{}@{};",
+                    other.kind(),
+                    other.location().byte_offset
+                );
+                1
+            }
+        }
+    }
+
+    fn body(&mut self, nodes: &[AstNode]) -> usize {
+        self.out.push_str(")[");
+        let size = nodes.iter().map(|node| self.node(node)).sum();
+        self.out.push(']');
+        size
+    }
+
+    fn operand(&mut self, operand: &Operand) {
+        match operand {
+            Operand::Path(path) => self.path(path),
+            Operand::Literal(literal) => {
+                let _ = write!(self.out, "{literal}");
+            }
+        }
+    }
+
+    fn condition(&mut self, condition: &Condition) {
+        match condition {
+            Condition::Path(path) => self.path(path),
+            Condition::Comparison(c) => {
+                self.path(&c.left);
+                self.out.push_str(c.op.as_str());
+                self.operand(&c.right);
+            }
+            Condition::Not(c) => {
+                self.out.push_str("not ");
+                self.condition(c);
+            }
+            Condition::And(left, right) => {
+                self.condition(left);
+                self.out.push_str(" and ");
+                self.condition(right);
+            }
+            Condition::Or(left, right) => {
+                self.condition(left);
+                self.out.push_str(" or ");
+                self.condition(right);
+            }
+            other => {
+                let _ = write!(self.out, "{other}");
+            }
+        }
+    }
+
+    /// Loop variables bound inside the block keep their position; every
+    /// other path becomes a slot shared by repeated uses of the same path.
+    fn path(&mut self, path: &Path) {
+        let segments = path.segments();
+        if let Some(depth) = self.bound.iter().rposition(|name| *name == segments[0]) {
+            let _ = write!(self.out, "@{depth}");
+            for segment in &segments[1..] {
+                let _ = write!(self.out, ".{segment}");
+            }
+            return;
+        }
+        let dotted = path.as_str();
+        let slot = match self.slots.iter().position(|s| *s == dotted) {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(dotted);
+                self.slots.len() - 1
+            }
+        };
+        let _ = write!(self.out, "${slot}");
+    }
+}
+
+fn to_group(group: Vec<Candidate>) -> DuplicateGroup {
+    let slot_count = group[0].slots.len();
+    // Slots read through the same path in every copy stay in the partial as-is
+    let mut constant_roots = HashSet::new();
+    let mut varying: BTreeMap<Vec<&str>, usize> = BTreeMap::new();
+    for slot in 0..slot_count {
+        let paths: Vec<&str> = group.iter().map(|c| c.slots[slot].as_str()).collect();
+        if paths.iter().all(|p| *p == paths[0]) {
+            constant_roots.insert(paths[0].split(['.', '[']).next().unwrap_or(paths[0]));
+        } else {
+            let next = varying.len();
+            varying.entry(paths).or_insert(next);
+        }
+    }
+
+    let mut varying: Vec<(Vec<&str>, usize)> = varying.into_iter().collect();
+    varying.sort_by_key(|(_, order)| *order);
+    let mut taken: HashSet<String> = constant_roots.iter().map(|r| r.to_string()).collect();
+    let arguments = varying
+        .into_iter()
+        .map(|(paths, _)| {
+            // Named after the last segment before any quoted key
+            let plain = paths[0].split('[').next().unwrap_or(paths[0]);
+            let base = plain.rsplit('.').next().unwrap_or(plain);
+            let mut name = base.to_string();
+            let mut n = 2;
+            while !taken.insert(name.clone()) {
+                name = format!("{base}{n}");
+                n += 1;
+            }
+            SuggestedArgument {
+                name,
+                paths: paths.into_iter().map(String::from).collect(),
+            }
+        })
+        .collect();
+
+    DuplicateGroup {
+        size: group[0].size,
+        occurrences: group
+            .into_iter()
+            .map(|c| DuplicateBlock {
+                template: c.template,
+                location: c.location,
+            })
+            .collect(),
+        arguments,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_duplicates_infer_arguments() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("post.ntzr"),
+            "{[#if post.author]}<b>{[ post.author.name ]}</b> in {[ site ]}{[/if]}\n\
+             {[#each tags as tag]}{[ tag ]}{[/each]}",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("page.ntzr"),
+            "<p>{[#if page.owner]}<i>{[ page.owner.name ]}</i> at {[ site ]}{[/if]}</p>\n\
+             {[#each labels as label]}{[ label ]}{[/each]}",
+        )
+        .unwrap();
+        let set = TemplateSet::load_dir(dir.path()).unwrap();
+
+        let groups = set.duplicates(2);
+        assert_eq!(groups.len(), 2);
+        let byline = &groups[0];
+        assert_eq!(byline.size, 5);
+        assert_eq!(
+            byline
+                .occurrences
+                .iter()
+                .map(|o| (o.template.as_str(), o.location.line))
+                .collect::<Vec<_>>(),
+            vec![("page.ntzr", 1), ("post.ntzr", 1)]
+        );
+        assert_eq!(
+            byline.include_tag("/byline", 1),
+            "{[!include /byline owner=post.author name=post.author.name ]}"
+        );
+        assert_eq!(
+            groups[1].include_tag("/tags", 0),
+            "{[!include /tags labels=labels ]}"
+        );
+
+        assert!(set.duplicates(6).is_empty());
+    }
+
+    #[test]
+    fn test_nested_duplicates_are_reported_once() {
+        let dir = TempDir::new().unwrap();
+        let block = "{[#each items as item]}{[#if item.on]}{[ item.name ]}{[/if]}{[/each]}";
+        fs::write(dir.path().join("a.ntzr"), block).unwrap();
+        fs::write(dir.path().join("b.ntzr"), block).unwrap();
+        let set = TemplateSet::load_dir(dir.path()).unwrap();
+
+        let groups = set.duplicates(1);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].size, 3);
+        assert!(groups[0].arguments.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+//! Edge-side include output mode.
+//!
+//! Selected includes are not rendered; the renderer emits an ESI or SSI
+//! directive instead so a CDN can assemble the fragment. Include arguments
+//! are stringified and serialized into the query string of the fragment URL:
+//!
+//! ```text
+//! {[!include /sidebar user=user.id ]}
+//! → <esi:include src="/fragments/sidebar?user=42" />
+//! ```
+
+use crate::error::{NatsuzoraError, Result};
+use crate::html_escape;
+use natsuzora_ast::{AstNode, Template};
+use std::collections::BTreeSet;
+
+/// Directive syntax emitted for edge includes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum EdgeIncludeFormat {
+    /// `<esi:include src="..." />`
+    #[default]
+    Esi,
+    /// `<!--#include virtual="..." -->`
+    Ssi,
+}
+
+/// Includes to emit as edge-side directives instead of rendering them.
+#[derive(Debug, Clone, Default)]
+pub struct EdgeIncludes {
+    format: EdgeIncludeFormat,
+    src_prefix: String,
+    names: BTreeSet<String>,
+}
+
+impl EdgeIncludes {
+    /// Create an empty selection; fragment URLs are `src_prefix` + include name
+    pub fn new(format: EdgeIncludeFormat, src_prefix: impl Into<String>) -> Self {
+        Self {
+            format,
+            src_prefix: src_prefix.into(),
+            names: BTreeSet::new(),
+        }
+    }
+
+    /// Emit the include `name` (e.g. `/components/sidebar`) as a directive
+    pub fn include(mut self, name: impl Into<String>) -> Self {
+        self.names.insert(name.into());
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    /// Check that every selected include is referenced by the template
+    pub(crate) fn validate(&self, template: &Template) -> Result<()> {
+        let mut referenced = BTreeSet::new();
+        collect_include_names(template.nodes(), &mut referenced);
+        match self
+            .names
+            .iter()
+            .find(|name| !referenced.contains(name.as_str()))
+        {
+            Some(name) => Err(NatsuzoraError::IncludeError {
+                message: format!("Edge include '{name}' is not referenced by the template"),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Build the directive for an include with stringified arguments
+    pub(crate) fn directive(&self, name: &str, args: &[(String, String)]) -> String {
+        let mut src = format!("{}{}", self.src_prefix, name);
+        for (index, (key, value)) in args.iter().enumerate() {
+            src.push(if index == 0 { '?' } else { '&' });
+            src.push_str(&percent_encode(key));
+            src.push('=');
+            src.push_str(&percent_encode(value));
+        }
+        let src = html_escape::escape(&src);
+        match self.format {
+            EdgeIncludeFormat::Esi => format!("<esi:include src=\"{src}\" />"),
+            EdgeIncludeFormat::Ssi => format!("<!--#include virtual=\"{src}\" -->"),
+        }
+    }
+}
+
+fn collect_include_names<'t>(nodes: &'t [AstNode], names: &mut BTreeSet<&'t str>) {
+    for node in nodes {
+        match node {
+            AstNode::Include(n) => {
+                if n.source.is_none() {
+                    names.insert(&n.name);
+                }
+                if let Some(body) = &n.body {
+                    collect_include_names(body, names);
+                }
+            }
+            AstNode::If(n) => {
+                collect_include_names(&n.then_branch, names);
+                for branch in &n.elseif_branches {
+                    collect_include_names(&branch.body, names);
+                }
+                if let Some(else_branch) = &n.else_branch {
+                    collect_include_names(else_branch, names);
+                }
+            }
+            AstNode::Unless(n) => {
+                collect_include_names(&n.body, names);
+                if let Some(else_branch) = &n.else_branch {
+                    collect_include_names(else_branch, names);
+                }
+            }
+            AstNode::Each(n) => {
+                collect_include_names(&n.body, names);
+                if let Some(else_branch) = &n.else_branch {
+                    collect_include_names(else_branch, names);
+                }
+            }
+            AstNode::Cache(n) => collect_include_names(&n.body, names),
+            AstNode::Block(n) => collect_include_names(&n.body, names),
+            _ => {}
+        }
+    }
+}
+
+/// Percent-encode everything except RFC 3986 unreserved characters.
+fn percent_encode(input: &str) -> String {
+    let mut encoded = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directive_serializes_arguments() {
+        let esi = EdgeIncludes::new(EdgeIncludeFormat::Esi, "/fragments").include("/sidebar");
+        assert_eq!(
+            esi.directive(
+                "/sidebar",
+                &[("user".into(), "42".into()), ("q".into(), "a b&c".into())]
+            ),
+            r#"<esi:include src="/fragments/sidebar?user=42&amp;q=a%20b%26c" />"#
+        );
+
+        let ssi = EdgeIncludes::new(EdgeIncludeFormat::Ssi, "");
+        assert_eq!(
+            ssi.directive("/nav", &[]),
+            r#"<!--#include virtual="/nav" -->"#
+        );
+    }
+
+    #[test]
+    fn test_validate_requires_referenced_include() {
+        let template = natsuzora_ast::parse("{[#if a]}{[!include /nav ]}{[/if]}").unwrap();
+        let edge = EdgeIncludes::new(EdgeIncludeFormat::Esi, "").include("/nav");
+        assert!(edge.validate(&template).is_ok());
+        assert!(edge.include("/footer").validate(&template).is_err());
+    }
+}
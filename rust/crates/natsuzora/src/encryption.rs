@@ -0,0 +1,124 @@
+//! Templates stored encrypted at rest (feature `encryption`).
+//!
+//! [`EncryptedSource`] wraps another [`TemplateSource`] and decrypts every
+//! file it reads with AES-256-GCM, so partials never exist as plaintext on
+//! disk. Each file holds a 12-byte nonce followed by the ciphertext and tag,
+//! as written by [`encrypt`]. The file's name below its include root is the
+//! associated data, so a file renamed or copied over another fails to
+//! decrypt. The key comes from the host application:
+//!
+//! ```rust,ignore
+//! use natsuzora::encryption::EncryptedSource;
+//! use natsuzora::{FsSource, Natsuzora};
+//!
+//! let key: [u8; 32] = load_key_from_vault();
+//! let tmpl = Natsuzora::parse_with_includes(source, "templates/shared")?
+//!     .with_template_source(EncryptedSource::new(FsSource, &key));
+//! ```
+
+use crate::error::{NatsuzoraError, Result};
+use crate::template_loader::TemplateSource;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use std::path::Path;
+
+const NONCE_LEN: usize = 12;
+
+/// A [`TemplateSource`] decrypting the files read by `S`.
+pub struct EncryptedSource<S> {
+    inner: S,
+    cipher: Aes256Gcm,
+}
+
+impl<S: TemplateSource> EncryptedSource<S> {
+    pub fn new(inner: S, key: &[u8; 32]) -> Self {
+        Self {
+            inner,
+            cipher: Aes256Gcm::new(key.into()),
+        }
+    }
+}
+
+impl<S: TemplateSource> TemplateSource for EncryptedSource<S> {
+    fn read(&self, path: &Path, name: &str) -> Result<Vec<u8>> {
+        let data = self.inner.read(path, name)?;
+        let failed = || NatsuzoraError::IncludeError {
+            message: format!("Failed to decrypt template: {}", path.display()),
+        };
+        if data.len() < NONCE_LEN {
+            return Err(failed());
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        self.cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: name.as_bytes(),
+                },
+            )
+            .map_err(|_| failed())
+    }
+}
+
+/// Encrypt a template for [`EncryptedSource`] under a fresh random nonce, to
+/// be stored as `name` below the include root, e.g. `components/_card.ntzr`
+pub fn encrypt(key: &[u8; 32], name: &str, plaintext: &[u8]) -> Vec<u8> {
+    let cipher = Aes256Gcm::new(key.into());
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let mut data = nonce.to_vec();
+    data.extend(
+        cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad: name.as_bytes(),
+                },
+            )
+            .expect("AES-GCM encryption of an in-memory buffer cannot fail"),
+    );
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template_loader::{FsSource, TemplateLoader};
+    use std::fs;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_encrypted_partials_load_and_tampering_fails() {
+        let key = [7u8; 32];
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("_card.ntzr"),
+            encrypt(&key, "_card.ntzr", "<b>{[ title ]}</b>".as_bytes()),
+        )
+        .unwrap();
+        let mut tampered = encrypt(&key, "_bad.ntzr", b"x");
+        *tampered.last_mut().unwrap() ^= 1;
+        fs::write(dir.path().join("_bad.ntzr"), tampered).unwrap();
+        // Encrypted for `_card.ntzr`, then renamed
+        fs::write(
+            dir.path().join("_renamed.ntzr"),
+            encrypt(&key, "_card.ntzr", b"card"),
+        )
+        .unwrap();
+
+        let mut loader = TemplateLoader::new(dir.path())
+            .unwrap()
+            .with_source(Arc::new(EncryptedSource::new(FsSource, &key)));
+        assert!(loader.load("/card").is_ok());
+        for name in ["/bad", "/renamed"] {
+            let err = loader.load(name).unwrap_err();
+            assert!(err.to_string().contains("Failed to decrypt template"));
+        }
+
+        let mut wrong_key = TemplateLoader::new(dir.path())
+            .unwrap()
+            .with_source(Arc::new(EncryptedSource::new(FsSource, &[0u8; 32])));
+        assert!(wrong_key.load("/card").is_err());
+    }
+}
@@ -7,10 +7,28 @@ pub use natsuzora_ast::Location;
 
 /// All errors that can occur in Natsuzora
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum NatsuzoraError {
     #[error("Parse error at line {}, column {}: {message}", location.line, location.column)]
     ParseError { message: String, location: Location },
 
+    /// A template broke a [`ParseLimits`](crate::ParseLimits) bound
+    #[error("Parse limit exceeded: {message}")]
+    ParseLimit { message: String },
+
+    /// A render's output outgrew [`RenderOptions::with_max_output_bytes`](crate::RenderOptions::with_max_output_bytes)
+    #[error("Output limit exceeded: {message}")]
+    OutputLimit { message: String },
+
+    /// An `each` broke a [`RenderOptions::with_max_loop_iterations`](crate::RenderOptions::with_max_loop_iterations)
+    /// or [`RenderOptions::with_max_render_iterations`](crate::RenderOptions::with_max_render_iterations) bound
+    #[error("Iteration limit exceeded: {message}")]
+    IterationLimit { message: String },
+
+    /// The flag given to [`RenderOptions::with_cancel_flag`](crate::RenderOptions::with_cancel_flag) was set
+    #[error("Render cancelled")]
+    Cancelled,
+
     #[error("{message}")]
     UndefinedVariable { message: String, location: Location },
 
@@ -25,8 +43,62 @@ pub enum NatsuzoraError {
     )]
     ShadowingError { name: String, origin: String },
 
+    #[error(
+        "Profile violation in {name} at line {}, column {}: {message}",
+        location.line,
+        location.column
+    )]
+    ProfileViolation {
+        name: String,
+        message: String,
+        location: Location,
+    },
+
+    #[error("Signature error: {message}")]
+    SignatureError { message: String },
+
+    #[error("Filter '{name}' is not registered")]
+    UnknownFilter { name: String },
+
+    #[error("No translation for '{key}' (tried: {locales})")]
+    MissingTranslation { key: String, locales: String },
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
+
+    #[error("{} errors: {}", errors.len(), join_errors(errors))]
+    Multiple { errors: Vec<NatsuzoraError> },
+}
+
+impl NatsuzoraError {
+    /// Stable identifier of the error kind, e.g. `parse_error`
+    pub fn code(&self) -> &'static str {
+        match self {
+            NatsuzoraError::ParseError { .. } => "parse_error",
+            NatsuzoraError::ParseLimit { .. } => "parse_limit",
+            NatsuzoraError::OutputLimit { .. } => "output_limit",
+            NatsuzoraError::IterationLimit { .. } => "iteration_limit",
+            NatsuzoraError::Cancelled => "cancelled",
+            NatsuzoraError::UndefinedVariable { .. } => "undefined_variable",
+            NatsuzoraError::TypeError { .. } => "type_error",
+            NatsuzoraError::IncludeError { .. } => "include_error",
+            NatsuzoraError::ShadowingError { .. } => "shadowing_error",
+            NatsuzoraError::ProfileViolation { .. } => "profile_violation",
+            NatsuzoraError::SignatureError { .. } => "signature_error",
+            NatsuzoraError::UnknownFilter { .. } => "unknown_filter",
+            NatsuzoraError::MissingTranslation { .. } => "missing_translation",
+            NatsuzoraError::IoError(_) => "io_error",
+            NatsuzoraError::Multiple { .. } => "multiple",
+        }
+    }
+}
+
+fn join_errors(errors: &[NatsuzoraError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 /// Result type alias for Natsuzora operations
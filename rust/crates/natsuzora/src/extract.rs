@@ -0,0 +1,195 @@
+//! Literal text extraction for translation workflows.
+//!
+//! [`MessageCatalog`] collects the literal text runs of templates (trimmed,
+//! at least `min_length` characters) with `file:line` references, and writes
+//! them as a gettext PO skeleton. [`MessageCatalog::untranslated`] lists the
+//! literals that have no entry in an existing set of translations.
+
+use natsuzora_ast::{AstNode, Template};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Where a literal occurs.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SourceRef {
+    pub file: String,
+    pub line: usize,
+}
+
+/// An extracted literal and every place it occurs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub text: String,
+    pub references: Vec<SourceRef>,
+}
+
+/// Literal texts of one or more templates, deduplicated by text.
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    min_length: usize,
+    messages: BTreeMap<String, Vec<SourceRef>>,
+}
+
+impl MessageCatalog {
+    /// Create an empty catalog keeping literals of at least `min_length` characters
+    pub fn new(min_length: usize) -> Self {
+        Self {
+            min_length,
+            messages: BTreeMap::new(),
+        }
+    }
+
+    /// Add the literal texts of a template parsed from `file`
+    pub fn add_template(&mut self, file: &str, template: &Template) {
+        self.add_nodes(file, template.nodes());
+    }
+
+    /// Extracted messages, ordered by text
+    pub fn messages(&self) -> impl Iterator<Item = Message> + '_ {
+        self.messages.iter().map(|(text, references)| Message {
+            text: text.clone(),
+            references: references.clone(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Messages without an entry in `translations` (keyed by source text)
+    pub fn untranslated<'a>(
+        &'a self,
+        translations: &'a HashMap<String, String>,
+    ) -> impl Iterator<Item = Message> + 'a {
+        self.messages()
+            .filter(|m| !translations.contains_key(&m.text))
+    }
+
+    fn add_nodes(&mut self, file: &str, nodes: &[AstNode]) {
+        for node in nodes {
+            match node {
+                AstNode::Text(n) => {
+                    let content: &str = &n.content;
+                    let text = content.trim();
+                    if text.is_empty() || text.chars().count() < self.min_length {
+                        continue;
+                    }
+                    let leading = &content[..content.len() - content.trim_start().len()];
+                    let line = n.location.line + leading.matches('\n').count();
+                    let references = self.messages.entry(text.to_string()).or_default();
+                    let reference = SourceRef {
+                        file: file.to_string(),
+                        line,
+                    };
+                    if !references.contains(&reference) {
+                        references.push(reference);
+                    }
+                }
+                AstNode::If(n) => {
+                    self.add_nodes(file, &n.then_branch);
+                    for branch in &n.elseif_branches {
+                        self.add_nodes(file, &branch.body);
+                    }
+                    if let Some(else_branch) = &n.else_branch {
+                        self.add_nodes(file, else_branch);
+                    }
+                }
+                AstNode::Unless(n) => {
+                    self.add_nodes(file, &n.body);
+                    if let Some(else_branch) = &n.else_branch {
+                        self.add_nodes(file, else_branch);
+                    }
+                }
+                AstNode::Each(n) => {
+                    self.add_nodes(file, &n.body);
+                    if let Some(else_branch) = &n.else_branch {
+                        self.add_nodes(file, else_branch);
+                    }
+                }
+                AstNode::Cache(n) => self.add_nodes(file, &n.body),
+                AstNode::Block(n) => self.add_nodes(file, &n.body),
+                AstNode::Include(n) => {
+                    if let Some(body) = &n.body {
+                        self.add_nodes(file, body);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Writes the catalog as a gettext PO skeleton with empty translations.
+impl fmt::Display for MessageCatalog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, message) in self.messages().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for reference in &message.references {
+                writeln!(f, "#: {}:{}", reference.file, reference.line)?;
+            }
+            writeln!(f, "msgid {}", po_quote(&message.text))?;
+            writeln!(f, "msgstr \"\"")?;
+        }
+        Ok(())
+    }
+}
+
+fn po_quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\r' => quoted.push_str("\\r"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extracts_trimmed_literals_with_lines() {
+        let template = natsuzora_ast::parse(
+            "<h1>\n  Welcome back\n</h1>\n{[#if user]}Hi {[ user ]}{[/if]}\n{[#each xs as x]}Say \"yes\"{[/each]}",
+        )
+        .unwrap();
+        let mut catalog = MessageCatalog::new(4);
+        catalog.add_template("page.ntzr", &template);
+        catalog.add_template("other.ntzr", &template);
+
+        let texts: Vec<String> = catalog.messages().map(|m| m.text).collect();
+        assert_eq!(texts, vec!["<h1>\n  Welcome back\n</h1>", "Say \"yes\""]);
+
+        let po = catalog.to_string();
+        assert!(po.starts_with("#: page.ntzr:1\n#: other.ntzr:1\nmsgid \"<h1>\\n  Welcome back\\n</h1>\"\nmsgstr \"\"\n"));
+        assert!(po.contains("#: page.ntzr:5\n#: other.ntzr:5\nmsgid \"Say \\\"yes\\\"\"\n"));
+    }
+
+    #[test]
+    fn test_untranslated_literals() {
+        let template = natsuzora_ast::parse("Hello {[ name ]}, goodbye").unwrap();
+        let mut catalog = MessageCatalog::new(1);
+        catalog.add_template("t", &template);
+
+        let translations = HashMap::from([("Hello".to_string(), "こんにちは".to_string())]);
+        let missing: Vec<String> = catalog
+            .untranslated(&translations)
+            .map(|m| m.text)
+            .collect();
+        assert_eq!(missing, vec![", goodbye"]);
+    }
+}
@@ -0,0 +1,229 @@
+//! Sitemap and feed generation.
+//!
+//! A static site needs `sitemap.xml` and a feed next to its pages. Both are
+//! rendered from the list of [`Page`]s the host already has, with ordinary
+//! Natsuzora templates: the built-in [`SITEMAP_TEMPLATE`] and
+//! [`ATOM_TEMPLATE`], or a host template (e.g. RSS) through [`render`].
+//! Standard escaping is also valid XML escaping, so values need no special
+//! treatment.
+//!
+//! Templates see `site` (`title`, `url`, `updated`) and `pages` (`url`,
+//! `title`, `updated`, `summary`); missing optional values are `null`.
+//! `site.updated` is the latest page `updated`, compared as strings, so use
+//! one timestamp format (e.g. RFC 3339) throughout.
+
+use crate::error::Result;
+use crate::Natsuzora;
+use natsuzora_ast::ParseOptions;
+use serde_json::{json, Value as JsonValue};
+
+/// Built-in `sitemap.xml` template
+pub const SITEMAP_TEMPLATE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+{[#each pages as page]}
+  <url>
+    <loc>{[ page.url ]}</loc>
+    {[#if page.updated]}
+    <lastmod>{[ page.updated ]}</lastmod>
+    {[/if]}
+  </url>
+{[/each]}
+</urlset>
+"#;
+
+/// Built-in Atom feed template
+pub const ATOM_TEMPLATE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>{[ site.title ]}</title>
+  <id>{[ site.url ]}</id>
+  <link href="{[ site.url ]}"/>
+  {[#if site.updated]}
+  <updated>{[ site.updated ]}</updated>
+  {[/if]}
+{[#each pages as page]}
+  <entry>
+    <title>{[ page.title ]}</title>
+    <id>{[ page.url ]}</id>
+    <link href="{[ page.url ]}"/>
+    {[#if page.updated]}
+    <updated>{[ page.updated ]}</updated>
+    {[/if]}
+    {[#if page.summary]}
+    <summary>{[ page.summary ]}</summary>
+    {[/if]}
+  </entry>
+{[/each]}
+</feed>
+"#;
+
+/// The site a sitemap or feed describes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+    pub title: String,
+    /// Absolute URL of the site, also the feed id
+    pub url: String,
+}
+
+impl Site {
+    pub fn new(title: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            url: url.into(),
+        }
+    }
+}
+
+/// One generated page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    /// Absolute URL of the page
+    pub url: String,
+    pub title: String,
+    /// Last modification timestamp (`lastmod` / `updated`)
+    pub updated: Option<String>,
+    pub summary: Option<String>,
+}
+
+impl Page {
+    pub fn new(url: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            title: title.into(),
+            updated: None,
+            summary: None,
+        }
+    }
+
+    pub fn with_updated(mut self, updated: impl Into<String>) -> Self {
+        self.updated = Some(updated.into());
+        self
+    }
+
+    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+}
+
+/// Render `sitemap.xml` with [`SITEMAP_TEMPLATE`]
+///
+/// # Example
+///
+/// ```rust
+/// use natsuzora::feed::{self, Page, Site};
+///
+/// let site = Site::new("Blog", "https://example.com/");
+/// let pages = [Page::new("https://example.com/a?x=1&y=2", "A").with_updated("2024-05-01")];
+/// let xml = feed::sitemap(&site, &pages).unwrap();
+/// assert!(xml.contains("<loc>https://example.com/a?x=1&amp;y=2</loc>"));
+/// assert!(xml.contains("<lastmod>2024-05-01</lastmod>"));
+/// ```
+pub fn sitemap(site: &Site, pages: &[Page]) -> Result<String> {
+    render(&parse(SITEMAP_TEMPLATE)?, site, pages)
+}
+
+/// Render an Atom feed with [`ATOM_TEMPLATE`]
+pub fn atom(site: &Site, pages: &[Page]) -> Result<String> {
+    render(&parse(ATOM_TEMPLATE)?, site, pages)
+}
+
+/// Render a host-supplied sitemap or feed template (e.g. RSS 2.0)
+pub fn render(template: &Natsuzora, site: &Site, pages: &[Page]) -> Result<String> {
+    template.render(data(site, pages))
+}
+
+/// The data a sitemap or feed template is rendered with
+pub fn data(site: &Site, pages: &[Page]) -> JsonValue {
+    let updated = pages
+        .iter()
+        .filter_map(|page| page.updated.as_deref())
+        .max();
+    let pages: Vec<JsonValue> = pages
+        .iter()
+        .map(|page| {
+            json!({
+                "url": page.url,
+                "title": page.title,
+                "updated": page.updated,
+                "summary": page.summary,
+            })
+        })
+        .collect();
+    json!({
+        "site": {"title": site.title, "url": site.url, "updated": updated},
+        "pages": pages,
+    })
+}
+
+/// Parse a built-in template; block tags keep their own lines out of the XML
+fn parse(source: &str) -> Result<Natsuzora> {
+    let options = ParseOptions {
+        trim_blocks: true,
+        lstrip_blocks: true,
+        ..ParseOptions::default()
+    };
+    Natsuzora::parse_with_options(source, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn pages() -> Vec<Page> {
+        vec![
+            Page::new("https://example.com/a", "A <1>").with_updated("2024-05-01T00:00:00Z"),
+            Page::new("https://example.com/b", "B")
+                .with_updated("2024-06-01T00:00:00Z")
+                .with_summary("Tom & Jerry"),
+            Page::new("https://example.com/c", "C"),
+        ]
+    }
+
+    #[test]
+    fn test_sitemap() {
+        let xml = sitemap(&Site::new("Blog", "https://example.com/"), &pages()[1..]).unwrap();
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n\
+             \x20 <url>\n\
+             \x20   <loc>https://example.com/b</loc>\n\
+             \x20   <lastmod>2024-06-01T00:00:00Z</lastmod>\n\
+             \x20 </url>\n\
+             \x20 <url>\n\
+             \x20   <loc>https://example.com/c</loc>\n\
+             \x20 </url>\n\
+             </urlset>\n"
+        );
+    }
+
+    #[test]
+    fn test_atom() {
+        let xml = atom(&Site::new("Blog", "https://example.com/"), &pages()).unwrap();
+        assert!(xml.contains("<title>A &lt;1&gt;</title>"));
+        assert!(xml.contains("<summary>Tom &amp; Jerry</summary>"));
+        assert!(xml.contains("  <updated>2024-06-01T00:00:00Z</updated>\n  <entry>"));
+        assert_eq!(xml.matches("<entry>").count(), 3);
+        assert!(!xml.contains("{["));
+    }
+
+    #[test]
+    fn test_render_custom_template() {
+        let rss = Natsuzora::parse(
+            "<channel><title>{[ site.title ]}</title>\
+             {[#each pages as page]}<item><link>{[ page.url ]}</link></item>{[/each]}</channel>",
+        )
+        .unwrap();
+        let xml = render(
+            &rss,
+            &Site::new("Blog", "https://example.com/"),
+            &pages()[..1],
+        )
+        .unwrap();
+        assert_eq!(
+            xml,
+            "<channel><title>Blog</title><item><link>https://example.com/a</link></item></channel>"
+        );
+    }
+}
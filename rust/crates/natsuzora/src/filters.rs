@@ -0,0 +1,311 @@
+//! Display filters of variable outputs.
+//!
+//! Besides the built-ins (`upper`, `lower`, `trim`, `truncate N`, `comma`,
+//! `group`, `count`),
+//! hosts can add their own pure filters. The names must be known when
+//! templates are parsed, and the functions when they are rendered:
+//!
+//! ```rust
+//! use natsuzora::{CustomFilters, Natsuzora, NatsuzoraError, ParseOptions, Value};
+//! use serde_json::json;
+//!
+//! let filters = CustomFilters::new().with("yen", |value: &Value| match value {
+//!     Value::Integer(n) => Ok(Value::String(format!("¥{n}"))),
+//!     other => Err(NatsuzoraError::TypeError {
+//!         message: format!("'yen' expects an integer, got {}", other.type_name()),
+//!     }),
+//! });
+//! let options = ParseOptions {
+//!     filters: filters.names(),
+//!     ..ParseOptions::default()
+//! };
+//! let tmpl = Natsuzora::parse_with_options("{[ price | yen ]}", options)
+//!     .unwrap()
+//!     .with_filters(filters);
+//! assert_eq!(tmpl.render(json!({"price": 500})).unwrap(), "¥500");
+//! ```
+
+use crate::error::{NatsuzoraError, Result};
+use crate::fragment_cache;
+use crate::value::Value;
+use natsuzora_ast::Filter;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Transform of the text of `{[ path ]}` outputs, applied after
+/// stringification and filters and before HTML escaping.
+///
+/// Set with [`Natsuzora::with_output_hook`](crate::Natsuzora::with_output_hook).
+/// Like a filter, a hook must be pure: rendering is deterministic only as
+/// long as the hook is. `{[!unsecure]}` outputs are not passed to it.
+pub trait OutputHook: Send + Sync {
+    /// `path` is the variable's path as written in the template
+    fn transform<'a>(&self, path: &str, text: &'a str) -> Cow<'a, str>;
+}
+
+impl<F> OutputHook for F
+where
+    F: for<'a> Fn(&str, &'a str) -> Cow<'a, str> + Send + Sync,
+{
+    fn transform<'a>(&self, path: &str, text: &'a str) -> Cow<'a, str> {
+        self(path, text)
+    }
+}
+
+/// Marker appended by `truncate` when it cuts the value
+const TRUNCATION_MARKER: &str = "…";
+
+/// Separator of `group` unless [`RenderOptions::with_group_separator`] sets one
+///
+/// [`RenderOptions::with_group_separator`]: crate::RenderOptions::with_group_separator
+pub(crate) const DEFAULT_GROUP_SEPARATOR: &str = ",";
+
+type FilterFn = dyn Fn(&Value) -> Result<Value> + Send + Sync;
+
+/// Host-defined filters by name.
+///
+/// A filter must be pure: the same input always gives the same output, so
+/// rendering stays deterministic. Built-in names cannot be overridden.
+#[derive(Clone, Default)]
+pub struct CustomFilters {
+    filters: HashMap<String, Arc<FilterFn>>,
+    /// Changes with every registration, for fragment cache keys
+    id: u64,
+}
+
+impl CustomFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `filter` as `| name`, replacing an earlier one of that name
+    pub fn with<F>(mut self, name: impl Into<String>, filter: F) -> Self
+    where
+        F: Fn(&Value) -> Result<Value> + Send + Sync + 'static,
+    {
+        self.filters.insert(name.into(), Arc::new(filter));
+        self.id = fragment_cache::registration_id();
+        self
+    }
+
+    /// The registered names, sorted, for [`ParseOptions::filters`](crate::ParseOptions::filters)
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.filters.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub(crate) fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn get(&self, name: &str) -> Option<&FilterFn> {
+        self.filters.get(name).map(|filter| filter.as_ref())
+    }
+}
+
+impl fmt::Debug for CustomFilters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomFilters")
+            .field("names", &self.names())
+            .finish()
+    }
+}
+
+/// Apply `filter` to `value`; built-ins other than `count` produce a string
+///
+/// `group_separator` is what `group` puts between digit groups.
+pub(crate) fn apply(
+    filter: &Filter,
+    value: &Value,
+    custom: Option<&CustomFilters>,
+    group_separator: &str,
+) -> Result<Value> {
+    // A host scalar is only known to custom filters
+    if let (Value::Scalar(scalar), false) = (value, matches!(filter, Filter::Custom(_))) {
+        return Err(NatsuzoraError::TypeError {
+            message: format!(
+                "'{}' does not accept {}",
+                filter.name(),
+                scalar.get().type_name()
+            ),
+        });
+    }
+    let output = match filter {
+        Filter::Upper => value.stringify()?.to_uppercase(),
+        Filter::Lower => value.stringify()?.to_lowercase(),
+        Filter::Trim => value
+            .stringify()?
+            .trim_matches(|c| matches!(c, ' ' | '\t' | '\r' | '\n'))
+            .to_string(),
+        Filter::Truncate(length) => {
+            let text = value.stringify()?;
+            match text.char_indices().nth(*length) {
+                Some((cut, _)) => format!("{}{TRUNCATION_MARKER}", &text[..cut]),
+                None => text,
+            }
+        }
+        Filter::Comma => group_integer(filter, value, ",")?,
+        Filter::Group => group_integer(filter, value, group_separator)?,
+        Filter::Count => {
+            let count = match value {
+                Value::Array(items) => items.len(),
+                Value::Object(entries) => entries.len(),
+                _ => {
+                    return Err(NatsuzoraError::TypeError {
+                        message: format!(
+                            "'count' expects an array or object, got {}",
+                            value.type_name()
+                        ),
+                    })
+                }
+            };
+            return Ok(Value::Integer(count as i64));
+        }
+        Filter::Custom(name) => {
+            return match custom.and_then(|filters| filters.get(name)) {
+                Some(filter) => filter(value),
+                None => Err(NatsuzoraError::UnknownFilter { name: name.clone() }),
+            };
+        }
+        other => {
+            return Err(NatsuzoraError::UnknownFilter {
+                name: other.name().to_string(),
+            })
+        }
+    };
+    Ok(Value::String(output))
+}
+
+fn group_integer(filter: &Filter, value: &Value, separator: &str) -> Result<String> {
+    match value {
+        Value::Integer(n) => Ok(group_thousands(*n, separator)),
+        _ => Err(NatsuzoraError::TypeError {
+            message: format!(
+                "'{}' expects an integer, got {}",
+                filter.name(),
+                value.type_name()
+            ),
+        }),
+    }
+}
+
+fn group_thousands(n: i64, separator: &str) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut output = String::with_capacity(digits.len() + digits.len() / 3 * separator.len() + 1);
+    if n < 0 {
+        output.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            output.push_str(separator);
+        }
+        output.push(digit);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Natsuzora, ParseOptions};
+    use serde_json::json;
+
+    fn text(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn test_string_filters() {
+        assert_eq!(
+            apply(&Filter::Upper, &text("straße"), None, ",").unwrap(),
+            text("STRASSE")
+        );
+        assert_eq!(
+            apply(&Filter::Lower, &text("ÀB"), None, ",").unwrap(),
+            text("àb")
+        );
+        assert_eq!(
+            apply(&Filter::Trim, &text("\n a b \t"), None, ",").unwrap(),
+            text("a b")
+        );
+        assert_eq!(
+            apply(&Filter::Truncate(2), &text("日本語"), None, ",").unwrap(),
+            text("日本…")
+        );
+        assert_eq!(
+            apply(&Filter::Truncate(3), &text("日本語"), None, ",").unwrap(),
+            text("日本語")
+        );
+        assert!(apply(&Filter::Upper, &Value::Null, None, ",").is_err());
+    }
+
+    #[test]
+    fn test_comma() {
+        assert_eq!(
+            apply(&Filter::Comma, &Value::Integer(0), None, ",").unwrap(),
+            text("0")
+        );
+        assert_eq!(
+            apply(&Filter::Comma, &Value::Integer(1000), None, ",").unwrap(),
+            text("1,000")
+        );
+        assert_eq!(
+            apply(&Filter::Comma, &Value::Integer(-1234567), None, ",").unwrap(),
+            text("-1,234,567")
+        );
+        assert!(apply(&Filter::Comma, &text("1000"), None, ",").is_err());
+    }
+
+    #[test]
+    fn test_group() {
+        assert_eq!(
+            apply(&Filter::Group, &Value::Integer(-1234567), None, "\u{202f}").unwrap(),
+            text("-1\u{202f}234\u{202f}567")
+        );
+        assert_eq!(
+            apply(&Filter::Group, &Value::Integer(999), None, ".").unwrap(),
+            text("999")
+        );
+        assert_eq!(
+            apply(&Filter::Comma, &Value::Integer(1000), None, ".").unwrap(),
+            text("1,000")
+        );
+        assert!(apply(&Filter::Group, &Value::Null, None, ".").is_err());
+    }
+
+    #[test]
+    fn test_count() {
+        let items = Value::Array(vec![Value::Null, Value::Integer(1)]);
+        assert_eq!(
+            apply(&Filter::Count, &items, None, ",").unwrap(),
+            Value::Integer(2)
+        );
+        assert!(apply(&Filter::Count, &text("ab"), None, ",").is_err());
+    }
+
+    #[test]
+    fn test_custom_filters() {
+        let filters = CustomFilters::new()
+            .with("shout", |value: &Value| {
+                Ok(text(&format!("{}!", value.stringify()?)))
+            })
+            .with("upper", |_: &Value| Ok(text("overridden")));
+        let options = ParseOptions {
+            filters: filters.names(),
+            ..ParseOptions::default()
+        };
+        assert_eq!(filters.names(), ["shout", "upper"]);
+
+        let source = "{[ name | upper | shout ]}";
+        let tmpl = Natsuzora::parse_with_options(source, options.clone()).unwrap();
+        let result = tmpl.render(json!({"name": "hi"}));
+        assert!(matches!(result, Err(NatsuzoraError::UnknownFilter { name }) if name == "shout"));
+        let tmpl = tmpl.with_filters(filters);
+        assert_eq!(tmpl.render(json!({"name": "hi"})).unwrap(), "HI!");
+
+        assert!(Natsuzora::parse(source).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! Fragment caching for `{[#cache key=... ]}` blocks.
+//!
+//! A [`FragmentCache`] memoizes the rendered output of cache blocks across
+//! renders. Entries are keyed by the block (its [`FragmentKey::fingerprint`])
+//! and the stringified key value, so the same block renders once per key.
+//! Renders without a cache evaluate cache blocks like plain content.
+//!
+//! The key also carries everything else that changes a fragment's output:
+//! the theme, the locale and a fingerprint of the render settings, so one
+//! store can be shared by renders configured differently.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+/// Identity of a cached fragment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FragmentKey {
+    /// Fingerprint of the cache block in its template
+    pub fingerprint: u64,
+    /// Stringified value of the block's `key`
+    pub key: String,
+    /// Theme chain the fragment was rendered with; empty without themes
+    pub theme: String,
+    /// Locale the fragment was rendered with; empty without one
+    pub locale: String,
+    /// Fingerprint of the render settings that change output, such as the
+    /// escape mode, custom filters, output hook and translations; empty
+    /// with the defaults
+    pub settings: String,
+}
+
+impl FragmentKey {
+    pub fn new(fingerprint: u64, key: impl Into<String>) -> Self {
+        Self {
+            fingerprint,
+            key: key.into(),
+            theme: String::new(),
+            locale: String::new(),
+            settings: String::new(),
+        }
+    }
+
+    pub fn with_theme(mut self, theme: impl Into<String>) -> Self {
+        self.theme = theme.into();
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    pub fn with_settings(mut self, settings: impl Into<String>) -> Self {
+        self.settings = settings.into();
+        self
+    }
+}
+
+/// A process-unique id for host code registered with a render, such as
+/// custom filters or an output hook
+///
+/// Closures cannot be compared, so fragments rendered with them are keyed by
+/// their registration instead: they are reused by renders sharing the same
+/// registration, and never by a process that registered them again.
+pub(crate) fn registration_id() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Pluggable store for rendered fragments.
+///
+/// Methods take `&self` so one store can be shared by concurrent renders.
+pub trait FragmentCache: Send + Sync {
+    /// Look up a previously rendered fragment
+    fn get(&self, key: &FragmentKey) -> Option<String>;
+
+    /// Store a rendered fragment
+    fn insert(&self, key: FragmentKey, fragment: String);
+}
+
+/// In-memory fragment cache evicting the least recently used entry.
+#[derive(Debug)]
+pub struct LruFragmentCache {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    entries: HashMap<FragmentKey, (String, u64)>,
+    recency: BTreeMap<u64, FragmentKey>,
+    tick: u64,
+}
+
+impl LruState {
+    fn touch(&mut self, key: &FragmentKey) -> Option<&String> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (fragment, last_used) = self.entries.get_mut(key)?;
+        self.recency.remove(last_used);
+        *last_used = tick;
+        self.recency.insert(tick, key.clone());
+        Some(fragment)
+    }
+}
+
+impl LruFragmentCache {
+    /// Create a cache holding at most `capacity` fragments
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Number of cached fragments
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all cached fragments
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+        state.recency.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl FragmentCache for LruFragmentCache {
+    fn get(&self, key: &FragmentKey) -> Option<String> {
+        self.lock().touch(key).cloned()
+    }
+
+    fn insert(&self, key: FragmentKey, fragment: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut state = self.lock();
+        if state.touch(&key).is_some() {
+            if let Some((existing, _)) = state.entries.get_mut(&key) {
+                *existing = fragment;
+            }
+            return;
+        }
+        if state.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = state.recency.pop_first() {
+                state.entries.remove(&oldest);
+            }
+        }
+        state.tick += 1;
+        let tick = state.tick;
+        state.recency.insert(tick, key.clone());
+        state.entries.insert(key, (fragment, tick));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = LruFragmentCache::new(2);
+        cache.insert(FragmentKey::new(1, "a"), "A".to_string());
+        cache.insert(FragmentKey::new(1, "b"), "B".to_string());
+        assert_eq!(cache.get(&FragmentKey::new(1, "a")).as_deref(), Some("A"));
+
+        cache.insert(FragmentKey::new(1, "c"), "C".to_string());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&FragmentKey::new(1, "b")), None);
+        assert_eq!(cache.get(&FragmentKey::new(1, "c")).as_deref(), Some("C"));
+    }
+
+    #[test]
+    fn test_zero_capacity_stores_nothing() {
+        let cache = LruFragmentCache::new(0);
+        cache.insert(FragmentKey::new(1, "a"), "A".to_string());
+        assert!(cache.is_empty());
+    }
+}
@@ -1,7 +1,26 @@
 //! HTML escaping for template output.
 
+use std::fmt::Write;
+
+/// Which characters variable output escapes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EscapeMode {
+    /// `& < > " '`, as required by the spec
+    #[default]
+    Standard,
+    /// Also `` ` `` and `=`, and every non-ASCII character as a numeric
+    /// reference, for unquoted attributes and legacy email clients
+    Extended,
+}
+
 /// Escape HTML special characters: & < > " '
 pub fn escape(input: &str) -> String {
+    escape_with(input, EscapeMode::Standard)
+}
+
+/// Escape `input` for output under `mode`
+pub fn escape_with(input: &str, mode: EscapeMode) -> String {
     let mut output = String::with_capacity(input.len());
     for c in input.chars() {
         match c {
@@ -10,6 +29,12 @@ pub fn escape(input: &str) -> String {
             '>' => output.push_str("&gt;"),
             '"' => output.push_str("&quot;"),
             '\'' => output.push_str("&#39;"),
+            _ if mode == EscapeMode::Standard => output.push(c),
+            '`' => output.push_str("&#96;"),
+            '=' => output.push_str("&#61;"),
+            _ if !c.is_ascii() => {
+                let _ = write!(output, "&#{};", u32::from(c));
+            }
             _ => output.push(c),
         }
     }
@@ -57,4 +82,13 @@ mod tests {
     fn test_no_escape_needed() {
         assert_eq!(escape("Hello, world!"), "Hello, world!");
     }
+
+    #[test]
+    fn test_escape_extended() {
+        assert_eq!(escape("a=`b` 夏"), "a=`b` 夏");
+        assert_eq!(
+            escape_with("a=`b` <夏>😀", EscapeMode::Extended),
+            "a&#61;&#96;b&#96; &lt;&#22799;&gt;&#128512;"
+        );
+    }
 }
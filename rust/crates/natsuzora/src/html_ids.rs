@@ -0,0 +1,240 @@
+//! Duplicate `id` attributes in rendered HTML.
+//!
+//! An `id` written inside `{[#each]}` is repeated for every item unless it
+//! includes something unique to the item, which breaks label associations
+//! and fragment links. With [`RenderOptions::with_duplicate_id_check`],
+//! [`Natsuzora::render_detailed`] scans the output for repeated ids and
+//! reports where each copy was written in the templates:
+//!
+//! ```rust
+//! use natsuzora::{Natsuzora, RenderOptions};
+//! use serde_json::json;
+//!
+//! let tmpl = Natsuzora::parse(
+//!     "{[#each items as item]}\n<li id=\"row\">{[ item ]}</li>{[/each]}",
+//! )
+//! .unwrap();
+//! let options = RenderOptions::new().with_duplicate_id_check();
+//! let rendered = tmpl.render_detailed(json!({"items": ["a", "b"]}), &options).unwrap();
+//! let duplicate = &rendered.duplicate_ids[0];
+//! assert_eq!(duplicate.id, "row");
+//! assert_eq!(duplicate.sites.len(), 2);
+//! assert_eq!((duplicate.sites[0].location.line, duplicate.sites[0].location.column), (2, 5));
+//! ```
+//!
+//! Output that is rendered apart and inserted later, such as the body of a
+//! block include or a cached fragment, is attributed to the tag inserting it.
+//!
+//! [`RenderOptions::with_duplicate_id_check`]: crate::RenderOptions::with_duplicate_id_check
+//! [`Natsuzora::render_detailed`]: crate::Natsuzora::render_detailed
+
+use crate::error::Location;
+use crate::output::Origin;
+use std::collections::HashMap;
+
+/// An `id` value given to more than one element of a rendered document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateId {
+    /// The attribute value as it appears in the HTML
+    pub id: String,
+    /// Every element carrying the id, in output order
+    pub sites: Vec<IdSite>,
+}
+
+/// Where an `id` attribute was written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdSite {
+    /// Partial or layout name, `None` for the rendered template itself
+    pub template: Option<String>,
+    /// The `id` attribute when the template text wrote it, otherwise the
+    /// tag that output it
+    pub location: Location,
+    /// Byte offset of the attribute in the output
+    pub offset: usize,
+}
+
+/// Ids of `html` used more than once, ordered by their first use
+pub(crate) fn find_duplicates(html: &str, origins: &[(usize, Origin)]) -> Vec<DuplicateId> {
+    let mut offsets: Vec<(&str, Vec<usize>)> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for (id, offset) in id_attributes(html) {
+        let slot = *index.entry(id).or_insert_with(|| {
+            offsets.push((id, Vec::new()));
+            offsets.len() - 1
+        });
+        offsets[slot].1.push(offset);
+    }
+
+    offsets
+        .into_iter()
+        .filter(|(_, offsets)| offsets.len() > 1)
+        .map(|(id, offsets)| DuplicateId {
+            id: id.to_string(),
+            sites: offsets
+                .into_iter()
+                .map(|offset| site(html, origins, offset))
+                .collect(),
+        })
+        .collect()
+}
+
+fn site(html: &str, origins: &[(usize, Origin)], offset: usize) -> IdSite {
+    let run = origins.partition_point(|(start, _)| *start <= offset);
+    match run.checked_sub(1).map(|i| &origins[i]) {
+        Some((start, origin)) => IdSite {
+            template: origin.template.clone(),
+            location: if origin.verbatim {
+                origin.location.advanced(&html[*start..offset])
+            } else {
+                origin.location
+            },
+            offset,
+        },
+        None => IdSite {
+            template: None,
+            location: Location::default(),
+            offset,
+        },
+    }
+}
+
+/// Non-empty `id` attribute values of the start tags in `html`, with the
+/// offset of each attribute name
+fn id_attributes(html: &str) -> Vec<(&str, usize)> {
+    let bytes = html.as_bytes();
+    let mut found = Vec::new();
+    let mut pos = 0;
+    while let Some(lt) = html[pos..].find('<').map(|i| pos + i) {
+        if html[lt..].starts_with("<!--") {
+            pos = html[lt..].find("-->").map_or(html.len(), |i| lt + i + 3);
+            continue;
+        }
+        pos = lt + 1;
+        if !bytes.get(pos).is_some_and(u8::is_ascii_alphabetic) {
+            continue;
+        }
+        // Tag name
+        while pos < bytes.len() && !is_tag_space(bytes[pos]) && !matches!(bytes[pos], b'>' | b'/') {
+            pos += 1;
+        }
+        // Attributes up to the end of the tag
+        loop {
+            while pos < bytes.len() && (is_tag_space(bytes[pos]) || bytes[pos] == b'/') {
+                pos += 1;
+            }
+            if pos >= bytes.len() || bytes[pos] == b'>' {
+                break;
+            }
+            let name_start = pos;
+            while pos < bytes.len()
+                && !is_tag_space(bytes[pos])
+                && !matches!(bytes[pos], b'>' | b'=' | b'/')
+            {
+                pos += 1;
+            }
+            let name = &html[name_start..pos];
+            while pos < bytes.len() && is_tag_space(bytes[pos]) {
+                pos += 1;
+            }
+            if bytes.get(pos) != Some(&b'=') {
+                continue;
+            }
+            pos += 1;
+            while pos < bytes.len() && is_tag_space(bytes[pos]) {
+                pos += 1;
+            }
+            let value = match bytes.get(pos) {
+                Some(&quote @ (b'"' | b'\'')) => {
+                    let start = pos + 1;
+                    let end = html[start..]
+                        .find(quote as char)
+                        .map_or(html.len(), |i| start + i);
+                    pos = (end + 1).min(html.len());
+                    &html[start..end]
+                }
+                _ => {
+                    let start = pos;
+                    while pos < bytes.len() && !is_tag_space(bytes[pos]) && bytes[pos] != b'>' {
+                        pos += 1;
+                    }
+                    &html[start..pos]
+                }
+            };
+            if name.eq_ignore_ascii_case("id") && !value.is_empty() {
+                found.push((value, name_start));
+            }
+        }
+    }
+    found
+}
+
+fn is_tag_space(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r' | b'\x0c')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Natsuzora, RenderOptions};
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_id_attributes() {
+        let html = "<p id=a><input ID = 'b' disabled id=\"\"><!-- <i id=a> --><br/><a title=\"x>y\" id=\"c\">";
+        let ids: Vec<&str> = id_attributes(html).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        assert!(id_attributes("a < b, id=x").is_empty());
+    }
+
+    #[test]
+    fn test_sites_in_partials_and_layouts() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("_field.ntzr"), "<input id=\"{[ id ]}\">").unwrap();
+        fs::write(
+            dir.path().join("_layout.ntzr"),
+            "<main id=\"main\">{[#block body]}{[/block]}</main>",
+        )
+        .unwrap();
+        let tmpl = Natsuzora::parse_with_includes(
+            "{[!extends /layout ]}{[#block body]}\n  <p id=main>\
+             {[!include /field id=\"q\" ]}{[!include /field id=\"q\" ]}{[/block]}",
+            dir.path(),
+        )
+        .unwrap();
+        let options = RenderOptions::new().with_duplicate_id_check();
+        let rendered = tmpl.render_detailed(json!({}), &options).unwrap();
+
+        let sites: Vec<(&str, Option<&str>, usize, usize)> = rendered
+            .duplicate_ids
+            .iter()
+            .flat_map(|d| {
+                d.sites.iter().map(|s| {
+                    let template = s.template.as_deref();
+                    (d.id.as_str(), template, s.location.line, s.location.column)
+                })
+            })
+            .collect();
+        assert_eq!(
+            sites,
+            [
+                ("main", Some("/layout"), 1, 7),
+                ("main", None, 2, 6),
+                ("q", Some("/field"), 1, 8),
+                ("q", Some("/field"), 1, 8),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unique_ids_are_not_reported() {
+        let tmpl =
+            Natsuzora::parse("{[#each items as item]}<li id=\"row-{[ item ]}\">{[/each]}").unwrap();
+        let options = RenderOptions::new().with_duplicate_id_check();
+        let rendered = tmpl
+            .render_detailed(json!({"items": [1, 2]}), &options)
+            .unwrap();
+        assert!(rendered.duplicate_ids.is_empty());
+    }
+}
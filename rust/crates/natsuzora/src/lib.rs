@@ -17,27 +17,114 @@
 //!
 //! assert_eq!(result, "Hello, World!");
 //! ```
+//!
+//! The supported API is collected in [`prelude`].
 
 // Public modules
+pub mod analysis;
+pub mod audit;
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+mod binary;
+#[doc(hidden)]
 pub mod context;
+pub mod coverage;
+pub mod docs;
+pub mod duplicates;
+pub mod edge_include;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod error;
+pub mod extract;
+pub mod feed;
+pub mod filters;
+pub mod fragment_cache;
 pub mod html_escape;
+pub mod html_ids;
+pub mod options;
+mod output;
+pub mod prelude;
+pub mod profile;
+pub mod rebuild;
+pub mod refactor;
+pub mod render_builder;
 pub mod renderer;
+pub mod report;
+pub mod repro;
+mod serialize;
+#[cfg(feature = "signing")]
+pub mod signing;
+mod skeleton;
+pub mod stream;
 pub mod template_loader;
+pub mod translations;
 pub mod value;
+pub mod version;
 
+pub use analysis::{TemplateInfo, TemplateSet};
+pub use audit::{AuditEvent, AuditOptions, AuditSink, AUDIT_SCHEMA_VERSION};
+pub use coverage::{Coverage, CoverageReport};
+pub use docs::{DocFormat, DocGenerator};
+pub use duplicates::DuplicateGroup;
+pub use edge_include::{EdgeIncludeFormat, EdgeIncludes};
 pub use error::{NatsuzoraError, Result};
-pub use natsuzora_ast::{IncludeLoader, LoaderError, Location, Modifier, ParseError, Template};
-pub use renderer::Renderer;
-pub use template_loader::TemplateLoader;
-pub use value::Value;
+pub use extract::MessageCatalog;
+pub use filters::{CustomFilters, OutputHook};
+pub use fragment_cache::{FragmentCache, FragmentKey, LruFragmentCache};
+pub use html_escape::EscapeMode;
+pub use html_ids::{DuplicateId, IdSite};
+pub use natsuzora_ast::{
+    trace_trims, Delimiters, Filter, IncludeLoader, InvalidDelimiters, LoaderError, Location,
+    Modifier, Param, ParseError, ParseLimits, ParseOptions, Template, Trim, TrimCause,
+};
+pub use options::RenderOptions;
+pub use profile::{Profile, Profiles};
+pub use rebuild::{ContentHashes, PageToRender};
+pub use refactor::{extract_partial, rename_path, ExtractedPartial, FileDiff};
+pub use render_builder::RenderBuilder;
+pub use renderer::{FallbackUse, Rendered, Renderer};
+pub use report::{CheckReport, Diagnostic, Severity, REPORT_SCHEMA_VERSION};
+pub use repro::{ReproBundle, REPRO_SCHEMA_VERSION};
+pub use stream::StreamRenderer;
+pub use template_loader::{FnLoader, FsSource, SourceHook, TemplateLoader, TemplateSource};
+pub use translations::Translations;
+pub use value::{ConflictPath, IntegerPolicy, IntegerRange, NatsuzoraScalar, SharedData, Value};
+pub use version::{features, LANGUAGE_VERSION, VERSION};
 
+use context::Context;
+use serde::Serialize;
+use std::io::Write;
 use std::path::Path;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
 
 /// Main template struct for parsing once and rendering multiple times
+///
+/// `Natsuzora` is `Send + Sync`: share one instance between threads (e.g. in
+/// an `Arc`) and render concurrently. Each render uses its own context and
+/// include loader, so no locking is involved.
 pub struct Natsuzora {
     template: Template,
     include_root: Option<std::path::PathBuf>,
+    parse_options: ParseOptions,
+    edge_includes: Option<EdgeIncludes>,
+    template_source: Option<Arc<dyn TemplateSource>>,
+    source_hook: Option<Arc<dyn SourceHook>>,
+    profiles: Option<Arc<Profiles>>,
+    themes: Vec<String>,
+    /// `themes` joined, for fragment cache keys
+    theme_key: Option<String>,
+    locale: Option<String>,
+    /// Hash of the source, recorded in audit events; computed by the first
+    /// audited render
+    source_hash: OnceLock<String>,
+    audit: Option<(String, Arc<dyn AuditSink>)>,
+    filters: Option<Arc<CustomFilters>>,
+    output_hook: Option<(Arc<dyn OutputHook>, u64)>,
+    translations: Option<Arc<Translations>>,
+    /// Merged beneath the data of every render
+    default_data: Option<Arc<serde_json::Value>>,
+    /// Data integer bounds of renders whose options set none
+    integer_bounds: Option<(IntegerRange, IntegerPolicy)>,
 }
 
 impl Natsuzora {
@@ -53,14 +140,32 @@ impl Natsuzora {
     /// assert_eq!(result, "Hello, Alice!");
     /// ```
     pub fn parse(source: &str) -> Result<Self> {
-        let template = natsuzora_ast::parse(source).map_err(|e| NatsuzoraError::ParseError {
-            message: e.to_string(),
-            location: Location::default(),
-        })?;
-        Ok(Self {
-            template,
-            include_root: None,
-        })
+        Self::from_source(source, None, ParseOptions::default())
+    }
+
+    /// Parse a template source string with [`ParseOptions`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use natsuzora::{Natsuzora, ParseOptions};
+    /// use serde_json::json;
+    ///
+    /// let options = ParseOptions {
+    ///     trim_blocks: true,
+    ///     lstrip_blocks: true,
+    ///     ..ParseOptions::default()
+    /// };
+    /// let tmpl = Natsuzora::parse_with_options(
+    ///     "<ul>\n  {[#each xs as x]}\n  <li>{[ x ]}</li>\n  {[/each]}\n</ul>",
+    ///     options,
+    /// )
+    /// .unwrap();
+    /// let result = tmpl.render(json!({"xs": [1, 2]})).unwrap();
+    /// assert_eq!(result, "<ul>\n  <li>1</li>\n  <li>2</li>\n</ul>");
+    /// ```
+    pub fn parse_with_options(source: &str, options: ParseOptions) -> Result<Self> {
+        Self::from_source(source, None, options)
     }
 
     /// Parse a template with include support
@@ -74,26 +179,648 @@ impl Natsuzora {
     /// ).unwrap();
     /// ```
     pub fn parse_with_includes(source: &str, include_root: impl AsRef<Path>) -> Result<Self> {
-        let template = natsuzora_ast::parse(source).map_err(|e| NatsuzoraError::ParseError {
-            message: e.to_string(),
-            location: Location::default(),
+        Self::from_source(
+            source,
+            Some(include_root.as_ref().to_path_buf()),
+            ParseOptions::default(),
+        )
+    }
+
+    /// Parse a template with include support and [`ParseOptions`]
+    ///
+    /// The options also apply to every included template.
+    pub fn parse_with_includes_and_options(
+        source: &str,
+        include_root: impl AsRef<Path>,
+        options: ParseOptions,
+    ) -> Result<Self> {
+        Self::from_source(source, Some(include_root.as_ref().to_path_buf()), options)
+    }
+
+    fn from_source(
+        source: &str,
+        include_root: Option<std::path::PathBuf>,
+        parse_options: ParseOptions,
+    ) -> Result<Self> {
+        let template = natsuzora_ast::parse_with_options(source, &parse_options).map_err(|e| {
+            if e.is_limit() {
+                NatsuzoraError::ParseLimit {
+                    message: e.to_string(),
+                }
+            } else {
+                NatsuzoraError::ParseError {
+                    message: e.to_string(),
+                    location: Location::default(),
+                }
+            }
         })?;
         Ok(Self {
             template,
-            include_root: Some(include_root.as_ref().to_path_buf()),
+            include_root,
+            parse_options,
+            edge_includes: None,
+            template_source: None,
+            source_hook: None,
+            profiles: None,
+            themes: Vec::new(),
+            theme_key: None,
+            locale: None,
+            source_hash: OnceLock::new(),
+            audit: None,
+            filters: None,
+            output_hook: None,
+            translations: None,
+            default_data: None,
+            integer_bounds: None,
         })
     }
 
+    /// Emit the selected includes as ESI/SSI directives instead of rendering them
+    ///
+    /// Fails if a selected include is not referenced by the template.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use natsuzora::{EdgeIncludeFormat, EdgeIncludes, Natsuzora};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("{[!include /sidebar user=id ]}")
+    ///     .unwrap()
+    ///     .with_edge_includes(
+    ///         EdgeIncludes::new(EdgeIncludeFormat::Esi, "/fragments").include("/sidebar"),
+    ///     )
+    ///     .unwrap();
+    /// let result = tmpl.render(json!({"id": 42})).unwrap();
+    /// assert_eq!(result, r#"<esi:include src="/fragments/sidebar?user=42" />"#);
+    /// ```
+    pub fn with_edge_includes(mut self, edge_includes: EdgeIncludes) -> Result<Self> {
+        edge_includes.validate(&self.template)?;
+        self.edge_includes = Some(edge_includes);
+        Ok(self)
+    }
+
+    /// Resolve includes against a theme fallback chain
+    ///
+    /// `themes` are directories under the include root, most specific
+    /// first; an include comes from the first theme that has it, else from
+    /// the include root. Fragment cache keys include the chain, so brands
+    /// can share one [`FragmentCache`].
+    ///
+    /// ```rust,ignore
+    /// let tmpl = Natsuzora::parse_with_includes(source, "templates")?
+    ///     .with_themes(["themes/acme", "themes/default"]);
+    /// ```
+    pub fn with_themes<I, S>(mut self, themes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.themes = themes.into_iter().map(Into::into).collect();
+        self.theme_key = (!self.themes.is_empty()).then(|| self.themes.join(">"));
+        self
+    }
+
+    /// Prefer includes localized for `locale`
+    ///
+    /// `/greeting` then loads `_greeting.ja.ntzr` over `_greeting.ntzr` for
+    /// locale `ja`; a region such as `pt-BR` falls back to `pt` first. Only
+    /// the partials that have a localized file need one. `{[@t]}` messages
+    /// are looked up for the locale too, see [`with_translations`]. Fragment
+    /// cache keys include the locale.
+    ///
+    /// [`with_translations`]: Natsuzora::with_translations
+    ///
+    /// ```rust,ignore
+    /// let tmpl = Natsuzora::parse_with_includes(source, "templates")?.with_locale("ja");
+    /// ```
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Read included template files through `source`
+    ///
+    /// With the `encryption` feature, an
+    /// [`EncryptedSource`](crate::encryption::EncryptedSource) decrypts
+    /// partials stored encrypted on disk; with the `signing` feature, a
+    /// [`SignedSource`](crate::signing::SignedSource) rejects partials not
+    /// signed by the trusted build pipeline.
+    pub fn with_template_source(mut self, source: impl TemplateSource + 'static) -> Self {
+        self.template_source = Some(Arc::new(source));
+        self
+    }
+
+    /// Inspect every included template source before it is parsed
+    ///
+    /// An error from the hook fails the render, e.g. to reject partials
+    /// containing secrets or disallowed markup.
+    pub fn with_source_hook(mut self, hook: impl SourceHook + 'static) -> Self {
+        self.source_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Enforce `profiles` on every partial loaded through includes
+    ///
+    /// A partial that breaks the profile for its include name fails the
+    /// render when it is loaded, before any of it is rendered.
+    pub fn with_profiles(mut self, profiles: Profiles) -> Self {
+        self.profiles = Some(Arc::new(profiles));
+        self
+    }
+
+    /// Apply `filters` for the host-defined filter names in templates
+    ///
+    /// The names must also be in [`ParseOptions::filters`] when the
+    /// template is parsed, see [`filters`].
+    pub fn with_filters(mut self, filters: CustomFilters) -> Self {
+        self.filters = Some(Arc::new(filters));
+        self
+    }
+
+    /// Pass the text of every `{[ path ]}` output through `hook` before it
+    /// is escaped
+    ///
+    /// Unset by default. The hook must be pure, or renders stop being
+    /// deterministic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use natsuzora::Natsuzora;
+    /// use serde_json::json;
+    /// use std::borrow::Cow;
+    ///
+    /// fn smart_quotes<'a>(_path: &str, text: &'a str) -> Cow<'a, str> {
+    ///     if text.contains('\'') {
+    ///         Cow::Owned(text.replace('\'', "’"))
+    ///     } else {
+    ///         Cow::Borrowed(text)
+    ///     }
+    /// }
+    ///
+    /// let tmpl = Natsuzora::parse("<p>{[ quote ]}</p>")
+    ///     .unwrap()
+    ///     .with_output_hook(smart_quotes);
+    /// let result = tmpl.render(json!({"quote": "it's <ok>"})).unwrap();
+    /// assert_eq!(result, "<p>it’s &lt;ok&gt;</p>");
+    /// ```
+    pub fn with_output_hook(mut self, hook: impl OutputHook + 'static) -> Self {
+        self.output_hook = Some((Arc::new(hook), fragment_cache::registration_id()));
+        self
+    }
+
+    /// Render `{[@t key name=value ]}` tags from the `translations` catalog
+    ///
+    /// Messages are looked up for the locale set by [`with_locale`]; a key
+    /// missing from every candidate locale fails the render.
+    ///
+    /// [`with_locale`]: Natsuzora::with_locale
+    pub fn with_translations(mut self, translations: Translations) -> Self {
+        self.translations = Some(Arc::new(translations));
+        self
+    }
+
+    /// Accept data integers in `range` in every render, converting the rest
+    /// per `policy`, e.g. to print 64-bit IDs
+    ///
+    /// Unlike [`RenderOptions::with_integer_bounds`], this also applies to
+    /// renders that take no options, such as [`Self::render_serialize`].
+    ///
+    /// ```rust
+    /// use natsuzora::{IntegerPolicy, IntegerRange, Natsuzora};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("{[ id ]}")
+    ///     .unwrap()
+    ///     .with_integer_bounds(IntegerRange::Full, IntegerPolicy::Stringify);
+    /// assert_eq!(tmpl.render(json!({"id": u64::MAX})).unwrap(), "18446744073709551615");
+    /// let ids = std::collections::BTreeMap::from([("id", i64::MAX)]);
+    /// assert_eq!(tmpl.render_serialize(&ids).unwrap(), "9223372036854775807");
+    /// ```
+    pub fn with_integer_bounds(mut self, range: IntegerRange, policy: IntegerPolicy) -> Self {
+        self.integer_bounds = Some((range, policy));
+        self
+    }
+
+    /// Merge `defaults` beneath the data of every render
+    ///
+    /// The render data is laid over the defaults with [`value::merge`]:
+    /// objects are merged key by key at every depth, and the render data's
+    /// arrays, scalars and `null`s replace the default wholesale.
+    /// Fails unless `defaults` is an object; its values are checked like
+    /// render data, when rendering.
+    ///
+    /// ```rust
+    /// use natsuzora::Natsuzora;
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("{[ site.name ]}: {[ site.lang ]}")
+    ///     .unwrap()
+    ///     .with_default_data(json!({"site": {"name": "Natsuzora", "lang": "ja"}}))
+    ///     .unwrap();
+    /// let result = tmpl.render(json!({"site": {"lang": "en"}})).unwrap();
+    /// assert_eq!(result, "Natsuzora: en");
+    /// ```
+    pub fn with_default_data(mut self, defaults: serde_json::Value) -> Result<Self> {
+        if !defaults.is_object() {
+            return Err(NatsuzoraError::TypeError {
+                message: "Default data must be an object".to_string(),
+            });
+        }
+        self.default_data = Some(Arc::new(defaults));
+        Ok(self)
+    }
+
+    /// Record an [`AuditEvent`] named `name` in `sink` for every render
+    ///
+    /// Events are emitted for failed renders too, with the error's
+    /// [`NatsuzoraError::code`]. The data is only hashed while a sink is set.
+    pub fn with_audit_sink(
+        mut self,
+        name: impl Into<String>,
+        sink: impl AuditSink + 'static,
+    ) -> Self {
+        self.audit = Some((name.into(), Arc::new(sink)));
+        self
+    }
+
+    /// Start a render that combines any of the per-render settings, see
+    /// [`RenderBuilder`]
+    pub fn render_builder(&self) -> RenderBuilder<'_> {
+        RenderBuilder::new(self)
+    }
+
     /// Render the template with the given JSON data
     pub fn render(&self, data: serde_json::Value) -> Result<String> {
-        let value = Value::from_json(data)?;
-        let mut loader = self
-            .include_root
-            .as_ref()
-            .map(TemplateLoader::new)
-            .transpose()?;
-        let mut renderer = Renderer::new(loader.as_mut());
-        renderer.render(&self.template, value)
+        self.render_builder().render(data)
+    }
+
+    /// Render the template with the given JSON data into `buf`
+    ///
+    /// `buf` is cleared first and keeps its allocation, so a server can reuse
+    /// one buffer across requests. After a failed render it holds the output
+    /// up to the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    ///
+    /// let tmpl = natsuzora::Natsuzora::parse("Hello, {[ name ]}!").unwrap();
+    /// let mut buf = String::with_capacity(4096);
+    /// for name in ["Alice", "Bob"] {
+    ///     tmpl.render_into(json!({"name": name}), &mut buf).unwrap();
+    ///     assert_eq!(buf, format!("Hello, {name}!"));
+    /// }
+    /// assert!(buf.capacity() >= 4096);
+    /// ```
+    pub fn render_into(&self, data: serde_json::Value, buf: &mut String) -> Result<()> {
+        self.render_builder().render_into(data, buf)
+    }
+
+    /// Render the template with the given JSON data, writing the output to
+    /// `out` as it is rendered instead of building it in memory
+    ///
+    /// The output is written in chunks, so a render that fails may have
+    /// written part of it already. `out` is flushed at the end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    ///
+    /// let tmpl = natsuzora::Natsuzora::parse("{[#each rows as row]}<p>{[ row ]}</p>{[/each]}").unwrap();
+    /// let mut out = Vec::new();
+    /// tmpl.render_to(json!({"rows": [1, 2]}), &mut out).unwrap();
+    /// assert_eq!(out, b"<p>1</p><p>2</p>");
+    /// ```
+    pub fn render_to(&self, data: serde_json::Value, out: &mut impl Write) -> Result<()> {
+        self.render_builder().render_to(data, out)
+    }
+
+    /// Render the template with borrowed JSON data
+    ///
+    /// Unlike [`Self::render`], the data is not converted up front: each
+    /// top-level entry is converted the first time the template reaches it,
+    /// so entries the template never uses cost nothing. Out-of-range integers
+    /// fail the render wherever they are, as with [`Self::render`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    ///
+    /// let tmpl = natsuzora::Natsuzora::parse("Hello, {[ name ]}!").unwrap();
+    /// let data = json!({"name": "Alice", "history": [1, 2, 3]});
+    /// assert_eq!(tmpl.render_json(&data).unwrap(), "Hello, Alice!");
+    /// ```
+    pub fn render_json(&self, data: &serde_json::Value) -> Result<String> {
+        self.render_builder().render(data)
+    }
+
+    /// Render the template with per-render data over [`SharedData`]
+    ///
+    /// The shared values are borrowed rather than copied, so a big object
+    /// used by every render of a build is converted once.
+    pub fn render_with_shared(
+        &self,
+        data: serde_json::Value,
+        shared: &SharedData,
+    ) -> Result<String> {
+        self.render_builder().with_shared(shared).render(data)
+    }
+
+    /// Render the template with any `serde::Serialize` data
+    ///
+    /// The data is converted straight into template values, without building
+    /// a `serde_json::Value` first; the output is the same as rendering
+    /// `serde_json::to_value(data)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use natsuzora::Natsuzora;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct User<'a> {
+    ///     name: &'a str,
+    /// }
+    ///
+    /// let tmpl = Natsuzora::parse("Hello, {[ name ]}!").unwrap();
+    /// let result = tmpl.render_serialize(&User { name: "Alice" }).unwrap();
+    /// assert_eq!(result, "Hello, Alice!");
+    /// ```
+    pub fn render_serialize(&self, data: &impl Serialize) -> Result<String> {
+        self.render_builder().render(Serialized(data))
+    }
+
+    /// Render the template with data already built as a [`Value`], e.g. by
+    /// [`value!`]
+    pub fn render_value(&self, data: Value) -> Result<String> {
+        self.render_builder().render(data)
+    }
+
+    /// Render the template with per-render options
+    pub fn render_with_options(
+        &self,
+        data: serde_json::Value,
+        options: &RenderOptions,
+    ) -> Result<String> {
+        self.render_builder().with_options(options).render(data)
+    }
+
+    /// Render the template with per-render options, reporting fallbacks used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use natsuzora::{Natsuzora, RenderOptions, Value};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("{[ user.name ]}").unwrap();
+    /// let options = RenderOptions::new()
+    ///     .with_fallback("user.name", Value::String("Lorem ipsum".into()));
+    /// let rendered = tmpl.render_detailed(json!({"user": {}}), &options).unwrap();
+    /// assert_eq!(rendered.output, "Lorem ipsum");
+    /// assert_eq!(rendered.fallbacks_used[0].path, "user.name");
+    /// ```
+    pub fn render_detailed(
+        &self,
+        data: serde_json::Value,
+        options: &RenderOptions,
+    ) -> Result<Rendered> {
+        self.render_builder()
+            .with_options(options)
+            .render_detailed(data)
+    }
+
+    /// Render the template while recording branch coverage under `name`
+    ///
+    /// The same collector can be passed to many renders to aggregate coverage
+    /// across a test corpus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    ///
+    /// let tmpl = natsuzora::Natsuzora::parse("{[#if admin]}Admin{[/if]}").unwrap();
+    /// let mut coverage = natsuzora::Coverage::new();
+    /// tmpl.render_with_coverage(json!({"admin": false}), "page", &mut coverage).unwrap();
+    /// assert_eq!(coverage.report().uncovered().count(), 1);
+    /// ```
+    pub fn render_with_coverage(
+        &self,
+        data: serde_json::Value,
+        name: &str,
+        coverage: &mut Coverage,
+    ) -> Result<String> {
+        self.render_builder()
+            .with_coverage(name, coverage)
+            .render(data)
+    }
+
+    /// Render the template, memoizing `{[#cache]}` blocks in `cache`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    ///
+    /// let tmpl = natsuzora::Natsuzora::parse("{[#cache key=id ]}{[ name ]}{[/cache]}").unwrap();
+    /// let cache = natsuzora::LruFragmentCache::new(100);
+    /// tmpl.render_with_fragment_cache(json!({"id": 1, "name": "a"}), &cache).unwrap();
+    /// let result = tmpl
+    ///     .render_with_fragment_cache(json!({"id": 1, "name": "b"}), &cache)
+    ///     .unwrap();
+    /// assert_eq!(result, "a");
+    /// ```
+    pub fn render_with_fragment_cache(
+        &self,
+        data: serde_json::Value,
+        cache: &dyn FragmentCache,
+    ) -> Result<String> {
+        self.render_builder()
+            .with_fragment_cache(cache)
+            .render(data)
+    }
+
+    /// Render like [`Self::render_with_options`], capturing everything needed
+    /// to reproduce the render in a [`ReproBundle`]
+    ///
+    /// A failed render is captured too; only failing to set up the include
+    /// loader is an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use natsuzora::{Natsuzora, RenderOptions};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("Hi {[ name | upper ]}").unwrap();
+    /// let bundle = tmpl
+    ///     .capture_repro(json!({"name": "ann"}), &RenderOptions::new())
+    ///     .unwrap();
+    /// assert_eq!(bundle.result.as_deref(), Ok("Hi ANN"));
+    /// assert_eq!(Natsuzora::replay(&bundle).unwrap(), "Hi ANN");
+    /// ```
+    pub fn capture_repro(
+        &self,
+        data: serde_json::Value,
+        options: &RenderOptions,
+    ) -> Result<ReproBundle> {
+        // The bundle keeps the template's integer bounds as render options
+        let options = match (options.integer_bounds(), self.integer_bounds) {
+            (None, Some((range, policy))) => options.clone().with_integer_bounds(range, policy),
+            _ => options.clone(),
+        };
+        let mut loader = self.loader()?.map(TemplateLoader::recording);
+        let result = self.audited(data.clone(), Some(&options), |context| {
+            let mut renderer = self.renderer(loader.as_mut()).with_options(&options);
+            renderer.render_context(&self.template, context)
+        });
+        Ok(ReproBundle {
+            natsuzora_version: VERSION.to_string(),
+            source: self.source().to_string(),
+            partials: loader
+                .as_mut()
+                .map(TemplateLoader::take_recorded)
+                .unwrap_or_default(),
+            data,
+            parse_options: self.parse_options.clone(),
+            render_options: options,
+            locale: self.locale.clone(),
+            default_data: self.default_data.as_deref().cloned(),
+            translations: self.translations.as_deref().cloned(),
+            result: result.map_err(|e| e.to_string()),
+        })
+    }
+
+    /// Render a [`ReproBundle`] again, from its captured sources only
+    pub fn replay(bundle: &ReproBundle) -> Result<String> {
+        let mut tmpl = Self::parse_with_options(&bundle.source, bundle.parse_options.clone())?;
+        tmpl.locale = bundle.locale.clone();
+        tmpl.default_data = bundle.default_data.clone().map(Arc::new);
+        tmpl.translations = bundle.translations.clone().map(Arc::new);
+        let mut loader = repro::BundleLoader { bundle };
+        tmpl.audited(
+            bundle.data.clone(),
+            Some(&bundle.render_options),
+            |context| {
+                tmpl.renderer(None)
+                    .with_include_loader(&mut loader)
+                    .with_options(&bundle.render_options)
+                    .render_context(&tmpl.template, context)
+            },
+        )
+    }
+
+    /// Render a data-free wireframe of the template for design review
+    ///
+    /// Variables become placeholders, every conditional branch is rendered
+    /// once between `⟦…⟧` markers, and loop bodies and includes are rendered
+    /// once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let tmpl = natsuzora::Natsuzora::parse(
+    ///     "{[#if user]}Hi {[ user.name ]}{[#else]}Sign in{[/if]}",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     tmpl.render_skeleton().unwrap(),
+    ///     "⟦if user⟧Hi ⟨user.name⟩⟦else⟧Sign in⟦/if⟧"
+    /// );
+    /// ```
+    pub fn render_skeleton(&self) -> Result<String> {
+        let mut loader = self.loader()?;
+        skeleton::Skeleton::new(loader.as_mut()).render(&self.template)
+    }
+
+    /// Run `render` on a context over the data, reporting it to the audit sink
+    fn audited<'d, T>(
+        &self,
+        data: impl RenderData<'d>,
+        options: Option<&RenderOptions>,
+        render: impl FnOnce(Context<'d>) -> Result<T>,
+    ) -> Result<T> {
+        let (range, policy) = options
+            .and_then(RenderOptions::integer_bounds)
+            .or(self.integer_bounds)
+            .unwrap_or_default();
+        let defaults = self.default_data.as_deref();
+        let Some((name, sink)) = &self.audit else {
+            return render(data.into_context(range, policy, defaults)?);
+        };
+        let started = Instant::now();
+        let data_hash = audit::stable_hash(data.json_text().as_bytes());
+        let result = data.into_context(range, policy, defaults).and_then(render);
+        sink.record(&AuditEvent {
+            template: name.clone(),
+            template_hash: self
+                .source_hash
+                .get_or_init(|| audit::stable_hash(self.source().as_bytes()))
+                .clone(),
+            data_hash,
+            options: AuditOptions::new(options, &self.themes, self.locale.as_deref()),
+            duration: started.elapsed(),
+            error_code: audit::error_code(&result),
+        });
+        result
+    }
+
+    fn loader(&self) -> Result<Option<TemplateLoader>> {
+        let Some(root) = &self.include_root else {
+            return Ok(None);
+        };
+        let mut loader = TemplateLoader::new(root)?.with_parse_options(self.parse_options.clone());
+        if let Some(source) = &self.template_source {
+            loader = loader.with_source(Arc::clone(source));
+        }
+        if let Some(hook) = &self.source_hook {
+            loader = loader.with_source_hook(Arc::clone(hook));
+        }
+        if let Some(profiles) = &self.profiles {
+            loader = loader.with_profiles(Arc::clone(profiles));
+        }
+        if !self.themes.is_empty() {
+            loader = loader.with_themes(&self.themes)?;
+        }
+        if let Some(locale) = &self.locale {
+            loader = loader.with_locale(locale)?;
+        }
+        Ok(Some(loader))
+    }
+
+    fn renderer<'a>(&'a self, loader: Option<&'a mut TemplateLoader>) -> Renderer<'a> {
+        let mut renderer = Renderer::new(loader);
+        if let Some(theme) = &self.theme_key {
+            renderer = renderer.with_theme(theme);
+        }
+        if let Some(locale) = &self.locale {
+            renderer = renderer.with_locale(locale);
+        }
+        if let Some(filters) = &self.filters {
+            renderer = renderer.with_filters(filters);
+        }
+        if let Some((hook, id)) = &self.output_hook {
+            renderer = renderer.with_registered_output_hook(hook.as_ref(), *id);
+        }
+        if let Some(translations) = &self.translations {
+            renderer = renderer.with_translations(translations);
+        }
+        match &self.edge_includes {
+            Some(edge_includes) => renderer.with_edge_includes(edge_includes),
+            None => renderer,
+        }
+    }
+
+    /// Source text of the template, shared with its text nodes
+    fn source(&self) -> &str {
+        self.template.source().unwrap_or_default()
     }
 
     /// Get a reference to the parsed template
@@ -102,6 +829,131 @@ impl Natsuzora {
     }
 }
 
+/// Data a render accepts: `serde_json::Value`, `&serde_json::Value`,
+/// [`Value`] and any `serde::Serialize` data wrapped in [`Serialized`]
+///
+/// Each is converted into template values its own way, see
+/// [`Natsuzora::render_json`], [`Natsuzora::render_serialize`] and
+/// [`Natsuzora::render_value`]. The trait is sealed.
+pub trait RenderData<'d>: sealed::IntoContext<'d> {}
+
+impl<'d, T: sealed::IntoContext<'d>> RenderData<'d> for T {}
+
+mod sealed {
+    use super::*;
+
+    /// The conversions behind [`RenderData`]
+    pub trait IntoContext<'d>: Sized {
+        fn into_value(self, range: IntegerRange, policy: IntegerPolicy) -> Result<Value>;
+
+        /// A context over the data, under `defaults` when the template has any
+        fn into_context(
+            self,
+            range: IntegerRange,
+            policy: IntegerPolicy,
+            defaults: Option<&serde_json::Value>,
+        ) -> Result<Context<'d>> {
+            let data = self.into_value(range, policy)?;
+            match defaults {
+                Some(defaults) => Context::new(under_defaults(data, defaults, range, policy)?),
+                None => Context::new(data),
+            }
+        }
+
+        /// The data as JSON text with sorted keys, hashed for audit events
+        fn json_text(&self) -> String;
+    }
+}
+
+impl sealed::IntoContext<'_> for serde_json::Value {
+    fn into_value(self, range: IntegerRange, policy: IntegerPolicy) -> Result<Value> {
+        Value::from_json_with(self, range, policy)
+    }
+
+    fn json_text(&self) -> String {
+        audit::sorted_json(self)
+    }
+}
+
+/// Borrowed JSON is only converted where the template reaches it, unless it
+/// has to be merged with default data
+impl<'d> sealed::IntoContext<'d> for &'d serde_json::Value {
+    fn into_value(self, range: IntegerRange, policy: IntegerPolicy) -> Result<Value> {
+        serialize::to_value(self, range, policy)
+    }
+
+    fn into_context(
+        self,
+        range: IntegerRange,
+        policy: IntegerPolicy,
+        defaults: Option<&serde_json::Value>,
+    ) -> Result<Context<'d>> {
+        match defaults {
+            Some(defaults) => {
+                let data = self.into_value(range, policy)?;
+                Context::new(under_defaults(data, defaults, range, policy)?)
+            }
+            None => Context::from_json(self, range, policy),
+        }
+    }
+
+    fn json_text(&self) -> String {
+        audit::sorted_json(self)
+    }
+}
+
+impl sealed::IntoContext<'_> for Value {
+    /// Built values were checked when they were built
+    fn into_value(self, _range: IntegerRange, _policy: IntegerPolicy) -> Result<Value> {
+        Ok(self)
+    }
+
+    fn json_text(&self) -> String {
+        Serialized(self).json_text()
+    }
+}
+
+/// `data` merged over the template's default data
+fn under_defaults(
+    data: Value,
+    defaults: &serde_json::Value,
+    range: IntegerRange,
+    policy: IntegerPolicy,
+) -> Result<Value> {
+    let defaults = Value::from_json_with(defaults.clone(), range, policy)?;
+    Ok(value::merge(defaults, data).0)
+}
+
+/// `serde::Serialize` data to render, converted as by
+/// [`Natsuzora::render_serialize`]
+///
+/// # Example
+///
+/// ```rust
+/// use natsuzora::{Natsuzora, RenderOptions, Serialized};
+/// use std::collections::BTreeMap;
+///
+/// let tmpl = Natsuzora::parse("{[ price ]}").unwrap();
+/// let options = RenderOptions::new().with_max_output_bytes(16);
+/// let data = BTreeMap::from([("price", 980)]);
+/// let result = tmpl.render_builder().with_options(&options).render(Serialized(&data));
+/// assert_eq!(result.unwrap(), "980");
+/// ```
+pub struct Serialized<'d, S: ?Sized>(pub &'d S);
+
+impl<S: Serialize + ?Sized> sealed::IntoContext<'_> for Serialized<'_, S> {
+    fn into_value(self, range: IntegerRange, policy: IntegerPolicy) -> Result<Value> {
+        serialize::to_value(self.0, range, policy)
+    }
+
+    fn json_text(&self) -> String {
+        // As JSON values, so the hash matches rendering the same data as JSON
+        serde_json::to_value(self.0)
+            .map(|json| audit::sorted_json(&json))
+            .unwrap_or_default()
+    }
+}
+
 /// Convenience function: parse and render in one call
 ///
 /// # Example
@@ -120,6 +972,43 @@ pub fn render(source: &str, data: serde_json::Value) -> Result<String> {
     Natsuzora::parse(source)?.render(data)
 }
 
+/// Render an already parsed template, loading includes through `loader`
+///
+/// The low-level entry point for callers that cache [`Template`]s themselves
+/// or resolve partials from somewhere other than the file system.
+///
+/// # Example
+///
+/// ```rust
+/// use natsuzora::{IncludeLoader, LoaderError, Template};
+/// use serde_json::json;
+///
+/// struct Memory;
+///
+/// impl IncludeLoader for Memory {
+///     fn load(&mut self, name: &str) -> Result<Template, LoaderError> {
+///         match name {
+///             "/greeting" => Ok(natsuzora_ast::parse("Hello, {[ name ]}!")?),
+///             _ => Err(format!("unknown partial {name}").into()),
+///         }
+///     }
+/// }
+///
+/// let template = natsuzora_ast::parse("{[!include /greeting name=user ]}").unwrap();
+/// let result = natsuzora::render_template(&template, json!({"user": "Alice"}), &mut Memory).unwrap();
+/// assert_eq!(result, "Hello, Alice!");
+/// ```
+pub fn render_template(
+    template: &Template,
+    data: serde_json::Value,
+    loader: &mut dyn IncludeLoader,
+) -> Result<String> {
+    let value = Value::from_json(data)?;
+    Renderer::new(None)
+        .with_include_loader(loader)
+        .render(template, value)
+}
+
 /// Convenience function: parse and render with include support
 ///
 /// # Example
@@ -141,10 +1030,258 @@ pub fn render_with_includes(
     Natsuzora::parse_with_includes(source, include_root)?.render(data)
 }
 
+/// Convenience function: render a template read from `reader` into `writer`
+/// in bounded memory, see [`StreamRenderer`]
+///
+/// # Example
+///
+/// ```rust
+/// use serde_json::json;
+///
+/// let mut html = Vec::new();
+/// natsuzora::render_stream("Hello, {[ name ]}!\n".as_bytes(), json!({"name": "World"}), &mut html)
+///     .unwrap();
+/// assert_eq!(html, b"Hello, World!\n");
+/// ```
+pub fn render_stream(
+    reader: impl std::io::BufRead,
+    data: serde_json::Value,
+    writer: impl std::io::Write,
+) -> Result<()> {
+    StreamRenderer::new().render(reader, data, writer)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::coverage::BranchKind;
     use serde_json::json;
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn test_default_data() {
+        let tmpl = Natsuzora::parse("{[ site.name ]}|{[ site.nav ]}|{[ site.footer? ]}|{[ page ]}")
+            .unwrap()
+            .with_default_data(json!({
+                "site": {"name": "A", "nav": ["x"], "footer": "F"},
+                "page": "home",
+            }))
+            .unwrap();
+        assert_eq!(
+            tmpl.render(json!({"site": {"nav": "N", "footer": null}}))
+                .unwrap(),
+            "A|N||home"
+        );
+        assert_eq!(
+            tmpl.render(json!({"page": "about", "site": {"nav": "M"}}))
+                .unwrap(),
+            "A|M|F|about"
+        );
+        assert_eq!(
+            tmpl.render_json(&json!({"site": {"nav": "N"}})).unwrap(),
+            "A|N|F|home"
+        );
+
+        let tmpl = Natsuzora::parse("x").unwrap();
+        assert!(tmpl.with_default_data(json!([1])).is_err());
+        let tmpl = Natsuzora::parse("{[ a ]}")
+            .unwrap()
+            .with_default_data(json!({"f": 1e20}))
+            .unwrap();
+        assert!(tmpl.render(json!({"a": "x"})).is_err());
+    }
+
+    #[test]
+    fn test_render_to_matches_render() {
+        let tmpl = Natsuzora::parse("{[#each rows as row]}<li>{[ row ]}</li>\n{[/each]}").unwrap();
+        let data = json!({"rows": (0..5000).collect::<Vec<_>>()});
+        let mut out = Vec::new();
+        tmpl.render_to(data.clone(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), tmpl.render(data).unwrap());
+
+        let tmpl = Natsuzora::parse("a{[ missing ]}").unwrap();
+        let mut out = Vec::new();
+        assert!(tmpl.render_to(json!({}), &mut out).is_err());
+    }
+
+    #[test]
+    fn test_render_builder() {
+        let tmpl = Natsuzora::parse(
+            "{[#if admin]}{[!include /badge name=site ]}{[/if]}{[#each rows as r]}{[ r ]}{[/each]}",
+        )
+        .unwrap();
+        let shared = SharedData::new().with("site", Value::String("Natsuzora".into()));
+        let options = RenderOptions::new().with_collected_errors();
+        let mut loader = FnLoader::new(|name: &str| match name {
+            "/badge" => Ok("[{[ name ]}]".to_string()),
+            _ => Err(NatsuzoraError::IncludeError {
+                message: format!("unknown partial {name}"),
+            }),
+        });
+        let mut coverage = Coverage::new();
+        let mut buf = String::new();
+        tmpl.render_builder()
+            .with_options(&options)
+            .with_shared(&shared)
+            .with_include_loader(&mut loader)
+            .with_coverage("page", &mut coverage)
+            .render_into(json!({"admin": true, "rows": [1, 2]}), &mut buf)
+            .unwrap();
+        assert_eq!(buf, "[Natsuzora]12");
+        let report = coverage.report();
+        let uncovered: Vec<BranchKind> = report.uncovered().map(|b| b.kind).collect();
+        assert_eq!(uncovered, vec![BranchKind::IfImplicitElse]);
+
+        // Settings apply whichever way the output is returned
+        let options = RenderOptions::new().with_max_loop_iterations(1);
+        let builder = || tmpl.render_builder().with_options(&options);
+        let data = json!({"admin": false, "rows": [1, 2]});
+        assert_eq!(
+            builder().render(data.clone()).unwrap_err().code(),
+            "iteration_limit"
+        );
+        assert_eq!(
+            builder()
+                .render_to(data.clone(), &mut Vec::new())
+                .unwrap_err()
+                .code(),
+            "iteration_limit"
+        );
+
+        // and whatever form the data comes in
+        let value = Value::from_json(data.clone()).unwrap();
+        for result in [
+            builder().render(&data),
+            builder().render(Serialized(&data)),
+            builder().render(value),
+        ] {
+            assert_eq!(result.unwrap_err().code(), "iteration_limit");
+        }
+    }
+
+    #[test]
+    fn test_max_output_bytes() {
+        let data = json!({"rows": [1, 2, 3, 4, 5, 6, 7, 8, 9], "id": 1});
+        let options = RenderOptions::new().with_max_output_bytes(10);
+        let render = |source: &str, options: &RenderOptions| {
+            Natsuzora::parse(source)
+                .unwrap()
+                .render_with_options(data.clone(), options)
+        };
+        assert_eq!(
+            render("{[#each rows as r]}{[ r ]}{[/each]}", &options).unwrap(),
+            "123456789"
+        );
+        let error = render("{[#each rows as r]}{[ r ]},{[/each]}", &options).unwrap_err();
+        assert_eq!(error.code(), "output_limit");
+
+        // Parts rendered aside count against the limit too
+        let cache = LruFragmentCache::new(10);
+        let tmpl =
+            Natsuzora::parse("ab{[#cache key=id ]}{[#each rows as r]}{[ r ]}{[/each]}{[/cache]}")
+                .unwrap();
+        let error = Renderer::new(None)
+            .with_options(&options)
+            .with_fragment_cache(&cache)
+            .render(tmpl.template(), Value::from_json(data.clone()).unwrap())
+            .unwrap_err();
+        assert_eq!(error.code(), "output_limit");
+        assert!(cache.is_empty());
+
+        let options = options.with_collected_errors();
+        let error = render(
+            "{[ missing ]}{[#each rows as r]}{[ r ]}-{[/each]}",
+            &options,
+        );
+        assert_eq!(error.unwrap_err().code(), "output_limit");
+    }
+
+    #[test]
+    fn test_iteration_limits() {
+        let data = json!({"rows": [[1, 2], [3, 4], [5, 6]], "n": 4});
+        let render = |source: &str, options: &RenderOptions| {
+            Natsuzora::parse(source)
+                .unwrap()
+                .render_with_options(data.clone(), options)
+        };
+        let nested = "{[#each rows as row]}{[#each row as x]}{[ x ]}{[/each]}{[/each]}";
+
+        let options = RenderOptions::new().with_max_loop_iterations(3);
+        assert_eq!(render(nested, &options).unwrap(), "123456");
+        assert_eq!(
+            render("{[#each 1..n as i limit=3]}{[ i ]}{[/each]}", &options).unwrap(),
+            "123"
+        );
+        let error = render("{[#each 1..n as i]}{[ i ]}{[/each]}", &options).unwrap_err();
+        assert_eq!(error.code(), "iteration_limit");
+
+        // The inner loop counts each time it runs
+        let options = RenderOptions::new().with_max_render_iterations(9);
+        assert_eq!(render(nested, &options).unwrap(), "123456");
+        let options = RenderOptions::new().with_max_render_iterations(8);
+        let error = render(nested, &options).unwrap_err();
+        assert_eq!(error.code(), "iteration_limit");
+
+        let options = options.with_collected_errors();
+        let error = render(&format!("{{[ missing ]}}{nested}"), &options);
+        assert_eq!(error.unwrap_err().code(), "iteration_limit");
+    }
+
+    #[test]
+    fn test_cancel_flag() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&cancel);
+        let filters = CustomFilters::new().with("stop", move |value: &Value| {
+            flag.store(true, Ordering::Relaxed);
+            Ok(value.clone())
+        });
+        let parse_options = ParseOptions {
+            filters: filters.names(),
+            ..ParseOptions::default()
+        };
+        let tmpl = Natsuzora::parse_with_options(
+            "{[#each rows as r]}{[ r ]}{[#if r == 2]}{[ r | stop ]}{[/if]}{[/each]}",
+            parse_options,
+        )
+        .unwrap()
+        .with_filters(filters);
+        let data = json!({"rows": [1, 2, 3]});
+
+        let options = RenderOptions::new().with_cancel_flag(Arc::clone(&cancel));
+        let error = tmpl
+            .render_with_options(data.clone(), &options)
+            .unwrap_err();
+        assert!(matches!(error, NatsuzoraError::Cancelled));
+
+        // Cancellation is not one of the collected errors
+        cancel.store(false, Ordering::Relaxed);
+        let options = options.with_collected_errors();
+        let error = tmpl
+            .render_with_options(data.clone(), &options)
+            .unwrap_err();
+        assert!(matches!(error, NatsuzoraError::Cancelled));
+
+        let tmpl = Natsuzora::parse("{[#each rows as r]}{[ r ]}{[/each]}").unwrap();
+        cancel.store(false, Ordering::Relaxed);
+        assert_eq!(tmpl.render_with_options(data, &options).unwrap(), "123");
+    }
+
+    #[test]
+    fn test_cancel_flag_stops_an_empty_loop() {
+        let tmpl = Natsuzora::parse("{[#each 1..1000000000000 as n]}{[/each]}").unwrap();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let options = RenderOptions::new().with_cancel_flag(Arc::clone(&cancel));
+
+        let canceller = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            cancel.store(true, Ordering::Relaxed);
+        });
+        let error = tmpl.render_with_options(json!({}), &options).unwrap_err();
+        assert!(matches!(error, NatsuzoraError::Cancelled));
+        canceller.join().unwrap();
+    }
 
     #[test]
     fn test_template_reuse() {
@@ -156,4 +1293,381 @@ mod tests {
         let result2 = tmpl.render(json!({"name": "Bob"})).unwrap();
         assert_eq!(result2, "Hello, Bob!");
     }
+
+    #[test]
+    fn test_render_template_with_custom_loader() {
+        struct Memory(HashMap<&'static str, &'static str>);
+
+        impl IncludeLoader for Memory {
+            fn load(&mut self, name: &str) -> std::result::Result<Template, LoaderError> {
+                let source = self.0.get(name).ok_or("no such partial")?;
+                Ok(natsuzora_ast::parse(source)?)
+            }
+        }
+
+        let mut loader = Memory(HashMap::from([
+            ("/item", "<{[ label ]}>"),
+            ("/loop", "{[!include /loop ]}"),
+        ]));
+        let template =
+            natsuzora_ast::parse("{[#each items as item]}{[!include /item label=item ]}{[/each]}")
+                .unwrap();
+        let result = render_template(&template, json!({"items": ["a", "b"]}), &mut loader);
+        assert_eq!(result.unwrap(), "<a><b>");
+
+        let template = natsuzora_ast::parse("{[!include /loop ]}").unwrap();
+        let err = render_template(&template, json!({}), &mut loader).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Include error: Circular include detected: /loop"
+        );
+
+        let template = natsuzora_ast::parse("{[!include /missing ]}").unwrap();
+        let err = render_template(&template, json!({}), &mut loader).unwrap_err();
+        assert_eq!(err.to_string(), "Include error: no such partial");
+    }
+
+    #[test]
+    fn test_render_non_ascii_text() {
+        let result = render("日本{[ a ]}語", json!({"a": "の"})).unwrap();
+        assert_eq!(result, "日本の語");
+    }
+
+    #[test]
+    fn test_fragment_cache_is_keyed_by_block_and_key() {
+        let tmpl = Natsuzora::parse(
+            "{[#cache key=id ]}<{[ name ]}>{[/cache]}{[#cache key=id ]}[{[ name ]}]{[/cache]}",
+        )
+        .unwrap();
+        let cache = LruFragmentCache::new(10);
+
+        let first = tmpl
+            .render_with_fragment_cache(json!({"id": 1, "name": "a"}), &cache)
+            .unwrap();
+        assert_eq!(first, "<a>[a]");
+        assert_eq!(cache.len(), 2);
+
+        let cached = tmpl
+            .render_with_fragment_cache(json!({"id": 1, "name": "b"}), &cache)
+            .unwrap();
+        assert_eq!(cached, "<a>[a]");
+
+        let other_key = tmpl
+            .render_with_fragment_cache(json!({"id": "2", "name": "b"}), &cache)
+            .unwrap();
+        assert_eq!(other_key, "<b>[b]");
+
+        let uncached = tmpl.render(json!({"id": 1, "name": "c"})).unwrap();
+        assert_eq!(uncached, "<c>[c]");
+    }
+
+    #[test]
+    fn test_fragment_cache_is_keyed_by_render_settings() {
+        let tmpl = Natsuzora::parse("{[#cache key=id ]}{[ name ]}{[/cache]}").unwrap();
+        let cache = LruFragmentCache::new(10);
+        let data = json!({"id": 1, "name": "夏="});
+        let extended = RenderOptions::new().with_escape_mode(EscapeMode::Extended);
+
+        let standard = tmpl.render_with_fragment_cache(data.clone(), &cache);
+        assert_eq!(standard.unwrap(), "夏=");
+        let escaped = tmpl
+            .render_builder()
+            .with_options(&extended)
+            .with_fragment_cache(&cache)
+            .render(data.clone());
+        assert_eq!(escaped.unwrap(), "&#22799;&#61;");
+        assert_eq!(cache.len(), 2);
+
+        fn upper<'a>(_: &str, text: &'a str) -> Cow<'a, str> {
+            Cow::Owned(text.to_uppercase())
+        }
+        let hooked = Natsuzora::parse("{[#cache key=id ]}{[ name ]}{[/cache]}")
+            .unwrap()
+            .with_output_hook(upper);
+        let upper = hooked.render_with_fragment_cache(json!({"id": 2, "name": "a"}), &cache);
+        assert_eq!(upper.unwrap(), "A");
+        let again = hooked.render_with_fragment_cache(json!({"id": 2, "name": "b"}), &cache);
+        assert_eq!(again.unwrap(), "A");
+        let plain = tmpl.render_with_fragment_cache(json!({"id": 2, "name": "a"}), &cache);
+        assert_eq!(plain.unwrap(), "a");
+        assert_eq!(cache.len(), 4);
+    }
+
+    #[test]
+    fn test_truncation_stops_render() {
+        let tmpl =
+            Natsuzora::parse("{[#each xs as x]}<li>{[ x ]}</li>{[/each]}{[ missing ]}").unwrap();
+        let options = RenderOptions::new().with_truncation(12, "...");
+        let result = tmpl
+            .render_with_options(json!({"xs": ["a", "b", "c"]}), &options)
+            .unwrap();
+        assert_eq!(result, "<li>a</li><l...");
+    }
+
+    #[test]
+    fn test_collected_errors() {
+        let tmpl = Natsuzora::parse(
+            "{[ a ]}{[#if missing]}{[ b ]}{[/if]}{[#each xs as x]}{[ x.name ]}{[/each]}{[ ok ]}",
+        )
+        .unwrap();
+        let options = RenderOptions::new().with_collected_errors();
+
+        let data = json!({"xs": [{"name": "n"}, {}], "ok": 1});
+        let Err(NatsuzoraError::Multiple { errors }) = tmpl.render_with_options(data, &options)
+        else {
+            panic!("expected multiple errors");
+        };
+        assert_eq!(errors.len(), 3);
+        assert!(matches!(
+            errors[0],
+            NatsuzoraError::UndefinedVariable { .. }
+        ));
+
+        let single = tmpl.render_with_options(
+            json!({"a": 1, "missing": false, "xs": [], "ok": 2}),
+            &options,
+        );
+        assert_eq!(single.unwrap(), "12");
+    }
+
+    #[test]
+    fn test_fallbacks_fire_only_for_undefined_paths() {
+        let tmpl = Natsuzora::parse(
+            "{[#each posts as post]}{[ post.title ]}/{[ post.image ]};{[/each]}{[ site ]}",
+        )
+        .unwrap();
+        let options = RenderOptions::new()
+            .with_fallback("post.image", Value::String("placeholder.png".into()))
+            .with_fallback("site", Value::String("Preview".into()))
+            .with_fallback("posts", Value::Array(vec![]));
+
+        let data = json!({"posts": [{"title": "A", "image": "a.png"}, {"title": "B"}]});
+        let rendered = tmpl.render_detailed(data, &options).unwrap();
+        assert_eq!(rendered.output, "A/a.png;B/placeholder.png;Preview");
+        let paths: Vec<&str> = rendered
+            .fallbacks_used
+            .iter()
+            .map(|used| used.path.as_str())
+            .collect();
+        assert_eq!(paths, vec!["post.image", "site"]);
+    }
+
+    #[test]
+    fn test_coverage_across_renders() {
+        let tmpl = Natsuzora::parse(
+            "{[#if a]}A{[#else]}B{[/if]}{[#unless a]}U{[/unless]}{[#each xs as x]}{[/each]}",
+        )
+        .unwrap();
+        let mut coverage = Coverage::new();
+
+        tmpl.render_with_coverage(json!({"a": true, "xs": []}), "t", &mut coverage)
+            .unwrap();
+        assert_eq!(coverage.report().covered(), 2);
+
+        tmpl.render_with_coverage(json!({"a": false, "xs": [1]}), "t", &mut coverage)
+            .unwrap();
+        let report = coverage.report();
+        assert_eq!(report.total(), 5);
+        assert_eq!(report.covered(), 5);
+    }
+
+    #[test]
+    fn test_parse_options_apply_to_includes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("_row.ntzr"),
+            "  {[#if x]}\n  <td>{[ x ]}</td>\n  {[/if]}\n",
+        )
+        .unwrap();
+        let options = ParseOptions {
+            trim_blocks: true,
+            lstrip_blocks: true,
+            ..ParseOptions::default()
+        };
+        let tmpl = Natsuzora::parse_with_includes_and_options(
+            "<tr>\n  {[#if x]}\n{[!include /row x=x ]}  {[/if]}\n</tr>",
+            dir.path(),
+            options,
+        )
+        .unwrap();
+        assert_eq!(
+            tmpl.render(json!({"x": 1})).unwrap(),
+            "<tr>\n  <td>1</td>\n</tr>"
+        );
+    }
+
+    #[test]
+    fn test_recursive_includes_up_to_max_depth() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("_comment.ntzr"),
+            "({[ c.body ]}{[#each c.replies as r]}{[!include /comment c=r ]}{[/each]})",
+        )
+        .unwrap();
+        let tmpl =
+            Natsuzora::parse_with_includes("{[!include /comment c=thread ]}", dir.path()).unwrap();
+        let data = json!({"thread": {"body": "a", "replies": [
+            {"body": "b", "replies": [{"body": "c", "replies": []}]},
+            {"body": "d", "replies": []},
+        ]}});
+
+        let err = tmpl.render(data.clone()).unwrap_err();
+        assert!(err.to_string().contains("Circular include detected"));
+        let options = RenderOptions::new().with_recursive_includes(2);
+        assert_eq!(
+            tmpl.render_with_options(data.clone(), &options).unwrap(),
+            "(a(b(c))(d))"
+        );
+        let options = RenderOptions::new().with_recursive_includes(1);
+        let err = tmpl.render_with_options(data, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Include error: Include recursion deeper than 1: /comment"
+        );
+    }
+
+    #[test]
+    fn test_blank_includes_collapsed() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("_badge.ntzr"),
+            "\n  {[#if n]}<b>{[ n ]}</b>{[/if]}\n",
+        )
+        .unwrap();
+        let tmpl =
+            Natsuzora::parse_with_includes("[{[!include /badge n=n ]}]", dir.path()).unwrap();
+        let options = RenderOptions::new().with_blank_includes_collapsed();
+        assert_eq!(
+            tmpl.render_with_options(json!({"n": 0}), &options).unwrap(),
+            "[]"
+        );
+        assert_eq!(
+            tmpl.render_with_options(json!({"n": 3}), &options).unwrap(),
+            "[\n  <b>3</b>\n]"
+        );
+        assert_eq!(tmpl.render(json!({"n": 0})).unwrap(), "[\n  \n]");
+    }
+
+    #[test]
+    fn test_parse_limits_apply_to_partials() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("_big.ntzr"), "x".repeat(100)).unwrap();
+        let options = ParseOptions {
+            limits: ParseLimits {
+                max_source_bytes: Some(64),
+                ..ParseLimits::default()
+            },
+            ..ParseOptions::default()
+        };
+        let error = Natsuzora::parse_with_options(&"x".repeat(65), options.clone()).err();
+        assert_eq!(error.map(|e| e.code()), Some("parse_limit"));
+
+        let tmpl =
+            Natsuzora::parse_with_includes_and_options("{[!include /big ]}", dir.path(), options)
+                .unwrap();
+        let error = tmpl.render(json!({})).unwrap_err();
+        assert_eq!(error.code(), "parse_limit");
+        assert!(error.to_string().contains("include '/big'"), "{error}");
+    }
+
+    #[test]
+    fn test_themes_fall_back_in_order() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("themes/acme")).unwrap();
+        std::fs::create_dir_all(root.join("themes/default")).unwrap();
+        for (path, source) in [
+            ("_nav.ntzr", "nav"),
+            ("_footer.ntzr", "root footer"),
+            ("themes/default/_header.ntzr", "default header"),
+            ("themes/default/_footer.ntzr", "default footer"),
+            ("themes/acme/_footer.ntzr", "acme footer"),
+        ] {
+            std::fs::write(root.join(path), source).unwrap();
+        }
+
+        let source = "{[!include /header ]}|{[!include /footer ]}|{[!include /nav ]}";
+        let acme = Natsuzora::parse_with_includes(source, root)
+            .unwrap()
+            .with_themes(["themes/acme", "themes/default"]);
+        assert_eq!(
+            acme.render(json!({})).unwrap(),
+            "default header|acme footer|nav"
+        );
+
+        let cache = LruFragmentCache::new(8);
+        let cached = "{[#cache key=id ]}{[!include /footer ]}{[/cache]}";
+        for (theme, expected) in [
+            ("themes/acme", "acme footer"),
+            ("themes/default", "default footer"),
+        ] {
+            let tmpl = Natsuzora::parse_with_includes(cached, root)
+                .unwrap()
+                .with_themes([theme]);
+            let result = tmpl
+                .render_with_fragment_cache(json!({"id": 1}), &cache)
+                .unwrap();
+            assert_eq!(result, expected);
+        }
+
+        let outside = Natsuzora::parse_with_includes("{[!include /nav ]}", root.join("themes"))
+            .unwrap()
+            .with_themes([".."]);
+        let err = outside.render(json!({})).unwrap_err();
+        assert!(err.to_string().contains("Theme outside include root"));
+    }
+
+    #[test]
+    fn test_locale_prefers_localized_partials() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("themes/acme")).unwrap();
+        for (path, source) in [
+            ("_greeting.ntzr", "Hello"),
+            ("_greeting.ja.ntzr", "こんにちは"),
+            ("_greeting.pt.ntzr", "Olá"),
+            ("_footer.ntzr", "footer"),
+            ("themes/acme/_greeting.ntzr", "Howdy"),
+        ] {
+            std::fs::write(root.join(path), source).unwrap();
+        }
+
+        let source = "{[!include /greeting ]}|{[!include /footer ]}";
+        for (locale, expected) in [
+            ("ja", "こんにちは|footer"),
+            ("pt-BR", "Olá|footer"),
+            ("fr", "Hello|footer"),
+        ] {
+            let tmpl = Natsuzora::parse_with_includes(source, root)
+                .unwrap()
+                .with_locale(locale);
+            assert_eq!(tmpl.render(json!({})).unwrap(), expected);
+        }
+
+        let themed = Natsuzora::parse_with_includes(source, root)
+            .unwrap()
+            .with_themes(["themes/acme"])
+            .with_locale("ja");
+        assert_eq!(themed.render(json!({})).unwrap(), "Howdy|footer");
+
+        let invalid = Natsuzora::parse_with_includes(source, root)
+            .unwrap()
+            .with_locale("../ja");
+        let err = invalid.render(json!({})).unwrap_err();
+        assert!(err.to_string().contains("Invalid locale"));
+    }
+
+    #[test]
+    fn test_output_hook_sees_path_and_text() {
+        fn tag<'a>(path: &str, text: &'a str) -> Cow<'a, str> {
+            Cow::Owned(format!("{path}={text}"))
+        }
+
+        let source = "{[ user.name | upper ]} {[ note? ]} {[!unsecure html ]}";
+        let data = json!({"user": {"name": "a&b"}, "note": null, "html": "<b>"});
+        let tmpl = Natsuzora::parse(source).unwrap();
+        assert_eq!(tmpl.render(data.clone()).unwrap(), "A&amp;B  <b>");
+        let hooked = tmpl.with_output_hook(tag);
+        assert_eq!(hooked.render(data).unwrap(), "user.name=A&amp;B note= <b>");
+    }
 }
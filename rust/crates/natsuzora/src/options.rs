@@ -0,0 +1,391 @@
+//! Per-render options.
+
+use crate::filters::DEFAULT_GROUP_SEPARATOR;
+use crate::html_escape::EscapeMode;
+use crate::value::{IntegerPolicy, IntegerRange, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Options applied to a single render.
+///
+/// # Example
+///
+/// ```rust
+/// use natsuzora::{Natsuzora, RenderOptions};
+/// use serde_json::json;
+///
+/// let tmpl = Natsuzora::parse("{[ body ]}").unwrap();
+/// let options = RenderOptions::new().with_truncation(8, "…");
+/// let result = tmpl
+///     .render_with_options(json!({"body": "Fish & Chips"}), &options)
+///     .unwrap();
+/// assert_eq!(result, "Fish …");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    truncation: Option<(usize, String)>,
+    max_output_bytes: Option<usize>,
+    max_loop_iterations: Option<usize>,
+    max_render_iterations: Option<usize>,
+    cancel: Option<Arc<AtomicBool>>,
+    collect_errors: bool,
+    fallbacks: HashMap<String, Value>,
+    integer_bounds: Option<(IntegerRange, IntegerPolicy)>,
+    escape_mode: EscapeMode,
+    include_recursion: usize,
+    collapse_blank_includes: bool,
+    group_separator: Option<String>,
+    dynamic_include_prefixes: Vec<String>,
+    check_duplicate_ids: bool,
+}
+
+impl RenderOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop rendering after `max_bytes` bytes of output and append `marker`
+    ///
+    /// The output is cut at a UTF-8 character boundary and never inside a
+    /// character reference such as `&amp;`, so it can be shorter than
+    /// `max_bytes`. The marker is not counted against the limit.
+    pub fn with_truncation(mut self, max_bytes: usize, marker: impl Into<String>) -> Self {
+        self.truncation = Some((max_bytes, marker.into()));
+        self
+    }
+
+    /// Fail the render with [`NatsuzoraError::OutputLimit`] once its output
+    /// would exceed `max_bytes` bytes
+    ///
+    /// Unlike [`Self::with_truncation`], this guards against templates that
+    /// are not trusted: the output is never built past the limit, including
+    /// parts rendered aside such as cached fragments and include bodies.
+    ///
+    /// ```rust
+    /// use natsuzora::{Natsuzora, RenderOptions};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("{[#each rows as row]}<p>{[ row ]}</p>{[/each]}").unwrap();
+    /// let options = RenderOptions::new().with_max_output_bytes(16);
+    /// let data = json!({"rows": [1, 2, 3, 4, 5]});
+    /// let error = tmpl.render_with_options(data, &options).unwrap_err();
+    /// assert_eq!(error.code(), "output_limit");
+    /// ```
+    ///
+    /// [`NatsuzoraError::OutputLimit`]: crate::NatsuzoraError::OutputLimit
+    pub fn with_max_output_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_output_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Fail the render with [`NatsuzoraError::IterationLimit`] when an
+    /// `each` would run its body more than `max` times
+    ///
+    /// The length of the collection is checked before its first iteration,
+    /// after `offset=` and `limit=`, so a huge array or range in untrusted
+    /// data fails at once instead of after rendering part of it.
+    ///
+    /// ```rust
+    /// use natsuzora::{Natsuzora, RenderOptions};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("{[#each 1..n as i]}{[ i ]}{[/each]}").unwrap();
+    /// let options = RenderOptions::new().with_max_loop_iterations(100);
+    /// assert!(tmpl.render_with_options(json!({"n": 100}), &options).is_ok());
+    /// let error = tmpl
+    ///     .render_with_options(json!({"n": 1_000_000_000}), &options)
+    ///     .unwrap_err();
+    /// assert_eq!(error.code(), "iteration_limit");
+    /// ```
+    ///
+    /// [`NatsuzoraError::IterationLimit`]: crate::NatsuzoraError::IterationLimit
+    pub fn with_max_loop_iterations(mut self, max: usize) -> Self {
+        self.max_loop_iterations = Some(max);
+        self
+    }
+
+    /// Fail the render with [`NatsuzoraError::IterationLimit`] when its
+    /// `each` loops would run their bodies more than `max` times in total
+    ///
+    /// Nested loops count every run of the inner loop, and loops in includes
+    /// count towards the same total.
+    ///
+    /// [`NatsuzoraError::IterationLimit`]: crate::NatsuzoraError::IterationLimit
+    pub fn with_max_render_iterations(mut self, max: usize) -> Self {
+        self.max_render_iterations = Some(max);
+        self
+    }
+
+    /// Fail the render with [`NatsuzoraError::Cancelled`] once `flag` is set
+    ///
+    /// The flag is checked before every node, so a render stopped from
+    /// another thread (e.g. on a request timeout) ends soon after, without
+    /// the output it has built so far. Options holding a flag are not
+    /// captured in repro bundles.
+    ///
+    /// ```rust
+    /// use natsuzora::{Natsuzora, RenderOptions};
+    /// use serde_json::json;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let tmpl = Natsuzora::parse("Hello, {[ name ]}!").unwrap();
+    /// let cancel = Arc::new(AtomicBool::new(false));
+    /// let options = RenderOptions::new().with_cancel_flag(Arc::clone(&cancel));
+    ///
+    /// // e.g. from a timer thread
+    /// cancel.store(true, Ordering::Relaxed);
+    /// let error = tmpl
+    ///     .render_with_options(json!({"name": "World"}), &options)
+    ///     .unwrap_err();
+    /// assert_eq!(error.code(), "cancelled");
+    /// ```
+    ///
+    /// [`NatsuzoraError::Cancelled`]: crate::NatsuzoraError::Cancelled
+    pub fn with_cancel_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(flag);
+        self
+    }
+
+    /// Keep going after an error and report every error of the render at once
+    ///
+    /// Output is only returned when the whole render succeeded; otherwise
+    /// the errors are returned as [`NatsuzoraError::Multiple`] (or the error
+    /// itself when there is only one). A failing condition or collection
+    /// skips its block, since its branches cannot be chosen.
+    ///
+    /// [`NatsuzoraError::Multiple`]: crate::NatsuzoraError::Multiple
+    pub fn with_collected_errors(mut self) -> Self {
+        self.collect_errors = true;
+        self
+    }
+
+    /// Use `value` when the path `path` (e.g. `user.avatar`) is undefined
+    ///
+    /// The template and data are untouched; which fallbacks fired is
+    /// reported by [`Natsuzora::render_detailed`].
+    ///
+    /// [`Natsuzora::render_detailed`]: crate::Natsuzora::render_detailed
+    pub fn with_fallback(mut self, path: impl Into<String>, value: Value) -> Self {
+        self.fallbacks.insert(path.into(), value);
+        self
+    }
+
+    /// Accept data integers in `range`, converting the rest per `policy`
+    ///
+    /// By default only the spec's safe range is accepted and anything else
+    /// is a type error, unless the template sets other bounds with
+    /// [`Natsuzora::with_integer_bounds`]; these override them. With
+    /// [`IntegerRange::Full`], IDs up to `i64::MAX` render as-is;
+    /// [`IntegerPolicy::Stringify`] also keeps larger `u64` values exact.
+    ///
+    /// [`Natsuzora::with_integer_bounds`]: crate::Natsuzora::with_integer_bounds
+    ///
+    /// ```rust
+    /// use natsuzora::{IntegerPolicy, IntegerRange, Natsuzora, RenderOptions};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("{[ id ]}").unwrap();
+    /// let options =
+    ///     RenderOptions::new().with_integer_bounds(IntegerRange::Full, IntegerPolicy::Stringify);
+    /// let result = tmpl
+    ///     .render_with_options(json!({"id": 18_446_744_073_709_551_615_u64}), &options)
+    ///     .unwrap();
+    /// assert_eq!(result, "18446744073709551615");
+    /// ```
+    pub fn with_integer_bounds(mut self, range: IntegerRange, policy: IntegerPolicy) -> Self {
+        self.integer_bounds = Some((range, policy));
+        self
+    }
+
+    /// Escape variable output under `mode` instead of [`EscapeMode::Standard`]
+    ///
+    /// ```rust
+    /// use natsuzora::{EscapeMode, Natsuzora, RenderOptions};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("<td class={[ class ]}>").unwrap();
+    /// let options = RenderOptions::new().with_escape_mode(EscapeMode::Extended);
+    /// let result = tmpl
+    ///     .render_with_options(json!({"class": "x onclick=go`1`"}), &options)
+    ///     .unwrap();
+    /// assert_eq!(result, "<td class=x onclick&#61;go&#96;1&#96;>");
+    /// ```
+    pub fn with_escape_mode(mut self, mode: EscapeMode) -> Self {
+        self.escape_mode = mode;
+        self
+    }
+
+    /// Let a partial include itself, directly or through other partials, up
+    /// to `max_depth` levels below its outermost use
+    ///
+    /// By default a partial that is already being rendered cannot be
+    /// included again (a circular include error). Tree-shaped data such as
+    /// comment threads can opt in; the partial stops the recursion itself,
+    /// e.g. by including only inside `{[#each comment.replies as reply]}`.
+    /// A recursive copy sees only its arguments and the root data, so its
+    /// loop variables may reuse the names of the outer copy's. Going deeper
+    /// than `max_depth` is an include error.
+    ///
+    /// ```rust,ignore
+    /// // _comment.ntzr: {[ c.body ]}{[#each c.replies as r]}{[!include /comment c=r ]}{[/each]}
+    /// let options = RenderOptions::new().with_recursive_includes(8);
+    /// tmpl.render_with_options(json!({"thread": thread}), &options)?;
+    /// ```
+    pub fn with_recursive_includes(mut self, max_depth: usize) -> Self {
+        self.include_recursion = max_depth;
+        self
+    }
+
+    /// Output nothing for an include whose output is only whitespace
+    ///
+    /// A partial that renders nothing for the given data often still
+    /// leaves its indentation and newlines behind; this drops them.
+    pub fn with_blank_includes_collapsed(mut self) -> Self {
+        self.collapse_blank_includes = true;
+        self
+    }
+
+    /// Separate the digit groups of `| group` with `separator` instead of `,`
+    ///
+    /// The separator is fixed per render rather than taken from the OS
+    /// locale, so the same data always renders the same text.
+    ///
+    /// ```rust
+    /// use natsuzora::{Natsuzora, RenderOptions};
+    /// use serde_json::json;
+    ///
+    /// let tmpl = Natsuzora::parse("{[ total | group ]}").unwrap();
+    /// let options = RenderOptions::new().with_group_separator(".");
+    /// let result = tmpl
+    ///     .render_with_options(json!({"total": 1234567}), &options)
+    ///     .unwrap();
+    /// assert_eq!(result, "1.234.567");
+    /// ```
+    pub fn with_group_separator(mut self, separator: impl Into<String>) -> Self {
+        self.group_separator = Some(separator.into());
+        self
+    }
+
+    /// Allow `{[!include widget.template ]}`, whose partial is named by a
+    /// string in the data, for names under `prefix`; may be repeated
+    ///
+    /// Dynamic includes are rejected unless a prefix allows them. The name
+    /// must be a valid include name such as `/widgets/hero`, so data cannot
+    /// reach outside the include root or the allowed directories.
+    ///
+    /// ```rust,ignore
+    /// // blocks: [{"template": "/widgets/hero", ...}, {"template": "/widgets/quote", ...}]
+    /// let options = RenderOptions::new().with_dynamic_includes_under("/widgets");
+    /// tmpl.render_with_options(json!({"blocks": blocks}), &options)?;
+    /// ```
+    pub fn with_dynamic_includes_under(mut self, prefix: impl Into<String>) -> Self {
+        self.dynamic_include_prefixes.push(prefix.into());
+        self
+    }
+
+    /// Report `id` attributes used more than once in the output, in
+    /// [`Rendered::duplicate_ids`](crate::Rendered::duplicate_ids)
+    ///
+    /// Each copy is located in the template that wrote it; see
+    /// [`html_ids`](crate::html_ids). The check only reads the output and
+    /// never fails the render.
+    pub fn with_duplicate_id_check(mut self) -> Self {
+        self.check_duplicate_ids = true;
+        self
+    }
+
+    pub(crate) fn max_output_bytes(&self) -> Option<usize> {
+        self.max_output_bytes
+    }
+
+    pub(crate) fn max_loop_iterations(&self) -> Option<usize> {
+        self.max_loop_iterations
+    }
+
+    pub(crate) fn max_render_iterations(&self) -> Option<usize> {
+        self.max_render_iterations
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn group_separator(&self) -> &str {
+        self.group_separator
+            .as_deref()
+            .unwrap_or(DEFAULT_GROUP_SEPARATOR)
+    }
+
+    pub(crate) fn dynamic_include_prefixes(&self) -> &[String] {
+        &self.dynamic_include_prefixes
+    }
+
+    pub(crate) fn check_duplicate_ids(&self) -> bool {
+        self.check_duplicate_ids
+    }
+
+    pub(crate) fn collapse_blank_includes(&self) -> bool {
+        self.collapse_blank_includes
+    }
+
+    pub(crate) fn include_recursion(&self) -> usize {
+        self.include_recursion
+    }
+
+    pub(crate) fn escape_mode(&self) -> EscapeMode {
+        self.escape_mode
+    }
+
+    pub(crate) fn integer_bounds(&self) -> Option<(IntegerRange, IntegerPolicy)> {
+        self.integer_bounds
+    }
+
+    pub(crate) fn fallback(&self, path: &str) -> Option<&Value> {
+        self.fallbacks.get(path)
+    }
+
+    pub(crate) fn fallback_paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self.fallbacks.keys().cloned().collect();
+        paths.sort();
+        paths
+    }
+
+    pub(crate) fn collect_errors(&self) -> bool {
+        self.collect_errors
+    }
+
+    /// The options that change what rendered content looks like, for
+    /// fragment cache keys; empty with the defaults
+    ///
+    /// Limits, collected errors and other options that only decide whether a
+    /// render fails are left out: fragments are not cached from failed renders.
+    pub(crate) fn output_settings(&self) -> String {
+        let mut settings = String::new();
+        if self.escape_mode != EscapeMode::default() {
+            settings.push_str(&format!("escape={:?};", self.escape_mode));
+        }
+        if let Some(separator) = &self.group_separator {
+            settings.push_str(&format!("group_separator={separator:?};"));
+        }
+        if self.collapse_blank_includes {
+            settings.push_str("collapse_blank_includes;");
+        }
+        if let Some((range, policy)) = self.integer_bounds {
+            settings.push_str(&format!("integers={range:?},{policy:?};"));
+        }
+        for path in self.fallback_paths() {
+            settings.push_str(&format!("fallback {path}={:?};", self.fallbacks[&path]));
+        }
+        settings
+    }
+
+    pub(crate) fn truncation(&self) -> Option<(usize, &str)> {
+        self.truncation
+            .as_ref()
+            .map(|(max_bytes, marker)| (*max_bytes, marker.as_str()))
+    }
+}
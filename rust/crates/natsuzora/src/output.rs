@@ -0,0 +1,287 @@
+//! Output builder used by the renderer.
+//!
+//! Rendered HTML is appended in chunks. With a byte limit, the chunk that
+//! crosses the limit is cut at a UTF-8 character boundary, never inside a
+//! character reference such as `&amp;`, and the truncation marker is
+//! appended once; later chunks are dropped.
+//!
+//! An output can also record which template node each run of it came
+//! from, so checks of the final HTML can point back into the templates.
+//!
+//! An output can also be capped: content that would take it past the cap
+//! is dropped and the output is marked as over it, for the renderer to fail
+//! the render. A fragment rendered aside is capped at what its parent has
+//! left, so the parts of a render never add up to more than the cap.
+//!
+//! An output writing to a sink hands its buffer over whenever it grows past
+//! [`FLUSH_BYTES`], so a large page is never held whole. The first write
+//! error stops the writing and is reported by [`Output::finish`].
+
+use natsuzora_ast::Location;
+use std::io::{self, Write};
+
+/// Buffered bytes that make an output writing to a sink write them out
+const FLUSH_BYTES: usize = 8 * 1024;
+
+/// The template node output from some offset on came from.
+#[derive(Debug, Clone)]
+pub(crate) struct Origin {
+    /// Partial or layout name, `None` for the rendered template itself
+    pub(crate) template: Option<String>,
+    pub(crate) location: Location,
+    /// The output is the node's source text, so offsets into it map to
+    /// source positions
+    pub(crate) verbatim: bool,
+}
+
+/// Accumulates rendered output, optionally truncating it.
+#[derive(Default)]
+pub(crate) struct Output<'w> {
+    buf: String,
+    /// Where the buffer is written out to, if anywhere
+    sink: Option<&'w mut dyn Write>,
+    /// Bytes already written out of the buffer
+    flushed: usize,
+    /// The first write error, after which the output is dropped
+    error: Option<io::Error>,
+    limit: Option<usize>,
+    marker: String,
+    truncated: bool,
+    cap: Option<usize>,
+    over_cap: bool,
+    /// Origins by the output offset they start at, when tracked
+    origins: Option<Vec<(usize, Origin)>>,
+}
+
+impl<'w> Output<'w> {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `limit` bytes of content, then append `marker`
+    pub(crate) fn truncating(limit: usize, marker: &str) -> Self {
+        Self {
+            limit: Some(limit),
+            marker: marker.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Build the output in `buf`, emptied first, reusing its allocation
+    pub(crate) fn in_buffer(mut self, mut buf: String) -> Self {
+        buf.clear();
+        self.buf = buf;
+        self
+    }
+
+    /// Hold at most `cap` bytes, see [`Self::is_over_cap`]
+    pub(crate) fn capped(mut self, cap: Option<usize>) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// An empty output for a part rendered aside and pushed into this one
+    /// later, capped at what this one has left
+    pub(crate) fn fragment(&self) -> Output<'static> {
+        Output::new().capped(self.cap.map(|cap| cap.saturating_sub(self.len())))
+    }
+
+    /// Whether content was dropped for going past the cap
+    pub(crate) fn is_over_cap(&self) -> bool {
+        self.over_cap
+    }
+
+    /// Whether nothing more will be added
+    pub(crate) fn is_full(&self) -> bool {
+        self.truncated || self.over_cap
+    }
+
+    /// Write the output to `sink` as it grows instead of keeping it
+    pub(crate) fn writing_to(mut self, sink: &'w mut dyn Write) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// Bytes of output so far, written out or not
+    fn len(&self) -> usize {
+        self.flushed + self.buf.len()
+    }
+
+    /// Record the origin of every run of output
+    pub(crate) fn tracking_origins(mut self) -> Self {
+        self.origins = Some(Vec::new());
+        self
+    }
+
+    pub(crate) fn tracks_origins(&self) -> bool {
+        self.origins.is_some()
+    }
+
+    /// Attribute the output pushed from now on to `origin`
+    pub(crate) fn mark(&mut self, origin: Origin) {
+        let offset = self.len();
+        let Some(origins) = self.origins.as_mut() else {
+            return;
+        };
+        // A node that output nothing leaves no run behind
+        if origins.last().is_some_and(|(start, _)| *start == offset) {
+            origins.pop();
+        }
+        origins.push((offset, origin));
+    }
+
+    pub(crate) fn push(&mut self, html: &str) {
+        if self.is_full() {
+            return;
+        }
+        if self.cap.is_some_and(|cap| self.len() + html.len() > cap) {
+            self.over_cap = true;
+            return;
+        }
+        let Some(limit) = self.limit else {
+            self.buf.push_str(html);
+            return self.write_out(FLUSH_BYTES);
+        };
+        let remaining = limit.saturating_sub(self.len());
+        if html.len() <= remaining {
+            self.buf.push_str(html);
+            return self.write_out(FLUSH_BYTES);
+        }
+        self.buf.push_str(&html[..safe_cut(html, remaining)]);
+        self.buf.push_str(&self.marker);
+        self.truncated = true;
+        self.write_out(FLUSH_BYTES);
+    }
+
+    /// Write the buffer out to the sink once it holds `threshold` bytes
+    fn write_out(&mut self, threshold: usize) {
+        let Some(sink) = self.sink.as_mut() else {
+            return;
+        };
+        if self.buf.len() < threshold {
+            return;
+        }
+        if self.error.is_none() {
+            if let Err(error) = sink.write_all(self.buf.as_bytes()) {
+                self.error = Some(error);
+            }
+        }
+        self.flushed += self.buf.len();
+        self.buf.clear();
+    }
+
+    /// Write what is left out to the sink and flush it
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.write_out(0);
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        match self.sink {
+            Some(sink) => sink.flush(),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn into_string(self) -> String {
+        self.buf
+    }
+
+    /// The output and the origins of its runs, empty unless tracked
+    pub(crate) fn into_parts(self) -> (String, Vec<(usize, Origin)>) {
+        (self.buf, self.origins.unwrap_or_default())
+    }
+}
+
+/// Largest cut point `<= max` that splits neither a character nor a
+/// character reference.
+fn safe_cut(html: &str, max: usize) -> usize {
+    let mut cut = max;
+    while !html.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    if let Some(amp) = html[..cut].rfind('&') {
+        let reference = &html[amp + 1..];
+        let name_len = reference
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '#'))
+            .unwrap_or(reference.len());
+        let ends_after_cut = amp + 1 + name_len >= cut;
+        if name_len > 0 && ends_after_cut && reference[name_len..].starts_with(';') {
+            cut = amp;
+        }
+    }
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn truncate(chunks: &[&str], limit: usize) -> String {
+        let mut output = Output::truncating(limit, "…");
+        for chunk in chunks {
+            output.push(chunk);
+        }
+        output.into_string()
+    }
+
+    #[test]
+    fn test_truncation_respects_boundaries() {
+        assert_eq!(truncate(&["abc", "def"], 6), "abcdef");
+        assert_eq!(truncate(&["abc", "def", "g"], 4), "abcd…");
+        assert_eq!(truncate(&["日本語"], 4), "日…");
+        assert_eq!(truncate(&["a &amp; b"], 5), "a …");
+        assert_eq!(truncate(&["a &amp; b"], 7), "a &amp;…");
+        assert_eq!(truncate(&["a &#39;"], 6), "a …");
+        assert_eq!(truncate(&["R&D team"], 3), "R&D…");
+    }
+
+    #[test]
+    fn test_cap() {
+        let mut output = Output::new().capped(Some(6));
+        output.push("abcd");
+        let mut fragment = output.fragment();
+        fragment.push("ef");
+        assert!(!fragment.is_over_cap());
+        fragment.push("g");
+        assert!(fragment.is_over_cap());
+        output.push(&fragment.into_string());
+        assert!(!output.is_over_cap());
+        output.push("g");
+        assert!(output.is_over_cap() && output.is_full());
+        assert_eq!(output.into_string(), "abcdef");
+    }
+
+    #[test]
+    fn test_writing_to_a_sink() {
+        let chunk = "x".repeat(FLUSH_BYTES / 2);
+        let mut sink = Vec::new();
+        let mut output = Output::truncating(FLUSH_BYTES * 2, "…").writing_to(&mut sink);
+        for _ in 0..5 {
+            output.push(&chunk);
+        }
+        assert!(output.buf.len() < FLUSH_BYTES);
+        assert!(output.is_full());
+        output.finish().unwrap();
+        assert_eq!(sink.len(), FLUSH_BYTES * 2 + "…".len());
+
+        struct Failing;
+
+        impl Write for Failing {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut sink = Failing;
+        let mut output = Output::new().writing_to(&mut sink);
+        output.push(&chunk);
+        output.push(&chunk);
+        output.push("tail");
+        let error = output.finish().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+    }
+}
@@ -0,0 +1,41 @@
+//! Supported public surface for downstream crates.
+//!
+//! `use natsuzora::prelude::*;` brings in everything needed to parse and
+//! render templates. Items that are only reachable through other modules
+//! (e.g. `natsuzora::context`) are implementation details and may change
+//! between minor releases.
+
+pub use crate::analysis::{TemplateInfo, TemplateSet};
+pub use crate::audit::{AuditEvent, AuditOptions, AuditSink, AUDIT_SCHEMA_VERSION};
+pub use crate::coverage::{Coverage, CoverageReport};
+pub use crate::docs::{DocFormat, DocGenerator};
+pub use crate::duplicates::DuplicateGroup;
+pub use crate::edge_include::{EdgeIncludeFormat, EdgeIncludes};
+pub use crate::error::NatsuzoraError;
+pub use crate::extract::MessageCatalog;
+pub use crate::filters::{CustomFilters, OutputHook};
+pub use crate::fragment_cache::{FragmentCache, FragmentKey, LruFragmentCache};
+pub use crate::html_escape::EscapeMode;
+pub use crate::html_ids::{DuplicateId, IdSite};
+pub use crate::options::RenderOptions;
+pub use crate::profile::{Profile, Profiles};
+pub use crate::rebuild::{ContentHashes, PageToRender};
+pub use crate::refactor::{extract_partial, rename_path, ExtractedPartial, FileDiff};
+pub use crate::render_builder::RenderBuilder;
+pub use crate::renderer::{FallbackUse, Rendered};
+pub use crate::report::{CheckReport, Diagnostic, Severity, REPORT_SCHEMA_VERSION};
+pub use crate::repro::{ReproBundle, REPRO_SCHEMA_VERSION};
+pub use crate::stream::StreamRenderer;
+pub use crate::template_loader::{FnLoader, FsSource, SourceHook, TemplateLoader, TemplateSource};
+pub use crate::translations::Translations;
+pub use crate::value::{
+    ConflictPath, IntegerPolicy, IntegerRange, NatsuzoraScalar, SharedData, Value,
+};
+pub use crate::version::{features, LANGUAGE_VERSION, VERSION};
+pub use crate::{
+    render, render_stream, render_template, render_with_includes, Natsuzora, RenderData, Serialized,
+};
+pub use natsuzora_ast::{
+    trace_trims, Delimiters, Filter, IncludeLoader, InvalidDelimiters, LoaderError, Location,
+    Modifier, Param, ParseError, ParseLimits, ParseOptions, Template, Trim, TrimCause,
+};
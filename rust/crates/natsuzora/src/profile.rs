@@ -0,0 +1,228 @@
+//! Per-directory language profiles.
+//!
+//! A [`Profile`] restricts the constructs templates may use, and
+//! [`Profiles`] assigns profiles to include-name prefixes. A
+//! [`TemplateLoader`](crate::TemplateLoader) with profiles checks every
+//! partial when it is loaded and rejects it before it is cached or rendered:
+//!
+//! ```rust
+//! use natsuzora::{Profile, Profiles};
+//!
+//! let profiles = Profiles::new()
+//!     .with("/email", Profile::new().deny_unsecure())
+//!     .with("/components", Profile::new().allow_includes_under("/components"));
+//! let partial = natsuzora_ast::parse("{[!unsecure body ]}").unwrap();
+//! assert!(profiles.check("/email/welcome", &partial).is_err());
+//! assert!(profiles.check("/pages/home", &partial).is_ok());
+//! ```
+
+use crate::error::{NatsuzoraError, Result};
+use natsuzora_ast::{AstNode, Location, Template};
+
+/// Constructs a group of templates may use. Everything is allowed by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    deny_unsecure: bool,
+    deny_cache: bool,
+    /// `None` allows any include; otherwise the allowed name prefixes
+    include_prefixes: Option<Vec<String>>,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject `{[!unsecure ...]}` output
+    pub fn deny_unsecure(mut self) -> Self {
+        self.deny_unsecure = true;
+        self
+    }
+
+    /// Reject `{[#cache]}` blocks
+    pub fn deny_cache(mut self) -> Self {
+        self.deny_cache = true;
+        self
+    }
+
+    /// Reject every include
+    pub fn deny_includes(mut self) -> Self {
+        self.include_prefixes = Some(Vec::new());
+        self
+    }
+
+    /// Only allow includes of partials under `prefix`; may be repeated
+    pub fn allow_includes_under(mut self, prefix: &str) -> Self {
+        self.include_prefixes
+            .get_or_insert_with(Vec::new)
+            .push(prefix.to_string());
+        self
+    }
+
+    fn violations(&self, nodes: &[AstNode], found: &mut Vec<(String, Location)>) {
+        for node in nodes {
+            match node {
+                AstNode::Unsecure(n) if self.deny_unsecure => {
+                    found.push(("unsecure output is not allowed".to_string(), n.location));
+                }
+                AstNode::If(n) => {
+                    self.violations(&n.then_branch, found);
+                    for branch in &n.elseif_branches {
+                        self.violations(&branch.body, found);
+                    }
+                    if let Some(else_branch) = &n.else_branch {
+                        self.violations(else_branch, found);
+                    }
+                }
+                AstNode::Unless(n) => {
+                    self.violations(&n.body, found);
+                    if let Some(else_branch) = &n.else_branch {
+                        self.violations(else_branch, found);
+                    }
+                }
+                AstNode::Each(n) => {
+                    self.violations(&n.body, found);
+                    if let Some(else_branch) = &n.else_branch {
+                        self.violations(else_branch, found);
+                    }
+                }
+                AstNode::Cache(n) => {
+                    if self.deny_cache {
+                        found.push(("cache blocks are not allowed".to_string(), n.location));
+                    }
+                    self.violations(&n.body, found);
+                }
+                AstNode::Block(n) => self.violations(&n.body, found),
+                AstNode::Include(n) => {
+                    match &n.source {
+                        // The partial is only known at render time
+                        Some(source) if self.include_prefixes.is_some() => found.push((
+                            format!("dynamic include of '{}' is not allowed", source.as_str()),
+                            n.location,
+                        )),
+                        Some(_) => {}
+                        None => self.check_include("include", &n.name, n.location, found),
+                    }
+                    if let Some(body) = &n.body {
+                        self.violations(body, found);
+                    }
+                }
+                AstNode::Extends(n) => self.check_include("layout", &n.name, n.location, found),
+                _ => {}
+            }
+        }
+    }
+
+    /// Layouts load through the include root, so they obey the same prefixes
+    fn check_include(
+        &self,
+        kind: &str,
+        name: &str,
+        location: Location,
+        found: &mut Vec<(String, Location)>,
+    ) {
+        if let Some(prefixes) = &self.include_prefixes {
+            if !prefixes.iter().any(|prefix| under(name, prefix)) {
+                let message = if prefixes.is_empty() {
+                    format!("{kind} of '{name}' is not allowed")
+                } else {
+                    format!("{kind} of '{name}' is outside {}", prefixes.join(", "))
+                };
+                found.push((message, location));
+            }
+        }
+    }
+}
+
+/// Profiles by include-name prefix.
+#[derive(Debug, Clone, Default)]
+pub struct Profiles {
+    rules: Vec<(String, Profile)>,
+}
+
+impl Profiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply `profile` to templates under `prefix` (e.g. `/email`)
+    ///
+    /// When prefixes nest, the longest matching one wins.
+    pub fn with(mut self, prefix: &str, profile: Profile) -> Self {
+        self.rules.push((prefix.to_string(), profile));
+        self
+    }
+
+    /// The profile governing the template `name`, if any
+    pub fn profile_for(&self, name: &str) -> Option<&Profile> {
+        self.rules
+            .iter()
+            .filter(|(prefix, _)| under(name, prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, profile)| profile)
+    }
+
+    /// Check the template `name` against its profile, reporting every violation
+    pub fn check(&self, name: &str, template: &Template) -> Result<()> {
+        let Some(profile) = self.profile_for(name) else {
+            return Ok(());
+        };
+        let mut found = Vec::new();
+        profile.violations(template.nodes(), &mut found);
+        let mut errors: Vec<NatsuzoraError> = found
+            .into_iter()
+            .map(|(message, location)| NatsuzoraError::ProfileViolation {
+                name: name.to_string(),
+                message,
+                location,
+            })
+            .collect();
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(NatsuzoraError::Multiple { errors }),
+        }
+    }
+}
+
+/// Whether `name` is `prefix` or lies under it, segment by segment
+pub(crate) fn under(name: &str, prefix: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    name == prefix
+        || name
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        let profiles = Profiles::new().with("/", Profile::new().deny_cache()).with(
+            "/components",
+            Profile::new().allow_includes_under("/components"),
+        );
+        let template = natsuzora_ast::parse(
+            "{[#cache key=id ]}{[!include /components/icon ]}{[!include /layout ]}{[/cache]}",
+        )
+        .unwrap();
+
+        let Err(NatsuzoraError::ProfileViolation {
+            message, location, ..
+        }) = profiles.check("/components/card", &template)
+        else {
+            panic!("expected a single violation");
+        };
+        assert_eq!(message, "include of '/layout' is outside /components");
+        assert_eq!(location.column, 51);
+
+        let Err(NatsuzoraError::ProfileViolation { message, .. }) =
+            profiles.check("/componentsx", &template)
+        else {
+            panic!("expected a single violation");
+        };
+        assert_eq!(message, "cache blocks are not allowed");
+    }
+}
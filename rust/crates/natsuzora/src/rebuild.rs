@@ -0,0 +1,177 @@
+//! Minimal rebuild sets for a template directory.
+//!
+//! A build tool keeps the [`TemplateSet::content_hashes`] of its last run
+//! and asks [`TemplateSet::changed_since`] which files differ now.
+//! [`TemplateSet::plan_rebuild`] turns changed files into the pages to render
+//! again: a changed page renders itself, while a changed partial or layout
+//! renders every page that includes or extends it, directly or through other
+//! partials.
+//!
+//! ```rust,ignore
+//! let set = natsuzora::TemplateSet::load_dir("templates")?;
+//! let changed = set.changed_since(&previous_hashes);
+//! for page in set.plan_rebuild(&changed) {
+//!     build_page(&page.path)?;
+//! }
+//! store_hashes(&set.content_hashes());
+//! ```
+
+use crate::analysis::{template_name, TemplateSet};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// Content hash of every template, by path relative to the set's root.
+pub type ContentHashes = BTreeMap<PathBuf, u64>;
+
+/// A page whose output is out of date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageToRender {
+    pub name: String,
+    /// Path relative to the set's root directory
+    pub path: PathBuf,
+    /// Changed templates the page depends on, ordered by name
+    pub changed: Vec<String>,
+}
+
+impl TemplateSet {
+    /// Hash of each template's source
+    ///
+    /// The hash is stable across runs and platforms, so it can be stored
+    /// between builds.
+    pub fn content_hashes(&self) -> ContentHashes {
+        self.iter()
+            .map(|entry| (entry.path.clone(), content_hash(&entry.source)))
+            .collect()
+    }
+
+    /// Files added, modified or removed since `previous` was taken
+    pub fn changed_since(&self, previous: &ContentHashes) -> Vec<PathBuf> {
+        let current = self.content_hashes();
+        let mut changed: BTreeSet<&PathBuf> = current
+            .iter()
+            .filter(|(path, hash)| previous.get(*path) != Some(*hash))
+            .map(|(path, _)| path)
+            .collect();
+        changed.extend(previous.keys().filter(|path| !current.contains_key(*path)));
+        changed.into_iter().cloned().collect()
+    }
+
+    /// Pages to render again after `changed_files` changed, ordered by name
+    ///
+    /// Paths may be relative to the set's root or absolute under it. A
+    /// removed partial still rebuilds the pages that include it, so they
+    /// report the broken include.
+    pub fn plan_rebuild<P: AsRef<Path>>(&self, changed_files: &[P]) -> Vec<PageToRender> {
+        let mut causes: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+        for file in changed_files {
+            let file = file.as_ref();
+            let path = file.strip_prefix(self.root()).unwrap_or(file);
+            if !path.extension().is_some_and(|ext| ext == "ntzr") {
+                continue;
+            }
+            let changed = template_name(path);
+            for name in self.dependents(&changed) {
+                causes.entry(name).or_default().insert(changed.clone());
+            }
+        }
+
+        causes
+            .into_iter()
+            .filter_map(|(name, changed)| {
+                let entry = self.get(name).filter(|entry| !entry.is_partial())?;
+                Some(PageToRender {
+                    name: entry.name.clone(),
+                    path: entry.path.clone(),
+                    changed: changed.into_iter().collect(),
+                })
+            })
+            .collect()
+    }
+
+    /// `name` and every template that reaches it through includes
+    fn dependents(&self, name: &str) -> BTreeSet<&str> {
+        let mut seen = BTreeSet::new();
+        let mut stack: Vec<&str> = self
+            .get(name)
+            .map(|e| e.name.as_str())
+            .into_iter()
+            .collect();
+        stack.extend(self.used_by(name));
+        while let Some(next) = stack.pop() {
+            if seen.insert(next) {
+                stack.extend(self.used_by(next));
+            }
+        }
+        seen
+    }
+}
+
+/// 64-bit FNV-1a
+fn content_hash(source: &str) -> u64 {
+    source.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plan_rebuild_follows_includes() {
+        let dir = TempDir::new().unwrap();
+        let files = [
+            ("index.ntzr", "{[!include /header ]}home"),
+            (
+                "about.ntzr",
+                "{[!extends /layout ]}{[#block body]}about{[/block]}",
+            ),
+            ("posts/list.ntzr", "{[!include /components/card ]}"),
+            ("_header.ntzr", "{[!include /components/card ]}"),
+            ("_layout.ntzr", "{[#block body]}{[/block]}"),
+            ("components/_card.ntzr", "card"),
+        ];
+        for (path, source) in files {
+            let file = dir.path().join(path);
+            fs::create_dir_all(file.parent().unwrap()).unwrap();
+            fs::write(file, source).unwrap();
+        }
+        let set = TemplateSet::load_dir(dir.path()).unwrap();
+
+        let names = |pages: Vec<PageToRender>| -> Vec<String> {
+            pages.into_iter().map(|page| page.name).collect()
+        };
+        assert_eq!(
+            names(set.plan_rebuild(&["components/_card.ntzr"])),
+            vec!["index.ntzr", "posts/list.ntzr"]
+        );
+        assert_eq!(
+            names(set.plan_rebuild(&[dir.path().join("_layout.ntzr")])),
+            vec!["about.ntzr"]
+        );
+        assert_eq!(
+            names(set.plan_rebuild(&["about.ntzr", "style.css"])),
+            vec!["about.ntzr"]
+        );
+
+        let pages = set.plan_rebuild(&["_header.ntzr", "components/_card.ntzr", "_gone.ntzr"]);
+        assert_eq!(pages[0].name, "index.ntzr");
+        assert_eq!(pages[0].changed, vec!["/components/card", "/header"]);
+
+        let mut previous = set.content_hashes();
+        assert!(set.changed_since(&previous).is_empty());
+        previous.insert(PathBuf::from("_header.ntzr"), 0);
+        previous.insert(PathBuf::from("_old.ntzr"), 1);
+        previous.remove(Path::new("index.ntzr"));
+        assert_eq!(
+            set.changed_since(&previous),
+            vec![
+                PathBuf::from("_header.ntzr"),
+                PathBuf::from("_old.ntzr"),
+                PathBuf::from("index.ntzr")
+            ]
+        );
+    }
+}
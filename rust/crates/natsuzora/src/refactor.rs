@@ -0,0 +1,451 @@
+//! Source-level refactorings for editor integrations.
+//!
+//! [`extract_partial`] moves a selected region of a template into a new
+//! partial and replaces the region with an include of it. The partial keeps
+//! the region's source verbatim; every variable the region reads from outside
+//! is passed as an include argument of the same name, so the partial renders
+//! the same output without depending on the caller's scope.
+//!
+//! [`rename_path`] renames a data path in every template of a directory and
+//! the partials they include, returning one [`FileDiff`] per changed file.
+//! Only the path tokens found in the AST are rewritten, so text that merely
+//! looks like the path is left alone.
+
+use crate::analysis::{TemplateInfo, TemplateSet};
+use crate::error::{Location, NatsuzoraError, Result};
+use crate::template_loader::{validate_include_name, TemplateLoader};
+use natsuzora_ast::{AstNode, Operand, Path, Template};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write as _;
+use std::ops::Range;
+use std::path::PathBuf;
+
+/// Result of [`extract_partial`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedPartial {
+    /// The original template with the region replaced by an include
+    pub template: String,
+    /// Source of the new partial
+    pub partial: String,
+    /// Where the partial belongs, relative to the include root
+    pub path: PathBuf,
+    /// Include arguments, by name
+    pub arguments: Vec<String>,
+}
+
+/// Extract the byte range `span` of `template` into the partial `new_name`
+///
+/// The range must cover whole nodes: both the region on its own and the
+/// template with the region replaced have to parse. Whitespace control on the
+/// region's first and last tag moves to the include tag.
+pub fn extract_partial(
+    template: &Template,
+    span: Range<usize>,
+    new_name: &str,
+) -> Result<ExtractedPartial> {
+    validate_include_name(new_name)?;
+    let source = template
+        .source()
+        .ok_or_else(|| NatsuzoraError::ParseError {
+            message: "Template has no source text to refactor".to_string(),
+            location: template.location(),
+        })?;
+    let region = source
+        .get(span.clone())
+        .filter(|region| !region.trim().is_empty())
+        .ok_or_else(|| NatsuzoraError::ParseError {
+            message: format!("Invalid selection {}..{}", span.start, span.end),
+            location: location_at(source, span.start.min(source.len())),
+        })?;
+
+    let partial = natsuzora_ast::parse(region).map_err(|e| NatsuzoraError::ParseError {
+        message: format!("Selection is not a complete sequence of nodes: {e}"),
+        location: location_at(source, span.start),
+    })?;
+    let arguments: Vec<String> = TemplateInfo::analyze(&partial)
+        .required_variables
+        .iter()
+        .map(|path| path.split('.').next().unwrap_or(path).to_string())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    let open = if region.starts_with("{[-") {
+        "{[-"
+    } else {
+        "{["
+    };
+    let close = if region.ends_with("-]}") {
+        " -]}"
+    } else {
+        " ]}"
+    };
+    let mut tag = format!("{open}!include {new_name}");
+    for name in &arguments {
+        tag.push_str(&format!(" {name}={name}"));
+    }
+    tag.push_str(close);
+
+    let rewritten = format!("{}{tag}{}", &source[..span.start], &source[span.end..]);
+    natsuzora_ast::parse(&rewritten).map_err(|e| NatsuzoraError::ParseError {
+        message: format!("Selection splits a block or tag: {e}"),
+        location: location_at(source, span.start),
+    })?;
+
+    Ok(ExtractedPartial {
+        template: rewritten,
+        partial: region.to_string(),
+        path: partial_path(new_name),
+        arguments,
+    })
+}
+
+/// `/components/card` → `components/_card.ntzr`
+fn partial_path(name: &str) -> PathBuf {
+    let mut path = PathBuf::new();
+    let mut segments = name.split('/').filter(|s| !s.is_empty()).peekable();
+    while let Some(segment) = segments.next() {
+        if segments.peek().is_some() {
+            path.push(segment);
+        } else {
+            path.push(format!("_{segment}.ntzr"));
+        }
+    }
+    path
+}
+
+/// Rewritten source of one template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    /// Template name: relative path in the directory, or include name for
+    /// partials found through the loader
+    pub name: String,
+    pub before: String,
+    pub after: String,
+}
+
+impl FileDiff {
+    /// Changed lines in unified diff format
+    pub fn unified(&self) -> String {
+        let name = self.name.trim_start_matches('/');
+        let mut out = format!("--- a/{name}\n+++ b/{name}\n");
+        // A rename never adds or removes lines, so lines pair up one to one
+        for (i, (before, after)) in self.before.lines().zip(self.after.lines()).enumerate() {
+            if before != after {
+                let _ = write!(out, "@@ -{0} +{0} @@\n-{before}\n+{after}\n", i + 1);
+            }
+        }
+        out
+    }
+}
+
+/// Rename the data path `old_path` to `new_path` in every template under
+/// `dir` and in the partials they include through `loader`
+///
+/// A reference is renamed when it starts with `old_path` segment by segment
+/// (`user` also renames `user.name`) and its first segment is not an `each`
+/// variable or, inside a partial, an include argument name. Files without
+/// references are not returned.
+pub fn rename_path(
+    dir: impl AsRef<std::path::Path>,
+    loader: &mut TemplateLoader,
+    old_path: &str,
+    new_path: &str,
+) -> Result<Vec<FileDiff>> {
+    let old = parse_path_argument(old_path)?;
+    parse_path_argument(new_path)?;
+
+    let set = TemplateSet::load_dir(dir)?;
+    let mut templates: BTreeMap<String, Template> = set
+        .iter()
+        .map(|entry| (entry.name.clone(), entry.template.clone()))
+        .collect();
+    let mut pending: Vec<String> = set
+        .iter()
+        .flat_map(|entry| entry.info.includes.iter().cloned())
+        .collect();
+    while let Some(name) = pending.pop() {
+        if templates.contains_key(&name) {
+            continue;
+        }
+        let template = loader.load(&name)?;
+        pending.extend(TemplateInfo::analyze(&template).includes);
+        templates.insert(name, template);
+    }
+
+    // Names bound by include arguments in each partial, from every call site
+    let mut arguments: HashMap<String, Vec<String>> = HashMap::new();
+    for template in templates.values() {
+        collect_include_arguments(template.nodes(), &mut arguments);
+    }
+
+    let mut diffs = Vec::new();
+    for (name, template) in &templates {
+        let Some(source) = template.source() else {
+            continue;
+        };
+        let mut bound = arguments.get(name).cloned().unwrap_or_default();
+        let mut ranges = Vec::new();
+        collect_references(template.nodes(), &old, &mut bound, &mut ranges);
+        if ranges.is_empty() {
+            continue;
+        }
+        ranges.sort_by_key(|range| range.start);
+        let mut after = String::with_capacity(source.len());
+        let mut last = 0;
+        for range in ranges {
+            after.push_str(&source[last..range.start]);
+            after.push_str(new_path);
+            last = range.end;
+        }
+        after.push_str(&source[last..]);
+        diffs.push(FileDiff {
+            name: name.clone(),
+            before: source.to_string(),
+            after,
+        });
+    }
+    Ok(diffs)
+}
+
+fn parse_path_argument(path: &str) -> Result<Vec<String>> {
+    let invalid = || NatsuzoraError::ParseError {
+        message: format!("Invalid path: {path}"),
+        location: Location::default(),
+    };
+    let template = natsuzora_ast::parse(&format!("{{[ {path} ]}}")).map_err(|_| invalid())?;
+    match template.nodes() {
+        [AstNode::Variable(n)] if n.path.as_str() == path => Ok(n.path.segments().to_vec()),
+        _ => Err(invalid()),
+    }
+}
+
+fn collect_include_arguments(nodes: &[AstNode], arguments: &mut HashMap<String, Vec<String>>) {
+    for node in nodes {
+        match node {
+            AstNode::Include(n) => {
+                if n.source.is_none() {
+                    let names = arguments.entry(n.name.clone()).or_default();
+                    names.extend(n.args.iter().map(|arg| arg.name.clone()));
+                }
+                if let Some(body) = &n.body {
+                    collect_include_arguments(body, arguments);
+                }
+            }
+            AstNode::If(n) => {
+                collect_include_arguments(&n.then_branch, arguments);
+                for branch in &n.elseif_branches {
+                    collect_include_arguments(&branch.body, arguments);
+                }
+                if let Some(else_branch) = &n.else_branch {
+                    collect_include_arguments(else_branch, arguments);
+                }
+            }
+            AstNode::Unless(n) => {
+                collect_include_arguments(&n.body, arguments);
+                if let Some(else_branch) = &n.else_branch {
+                    collect_include_arguments(else_branch, arguments);
+                }
+            }
+            AstNode::Each(n) => {
+                collect_include_arguments(&n.body, arguments);
+                if let Some(else_branch) = &n.else_branch {
+                    collect_include_arguments(else_branch, arguments);
+                }
+            }
+            AstNode::Cache(n) => collect_include_arguments(&n.body, arguments),
+            AstNode::Block(n) => collect_include_arguments(&n.body, arguments),
+            _ => {}
+        }
+    }
+}
+
+/// Source ranges of the `old` prefix of every matching path
+fn collect_references(
+    nodes: &[AstNode],
+    old: &[String],
+    bound: &mut Vec<String>,
+    ranges: &mut Vec<Range<usize>>,
+) {
+    let add = |path: &Path, bound: &[String], ranges: &mut Vec<Range<usize>>| {
+        let segments = path.segments();
+        if segments.starts_with(old) && !bound.contains(&segments[0]) {
+            let start = path.location().byte_offset;
+            ranges.push(start..start + Path::join(old).len());
+        }
+    };
+    for node in nodes {
+        match node {
+            AstNode::Variable(n) => add(&n.path, bound, ranges),
+            AstNode::Unsecure(n) => add(&n.path, bound, ranges),
+            AstNode::Attr(n) => add(&n.path, bound, ranges),
+            AstNode::If(n) => {
+                n.condition
+                    .paths()
+                    .into_iter()
+                    .for_each(|p| add(p, bound, ranges));
+                collect_references(&n.then_branch, old, bound, ranges);
+                for branch in &n.elseif_branches {
+                    branch
+                        .condition
+                        .paths()
+                        .into_iter()
+                        .for_each(|p| add(p, bound, ranges));
+                    collect_references(&branch.body, old, bound, ranges);
+                }
+                if let Some(else_branch) = &n.else_branch {
+                    collect_references(else_branch, old, bound, ranges);
+                }
+            }
+            AstNode::Unless(n) => {
+                n.condition
+                    .paths()
+                    .into_iter()
+                    .for_each(|p| add(p, bound, ranges));
+                collect_references(&n.body, old, bound, ranges);
+                if let Some(else_branch) = &n.else_branch {
+                    collect_references(else_branch, old, bound, ranges);
+                }
+            }
+            AstNode::Each(n) => {
+                for path in n.collection.paths().chain(n.window_paths()) {
+                    add(path, bound, ranges);
+                }
+                let depth = bound.len();
+                bound.extend(n.bindings().map(String::from));
+                collect_references(&n.body, old, bound, ranges);
+                bound.truncate(depth);
+                if let Some(else_branch) = &n.else_branch {
+                    collect_references(else_branch, old, bound, ranges);
+                }
+            }
+            AstNode::Cache(n) => {
+                add(&n.key, bound, ranges);
+                collect_references(&n.body, old, bound, ranges);
+            }
+            AstNode::Block(n) => collect_references(&n.body, old, bound, ranges),
+            AstNode::Include(n) => {
+                for arg in &n.args {
+                    if let Operand::Path(path) = &arg.value {
+                        add(path, bound, ranges);
+                    }
+                }
+                if let Some(spread) = &n.spread {
+                    add(spread, bound, ranges);
+                }
+                if let Some(source) = &n.source {
+                    add(source, bound, ranges);
+                }
+                if let Some(body) = &n.body {
+                    collect_references(body, old, bound, ranges);
+                }
+            }
+            AstNode::Translate(n) => {
+                for arg in &n.args {
+                    if let Operand::Path(path) = &arg.value {
+                        add(path, bound, ranges);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn location_at(source: &str, offset: usize) -> Location {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Location::new(line, before[line_start..].chars().count() + 1, offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span_of(source: &str, region: &str) -> Range<usize> {
+        let start = source.find(region).unwrap();
+        start..start + region.len()
+    }
+
+    #[test]
+    fn test_extract_partial_passes_free_variables() {
+        let source = "<ul>\n{[#each posts as post]}\n  {[-#if post.author]}<b>{[ post.author.name ]}</b> on {[ site.name ]}{[/if-]}\n{[/each]}\n</ul>";
+        let template = natsuzora_ast::parse(source).unwrap();
+        let region = "{[-#if post.author]}<b>{[ post.author.name ]}</b> on {[ site.name ]}{[/if-]}";
+
+        let extracted =
+            extract_partial(&template, span_of(source, region), "/posts/byline").unwrap();
+        assert_eq!(extracted.partial, region);
+        assert_eq!(extracted.arguments, vec!["post", "site"]);
+        assert_eq!(extracted.path, PathBuf::from("posts/_byline.ntzr"));
+        assert_eq!(
+            extracted.template,
+            "<ul>\n{[#each posts as post]}\n  {[-!include /posts/byline post=post site=site -]}\n{[/each]}\n</ul>"
+        );
+
+        let data = serde_json::json!({
+            "posts": [{"author": {"name": "Ann"}}, {"author": null}],
+            "site": {"name": "Blog"},
+        });
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("posts")).unwrap();
+        std::fs::write(dir.path().join(&extracted.path), &extracted.partial).unwrap();
+        assert_eq!(
+            crate::render_with_includes(&extracted.template, data.clone(), dir.path()).unwrap(),
+            crate::render(source, data).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rename_path_skips_bound_names() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let write =
+            |file: &str, source: &str| std::fs::write(dir.path().join(file), source).unwrap();
+        write(
+            "index.ntzr",
+            "{[#if user.admin]}<b>{[ user.name ]}</b> ({[ username ]}){[/if]}\n{[#each user.posts as post]}{[!include /card post=post ]}{[/each]}\n",
+        );
+        write(
+            "_card.ntzr",
+            "{[ post.title ]}\n{[ user.name ]}{[!include /badge user=post.author ]}",
+        );
+        write("_badge.ntzr", "{[ user.name ]}");
+        let mut loader = TemplateLoader::new(dir.path()).unwrap();
+
+        let diffs = rename_path(dir.path(), &mut loader, "user", "account").unwrap();
+        assert_eq!(
+            diffs.iter().map(|d| d.name.as_str()).collect::<Vec<_>>(),
+            vec!["/card", "index.ntzr"]
+        );
+        assert_eq!(
+            diffs[1].after,
+            "{[#if account.admin]}<b>{[ account.name ]}</b> ({[ username ]}){[/if]}\n{[#each account.posts as post]}{[!include /card post=post ]}{[/each]}\n"
+        );
+        assert_eq!(
+            diffs[0].unified(),
+            "--- a/card\n+++ b/card\n@@ -2 +2 @@\n-{[ user.name ]}{[!include /badge user=post.author ]}\n+{[ account.name ]}{[!include /badge user=post.author ]}\n"
+        );
+
+        let diffs = rename_path(dir.path(), &mut loader, "post.title", "post.heading").unwrap();
+        assert!(diffs.is_empty());
+        assert!(rename_path(dir.path(), &mut loader, "user", "each").is_err());
+    }
+
+    #[test]
+    fn test_extract_partial_rejects_partial_selection() {
+        let source = "{[#if a]}x{[/if]}{[#if b]}y{[/if]}";
+        let template = natsuzora_ast::parse(source).unwrap();
+
+        for region in ["x{[/if]}{[#if b]}", "{[#if a]}x", "if a]}x{[/if]}"] {
+            assert!(matches!(
+                extract_partial(&template, span_of(source, region), "/part"),
+                Err(NatsuzoraError::ParseError { .. })
+            ));
+        }
+        assert!(matches!(
+            extract_partial(&template, span_of(source, "{[#if a]}x{[/if]}"), "part"),
+            Err(NatsuzoraError::IncludeError { .. })
+        ));
+    }
+}
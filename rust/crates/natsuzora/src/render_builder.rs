@@ -0,0 +1,168 @@
+//! One render of a [`Natsuzora`] template, configured step by step.
+
+use crate::context::Context;
+use crate::coverage::Coverage;
+use crate::error::Result;
+use crate::fragment_cache::FragmentCache;
+use crate::options::RenderOptions;
+use crate::renderer::{Rendered, Renderer};
+use crate::value::SharedData;
+use crate::{Natsuzora, RenderData};
+use natsuzora_ast::IncludeLoader;
+use std::io::Write;
+
+/// A render of a template, from [`Natsuzora::render_builder`].
+///
+/// Everything a render can take is set here and combines freely with every
+/// way of returning the output, so a new setting does not need a new
+/// `render_with_*` method for each of them. Settings left out behave as in
+/// [`Natsuzora::render`].
+///
+/// # Example
+///
+/// ```rust
+/// use natsuzora::{LruFragmentCache, Natsuzora, RenderOptions};
+/// use serde_json::json;
+///
+/// let tmpl = Natsuzora::parse("{[#cache key=id ]}<p>{[ name ]}</p>{[/cache]}").unwrap();
+/// let options = RenderOptions::new().with_max_output_bytes(1024);
+/// let cache = LruFragmentCache::new(100);
+/// let mut out = Vec::new();
+/// tmpl.render_builder()
+///     .with_options(&options)
+///     .with_fragment_cache(&cache)
+///     .render_to(json!({"id": 1, "name": "Alice"}), &mut out)
+///     .unwrap();
+/// assert_eq!(out, b"<p>Alice</p>");
+/// assert_eq!(cache.len(), 1);
+/// ```
+pub struct RenderBuilder<'a> {
+    tmpl: &'a Natsuzora,
+    options: Option<&'a RenderOptions>,
+    fragment_cache: Option<&'a dyn FragmentCache>,
+    coverage: Option<(&'a str, &'a mut Coverage)>,
+    shared: Option<&'a SharedData>,
+    include_loader: Option<&'a mut dyn IncludeLoader>,
+}
+
+impl<'a> RenderBuilder<'a> {
+    pub(crate) fn new(tmpl: &'a Natsuzora) -> Self {
+        Self {
+            tmpl,
+            options: None,
+            fragment_cache: None,
+            coverage: None,
+            shared: None,
+            include_loader: None,
+        }
+    }
+
+    /// Apply per-render options: limits, escaping, collected errors and so on
+    pub fn with_options(mut self, options: &'a RenderOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Memoize `{[#cache]}` blocks in `cache`
+    pub fn with_fragment_cache(mut self, cache: &'a dyn FragmentCache) -> Self {
+        self.fragment_cache = Some(cache);
+        self
+    }
+
+    /// Record branch coverage into `coverage`, naming the template `name`
+    pub fn with_coverage(mut self, name: &'a str, coverage: &'a mut Coverage) -> Self {
+        self.coverage = Some((name, coverage));
+        self
+    }
+
+    /// Resolve names the data does not have in `shared`
+    pub fn with_shared(mut self, shared: &'a SharedData) -> Self {
+        self.shared = Some(shared);
+        self
+    }
+
+    /// Load includes through `loader` instead of the template's include root
+    pub fn with_include_loader(mut self, loader: &'a mut dyn IncludeLoader) -> Self {
+        self.include_loader = Some(loader);
+        self
+    }
+
+    /// Render the template with the given data: JSON, borrowed JSON, a
+    /// [`Value`](crate::Value) or [`Serialized`](crate::Serialized) data
+    pub fn render(self, data: impl RenderData<'a>) -> Result<String> {
+        let tmpl = self.tmpl;
+        self.run(data, |renderer, context| {
+            renderer.render_context(&tmpl.template, context)
+        })
+    }
+
+    /// Render into `buf`, as [`Natsuzora::render_into`]
+    pub fn render_into(self, data: impl RenderData<'a>, buf: &mut String) -> Result<()> {
+        let tmpl = self.tmpl;
+        self.run(data, |renderer, context| {
+            renderer.render_context_into(&tmpl.template, context, buf)
+        })
+    }
+
+    /// Render to `out` as the output grows, as [`Natsuzora::render_to`]
+    pub fn render_to(self, data: impl RenderData<'a>, out: &mut impl Write) -> Result<()> {
+        let tmpl = self.tmpl;
+        self.run(data, |renderer, context| {
+            renderer.render_context_to(&tmpl.template, context, out)
+        })
+    }
+
+    /// Render, reporting fallbacks used and duplicate ids, as
+    /// [`Natsuzora::render_detailed`]
+    pub fn render_detailed(self, data: impl RenderData<'a>) -> Result<Rendered> {
+        let tmpl = self.tmpl;
+        self.run(data, |renderer, context| {
+            let output = renderer.render_context(&tmpl.template, context)?;
+            Ok(Rendered {
+                output,
+                fallbacks_used: renderer.fallbacks_used().to_vec(),
+                duplicate_ids: renderer.duplicate_ids().to_vec(),
+            })
+        })
+    }
+
+    /// Run `render` with a renderer and context set up from the settings
+    fn run<T>(
+        self,
+        data: impl RenderData<'a>,
+        render: impl FnOnce(&mut Renderer, Context<'a>) -> Result<T>,
+    ) -> Result<T> {
+        let Self {
+            tmpl,
+            options,
+            fragment_cache,
+            coverage,
+            shared,
+            include_loader,
+        } = self;
+        tmpl.audited(data, options, |context| {
+            let mut loader = match include_loader {
+                Some(_) => None,
+                None => tmpl.loader()?,
+            };
+            let mut renderer = tmpl.renderer(loader.as_mut());
+            if let Some(loader) = include_loader {
+                renderer = renderer.with_include_loader(loader);
+            }
+            if let Some(options) = options {
+                renderer = renderer.with_options(options);
+            }
+            if let Some(cache) = fragment_cache {
+                renderer = renderer.with_fragment_cache(cache);
+            }
+            if let Some((name, coverage)) = coverage {
+                renderer = renderer.with_coverage(name, coverage);
+            }
+            let context = match shared {
+                Some(shared) => context.with_shared(shared),
+                None => context,
+            };
+            render(&mut renderer, context)
+        })
+    }
+}
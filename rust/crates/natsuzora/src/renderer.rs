@@ -3,144 +3,1256 @@
 //! Since TokenProcessor handles whitespace control before parsing,
 //! the renderer simply evaluates the AST without any whitespace trimming logic.
 
-use crate::context::Context;
-use crate::error::{NatsuzoraError, Result};
-use crate::html_escape;
-use crate::template_loader::TemplateLoader;
-use crate::value::Value;
+use crate::audit;
+use crate::context::{Binding, Context, Element, Step};
+use crate::coverage::{BranchKind, Coverage};
+use crate::edge_include::EdgeIncludes;
+use crate::error::{Location, NatsuzoraError, Result};
+use crate::filters::{self, CustomFilters, OutputHook};
+use crate::fragment_cache::{self, FragmentCache, FragmentKey};
+use crate::html_escape::{self, EscapeMode};
+use crate::html_ids::{self, DuplicateId};
+use crate::options::RenderOptions;
+use crate::output::{Origin, Output};
+use crate::profile::under;
+use crate::template_loader::{validate_include_name, TemplateLoader};
+use crate::translations::{self, Translations};
+use crate::value::{IndexMap, Value};
 use natsuzora_ast::{
-    AstNode, EachBlock, IfBlock, IncludeNode, Modifier, Template, UnlessBlock, UnsecureNode,
-    VariableNode,
+    AstNode, AttrNode, BlockNode, CacheBlock, Collection, CompareOp, Comparison, Condition,
+    EachBlock, IfBlock, IncludeArg, IncludeLoader, IncludeNode, LoaderError, Modifier, Operand,
+    Param, Path, Template, TranslateNode, UnlessBlock, UnsecureNode, VariableNode,
 };
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::io::Write;
+
+/// A fallback value substituted for an undefined variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallbackUse {
+    /// Dotted path as written in the template
+    pub path: String,
+    pub location: Location,
+}
+
+/// Output of a render together with what happened during it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rendered {
+    pub output: String,
+    /// Fallbacks from [`RenderOptions::with_fallback`] that fired, in render order
+    pub fallbacks_used: Vec<FallbackUse>,
+    /// Ids repeated in the output, when [`RenderOptions::with_duplicate_id_check`] is set
+    pub duplicate_ids: Vec<DuplicateId>,
+}
 
 /// Renderer for evaluating Natsuzora AST
 pub struct Renderer<'a> {
-    template_loader: Option<&'a mut TemplateLoader>,
+    template_loader: Option<&'a mut dyn IncludeLoader>,
+    /// Names of the partials being rendered, for circular include detection
+    /// and the recursion limit
+    include_stack: Vec<String>,
+    /// Block bodies supplied by the templates extending the current layout
+    block_overrides: HashMap<String, BlockOverride>,
+    /// Partial or layout being rendered, `None` for the root template
+    current_template: Option<String>,
+    /// Rendered bodies of the enclosing block includes, output by `{[!yield]}`
+    yields: Vec<String>,
+    coverage: Option<&'a mut Coverage>,
+    template_names: Vec<String>,
+    fragment_cache: Option<&'a dyn FragmentCache>,
+    theme: Option<&'a str>,
+    locale: Option<&'a str>,
+    filters: Option<&'a CustomFilters>,
+    /// The hook and its registration id, which stands for it in fragment keys
+    output_hook: Option<(&'a dyn OutputHook, u64)>,
+    translations: Option<&'a Translations>,
+    edge_includes: Option<&'a EdgeIncludes>,
+    options: Option<&'a RenderOptions>,
+    errors: Vec<NatsuzoraError>,
+    fallbacks_used: Vec<FallbackUse>,
+    duplicate_ids: Vec<DuplicateId>,
+    /// `each` iterations so far, for the per-render iteration limit
+    iterations: usize,
+}
+
+/// A block body and the template that supplied it
+#[derive(Clone)]
+struct BlockOverride {
+    body: Vec<AstNode>,
+    template: Option<String>,
 }
 
 impl<'a> Renderer<'a> {
     /// Create a new renderer
     pub fn new(template_loader: Option<&'a mut TemplateLoader>) -> Self {
-        Self { template_loader }
+        Self {
+            template_loader: template_loader.map(|loader| loader as &mut dyn IncludeLoader),
+            include_stack: Vec::new(),
+            block_overrides: HashMap::new(),
+            current_template: None,
+            yields: Vec::new(),
+            coverage: None,
+            template_names: Vec::new(),
+            fragment_cache: None,
+            theme: None,
+            locale: None,
+            filters: None,
+            output_hook: None,
+            translations: None,
+            edge_includes: None,
+            options: None,
+            errors: Vec::new(),
+            fallbacks_used: Vec::new(),
+            duplicate_ids: Vec::new(),
+            iterations: 0,
+        }
+    }
+
+    /// Load includes through `loader` instead of a [`TemplateLoader`]
+    pub fn with_include_loader(mut self, loader: &'a mut dyn IncludeLoader) -> Self {
+        self.template_loader = Some(loader);
+        self
+    }
+
+    /// Record branch coverage into the given collector, naming the root template
+    pub fn with_coverage(mut self, template_name: &str, coverage: &'a mut Coverage) -> Self {
+        self.template_names = vec![template_name.to_string()];
+        self.coverage = Some(coverage);
+        self
+    }
+
+    /// Memoize `{[#cache]}` block output in the given store
+    pub fn with_fragment_cache(mut self, cache: &'a dyn FragmentCache) -> Self {
+        self.fragment_cache = Some(cache);
+        self
+    }
+
+    /// Key cached fragments by `theme` too, as their includes depend on it
+    pub fn with_theme(mut self, theme: &'a str) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Select `{[@t]}` messages for `locale`, and key cached fragments by it
+    /// too, as their includes depend on it
+    pub fn with_locale(mut self, locale: &'a str) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Apply host-defined filters from `filters`
+    pub fn with_filters(mut self, filters: &'a CustomFilters) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    /// Pass variable output text through `hook` before escaping
+    ///
+    /// Fragments cached under a hook are only reused by this renderer.
+    pub fn with_output_hook(self, hook: &'a dyn OutputHook) -> Self {
+        self.with_registered_output_hook(hook, fragment_cache::registration_id())
+    }
+
+    /// [`Self::with_output_hook`], sharing fragments with every render
+    /// passing the same `id`
+    pub(crate) fn with_registered_output_hook(mut self, hook: &'a dyn OutputHook, id: u64) -> Self {
+        self.output_hook = Some((hook, id));
+        self
+    }
+
+    /// Look `{[@t]}` messages up in `translations`
+    pub fn with_translations(mut self, translations: &'a Translations) -> Self {
+        self.translations = Some(translations);
+        self
+    }
+
+    /// Emit the selected includes as edge-side directives
+    pub fn with_edge_includes(mut self, edge_includes: &'a EdgeIncludes) -> Self {
+        self.edge_includes = Some(edge_includes);
+        self
+    }
+
+    /// Apply per-render options
+    pub fn with_options(mut self, options: &'a RenderOptions) -> Self {
+        self.options = Some(options);
+        self
     }
 
     /// Render a template with the given data
     pub fn render(&mut self, template: &Template, data: Value) -> Result<String> {
-        let mut context = Context::new(data)?;
-        self.render_nodes(template.nodes(), &mut context)
+        self.render_context(template, Context::new(data)?)
+    }
+
+    /// Render a template against a context made by the caller
+    pub(crate) fn render_context(
+        &mut self,
+        template: &Template,
+        context: Context,
+    ) -> Result<String> {
+        let mut buf = String::new();
+        self.render_context_into(template, context, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Render a template into `buf`, replacing its contents; after an error
+    /// it holds the output up to the error
+    pub(crate) fn render_context_into(
+        &mut self,
+        template: &Template,
+        context: Context,
+        buf: &mut String,
+    ) -> Result<()> {
+        let mut output = self.new_output().in_buffer(std::mem::take(buf));
+        if self.options.is_some_and(RenderOptions::check_duplicate_ids) {
+            output = output.tracking_origins();
+        }
+        let result = self.render_output(template, context, &mut output);
+
+        let (output, origins) = output.into_parts();
+        *buf = output;
+        result?;
+        if self.options.is_some_and(RenderOptions::check_duplicate_ids) {
+            self.duplicate_ids = html_ids::find_duplicates(buf, &origins);
+        }
+        self.collected(())
     }
 
-    fn render_nodes(&mut self, nodes: &[AstNode], context: &mut Context) -> Result<String> {
-        let mut output = String::new();
+    /// Render a template to `sink` as the output grows, without duplicate
+    /// id checks, which need the whole output
+    pub(crate) fn render_context_to(
+        &mut self,
+        template: &Template,
+        context: Context,
+        sink: &mut dyn Write,
+    ) -> Result<()> {
+        let mut output = self.new_output().writing_to(sink);
+        self.render_output(template, context, &mut output)?;
+        output.finish()?;
+        self.collected(())
+    }
 
+    fn new_output<'w>(&self) -> Output<'w> {
+        let output = match self.options.and_then(RenderOptions::truncation) {
+            Some((max_bytes, marker)) => Output::truncating(max_bytes, marker),
+            None => Output::new(),
+        };
+        output.capped(self.options.and_then(RenderOptions::max_output_bytes))
+    }
+
+    fn render_output(
+        &mut self,
+        template: &Template,
+        mut context: Context,
+        output: &mut Output,
+    ) -> Result<()> {
+        if let (Some(coverage), Some(name)) = (self.coverage.as_mut(), self.template_names.last()) {
+            coverage.register(name, template);
+        }
+        self.render_template(template.nodes(), &mut context, output)
+    }
+
+    fn output_limit(&self) -> NatsuzoraError {
+        let max = self.options.and_then(RenderOptions::max_output_bytes);
+        NatsuzoraError::OutputLimit {
+            message: format!("output would exceed {} bytes", max.unwrap_or_default()),
+        }
+    }
+
+    /// `result`, unless errors were collected on the way to it
+    fn collected<T>(&mut self, result: T) -> Result<T> {
+        let mut errors = std::mem::take(&mut self.errors);
+        match errors.len() {
+            0 => Ok(result),
+            1 => Err(errors.remove(0)),
+            _ => Err(NatsuzoraError::Multiple { errors }),
+        }
+    }
+
+    /// Render one piece of a template against a context shared by all pieces
+    pub(crate) fn render_part(
+        &mut self,
+        template: &Template,
+        context: &mut Context,
+    ) -> Result<String> {
+        let mut output = Output::new();
+        self.render_template(template.nodes(), context, &mut output)?;
+        Ok(output.into_string())
+    }
+
+    /// Fallback values substituted during the last render
+    pub fn fallbacks_used(&self) -> &[FallbackUse] {
+        &self.fallbacks_used
+    }
+
+    /// Ids repeated in the output of the last render, when checked
+    pub fn duplicate_ids(&self) -> &[DuplicateId] {
+        &self.duplicate_ids
+    }
+
+    fn collects_errors(&self) -> bool {
+        self.options.is_some_and(RenderOptions::collect_errors)
+    }
+
+    /// Resolve a path, substituting the configured fallback for undefined variables
+    fn resolve<'c>(
+        &mut self,
+        context: &'c Context,
+        path: &Path,
+        location: Location,
+    ) -> Result<&'c Value>
+    where
+        'a: 'c,
+    {
+        match context.resolve(path.segments(), location) {
+            Err(NatsuzoraError::UndefinedVariable { message, location }) => {
+                let path = path.as_str();
+                match self.options.and_then(|options| options.fallback(&path)) {
+                    Some(value) => {
+                        self.fallbacks_used.push(FallbackUse { path, location });
+                        Ok(value)
+                    }
+                    None => Err(NatsuzoraError::UndefinedVariable { message, location }),
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Evaluate an if/elseif/unless condition
+    ///
+    /// `and` / `or` short-circuit: the right side is not resolved when the
+    /// left side decides the result.
+    fn evaluate(
+        &mut self,
+        context: &Context,
+        condition: &Condition,
+        location: Location,
+    ) -> Result<bool> {
+        match condition {
+            Condition::Path(path) => Ok(self.resolve(context, path, location)?.is_truthy()),
+            Condition::Comparison(comparison) => self.compare(context, comparison, location),
+            Condition::Not(inner) => Ok(!self.evaluate(context, inner, location)?),
+            Condition::And(left, right) => Ok(self.evaluate(context, left, location)?
+                && self.evaluate(context, right, location)?),
+            Condition::Or(left, right) => Ok(self.evaluate(context, left, location)?
+                || self.evaluate(context, right, location)?),
+            other => Err(unsupported(&format!("condition '{other}'"))),
+        }
+    }
+
+    /// Comparisons use strict equality: values of different types are never
+    /// equal.
+    fn compare(
+        &mut self,
+        context: &Context,
+        comparison: &Comparison,
+        location: Location,
+    ) -> Result<bool> {
+        let left = self.resolve(context, &comparison.left, location)?;
+        let literal;
+        let right = match &comparison.right {
+            Operand::Path(path) => self.resolve(context, path, location)?,
+            Operand::Literal(value) => {
+                literal = Value::from(value);
+                &literal
+            }
+        };
+        let ordering = match (comparison.op, left, right) {
+            (CompareOp::Eq, left, right) => return Ok(left == right),
+            (CompareOp::Ne, left, right) => return Ok(left != right),
+            (_, Value::Integer(left), Value::Integer(right)) => left.cmp(right),
+            // Safe integers convert to f64 exactly, and floats are finite
+            (_, Value::Integer(_) | Value::Float(_), Value::Integer(_) | Value::Float(_)) => {
+                number(left).total_cmp(&number(right))
+            }
+            (op, left, right) => {
+                return Err(NatsuzoraError::TypeError {
+                    message: format!(
+                        "'{}' compares numbers, got {} and {}",
+                        op.as_str(),
+                        left.type_name(),
+                        right.type_name()
+                    ),
+                })
+            }
+        };
+        Ok(match comparison.op {
+            CompareOp::Lt => ordering.is_lt(),
+            CompareOp::Le => ordering.is_le(),
+            CompareOp::Gt => ordering.is_gt(),
+            CompareOp::Ge => ordering.is_ge(),
+            CompareOp::Eq | CompareOp::Ne => unreachable!(),
+        })
+    }
+
+    fn record_branch(&mut self, location: Location, kind: BranchKind) {
+        if let (Some(coverage), Some(name)) = (self.coverage.as_mut(), self.template_names.last()) {
+            coverage.hit(name, location, kind);
+        }
+    }
+
+    fn render_nodes(
+        &mut self,
+        nodes: &[AstNode],
+        context: &mut Context,
+        output: &mut Output,
+    ) -> Result<()> {
         for node in nodes {
-            match node {
-                AstNode::Text(n) => output.push_str(&n.content),
-                AstNode::Variable(n) => output.push_str(&self.render_variable(n, context)?),
-                AstNode::Unsecure(n) => output.push_str(&self.render_unsecure(n, context)?),
-                AstNode::If(n) => output.push_str(&self.render_if(n, context)?),
-                AstNode::Unless(n) => output.push_str(&self.render_unless(n, context)?),
-                AstNode::Each(n) => output.push_str(&self.render_each(n, context)?),
-                AstNode::Include(n) => output.push_str(&self.render_include(n, context)?),
+            if output.is_full() {
+                break;
+            }
+            if self.options.is_some_and(RenderOptions::is_cancelled) {
+                return Err(NatsuzoraError::Cancelled);
+            }
+            if output.tracks_origins() {
+                output.mark(Origin {
+                    template: self.current_template.clone(),
+                    location: node.location(),
+                    verbatim: matches!(node, AstNode::Text(_)),
+                });
+            }
+            let result = match node {
+                AstNode::Text(n) => {
+                    output.push(&n.content);
+                    Ok(())
+                }
+                AstNode::Variable(n) => self
+                    .render_variable(n, context)
+                    .map(|html| output.push(&html)),
+                AstNode::Unsecure(n) => self
+                    .render_unsecure(n, context)
+                    .map(|html| output.push(&html)),
+                AstNode::Translate(n) => self
+                    .render_translate(n, context)
+                    .map(|html| output.push(&html)),
+                AstNode::Attr(n) => self.render_attr(n, context).map(|html| output.push(&html)),
+                AstNode::If(n) => self.render_if(n, context, output),
+                AstNode::Unless(n) => self.render_unless(n, context, output),
+                AstNode::Each(n) => self.render_each(n, context, output),
+                AstNode::Cache(n) => self.render_cache(n, context, output),
+                AstNode::Include(n) => self.render_include(n, context, output),
+                AstNode::Block(n) => self.render_block(n, context, output),
+                AstNode::Yield(_) => {
+                    if let Some(body) = self.yields.last() {
+                        output.push(body);
+                    }
+                    Ok(())
+                }
+                // Handled by render_template; the parser keeps it at the top level
+                AstNode::Extends(_) => Ok(()),
+                other => Err(unsupported(&format!("'{}' node", other.kind()))),
+            };
+            match result {
+                // Going on would only run into the limit or cancellation again
+                Err(error)
+                    if self.collects_errors()
+                        && !matches!(
+                            error,
+                            NatsuzoraError::OutputLimit { .. }
+                                | NatsuzoraError::IterationLimit { .. }
+                                | NatsuzoraError::Cancelled
+                        ) =>
+                {
+                    self.errors.push(error)
+                }
+                result => result?,
+            }
+            if output.is_over_cap() {
+                return Err(self.output_limit());
             }
         }
 
-        Ok(output)
+        Ok(())
     }
 
-    fn render_variable(&self, node: &VariableNode, context: &Context) -> Result<String> {
+    /// Render a template's nodes, or the layout it extends with its blocks
+    /// filled in
+    fn render_template(
+        &mut self,
+        nodes: &[AstNode],
+        context: &mut Context,
+        output: &mut Output,
+    ) -> Result<()> {
+        let Some(extends) = nodes.iter().find_map(|node| match node {
+            AstNode::Extends(n) => Some(n),
+            _ => None,
+        }) else {
+            return self.render_nodes(nodes, context, output);
+        };
+
+        // The most derived template renders first, so its blocks win
+        for node in nodes {
+            if let AstNode::Block(block) = node {
+                collect_blocks(block, &self.current_template, &mut self.block_overrides);
+            }
+        }
+        if self.include_stack.contains(&extends.name) {
+            return Err(NatsuzoraError::IncludeError {
+                message: format!("Circular extends detected: {}", extends.name),
+            });
+        }
+        let layout = self.load(&extends.name)?;
+
+        self.include_stack.push(extends.name.clone());
+        if let Some(coverage) = self.coverage.as_mut() {
+            coverage.register(&extends.name, &layout);
+            self.template_names.push(extends.name.clone());
+        }
+
+        let includer = self.current_template.replace(extends.name.clone());
+        let result = self.render_template(layout.nodes(), context, output);
+        self.current_template = includer;
+
+        if self.coverage.is_some() {
+            self.template_names.pop();
+        }
+        self.include_stack.pop();
+
+        result
+    }
+
+    fn render_block(
+        &mut self,
+        node: &BlockNode,
+        context: &mut Context,
+        output: &mut Output,
+    ) -> Result<()> {
+        match self.block_overrides.get(&node.name).cloned() {
+            Some(block) => {
+                let layout = std::mem::replace(&mut self.current_template, block.template);
+                let result = self.render_nodes(&block.body, context, output);
+                self.current_template = layout;
+                result
+            }
+            None => self.render_nodes(&node.body, context, output),
+        }
+    }
+
+    fn render_variable(&mut self, node: &VariableNode, context: &Context) -> Result<String> {
         let location = node.location;
-        let value = context.resolve(node.path.segments(), location)?;
+        let value = self.resolve(context, &node.path, location)?;
 
-        let str_value = match node.modifier {
-            Modifier::None => value.stringify()?,
-            Modifier::Nullable => value.stringify_nullable()?,
-            Modifier::Required => value.stringify_required()?,
+        // Filters transform what the modifier accepted; a null let through by `?` stays empty
+        let str_value = if node.filters.is_empty() || value.is_null() {
+            match node.modifier {
+                Modifier::None => value.stringify()?,
+                Modifier::Nullable => value.stringify_nullable()?,
+                Modifier::Required => value.stringify_required()?,
+            }
+        } else {
+            if node.modifier == Modifier::Required && value.is_empty_string() {
+                value.stringify_required()?;
+            }
+            let separator = self.options.map_or(
+                filters::DEFAULT_GROUP_SEPARATOR,
+                RenderOptions::group_separator,
+            );
+            let mut filtered = value.clone();
+            for filter in &node.filters {
+                filtered = filters::apply(filter, &filtered, self.filters, separator)?;
+            }
+            filtered.stringify()?
         };
-        Ok(html_escape::escape(&str_value))
+        let str_value = match self.output_hook {
+            Some((hook, _)) => hook.transform(&node.path.as_str(), &str_value),
+            None => Cow::Borrowed(str_value.as_str()),
+        };
+        let mode = self
+            .options
+            .map_or(EscapeMode::default(), RenderOptions::escape_mode);
+        Ok(html_escape::escape_with(&str_value, mode))
     }
 
-    fn render_unsecure(&self, node: &UnsecureNode, context: &Context) -> Result<String> {
+    fn render_unsecure(&mut self, node: &UnsecureNode, context: &Context) -> Result<String> {
         let location = node.location;
-        let value = context.resolve(node.path.segments(), location)?;
+        let value = self.resolve(context, &node.path, location)?;
         value.stringify()
     }
 
-    fn render_if(&mut self, node: &IfBlock, context: &mut Context) -> Result<String> {
+    fn render_translate(&mut self, node: &TranslateNode, context: &Context) -> Result<String> {
+        let empty = Translations::default();
+        let message = self
+            .translations
+            .unwrap_or(&empty)
+            .lookup(self.locale, &node.key)?;
+        let mut args = Vec::with_capacity(node.args.len());
+        for arg in &node.args {
+            let value = self.include_argument(context, arg)?;
+            args.push((arg.name.as_str(), value.stringify()?));
+        }
+        let text = translations::format(message, &args, node.location)?;
+        let mode = self
+            .options
+            .map_or(EscapeMode::default(), RenderOptions::escape_mode);
+        Ok(html_escape::escape_with(&text, mode))
+    }
+
+    /// ` name`, ` name="value"` or nothing, per spec section 4.5.11
+    fn render_attr(&mut self, node: &AttrNode, context: &Context) -> Result<String> {
+        let value = self.resolve(context, &node.path, node.location)?;
+        let text = match value {
+            Value::Null | Value::Bool(false) => return Ok(String::new()),
+            Value::Bool(true) => return Ok(format!(" {}", node.name)),
+            Value::Array(items) => {
+                let mut tokens = Vec::with_capacity(items.len());
+                for item in items {
+                    let token = item.stringify()?;
+                    if !token.is_empty() {
+                        tokens.push(token);
+                    }
+                }
+                tokens.join(" ")
+            }
+            Value::Object(_) => {
+                return Err(NatsuzoraError::TypeError {
+                    message: format!("Cannot use object as value of attribute '{}'", node.name),
+                })
+            }
+            value => value.stringify()?,
+        };
+        if text.is_empty() {
+            return Ok(String::new());
+        }
+        let mode = self
+            .options
+            .map_or(EscapeMode::default(), RenderOptions::escape_mode);
+        Ok(format!(
+            " {}=\"{}\"",
+            node.name,
+            html_escape::escape_with(&text, mode)
+        ))
+    }
+
+    fn render_if(
+        &mut self,
+        node: &IfBlock,
+        context: &mut Context,
+        output: &mut Output,
+    ) -> Result<()> {
         let location = node.location;
-        let value = context.resolve(node.condition.segments(), location)?;
+        let truthy = self.evaluate(context, &node.condition, location)?;
+
+        if truthy {
+            self.record_branch(location, BranchKind::IfThen);
+            return self.render_nodes(&node.then_branch, context, output);
+        }
 
-        if value.is_truthy() {
-            self.render_nodes(&node.then_branch, context)
-        } else if let Some(else_branch) = &node.else_branch {
-            self.render_nodes(else_branch, context)
+        for branch in &node.elseif_branches {
+            let truthy = self.evaluate(context, &branch.condition, branch.location)?;
+            if truthy {
+                self.record_branch(branch.location, BranchKind::IfElseIf);
+                return self.render_nodes(&branch.body, context, output);
+            }
+        }
+
+        if let Some(else_branch) = &node.else_branch {
+            self.record_branch(location, BranchKind::IfElse);
+            self.render_nodes(else_branch, context, output)
         } else {
-            Ok(String::new())
+            self.record_branch(location, BranchKind::IfImplicitElse);
+            Ok(())
         }
     }
 
-    fn render_unless(&mut self, node: &UnlessBlock, context: &mut Context) -> Result<String> {
+    fn render_unless(
+        &mut self,
+        node: &UnlessBlock,
+        context: &mut Context,
+        output: &mut Output,
+    ) -> Result<()> {
         let location = node.location;
-        let value = context.resolve(node.condition.segments(), location)?;
+        let truthy = self.evaluate(context, &node.condition, location)?;
 
-        if value.is_truthy() {
-            Ok(String::new())
+        if truthy {
+            let Some(else_branch) = &node.else_branch else {
+                self.record_branch(location, BranchKind::UnlessImplicitElse);
+                return Ok(());
+            };
+            self.record_branch(location, BranchKind::UnlessElse);
+            self.render_nodes(else_branch, context, output)
         } else {
-            self.render_nodes(&node.body, context)
+            self.record_branch(location, BranchKind::UnlessBody);
+            self.render_nodes(&node.body, context, output)
         }
     }
 
-    fn render_each(&mut self, node: &EachBlock, context: &mut Context) -> Result<String> {
+    fn render_each(
+        &mut self,
+        node: &EachBlock,
+        context: &mut Context,
+        output: &mut Output,
+    ) -> Result<()> {
         let location = node.location;
-        let len = context.get_array_len(node.collection.segments(), location)?;
+        // Ranges are never materialized, so a large bound costs nothing up front
+        let (mut entries, mut length): (Box<dyn Iterator<Item = (Binding, Binding)>>, usize) =
+            match &node.collection {
+                Collection::Path(path) => {
+                    let collection = self.resolve(context, path, location)?;
+                    // Items of data the context holds are bound in place;
+                    // only a fallback collection is copied
+                    match (collection, context.slot(path.segments())) {
+                        (Value::Array(items), Some(slot)) => {
+                            let length = items.len();
+                            let entries = (0..length).map(move |index| {
+                                (
+                                    Binding::Element(Element::new(&slot, Step::Index(index))),
+                                    Binding::Value(Value::Integer(index as i64)),
+                                )
+                            });
+                            (Box::new(entries), length)
+                        }
+                        (Value::Object(entries), Some(slot)) => {
+                            let mut keys: Vec<String> = entries.keys().cloned().collect();
+                            keys.sort();
+                            let length = keys.len();
+                            let entries = keys.into_iter().map(move |key| {
+                                (
+                                    Binding::Value(Value::String(key.clone())),
+                                    Binding::Element(Element::new(&slot, Step::Key(key))),
+                                )
+                            });
+                            (Box::new(entries), length)
+                        }
+                        (collection, _) => {
+                            let entries = collection.loop_entries()?;
+                            let length = entries.len();
+                            let entries = entries.into_iter().map(|(first, second)| {
+                                (Binding::Value(first), Binding::Value(second))
+                            });
+                            (Box::new(entries), length)
+                        }
+                    }
+                }
+                Collection::Range { start, end } => {
+                    let start = self.range_bound(context, start, location)?;
+                    let end = self.range_bound(context, end, location)?;
+                    let length = if end < start {
+                        0
+                    } else {
+                        usize::try_from(i128::from(end) - i128::from(start) + 1)
+                            .unwrap_or(usize::MAX)
+                    };
+                    let entries = (start..=end).enumerate().map(|(index, n)| {
+                        (
+                            Binding::Value(Value::Integer(n)),
+                            Binding::Value(Value::Integer(index as i64)),
+                        )
+                    });
+                    (Box::new(entries), length)
+                }
+                other => return Err(unsupported(&format!("each collection '{other}'"))),
+            };
+        if let Some(offset) = &node.offset {
+            let offset = self
+                .window_bound(context, offset, "offset", location)?
+                .min(length);
+            entries = Box::new(entries.skip(offset));
+            length -= offset;
+        }
+        if let Some(limit) = &node.limit {
+            length = length.min(self.window_bound(context, limit, "limit", location)?);
+            entries = Box::new(entries.take(length));
+        }
+        self.count_iterations(length)?;
+        if length == 0 {
+            let Some(else_branch) = &node.else_branch else {
+                return Ok(());
+            };
+            self.record_branch(location, BranchKind::EachElse);
+            return self.render_nodes(else_branch, context, output);
+        }
+        self.record_branch(location, BranchKind::EachBody);
+
+        // One scope serves every iteration; only its values change
+        let names = std::iter::once(&node.item_ident)
+            .chain(&node.index_ident)
+            .chain(&node.meta_ident);
+        context.push_each_scope(names)?;
+        let mut result = Ok(());
+        for (index, (first, second)) in entries.enumerate() {
+            if output.is_full() {
+                break;
+            }
+            // Checked here too, as an empty or text-only body never reaches
+            // the per-node check
+            if self.options.is_some_and(RenderOptions::is_cancelled) {
+                result = Err(NatsuzoraError::Cancelled);
+                break;
+            }
+
+            context.rebind(&node.item_ident, first);
+            if let Some(index_ident) = &node.index_ident {
+                context.rebind(index_ident, second);
+            }
+            if let Some(meta_ident) = &node.meta_ident {
+                context.rebind(meta_ident, Binding::Value(loop_meta(index, length)));
+            }
+
+            result = self.render_nodes(&node.body, context, output);
+            if result.is_err() {
+                break;
+            }
+        }
+        context.pop_scope();
+        result
+    }
+
+    /// Count the `length` iterations of an each against the iteration limits
+    fn count_iterations(&mut self, length: usize) -> Result<()> {
+        let Some(options) = self.options else {
+            return Ok(());
+        };
+        if let Some(max) = options.max_loop_iterations().filter(|&max| length > max) {
+            return Err(NatsuzoraError::IterationLimit {
+                message: format!("each over {length} items, more than {max}"),
+            });
+        }
+        self.iterations = self.iterations.saturating_add(length);
+        match options.max_render_iterations() {
+            Some(max) if self.iterations > max => Err(NatsuzoraError::IterationLimit {
+                message: format!("each over {length} items takes the render past {max} iterations"),
+            }),
+            _ => Ok(()),
+        }
+    }
 
-        let mut output = String::new();
-        for index in 0..len {
-            let item = context.get_array_item(node.collection.segments(), index, location)?;
+    /// Value of an each `limit=` or `offset=`: a non-negative integer
+    fn window_bound(
+        &mut self,
+        context: &Context,
+        operand: &Operand,
+        name: &str,
+        location: Location,
+    ) -> Result<usize> {
+        let value = match operand {
+            Operand::Path(path) => self.resolve(context, path, location)?.clone(),
+            Operand::Literal(literal) => Value::from(literal),
+        };
+        match value {
+            Value::Integer(n) if n >= 0 => Ok(usize::try_from(n).unwrap_or(usize::MAX)),
+            Value::Integer(n) => Err(NatsuzoraError::TypeError {
+                message: format!("each {name} must be a non-negative integer, got {n}"),
+            }),
+            value => Err(NatsuzoraError::TypeError {
+                message: format!(
+                    "each {name} must be a non-negative integer, got {}",
+                    value.type_name()
+                ),
+            }),
+        }
+    }
 
-            let mut bindings = HashMap::new();
-            bindings.insert(node.item_ident.clone(), item);
+    /// Value of an each range bound: an integer
+    fn range_bound(
+        &mut self,
+        context: &Context,
+        operand: &Operand,
+        location: Location,
+    ) -> Result<i64> {
+        let value = match operand {
+            Operand::Path(path) => self.resolve(context, path, location)?.clone(),
+            Operand::Literal(literal) => Value::from(literal),
+        };
+        match value {
+            Value::Integer(n) => Ok(n),
+            value => Err(NatsuzoraError::TypeError {
+                message: format!(
+                    "each range bound must be an integer, got {}",
+                    value.type_name()
+                ),
+            }),
+        }
+    }
 
-            context.push_scope(bindings)?;
-            let iteration = self.render_nodes(&node.body, context)?;
-            context.pop_scope();
+    fn render_cache(
+        &mut self,
+        node: &CacheBlock,
+        context: &mut Context,
+        output: &mut Output,
+    ) -> Result<()> {
+        let key = self
+            .resolve(context, &node.key, node.location)?
+            .stringify()?;
+        let Some(cache) = self.fragment_cache else {
+            return self.render_nodes(&node.body, context, output);
+        };
 
-            output.push_str(&iteration);
+        let mut key = FragmentKey::new(node.fingerprint, key);
+        if let Some(theme) = self.theme {
+            key = key.with_theme(theme);
+        }
+        if let Some(locale) = self.locale {
+            key = key.with_locale(locale);
+        }
+        let settings = self.fragment_settings();
+        if !settings.is_empty() {
+            key = key.with_settings(settings);
+        }
+        if let Some(fragment) = cache.get(&key) {
+            output.push(&fragment);
+            return Ok(());
+        }
+        // Render the whole fragment so a truncated render never caches a partial one
+        let errors_before = self.errors.len();
+        let mut fragment = output.fragment();
+        self.render_nodes(&node.body, context, &mut fragment)?;
+        let fragment = fragment.into_string();
+        output.push(&fragment);
+        if self.errors.len() == errors_before {
+            cache.insert(key, fragment);
         }
+        Ok(())
+    }
 
-        Ok(output)
+    /// Fingerprint of the settings besides theme and locale that change
+    /// fragment output; empty with the defaults
+    fn fragment_settings(&self) -> String {
+        let mut settings = self
+            .options
+            .map(RenderOptions::output_settings)
+            .unwrap_or_default();
+        if let Some(filters) = self.filters.filter(|filters| filters.id() != 0) {
+            settings.push_str(&format!("filters={};", filters.id()));
+        }
+        if let Some((_, id)) = self.output_hook {
+            settings.push_str(&format!("output_hook={id};"));
+        }
+        if let Some(translations) = self.translations {
+            settings.push_str(&format!("translations={};", translations.fingerprint()));
+        }
+        if let Some(edge_includes) = self.edge_includes {
+            settings.push_str(&format!("edge_includes={edge_includes:?};"));
+        }
+        if settings.is_empty() {
+            settings
+        } else {
+            audit::stable_hash(settings.as_bytes())
+        }
     }
 
-    fn render_include(&mut self, node: &IncludeNode, context: &mut Context) -> Result<String> {
-        let partial = {
-            let loader =
-                self.template_loader
-                    .as_mut()
-                    .ok_or_else(|| NatsuzoraError::IncludeError {
-                        message: "Template loader not configured for include".to_string(),
-                    })?;
-            loader.load(&node.name)?
+    /// Value passed for an include argument: a resolved path or a literal
+    fn include_argument(&mut self, context: &Context, arg: &IncludeArg) -> Result<Value> {
+        match &arg.value {
+            Operand::Path(path) => Ok(self.resolve(context, path, arg.location)?.clone()),
+            Operand::Literal(literal) => Ok(Value::from(literal)),
+        }
+    }
+
+    /// The partial named by the string at `source`, which must be a valid
+    /// include name under one of the prefixes allowed for dynamic includes
+    fn dynamic_include_name(
+        &mut self,
+        context: &Context,
+        source: &Path,
+        location: Location,
+    ) -> Result<String> {
+        let name = match self.resolve(context, source, location)? {
+            Value::String(name) => name.clone(),
+            other => {
+                return Err(NatsuzoraError::TypeError {
+                    message: format!(
+                        "Dynamic include name '{}' must be a string, got {}",
+                        source.as_str(),
+                        other.type_name()
+                    ),
+                })
+            }
         };
+        validate_include_name(&name)?;
+        let prefixes = self
+            .options
+            .map_or(&[][..], RenderOptions::dynamic_include_prefixes);
+        if !prefixes.iter().any(|prefix| under(&name, prefix)) {
+            let message = if prefixes.is_empty() {
+                format!("Dynamic include of '{name}' is not allowed")
+            } else {
+                format!(
+                    "Dynamic include of '{name}' is outside {}",
+                    prefixes.join(", ")
+                )
+            };
+            return Err(NatsuzoraError::IncludeError { message });
+        }
+        Ok(name)
+    }
 
-        let mut bindings = HashMap::new();
+    /// The spread object's entries ordered by key, then the explicit
+    /// arguments, which replace entries of the same name
+    fn include_arguments(
+        &mut self,
+        context: &Context,
+        node: &IncludeNode,
+    ) -> Result<Vec<(String, Value)>> {
+        let mut arguments = Vec::new();
+        if let Some(spread) = &node.spread {
+            let object = match self.resolve(context, spread, spread.location())? {
+                Value::Object(object) => object,
+                value => {
+                    return Err(NatsuzoraError::TypeError {
+                        message: format!(
+                            "Spread argument '{}' must be an object, got {}",
+                            spread.as_str(),
+                            value.type_name()
+                        ),
+                    })
+                }
+            };
+            let mut entries: Vec<(&String, &Value)> = object
+                .iter()
+                .filter(|(key, _)| node.args.iter().all(|arg| arg.name != **key))
+                .collect();
+            entries.sort_by_key(|(key, _)| *key);
+            arguments.extend(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            );
+        }
         for arg in &node.args {
-            let value = context.resolve(arg.value.segments(), arg.location)?.clone();
-            bindings.insert(arg.name.clone(), value);
+            let value = self.include_argument(context, arg)?;
+            arguments.push((arg.name.clone(), value));
+        }
+        Ok(arguments)
+    }
+
+    fn render_include(
+        &mut self,
+        node: &IncludeNode,
+        context: &mut Context,
+        output: &mut Output,
+    ) -> Result<()> {
+        let resolved;
+        let name = match &node.source {
+            Some(source) => {
+                resolved = self.dynamic_include_name(context, source, node.location)?;
+                resolved.as_str()
+            }
+            None => node.name.as_str(),
+        };
+        if let Some(edge_includes) = self.edge_includes.filter(|e| e.contains(name)) {
+            if node.body.is_some() {
+                return Err(NatsuzoraError::IncludeError {
+                    message: format!("Edge include cannot take a body: {name}"),
+                });
+            }
+            let mut args = Vec::new();
+            for (name, value) in self.include_arguments(context, node)? {
+                let value = value.stringify()?;
+                args.push((name, value));
+            }
+            output.push(&edge_includes.directive(name, &args));
+            return Ok(());
+        }
+
+        let active = self.include_stack.iter().filter(|n| **n == name).count();
+        let max_depth = self.options.map_or(0, RenderOptions::include_recursion);
+        if active > max_depth {
+            let message = if max_depth == 0 {
+                format!("Circular include detected: {name}")
+            } else {
+                format!("Include recursion deeper than {max_depth}: {name}")
+            };
+            return Err(NatsuzoraError::IncludeError { message });
         }
+        let partial = self.load(name)?;
 
-        if let Some(loader) = self.template_loader.as_mut() {
-            loader.push_include(&node.name);
+        let mut bindings: HashMap<String, Value> =
+            self.include_arguments(context, node)?.into_iter().collect();
+        if let Some(params) = partial.params() {
+            check_params(name, node, params, &mut bindings)?;
         }
 
-        context.push_include_scope(bindings);
-        let result = self.render_nodes(partial.nodes(), context);
+        // The body is rendered in the caller's scope, before the partial's
+        let body = match &node.body {
+            Some(body) => {
+                let mut rendered = output.fragment();
+                self.render_nodes(body, context, &mut rendered)?;
+                rendered.into_string()
+            }
+            None => String::new(),
+        };
+
+        self.include_stack.push(name.to_string());
+        if let Some(coverage) = self.coverage.as_mut() {
+            coverage.register(name, &partial);
+            self.template_names.push(name.to_string());
+        }
+
+        // A partial's blocks belong to its own layout, not to the includer's
+        let overrides = std::mem::take(&mut self.block_overrides);
+        // A partial included without a body yields nothing, not its includer's body
+        self.yields.push(body);
+        // A recursive include sees only its arguments, so the partial's loop
+        // variables do not shadow those of its outer copy
+        if active > 0 {
+            context.push_isolated_scope(bindings);
+        } else {
+            context.push_include_scope(bindings);
+        }
+        let nodes = trim_partial(partial.nodes(), node.trim_left, node.trim_right);
+        let includer = self.current_template.replace(name.to_string());
+        let result = if self
+            .options
+            .is_some_and(RenderOptions::collapse_blank_includes)
+        {
+            let mut rendered = output.fragment();
+            let result = self.render_template(&nodes, context, &mut rendered);
+            let rendered = rendered.into_string();
+            if !rendered.chars().all(is_blank) {
+                output.push(&rendered);
+            }
+            result
+        } else {
+            self.render_template(&nodes, context, output)
+        };
+        self.current_template = includer;
         context.pop_scope();
+        self.yields.pop();
+        self.block_overrides = overrides;
 
-        if let Some(loader) = self.template_loader.as_mut() {
-            loader.pop_include();
+        if self.coverage.is_some() {
+            self.template_names.pop();
         }
 
+        self.include_stack.pop();
+
         result
     }
+
+    fn load(&mut self, name: &str) -> Result<Template> {
+        let loader = self
+            .template_loader
+            .as_mut()
+            .ok_or_else(|| NatsuzoraError::IncludeError {
+                message: "Template loader not configured for include".to_string(),
+            })?;
+        loader.load(name).map_err(loader_error)
+    }
+}
+
+/// An integer or float as `f64`
+fn number(value: &Value) -> f64 {
+    match value {
+        Value::Integer(n) => *n as f64,
+        Value::Float(f) => *f,
+        _ => unreachable!("not a number"),
+    }
+}
+
+/// Check an include's arguments against the partial's `{[%params ...]}`,
+/// binding omitted optional parameters to null
+///
+/// Keys of a spread object may satisfy parameters but are not required to
+/// be declared.
+fn check_params(
+    name: &str,
+    node: &IncludeNode,
+    params: &[Param],
+    bindings: &mut HashMap<String, Value>,
+) -> Result<()> {
+    let unknown = node
+        .args
+        .iter()
+        .find(|arg| params.iter().all(|param| param.name != arg.name));
+    if let Some(arg) = unknown {
+        let declared: Vec<&str> = params.iter().map(|param| param.name.as_str()).collect();
+        return Err(NatsuzoraError::IncludeError {
+            message: format!(
+                "Unknown argument '{}' for {} (declared: {})",
+                arg.name,
+                name,
+                if declared.is_empty() {
+                    "none".to_string()
+                } else {
+                    declared.join(", ")
+                }
+            ),
+        });
+    }
+    for param in params {
+        if bindings.contains_key(&param.name) {
+            continue;
+        }
+        if !param.optional {
+            return Err(NatsuzoraError::IncludeError {
+                message: format!("Missing argument '{}' for {name}", param.name),
+            });
+        }
+        bindings.insert(param.name.clone(), Value::Null);
+    }
+    Ok(())
+}
+
+/// `nodes` with the leading whitespace of a first text node and the trailing
+/// whitespace of a last text node removed, as the include tag's trim
+/// markers ask
+fn trim_partial(nodes: &[AstNode], trim_left: bool, trim_right: bool) -> Cow<'_, [AstNode]> {
+    if !trim_left && !trim_right {
+        return Cow::Borrowed(nodes);
+    }
+    let mut nodes = nodes.to_vec();
+    if trim_left {
+        if let Some(AstNode::Text(text)) = nodes.first_mut() {
+            let start = text.content.len() - text.content.trim_start_matches(is_blank).len();
+            text.content = text.content.slice(start..text.content.len());
+        }
+    }
+    if trim_right {
+        if let Some(AstNode::Text(text)) = nodes.last_mut() {
+            let end = text.content.trim_end_matches(is_blank).len();
+            text.content = text.content.slice(0..end);
+        }
+    }
+    Cow::Owned(nodes)
+}
+
+/// Whitespace as trimmed around includes: spaces, tabs and line breaks
+fn is_blank(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+/// Record `block` and the blocks nested in it, keeping overrides already set
+fn collect_blocks(
+    block: &BlockNode,
+    template: &Option<String>,
+    overrides: &mut HashMap<String, BlockOverride>,
+) {
+    overrides
+        .entry(block.name.clone())
+        .or_insert_with(|| BlockOverride {
+            body: block.body.clone(),
+            template: template.clone(),
+        });
+    for node in &block.body {
+        if let AstNode::Block(nested) = node {
+            collect_blocks(nested, template, overrides);
+        }
+    }
+}
+
+/// Error for syntax from a newer natsuzora-ast than this renderer handles
+fn unsupported(what: &str) -> NatsuzoraError {
+    NatsuzoraError::TypeError {
+        message: format!("Unsupported {what}"),
+    }
+}
+
+/// Recover the [`NatsuzoraError`] a [`TemplateLoader`] boxed, or wrap another loader's error
+fn loader_error(error: LoaderError) -> NatsuzoraError {
+    match error.downcast::<NatsuzoraError>() {
+        Ok(error) => *error,
+        Err(error) => NatsuzoraError::IncludeError {
+            message: error.to_string(),
+        },
+    }
+}
+
+/// `index`, `first`, `last` and `length` of one `each` iteration
+fn loop_meta(index: usize, length: usize) -> Value {
+    Value::Object(IndexMap::from([
+        ("index".to_string(), Value::Integer(index as i64)),
+        ("first".to_string(), Value::Bool(index == 0)),
+        ("last".to_string(), Value::Bool(index + 1 == length)),
+        ("length".to_string(), Value::Integer(length as i64)),
+    ]))
 }
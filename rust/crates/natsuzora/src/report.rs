@@ -0,0 +1,368 @@
+//! Machine-readable check report for a template directory.
+//!
+//! [`CheckReport`] gathers what the analysis features find — parse errors,
+//! broken or cyclic includes, unused partials, unsecure output and the
+//! include graph — into one artifact. [`CheckReport::to_json`] emits it as
+//! a schema-versioned JSON document for CI annotations and dashboards:
+//!
+//! ```rust,ignore
+//! let report = natsuzora::CheckReport::check_dir("templates")?;
+//! println!("{}", report.to_json());
+//! std::process::exit(if report.is_ok() { 0 } else { 1 });
+//! ```
+
+use crate::analysis::TemplateSet;
+use crate::error::{Location, Result};
+use crate::version::{LANGUAGE_VERSION, VERSION};
+use natsuzora_ast::AstNode;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+/// Version of the JSON layout produced by [`CheckReport::to_json`]
+///
+/// Bumped whenever a field is removed or changes meaning; new fields may be
+/// added without a bump.
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// A problem found in one template file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Stable identifier: `parse_error`, `missing_include`, `include_cycle`
+    /// or `unused_partial`
+    pub code: &'static str,
+    /// Path relative to the checked directory
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// An `{[!unsecure ... ]}` output, listed for review.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsecureAudit {
+    pub file: PathBuf,
+    pub template: String,
+    pub path: String,
+    pub location: Location,
+}
+
+/// Report over every template under a directory.
+#[derive(Debug, Clone)]
+pub struct CheckReport {
+    root: PathBuf,
+    set: TemplateSet,
+    diagnostics: Vec<Diagnostic>,
+    unsecure: Vec<UnsecureAudit>,
+}
+
+impl CheckReport {
+    /// Check every `.ntzr` file under `root`
+    ///
+    /// Files that fail to parse are reported as diagnostics rather than
+    /// errors; only I/O failures make this return `Err`.
+    pub fn check_dir(root: impl AsRef<Path>) -> Result<Self> {
+        let root = root.as_ref().to_path_buf();
+        let (set, failures) = TemplateSet::load_dir_lenient(&root)?;
+
+        let mut diagnostics: Vec<Diagnostic> = failures
+            .into_iter()
+            .map(|(file, e)| {
+                let (line, column) = e.line_column();
+                Diagnostic {
+                    severity: Severity::Error,
+                    code: "parse_error",
+                    file,
+                    line,
+                    column,
+                    message: e.to_string(),
+                }
+            })
+            .collect();
+        let mut unsecure = Vec::new();
+
+        for entry in set.iter() {
+            let mut sites = Vec::new();
+            include_sites(entry.template.nodes(), &mut sites);
+            for (name, location) in sites {
+                let problem = if set.get(name).is_none() {
+                    Some((
+                        "missing_include",
+                        format!("included template '{name}' not found"),
+                    ))
+                } else if set.on_cycle(&entry.name, name) {
+                    Some((
+                        "include_cycle",
+                        format!("include of '{name}' forms a cycle"),
+                    ))
+                } else {
+                    None
+                };
+                if let Some((code, message)) = problem {
+                    diagnostics.push(Diagnostic {
+                        severity: Severity::Error,
+                        code,
+                        file: entry.path.clone(),
+                        line: location.line,
+                        column: location.column,
+                        message,
+                    });
+                }
+            }
+            if entry.is_partial() && set.used_by(&entry.name).is_empty() {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Warning,
+                    code: "unused_partial",
+                    file: entry.path.clone(),
+                    line: 1,
+                    column: 1,
+                    message: format!("partial '{}' is not included anywhere", entry.name),
+                });
+            }
+            unsecure.extend(entry.info.unsecure.iter().map(|u| UnsecureAudit {
+                file: entry.path.clone(),
+                template: entry.name.clone(),
+                path: u.path.clone(),
+                location: u.location,
+            }));
+        }
+        diagnostics.sort_by(|a, b| (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column)));
+
+        Ok(Self {
+            root,
+            set,
+            diagnostics,
+            unsecure,
+        })
+    }
+
+    /// Whether no diagnostic is an error
+    pub fn is_ok(&self) -> bool {
+        !self
+            .diagnostics
+            .iter()
+            .any(|d| d.severity == Severity::Error)
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn unsecure(&self) -> &[UnsecureAudit] {
+        &self.unsecure
+    }
+
+    /// The templates that parsed
+    pub fn templates(&self) -> &TemplateSet {
+        &self.set
+    }
+
+    /// The report as JSON, following [`REPORT_SCHEMA_VERSION`]
+    pub fn to_json(&self) -> serde_json::Value {
+        let count = |severity| {
+            self.diagnostics
+                .iter()
+                .filter(|d| d.severity == severity)
+                .count()
+        };
+        let diagnostics: Vec<_> = self
+            .diagnostics
+            .iter()
+            .map(|d| {
+                json!({
+                    "severity": d.severity.as_str(),
+                    "code": d.code,
+                    "file": slash_path(&d.file),
+                    "line": d.line,
+                    "column": d.column,
+                    "message": d.message,
+                })
+            })
+            .collect();
+        let unsecure: Vec<_> = self
+            .unsecure
+            .iter()
+            .map(|u| {
+                json!({
+                    "file": slash_path(&u.file),
+                    "template": u.template,
+                    "path": u.path,
+                    "line": u.location.line,
+                    "column": u.location.column,
+                })
+            })
+            .collect();
+        let nodes: Vec<_> = self
+            .set
+            .iter()
+            .map(|entry| {
+                json!({
+                    "name": entry.name,
+                    "file": slash_path(&entry.path),
+                    "partial": entry.is_partial(),
+                    "bytes": entry.source.len(),
+                })
+            })
+            .collect();
+        let edges: Vec<_> = self
+            .set
+            .edges()
+            .map(|(from, to)| {
+                json!({
+                    "from": from,
+                    "to": to,
+                    "missing": self.set.get(to).is_none(),
+                    "cyclic": self.set.on_cycle(from, to),
+                })
+            })
+            .collect();
+
+        json!({
+            "schema_version": REPORT_SCHEMA_VERSION,
+            "natsuzora_version": VERSION,
+            "language_version": LANGUAGE_VERSION,
+            "root": self.root.display().to_string(),
+            "ok": self.is_ok(),
+            "stats": {
+                "templates": self.set.iter().filter(|e| !e.is_partial()).count(),
+                "partials": self.set.iter().filter(|e| e.is_partial()).count(),
+                "includes": edges.len(),
+                "unsecure_outputs": unsecure.len(),
+                "errors": count(Severity::Error),
+                "warnings": count(Severity::Warning),
+            },
+            "diagnostics": diagnostics,
+            "unsecure": unsecure,
+            "include_graph": {
+                "nodes": nodes,
+                "edges": edges,
+            },
+        })
+    }
+}
+
+/// Every include and layout reference in `nodes` with its location, in source order
+fn include_sites<'a>(nodes: &'a [AstNode], out: &mut Vec<(&'a str, Location)>) {
+    for node in nodes {
+        match node {
+            AstNode::If(n) => {
+                include_sites(&n.then_branch, out);
+                for branch in &n.elseif_branches {
+                    include_sites(&branch.body, out);
+                }
+                if let Some(else_branch) = &n.else_branch {
+                    include_sites(else_branch, out);
+                }
+            }
+            AstNode::Unless(n) => {
+                include_sites(&n.body, out);
+                if let Some(else_branch) = &n.else_branch {
+                    include_sites(else_branch, out);
+                }
+            }
+            AstNode::Each(n) => {
+                include_sites(&n.body, out);
+                if let Some(else_branch) = &n.else_branch {
+                    include_sites(else_branch, out);
+                }
+            }
+            AstNode::Cache(n) => include_sites(&n.body, out),
+            AstNode::Block(n) => include_sites(&n.body, out),
+            AstNode::Include(n) => {
+                if n.source.is_none() {
+                    out.push((&n.name, n.location));
+                }
+                if let Some(body) = &n.body {
+                    include_sites(body, out);
+                }
+            }
+            AstNode::Extends(n) => out.push((&n.name, n.location)),
+            _ => {}
+        }
+    }
+}
+
+/// Relative path with `/` separators on every platform
+fn slash_path(path: &Path) -> String {
+    path.iter()
+        .map(|segment| segment.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_report_collects_every_finding() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for (path, source) in [
+            ("index.ntzr", "{[!include /a ]}\n{[!include /gone ]}"),
+            ("broken.ntzr", "ok\n{[#if]}"),
+            ("_a.ntzr", "{[!unsecure html ]}{[!include /b ]}"),
+            ("_b.ntzr", "{[!include /a ]}"),
+            ("_spare.ntzr", "spare"),
+        ] {
+            fs::write(dir.path().join(path), source).unwrap();
+        }
+
+        let report = CheckReport::check_dir(dir.path()).unwrap();
+        assert!(!report.is_ok());
+        let codes: Vec<_> = report
+            .diagnostics()
+            .iter()
+            .map(|d| (slash_path(&d.file), d.code, d.line))
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                ("_a.ntzr".to_string(), "include_cycle", 1),
+                ("_b.ntzr".to_string(), "include_cycle", 1),
+                ("_spare.ntzr".to_string(), "unused_partial", 1),
+                ("broken.ntzr".to_string(), "parse_error", 2),
+                ("index.ntzr".to_string(), "missing_include", 2),
+            ]
+        );
+
+        let json = report.to_json();
+        assert_eq!(json["schema_version"], REPORT_SCHEMA_VERSION);
+        assert_eq!(json["ok"], false);
+        assert_eq!(
+            json["stats"],
+            json!({
+                "templates": 1,
+                "partials": 3,
+                "includes": 4,
+                "unsecure_outputs": 1,
+                "errors": 4,
+                "warnings": 1,
+            })
+        );
+        assert_eq!(
+            json["unsecure"][0],
+            json!({"file": "_a.ntzr", "template": "/a", "path": "html", "line": 1, "column": 3})
+        );
+        assert_eq!(
+            json["include_graph"]["edges"][0],
+            json!({"from": "/a", "to": "/b", "missing": false, "cyclic": true})
+        );
+    }
+}
@@ -0,0 +1,384 @@
+//! Self-contained render snapshots for bug reports.
+//!
+//! [`Natsuzora::capture_repro`] renders like
+//! [`Natsuzora::render_with_options`] while recording the source of every
+//! partial and layout it loads. The resulting [`ReproBundle`] holds the entry
+//! template, those sources, the data, the options, the engine version and
+//! the outcome; [`ReproBundle::to_json`] turns it into one file to attach to
+//! an issue. [`Natsuzora::replay`] renders a bundle again without the
+//! original template directory:
+//!
+//! ```rust,ignore
+//! let bundle = tmpl.capture_repro(data, &options)?;
+//! fs::write("repro.json", bundle.to_json().to_string())?;
+//!
+//! // on the maintainer's machine
+//! let bundle = ReproBundle::from_json(serde_json::from_str(&fs::read_to_string("repro.json")?)?)?;
+//! assert_eq!(Natsuzora::replay(&bundle).map_err(|e| e.to_string()), bundle.result);
+//! ```
+//!
+//! Custom filters, output hooks, source hooks, template sources, profiles
+//! and edge includes are host code and are not captured.
+//!
+//! [`Natsuzora::capture_repro`]: crate::Natsuzora::capture_repro
+//! [`Natsuzora::render_with_options`]: crate::Natsuzora::render_with_options
+//! [`Natsuzora::replay`]: crate::Natsuzora::replay
+
+use crate::error::{NatsuzoraError, Result};
+use crate::html_escape::EscapeMode;
+use crate::options::RenderOptions;
+use crate::translations::Translations;
+use crate::value::{IntegerPolicy, IntegerRange, Value};
+use natsuzora_ast::{Delimiters, IncludeLoader, LoaderError, ParseLimits, ParseOptions, Template};
+use serde_json::{json, Value as JsonValue};
+use std::collections::BTreeMap;
+
+/// Version of the JSON layout produced by [`ReproBundle::to_json`]
+///
+/// Bumped whenever a field is removed or changes meaning; new fields may be
+/// added without a bump.
+pub const REPRO_SCHEMA_VERSION: u32 = 1;
+
+/// Everything needed to render a template again, and what it rendered.
+#[derive(Debug, Clone)]
+pub struct ReproBundle {
+    /// Version of the engine that captured the bundle
+    pub natsuzora_version: String,
+    /// Source of the entry template
+    pub source: String,
+    /// Source of every partial and layout loaded, by include name
+    pub partials: BTreeMap<String, String>,
+    pub data: JsonValue,
+    pub parse_options: ParseOptions,
+    pub render_options: RenderOptions,
+    pub locale: Option<String>,
+    pub default_data: Option<JsonValue>,
+    pub translations: Option<Translations>,
+    /// Output of the captured render, or its error message
+    pub result: std::result::Result<String, String>,
+}
+
+impl ReproBundle {
+    /// The bundle as a JSON document
+    pub fn to_json(&self) -> JsonValue {
+        let options = &self.render_options;
+        let (range, policy) = options.integer_bounds().unwrap_or_default();
+        let fallbacks: serde_json::Map<String, JsonValue> = options
+            .fallback_paths()
+            .into_iter()
+            .map(|path| {
+                let value = options.fallback(&path).map_or(JsonValue::Null, to_json);
+                (path, value)
+            })
+            .collect();
+        let (output, error) = match &self.result {
+            Ok(output) => (Some(output), None),
+            Err(error) => (None, Some(error)),
+        };
+        json!({
+            "schema_version": REPRO_SCHEMA_VERSION,
+            "natsuzora_version": self.natsuzora_version,
+            "source": self.source,
+            "partials": self.partials,
+            "data": self.data,
+            "parse_options": {
+                "trim_blocks": self.parse_options.trim_blocks,
+                "lstrip_blocks": self.parse_options.lstrip_blocks,
+                "delimiters": [
+                    self.parse_options.delimiters.open(),
+                    self.parse_options.delimiters.close(),
+                ],
+                "filters": self.parse_options.filters,
+                "limits": {
+                    "max_source_bytes": self.parse_options.limits.max_source_bytes,
+                    "max_nodes": self.parse_options.limits.max_nodes,
+                    "max_depth": self.parse_options.limits.max_depth,
+                },
+            },
+            "render_options": {
+                "truncation": options.truncation().map(|(max_bytes, marker)| {
+                    json!({"max_bytes": max_bytes, "marker": marker})
+                }),
+                "max_output_bytes": options.max_output_bytes(),
+                "max_loop_iterations": options.max_loop_iterations(),
+                "max_render_iterations": options.max_render_iterations(),
+                "collect_errors": options.collect_errors(),
+                "fallbacks": fallbacks,
+                "integer_range": match range {
+                    IntegerRange::Safe => "safe",
+                    IntegerRange::Full => "full",
+                },
+                "integer_policy": match policy {
+                    IntegerPolicy::Error => "error",
+                    IntegerPolicy::Saturate => "saturate",
+                    IntegerPolicy::Stringify => "stringify",
+                },
+                "escape_mode": match options.escape_mode() {
+                    EscapeMode::Standard => "standard",
+                    EscapeMode::Extended => "extended",
+                },
+                "include_recursion": options.include_recursion(),
+                "collapse_blank_includes": options.collapse_blank_includes(),
+                "group_separator": options.group_separator(),
+            },
+            "locale": self.locale,
+            "default_data": self.default_data,
+            "translations": self.translations.as_ref().map(Translations::to_json),
+            "fallback_locale": self.translations.as_ref().and_then(Translations::fallback_locale),
+            "output": output,
+            "error": error,
+        })
+    }
+
+    /// Read a bundle written by [`Self::to_json`]
+    pub fn from_json(json: JsonValue) -> Result<Self> {
+        let schema = json["schema_version"].as_u64();
+        if schema != Some(u64::from(REPRO_SCHEMA_VERSION)) {
+            return Err(invalid(&format!(
+                "unsupported schema version {}",
+                json["schema_version"]
+            )));
+        }
+
+        let parse = &json["parse_options"];
+        let delimiters = match parse["delimiters"].as_array().map(Vec::as_slice) {
+            Some([open, close]) => Delimiters::new(string(open)?, string(close)?)
+                .map_err(|e| invalid(&e.to_string()))?,
+            _ => return Err(invalid("'delimiters' must be a pair of strings")),
+        };
+        let parse_options = ParseOptions {
+            trim_blocks: flag(&parse["trim_blocks"]),
+            lstrip_blocks: flag(&parse["lstrip_blocks"]),
+            delimiters,
+            filters: parse["filters"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(string)
+                .collect::<Result<_>>()?,
+            limits: ParseLimits {
+                max_source_bytes: limit(&parse["limits"]["max_source_bytes"]),
+                max_nodes: limit(&parse["limits"]["max_nodes"]),
+                max_depth: limit(&parse["limits"]["max_depth"]),
+            },
+        };
+
+        let render = &json["render_options"];
+        let mut render_options = RenderOptions::new();
+        if let Some(truncation) = render["truncation"].as_object() {
+            let max_bytes = truncation["max_bytes"]
+                .as_u64()
+                .and_then(|n| usize::try_from(n).ok())
+                .ok_or_else(|| invalid("'max_bytes' must be an integer"))?;
+            render_options =
+                render_options.with_truncation(max_bytes, string(&truncation["marker"])?);
+        }
+        if let Some(max_bytes) = limit(&render["max_output_bytes"]) {
+            render_options = render_options.with_max_output_bytes(max_bytes);
+        }
+        if let Some(max) = limit(&render["max_loop_iterations"]) {
+            render_options = render_options.with_max_loop_iterations(max);
+        }
+        if let Some(max) = limit(&render["max_render_iterations"]) {
+            render_options = render_options.with_max_render_iterations(max);
+        }
+        if flag(&render["collect_errors"]) {
+            render_options = render_options.with_collected_errors();
+        }
+        for (path, value) in render["fallbacks"].as_object().into_iter().flatten() {
+            render_options = render_options.with_fallback(path, Value::from_json(value.clone())?);
+        }
+        let range = match render["integer_range"].as_str() {
+            Some("full") => IntegerRange::Full,
+            _ => IntegerRange::Safe,
+        };
+        let policy = match render["integer_policy"].as_str() {
+            Some("saturate") => IntegerPolicy::Saturate,
+            Some("stringify") => IntegerPolicy::Stringify,
+            _ => IntegerPolicy::Error,
+        };
+        render_options = render_options.with_integer_bounds(range, policy);
+        if render["escape_mode"].as_str() == Some("extended") {
+            render_options = render_options.with_escape_mode(EscapeMode::Extended);
+        }
+        if flag(&render["collapse_blank_includes"]) {
+            render_options = render_options.with_blank_includes_collapsed();
+        }
+        if let Some(separator) = render["group_separator"].as_str() {
+            render_options = render_options.with_group_separator(separator);
+        }
+        if let Some(max_depth) = render["include_recursion"].as_u64() {
+            let max_depth = usize::try_from(max_depth)
+                .map_err(|_| invalid("'include_recursion' is out of range"))?;
+            render_options = render_options.with_recursive_includes(max_depth);
+        }
+
+        let translations = match &json["translations"] {
+            JsonValue::Null => None,
+            catalog => {
+                let mut translations = Translations::from_json(catalog.clone())?;
+                if let Some(fallback) = json["fallback_locale"].as_str() {
+                    translations = translations.with_fallback_locale(fallback);
+                }
+                Some(translations)
+            }
+        };
+        let result = match (&json["output"], &json["error"]) {
+            (JsonValue::String(output), JsonValue::Null) => Ok(output.clone()),
+            (JsonValue::Null, JsonValue::String(error)) => Err(error.clone()),
+            _ => return Err(invalid("exactly one of 'output' and 'error' must be set")),
+        };
+
+        Ok(Self {
+            natsuzora_version: string(&json["natsuzora_version"])?,
+            source: string(&json["source"])?,
+            partials: json["partials"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(name, source)| Ok((name.clone(), string(source)?)))
+                .collect::<Result<_>>()?,
+            data: json["data"].clone(),
+            parse_options,
+            render_options,
+            locale: json["locale"].as_str().map(str::to_string),
+            default_data: Some(json["default_data"].clone()).filter(|d| !d.is_null()),
+            translations,
+            result,
+        })
+    }
+}
+
+/// Serves a bundle's partials to the renderer.
+pub(crate) struct BundleLoader<'a> {
+    pub(crate) bundle: &'a ReproBundle,
+}
+
+impl IncludeLoader for BundleLoader<'_> {
+    fn load(&mut self, name: &str) -> std::result::Result<Template, LoaderError> {
+        let source = self.bundle.partials.get(name).ok_or_else(|| {
+            Box::new(NatsuzoraError::IncludeError {
+                message: format!("Include file not found in bundle: {name}"),
+            }) as LoaderError
+        })?;
+        natsuzora_ast::parse_with_options(source, &self.bundle.parse_options).map_err(|e| {
+            Box::new(NatsuzoraError::IncludeError {
+                message: format!("Failed to parse include '{name}': {e}"),
+            }) as LoaderError
+        })
+    }
+}
+
+fn to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Null => JsonValue::Null,
+        Value::Bool(b) => JsonValue::Bool(*b),
+        Value::Integer(n) => JsonValue::from(*n),
+        Value::Float(f) => JsonValue::from(*f),
+        Value::String(s) => JsonValue::String(s.clone()),
+        Value::Array(items) => JsonValue::Array(items.iter().map(to_json).collect()),
+        Value::Object(map) => JsonValue::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), to_json(value)))
+                .collect(),
+        ),
+        // Captured as the text it renders as
+        Value::Scalar(scalar) => JsonValue::String(scalar.get().stringify()),
+    }
+}
+
+fn string(value: &JsonValue) -> Result<String> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| invalid(&format!("expected a string, got {value}")))
+}
+
+fn flag(value: &JsonValue) -> bool {
+    value.as_bool().unwrap_or(false)
+}
+
+/// A parse limit, unbounded when absent
+fn limit(value: &JsonValue) -> Option<usize> {
+    value.as_u64().and_then(|n| usize::try_from(n).ok())
+}
+
+fn invalid(message: &str) -> NatsuzoraError {
+    NatsuzoraError::TypeError {
+        message: format!("Invalid repro bundle: {message}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Natsuzora;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_capture_and_replay_without_templates() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("_layout.ntzr"),
+            "<main>{[#block body]}{[/block]}</main>",
+        )
+        .unwrap();
+        fs::write(dir.path().join("_card.ntzr"), "[{[ t ]}]").unwrap();
+        fs::write(dir.path().join("_unused.ntzr"), "x").unwrap();
+        let tmpl = Natsuzora::parse_with_includes(
+            "{[!extends /layout ]}{[#block body]}{[!include /card t=title ]}{[ missing ]}{[/block]}",
+            dir.path(),
+        )
+        .unwrap()
+        .with_locale("ja")
+        .with_default_data(json!({"title": "Default"}))
+        .unwrap();
+        let options = RenderOptions::new()
+            .with_fallback("missing", Value::String("<fb>".into()))
+            .with_escape_mode(EscapeMode::Extended);
+
+        let bundle = tmpl.capture_repro(json!({}), &options).unwrap();
+        assert_eq!(
+            bundle.result.as_deref(),
+            Ok("<main>[Default]&lt;fb&gt;</main>")
+        );
+        assert_eq!(
+            bundle.partials.keys().collect::<Vec<_>>(),
+            ["/card", "/layout"]
+        );
+
+        let bundle = ReproBundle::from_json(bundle.to_json()).unwrap();
+        drop(dir);
+        assert_eq!(
+            Natsuzora::replay(&bundle).map_err(|e| e.to_string()),
+            bundle.result
+        );
+    }
+
+    #[test]
+    fn test_failed_render_is_captured() {
+        let tmpl = Natsuzora::parse("{[ a ]}").unwrap();
+        let bundle = tmpl
+            .capture_repro(json!({"a": [1]}), &RenderOptions::new())
+            .unwrap();
+        let error = bundle.result.clone().unwrap_err();
+        let bundle = ReproBundle::from_json(bundle.to_json()).unwrap();
+        assert_eq!(Natsuzora::replay(&bundle).unwrap_err().to_string(), error);
+
+        assert!(ReproBundle::from_json(json!({"schema_version": 99})).is_err());
+    }
+
+    #[test]
+    fn test_template_integer_bounds_are_captured() {
+        let tmpl = Natsuzora::parse("{[ id ]}")
+            .unwrap()
+            .with_integer_bounds(IntegerRange::Full, IntegerPolicy::Error);
+        let bundle = tmpl
+            .capture_repro(json!({"id": i64::MAX}), &RenderOptions::new())
+            .unwrap();
+        assert_eq!(bundle.result.as_deref(), Ok("9223372036854775807"));
+        let bundle = ReproBundle::from_json(bundle.to_json()).unwrap();
+        assert_eq!(Natsuzora::replay(&bundle).unwrap(), "9223372036854775807");
+    }
+}
@@ -0,0 +1,459 @@
+//! Conversion of `serde::Serialize` data straight into [`Value`].
+//!
+//! Builds the same value as going through `serde_json::to_value` and
+//! [`Value::from_json_with`], without the intermediate JSON tree: structs
+//! and maps become objects, sequences and tuples arrays, `None` and unit
+//! null, unit variants their name, and other variants an object keyed by
+//! the variant name. Non-finite floats become null, as in `serde_json`.
+
+use crate::error::{NatsuzoraError, Result};
+use crate::value::{IndexMap, IntegerPolicy, IntegerRange, Value};
+use serde::ser::{self, Serialize};
+use std::fmt;
+
+/// Convert `data`, handling integers outside `range` per `policy`
+pub(crate) fn to_value<T: Serialize + ?Sized>(
+    data: &T,
+    range: IntegerRange,
+    policy: IntegerPolicy,
+) -> Result<Value> {
+    data.serialize(Serializer { range, policy })
+}
+
+impl ser::Error for NatsuzoraError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        NatsuzoraError::TypeError {
+            message: msg.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Serializer {
+    range: IntegerRange,
+    policy: IntegerPolicy,
+}
+
+impl Serializer {
+    fn integer(self, wide: i128) -> Result<Value> {
+        Value::from_integer(wide, &wide, self.range, self.policy)
+    }
+
+    fn number(self, f: f64) -> Result<Value> {
+        if !f.is_finite() {
+            return Ok(Value::Null);
+        }
+        Value::from_number(f, &f, self.range, self.policy)
+    }
+
+    /// Object keys are strings; numbers and booleans are written out, as in JSON
+    fn key<T: Serialize + ?Sized>(key: &T) -> Result<String> {
+        let exact = Serializer {
+            range: IntegerRange::Full,
+            policy: IntegerPolicy::Stringify,
+        };
+        match key.serialize(exact)? {
+            Value::String(s) => Ok(s),
+            Value::Integer(n) => Ok(n.to_string()),
+            Value::Bool(b) => Ok(b.to_string()),
+            other => Err(NatsuzoraError::TypeError {
+                message: format!("Object key must be a string, got {}", other.type_name()),
+            }),
+        }
+    }
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Value;
+    type Error = NatsuzoraError;
+    type SerializeSeq = SeqBuilder;
+    type SerializeTuple = SeqBuilder;
+    type SerializeTupleStruct = SeqBuilder;
+    type SerializeTupleVariant = SeqBuilder;
+    type SerializeMap = MapBuilder;
+    type SerializeStruct = MapBuilder;
+    type SerializeStructVariant = MapBuilder;
+
+    fn serialize_bool(self, v: bool) -> Result<Value> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value> {
+        self.integer(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value> {
+        self.integer(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value> {
+        self.integer(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value> {
+        self.integer(v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value> {
+        self.integer(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value> {
+        self.integer(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value> {
+        self.integer(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value> {
+        self.integer(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value> {
+        self.integer(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value> {
+        // Beyond i128 is out of any range; saturate to the largest i128
+        self.integer(i128::try_from(v).unwrap_or(i128::MAX))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value> {
+        self.number(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value> {
+        self.number(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value> {
+        Ok(Value::Array(
+            v.iter().map(|b| Value::Integer((*b).into())).collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Value> {
+        Ok(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value> {
+        let value = value.serialize(self)?;
+        Ok(Value::Object(IndexMap::from([(
+            variant.to_string(),
+            value,
+        )])))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqBuilder> {
+        Ok(SeqBuilder {
+            serializer: self,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqBuilder> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqBuilder> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqBuilder> {
+        let mut builder = self.serialize_seq(Some(len))?;
+        builder.variant = Some(variant);
+        Ok(builder)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapBuilder> {
+        Ok(MapBuilder {
+            serializer: self,
+            map: IndexMap::with_capacity(len.unwrap_or(0)),
+            key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapBuilder> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<MapBuilder> {
+        let mut builder = self.serialize_map(Some(len))?;
+        builder.variant = Some(variant);
+        Ok(builder)
+    }
+}
+
+/// Wrap `value` in an object keyed by `variant`, if any
+fn in_variant(variant: Option<&'static str>, value: Value) -> Value {
+    match variant {
+        Some(variant) => Value::Object(IndexMap::from([(variant.to_string(), value)])),
+        None => value,
+    }
+}
+
+struct SeqBuilder {
+    serializer: Serializer,
+    items: Vec<Value>,
+    variant: Option<&'static str>,
+}
+
+impl SeqBuilder {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.items.push(value.serialize(self.serializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value> {
+        Ok(in_variant(self.variant, Value::Array(self.items)))
+    }
+}
+
+impl ser::SerializeSeq for SeqBuilder {
+    type Ok = Value;
+    type Error = NatsuzoraError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqBuilder {
+    type Ok = Value;
+    type Error = NatsuzoraError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqBuilder {
+    type Ok = Value;
+    type Error = NatsuzoraError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqBuilder {
+    type Ok = Value;
+    type Error = NatsuzoraError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+struct MapBuilder {
+    serializer: Serializer,
+    map: IndexMap<String, Value>,
+    /// Key of the entry whose value comes next
+    key: Option<String>,
+    variant: Option<&'static str>,
+}
+
+impl MapBuilder {
+    fn insert<T: Serialize + ?Sized>(&mut self, key: String, value: &T) -> Result<()> {
+        let value = value.serialize(self.serializer)?;
+        self.map.insert(key, value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value> {
+        Ok(in_variant(self.variant, Value::Object(self.map)))
+    }
+}
+
+impl ser::SerializeMap for MapBuilder {
+    type Ok = Value;
+    type Error = NatsuzoraError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(Serializer::key(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| <NatsuzoraError as ser::Error>::custom("map value without a key"))?;
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for MapBuilder {
+    type Ok = Value;
+    type Error = NatsuzoraError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.insert(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for MapBuilder {
+    type Ok = Value;
+    type Error = NatsuzoraError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.insert(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Value> {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    enum Shape {
+        Dot,
+        Circle(u8),
+        Line(i32, i32),
+        Rect { w: u32, h: u32 },
+    }
+
+    #[derive(Serialize)]
+    struct Page<'a> {
+        title: &'a str,
+        score: f64,
+        tags: Vec<&'a str>,
+        author: Option<&'a str>,
+        counts: std::collections::BTreeMap<u16, bool>,
+        shapes: [Shape; 4],
+        id: (u64, char),
+    }
+
+    #[test]
+    fn test_matches_the_json_route() {
+        let page = Page {
+            title: "Hi",
+            score: 2.5,
+            tags: vec!["a", "b"],
+            author: None,
+            counts: [(7, true)].into(),
+            shapes: [
+                Shape::Dot,
+                Shape::Circle(3),
+                Shape::Line(1, -1),
+                Shape::Rect { w: 2, h: 3 },
+            ],
+            id: (1 << 60, 'x'),
+        };
+        for policy in [IntegerPolicy::Saturate, IntegerPolicy::Stringify] {
+            let json = serde_json::to_value(&page).unwrap();
+            assert_eq!(
+                to_value(&page, IntegerRange::Safe, policy).unwrap(),
+                Value::from_json_with(json, IntegerRange::Safe, policy).unwrap()
+            );
+        }
+        assert!(to_value(&page, IntegerRange::Safe, IntegerPolicy::Error).is_err());
+        assert_eq!(
+            to_value(&f64::NAN, IntegerRange::Safe, IntegerPolicy::Error).unwrap(),
+            Value::from_json(json!(null)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_keys_must_be_scalars() {
+        let map = std::collections::BTreeMap::from([((1, 2), "pair")]);
+        let error = to_value(&map, IntegerRange::Safe, IntegerPolicy::Error).unwrap_err();
+        assert!(error.to_string().contains("Object key must be a string"));
+    }
+}
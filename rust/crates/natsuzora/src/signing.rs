@@ -0,0 +1,134 @@
+//! Templates signed by a trusted build pipeline (feature `signing`).
+//!
+//! [`SignedSource`] wraps another [`TemplateSource`] and only hands out
+//! files carrying a valid ed25519 signature, so a render service refuses
+//! partials that were not produced by the pipeline holding the signing key.
+//! Each file holds a 64-byte signature followed by the template, as written
+//! by [`sign`]. The signature also covers the file's name below its include
+//! root, so a signed partial copied over another one is rejected too.
+//! Failures surface as [`NatsuzoraError::SignatureError`]:
+//!
+//! ```rust,ignore
+//! use natsuzora::signing::SignedSource;
+//! use natsuzora::{FsSource, Natsuzora};
+//!
+//! let public_key: [u8; 32] = load_pipeline_public_key();
+//! let tmpl = Natsuzora::parse_with_includes(source, "templates/shared")?
+//!     .with_template_source(SignedSource::new(FsSource, &public_key)?);
+//! ```
+
+use crate::error::{NatsuzoraError, Result};
+use crate::template_loader::TemplateSource;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey, SIGNATURE_LENGTH};
+use std::path::Path;
+
+/// A [`TemplateSource`] verifying the files read by `S`.
+pub struct SignedSource<S> {
+    inner: S,
+    key: VerifyingKey,
+}
+
+impl<S: TemplateSource> SignedSource<S> {
+    /// Fails if `public_key` is not a valid ed25519 public key.
+    pub fn new(inner: S, public_key: &[u8; 32]) -> Result<Self> {
+        let key =
+            VerifyingKey::from_bytes(public_key).map_err(|_| NatsuzoraError::SignatureError {
+                message: "Invalid ed25519 public key".to_string(),
+            })?;
+        Ok(Self { inner, key })
+    }
+}
+
+impl<S: TemplateSource> TemplateSource for SignedSource<S> {
+    fn read(&self, path: &Path, name: &str) -> Result<Vec<u8>> {
+        let data = self.inner.read(path, name)?;
+        let failed = || NatsuzoraError::SignatureError {
+            message: format!("Template signature verification failed: {}", path.display()),
+        };
+        if data.len() < SIGNATURE_LENGTH {
+            return Err(failed());
+        }
+        let (signature, template) = data.split_at(SIGNATURE_LENGTH);
+        let signature = Signature::from_slice(signature).map_err(|_| failed())?;
+        self.key
+            .verify_strict(&signed_message(name, template), &signature)
+            .map_err(|_| failed())?;
+        Ok(template.to_vec())
+    }
+}
+
+/// Public key matching a 32-byte ed25519 secret key
+pub fn public_key(secret_key: &[u8; 32]) -> [u8; 32] {
+    SigningKey::from_bytes(secret_key)
+        .verifying_key()
+        .to_bytes()
+}
+
+/// Sign a template for [`SignedSource`], to be stored as `name` below the
+/// include root, e.g. `components/_card.ntzr`
+pub fn sign(secret_key: &[u8; 32], name: &str, template: &[u8]) -> Vec<u8> {
+    let signature = SigningKey::from_bytes(secret_key).sign(&signed_message(name, template));
+    let mut data = signature.to_bytes().to_vec();
+    data.extend_from_slice(template);
+    data
+}
+
+/// What is signed: `name`, a NUL byte, then the template
+fn signed_message(name: &str, template: &[u8]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(name.len() + 1 + template.len());
+    message.extend_from_slice(name.as_bytes());
+    message.push(0);
+    message.extend_from_slice(template);
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template_loader::{FsSource, TemplateLoader};
+    use std::fs;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_only_signed_partials_load() {
+        let secret = [3u8; 32];
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("_card.ntzr"),
+            sign(&secret, "_card.ntzr", "<b>{[ title ]}</b>".as_bytes()),
+        )
+        .unwrap();
+        let mut tampered = sign(&secret, "_bad.ntzr", b"safe");
+        *tampered.last_mut().unwrap() = b'!';
+        fs::write(dir.path().join("_bad.ntzr"), tampered).unwrap();
+        fs::write(dir.path().join("_plain.ntzr"), "plain").unwrap();
+        // Validly signed, but for another file
+        fs::write(
+            dir.path().join("_swapped.ntzr"),
+            sign(&secret, "_card.ntzr", b"card"),
+        )
+        .unwrap();
+
+        let source = SignedSource::new(FsSource, &public_key(&secret)).unwrap();
+        let mut loader = TemplateLoader::new(dir.path())
+            .unwrap()
+            .with_source(Arc::new(source));
+        assert!(loader.load("/card").is_ok());
+        for name in ["/bad", "/plain", "/swapped"] {
+            let err = loader.load(name).unwrap_err();
+            assert!(
+                matches!(err, NatsuzoraError::SignatureError { .. }),
+                "{err}"
+            );
+        }
+
+        let other = SignedSource::new(FsSource, &public_key(&[4u8; 32])).unwrap();
+        let mut untrusted = TemplateLoader::new(dir.path())
+            .unwrap()
+            .with_source(Arc::new(other));
+        assert!(matches!(
+            untrusted.load("/card"),
+            Err(NatsuzoraError::SignatureError { .. })
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+//! Data-free wireframe rendering.
+//!
+//! No data is involved. Every variable is rendered as a stable placeholder
+//! (`⟨user.name⟩`), every branch of a conditional is rendered once in order
+//! between visible markers, and each loop body and include is rendered once:
+//!
+//! ```text
+//! {[#if user]}Hi {[ user.name ]}{[#else]}Sign in{[/if]}
+//! → ⟦if user⟧Hi ⟨user.name⟩⟦else⟧Sign in⟦/if⟧
+//! ```
+
+use crate::error::Result;
+use crate::template_loader::TemplateLoader;
+use natsuzora_ast::{AstNode, Path, Template};
+
+pub(crate) struct Skeleton<'a> {
+    loader: Option<&'a mut TemplateLoader>,
+    output: String,
+    /// Rendered bodies of the enclosing block includes, for `{[!yield]}`
+    yields: Vec<String>,
+}
+
+impl<'a> Skeleton<'a> {
+    pub(crate) fn new(loader: Option<&'a mut TemplateLoader>) -> Self {
+        Self {
+            loader,
+            output: String::new(),
+            yields: Vec::new(),
+        }
+    }
+
+    pub(crate) fn render(mut self, template: &Template) -> Result<String> {
+        self.render_nodes(template.nodes())?;
+        Ok(self.output)
+    }
+
+    fn render_nodes(&mut self, nodes: &[AstNode]) -> Result<()> {
+        for node in nodes {
+            match node {
+                AstNode::Text(n) => self.output.push_str(&n.content),
+                AstNode::Variable(n) => self.placeholder(&n.path.as_str()),
+                AstNode::Unsecure(n) => self.placeholder(&format!("!unsecure {}", n.path.as_str())),
+                AstNode::Translate(n) => self.placeholder(&format!("@t {}", n.key)),
+                AstNode::Attr(n) => {
+                    self.placeholder(&format!("@attr {} {}", n.name, n.path.as_str()))
+                }
+                AstNode::If(n) => {
+                    self.marker(&format!("if {}", n.condition));
+                    self.render_nodes(&n.then_branch)?;
+                    for branch in &n.elseif_branches {
+                        self.marker(&format!("elseif {}", branch.condition));
+                        self.render_nodes(&branch.body)?;
+                    }
+                    if let Some(else_branch) = &n.else_branch {
+                        self.marker("else");
+                        self.render_nodes(else_branch)?;
+                    }
+                    self.marker("/if");
+                }
+                AstNode::Unless(n) => {
+                    self.marker(&format!("unless {}", n.condition));
+                    self.render_nodes(&n.body)?;
+                    if let Some(else_branch) = &n.else_branch {
+                        self.marker("else");
+                        self.render_nodes(else_branch)?;
+                    }
+                    self.marker("/unless");
+                }
+                AstNode::Each(n) => {
+                    let mut marker = format!("each {} as {}", n.collection, n.item_ident);
+                    if let Some(index) = &n.index_ident {
+                        marker.push_str(&format!(", {index}"));
+                    }
+                    if let Some(meta) = &n.meta_ident {
+                        marker.push_str(&format!(" with {meta}"));
+                    }
+                    if let Some(limit) = &n.limit {
+                        marker.push_str(&format!(" limit={limit}"));
+                    }
+                    if let Some(offset) = &n.offset {
+                        marker.push_str(&format!(" offset={offset}"));
+                    }
+                    self.marker(&marker);
+                    self.render_nodes(&n.body)?;
+                    if let Some(else_branch) = &n.else_branch {
+                        self.marker("else");
+                        self.render_nodes(else_branch)?;
+                    }
+                    self.marker("/each");
+                }
+                AstNode::Cache(n) => self.render_nodes(&n.body)?,
+                AstNode::Block(n) => {
+                    self.marker(&format!("block {}", n.name));
+                    self.render_nodes(&n.body)?;
+                    self.marker("/block");
+                }
+                AstNode::Extends(n) => self.marker(&format!("extends {}", n.name)),
+                AstNode::Include(n) => {
+                    let body = match &n.body {
+                        Some(body) => {
+                            let outer = std::mem::take(&mut self.output);
+                            let result = self.render_nodes(body);
+                            let rendered = std::mem::replace(&mut self.output, outer);
+                            result?;
+                            rendered
+                        }
+                        None => String::new(),
+                    };
+                    // A dynamic include's partial depends on the data, so it stays a marker
+                    let loader = self.loader.as_mut().filter(|_| n.source.is_none());
+                    let Some(loader) = loader else {
+                        let name = n
+                            .source
+                            .as_ref()
+                            .map_or_else(|| n.name.clone(), Path::as_str);
+                        self.marker(&format!("include {name}"));
+                        self.output.push_str(&body);
+                        self.marker("/include");
+                        continue;
+                    };
+                    let partial = loader.load(&n.name)?;
+                    loader.push_include(&n.name);
+                    self.yields.push(body);
+                    self.marker(&format!("include {}", n.name));
+                    let result = self.render_nodes(partial.nodes());
+                    self.marker("/include");
+                    self.yields.pop();
+                    if let Some(loader) = self.loader.as_mut() {
+                        loader.pop_include();
+                    }
+                    result?;
+                }
+                AstNode::Yield(_) => {
+                    self.output
+                        .push_str(self.yields.last().map_or("", String::as_str));
+                }
+                other => self.marker(other.kind()),
+            }
+        }
+        Ok(())
+    }
+
+    fn placeholder(&mut self, label: &str) {
+        self.output.push('⟨');
+        self.output.push_str(label);
+        self.output.push('⟩');
+    }
+
+    fn marker(&mut self, label: &str) {
+        self.output.push('⟦');
+        self.output.push_str(label);
+        self.output.push('⟧');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Natsuzora;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_skeleton_marks_every_branch_once() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("_card.ntzr"), "<b>{[ title ]}</b>").unwrap();
+        let tmpl = Natsuzora::parse_with_includes(
+            "{[#each items as item]}{[!include /card title=item.name ]}{[/each]}\
+             {[#if a]}A{[#elseif b]}B{[/if]}{[#unless c]}C{[/unless]}\
+             {[#cache key=id ]}{[!unsecure html ]}{[ x? ]}{[/cache]}",
+            dir.path(),
+        )
+        .unwrap();
+        assert_eq!(
+            tmpl.render_skeleton().unwrap(),
+            "⟦each items as item⟧⟦include /card⟧<b>⟨title⟩</b>⟦/include⟧⟦/each⟧\
+             ⟦if a⟧A⟦elseif b⟧B⟦/if⟧⟦unless c⟧C⟦/unless⟧\
+             ⟨!unsecure html⟩⟨x⟩"
+        );
+
+        let tmpl = Natsuzora::parse("{[!include /card ]}").unwrap();
+        assert_eq!(tmpl.render_skeleton().unwrap(), "⟦include /card⟧⟦/include⟧");
+    }
+}
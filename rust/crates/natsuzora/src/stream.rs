@@ -0,0 +1,440 @@
+//! Bounded-memory rendering of very large templates.
+//!
+//! [`StreamRenderer`] reads the template from a [`BufRead`] and writes the
+//! output to a [`Write`] as it goes, instead of holding the whole source,
+//! its AST and the output at once. The source is cut into chunks at line
+//! breaks that lie outside every block, tag and comment; each chunk is
+//! parsed and rendered on its own against one shared data context. Memory
+//! is bounded by the chunk size plus the largest top-level block.
+//!
+//! A line that starts (after indentation) with a tag never begins a chunk,
+//! so whitespace control and [`ParseOptions`] trimming behave exactly as in
+//! a whole-template parse. Includes and layouts are not supported: there is
+//! no loader, so `{[!include]}` and `{[!extends]}` fail the render.
+//!
+//! ```rust,ignore
+//! let input = BufReader::new(File::open("report.ntzr")?);
+//! let output = BufWriter::new(File::create("report.html")?);
+//! natsuzora::render_stream(input, data, output)?;
+//! ```
+
+use crate::context::Context;
+use crate::error::{Location, NatsuzoraError, Result};
+use crate::filters::CustomFilters;
+use crate::renderer::Renderer;
+use crate::value::Value;
+use natsuzora_ast::{is_ident_continue, Delimiters, ParseError, ParseOptions};
+use std::io::{BufRead, Write};
+
+/// Default number of source bytes gathered before a chunk may be cut
+pub const DEFAULT_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Block keywords that open a region closed by `{[/...]}`.
+const OPENING_KEYWORDS: &[&str] = &["if", "unless", "each", "cache", "block", "include", "raw"];
+
+/// Renders a template read incrementally, see the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct StreamRenderer {
+    parse_options: ParseOptions,
+    chunk_bytes: usize,
+    filters: CustomFilters,
+}
+
+impl Default for StreamRenderer {
+    fn default() -> Self {
+        Self {
+            parse_options: ParseOptions::default(),
+            chunk_bytes: DEFAULT_CHUNK_BYTES,
+            filters: CustomFilters::default(),
+        }
+    }
+}
+
+impl StreamRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+
+    /// Cut a chunk once it holds at least `bytes` bytes of source
+    pub fn with_chunk_bytes(mut self, bytes: usize) -> Self {
+        self.chunk_bytes = bytes;
+        self
+    }
+
+    /// Accept and apply the host-defined `filters`
+    pub fn with_filters(mut self, filters: CustomFilters) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Render the template read from `reader` with `data` into `writer`
+    ///
+    /// Output already written stays written when a later chunk fails. Error
+    /// locations refer to the whole template.
+    pub fn render<R: BufRead, W: Write>(
+        &self,
+        reader: R,
+        data: serde_json::Value,
+        mut writer: W,
+    ) -> Result<()> {
+        let mut context = Context::new(Value::from_json(data)?)?;
+        let mut renderer = Renderer::new(None).with_filters(&self.filters);
+        let mut parse_options = self.parse_options.clone();
+        parse_options.filters.extend(self.filters.names());
+        for_each_chunk(
+            reader,
+            &parse_options.delimiters,
+            self.chunk_bytes,
+            |chunk, start| {
+                let template = natsuzora_ast::parse_with_options(chunk, &parse_options)
+                    .map_err(|error| parse_error(error, start))?;
+                let output = renderer
+                    .render_part(&template, &mut context)
+                    .map_err(|error| shift_error(error, start))?;
+                writer.write_all(output.as_bytes())?;
+                Ok(())
+            },
+        )?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Split the source read from `reader` into chunks, passing each with the
+/// location of its first byte
+fn for_each_chunk<R: BufRead>(
+    mut reader: R,
+    delimiters: &Delimiters,
+    chunk_bytes: usize,
+    mut f: impl FnMut(&str, Location) -> Result<()>,
+) -> Result<()> {
+    let mut scanner = Scanner::new(delimiters);
+    let mut chunk = String::new();
+    let mut start = Location::new(1, 1, 0);
+    let mut lines = 0;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        if chunk.len() >= chunk_bytes && scanner.can_cut_before(&line) {
+            f(&chunk, start)?;
+            start = Location::new(start.line + lines, 1, start.byte_offset + chunk.len());
+            lines = 0;
+            chunk.clear();
+        }
+        scanner.scan(line.as_bytes());
+        chunk.push_str(&line);
+        lines += 1;
+    }
+    f(&chunk, start)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Text,
+    Tag,
+    Comment,
+    Raw,
+}
+
+/// Tracks just enough of the lexer's state to tell where a chunk may end.
+///
+/// When unsure it keeps the chunk going: a missed cut only costs memory.
+struct Scanner<'a> {
+    open: &'a [u8],
+    close: &'a [u8],
+    escape: Vec<u8>,
+    state: State,
+    /// Open blocks, including a raw block
+    depth: usize,
+    /// Bytes of the current tag after its open delimiter
+    tag: Vec<u8>,
+    in_string: bool,
+    escaped: bool,
+    /// A tag closed with `~` and only whitespace has followed
+    trimming: bool,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(delimiters: &'a Delimiters) -> Self {
+        let (open, close) = (delimiters.open().as_bytes(), delimiters.close().as_bytes());
+        Self {
+            open,
+            close,
+            escape: [open, b"{", close].concat(),
+            state: State::Text,
+            depth: 0,
+            tag: Vec::new(),
+            in_string: false,
+            escaped: false,
+            trimming: false,
+        }
+    }
+
+    /// Whether a chunk may end before `line`, given everything scanned so far
+    ///
+    /// A `~` trim marker before the cut must already have met text, and the
+    /// line must hold text before any tag, which a `~` after the cut stops at.
+    fn can_cut_before(&self, line: &str) -> bool {
+        let content = line.trim_start_matches([' ', '\t']);
+        self.state == State::Text
+            && self.depth == 0
+            && !self.trimming
+            && !content.trim_start().is_empty()
+            && !content.as_bytes().starts_with(self.open)
+    }
+
+    fn scan(&mut self, line: &[u8]) {
+        let mut pos = 0;
+        while pos < line.len() {
+            let rest = &line[pos..];
+            match self.state {
+                State::Text if rest.starts_with(&self.escape) => {
+                    self.trimming = false;
+                    pos += self.escape.len();
+                }
+                State::Text if rest.starts_with(self.open) => {
+                    pos += self.open.len();
+                    let after = &line[pos..];
+                    let after = after.strip_prefix(b"-").unwrap_or(after);
+                    let after = after.strip_prefix(b"~").unwrap_or(after);
+                    self.state = if after.starts_with(b"%") {
+                        State::Comment
+                    } else {
+                        State::Tag
+                    };
+                    self.tag.clear();
+                }
+                State::Text => {
+                    self.trimming &= rest[0].is_ascii_whitespace();
+                    pos += 1;
+                }
+                State::Comment if rest.starts_with(self.close) => {
+                    self.trimming = pos > 0 && line[pos - 1] == b'~';
+                    pos += self.close.len();
+                    self.state = State::Text;
+                }
+                State::Comment => pos += 1,
+                State::Raw if self.raw_close(rest) => {
+                    pos += self.open.len();
+                    self.state = State::Tag;
+                    self.tag.clear();
+                }
+                State::Raw => pos += 1,
+                State::Tag if self.in_string => {
+                    match rest[0] {
+                        _ if self.escaped => self.escaped = false,
+                        b'\\' => self.escaped = true,
+                        b'"' => self.in_string = false,
+                        _ => {}
+                    }
+                    self.tag.push(rest[0]);
+                    pos += 1;
+                }
+                State::Tag if rest.starts_with(self.close) => {
+                    pos += self.close.len();
+                    self.close_tag();
+                }
+                State::Tag => {
+                    self.in_string = rest[0] == b'"';
+                    self.tag.push(rest[0]);
+                    pos += 1;
+                }
+            }
+        }
+    }
+
+    fn close_tag(&mut self) {
+        self.state = State::Text;
+        self.trimming = self.tag.last() == Some(&b'~');
+        let tag = self.tag.strip_prefix(b"-").unwrap_or(&self.tag);
+        let tag = tag.strip_prefix(b"~").unwrap_or(tag);
+        match tag.split_first() {
+            Some((b'#', rest)) => {
+                let rest = std::str::from_utf8(skip_whitespace(rest)).unwrap_or_default();
+                let len = rest.find(|c| !is_ident_continue(c)).unwrap_or(rest.len());
+                let keyword = &rest[..len];
+                if OPENING_KEYWORDS.contains(&keyword) {
+                    self.depth += 1;
+                }
+                if keyword == "raw" {
+                    self.state = State::Raw;
+                }
+            }
+            Some((b'/', _)) => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    /// `{[` `-`? `~`? `/` ws? `raw` ws? `-`? `~`? `]}`, as the lexer ends a
+    /// raw body
+    fn raw_close(&self, rest: &[u8]) -> bool {
+        let Some(rest) = rest.strip_prefix(self.open) else {
+            return false;
+        };
+        let rest = rest.strip_prefix(b"-").unwrap_or(rest);
+        let rest = rest.strip_prefix(b"~").unwrap_or(rest);
+        let Some(rest) = rest.strip_prefix(b"/") else {
+            return false;
+        };
+        let Some(rest) = skip_whitespace(rest).strip_prefix(b"raw") else {
+            return false;
+        };
+        if std::str::from_utf8(rest)
+            .ok()
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(is_ident_continue)
+        {
+            return false;
+        }
+        let rest = skip_whitespace(rest);
+        let rest = rest.strip_prefix(b"-").unwrap_or(rest);
+        rest.strip_prefix(b"~")
+            .unwrap_or(rest)
+            .starts_with(self.close)
+    }
+}
+
+fn skip_whitespace(bytes: &[u8]) -> &[u8] {
+    let len = bytes
+        .iter()
+        .take_while(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+        .count();
+    &bytes[len..]
+}
+
+/// Report a chunk's parse error at its line in the whole template
+fn parse_error(mut error: ParseError, start: Location) -> NatsuzoraError {
+    match &mut error {
+        ParseError::SyntaxError { line, .. }
+        | ParseError::UnexpectedToken { line, .. }
+        | ParseError::ReservedWord { line, .. }
+        | ParseError::InvalidIdentifier { line, .. }
+        | ParseError::UnclosedComment { line, .. }
+        | ParseError::TooManyNodes { line, .. }
+        | ParseError::NestedTooDeep { line, .. } => *line += start.line - 1,
+        _ => {}
+    }
+    NatsuzoraError::ParseError {
+        message: error.to_string(),
+        location: Location::default(),
+    }
+}
+
+/// Move a render error's location from the chunk to the whole template
+fn shift_error(mut error: NatsuzoraError, start: Location) -> NatsuzoraError {
+    if let NatsuzoraError::UndefinedVariable { location, .. } = &mut error {
+        location.line += start.line - 1;
+        location.byte_offset += start.byte_offset;
+    }
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn chunks(source: &str, chunk_bytes: usize) -> Vec<String> {
+        let mut chunks = Vec::new();
+        for_each_chunk(
+            source.as_bytes(),
+            &Delimiters::default(),
+            chunk_bytes,
+            |chunk, _| {
+                chunks.push(chunk.to_string());
+                Ok(())
+            },
+        )
+        .unwrap();
+        chunks
+    }
+
+    fn render(source: &str, options: ParseOptions, data: serde_json::Value) -> String {
+        let mut output = Vec::new();
+        StreamRenderer::new()
+            .with_parse_options(options)
+            .with_chunk_bytes(1)
+            .render(source.as_bytes(), data, &mut output)
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_chunks_end_outside_blocks_tags_and_comments() {
+        let source = "a\n{[#each xs as x]}\n{[ x ]}\n{[/each]}\nb\n{[% note\n]}\n  {[-ok ]}\nc\n\
+                      {[#raw]}\n{[/each]}\n{[/raw]}\nd {[#if s == \"]}\n\"]}\n{[/if]}\ne\n";
+        assert_eq!(
+            chunks(source, 1),
+            [
+                "a\n{[#each xs as x]}\n{[ x ]}\n{[/each]}\n",
+                "b\n{[% note\n]}\n  {[-ok ]}\n",
+                "c\n{[#raw]}\n{[/each]}\n{[/raw]}\n",
+                "d {[#if s == \"]}\n\"]}\n{[/if]}\n",
+                "e\n",
+            ]
+        );
+        assert_eq!(chunks(source, 1024), [source]);
+    }
+
+    #[test]
+    fn test_chunks_keep_tilde_trims_whole() {
+        let source = "a\n\n {[~ x ~]}\n\n\t b\n{[~#if x]}\n{[/if~]}\n  c\n";
+        assert_eq!(
+            chunks(source, 1),
+            ["a\n\n {[~ x ~]}\n\n\t b\n{[~#if x]}\n{[/if~]}\n  c\n"]
+        );
+        assert_eq!(
+            chunks("a\n{[ x ~]}\nb\nc\n", 1),
+            ["a\n{[ x ~]}\nb\n", "c\n"]
+        );
+    }
+
+    #[test]
+    fn test_stream_matches_whole_render() {
+        let source = "<ul>\n{[#each items as item-]}\n  <li>{[ item ]}</li>\n{[-/each]}\n</ul>\n\
+                      text {[ title ]}\n  \n{[#if title]}\n    yes\n{[/if]}\nend\n";
+        let data = json!({"items": ["a", "b"], "title": "T"});
+        for options in [
+            ParseOptions::default(),
+            ParseOptions {
+                trim_blocks: true,
+                lstrip_blocks: true,
+                ..ParseOptions::default()
+            },
+        ] {
+            let whole = crate::Natsuzora::parse_with_options(source, options.clone())
+                .unwrap()
+                .render(data.clone())
+                .unwrap();
+            assert_eq!(render(source, options, data.clone()), whole);
+        }
+    }
+
+    #[test]
+    fn test_errors_point_into_the_whole_template() {
+        let mut output = Vec::new();
+        let err = StreamRenderer::new()
+            .with_chunk_bytes(1)
+            .render("a\nb\n{[ missing ]}\n".as_bytes(), json!({}), &mut output)
+            .unwrap_err();
+        let NatsuzoraError::UndefinedVariable { location, .. } = err else {
+            panic!("expected undefined variable, got {err}");
+        };
+        assert_eq!((location.line, location.byte_offset), (3, 7));
+        assert_eq!(output, b"a\n");
+
+        let err = StreamRenderer::new()
+            .with_chunk_bytes(1)
+            .render("a\nb\n{[#if x]}\n".as_bytes(), json!({}), &mut output)
+            .unwrap_err();
+        assert!(err.to_string().contains("line 4"), "{err}");
+    }
+}
@@ -1,10 +1,12 @@
 //! Template loader for handling include directives.
 
 use crate::error::{NatsuzoraError, Result};
-use natsuzora_ast::{IncludeLoader, LoaderError, Template};
-use std::collections::HashMap;
+use crate::profile::Profiles;
+use natsuzora_ast::{IncludeLoader, LoaderError, ParseOptions, Template};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 struct IncludePathResolver {
     include_root: PathBuf,
@@ -77,6 +79,16 @@ impl IncludePathResolver {
     fn within_root(&self, path: &Path) -> bool {
         path == self.include_root || path.starts_with(&self.include_root)
     }
+
+    /// `path` below the include root, joined with `/` on every platform
+    fn relative_name(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(&self.include_root).unwrap_or(path);
+        relative
+            .iter()
+            .map(|segment| segment.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
 }
 
 fn split_existing_parent(path: &Path) -> (PathBuf, Vec<String>) {
@@ -103,11 +115,61 @@ fn split_existing_parent(path: &Path) -> (PathBuf, Vec<String>) {
     (cursor, missing_segments)
 }
 
+/// Inspects template sources read by a [`TemplateLoader`] before they are parsed.
+///
+/// Returning an error rejects the template: the include fails with that
+/// error and nothing is cached. Implemented for closures taking
+/// `(name, source)`.
+pub trait SourceHook: Send + Sync {
+    fn on_source_loaded(&self, name: &str, source: &str) -> Result<()>;
+}
+
+impl<F> SourceHook for F
+where
+    F: Fn(&str, &str) -> Result<()> + Send + Sync,
+{
+    fn on_source_loaded(&self, name: &str, source: &str) -> Result<()> {
+        self(name, source)
+    }
+}
+
+/// Reads template files for a [`TemplateLoader`].
+///
+/// The loader still resolves include names and checks paths against the
+/// include root on the file system; a source only decides how a file's
+/// bytes are obtained, e.g. by decrypting them. `name` is the file's path
+/// below the include root or theme directory holding it, joined with `/`
+/// (e.g. `components/_card.ntzr`), for sources that bind a file's contents
+/// to where it lives.
+pub trait TemplateSource: Send + Sync {
+    fn read(&self, path: &Path, name: &str) -> Result<Vec<u8>>;
+}
+
+/// Reads template files as they are on disk; the default source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FsSource;
+
+impl TemplateSource for FsSource {
+    fn read(&self, path: &Path, _name: &str) -> Result<Vec<u8>> {
+        Ok(fs::read(path)?)
+    }
+}
+
 /// Template loader for handling include directives
 pub struct TemplateLoader {
     path_resolver: IncludePathResolver,
     cache: HashMap<String, Template>,
     include_stack: Vec<String>,
+    source: Arc<dyn TemplateSource>,
+    source_hook: Option<Arc<dyn SourceHook>>,
+    profiles: Option<Arc<Profiles>>,
+    parse_options: ParseOptions,
+    /// Theme directories searched before the include root, in order
+    themes: Vec<IncludePathResolver>,
+    /// File suffixes tried before the plain file, most specific first
+    locales: Vec<String>,
+    /// Sources read so far by include name, when recording
+    recorded: Option<BTreeMap<String, String>>,
 }
 
 impl TemplateLoader {
@@ -117,9 +179,98 @@ impl TemplateLoader {
             path_resolver: IncludePathResolver::new(include_root)?,
             cache: HashMap::new(),
             include_stack: Vec::new(),
+            source: Arc::new(FsSource),
+            source_hook: None,
+            profiles: None,
+            parse_options: ParseOptions::default(),
+            themes: Vec::new(),
+            locales: Vec::new(),
+            recorded: None,
         })
     }
 
+    /// Parse loaded templates with `options`
+    pub fn with_parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+
+    /// Read template files through `source` instead of directly from disk
+    pub fn with_source(mut self, source: Arc<dyn TemplateSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Run `hook` on every template source before it is parsed and cached
+    pub fn with_source_hook(mut self, hook: Arc<dyn SourceHook>) -> Self {
+        self.source_hook = Some(hook);
+        self
+    }
+
+    /// Check every loaded template against the profile for its name
+    pub fn with_profiles(mut self, profiles: Arc<Profiles>) -> Self {
+        self.profiles = Some(profiles);
+        self
+    }
+
+    /// Resolve includes against theme directories before the include root
+    ///
+    /// `themes` are directories under the include root, most specific first
+    /// (e.g. `themes/acme`, `themes/default`). An include is loaded from the
+    /// first theme that has it, falling back to the include root itself.
+    pub fn with_themes<P: AsRef<Path>>(mut self, themes: &[P]) -> Result<Self> {
+        self.themes = themes
+            .iter()
+            .map(|theme| {
+                let dir = self.path_resolver.include_root.join(theme);
+                let resolver = IncludePathResolver::new(&dir)?;
+                if !self.path_resolver.within_root(&resolver.include_root) {
+                    return Err(NatsuzoraError::IncludeError {
+                        message: format!("Theme outside include root: {}", dir.display()),
+                    });
+                }
+                Ok(resolver)
+            })
+            .collect::<Result<_>>()?;
+        Ok(self)
+    }
+
+    /// Prefer locale-suffixed partials
+    ///
+    /// With locale `pt-BR`, `/greeting` loads `_greeting.pt-BR.ntzr`, then
+    /// `_greeting.pt.ntzr`, then `_greeting.ntzr`, in each theme and then in
+    /// the include root.
+    pub fn with_locale(mut self, locale: &str) -> Result<Self> {
+        let valid = !locale.is_empty()
+            && locale
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+        if !valid {
+            return Err(NatsuzoraError::IncludeError {
+                message: format!("Invalid locale: {locale:?}"),
+            });
+        }
+        self.locales = vec![locale.to_string()];
+        if let Some((language, _)) = locale.split_once(['-', '_']) {
+            self.locales.push(language.to_string());
+        }
+        Ok(self)
+    }
+
+    /// Keep the source of every template read, for [`Self::take_recorded`]
+    pub(crate) fn recording(mut self) -> Self {
+        self.recorded = Some(BTreeMap::new());
+        self
+    }
+
+    /// Sources read since recording started, by include name
+    pub(crate) fn take_recorded(&mut self) -> BTreeMap<String, String> {
+        self.recorded
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
     /// Load a partial template by name
     pub fn load(&mut self, name: &str) -> Result<Template> {
         validate_include_name(name)?;
@@ -149,9 +300,33 @@ impl TemplateLoader {
         self.include_stack.pop();
     }
 
-    fn load_and_parse(&self, name: &str) -> Result<Template> {
+    /// Path of `name` in the first theme that has it, else in the include
+    /// root; within each directory a localized file wins. Also returns the
+    /// path's name below that directory.
+    fn resolve_path(&self, name: &str) -> Result<(PathBuf, String)> {
+        for dir in self.themes.iter().chain([&self.path_resolver]) {
+            let path = dir.resolve_template_path(name);
+            dir.ensure_within_root(&path)?;
+            for locale in &self.locales {
+                let localized = path.with_extension(format!("{locale}.ntzr"));
+                dir.ensure_within_root(&localized)?;
+                if localized.is_file() {
+                    let relative = dir.relative_name(&localized);
+                    return Ok((localized, relative));
+                }
+            }
+            if path.is_file() {
+                let relative = dir.relative_name(&path);
+                return Ok((path, relative));
+            }
+        }
         let path = self.path_resolver.resolve_template_path(name);
-        self.path_resolver.ensure_within_root(&path)?;
+        let relative = self.path_resolver.relative_name(&path);
+        Ok((path, relative))
+    }
+
+    fn load_and_parse(&mut self, name: &str) -> Result<Template> {
+        let (path, relative) = self.resolve_path(name)?;
 
         if !path.is_file() {
             return Err(NatsuzoraError::IncludeError {
@@ -159,21 +334,103 @@ impl TemplateLoader {
             });
         }
 
-        let source = fs::read_to_string(&path)?;
+        let source = String::from_utf8(self.source.read(&path, &relative)?)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        if let Some(hook) = &self.source_hook {
+            hook.on_source_loaded(name, &source)?;
+        }
+        if let Some(recorded) = &mut self.recorded {
+            recorded.insert(name.to_string(), source.clone());
+        }
+        let template =
+            natsuzora_ast::parse_with_options(&source, &self.parse_options).map_err(|e| {
+                if e.is_limit() {
+                    NatsuzoraError::ParseLimit {
+                        message: format!("include '{name}': {e}"),
+                    }
+                } else {
+                    NatsuzoraError::IncludeError {
+                        message: format!("Failed to parse include '{name}': {e}"),
+                    }
+                }
+            })?;
+        if let Some(profiles) = &self.profiles {
+            profiles.check(name, &template)?;
+        }
+        Ok(template)
+    }
+}
+
+impl IncludeLoader for TemplateLoader {
+    fn load(&mut self, name: &str) -> std::result::Result<Template, LoaderError> {
+        TemplateLoader::load(self, name).map_err(|e| Box::new(e) as LoaderError)
+    }
+}
+
+/// [`IncludeLoader`] over a closure returning partial sources by include name
+///
+/// Sources are parsed on first use and cached, so the closure runs at most
+/// once per name.
+///
+/// # Example
+///
+/// ```rust
+/// use natsuzora::FnLoader;
+/// use serde_json::json;
+///
+/// let mut loader = FnLoader::new(|name: &str| match name {
+///     "/greeting" => Ok("Hello, {[ name ]}!".to_string()),
+///     _ => Err(natsuzora::NatsuzoraError::IncludeError {
+///         message: format!("unknown partial {name}"),
+///     }),
+/// });
+/// let template = natsuzora_ast::parse("{[!include /greeting name=user ]}").unwrap();
+/// let result = natsuzora::render_template(&template, json!({"user": "Alice"}), &mut loader);
+/// assert_eq!(result.unwrap(), "Hello, Alice!");
+/// ```
+pub struct FnLoader<F> {
+    source: F,
+    cache: HashMap<String, Template>,
+}
+
+impl<F> FnLoader<F>
+where
+    F: FnMut(&str) -> Result<String>,
+{
+    pub fn new(source: F) -> Self {
+        Self {
+            source,
+            cache: HashMap::new(),
+        }
+    }
+
+    fn load_and_parse(&mut self, name: &str) -> Result<Template> {
+        validate_include_name(name)?;
+        let source = (self.source)(name)?;
         natsuzora_ast::parse(&source).map_err(|e| NatsuzoraError::IncludeError {
             message: format!("Failed to parse include '{name}': {e}"),
         })
     }
 }
 
-impl IncludeLoader for TemplateLoader {
+impl<F> IncludeLoader for FnLoader<F>
+where
+    F: FnMut(&str) -> Result<String>,
+{
     fn load(&mut self, name: &str) -> std::result::Result<Template, LoaderError> {
-        TemplateLoader::load(self, name).map_err(|e| Box::new(e) as LoaderError)
+        if let Some(template) = self.cache.get(name) {
+            return Ok(template.clone());
+        }
+        let template = self
+            .load_and_parse(name)
+            .map_err(|e| Box::new(e) as LoaderError)?;
+        self.cache.insert(name.to_string(), template.clone());
+        Ok(template)
     }
 }
 
 /// Validate include name at runtime
-fn validate_include_name(name: &str) -> Result<()> {
+pub(crate) fn validate_include_name(name: &str) -> Result<()> {
     if !name.starts_with('/') {
         return Err(NatsuzoraError::IncludeError {
             message: format!("Include name must start with '/': {name}"),
@@ -209,6 +466,7 @@ fn is_valid_segment(segment: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::profile::Profile;
     use std::env;
 
     #[test]
@@ -226,6 +484,28 @@ mod tests {
         assert!(validate_include_name("/with-dash").is_err());
     }
 
+    #[test]
+    fn test_fn_loader_parses_once_per_name() {
+        let mut calls = 0;
+        let mut loader = FnLoader::new(|name: &str| {
+            calls += 1;
+            match name {
+                "/ok" => Ok("ok {[ x ]}".to_string()),
+                _ => Ok("{[#if]}".to_string()),
+            }
+        });
+
+        assert!(IncludeLoader::load(&mut loader, "/ok").is_ok());
+        assert!(IncludeLoader::load(&mut loader, "/ok").is_ok());
+        let err = IncludeLoader::load(&mut loader, "/broken").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Failed to parse include '/broken'"));
+        assert!(IncludeLoader::load(&mut loader, "bad").is_err());
+        drop(loader);
+        assert_eq!(calls, 2);
+    }
+
     #[test]
     fn test_circular_include_detection() {
         let mut loader = TemplateLoader {
@@ -234,9 +514,63 @@ mod tests {
             },
             cache: HashMap::new(),
             include_stack: vec!["/a".to_string()],
+            source: Arc::new(FsSource),
+            source_hook: None,
+            profiles: None,
+            parse_options: ParseOptions::default(),
+            themes: Vec::new(),
+            locales: Vec::new(),
+            recorded: None,
         };
 
         let result = loader.load("/a");
         assert!(matches!(result, Err(NatsuzoraError::IncludeError { .. })));
     }
+
+    #[test]
+    fn test_source_hook_rejects_before_caching() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("_secret.ntzr"), "AKIA0123").unwrap();
+        fs::write(dir.path().join("_plain.ntzr"), "ok").unwrap();
+        let hook = |name: &str, source: &str| {
+            if source.contains("AKIA") {
+                return Err(NatsuzoraError::IncludeError {
+                    message: format!("Secret found in {name}"),
+                });
+            }
+            Ok(())
+        };
+        let mut loader = TemplateLoader::new(dir.path())
+            .unwrap()
+            .with_source_hook(Arc::new(hook));
+
+        assert!(loader.load("/plain").is_ok());
+        let err = loader.load("/secret").unwrap_err();
+        assert_eq!(err.to_string(), "Include error: Secret found in /secret");
+        assert!(!loader.cache.contains_key("/secret"));
+    }
+
+    #[test]
+    fn test_profiles_checked_on_load() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("email")).unwrap();
+        fs::write(
+            dir.path().join("email/_welcome.ntzr"),
+            "Hi\n{[!unsecure body ]}",
+        )
+        .unwrap();
+        fs::write(dir.path().join("_page.ntzr"), "{[!unsecure body ]}").unwrap();
+        let profiles = Profiles::new().with("/email", Profile::new().deny_unsecure());
+        let mut loader = TemplateLoader::new(dir.path())
+            .unwrap()
+            .with_profiles(Arc::new(profiles));
+
+        assert!(loader.load("/page").is_ok());
+        let err = loader.load("/email/welcome").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Profile violation in /email/welcome at line 2, column 3: unsecure output is not allowed"
+        );
+        assert!(!loader.cache.contains_key("/email/welcome"));
+    }
 }
@@ -0,0 +1,249 @@
+//! Message catalogs for `{[@t key name=value ]}` tags.
+//!
+//! [`Translations`] holds, per locale, message keys and their text. A
+//! message is plain text with `{name}` placeholders, filled in from the tag
+//! arguments; the result is HTML-escaped like variable output. A render with
+//! locale `pt-BR` looks a key up in `pt-BR`, then `pt`, then the fallback
+//! locale.
+
+use crate::audit;
+use crate::error::{Location, NatsuzoraError, Result};
+use natsuzora_ast::{is_ident_continue, is_ident_start};
+use std::collections::HashMap;
+
+/// Per-locale message catalog.
+#[derive(Debug, Clone, Default)]
+pub struct Translations {
+    locales: HashMap<String, HashMap<String, String>>,
+    fallback: Option<String>,
+}
+
+impl Translations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the message for `key` in `locale`, replacing an existing one
+    pub fn with_message(
+        mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.locales
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), message.into());
+        self
+    }
+
+    /// Look keys missing from the render locale up in `locale`
+    pub fn with_fallback_locale(mut self, locale: impl Into<String>) -> Self {
+        self.fallback = Some(locale.into());
+        self
+    }
+
+    /// Build a catalog from `{"ja": {"greeting": {"hello": "..."}}}`
+    ///
+    /// Nested objects give dotted keys (`greeting.hello`); every message
+    /// must be a string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use natsuzora::{Natsuzora, Translations};
+    /// use serde_json::json;
+    ///
+    /// let translations = Translations::from_json(json!({
+    ///     "en": {"greeting": {"hello": "Hello, {name}!"}},
+    ///     "ja": {"greeting": {"hello": "こんにちは、{name}さん"}},
+    /// }))
+    /// .unwrap();
+    /// let tmpl = Natsuzora::parse("{[@t greeting.hello name=user.name ]}")
+    ///     .unwrap()
+    ///     .with_translations(translations)
+    ///     .with_locale("ja");
+    /// let result = tmpl.render(json!({"user": {"name": "<Alice>"}})).unwrap();
+    /// assert_eq!(result, "こんにちは、&lt;Alice&gt;さん");
+    /// ```
+    pub fn from_json(json: serde_json::Value) -> Result<Self> {
+        let serde_json::Value::Object(locales) = json else {
+            return Err(NatsuzoraError::TypeError {
+                message: "Translations must be an object of locales".to_string(),
+            });
+        };
+        let mut translations = Self::new();
+        for (locale, messages) in locales {
+            let messages_of = translations.locales.entry(locale).or_default();
+            flatten(String::new(), messages, messages_of)?;
+        }
+        Ok(translations)
+    }
+
+    /// The catalog as `{"locale": {"dotted.key": "message"}}`, readable by
+    /// [`Self::from_json`]
+    pub(crate) fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Object(
+            self.locales
+                .iter()
+                .map(|(locale, messages)| {
+                    let messages = messages
+                        .iter()
+                        .map(|(key, message)| (key.clone(), message.clone().into()))
+                        .collect();
+                    (locale.clone(), serde_json::Value::Object(messages))
+                })
+                .collect(),
+        )
+    }
+
+    /// Stable hash of the messages and fallback locale, for fragment cache keys
+    pub(crate) fn fingerprint(&self) -> String {
+        let mut locales: Vec<_> = self.locales.iter().collect();
+        locales.sort_by_key(|(locale, _)| *locale);
+        let mut text = format!("{:?}\0", self.fallback);
+        for (locale, messages) in locales {
+            let mut messages: Vec<_> = messages.iter().collect();
+            messages.sort();
+            text.push_str(&format!("{locale:?}{messages:?}\0"));
+        }
+        audit::stable_hash(text.as_bytes())
+    }
+
+    pub(crate) fn fallback_locale(&self) -> Option<&str> {
+        self.fallback.as_deref()
+    }
+
+    /// The message for `key`, trying `locale`, its language, then the fallback
+    pub(crate) fn lookup(&self, locale: Option<&str>, key: &str) -> Result<&str> {
+        let mut tried = Vec::new();
+        if let Some(locale) = locale {
+            tried.push(locale);
+            if let Some((language, _)) = locale.split_once(['-', '_']) {
+                tried.push(language);
+            }
+        }
+        tried.extend(self.fallback.as_deref());
+        tried
+            .iter()
+            .find_map(|locale| self.locales.get(*locale)?.get(key))
+            .map(String::as_str)
+            .ok_or_else(|| NatsuzoraError::MissingTranslation {
+                key: key.to_string(),
+                locales: tried.join(", "),
+            })
+    }
+}
+
+fn flatten(
+    prefix: String,
+    value: serde_json::Value,
+    messages: &mut HashMap<String, String>,
+) -> Result<()> {
+    match value {
+        serde_json::Value::String(message) if !prefix.is_empty() => {
+            messages.insert(prefix, message);
+        }
+        serde_json::Value::Object(entries) => {
+            for (key, value) in entries {
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(key, value, messages)?;
+            }
+        }
+        _ => {
+            return Err(NatsuzoraError::TypeError {
+                message: format!("Translation '{prefix}' must be a string"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Fill the `{name}` placeholders of `message` from `args`
+///
+/// Braces not enclosing an identifier are kept as written.
+pub(crate) fn format(message: &str, args: &[(&str, String)], location: Location) -> Result<String> {
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let name_len = after
+            .find(|c: char| !is_ident_continue(c))
+            .unwrap_or(after.len());
+        let name = &after[..name_len];
+        let is_placeholder = after[name_len..].starts_with('}') && name.starts_with(is_ident_start);
+        if !is_placeholder {
+            out.push('{');
+            rest = after;
+            continue;
+        }
+        let value = args.iter().find(|(arg, _)| *arg == name).ok_or_else(|| {
+            NatsuzoraError::UndefinedVariable {
+                message: format!("Undefined message argument: {name}"),
+                location,
+            }
+        })?;
+        out.push_str(&value.1);
+        rest = &after[name_len + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_lookup_falls_back_through_language_and_fallback_locale() {
+        let translations = Translations::new()
+            .with_message("pt", "a", "pt a")
+            .with_message("en", "a", "en a")
+            .with_message("en", "b", "en b")
+            .with_fallback_locale("en");
+        assert_eq!(translations.lookup(Some("pt-BR"), "a").unwrap(), "pt a");
+        assert_eq!(translations.lookup(Some("pt-BR"), "b").unwrap(), "en b");
+        assert_eq!(translations.lookup(None, "a").unwrap(), "en a");
+        let err = translations.lookup(Some("pt-BR"), "c").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No translation for 'c' (tried: pt-BR, pt, en)"
+        );
+    }
+
+    #[test]
+    fn test_from_json_flattens_keys() {
+        let translations =
+            Translations::from_json(json!({"ja": {"nav": {"home": "ホーム"}, "title": "題"}}))
+                .unwrap();
+        assert_eq!(
+            translations.lookup(Some("ja"), "nav.home").unwrap(),
+            "ホーム"
+        );
+        assert_eq!(translations.lookup(Some("ja"), "title").unwrap(), "題");
+        assert!(Translations::from_json(json!({"ja": {"n": 1}})).is_err());
+        assert!(Translations::from_json(json!({"ja": "x"})).is_err());
+    }
+
+    #[test]
+    fn test_format_placeholders() {
+        let location = Location::default();
+        let args = [("name", "Ann".to_string()), ("n", "3".to_string())];
+        assert_eq!(
+            format("{name} has {n} {items}", &args, location)
+                .unwrap_err()
+                .to_string(),
+            "Undefined message argument: items"
+        );
+        assert_eq!(
+            format("{name} has {n}. {} {1} {", &args, location).unwrap(),
+            "Ann has 3. {} {1} {"
+        );
+    }
+}
@@ -1,68 +1,279 @@
 //! Runtime value types for Natsuzora templates.
 
 use crate::error::{NatsuzoraError, Result};
+/// The map behind [`Value::Object`]
+pub use indexmap::IndexMap;
+use natsuzora_ast::Literal;
+use serde::{Serialize, Serializer};
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
 
 /// Integer range per spec: -9007199254740991 to 9007199254740991 (JavaScript safe integers)
 pub const INTEGER_MIN: i64 = -9_007_199_254_740_991;
 pub const INTEGER_MAX: i64 = 9_007_199_254_740_991;
 
+/// Range of data integers accepted as [`Value::Integer`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntegerRange {
+    /// [`INTEGER_MIN`] to [`INTEGER_MAX`], as required by the spec
+    #[default]
+    Safe,
+    /// The whole `i64` range
+    Full,
+}
+
+impl IntegerRange {
+    fn bounds(self) -> (i64, i64) {
+        match self {
+            IntegerRange::Safe => (INTEGER_MIN, INTEGER_MAX),
+            IntegerRange::Full => (i64::MIN, i64::MAX),
+        }
+    }
+}
+
+/// What becomes of a data integer outside the [`IntegerRange`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntegerPolicy {
+    /// Fail the render with a type error
+    #[default]
+    Error,
+    /// Clamp to the nearest bound of the range
+    Saturate,
+    /// Keep the exact digits as a [`Value::String`]
+    Stringify,
+}
+
 /// Runtime value type for Natsuzora templates
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Null,
     Bool(bool),
     Integer(i64),
+    /// A number with a fractional part; whole numbers are always integers
+    Float(f64),
     String(String),
     Array(Vec<Value>),
-    Object(HashMap<String, Value>),
+    /// Entries in insertion order, so that everything walking an object
+    /// (merges, error messages, `Debug`) sees the same order every run
+    Object(IndexMap<String, Value>),
+    /// A host-defined value, see [`NatsuzoraScalar`]
+    Scalar(Scalar),
+}
+
+/// A host type, such as a date or an amount of money, carried in the data
+/// as itself instead of pre-rendered text
+///
+/// A scalar renders as its [`stringify`](Self::stringify) text and is
+/// truthy. Everything else rejects it as it would an object: it has no
+/// properties, cannot be looped over, and filters that work on strings or
+/// numbers do not accept it.
+///
+/// ```rust
+/// use natsuzora::value::{NatsuzoraScalar, Value};
+/// use natsuzora::Natsuzora;
+///
+/// #[derive(Debug)]
+/// struct Yen(u64);
+///
+/// impl NatsuzoraScalar for Yen {
+///     fn type_name(&self) -> &'static str {
+///         "Yen"
+///     }
+///
+///     fn stringify(&self) -> String {
+///         format!("¥{}", self.0)
+///     }
+/// }
+///
+/// let data = Value::from_iter([("price", Value::scalar(Yen(980)))]);
+/// let tmpl = Natsuzora::parse("{[ price ]}").unwrap();
+/// assert_eq!(tmpl.render_value(data.clone()).unwrap(), "¥980");
+/// let tmpl = Natsuzora::parse("{[ price | upper ]}").unwrap();
+/// assert!(tmpl.render_value(data).is_err());
+/// ```
+pub trait NatsuzoraScalar: fmt::Debug + Send + Sync {
+    /// Name of the type in error messages
+    fn type_name(&self) -> &'static str;
+
+    /// The text the value renders as; the same value must always give the
+    /// same text, so that output stays deterministic
+    fn stringify(&self) -> String;
+}
+
+/// A shared [`NatsuzoraScalar`], equal to another of the same type with the
+/// same text
+#[derive(Debug, Clone)]
+pub struct Scalar(Arc<dyn NatsuzoraScalar>);
+
+impl Scalar {
+    pub fn get(&self) -> &dyn NatsuzoraScalar {
+        self.0.as_ref()
+    }
+}
+
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.type_name() == other.0.type_name() && self.0.stringify() == other.0.stringify()
+    }
 }
 
 impl Value {
+    /// Wrap a host value, see [`NatsuzoraScalar`]
+    pub fn scalar(value: impl NatsuzoraScalar + 'static) -> Self {
+        Value::Scalar(Scalar(Arc::new(value)))
+    }
+
     /// Convert a JSON value to a Natsuzora Value
+    ///
+    /// Object entries keep the order `serde_json`'s map iterates them in:
+    /// sorted by key unless its `preserve_order` feature is enabled.
     pub fn from_json(json: JsonValue) -> Result<Self> {
+        Self::from_json_with(json, IntegerRange::Safe, IntegerPolicy::Error)
+    }
+
+    /// Convert a JSON value, handling integers outside `range` per `policy`
+    ///
+    /// Integers beyond `i64` (large `u64` values) are always out of range,
+    /// so [`IntegerPolicy::Stringify`] is the lossless choice for them.
+    pub fn from_json_with(
+        json: JsonValue,
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    ) -> Result<Self> {
         match json {
             JsonValue::Null => Ok(Value::Null),
             JsonValue::Bool(b) => Ok(Value::Bool(b)),
-            JsonValue::Number(n) => {
-                if let Some(i) = n.as_i64() {
-                    if !(INTEGER_MIN..=INTEGER_MAX).contains(&i) {
-                        return Err(NatsuzoraError::TypeError {
-                            message: format!("Integer out of range: {i}"),
-                        });
-                    }
-                    Ok(Value::Integer(i))
-                } else if let Some(f) = n.as_f64() {
-                    // Try to convert float to integer if it's a whole number
-                    if f.fract() == 0.0 && f >= INTEGER_MIN as f64 && f <= INTEGER_MAX as f64 {
-                        Ok(Value::Integer(f as i64))
-                    } else {
-                        Err(NatsuzoraError::TypeError {
-                            message: format!("Floating point numbers are not supported: {f}"),
-                        })
-                    }
-                } else {
-                    Err(NatsuzoraError::TypeError {
-                        message: "Invalid number".to_string(),
-                    })
-                }
-            }
+            JsonValue::Number(n) => Value::from_json_number(&n, range, policy),
             JsonValue::String(s) => Ok(Value::String(s)),
             JsonValue::Array(arr) => {
-                let values: Result<Vec<Value>> = arr.into_iter().map(Value::from_json).collect();
+                let values: Result<Vec<Value>> = arr
+                    .into_iter()
+                    .map(|v| Value::from_json_with(v, range, policy))
+                    .collect();
                 Ok(Value::Array(values?))
             }
             JsonValue::Object(obj) => {
-                let mut map = HashMap::new();
+                let mut map = IndexMap::with_capacity(obj.len());
                 for (k, v) in obj {
-                    map.insert(k, Value::from_json(v)?);
+                    map.insert(k, Value::from_json_with(v, range, policy)?);
                 }
                 Ok(Value::Object(map))
             }
         }
     }
 
+    /// A JSON number of the data
+    pub(crate) fn from_json_number(
+        n: &serde_json::Number,
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    ) -> Result<Self> {
+        let integer = n.as_i64().map(i128::from);
+        if let Some(wide) = integer.or_else(|| n.as_u64().map(i128::from)) {
+            Value::from_integer(wide, n, range, policy)
+        } else if let Some(f) = n.as_f64() {
+            Value::from_number(f, n, range, policy)
+        } else {
+            Err(NatsuzoraError::TypeError {
+                message: "Invalid number".to_string(),
+            })
+        }
+    }
+
+    /// Decode MessagePack data (feature `msgpack`), see [`Self::from_json`]
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self> {
+        Self::from_msgpack_with(bytes, IntegerRange::Safe, IntegerPolicy::Error)
+    }
+
+    /// Decode MessagePack data, handling integers outside `range` per `policy`
+    ///
+    /// ```rust
+    /// use natsuzora::{IntegerPolicy, IntegerRange, Natsuzora, Value};
+    ///
+    /// // {"id": 18446744073709551615}
+    /// let mut bytes = vec![0x81, 0xa2, b'i', b'd', 0xcf];
+    /// bytes.extend(u64::MAX.to_be_bytes());
+    /// let data = Value::from_msgpack_with(&bytes, IntegerRange::Full, IntegerPolicy::Stringify)
+    ///     .unwrap();
+    /// let tmpl = Natsuzora::parse("{[ id ]}").unwrap();
+    /// assert_eq!(tmpl.render_value(data).unwrap(), "18446744073709551615");
+    /// ```
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack_with(
+        bytes: &[u8],
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    ) -> Result<Self> {
+        crate::binary::msgpack(bytes, range, policy)
+    }
+
+    /// Decode CBOR data (feature `cbor`), see [`Self::from_json`]
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self> {
+        Self::from_cbor_with(bytes, IntegerRange::Safe, IntegerPolicy::Error)
+    }
+
+    /// Decode CBOR data, handling integers outside `range` per `policy`
+    ///
+    /// Tags are ignored, so a tagged date string is just a string.
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor_with(
+        bytes: &[u8],
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    ) -> Result<Self> {
+        crate::binary::cbor(bytes, range, policy)
+    }
+
+    /// An integer of the data, `shown` as written in errors
+    pub(crate) fn from_integer(
+        wide: i128,
+        shown: &dyn fmt::Display,
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    ) -> Result<Self> {
+        let (min, max) = range.bounds();
+        if (i128::from(min)..=i128::from(max)).contains(&wide) {
+            return Ok(Value::Integer(wide as i64));
+        }
+        match policy {
+            IntegerPolicy::Error => Err(NatsuzoraError::TypeError {
+                message: format!("Integer out of range: {shown}"),
+            }),
+            IntegerPolicy::Saturate => Ok(Value::Integer(if wide < 0 { min } else { max })),
+            IntegerPolicy::Stringify => Ok(Value::String(wide.to_string())),
+        }
+    }
+
+    /// A floating-point number of the data, `shown` as written in errors
+    pub(crate) fn from_number(
+        f: f64,
+        shown: &dyn fmt::Display,
+        range: IntegerRange,
+        policy: IntegerPolicy,
+    ) -> Result<Self> {
+        // A whole number such as `2.0` is the integer it equals
+        if f.fract() != 0.0 {
+            return Ok(Value::Float(f));
+        }
+        let (min, max) = range.bounds();
+        if f >= min as f64 && f <= max as f64 {
+            return Ok(Value::Integer(f as i64));
+        }
+        match policy {
+            IntegerPolicy::Error => Err(NatsuzoraError::TypeError {
+                message: format!("Integer out of range: {shown}"),
+            }),
+            IntegerPolicy::Saturate => Ok(Value::Integer(if f < 0.0 { min } else { max })),
+            IntegerPolicy::Stringify => Ok(Value::String(format!("{f}"))),
+        }
+    }
+
     /// Check if the value is truthy per spec section 3.4
     /// Falsy values: false, null, 0, "", [], {}
     pub fn is_truthy(&self) -> bool {
@@ -70,25 +281,26 @@ impl Value {
             Value::Null => false,
             Value::Bool(b) => *b,
             Value::Integer(n) => *n != 0,
+            // Never zero, which is an integer
+            Value::Float(_) => true,
             Value::String(s) => !s.is_empty(),
             Value::Array(arr) => !arr.is_empty(),
             Value::Object(obj) => !obj.is_empty(),
+            Value::Scalar(_) => true,
         }
     }
 
     /// Stringify the value per spec section 3.3
-    /// Only String and Integer can be stringified. Null causes error (v4.0).
+    /// Only String, Integer, Float and Scalar can be stringified. Null causes error (v4.0).
+    /// The integer range is enforced when data is converted, see [`Value::from_json_with`].
+    /// Floats use the shortest digits that read back as the same number,
+    /// without an exponent and regardless of locale: `0.1`, `-1234.5`.
     pub fn stringify(&self) -> Result<String> {
         match self {
             Value::String(s) => Ok(s.clone()),
-            Value::Integer(n) => {
-                if *n < INTEGER_MIN || *n > INTEGER_MAX {
-                    return Err(NatsuzoraError::TypeError {
-                        message: format!("Integer out of range: {n}"),
-                    });
-                }
-                Ok(n.to_string())
-            }
+            Value::Integer(n) => Ok(n.to_string()),
+            Value::Float(f) => Ok(f.to_string()),
+            Value::Scalar(scalar) => Ok(scalar.get().stringify()),
             Value::Null => Err(NatsuzoraError::TypeError {
                 message: "Cannot stringify null value without '?' modifier".to_string(),
             }),
@@ -132,6 +344,83 @@ impl Value {
         }
     }
 
+    /// The value at `path`, a key of an object or an index of an array per
+    /// segment, as a template path such as `user.tags.0` reads it
+    ///
+    /// ```rust
+    /// use natsuzora::Value;
+    /// use serde_json::json;
+    ///
+    /// let data = Value::from_json(json!({"user": {"tags": ["a", "b"]}})).unwrap();
+    /// assert_eq!(data.get_path(&["user", "tags", "1"]), Some(&Value::from("b")));
+    /// assert_eq!(data.get_path(&["user", "name"]), None);
+    /// ```
+    pub fn get_path<S: AsRef<str>>(&self, path: &[S]) -> Option<&Value> {
+        path.iter()
+            .try_fold(self, |value, segment| value.child(segment.as_ref()))
+    }
+
+    /// The value at a JSON Pointer (RFC 6901) such as `/user/profile/name`
+    ///
+    /// The empty pointer is the value itself; `~1` and `~0` in a segment
+    /// stand for `/` and `~`. `None` when the pointer does not start with
+    /// `/` or leads nowhere.
+    ///
+    /// ```rust
+    /// use natsuzora::Value;
+    /// use serde_json::json;
+    ///
+    /// let data = Value::from_json(json!({"a/b": [{"c": 1}]})).unwrap();
+    /// assert_eq!(data.pointer("/a~1b/0/c"), Some(&Value::Integer(1)));
+    /// assert_eq!(data.pointer("/a~1b/1"), None);
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .try_fold(self, |value, token| {
+                value.child(&token.replace("~1", "/").replace("~0", "~"))
+            })
+    }
+
+    /// Entry `segment` of an object, or element of an array
+    fn child(&self, segment: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.get(segment),
+            Value::Array(items) if segment.bytes().all(|b| b.is_ascii_digit()) => {
+                items.get(segment.parse::<usize>().ok()?)
+            }
+            _ => None,
+        }
+    }
+
+    /// The `(first, second)` pairs an each block binds per iteration
+    ///
+    /// Arrays yield `(item, index)`; objects yield `(key, value)` in key order.
+    pub fn loop_entries(&self) -> Result<Vec<(Value, Value)>> {
+        match self {
+            Value::Array(arr) => Ok(arr
+                .iter()
+                .enumerate()
+                .map(|(index, item)| (item.clone(), Value::Integer(index as i64)))
+                .collect()),
+            Value::Object(obj) => {
+                let mut keys: Vec<&String> = obj.keys().collect();
+                keys.sort();
+                Ok(keys
+                    .into_iter()
+                    .map(|key| (Value::String(key.clone()), obj[key].clone()))
+                    .collect())
+            }
+            _ => Err(NatsuzoraError::TypeError {
+                message: format!("Expected array or object, got {}", self.type_name()),
+            }),
+        }
+    }
+
     /// Stringify with required modifier (! modifier)
     /// Null and empty string cause TypeError
     pub fn stringify_required(&self) -> Result<String> {
@@ -155,9 +444,373 @@ impl Value {
             Value::Bool(true) => "TrueClass",
             Value::Bool(false) => "FalseClass",
             Value::Integer(_) => "Integer",
+            Value::Float(_) => "Float",
             Value::String(_) => "String",
             Value::Array(_) => "Array",
             Value::Object(_) => "Hash",
+            Value::Scalar(scalar) => scalar.get().type_name(),
+        }
+    }
+}
+
+/// Top-level values shared by many renders without being copied into each
+///
+/// Built once, e.g. for a `site` object every page of a build uses, and
+/// passed to [`Natsuzora::render_with_shared`](crate::Natsuzora::render_with_shared).
+/// Cloning it only bumps reference counts. A name the per-render data also
+/// has is taken from the per-render data.
+///
+/// ```rust
+/// use natsuzora::value::{SharedData, Value};
+/// use serde_json::json;
+///
+/// let shared = SharedData::from_json(json!({"site": {"name": "Natsuzora"}})).unwrap();
+/// let tmpl = natsuzora::Natsuzora::parse("{[ title ]} - {[ site.name ]}").unwrap();
+/// for title in ["Home", "About"] {
+///     let output = tmpl.render_with_shared(json!({"title": title}), &shared).unwrap();
+///     assert_eq!(output, format!("{title} - Natsuzora"));
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SharedData {
+    entries: HashMap<String, Arc<Value>>,
+}
+
+impl SharedData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The entries of a JSON object, converted once
+    pub fn from_json(json: JsonValue) -> Result<Self> {
+        match Value::from_json(json)? {
+            Value::Object(entries) => Ok(Self {
+                entries: entries
+                    .into_iter()
+                    .map(|(name, value)| (name, Arc::new(value)))
+                    .collect(),
+            }),
+            _ => Err(NatsuzoraError::TypeError {
+                message: "Shared data must be an object".to_string(),
+            }),
+        }
+    }
+
+    /// Share `value` under `name`, e.g. an `Arc` also held elsewhere
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<Arc<Value>>) -> Self {
+        self.entries.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.entries.get(name).map(Arc::as_ref)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+}
+
+/// Path of a value in `base` that the overlay replaced in [`merge`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConflictPath(pub Vec<String>);
+
+impl fmt::Display for ConflictPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.join("."))
+    }
+}
+
+/// Lay `overlay` over `base`, reporting which values of `base` it replaced
+///
+/// Objects are merged key by key at every depth; anything else in
+/// `overlay` (arrays, scalars, `null`) replaces the value in `base`
+/// wholesale. Replacing a value with an equal one is not a conflict.
+/// Conflicts are sorted, so the result does not depend on key order, and
+/// values are trees, so the merge always ends.
+///
+/// ```rust
+/// use natsuzora::value::{merge, Value};
+/// use serde_json::json;
+///
+/// let cms = Value::from_json(json!({"site": {"title": "A", "lang": "ja"}})).unwrap();
+/// let request = Value::from_json(json!({"site": {"title": "B"}, "user": "u"})).unwrap();
+/// let (merged, conflicts) = merge(cms, request);
+/// assert_eq!(conflicts[0].to_string(), "site.title");
+/// assert_eq!(
+///     merged,
+///     Value::from_json(json!({"site": {"title": "B", "lang": "ja"}, "user": "u"})).unwrap()
+/// );
+/// ```
+pub fn merge(base: Value, overlay: Value) -> (Value, Vec<ConflictPath>) {
+    let mut conflicts = Vec::new();
+    let merged = merge_at(base, overlay, &mut Vec::new(), &mut conflicts);
+    conflicts.sort();
+    (merged, conflicts)
+}
+
+fn merge_at(
+    base: Value,
+    overlay: Value,
+    path: &mut Vec<String>,
+    conflicts: &mut Vec<ConflictPath>,
+) -> Value {
+    match (base, overlay) {
+        (Value::Object(mut base), Value::Object(overlay)) => {
+            // Merged keys keep their place in `base`; new ones follow in overlay order
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => {
+                        path.push(key);
+                        let previous = std::mem::replace(existing, Value::Null);
+                        *existing = merge_at(previous, value, path, conflicts);
+                        path.pop();
+                    }
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+            Value::Object(base)
+        }
+        (base, overlay) => {
+            if base != overlay {
+                conflicts.push(ConflictPath(path.clone()));
+            }
+            overlay
+        }
+    }
+}
+
+/// Writes the value as the JSON data it stands for; a scalar is written as
+/// its text.
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_unit(),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Integer(n) => serializer.serialize_i64(*n),
+            Value::Float(f) => serializer.serialize_f64(*f),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Array(items) => serializer.collect_seq(items),
+            Value::Object(map) => serializer.collect_map(map),
+            Value::Scalar(scalar) => serializer.serialize_str(&scalar.get().stringify()),
+        }
+    }
+}
+
+/// Build a [`Value`] with JSON-like syntax, as `serde_json::json!` does
+///
+/// `null`, arrays and objects are written as in JSON; any other element is
+/// a Rust expression of a `serde::Serialize` type, converted as
+/// [`Natsuzora::render_serialize`](crate::Natsuzora::render_serialize)
+/// converts data. Object keys are string literals or parenthesized
+/// expressions.
+///
+/// # Panics
+///
+/// If an integer is outside the safe range, which renders reject as data.
+///
+/// # Example
+///
+/// ```rust
+/// use natsuzora::{value, Natsuzora};
+///
+/// let name = "Alice";
+/// let data = value!({
+///     "user": {"name": name, "admin": false},
+///     "items": [1, -2.5, null, [name]],
+/// });
+/// let tmpl = Natsuzora::parse("{[ user.name ]}: {[ items.0 ]}").unwrap();
+/// assert_eq!(tmpl.render_value(data).unwrap(), "Alice: 1");
+/// ```
+#[macro_export]
+macro_rules! value {
+    (null) => {
+        $crate::Value::Null
+    };
+    ([ $($tt:tt)* ]) => {
+        $crate::Value::Array($crate::value!(@array [] $($tt)*))
+    };
+    ({ $($tt:tt)* }) => {{
+        #[allow(unused_mut)]
+        let mut object = $crate::value::IndexMap::new();
+        $crate::value!(@object object $($tt)*);
+        $crate::Value::Object(object)
+    }};
+
+    // Array elements, converted ones in brackets
+    (@array [$($done:expr,)*]) => {
+        ::std::vec![$($done,)*]
+    };
+    (@array [$($done:expr,)*] , $($rest:tt)*) => {
+        $crate::value!(@array [$($done,)*] $($rest)*)
+    };
+    (@array [$($done:expr,)*] null $($rest:tt)*) => {
+        $crate::value!(@array [$($done,)* $crate::Value::Null,] $($rest)*)
+    };
+    (@array [$($done:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
+        $crate::value!(@array [$($done,)* $crate::value!([$($array)*]),] $($rest)*)
+    };
+    (@array [$($done:expr,)*] {$($object:tt)*} $($rest:tt)*) => {
+        $crate::value!(@array [$($done,)* $crate::value!({$($object)*}),] $($rest)*)
+    };
+    (@array [$($done:expr,)*] $next:expr , $($rest:tt)*) => {
+        $crate::value!(@array [$($done,)* $crate::value!($next),] $($rest)*)
+    };
+    (@array [$($done:expr,)*] $last:expr) => {
+        $crate::value!(@array [$($done,)* $crate::value!($last),])
+    };
+
+    // Object entries, inserted into `$object`
+    (@object $object:ident) => {};
+    (@object $object:ident , $($rest:tt)*) => {
+        $crate::value!(@object $object $($rest)*)
+    };
+    (@object $object:ident $key:tt : null $($rest:tt)*) => {
+        $crate::value!(@entry $object $key ($crate::Value::Null) $($rest)*)
+    };
+    (@object $object:ident $key:tt : [$($array:tt)*] $($rest:tt)*) => {
+        $crate::value!(@entry $object $key ($crate::value!([$($array)*])) $($rest)*)
+    };
+    (@object $object:ident $key:tt : {$($inner:tt)*} $($rest:tt)*) => {
+        $crate::value!(@entry $object $key ($crate::value!({$($inner)*})) $($rest)*)
+    };
+    (@object $object:ident $key:tt : $value:expr , $($rest:tt)*) => {
+        $crate::value!(@entry $object $key ($crate::value!($value)) , $($rest)*)
+    };
+    (@object $object:ident $key:tt : $value:expr) => {
+        $crate::value!(@entry $object $key ($crate::value!($value)))
+    };
+    (@entry $object:ident $key:tt ($value:expr) $($rest:tt)*) => {
+        $object.insert(::std::string::String::from($key), $value);
+        $crate::value!(@object $object $($rest)*);
+    };
+
+    ($other:expr) => {
+        $crate::value::from_serialize(&$other)
+    };
+}
+
+/// The [`value!`](crate::value!) conversion of a Rust expression
+#[doc(hidden)]
+pub fn from_serialize<T: Serialize + ?Sized>(data: &T) -> Value {
+    crate::serialize::to_value(data, IntegerRange::Safe, IntegerPolicy::Error)
+        .unwrap_or_else(|e| panic!("value!: {e}"))
+}
+
+// Conversions for building data by hand. Integers that may fall outside the
+// spec's safe range and floats are `TryFrom`, checked as JSON data is.
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+macro_rules! from_small_integer {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Value {
+            fn from(n: $t) -> Self {
+                Value::Integer(i64::from(n))
+            }
+        }
+    )*};
+}
+
+from_small_integer!(i8, i16, i32, u8, u16, u32);
+
+impl TryFrom<i64> for Value {
+    type Error = NatsuzoraError;
+
+    fn try_from(n: i64) -> Result<Self> {
+        Value::from_integer(i128::from(n), &n, IntegerRange::Safe, IntegerPolicy::Error)
+    }
+}
+
+impl TryFrom<u64> for Value {
+    type Error = NatsuzoraError;
+
+    fn try_from(n: u64) -> Result<Self> {
+        Value::from_integer(i128::from(n), &n, IntegerRange::Safe, IntegerPolicy::Error)
+    }
+}
+
+/// A whole number becomes an integer; NaN and infinities become null, as
+/// JSON has neither
+impl TryFrom<f64> for Value {
+    type Error = NatsuzoraError;
+
+    fn try_from(f: f64) -> Result<Self> {
+        if !f.is_finite() {
+            return Ok(Value::Null);
+        }
+        Value::from_number(f, &f, IntegerRange::Safe, IntegerPolicy::Error)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(items: Vec<T>) -> Self {
+        items.into_iter().collect()
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<BTreeMap<K, V>> for Value {
+    fn from(entries: BTreeMap<K, V>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<HashMap<K, V>> for Value {
+    fn from(entries: HashMap<K, V>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+/// Collects an array
+impl<T: Into<Value>> FromIterator<T> for Value {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        Value::Array(items.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Collects an object; a repeated key keeps its last value
+impl<K: Into<String>, V: Into<Value>> FromIterator<(K, V)> for Value {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+impl From<&Literal> for Value {
+    fn from(literal: &Literal) -> Self {
+        match literal {
+            Literal::String(s) => Value::String(s.clone()),
+            Literal::Integer(n) => Value::Integer(*n),
+            Literal::Bool(b) => Value::Bool(*b),
+            Literal::Null => Value::Null,
         }
     }
 }
@@ -167,6 +820,41 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn test_value_macro_matches_json() {
+        let key = String::from("k");
+        let tags = vec!["x", "y"];
+        let built = crate::value!({
+            "n": -1,
+            "f": 2.5,
+            "whole": 3.0,
+            "s": "text",
+            "none": null,
+            "nested": {"a": [1, {"b": true}, null,], (key.as_str()): (1 + 2)},
+            "tags": tags,
+            "maybe": Option::<i32>::None,
+        });
+        let json = json!({
+            "n": -1,
+            "f": 2.5,
+            "whole": 3,
+            "s": "text",
+            "none": null,
+            "nested": {"a": [1, {"b": true}, null], "k": 3},
+            "tags": ["x", "y"],
+            "maybe": null,
+        });
+        assert_eq!(built, Value::from_json(json).unwrap());
+        assert_eq!(crate::value!([]), Value::Array(vec![]));
+        assert_eq!(crate::value!({}), Value::Object(IndexMap::new()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Integer out of range")]
+    fn test_value_macro_checks_integer_range() {
+        crate::value!({"id": (1u64 << 60)});
+    }
+
     #[test]
     fn test_truthy() {
         assert!(!Value::Null.is_truthy());
@@ -175,11 +863,12 @@ mod tests {
         assert!(!Value::Integer(0).is_truthy());
         assert!(Value::Integer(1).is_truthy());
         assert!(Value::Integer(-1).is_truthy());
+        assert!(Value::Float(-0.5).is_truthy());
         assert!(!Value::String("".to_string()).is_truthy());
         assert!(Value::String("hello".to_string()).is_truthy());
         assert!(!Value::Array(vec![]).is_truthy());
         assert!(Value::Array(vec![Value::Integer(1)]).is_truthy());
-        assert!(!Value::Object(HashMap::new()).is_truthy());
+        assert!(!Value::Object(IndexMap::new()).is_truthy());
     }
 
     #[test]
@@ -191,12 +880,41 @@ mod tests {
         assert_eq!(Value::Integer(42).stringify().unwrap(), "42");
         assert_eq!(Value::Integer(-42).stringify().unwrap(), "-42");
         assert_eq!(Value::Integer(0).stringify().unwrap(), "0");
+        assert_eq!(Value::Float(0.1).stringify().unwrap(), "0.1");
+        assert_eq!(Value::Float(-1234.5).stringify().unwrap(), "-1234.5");
+        assert_eq!(Value::Float(1e-7).stringify().unwrap(), "0.0000001");
+        assert_eq!(
+            Value::Float(-2.5e-12).stringify().unwrap(),
+            "-0.0000000000025"
+        );
 
         // Null causes error in v4.0
         assert!(Value::Null.stringify().is_err());
         assert!(Value::Bool(true).stringify().is_err());
         assert!(Value::Array(vec![]).stringify().is_err());
-        assert!(Value::Object(HashMap::new()).stringify().is_err());
+        assert!(Value::Object(IndexMap::new()).stringify().is_err());
+    }
+
+    #[test]
+    fn test_loop_entries() {
+        let array = Value::Array(vec![Value::Bool(true)]);
+        assert_eq!(
+            array.loop_entries().unwrap(),
+            vec![(Value::Bool(true), Value::Integer(0))]
+        );
+
+        let object = Value::Object(IndexMap::from([
+            ("b".to_string(), Value::Integer(2)),
+            ("a".to_string(), Value::Integer(1)),
+        ]));
+        assert_eq!(
+            object.loop_entries().unwrap(),
+            vec![
+                (Value::String("a".to_string()), Value::Integer(1)),
+                (Value::String("b".to_string()), Value::Integer(2)),
+            ]
+        );
+        assert!(Value::Null.loop_entries().is_err());
     }
 
     #[test]
@@ -220,4 +938,159 @@ mod tests {
             panic!("Expected Object");
         }
     }
+
+    #[test]
+    fn test_from_json_float() {
+        assert_eq!(Value::from_json(json!(19.99)).unwrap(), Value::Float(19.99));
+        assert_eq!(Value::from_json(json!(2.0)).unwrap(), Value::Integer(2));
+        assert!(Value::from_json(json!(1e20)).is_err());
+        assert_eq!(
+            Value::from_json_with(json!(1e20), IntegerRange::Safe, IntegerPolicy::Stringify)
+                .unwrap(),
+            Value::String("100000000000000000000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_scalar() {
+        #[derive(Debug)]
+        struct Day(u32);
+
+        impl NatsuzoraScalar for Day {
+            fn type_name(&self) -> &'static str {
+                "Day"
+            }
+
+            fn stringify(&self) -> String {
+                format!("day {}", self.0)
+            }
+        }
+
+        let day = Value::scalar(Day(0));
+        assert_eq!(day.stringify().unwrap(), "day 0");
+        assert!(day.is_truthy());
+        assert_eq!(day.type_name(), "Day");
+        assert_eq!(day, Value::scalar(Day(0)));
+        assert_ne!(day, Value::scalar(Day(1)));
+        assert_ne!(day, Value::String("day 0".to_string()));
+        assert!(day.loop_entries().is_err());
+        assert_eq!(serde_json::to_value(&day).unwrap(), json!("day 0"));
+    }
+
+    #[test]
+    fn test_object_keys_keep_insertion_order() {
+        let json = json!({"b": 1, "a": {"d": null, "c": true}});
+        let Value::Object(entries) = Value::from_json(json.clone()).unwrap() else {
+            panic!("expected an object");
+        };
+        assert!(entries.keys().eq(json.as_object().unwrap().keys()));
+        let built = crate::value!({"z": 1, "y": 2});
+        let Value::Object(entries) = &built else {
+            panic!("expected an object");
+        };
+        assert_eq!(entries.keys().collect::<Vec<_>>(), ["z", "y"]);
+        assert_eq!(
+            built.loop_entries().unwrap()[0],
+            (Value::String("y".to_string()), Value::Integer(2))
+        );
+    }
+
+    #[test]
+    fn test_from_impls() {
+        let tags: Value = vec!["a", "b"].into();
+        let data: Value = [
+            ("name", Value::from("Ann")),
+            ("age", 30.into()),
+            ("admin", false.into()),
+            ("tags", tags),
+            ("nickname", None::<&str>.into()),
+            ("score", Value::try_from(1.5).unwrap()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            data,
+            Value::from_json(json!({
+                "name": "Ann", "age": 30, "admin": false, "tags": ["a", "b"],
+                "nickname": null, "score": 1.5
+            }))
+            .unwrap()
+        );
+        let squares: Value = (1..=3u8).map(|n| n * n).collect();
+        assert_eq!(squares, Value::from_json(json!([1, 4, 9])).unwrap());
+        assert_eq!(
+            Value::from(HashMap::from([("k", 1)])),
+            Value::from_json(json!({"k": 1})).unwrap()
+        );
+
+        assert_eq!(
+            Value::try_from(INTEGER_MAX).unwrap(),
+            Value::Integer(INTEGER_MAX)
+        );
+        assert!(Value::try_from(INTEGER_MAX + 1).is_err());
+        assert!(Value::try_from(u64::MAX).is_err());
+        assert_eq!(Value::try_from(2.0).unwrap(), Value::Integer(2));
+        assert_eq!(Value::try_from(f64::NAN).unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn test_get_path_and_pointer() {
+        let data = Value::from_json(json!({
+            "user": {"profile": {"name": "Ann"}, "tags": ["x"]},
+            "~": {"": 1},
+        }))
+        .unwrap();
+        let name = Value::from("Ann");
+        assert_eq!(data.get_path(&["user", "profile", "name"]), Some(&name));
+        assert_eq!(data.pointer("/user/profile/name"), Some(&name));
+        assert_eq!(data.get_path::<&str>(&[]), Some(&data));
+        assert_eq!(data.pointer(""), Some(&data));
+
+        let tags = ["user".to_string(), "tags".to_string(), "0".to_string()];
+        assert_eq!(data.get_path(&tags), Some(&Value::from("x")));
+        assert_eq!(data.pointer("/user/tags/+0"), None);
+        assert_eq!(data.pointer("/user/profile/name/first"), None);
+        assert_eq!(data.pointer("/~0/"), Some(&Value::Integer(1)));
+        assert_eq!(data.pointer("user"), None);
+    }
+
+    #[test]
+    fn test_merge() {
+        let base = Value::from_json(json!({"a": {"b": 1, "c": [1]}, "d": "x", "e": null})).unwrap();
+        let overlay = Value::from_json(json!({"a": {"c": [2], "f": 3}, "d": "x", "e": 0})).unwrap();
+        let (merged, conflicts) = merge(base, overlay);
+        assert_eq!(
+            merged,
+            Value::from_json(json!({"a": {"b": 1, "c": [2], "f": 3}, "d": "x", "e": 0})).unwrap()
+        );
+        let paths: Vec<String> = conflicts.iter().map(ToString::to_string).collect();
+        assert_eq!(paths, ["a.c", "e"]);
+
+        let (merged, conflicts) = merge(Value::Integer(1), Value::Null);
+        assert_eq!(merged, Value::Null);
+        assert_eq!(conflicts, [ConflictPath(Vec::new())]);
+    }
+
+    #[test]
+    fn test_from_json_integer_bounds() {
+        let big = json!(9_007_199_254_740_993_i64);
+        let huge = json!(u64::MAX);
+        assert!(Value::from_json(big.clone()).is_err());
+        assert_eq!(
+            Value::from_json_with(big.clone(), IntegerRange::Full, IntegerPolicy::Error).unwrap(),
+            Value::Integer(9_007_199_254_740_993)
+        );
+        assert!(
+            Value::from_json_with(huge.clone(), IntegerRange::Full, IntegerPolicy::Error).is_err()
+        );
+        assert_eq!(
+            Value::from_json_with(big, IntegerRange::Safe, IntegerPolicy::Saturate).unwrap(),
+            Value::Integer(INTEGER_MAX)
+        );
+        assert_eq!(
+            Value::from_json_with(json!([huge]), IntegerRange::Full, IntegerPolicy::Stringify)
+                .unwrap(),
+            Value::Array(vec![Value::String("18446744073709551615".to_string())])
+        );
+    }
 }
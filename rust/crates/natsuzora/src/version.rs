@@ -0,0 +1,46 @@
+//! Version and capability introspection for bindings.
+
+/// Version of this crate
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Version of the template language specification implemented
+pub const LANGUAGE_VERSION: &str = "4.0";
+
+/// Capabilities compiled into this build
+///
+/// Bindings can check for a capability (e.g. `"include"`) before offering
+/// the corresponding API. Names are only ever added.
+pub fn features() -> &'static [&'static str] {
+    &[
+        "include",
+        "cache",
+        "edge-include",
+        "coverage",
+        "extract",
+        "docs",
+        "report",
+        "audit",
+        "stream",
+        "translations",
+        "repro",
+        #[cfg(feature = "encryption")]
+        "encryption",
+        #[cfg(feature = "signing")]
+        "signing",
+        #[cfg(feature = "msgpack")]
+        "msgpack",
+        #[cfg(feature = "cbor")]
+        "cbor",
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_metadata() {
+        assert_eq!(VERSION.split('.').count(), 3);
+        assert!(features().contains(&"include"));
+    }
+}
@@ -0,0 +1,153 @@
+//! Compile-time pin of the supported public surface (`natsuzora::prelude`).
+//!
+//! A build failure here means a breaking change for downstream crates and
+//! must come with a major (or, pre-1.0, minor) version bump.
+
+use natsuzora::prelude::*;
+use serde_json::json;
+
+#[test]
+fn prelude_function_signatures() {
+    let _: fn(&str, serde_json::Value) -> Result<String, NatsuzoraError> = render;
+    let _: fn(&str) -> Result<Natsuzora, NatsuzoraError> = Natsuzora::parse;
+    let _: fn(&str, ParseOptions) -> Result<Natsuzora, NatsuzoraError> =
+        Natsuzora::parse_with_options;
+    let _: fn(&Natsuzora, serde_json::Value) -> Result<String, NatsuzoraError> = Natsuzora::render;
+    let _: fn(&Natsuzora) -> &Template = Natsuzora::template;
+    let _: fn(
+        &Template,
+        serde_json::Value,
+        &mut dyn IncludeLoader,
+    ) -> Result<String, NatsuzoraError> = render_template;
+    let _: fn(serde_json::Value) -> Result<Value, NatsuzoraError> = Value::from_json;
+    let _: fn(&str) -> Result<Template, ParseError> = natsuzora_ast::parse;
+    let _: fn(&Template) -> Location = Template::location;
+}
+
+#[test]
+fn prelude_generic_entry_points() {
+    let dir = tempfile::tempdir().unwrap();
+    let rendered: Result<String, NatsuzoraError> =
+        render_with_includes("Hi", json!({}), dir.path());
+    assert_eq!(rendered.unwrap(), "Hi");
+
+    let tmpl = Natsuzora::parse_with_includes("{[#if a]}x{[/if]}", dir.path()).unwrap();
+    let mut coverage = Coverage::new();
+    tmpl.render_with_coverage(json!({"a": true}), "t", &mut coverage)
+        .unwrap();
+    let _: CoverageReport = coverage.report();
+
+    let mut loader = TemplateLoader::new(dir.path()).unwrap();
+    let _: &mut dyn IncludeLoader = &mut loader;
+    let _: Option<LoaderError> = None;
+    let _ = Modifier::default();
+}
+
+/// Every item the prelude exports; removing or renaming one fails to build
+#[test]
+fn prelude_items() {
+    fn item<T: ?Sized>() {}
+    fn render_data<'d, T: RenderData<'d>>() {}
+
+    item::<TemplateInfo>();
+    item::<TemplateSet>();
+    item::<AuditEvent>();
+    item::<AuditOptions>();
+    item::<dyn AuditSink>();
+    item::<Coverage>();
+    item::<CoverageReport>();
+    item::<DocFormat>();
+    item::<DocGenerator<'static>>();
+    item::<DuplicateGroup>();
+    item::<EdgeIncludeFormat>();
+    item::<EdgeIncludes>();
+    item::<NatsuzoraError>();
+    item::<MessageCatalog>();
+    item::<CustomFilters>();
+    item::<dyn OutputHook>();
+    item::<dyn FragmentCache>();
+    item::<FragmentKey>();
+    item::<LruFragmentCache>();
+    item::<EscapeMode>();
+    item::<DuplicateId>();
+    item::<IdSite>();
+    item::<RenderOptions>();
+    item::<Profile>();
+    item::<Profiles>();
+    item::<ContentHashes>();
+    item::<PageToRender>();
+    item::<ExtractedPartial>();
+    item::<FileDiff>();
+    item::<RenderBuilder<'static>>();
+    item::<FallbackUse>();
+    item::<Rendered>();
+    item::<CheckReport>();
+    item::<Diagnostic>();
+    item::<Severity>();
+    item::<ReproBundle>();
+    item::<StreamRenderer>();
+    item::<FnLoader<fn(&str) -> Result<String, NatsuzoraError>>>();
+    item::<FsSource>();
+    item::<dyn SourceHook>();
+    item::<TemplateLoader>();
+    item::<dyn TemplateSource>();
+    item::<Translations>();
+    item::<ConflictPath>();
+    item::<IntegerPolicy>();
+    item::<IntegerRange>();
+    item::<dyn NatsuzoraScalar>();
+    item::<SharedData>();
+    item::<Value>();
+    item::<Natsuzora>();
+    item::<Serialized<'static, str>>();
+    item::<Delimiters>();
+    item::<Filter>();
+    item::<dyn IncludeLoader>();
+    item::<InvalidDelimiters>();
+    item::<LoaderError>();
+    item::<Location>();
+    item::<Modifier>();
+    item::<Param>();
+    item::<ParseError>();
+    item::<ParseLimits>();
+    item::<ParseOptions>();
+    item::<Template>();
+    item::<Trim>();
+    item::<TrimCause>();
+
+    render_data::<serde_json::Value>();
+    render_data::<&serde_json::Value>();
+    render_data::<Value>();
+    render_data::<Serialized<'_, [u8]>>();
+
+    let _: u32 = AUDIT_SCHEMA_VERSION;
+    let _: u32 = REPORT_SCHEMA_VERSION;
+    let _: u32 = REPRO_SCHEMA_VERSION;
+    let _: &str = LANGUAGE_VERSION;
+    let _: &str = VERSION;
+    let _: fn() -> &'static [&'static str] = features;
+    let _: fn(&str, &ParseOptions) -> Result<Vec<Trim>, ParseError> = trace_trims;
+    let _: fn(&Template, std::ops::Range<usize>, &str) -> Result<ExtractedPartial, NatsuzoraError> =
+        extract_partial;
+    let _ = |dir: &std::path::Path,
+             loader: &mut TemplateLoader|
+     -> Result<Vec<FileDiff>, NatsuzoraError> { rename_path(dir, loader, "a", "b") };
+    let _ = |source: &[u8], out: &mut Vec<u8>| -> Result<(), NatsuzoraError> {
+        render_stream(source, json!({}), out)
+    };
+}
+
+#[test]
+fn prelude_render_builder_combines_settings_and_data() {
+    let tmpl = Natsuzora::parse("{[#cache key=id ]}{[ id ]}{[/cache]}").unwrap();
+    let builder: fn(&Natsuzora) -> RenderBuilder<'_> = Natsuzora::render_builder;
+    let options = RenderOptions::new().with_max_output_bytes(8);
+    let cache = LruFragmentCache::new(10);
+    let data = json!({"id": 7});
+    let rendered = builder(&tmpl)
+        .with_options(&options)
+        .with_fragment_cache(&cache)
+        .render(Serialized(&data));
+    assert_eq!(rendered.unwrap(), "7");
+    assert_eq!(builder(&tmpl).render(&data).unwrap(), "7");
+}
@@ -1,6 +1,6 @@
 //! Integration tests using shared test cases from tests/*.json
 
-use natsuzora::{render, render_with_includes};
+use natsuzora::{render_with_includes, Natsuzora, RenderOptions, Translations};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
@@ -24,6 +24,14 @@ struct TestCase {
     error: Option<String>,
     #[serde(default)]
     partials: Option<HashMap<String, String>>,
+    #[serde(default)]
+    translations: Option<serde_json::Value>,
+    #[serde(default)]
+    locale: Option<String>,
+    #[serde(default)]
+    group_separator: Option<String>,
+    #[serde(default)]
+    dynamic_includes: Vec<String>,
 }
 
 fn get_tests_dir() -> PathBuf {
@@ -67,12 +75,27 @@ fn setup_partials(partials: &HashMap<String, String>) -> tempfile::TempDir {
 }
 
 fn run_test_case(case: &TestCase) {
-    let result = if let Some(partials) = &case.partials {
-        let dir = setup_partials(partials);
-        render_with_includes(&case.template, case.data.clone(), dir.path())
-    } else {
-        render(&case.template, case.data.clone())
-    };
+    let dir = case.partials.as_ref().map(setup_partials);
+    let result = match &dir {
+        Some(dir) => Natsuzora::parse_with_includes(&case.template, dir.path()),
+        None => Natsuzora::parse(&case.template),
+    }
+    .and_then(|mut tmpl| {
+        if let Some(translations) = &case.translations {
+            tmpl = tmpl.with_translations(Translations::from_json(translations.clone())?);
+        }
+        if let Some(locale) = &case.locale {
+            tmpl = tmpl.with_locale(locale);
+        }
+        let mut options = RenderOptions::new();
+        if let Some(separator) = &case.group_separator {
+            options = options.with_group_separator(separator);
+        }
+        for prefix in &case.dynamic_includes {
+            options = options.with_dynamic_includes_under(prefix);
+        }
+        tmpl.render_with_options(case.data.clone(), &options)
+    });
 
     if let Some(expected) = &case.expected {
         match result {
@@ -123,6 +146,7 @@ fn error_type_matches(e: &natsuzora::NatsuzoraError, expected: &str) -> bool {
         (TypeError { .. }, "EmptyStringError") => true,
         (ShadowingError { .. }, "ShadowingError") => true,
         (IncludeError { .. }, "IncludeError") => true,
+        (MissingTranslation { .. }, "MissingTranslation") => true,
         _ => false,
     }
 }
@@ -164,6 +188,16 @@ fn test_if_block() {
     run_test_suite("if_block.json", &[]);
 }
 
+#[test]
+fn test_comparison() {
+    run_test_suite("comparison.json", &[]);
+}
+
+#[test]
+fn test_logical() {
+    run_test_suite("logical.json", &[]);
+}
+
 #[test]
 fn test_each_block() {
     run_test_suite("each_block.json", &[]);
@@ -199,6 +233,11 @@ fn test_delimiter_escape() {
     run_test_suite("delimiter_escape.json", &[]);
 }
 
+#[test]
+fn test_cache_block() {
+    run_test_suite("cache_block.json", &[]);
+}
+
 #[test]
 fn test_unless_block() {
     run_test_suite("unless_block.json", &[]);
@@ -213,3 +252,110 @@ fn test_block_errors() {
 fn test_edge_cases() {
     run_test_suite("edge_cases.json", &[]);
 }
+
+#[test]
+fn test_raw_block() {
+    run_test_suite("raw_block.json", &[]);
+}
+
+#[test]
+fn test_layout() {
+    run_test_suite("layout.json", &[]);
+}
+
+#[test]
+fn test_slots() {
+    run_test_suite("slots.json", &[]);
+}
+
+#[test]
+fn test_filters() {
+    run_test_suite("filters.json", &[]);
+}
+
+#[test]
+fn test_translate() {
+    run_test_suite("translate.json", &[]);
+}
+
+#[test]
+fn test_attr() {
+    run_test_suite("attr.json", &[]);
+}
+
+#[test]
+fn test_params() {
+    run_test_suite("params.json", &[]);
+}
+
+#[test]
+fn test_xss() {
+    run_test_suite("xss.json", &[]);
+}
+
+#[derive(Debug, Deserialize)]
+struct PayloadCorpus {
+    payloads: Vec<String>,
+}
+
+/// Reverse the five entities of HTML escaping, `&amp;` last
+fn unescape(escaped: &str) -> String {
+    escaped
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[test]
+fn test_xss_payloads_in_every_position() {
+    let path = get_tests_dir().join("xss.json");
+    let content = fs::read_to_string(path).unwrap();
+    let corpus: PayloadCorpus = serde_json::from_str(&content).unwrap();
+    let partials = HashMap::from([
+        ("/echo".to_string(), "{[ value ]}".to_string()),
+        ("/wrap".to_string(), "{[!yield]}".to_string()),
+    ]);
+    let dir = setup_partials(&partials);
+    // (template, literal text around the value)
+    let positions = [
+        ("<p>{[ v ]}</p>", "<p>", "</p>"),
+        ("<a title=\"{[ v ]}\">", "<a title=\"", "\">"),
+        ("<a title='{[ v? ]}'>", "<a title='", "'>"),
+        ("[{[ v! ]}]", "[", "]"),
+        (
+            "{[#each xs as x]}<li>{[ x ]}</li>{[/each]}",
+            "<li>",
+            "</li>",
+        ),
+        (
+            "{[#each m as k, x]}<dt>{[ k ]}</dt>{[/each]}",
+            "<dt>",
+            "</dt>",
+        ),
+        ("<b>{[!include /echo value=v ]}</b>", "<b>", "</b>"),
+        (
+            "<i>{[#include /wrap ]}{[ v ]}{[/include]}</i>",
+            "<i>",
+            "</i>",
+        ),
+    ];
+
+    for payload in &corpus.payloads {
+        let data = serde_json::json!({"v": payload, "xs": [payload], "m": {payload: 1}});
+        for (template, prefix, suffix) in positions {
+            let output = render_with_includes(template, data.clone(), dir.path())
+                .unwrap_or_else(|e| panic!("{template} failed for {payload:?}: {e}"));
+            let value = output
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+                .unwrap_or_else(|| panic!("{template} rendered {output:?} for {payload:?}"));
+            assert!(
+                !value.contains(['<', '>', '"', '\'']),
+                "{template} left markup in {value:?}"
+            );
+            assert_eq!(&unescape(value), payload, "{template} altered the payload");
+        }
+    }
+}
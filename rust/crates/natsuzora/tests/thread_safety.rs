@@ -0,0 +1,60 @@
+//! Thread-safety guarantees of the public API.
+//!
+//! A parsed [`Natsuzora`] is shared read-only between threads; every render
+//! builds its own context and include loader, so concurrent renders need no
+//! locking. Fragment caches synchronize internally.
+
+use natsuzora::prelude::*;
+use serde_json::json;
+use std::fs;
+use std::sync::Arc;
+use std::thread;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn public_types_are_send_and_sync() {
+    assert_send_sync::<Natsuzora>();
+    assert_send_sync::<Template>();
+    assert_send_sync::<LruFragmentCache>();
+    assert_send_sync::<EdgeIncludes>();
+    assert_send_sync::<NatsuzoraError>();
+}
+
+#[test]
+fn concurrent_renders_share_one_template() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("_item.ntzr"), "<li>{[ name ]}</li>").unwrap();
+    let tmpl = Arc::new(
+        Natsuzora::parse_with_includes(
+            "{[#cache key=id ]}{[ id ]}:{[/cache]}{[#each items as item]}{[!include /item name=item ]}{[/each]}",
+            dir.path(),
+        )
+        .unwrap(),
+    );
+    let cache = Arc::new(LruFragmentCache::new(8));
+
+    let handles: Vec<_> = (0..16)
+        .map(|worker| {
+            let tmpl = Arc::clone(&tmpl);
+            let cache = Arc::clone(&cache);
+            thread::spawn(move || {
+                for i in 0..200 {
+                    let id = (worker * 200 + i) % 32;
+                    let data = json!({"id": id, "items": [format!("w{worker}"), format!("i{i}")]});
+                    let expected = format!("{id}:<li>w{worker}</li><li>i{i}</li>");
+                    assert_eq!(tmpl.render(data.clone()).unwrap(), expected);
+                    assert_eq!(
+                        tmpl.render_with_fragment_cache(data, cache.as_ref())
+                            .unwrap(),
+                        expected
+                    );
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert!(cache.len() <= 8);
+}
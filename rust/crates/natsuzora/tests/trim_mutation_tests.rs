@@ -0,0 +1,315 @@
+//! Mutation tests for whitespace control (spec 4.3).
+//!
+//! Every tag in the shared fixtures (tests/*.json) is mutated by toggling a
+//! trim marker on one side. The mutant's output must equal the output of an
+//! oracle source where the trim was applied by hand at the source level:
+//!
+//! - `{[-`: remove the same-line trailing segment of the preceding text if
+//!   it consists of spaces/tabs only.
+//! - `-]}`: remove leading spaces/tabs of the following text plus one line
+//!   break, or the whole text if it is blank.
+//!
+//! Mutations are only generated where the neighbouring text is adjacent to
+//! the tag and not already claimed by a trim marker of another tag, so the
+//! oracle stays a direct transcription of the spec. Include tags are left
+//! out, since their markers also trim the partial's own text, and so are
+//! sides carrying or claimed by a `~` marker.
+
+use natsuzora::{render, render_with_includes};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Deserialize)]
+struct TestSuite {
+    tests: Vec<TestCase>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestCase {
+    name: String,
+    template: String,
+    data: serde_json::Value,
+    #[serde(default)]
+    expected: Option<String>,
+    #[serde(default)]
+    partials: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tag {
+    start: usize,
+    end: usize,
+}
+
+impl Tag {
+    fn has_left_trim(&self, source: &str) -> bool {
+        source[self.start + 2..].starts_with('-')
+    }
+
+    fn has_right_trim(&self, source: &str) -> bool {
+        source[..self.end - 2].ends_with('-')
+    }
+
+    fn has_tilde(&self, source: &str, side: Side) -> bool {
+        match side {
+            Side::Left => source[self.start + 2..].starts_with('~'),
+            Side::Right => source[..self.end - 2].ends_with('~'),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+fn get_tests_dir() -> PathBuf {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    PathBuf::from(manifest_dir)
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("tests")
+}
+
+fn setup_partials(partials: &HashMap<String, String>) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().expect("Failed to create temp dir");
+    for (name, content) in partials {
+        let segments: Vec<&str> = name.split('/').filter(|s| !s.is_empty()).collect();
+        let mut path = dir.path().to_path_buf();
+        for (i, seg) in segments.iter().enumerate() {
+            if i == segments.len() - 1 {
+                path.push(format!("_{seg}"));
+            } else {
+                path.push(seg);
+            }
+        }
+        path.set_extension("ntzr");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("Failed to create partial dir");
+        }
+        fs::write(&path, content).expect("Failed to write partial");
+    }
+    dir
+}
+
+/// Locate `{[ ... ]}` tags, skipping delimiter escapes and raw block bodies.
+fn scan_tags(source: &str) -> Vec<Tag> {
+    let mut tags = Vec::new();
+    let mut pos = 0;
+    let mut in_raw = false;
+    while let Some(offset) = source[pos..].find("{[") {
+        let start = pos + offset;
+        if !in_raw && source[start..].starts_with("{[{]}") {
+            pos = start + 5;
+            continue;
+        }
+        let Some(close) = source[start + 2..].find("]}") else {
+            break;
+        };
+        let end = start + 2 + close + 2;
+        if in_raw && !is_raw_tag(&source[start..end], '/') {
+            pos = start + 2;
+            continue;
+        }
+        in_raw = is_raw_tag(&source[start..end], '#');
+        tags.push(Tag { start, end });
+        pos = end;
+    }
+    tags
+}
+
+/// Whether `tag` is `{[#raw]}` (marker `#`) or `{[/raw]}` (marker `/`).
+fn is_raw_tag(tag: &str, marker: char) -> bool {
+    let inner = &tag[2..tag.len() - 2];
+    let inner = inner.strip_prefix(['-', '~']).unwrap_or(inner);
+    let inner = inner.strip_suffix(['-', '~']).unwrap_or(inner);
+    inner
+        .strip_prefix(marker)
+        .is_some_and(|rest| rest.trim_matches([' ', '\t', '\r', '\n']) == "raw")
+}
+
+fn with_marker(source: &str, tag: Tag, side: Side, present: bool) -> String {
+    let (has, at) = match side {
+        Side::Left => (tag.has_left_trim(source), tag.start + 2),
+        Side::Right => (tag.has_right_trim(source), tag.end - 2),
+    };
+    match (has, present) {
+        (false, true) => format!("{}-{}", &source[..at], &source[at..]),
+        (true, false) => match side {
+            Side::Left => format!("{}{}", &source[..at], &source[at + 1..]),
+            Side::Right => format!("{}{}", &source[..at - 1], &source[at..]),
+        },
+        _ => source.to_string(),
+    }
+}
+
+/// Whether `tag` opens an `!include` or `#include`.
+fn is_include_tag(tag: &str) -> bool {
+    let inner = tag[2..].trim_start_matches(['-', '~']);
+    let inner = inner.trim_start_matches([' ', '\t', '\r', '\n']);
+    inner.starts_with("!include") || inner.starts_with("#include")
+}
+
+/// Apply a trim by hand to `source` (which has no marker on that side).
+///
+/// Returns `None` when the mutation falls outside the oracle's scope.
+fn oracle(source: &str, index: usize, side: Side) -> Option<String> {
+    let tags = scan_tags(source);
+    let tag = tags[index];
+    if is_include_tag(&source[tag.start..tag.end]) || tag.has_tilde(source, side) {
+        return None;
+    }
+    match side {
+        Side::Left => {
+            let text_start = if index == 0 { 0 } else { tags[index - 1].end };
+            if text_start == tag.start {
+                return None;
+            }
+            if index > 0
+                && (tags[index - 1].has_right_trim(source)
+                    || tags[index - 1].has_tilde(source, Side::Right))
+            {
+                return None;
+            }
+            let text = &source[text_start..tag.start];
+            let line_start = text.rfind(['\n', '\r']).map_or(0, |i| i + 1);
+            if !text[line_start..].chars().all(|c| c == ' ' || c == '\t') {
+                return Some(source.to_string());
+            }
+            Some(format!(
+                "{}{}",
+                &source[..text_start + line_start],
+                &source[tag.start..]
+            ))
+        }
+        Side::Right => {
+            let text_end = tags.get(index + 1).map_or(source.len(), |t| t.start);
+            if text_end == tag.end {
+                return None;
+            }
+            if tags.get(index + 1).is_some_and(|next| {
+                next.has_left_trim(source) || next.has_tilde(source, Side::Left)
+            }) {
+                return None;
+            }
+            let text = &source[tag.end..text_end];
+            let blank = text.len() - text.trim_start_matches([' ', '\t']).len();
+            let rest = &text[blank..];
+            let removed = if rest.is_empty() {
+                blank
+            } else if rest.starts_with("\r\n") {
+                blank + 2
+            } else if rest.starts_with(['\n', '\r']) {
+                blank + 1
+            } else {
+                0
+            };
+            Some(format!(
+                "{}{}",
+                &source[..tag.end],
+                &source[tag.end + removed..]
+            ))
+        }
+    }
+}
+
+fn render_case(case: &TestCase, source: &str) -> Result<String, String> {
+    let result = if let Some(partials) = &case.partials {
+        let dir = setup_partials(partials);
+        render_with_includes(source, case.data.clone(), dir.path())
+    } else {
+        render(source, case.data.clone())
+    };
+    result.map_err(|e| format!("{e:?}"))
+}
+
+/// Returns (mutations checked, mutations that changed the source).
+fn check_case(case: &TestCase, failures: &mut Vec<String>) -> (usize, usize) {
+    let mut checked = 0;
+    let mut effective = 0;
+    let tag_count = scan_tags(&case.template).len();
+
+    for index in 0..tag_count {
+        for side in [Side::Left, Side::Right] {
+            let tag = scan_tags(&case.template)[index];
+            let without = with_marker(&case.template, tag, side, false);
+            let with = with_marker(&case.template, tag, side, true);
+            let Some(expected_source) = oracle(&without, index, side) else {
+                continue;
+            };
+
+            checked += 1;
+            if expected_source != without {
+                effective += 1;
+            }
+
+            let actual = render_case(case, &with);
+            let expected = render_case(case, &expected_source);
+            let consistent = match (&actual, &expected) {
+                (Ok(a), Ok(e)) => a == e,
+                (Err(_), Err(_)) => true,
+                _ => false,
+            };
+            if !consistent {
+                failures.push(format!(
+                    "'{}': {:?} trim on tag {} of {:?}\n  mutant: {:?} -> {:?}\n  oracle: {:?} -> {:?}",
+                    case.name, side, index, case.template, with, actual, expected_source, expected
+                ));
+            }
+        }
+    }
+
+    (checked, effective)
+}
+
+#[test]
+fn trim_markers_follow_spec_on_all_fixtures() {
+    let mut entries: Vec<PathBuf> = fs::read_dir(get_tests_dir())
+        .expect("Failed to read tests dir")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    entries.sort();
+
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    let mut effective = 0;
+
+    for path in entries {
+        let content = fs::read_to_string(&path).unwrap();
+        let suite: TestSuite = serde_json::from_str(&content)
+            .unwrap_or_else(|_| panic!("Failed to parse {}", path.display()));
+        for case in suite.tests.iter().filter(|c| c.expected.is_some()) {
+            let (c, e) = check_case(case, &mut failures);
+            checked += c;
+            effective += e;
+        }
+    }
+
+    eprintln!("trim mutations: {checked} checked, {effective} changed the source");
+    assert!(effective > 0, "no effective trim mutations were generated");
+    assert!(
+        failures.is_empty(),
+        "{} trim mutation(s) diverged from the spec:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn oracle_applies_left_and_right_rules() {
+    let source = "a\n  {[ x ]}  \nb";
+    assert_eq!(oracle(source, 0, Side::Left).unwrap(), "a\n{[ x ]}  \nb");
+    assert_eq!(oracle(source, 0, Side::Right).unwrap(), "a\n  {[ x ]}b");
+
+    let inline = "a {[ x ]} b";
+    assert_eq!(oracle(inline, 0, Side::Left).unwrap(), inline);
+    assert_eq!(oracle(inline, 0, Side::Right).unwrap(), inline);
+}
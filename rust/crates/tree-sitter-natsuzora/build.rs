@@ -0,0 +1,17 @@
+//! Compiles the generated parser of `tree-sitter/` at the repository root.
+
+fn main() {
+    let src_dir = std::path::Path::new("../../../tree-sitter/src");
+
+    let mut c_config = cc::Build::new();
+    c_config.std("c11").include(src_dir).warnings(false);
+
+    #[cfg(target_env = "msvc")]
+    c_config.flag("-utf-8");
+
+    let parser_path = src_dir.join("parser.c");
+    c_config.file(&parser_path);
+    println!("cargo:rerun-if-changed={}", parser_path.display());
+
+    c_config.compile("tree-sitter-natsuzora");
+}
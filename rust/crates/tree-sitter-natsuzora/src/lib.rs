@@ -0,0 +1,111 @@
+//! Tree-sitter parser for Natsuzora templates.
+//!
+//! Links the parser generated from `tree-sitter/grammar.js` and checks it
+//! against the node kinds natsuzora-ast expects. A parser generated from an
+//! older grammar still loads, but trees from it lack nodes tools map to the
+//! AST; call [`check_compatibility`] once at startup to fail with the kinds
+//! that differ instead of on the first unexpected node.
+//!
+//! ```rust
+//! let mut parser = tree_sitter::Parser::new();
+//! tree_sitter_natsuzora::check_compatibility(&tree_sitter_natsuzora::language()).unwrap();
+//! parser.set_language(&tree_sitter_natsuzora::language()).unwrap();
+//! let tree = parser.parse("Hello, {[ name ]}!", None).unwrap();
+//! assert!(!tree.root_node().has_error());
+//! ```
+
+use natsuzora_ast::GRAMMAR_KINDS;
+use std::collections::BTreeSet;
+use tree_sitter::Language;
+
+extern "C" {
+    fn tree_sitter_natsuzora() -> Language;
+}
+
+/// Version of the template language the grammar implements
+pub const GRAMMAR_VERSION: &str = "4.0";
+
+/// Contents of the grammar's `node-types.json`
+pub const NODE_TYPES: &str = include_str!("../../../../tree-sitter/src/node-types.json");
+
+/// Highlight query for editors
+pub const HIGHLIGHTS_QUERY: &str = include_str!("../../../../tree-sitter/queries/highlights.scm");
+
+/// The tree-sitter [`Language`] of the linked parser
+pub fn language() -> Language {
+    unsafe { tree_sitter_natsuzora() }
+}
+
+/// A parser whose named node kinds differ from natsuzora-ast's registry.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "tree-sitter parser does not match grammar {GRAMMAR_VERSION} \
+     (missing: [{}], unknown: [{}]); regenerate it with tree-sitter generate",
+    missing.join(", "),
+    unknown.join(", ")
+)]
+pub struct IncompatibleGrammar {
+    /// Kinds natsuzora-ast expects that the parser never produces
+    pub missing: Vec<String>,
+    /// Kinds the parser produces that natsuzora-ast does not classify
+    pub unknown: Vec<String>,
+}
+
+/// Check that `language` has exactly the named node kinds listed in
+/// [`natsuzora_ast::GRAMMAR_KINDS`]
+pub fn check_compatibility(language: &Language) -> Result<(), IncompatibleGrammar> {
+    let linked: BTreeSet<&str> = (0..language.node_kind_count() as u16)
+        .filter(|&id| language.node_kind_is_named(id) && language.node_kind_is_visible(id))
+        .filter_map(|id| language.node_kind_for_id(id))
+        .collect();
+    let expected: BTreeSet<&str> = GRAMMAR_KINDS.iter().map(|(name, _)| *name).collect();
+
+    let missing: Vec<String> = expected
+        .difference(&linked)
+        .map(|name| name.to_string())
+        .collect();
+    let unknown: Vec<String> = linked
+        .difference(&expected)
+        .map(|name| name.to_string())
+        .collect();
+    if missing.is_empty() && unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(IncompatibleGrammar { missing, unknown })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linked_parser_is_compatible() {
+        assert_eq!(check_compatibility(&language()), Ok(()));
+    }
+
+    #[test]
+    fn test_node_types_match_linked_parser() {
+        let language = language();
+        for (name, _) in GRAMMAR_KINDS {
+            assert!(
+                NODE_TYPES.contains(&format!("\"type\": \"{name}\"")),
+                "'{name}' is missing from NODE_TYPES"
+            );
+            assert_ne!(language.id_for_node_kind(name, true), 0, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_incompatible_grammar_message() {
+        let error = IncompatibleGrammar {
+            missing: vec!["filter".to_string()],
+            unknown: vec![],
+        };
+        assert_eq!(
+            error.to_string(),
+            "tree-sitter parser does not match grammar 4.0 (missing: [filter], unknown: []); \
+             regenerate it with tree-sitter generate"
+        );
+    }
+}
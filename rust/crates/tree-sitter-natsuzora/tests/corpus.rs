@@ -0,0 +1,73 @@
+//! The cases of `tree-sitter/test/corpus`, as `tree-sitter test` runs them.
+
+use std::fs;
+use std::path::Path;
+
+/// (file, name, input, expected tree) of every corpus case
+fn cases() -> Vec<(String, String, String, String)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../tree-sitter/test/corpus");
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("cannot read {}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+
+    let is_rule = |line: &str, c: char| line.len() >= 3 && line.chars().all(|l| l == c);
+    let mut cases = Vec::new();
+    for path in paths {
+        let file = path.file_name().unwrap().to_string_lossy().into_owned();
+        let content = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        let mut i = 0;
+        while i < lines.len() {
+            if !is_rule(lines[i], '=') {
+                i += 1;
+                continue;
+            }
+            let name = lines[i + 1].to_string();
+            i += 3;
+            let input_start = i;
+            while !is_rule(lines[i], '-') {
+                i += 1;
+            }
+            let input = lines[input_start..i].join("\n");
+            i += 1;
+            let expected_start = i;
+            while i < lines.len() && !is_rule(lines[i], '=') {
+                i += 1;
+            }
+            let expected = lines[expected_start..i].join("\n");
+            cases.push((file.clone(), name, input, expected));
+        }
+    }
+    cases
+}
+
+/// An S-expression with its whitespace collapsed
+fn normalize(tree: &str) -> String {
+    tree.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" )", ")")
+}
+
+#[test]
+fn corpus_cases_parse_as_expected() {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_natsuzora::language())
+        .unwrap();
+
+    let cases = cases();
+    assert!(!cases.is_empty());
+    let failures: Vec<String> = cases
+        .iter()
+        .filter_map(|(file, name, input, expected)| {
+            let tree = parser.parse(input, None).unwrap();
+            let actual = tree.root_node().to_sexp();
+            (normalize(&actual) != normalize(expected))
+                .then(|| format!("{file} / {name}:\n  expected {expected}\n  got {actual}"))
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
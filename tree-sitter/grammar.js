@@ -4,8 +4,14 @@
  * Changes from v2.0:
  * - Variable modifiers: {[ name? ]} (nullable), {[ name! ]} (required)
  * - Unsecure output: {[!unsecure path ]} (inline form)
- * - Include: {[!include /path key=value ]} (! prefix instead of >)
+ * - Include: {[!include /path key=value ...spread ]} (! prefix instead of >),
+ *   or {[!include block.template ]} named by data
  * - Comment: {[% ... ]} uses % prefix (unambiguous, handled inline)
+ * - Layouts: {[!extends /path ]} and {[#block name]} ... {[/block]}
+ * - Block include: {[#include /path ]} ... {[/include]} with {[!yield]}
+ * - Filters: {[ name | upper | truncate 20 ]}
+ * - Translation: {[@t greeting.hello name=user.name ]}
+ * - Attribute: {[@attr "disabled" form.locked ]}
  */
 
 module.exports = grammar({
@@ -16,20 +22,32 @@ module.exports = grammar({
   conflicts: $ => [
     [$.else_clause],
     [$.include_args],
+    [$.include_call_args],
+    [$._condition, $.comparison],
+    [$.each_open, $.path],
+    [$.filter],
   ],
 
   rules: {
     template: $ => repeat($._node),
 
     _node: $ => choice(
+      $.params,
       $.comment,
       $.delimiter_escape,
       $.if_block,
       $.unless_block,
       $.each_block,
       $.unsecure_block,
+      $.raw_block,
+      $.named_block,
+      $.include_block,
       $.unsecure_output,
       $.include,
+      $.extends,
+      $.yield,
+      $.translate,
+      $.attr,
       $.variable,
       $.text,
     ),
@@ -45,12 +63,13 @@ module.exports = grammar({
       ']',            // Single ] (GLR prefers ]} as tag_close due to prec)
     ),
 
-    // Variable: {[ path ]} or {[ path? ]} or {[ path! ]}
+    // Variable: {[ path ]} or {[ path? ]} or {[ path! ]}, then filters
     variable: $ => seq(
       $.tag_open,
       optional($._ws),
       $.path,
       optional($.modifier),
+      repeat($.filter),
       optional($._ws),
       $.tag_close,
     ),
@@ -58,6 +77,15 @@ module.exports = grammar({
     // Variable modifier: ? (nullable) or ! (required)
     modifier: _ => choice('?', '!'),
 
+    // Filter: | upper, | truncate 20 (names are checked by implementations)
+    filter: $ => seq(
+      optional($._ws),
+      '|',
+      optional($._ws),
+      $.identifier,
+      optional(seq($._ws, $.integer)),
+    ),
+
     // If block: {[#if expr]} ... {[#else]} ... {[/if]}
     if_block: $ => seq(
       $.if_open,
@@ -72,7 +100,7 @@ module.exports = grammar({
       optional($._ws),
       'if',
       $._ws,
-      $.path,
+      $._condition,
       optional($._ws),
       $.tag_close,
     ),
@@ -100,10 +128,11 @@ module.exports = grammar({
       $.tag_close,
     ),
 
-    // Unless block: {[#unless expr]} ... {[/unless]}
+    // Unless block: {[#unless expr]} ... {[#else]} ... {[/unless]}
     unless_block: $ => seq(
       $.unless_open,
       repeat($._node),
+      optional($.else_clause),
       $.unless_close,
     ),
 
@@ -113,7 +142,7 @@ module.exports = grammar({
       optional($._ws),
       'unless',
       $._ws,
-      $.path,
+      $._condition,
       optional($._ws),
       $.tag_close,
     ),
@@ -131,6 +160,7 @@ module.exports = grammar({
     each_block: $ => seq(
       $.each_open,
       repeat($._node),
+      optional($.else_clause),
       $.each_close,
     ),
 
@@ -140,16 +170,24 @@ module.exports = grammar({
       optional($._ws),
       'each',
       $._ws,
-      $.path,
-      $._ws,
-      'as',
-      $._ws,
-      $.identifier,
-      optional($.each_index),
+      choice(
+        seq(choice($.path, $.each_range), $._ws, 'as', $._ws, $.identifier, optional($.each_index)),
+        // Alternative form: {[#each item, index in expr]}
+        seq($.identifier, optional($.each_index), $._ws, 'in', $._ws, choice($.path, $.each_range)),
+      ),
+      optional($.each_meta),
+      repeat($.each_window),
       optional($._ws),
       $.tag_close,
     ),
 
+    // Inclusive integer range: 1..page.count
+    each_range: $ => seq(
+      choice($.path, $.integer),
+      '..',
+      choice($.path, $.integer),
+    ),
+
     each_index: $ => seq(
       optional($._ws),
       ',',
@@ -157,6 +195,24 @@ module.exports = grammar({
       $.identifier,
     ),
 
+    // Loop metadata binding: with loop
+    each_meta: $ => seq(
+      $._ws,
+      'with',
+      $._ws,
+      $.identifier,
+    ),
+
+    // Iteration range: limit=5 offset=page.start
+    each_window: $ => seq(
+      $._ws,
+      choice('limit', 'offset'),
+      optional($._ws),
+      '=',
+      optional($._ws),
+      choice($.path, $.integer),
+    ),
+
     each_close: $ => seq(
       $.tag_open,
       '/',
@@ -191,6 +247,95 @@ module.exports = grammar({
       $.tag_close,
     ),
 
+    // Raw block: {[#raw]} ... {[/raw]}, body output verbatim
+    raw_block: $ => seq(
+      $.raw_open,
+      optional($.raw_content),
+      $.raw_close,
+    ),
+
+    raw_open: $ => seq(
+      $.tag_open,
+      '#',
+      optional($._ws),
+      'raw',
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    // Approximation: the body ends at the first '{[/' (the lexers stop only
+    // at '{[/raw]}'), so raw bodies containing block closes do not parse here
+    raw_content: _ => token(prec(1, repeat1(choice(
+      /[^{]/,
+      /\{[^\[]/,
+      /\{\[[^\/\-~]/,
+      /\{\[[-~][^\/]/,
+    )))),
+
+    raw_close: $ => seq(
+      $.tag_open,
+      '/',
+      optional($._ws),
+      'raw',
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    // Layout block: {[#block name]} ... {[/block]}
+    named_block: $ => seq(
+      $.block_open,
+      repeat($._node),
+      $.block_close,
+    ),
+
+    block_open: $ => seq(
+      $.tag_open,
+      '#',
+      optional($._ws),
+      'block',
+      $._ws,
+      $.identifier,
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    block_close: $ => seq(
+      $.tag_open,
+      '/',
+      optional($._ws),
+      'block',
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    // Block include: {[#include /path key=value]} ... {[/include]}
+    include_block: $ => seq(
+      $.include_open,
+      repeat($._node),
+      $.include_close,
+    ),
+
+    include_open: $ => seq(
+      $.tag_open,
+      '#',
+      optional($._ws),
+      'include',
+      $._ws,
+      choice($.include_name, $.path),
+      optional($.include_call_args),
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    include_close: $ => seq(
+      $.tag_open,
+      '/',
+      optional($._ws),
+      'include',
+      optional($._ws),
+      $.tag_close,
+    ),
+
     // Unsecure output (inline): {[!unsecure path ]}
     unsecure_output: $ => seq(
       $.tag_open,
@@ -210,52 +355,170 @@ module.exports = grammar({
       optional($._ws),
       'include',
       $._ws,
+      choice($.include_name, $.path),
+      optional($.include_call_args),
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    // Extends: {[!extends /path/to/layout ]}
+    extends: $ => seq(
+      $.tag_open,
+      '!',
+      optional($._ws),
+      'extends',
+      $._ws,
       $.include_name,
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    // Body insertion point of a block include: {[!yield]}
+    yield: $ => seq(
+      $.tag_open,
+      '!',
+      optional($._ws),
+      'yield',
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    // Translation: {[@t message.key name=value ]}
+    translate: $ => seq(
+      $.tag_open,
+      '@t',
+      $._ws,
+      $.path,
       optional($.include_args),
       optional($._ws),
       $.tag_close,
     ),
 
+    // Conditional HTML attribute: {[@attr "name" value.path ]}
+    attr: $ => seq(
+      $.tag_open,
+      '@attr',
+      $._ws,
+      $.string,
+      $._ws,
+      $.path,
+      optional($._ws),
+      $.tag_close,
+    ),
+
     include_args: $ => repeat1(seq(
       $._ws,
       $.include_arg,
     )),
 
+    // Arguments of include tags, which may also spread an object: ...card
+    include_call_args: $ => repeat1(seq(
+      $._ws,
+      choice($.include_arg, $.spread_arg),
+    )),
+
+    spread_arg: $ => seq('...', $.path),
+
     include_arg: $ => seq(
       $.identifier,
       optional($._ws),
       '=',
       optional($._ws),
+      choice($.path, $.string, $.integer, $.boolean),
+    ),
+
+    // Condition of if/unless: path truthiness, comparison, or and/or/not
+    _condition: $ => choice(
       $.path,
+      $.comparison,
+      $.not_condition,
+      $.logical_condition,
     ),
 
+    // Precedence: not > and > or
+    not_condition: $ => prec(3, seq('not', $._ws, $._condition)),
+
+    logical_condition: $ => choice(
+      prec.left(2, seq(field('left', $._condition), $._ws, 'and', $._ws, field('right', $._condition))),
+      prec.left(1, seq(field('left', $._condition), $._ws, 'or', $._ws, field('right', $._condition))),
+    ),
+
+    comparison: $ => seq(
+      $.path,
+      optional($._ws),
+      $.comparison_operator,
+      optional($._ws),
+      choice($.path, $._literal),
+    ),
+
+    comparison_operator: _ => choice('==', '!=', '<=', '<', '>=', '>'),
+
+    _literal: $ => choice(
+      $.string,
+      $.integer,
+      $.boolean,
+      $.null,
+    ),
+
+    // String literal: only \" and \\ escapes
+    string: _ => token(seq('"', repeat(choice(/[^"\\]/, /\\["\\]/)), '"')),
+
+    integer: _ => /-?[0-9]+/,
+
+    boolean: _ => choice('true', 'false'),
+
+    null: _ => 'null',
+
     // Delimiter escape: {[{]} outputs literal {[
     delimiter_escape: _ => '{[{]}',
 
-    // Tag open/close with optional whitespace control
-    tag_open: _ => token(choice('{[', '{[-')),
-    tag_close: _ => token(choice(']}', '-]}')),
+    // Tag open/close with optional whitespace control (`~` also trims line breaks)
+    tag_open: _ => token(choice('{[', '{[-', '{[~')),
+    tag_close: _ => token(choice(']}', '-]}', '~]}')),
 
-    // Path: identifier.identifier.identifier
+    // Path: identifier.identifier.identifier, with array indexes after the first
     path: $ => seq(
       $.identifier,
-      repeat(seq('.', $.identifier)),
+      repeat(choice(
+        seq('.', choice($.identifier, $.index)),
+        seq('[', $.string, ']'),
+      )),
     ),
 
-    // Identifier
-    identifier: _ => /[A-Za-z][A-Za-z0-9_]*/,
+    // Array index segment: 0 or digits without a leading zero
+    index: _ => /0|[1-9][0-9]*/,
+
+    // Identifier: Unicode XID_Start followed by XID_Continue (e.g. 商品名)
+    identifier: _ => /[\p{XID_Start}][\p{XID_Continue}]*/,
 
     // Include name: /path/to/partial
     // Each segment must start with a letter (not digit or underscore)
     include_name: _ => /\/[A-Za-z][A-Za-z0-9_]*(\/[A-Za-z][A-Za-z0-9_]*)*/,
 
+    // Parameter header of a partial: {[%params title, actions? ]}
+    params: $ => seq(
+      $.params_open,
+      optional(seq(
+        $._ws,
+        $.param,
+        repeat(seq(optional($._ws), ',', optional($._ws), $.param)),
+      )),
+      optional($._ws),
+      $.tag_close,
+    ),
+
+    // Outranks comment, which would otherwise swallow the whole tag
+    params_open: _ => token(prec(1, seq(choice('{[', '{[-', '{[~'), '%params'))),
+
+    param: $ => seq($.identifier, optional('?')),
+
     // Comment: {[% ... ]} - % prefix is unambiguous, no external scanner needed
     comment: _ => token(
       seq(
-        choice('{[-', '{['),
+        choice('{[-', '{[~', '{['),
         '%',
         /([^\]]|\][^}])*/,
-        choice('-]}', ']}'),
+        choice('-]}', '~]}', ']}'),
       )
     ),
 
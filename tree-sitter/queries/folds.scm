@@ -5,6 +5,9 @@
 (unless_block) @fold
 (each_block) @fold
 (unsecure_block) @fold
+(raw_block) @fold
+(named_block) @fold
+(include_block) @fold
 
 ; Else clause can be folded separately
 (else_clause) @fold
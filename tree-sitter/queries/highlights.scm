@@ -2,6 +2,9 @@
 
 ; Comments
 (comment) @comment
+(params_open) @keyword
+(param (identifier) @variable.parameter)
+(param "?" @operator)
 
 ; Delimiter escape (outputs literal {[)
 (delimiter_escape) @string.escape
@@ -18,10 +21,17 @@
 (unless_close "unless" @keyword.conditional)
 (each_open "each" @keyword.repeat)
 (each_open "as" @keyword)
+(each_open "in" @keyword)
 (each_close "each" @keyword.repeat)
 (unsecure_open "unsecure" @keyword)
 (unsecure_close "unsecure" @keyword)
 (unsecure_output "unsecure" @keyword)
+(raw_open "raw" @keyword)
+(raw_close "raw" @keyword)
+(block_open "block" @keyword)
+(block_close "block" @keyword)
+(include_open "include" @keyword)
+(include_close "include" @keyword)
 
 ; Block markers
 (if_open "#" @punctuation.special)
@@ -29,21 +39,39 @@
 (unless_open "#" @punctuation.special)
 (each_open "#" @punctuation.special)
 (unsecure_open "#" @punctuation.special)
+(raw_open "#" @punctuation.special)
+(block_open "#" @punctuation.special)
+(include_open "#" @punctuation.special)
 (if_close "/" @punctuation.special)
 (unless_close "/" @punctuation.special)
 (each_close "/" @punctuation.special)
 (unsecure_close "/" @punctuation.special)
+(raw_close "/" @punctuation.special)
+(block_close "/" @punctuation.special)
+(include_close "/" @punctuation.special)
 
 ; Include and unsecure output markers
 (include "!" @punctuation.special)
 (include "include" @keyword)
 (unsecure_output "!" @punctuation.special)
+(extends "!" @punctuation.special)
+(extends "extends" @keyword)
+(yield "!" @punctuation.special)
+(yield "yield" @keyword)
+(translate "@t" @keyword)
+(attr "@attr" @keyword)
+(attr (string) @attribute)
 (include_name) @string.special
 
+; Block names
+(block_open
+  (identifier) @label)
+
 ; Include arguments
 (include_arg
   (identifier) @variable.parameter
   "=" @operator)
+(spread_arg "..." @operator)
 
 ; Variables and paths
 (variable
@@ -53,6 +81,11 @@
 ; Variable modifiers
 (modifier) @operator
 
+; Filters
+(filter
+  "|" @operator
+  (identifier) @function.builtin)
+
 (path
   "." @punctuation.delimiter)
 
@@ -62,12 +95,28 @@
 (each_index
   "," @punctuation.delimiter
   (identifier) @variable.parameter)
+(each_meta
+  "with" @keyword
+  (identifier) @variable.parameter)
+(each_window ["limit" "offset"] @property)
+(each_window "=" @operator)
+(each_range ".." @operator)
 
 ; Condition expressions
 (if_open
   (path (identifier) @variable))
 (unless_open
   (path (identifier) @variable))
+(comparison
+  (path (identifier) @variable))
+(comparison_operator) @operator
+(not_condition "not" @keyword.operator)
+(logical_condition ["and" "or"] @keyword.operator)
+(string) @string
+(integer) @number
+(index) @number
+(boolean) @boolean
+(null) @constant.builtin
 (each_open
   (path (identifier) @variable))
 
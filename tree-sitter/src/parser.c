@@ -5,15 +5,15 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 201
+#define STATE_COUNT 443
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 55
+#define SYMBOL_COUNT 118
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 25
+#define TOKEN_COUNT 56
 #define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 0
-#define MAX_ALIAS_SEQUENCE_LENGTH 13
-#define PRODUCTION_ID_COUNT 1
+#define FIELD_COUNT 2
+#define MAX_ALIAS_SEQUENCE_LENGTH 15
+#define PRODUCTION_ID_COUNT 2
 
 enum ts_symbol_identifiers {
   aux_sym__text_char_token1 = 1,
@@ -21,55 +21,118 @@ enum ts_symbol_identifiers {
   anon_sym_RBRACK = 3,
   anon_sym_QMARK = 4,
   anon_sym_BANG = 5,
-  anon_sym_POUND = 6,
-  anon_sym_if = 7,
-  anon_sym_SLASH = 8,
-  anon_sym_else = 9,
-  anon_sym_unless = 10,
-  anon_sym_each = 11,
-  anon_sym_as = 12,
-  anon_sym_COMMA = 13,
-  anon_sym_unsecure = 14,
-  anon_sym_include = 15,
-  anon_sym_EQ = 16,
-  sym_delimiter_escape = 17,
-  sym_tag_open = 18,
-  sym_tag_close = 19,
-  anon_sym_DOT = 20,
-  sym_identifier = 21,
-  sym_include_name = 22,
-  sym_comment = 23,
-  sym__ws = 24,
-  sym_template = 25,
-  sym__node = 26,
-  sym_text = 27,
-  sym__text_char = 28,
-  sym_variable = 29,
-  sym_modifier = 30,
-  sym_if_block = 31,
-  sym_if_open = 32,
-  sym_if_close = 33,
-  sym_else_clause = 34,
-  sym_else_open = 35,
-  sym_unless_block = 36,
-  sym_unless_open = 37,
-  sym_unless_close = 38,
-  sym_each_block = 39,
-  sym_each_open = 40,
-  sym_each_index = 41,
-  sym_each_close = 42,
-  sym_unsecure_block = 43,
-  sym_unsecure_open = 44,
-  sym_unsecure_close = 45,
-  sym_unsecure_output = 46,
-  sym_include = 47,
-  sym_include_args = 48,
-  sym_include_arg = 49,
-  sym_path = 50,
-  aux_sym_template_repeat1 = 51,
-  aux_sym_text_repeat1 = 52,
-  aux_sym_include_args_repeat1 = 53,
-  aux_sym_path_repeat1 = 54,
+  anon_sym_PIPE = 6,
+  anon_sym_POUND = 7,
+  anon_sym_if = 8,
+  anon_sym_SLASH = 9,
+  anon_sym_else = 10,
+  anon_sym_unless = 11,
+  anon_sym_each = 12,
+  anon_sym_as = 13,
+  anon_sym_in = 14,
+  anon_sym_DOT_DOT = 15,
+  anon_sym_COMMA = 16,
+  anon_sym_with = 17,
+  anon_sym_limit = 18,
+  anon_sym_offset = 19,
+  anon_sym_EQ = 20,
+  anon_sym_unsecure = 21,
+  anon_sym_raw = 22,
+  sym_raw_content = 23,
+  anon_sym_block = 24,
+  anon_sym_include = 25,
+  anon_sym_extends = 26,
+  anon_sym_yield = 27,
+  anon_sym_ATt = 28,
+  anon_sym_ATattr = 29,
+  anon_sym_DOT_DOT_DOT = 30,
+  anon_sym_not = 31,
+  anon_sym_and = 32,
+  anon_sym_or = 33,
+  anon_sym_EQ_EQ = 34,
+  anon_sym_BANG_EQ = 35,
+  anon_sym_LT_EQ = 36,
+  anon_sym_LT = 37,
+  anon_sym_GT_EQ = 38,
+  anon_sym_GT = 39,
+  sym_string = 40,
+  sym_integer = 41,
+  anon_sym_true = 42,
+  anon_sym_false = 43,
+  sym_null = 44,
+  sym_delimiter_escape = 45,
+  sym_tag_open = 46,
+  sym_tag_close = 47,
+  anon_sym_DOT = 48,
+  anon_sym_LBRACK = 49,
+  sym_index = 50,
+  sym_identifier = 51,
+  sym_include_name = 52,
+  sym_params_open = 53,
+  sym_comment = 54,
+  sym__ws = 55,
+  sym_template = 56,
+  sym__node = 57,
+  sym_text = 58,
+  sym__text_char = 59,
+  sym_variable = 60,
+  sym_modifier = 61,
+  sym_filter = 62,
+  sym_if_block = 63,
+  sym_if_open = 64,
+  sym_if_close = 65,
+  sym_else_clause = 66,
+  sym_else_open = 67,
+  sym_unless_block = 68,
+  sym_unless_open = 69,
+  sym_unless_close = 70,
+  sym_each_block = 71,
+  sym_each_open = 72,
+  sym_each_range = 73,
+  sym_each_index = 74,
+  sym_each_meta = 75,
+  sym_each_window = 76,
+  sym_each_close = 77,
+  sym_unsecure_block = 78,
+  sym_unsecure_open = 79,
+  sym_unsecure_close = 80,
+  sym_raw_block = 81,
+  sym_raw_open = 82,
+  sym_raw_close = 83,
+  sym_named_block = 84,
+  sym_block_open = 85,
+  sym_block_close = 86,
+  sym_include_block = 87,
+  sym_include_open = 88,
+  sym_include_close = 89,
+  sym_unsecure_output = 90,
+  sym_include = 91,
+  sym_extends = 92,
+  sym_yield = 93,
+  sym_translate = 94,
+  sym_attr = 95,
+  sym_include_args = 96,
+  sym_include_call_args = 97,
+  sym_spread_arg = 98,
+  sym_include_arg = 99,
+  sym__condition = 100,
+  sym_not_condition = 101,
+  sym_logical_condition = 102,
+  sym_comparison = 103,
+  sym_comparison_operator = 104,
+  sym__literal = 105,
+  sym_boolean = 106,
+  sym_path = 107,
+  sym_params = 108,
+  sym_param = 109,
+  aux_sym_template_repeat1 = 110,
+  aux_sym_text_repeat1 = 111,
+  aux_sym_variable_repeat1 = 112,
+  aux_sym_each_open_repeat1 = 113,
+  aux_sym_include_args_repeat1 = 114,
+  aux_sym_include_call_args_repeat1 = 115,
+  aux_sym_path_repeat1 = 116,
+  aux_sym_params_repeat1 = 117,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -79,6 +142,7 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_RBRACK] = "]",
   [anon_sym_QMARK] = "\?",
   [anon_sym_BANG] = "!",
+  [anon_sym_PIPE] = "|",
   [anon_sym_POUND] = "#",
   [anon_sym_if] = "if",
   [anon_sym_SLASH] = "/",
@@ -86,16 +150,46 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_unless] = "unless",
   [anon_sym_each] = "each",
   [anon_sym_as] = "as",
+  [anon_sym_in] = "in",
+  [anon_sym_DOT_DOT] = "..",
   [anon_sym_COMMA] = ",",
+  [anon_sym_with] = "with",
+  [anon_sym_limit] = "limit",
+  [anon_sym_offset] = "offset",
+  [anon_sym_EQ] = "=",
   [anon_sym_unsecure] = "unsecure",
+  [anon_sym_raw] = "raw",
+  [sym_raw_content] = "raw_content",
+  [anon_sym_block] = "block",
   [anon_sym_include] = "include",
-  [anon_sym_EQ] = "=",
+  [anon_sym_extends] = "extends",
+  [anon_sym_yield] = "yield",
+  [anon_sym_ATt] = "@t",
+  [anon_sym_ATattr] = "@attr",
+  [anon_sym_DOT_DOT_DOT] = "...",
+  [anon_sym_not] = "not",
+  [anon_sym_and] = "and",
+  [anon_sym_or] = "or",
+  [anon_sym_EQ_EQ] = "==",
+  [anon_sym_BANG_EQ] = "!=",
+  [anon_sym_LT_EQ] = "<=",
+  [anon_sym_LT] = "<",
+  [anon_sym_GT_EQ] = ">=",
+  [anon_sym_GT] = ">",
+  [sym_string] = "string",
+  [sym_integer] = "integer",
+  [anon_sym_true] = "true",
+  [anon_sym_false] = "false",
+  [sym_null] = "null",
   [sym_delimiter_escape] = "delimiter_escape",
   [sym_tag_open] = "tag_open",
   [sym_tag_close] = "tag_close",
   [anon_sym_DOT] = ".",
+  [anon_sym_LBRACK] = "[",
+  [sym_index] = "index",
   [sym_identifier] = "identifier",
   [sym_include_name] = "include_name",
+  [sym_params_open] = "params_open",
   [sym_comment] = "comment",
   [sym__ws] = "_ws",
   [sym_template] = "template",
@@ -104,6 +198,7 @@ static const char * const ts_symbol_names[] = {
   [sym__text_char] = "_text_char",
   [sym_variable] = "variable",
   [sym_modifier] = "modifier",
+  [sym_filter] = "filter",
   [sym_if_block] = "if_block",
   [sym_if_open] = "if_open",
   [sym_if_close] = "if_close",
@@ -114,20 +209,51 @@ static const char * const ts_symbol_names[] = {
   [sym_unless_close] = "unless_close",
   [sym_each_block] = "each_block",
   [sym_each_open] = "each_open",
+  [sym_each_range] = "each_range",
   [sym_each_index] = "each_index",
+  [sym_each_meta] = "each_meta",
+  [sym_each_window] = "each_window",
   [sym_each_close] = "each_close",
   [sym_unsecure_block] = "unsecure_block",
   [sym_unsecure_open] = "unsecure_open",
   [sym_unsecure_close] = "unsecure_close",
+  [sym_raw_block] = "raw_block",
+  [sym_raw_open] = "raw_open",
+  [sym_raw_close] = "raw_close",
+  [sym_named_block] = "named_block",
+  [sym_block_open] = "block_open",
+  [sym_block_close] = "block_close",
+  [sym_include_block] = "include_block",
+  [sym_include_open] = "include_open",
+  [sym_include_close] = "include_close",
   [sym_unsecure_output] = "unsecure_output",
   [sym_include] = "include",
+  [sym_extends] = "extends",
+  [sym_yield] = "yield",
+  [sym_translate] = "translate",
+  [sym_attr] = "attr",
   [sym_include_args] = "include_args",
+  [sym_include_call_args] = "include_call_args",
+  [sym_spread_arg] = "spread_arg",
   [sym_include_arg] = "include_arg",
+  [sym__condition] = "_condition",
+  [sym_not_condition] = "not_condition",
+  [sym_logical_condition] = "logical_condition",
+  [sym_comparison] = "comparison",
+  [sym_comparison_operator] = "comparison_operator",
+  [sym__literal] = "_literal",
+  [sym_boolean] = "boolean",
   [sym_path] = "path",
+  [sym_params] = "params",
+  [sym_param] = "param",
   [aux_sym_template_repeat1] = "template_repeat1",
   [aux_sym_text_repeat1] = "text_repeat1",
+  [aux_sym_variable_repeat1] = "variable_repeat1",
+  [aux_sym_each_open_repeat1] = "each_open_repeat1",
   [aux_sym_include_args_repeat1] = "include_args_repeat1",
+  [aux_sym_include_call_args_repeat1] = "include_call_args_repeat1",
   [aux_sym_path_repeat1] = "path_repeat1",
+  [aux_sym_params_repeat1] = "params_repeat1",
 };
 
 static const TSSymbol ts_symbol_map[] = {
@@ -137,6 +263,7 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_RBRACK] = anon_sym_RBRACK,
   [anon_sym_QMARK] = anon_sym_QMARK,
   [anon_sym_BANG] = anon_sym_BANG,
+  [anon_sym_PIPE] = anon_sym_PIPE,
   [anon_sym_POUND] = anon_sym_POUND,
   [anon_sym_if] = anon_sym_if,
   [anon_sym_SLASH] = anon_sym_SLASH,
@@ -144,16 +271,46 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_unless] = anon_sym_unless,
   [anon_sym_each] = anon_sym_each,
   [anon_sym_as] = anon_sym_as,
+  [anon_sym_in] = anon_sym_in,
+  [anon_sym_DOT_DOT] = anon_sym_DOT_DOT,
   [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_with] = anon_sym_with,
+  [anon_sym_limit] = anon_sym_limit,
+  [anon_sym_offset] = anon_sym_offset,
+  [anon_sym_EQ] = anon_sym_EQ,
   [anon_sym_unsecure] = anon_sym_unsecure,
+  [anon_sym_raw] = anon_sym_raw,
+  [sym_raw_content] = sym_raw_content,
+  [anon_sym_block] = anon_sym_block,
   [anon_sym_include] = anon_sym_include,
-  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_extends] = anon_sym_extends,
+  [anon_sym_yield] = anon_sym_yield,
+  [anon_sym_ATt] = anon_sym_ATt,
+  [anon_sym_ATattr] = anon_sym_ATattr,
+  [anon_sym_DOT_DOT_DOT] = anon_sym_DOT_DOT_DOT,
+  [anon_sym_not] = anon_sym_not,
+  [anon_sym_and] = anon_sym_and,
+  [anon_sym_or] = anon_sym_or,
+  [anon_sym_EQ_EQ] = anon_sym_EQ_EQ,
+  [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
+  [anon_sym_LT_EQ] = anon_sym_LT_EQ,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_GT_EQ] = anon_sym_GT_EQ,
+  [anon_sym_GT] = anon_sym_GT,
+  [sym_string] = sym_string,
+  [sym_integer] = sym_integer,
+  [anon_sym_true] = anon_sym_true,
+  [anon_sym_false] = anon_sym_false,
+  [sym_null] = sym_null,
   [sym_delimiter_escape] = sym_delimiter_escape,
   [sym_tag_open] = sym_tag_open,
   [sym_tag_close] = sym_tag_close,
   [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [sym_index] = sym_index,
   [sym_identifier] = sym_identifier,
   [sym_include_name] = sym_include_name,
+  [sym_params_open] = sym_params_open,
   [sym_comment] = sym_comment,
   [sym__ws] = sym__ws,
   [sym_template] = sym_template,
@@ -162,6 +319,7 @@ static const TSSymbol ts_symbol_map[] = {
   [sym__text_char] = sym__text_char,
   [sym_variable] = sym_variable,
   [sym_modifier] = sym_modifier,
+  [sym_filter] = sym_filter,
   [sym_if_block] = sym_if_block,
   [sym_if_open] = sym_if_open,
   [sym_if_close] = sym_if_close,
@@ -172,20 +330,51 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_unless_close] = sym_unless_close,
   [sym_each_block] = sym_each_block,
   [sym_each_open] = sym_each_open,
+  [sym_each_range] = sym_each_range,
   [sym_each_index] = sym_each_index,
+  [sym_each_meta] = sym_each_meta,
+  [sym_each_window] = sym_each_window,
   [sym_each_close] = sym_each_close,
   [sym_unsecure_block] = sym_unsecure_block,
   [sym_unsecure_open] = sym_unsecure_open,
   [sym_unsecure_close] = sym_unsecure_close,
+  [sym_raw_block] = sym_raw_block,
+  [sym_raw_open] = sym_raw_open,
+  [sym_raw_close] = sym_raw_close,
+  [sym_named_block] = sym_named_block,
+  [sym_block_open] = sym_block_open,
+  [sym_block_close] = sym_block_close,
+  [sym_include_block] = sym_include_block,
+  [sym_include_open] = sym_include_open,
+  [sym_include_close] = sym_include_close,
   [sym_unsecure_output] = sym_unsecure_output,
   [sym_include] = sym_include,
+  [sym_extends] = sym_extends,
+  [sym_yield] = sym_yield,
+  [sym_translate] = sym_translate,
+  [sym_attr] = sym_attr,
   [sym_include_args] = sym_include_args,
+  [sym_include_call_args] = sym_include_call_args,
+  [sym_spread_arg] = sym_spread_arg,
   [sym_include_arg] = sym_include_arg,
+  [sym__condition] = sym__condition,
+  [sym_not_condition] = sym_not_condition,
+  [sym_logical_condition] = sym_logical_condition,
+  [sym_comparison] = sym_comparison,
+  [sym_comparison_operator] = sym_comparison_operator,
+  [sym__literal] = sym__literal,
+  [sym_boolean] = sym_boolean,
   [sym_path] = sym_path,
+  [sym_params] = sym_params,
+  [sym_param] = sym_param,
   [aux_sym_template_repeat1] = aux_sym_template_repeat1,
   [aux_sym_text_repeat1] = aux_sym_text_repeat1,
+  [aux_sym_variable_repeat1] = aux_sym_variable_repeat1,
+  [aux_sym_each_open_repeat1] = aux_sym_each_open_repeat1,
   [aux_sym_include_args_repeat1] = aux_sym_include_args_repeat1,
+  [aux_sym_include_call_args_repeat1] = aux_sym_include_call_args_repeat1,
   [aux_sym_path_repeat1] = aux_sym_path_repeat1,
+  [aux_sym_params_repeat1] = aux_sym_params_repeat1,
 };
 
 static const TSSymbolMetadata ts_symbol_metadata[] = {
@@ -213,6 +402,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_PIPE] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_POUND] = {
     .visible = true,
     .named = false,
@@ -241,22 +434,130 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_in] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT_DOT] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_COMMA] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_with] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_limit] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_offset] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_unsecure] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_raw] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_raw_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_block] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_include] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_EQ] = {
+  [anon_sym_extends] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_yield] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATt] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATattr] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT_DOT_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_not] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_and] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_or] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_integer] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_true] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_false] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_null] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_delimiter_escape] = {
     .visible = true,
     .named = true,
@@ -273,6 +574,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_index] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_identifier] = {
     .visible = true,
     .named = true,
@@ -281,6 +590,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_params_open] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_comment] = {
     .visible = true,
     .named = true,
@@ -313,6 +626,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_filter] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_if_block] = {
     .visible = true,
     .named = true,
@@ -353,10 +670,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_each_range] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_each_index] = {
     .visible = true,
     .named = true,
   },
+  [sym_each_meta] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_each_window] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_each_close] = {
     .visible = true,
     .named = true,
@@ -373,6 +702,42 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_raw_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_raw_open] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_raw_close] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_named_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block_open] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block_close] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_include_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_include_open] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_include_close] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_unsecure_output] = {
     .visible = true,
     .named = true,
@@ -381,18 +746,78 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_extends] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_yield] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_translate] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attr] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_include_args] = {
     .visible = true,
     .named = true,
   },
+  [sym_include_call_args] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_spread_arg] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_include_arg] = {
     .visible = true,
     .named = true,
   },
+  [sym__condition] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_not_condition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_logical_condition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comparison] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comparison_operator] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__literal] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_boolean] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_path] = {
     .visible = true,
     .named = true,
   },
+  [sym_params] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_param] = {
+    .visible = true,
+    .named = true,
+  },
   [aux_sym_template_repeat1] = {
     .visible = false,
     .named = false,
@@ -401,14 +826,51 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_variable_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_each_open_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_include_args_repeat1] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_include_call_args_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_path_repeat1] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_params_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_left = 1,
+  field_right = 2,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_left] = "left",
+  [field_right] = "right",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 2},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_left, 0},
+    {field_right, 4},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -621,6 +1083,432 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [198] = 198,
   [199] = 199,
   [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 368,
+  [369] = 369,
+  [370] = 370,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 378,
+  [379] = 379,
+  [380] = 380,
+  [381] = 381,
+  [382] = 382,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 410,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 415,
+  [416] = 416,
+  [417] = 417,
+  [418] = 418,
+  [419] = 419,
+  [420] = 420,
+  [421] = 421,
+  [422] = 422,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 430,
+  [431] = 431,
+  [432] = 432,
+  [433] = 433,
+  [434] = 434,
+  [435] = 435,
+  [436] = 436,
+  [437] = 437,
+  [438] = 438,
+  [439] = 439,
+  [440] = 440,
+  [441] = 441,
+  [442] = 442,
+};
+
+static TSCharacterRange sym_identifier_character_set_1[] = {
+  {'A', 'Z'}, {'a', 'z'}, {0xaa, 0xaa}, {0xb5, 0xb5}, {0xba, 0xba}, {0xc0, 0xd6}, {0xd8, 0xf6}, {0xf8, 0x2c1},
+  {0x2c6, 0x2d1}, {0x2e0, 0x2e4}, {0x2ec, 0x2ec}, {0x2ee, 0x2ee}, {0x370, 0x374}, {0x376, 0x377}, {0x37b, 0x37d}, {0x37f, 0x37f},
+  {0x386, 0x386}, {0x388, 0x38a}, {0x38c, 0x38c}, {0x38e, 0x3a1}, {0x3a3, 0x3f5}, {0x3f7, 0x481}, {0x48a, 0x52f}, {0x531, 0x556},
+  {0x559, 0x559}, {0x560, 0x588}, {0x5d0, 0x5ea}, {0x5ef, 0x5f2}, {0x620, 0x64a}, {0x66e, 0x66f}, {0x671, 0x6d3}, {0x6d5, 0x6d5},
+  {0x6e5, 0x6e6}, {0x6ee, 0x6ef}, {0x6fa, 0x6fc}, {0x6ff, 0x6ff}, {0x710, 0x710}, {0x712, 0x72f}, {0x74d, 0x7a5}, {0x7b1, 0x7b1},
+  {0x7ca, 0x7ea}, {0x7f4, 0x7f5}, {0x7fa, 0x7fa}, {0x800, 0x815}, {0x81a, 0x81a}, {0x824, 0x824}, {0x828, 0x828}, {0x840, 0x858},
+  {0x860, 0x86a}, {0x870, 0x887}, {0x889, 0x88e}, {0x8a0, 0x8c9}, {0x904, 0x939}, {0x93d, 0x93d}, {0x950, 0x950}, {0x958, 0x961},
+  {0x971, 0x980}, {0x985, 0x98c}, {0x98f, 0x990}, {0x993, 0x9a8}, {0x9aa, 0x9b0}, {0x9b2, 0x9b2}, {0x9b6, 0x9b9}, {0x9bd, 0x9bd},
+  {0x9ce, 0x9ce}, {0x9dc, 0x9dd}, {0x9df, 0x9e1}, {0x9f0, 0x9f1}, {0x9fc, 0x9fc}, {0xa05, 0xa0a}, {0xa0f, 0xa10}, {0xa13, 0xa28},
+  {0xa2a, 0xa30}, {0xa32, 0xa33}, {0xa35, 0xa36}, {0xa38, 0xa39}, {0xa59, 0xa5c}, {0xa5e, 0xa5e}, {0xa72, 0xa74}, {0xa85, 0xa8d},
+  {0xa8f, 0xa91}, {0xa93, 0xaa8}, {0xaaa, 0xab0}, {0xab2, 0xab3}, {0xab5, 0xab9}, {0xabd, 0xabd}, {0xad0, 0xad0}, {0xae0, 0xae1},
+  {0xaf9, 0xaf9}, {0xb05, 0xb0c}, {0xb0f, 0xb10}, {0xb13, 0xb28}, {0xb2a, 0xb30}, {0xb32, 0xb33}, {0xb35, 0xb39}, {0xb3d, 0xb3d},
+  {0xb5c, 0xb5d}, {0xb5f, 0xb61}, {0xb71, 0xb71}, {0xb83, 0xb83}, {0xb85, 0xb8a}, {0xb8e, 0xb90}, {0xb92, 0xb95}, {0xb99, 0xb9a},
+  {0xb9c, 0xb9c}, {0xb9e, 0xb9f}, {0xba3, 0xba4}, {0xba8, 0xbaa}, {0xbae, 0xbb9}, {0xbd0, 0xbd0}, {0xc05, 0xc0c}, {0xc0e, 0xc10},
+  {0xc12, 0xc28}, {0xc2a, 0xc39}, {0xc3d, 0xc3d}, {0xc58, 0xc5a}, {0xc5d, 0xc5d}, {0xc60, 0xc61}, {0xc80, 0xc80}, {0xc85, 0xc8c},
+  {0xc8e, 0xc90}, {0xc92, 0xca8}, {0xcaa, 0xcb3}, {0xcb5, 0xcb9}, {0xcbd, 0xcbd}, {0xcdd, 0xcde}, {0xce0, 0xce1}, {0xcf1, 0xcf2},
+  {0xd04, 0xd0c}, {0xd0e, 0xd10}, {0xd12, 0xd3a}, {0xd3d, 0xd3d}, {0xd4e, 0xd4e}, {0xd54, 0xd56}, {0xd5f, 0xd61}, {0xd7a, 0xd7f},
+  {0xd85, 0xd96}, {0xd9a, 0xdb1}, {0xdb3, 0xdbb}, {0xdbd, 0xdbd}, {0xdc0, 0xdc6}, {0xe01, 0xe30}, {0xe32, 0xe32}, {0xe40, 0xe46},
+  {0xe81, 0xe82}, {0xe84, 0xe84}, {0xe86, 0xe8a}, {0xe8c, 0xea3}, {0xea5, 0xea5}, {0xea7, 0xeb0}, {0xeb2, 0xeb2}, {0xebd, 0xebd},
+  {0xec0, 0xec4}, {0xec6, 0xec6}, {0xedc, 0xedf}, {0xf00, 0xf00}, {0xf40, 0xf47}, {0xf49, 0xf6c}, {0xf88, 0xf8c}, {0x1000, 0x102a},
+  {0x103f, 0x103f}, {0x1050, 0x1055}, {0x105a, 0x105d}, {0x1061, 0x1061}, {0x1065, 0x1066}, {0x106e, 0x1070}, {0x1075, 0x1081}, {0x108e, 0x108e},
+  {0x10a0, 0x10c5}, {0x10c7, 0x10c7}, {0x10cd, 0x10cd}, {0x10d0, 0x10fa}, {0x10fc, 0x1248}, {0x124a, 0x124d}, {0x1250, 0x1256}, {0x1258, 0x1258},
+  {0x125a, 0x125d}, {0x1260, 0x1288}, {0x128a, 0x128d}, {0x1290, 0x12b0}, {0x12b2, 0x12b5}, {0x12b8, 0x12be}, {0x12c0, 0x12c0}, {0x12c2, 0x12c5},
+  {0x12c8, 0x12d6}, {0x12d8, 0x1310}, {0x1312, 0x1315}, {0x1318, 0x135a}, {0x1380, 0x138f}, {0x13a0, 0x13f5}, {0x13f8, 0x13fd}, {0x1401, 0x166c},
+  {0x166f, 0x167f}, {0x1681, 0x169a}, {0x16a0, 0x16ea}, {0x16ee, 0x16f8}, {0x1700, 0x1711}, {0x171f, 0x1731}, {0x1740, 0x1751}, {0x1760, 0x176c},
+  {0x176e, 0x1770}, {0x1780, 0x17b3}, {0x17d7, 0x17d7}, {0x17dc, 0x17dc}, {0x1820, 0x1878}, {0x1880, 0x18a8}, {0x18aa, 0x18aa}, {0x18b0, 0x18f5},
+  {0x1900, 0x191e}, {0x1950, 0x196d}, {0x1970, 0x1974}, {0x1980, 0x19ab}, {0x19b0, 0x19c9}, {0x1a00, 0x1a16}, {0x1a20, 0x1a54}, {0x1aa7, 0x1aa7},
+  {0x1b05, 0x1b33}, {0x1b45, 0x1b4c}, {0x1b83, 0x1ba0}, {0x1bae, 0x1baf}, {0x1bba, 0x1be5}, {0x1c00, 0x1c23}, {0x1c4d, 0x1c4f}, {0x1c5a, 0x1c7d},
+  {0x1c80, 0x1c88}, {0x1c90, 0x1cba}, {0x1cbd, 0x1cbf}, {0x1ce9, 0x1cec}, {0x1cee, 0x1cf3}, {0x1cf5, 0x1cf6}, {0x1cfa, 0x1cfa}, {0x1d00, 0x1dbf},
+  {0x1e00, 0x1f15}, {0x1f18, 0x1f1d}, {0x1f20, 0x1f45}, {0x1f48, 0x1f4d}, {0x1f50, 0x1f57}, {0x1f59, 0x1f59}, {0x1f5b, 0x1f5b}, {0x1f5d, 0x1f5d},
+  {0x1f5f, 0x1f7d}, {0x1f80, 0x1fb4}, {0x1fb6, 0x1fbc}, {0x1fbe, 0x1fbe}, {0x1fc2, 0x1fc4}, {0x1fc6, 0x1fcc}, {0x1fd0, 0x1fd3}, {0x1fd6, 0x1fdb},
+  {0x1fe0, 0x1fec}, {0x1ff2, 0x1ff4}, {0x1ff6, 0x1ffc}, {0x2071, 0x2071}, {0x207f, 0x207f}, {0x2090, 0x209c}, {0x2102, 0x2102}, {0x2107, 0x2107},
+  {0x210a, 0x2113}, {0x2115, 0x2115}, {0x2118, 0x211d}, {0x2124, 0x2124}, {0x2126, 0x2126}, {0x2128, 0x2128}, {0x212a, 0x2139}, {0x213c, 0x213f},
+  {0x2145, 0x2149}, {0x214e, 0x214e}, {0x2160, 0x2188}, {0x2c00, 0x2ce4}, {0x2ceb, 0x2cee}, {0x2cf2, 0x2cf3}, {0x2d00, 0x2d25}, {0x2d27, 0x2d27},
+  {0x2d2d, 0x2d2d}, {0x2d30, 0x2d67}, {0x2d6f, 0x2d6f}, {0x2d80, 0x2d96}, {0x2da0, 0x2da6}, {0x2da8, 0x2dae}, {0x2db0, 0x2db6}, {0x2db8, 0x2dbe},
+  {0x2dc0, 0x2dc6}, {0x2dc8, 0x2dce}, {0x2dd0, 0x2dd6}, {0x2dd8, 0x2dde}, {0x3005, 0x3007}, {0x3021, 0x3029}, {0x3031, 0x3035}, {0x3038, 0x303c},
+  {0x3041, 0x3096}, {0x309d, 0x309f}, {0x30a1, 0x30fa}, {0x30fc, 0x30ff}, {0x3105, 0x312f}, {0x3131, 0x318e}, {0x31a0, 0x31bf}, {0x31f0, 0x31ff},
+  {0x3400, 0x4dbf}, {0x4e00, 0xa48c}, {0xa4d0, 0xa4fd}, {0xa500, 0xa60c}, {0xa610, 0xa61f}, {0xa62a, 0xa62b}, {0xa640, 0xa66e}, {0xa67f, 0xa69d},
+  {0xa6a0, 0xa6ef}, {0xa717, 0xa71f}, {0xa722, 0xa788}, {0xa78b, 0xa7ca}, {0xa7d0, 0xa7d1}, {0xa7d3, 0xa7d3}, {0xa7d5, 0xa7d9}, {0xa7f2, 0xa801},
+  {0xa803, 0xa805}, {0xa807, 0xa80a}, {0xa80c, 0xa822}, {0xa840, 0xa873}, {0xa882, 0xa8b3}, {0xa8f2, 0xa8f7}, {0xa8fb, 0xa8fb}, {0xa8fd, 0xa8fe},
+  {0xa90a, 0xa925}, {0xa930, 0xa946}, {0xa960, 0xa97c}, {0xa984, 0xa9b2}, {0xa9cf, 0xa9cf}, {0xa9e0, 0xa9e4}, {0xa9e6, 0xa9ef}, {0xa9fa, 0xa9fe},
+  {0xaa00, 0xaa28}, {0xaa40, 0xaa42}, {0xaa44, 0xaa4b}, {0xaa60, 0xaa76}, {0xaa7a, 0xaa7a}, {0xaa7e, 0xaaaf}, {0xaab1, 0xaab1}, {0xaab5, 0xaab6},
+  {0xaab9, 0xaabd}, {0xaac0, 0xaac0}, {0xaac2, 0xaac2}, {0xaadb, 0xaadd}, {0xaae0, 0xaaea}, {0xaaf2, 0xaaf4}, {0xab01, 0xab06}, {0xab09, 0xab0e},
+  {0xab11, 0xab16}, {0xab20, 0xab26}, {0xab28, 0xab2e}, {0xab30, 0xab5a}, {0xab5c, 0xab69}, {0xab70, 0xabe2}, {0xac00, 0xd7a3}, {0xd7b0, 0xd7c6},
+  {0xd7cb, 0xd7fb}, {0xf900, 0xfa6d}, {0xfa70, 0xfad9}, {0xfb00, 0xfb06}, {0xfb13, 0xfb17}, {0xfb1d, 0xfb1d}, {0xfb1f, 0xfb28}, {0xfb2a, 0xfb36},
+  {0xfb38, 0xfb3c}, {0xfb3e, 0xfb3e}, {0xfb40, 0xfb41}, {0xfb43, 0xfb44}, {0xfb46, 0xfbb1}, {0xfbd3, 0xfc5d}, {0xfc64, 0xfd3d}, {0xfd50, 0xfd8f},
+  {0xfd92, 0xfdc7}, {0xfdf0, 0xfdf9}, {0xfe71, 0xfe71}, {0xfe73, 0xfe73}, {0xfe77, 0xfe77}, {0xfe79, 0xfe79}, {0xfe7b, 0xfe7b}, {0xfe7d, 0xfe7d},
+  {0xfe7f, 0xfefc}, {0xff21, 0xff3a}, {0xff41, 0xff5a}, {0xff66, 0xff9d}, {0xffa0, 0xffbe}, {0xffc2, 0xffc7}, {0xffca, 0xffcf}, {0xffd2, 0xffd7},
+  {0xffda, 0xffdc}, {0x10000, 0x1000b}, {0x1000d, 0x10026}, {0x10028, 0x1003a}, {0x1003c, 0x1003d}, {0x1003f, 0x1004d}, {0x10050, 0x1005d}, {0x10080, 0x100fa},
+  {0x10140, 0x10174}, {0x10280, 0x1029c}, {0x102a0, 0x102d0}, {0x10300, 0x1031f}, {0x1032d, 0x1034a}, {0x10350, 0x10375}, {0x10380, 0x1039d}, {0x103a0, 0x103c3},
+  {0x103c8, 0x103cf}, {0x103d1, 0x103d5}, {0x10400, 0x1049d}, {0x104b0, 0x104d3}, {0x104d8, 0x104fb}, {0x10500, 0x10527}, {0x10530, 0x10563}, {0x10570, 0x1057a},
+  {0x1057c, 0x1058a}, {0x1058c, 0x10592}, {0x10594, 0x10595}, {0x10597, 0x105a1}, {0x105a3, 0x105b1}, {0x105b3, 0x105b9}, {0x105bb, 0x105bc}, {0x10600, 0x10736},
+  {0x10740, 0x10755}, {0x10760, 0x10767}, {0x10780, 0x10785}, {0x10787, 0x107b0}, {0x107b2, 0x107ba}, {0x10800, 0x10805}, {0x10808, 0x10808}, {0x1080a, 0x10835},
+  {0x10837, 0x10838}, {0x1083c, 0x1083c}, {0x1083f, 0x10855}, {0x10860, 0x10876}, {0x10880, 0x1089e}, {0x108e0, 0x108f2}, {0x108f4, 0x108f5}, {0x10900, 0x10915},
+  {0x10920, 0x10939}, {0x10980, 0x109b7}, {0x109be, 0x109bf}, {0x10a00, 0x10a00}, {0x10a10, 0x10a13}, {0x10a15, 0x10a17}, {0x10a19, 0x10a35}, {0x10a60, 0x10a7c},
+  {0x10a80, 0x10a9c}, {0x10ac0, 0x10ac7}, {0x10ac9, 0x10ae4}, {0x10b00, 0x10b35}, {0x10b40, 0x10b55}, {0x10b60, 0x10b72}, {0x10b80, 0x10b91}, {0x10c00, 0x10c48},
+  {0x10c80, 0x10cb2}, {0x10cc0, 0x10cf2}, {0x10d00, 0x10d23}, {0x10e80, 0x10ea9}, {0x10eb0, 0x10eb1}, {0x10f00, 0x10f1c}, {0x10f27, 0x10f27}, {0x10f30, 0x10f45},
+  {0x10f70, 0x10f81}, {0x10fb0, 0x10fc4}, {0x10fe0, 0x10ff6}, {0x11003, 0x11037}, {0x11071, 0x11072}, {0x11075, 0x11075}, {0x11083, 0x110af}, {0x110d0, 0x110e8},
+  {0x11103, 0x11126}, {0x11144, 0x11144}, {0x11147, 0x11147}, {0x11150, 0x11172}, {0x11176, 0x11176}, {0x11183, 0x111b2}, {0x111c1, 0x111c4}, {0x111da, 0x111da},
+  {0x111dc, 0x111dc}, {0x11200, 0x11211}, {0x11213, 0x1122b}, {0x11280, 0x11286}, {0x11288, 0x11288}, {0x1128a, 0x1128d}, {0x1128f, 0x1129d}, {0x1129f, 0x112a8},
+  {0x112b0, 0x112de}, {0x11305, 0x1130c}, {0x1130f, 0x11310}, {0x11313, 0x11328}, {0x1132a, 0x11330}, {0x11332, 0x11333}, {0x11335, 0x11339}, {0x1133d, 0x1133d},
+  {0x11350, 0x11350}, {0x1135d, 0x11361}, {0x11400, 0x11434}, {0x11447, 0x1144a}, {0x1145f, 0x11461}, {0x11480, 0x114af}, {0x114c4, 0x114c5}, {0x114c7, 0x114c7},
+  {0x11580, 0x115ae}, {0x115d8, 0x115db}, {0x11600, 0x1162f}, {0x11644, 0x11644}, {0x11680, 0x116aa}, {0x116b8, 0x116b8}, {0x11700, 0x1171a}, {0x11740, 0x11746},
+  {0x11800, 0x1182b}, {0x118a0, 0x118df}, {0x118ff, 0x11906}, {0x11909, 0x11909}, {0x1190c, 0x11913}, {0x11915, 0x11916}, {0x11918, 0x1192f}, {0x1193f, 0x1193f},
+  {0x11941, 0x11941}, {0x119a0, 0x119a7}, {0x119aa, 0x119d0}, {0x119e1, 0x119e1}, {0x119e3, 0x119e3}, {0x11a00, 0x11a00}, {0x11a0b, 0x11a32}, {0x11a3a, 0x11a3a},
+  {0x11a50, 0x11a50}, {0x11a5c, 0x11a89}, {0x11a9d, 0x11a9d}, {0x11ab0, 0x11af8}, {0x11c00, 0x11c08}, {0x11c0a, 0x11c2e}, {0x11c40, 0x11c40}, {0x11c72, 0x11c8f},
+  {0x11d00, 0x11d06}, {0x11d08, 0x11d09}, {0x11d0b, 0x11d30}, {0x11d46, 0x11d46}, {0x11d60, 0x11d65}, {0x11d67, 0x11d68}, {0x11d6a, 0x11d89}, {0x11d98, 0x11d98},
+  {0x11ee0, 0x11ef2}, {0x11fb0, 0x11fb0}, {0x12000, 0x12399}, {0x12400, 0x1246e}, {0x12480, 0x12543}, {0x12f90, 0x12ff0}, {0x13000, 0x1342e}, {0x14400, 0x14646},
+  {0x16800, 0x16a38}, {0x16a40, 0x16a5e}, {0x16a70, 0x16abe}, {0x16ad0, 0x16aed}, {0x16b00, 0x16b2f}, {0x16b40, 0x16b43}, {0x16b63, 0x16b77}, {0x16b7d, 0x16b8f},
+  {0x16e40, 0x16e7f}, {0x16f00, 0x16f4a}, {0x16f50, 0x16f50}, {0x16f93, 0x16f9f}, {0x16fe0, 0x16fe1}, {0x16fe3, 0x16fe3}, {0x17000, 0x187f7}, {0x18800, 0x18cd5},
+  {0x18d00, 0x18d08}, {0x1aff0, 0x1aff3}, {0x1aff5, 0x1affb}, {0x1affd, 0x1affe}, {0x1b000, 0x1b122}, {0x1b150, 0x1b152}, {0x1b164, 0x1b167}, {0x1b170, 0x1b2fb},
+  {0x1bc00, 0x1bc6a}, {0x1bc70, 0x1bc7c}, {0x1bc80, 0x1bc88}, {0x1bc90, 0x1bc99}, {0x1d400, 0x1d454}, {0x1d456, 0x1d49c}, {0x1d49e, 0x1d49f}, {0x1d4a2, 0x1d4a2},
+  {0x1d4a5, 0x1d4a6}, {0x1d4a9, 0x1d4ac}, {0x1d4ae, 0x1d4b9}, {0x1d4bb, 0x1d4bb}, {0x1d4bd, 0x1d4c3}, {0x1d4c5, 0x1d505}, {0x1d507, 0x1d50a}, {0x1d50d, 0x1d514},
+  {0x1d516, 0x1d51c}, {0x1d51e, 0x1d539}, {0x1d53b, 0x1d53e}, {0x1d540, 0x1d544}, {0x1d546, 0x1d546}, {0x1d54a, 0x1d550}, {0x1d552, 0x1d6a5}, {0x1d6a8, 0x1d6c0},
+  {0x1d6c2, 0x1d6da}, {0x1d6dc, 0x1d6fa}, {0x1d6fc, 0x1d714}, {0x1d716, 0x1d734}, {0x1d736, 0x1d74e}, {0x1d750, 0x1d76e}, {0x1d770, 0x1d788}, {0x1d78a, 0x1d7a8},
+  {0x1d7aa, 0x1d7c2}, {0x1d7c4, 0x1d7cb}, {0x1df00, 0x1df1e}, {0x1e100, 0x1e12c}, {0x1e137, 0x1e13d}, {0x1e14e, 0x1e14e}, {0x1e290, 0x1e2ad}, {0x1e2c0, 0x1e2eb},
+  {0x1e7e0, 0x1e7e6}, {0x1e7e8, 0x1e7eb}, {0x1e7ed, 0x1e7ee}, {0x1e7f0, 0x1e7fe}, {0x1e800, 0x1e8c4}, {0x1e900, 0x1e943}, {0x1e94b, 0x1e94b}, {0x1ee00, 0x1ee03},
+  {0x1ee05, 0x1ee1f}, {0x1ee21, 0x1ee22}, {0x1ee24, 0x1ee24}, {0x1ee27, 0x1ee27}, {0x1ee29, 0x1ee32}, {0x1ee34, 0x1ee37}, {0x1ee39, 0x1ee39}, {0x1ee3b, 0x1ee3b},
+  {0x1ee42, 0x1ee42}, {0x1ee47, 0x1ee47}, {0x1ee49, 0x1ee49}, {0x1ee4b, 0x1ee4b}, {0x1ee4d, 0x1ee4f}, {0x1ee51, 0x1ee52}, {0x1ee54, 0x1ee54}, {0x1ee57, 0x1ee57},
+  {0x1ee59, 0x1ee59}, {0x1ee5b, 0x1ee5b}, {0x1ee5d, 0x1ee5d}, {0x1ee5f, 0x1ee5f}, {0x1ee61, 0x1ee62}, {0x1ee64, 0x1ee64}, {0x1ee67, 0x1ee6a}, {0x1ee6c, 0x1ee72},
+  {0x1ee74, 0x1ee77}, {0x1ee79, 0x1ee7c}, {0x1ee7e, 0x1ee7e}, {0x1ee80, 0x1ee89}, {0x1ee8b, 0x1ee9b}, {0x1eea1, 0x1eea3}, {0x1eea5, 0x1eea9}, {0x1eeab, 0x1eebb},
+  {0x20000, 0x2a6df}, {0x2a700, 0x2b738}, {0x2b740, 0x2b81d}, {0x2b820, 0x2cea1}, {0x2ceb0, 0x2ebe0}, {0x2f800, 0x2fa1d}, {0x30000, 0x3134a},
+};
+
+static TSCharacterRange sym_identifier_character_set_2[] = {
+  {'0', '9'}, {'A', 'Z'}, {'_', '_'}, {'a', 'z'}, {0xaa, 0xaa}, {0xb5, 0xb5}, {0xb7, 0xb7}, {0xba, 0xba},
+  {0xc0, 0xd6}, {0xd8, 0xf6}, {0xf8, 0x2c1}, {0x2c6, 0x2d1}, {0x2e0, 0x2e4}, {0x2ec, 0x2ec}, {0x2ee, 0x2ee}, {0x300, 0x374},
+  {0x376, 0x377}, {0x37b, 0x37d}, {0x37f, 0x37f}, {0x386, 0x38a}, {0x38c, 0x38c}, {0x38e, 0x3a1}, {0x3a3, 0x3f5}, {0x3f7, 0x481},
+  {0x483, 0x487}, {0x48a, 0x52f}, {0x531, 0x556}, {0x559, 0x559}, {0x560, 0x588}, {0x591, 0x5bd}, {0x5bf, 0x5bf}, {0x5c1, 0x5c2},
+  {0x5c4, 0x5c5}, {0x5c7, 0x5c7}, {0x5d0, 0x5ea}, {0x5ef, 0x5f2}, {0x610, 0x61a}, {0x620, 0x669}, {0x66e, 0x6d3}, {0x6d5, 0x6dc},
+  {0x6df, 0x6e8}, {0x6ea, 0x6fc}, {0x6ff, 0x6ff}, {0x710, 0x74a}, {0x74d, 0x7b1}, {0x7c0, 0x7f5}, {0x7fa, 0x7fa}, {0x7fd, 0x7fd},
+  {0x800, 0x82d}, {0x840, 0x85b}, {0x860, 0x86a}, {0x870, 0x887}, {0x889, 0x88e}, {0x898, 0x8e1}, {0x8e3, 0x963}, {0x966, 0x96f},
+  {0x971, 0x983}, {0x985, 0x98c}, {0x98f, 0x990}, {0x993, 0x9a8}, {0x9aa, 0x9b0}, {0x9b2, 0x9b2}, {0x9b6, 0x9b9}, {0x9bc, 0x9c4},
+  {0x9c7, 0x9c8}, {0x9cb, 0x9ce}, {0x9d7, 0x9d7}, {0x9dc, 0x9dd}, {0x9df, 0x9e3}, {0x9e6, 0x9f1}, {0x9fc, 0x9fc}, {0x9fe, 0x9fe},
+  {0xa01, 0xa03}, {0xa05, 0xa0a}, {0xa0f, 0xa10}, {0xa13, 0xa28}, {0xa2a, 0xa30}, {0xa32, 0xa33}, {0xa35, 0xa36}, {0xa38, 0xa39},
+  {0xa3c, 0xa3c}, {0xa3e, 0xa42}, {0xa47, 0xa48}, {0xa4b, 0xa4d}, {0xa51, 0xa51}, {0xa59, 0xa5c}, {0xa5e, 0xa5e}, {0xa66, 0xa75},
+  {0xa81, 0xa83}, {0xa85, 0xa8d}, {0xa8f, 0xa91}, {0xa93, 0xaa8}, {0xaaa, 0xab0}, {0xab2, 0xab3}, {0xab5, 0xab9}, {0xabc, 0xac5},
+  {0xac7, 0xac9}, {0xacb, 0xacd}, {0xad0, 0xad0}, {0xae0, 0xae3}, {0xae6, 0xaef}, {0xaf9, 0xaff}, {0xb01, 0xb03}, {0xb05, 0xb0c},
+  {0xb0f, 0xb10}, {0xb13, 0xb28}, {0xb2a, 0xb30}, {0xb32, 0xb33}, {0xb35, 0xb39}, {0xb3c, 0xb44}, {0xb47, 0xb48}, {0xb4b, 0xb4d},
+  {0xb55, 0xb57}, {0xb5c, 0xb5d}, {0xb5f, 0xb63}, {0xb66, 0xb6f}, {0xb71, 0xb71}, {0xb82, 0xb83}, {0xb85, 0xb8a}, {0xb8e, 0xb90},
+  {0xb92, 0xb95}, {0xb99, 0xb9a}, {0xb9c, 0xb9c}, {0xb9e, 0xb9f}, {0xba3, 0xba4}, {0xba8, 0xbaa}, {0xbae, 0xbb9}, {0xbbe, 0xbc2},
+  {0xbc6, 0xbc8}, {0xbca, 0xbcd}, {0xbd0, 0xbd0}, {0xbd7, 0xbd7}, {0xbe6, 0xbef}, {0xc00, 0xc0c}, {0xc0e, 0xc10}, {0xc12, 0xc28},
+  {0xc2a, 0xc39}, {0xc3c, 0xc44}, {0xc46, 0xc48}, {0xc4a, 0xc4d}, {0xc55, 0xc56}, {0xc58, 0xc5a}, {0xc5d, 0xc5d}, {0xc60, 0xc63},
+  {0xc66, 0xc6f}, {0xc80, 0xc83}, {0xc85, 0xc8c}, {0xc8e, 0xc90}, {0xc92, 0xca8}, {0xcaa, 0xcb3}, {0xcb5, 0xcb9}, {0xcbc, 0xcc4},
+  {0xcc6, 0xcc8}, {0xcca, 0xccd}, {0xcd5, 0xcd6}, {0xcdd, 0xcde}, {0xce0, 0xce3}, {0xce6, 0xcef}, {0xcf1, 0xcf2}, {0xd00, 0xd0c},
+  {0xd0e, 0xd10}, {0xd12, 0xd44}, {0xd46, 0xd48}, {0xd4a, 0xd4e}, {0xd54, 0xd57}, {0xd5f, 0xd63}, {0xd66, 0xd6f}, {0xd7a, 0xd7f},
+  {0xd81, 0xd83}, {0xd85, 0xd96}, {0xd9a, 0xdb1}, {0xdb3, 0xdbb}, {0xdbd, 0xdbd}, {0xdc0, 0xdc6}, {0xdca, 0xdca}, {0xdcf, 0xdd4},
+  {0xdd6, 0xdd6}, {0xdd8, 0xddf}, {0xde6, 0xdef}, {0xdf2, 0xdf3}, {0xe01, 0xe3a}, {0xe40, 0xe4e}, {0xe50, 0xe59}, {0xe81, 0xe82},
+  {0xe84, 0xe84}, {0xe86, 0xe8a}, {0xe8c, 0xea3}, {0xea5, 0xea5}, {0xea7, 0xebd}, {0xec0, 0xec4}, {0xec6, 0xec6}, {0xec8, 0xecd},
+  {0xed0, 0xed9}, {0xedc, 0xedf}, {0xf00, 0xf00}, {0xf18, 0xf19}, {0xf20, 0xf29}, {0xf35, 0xf35}, {0xf37, 0xf37}, {0xf39, 0xf39},
+  {0xf3e, 0xf47}, {0xf49, 0xf6c}, {0xf71, 0xf84}, {0xf86, 0xf97}, {0xf99, 0xfbc}, {0xfc6, 0xfc6}, {0x1000, 0x1049}, {0x1050, 0x109d},
+  {0x10a0, 0x10c5}, {0x10c7, 0x10c7}, {0x10cd, 0x10cd}, {0x10d0, 0x10fa}, {0x10fc, 0x1248}, {0x124a, 0x124d}, {0x1250, 0x1256}, {0x1258, 0x1258},
+  {0x125a, 0x125d}, {0x1260, 0x1288}, {0x128a, 0x128d}, {0x1290, 0x12b0}, {0x12b2, 0x12b5}, {0x12b8, 0x12be}, {0x12c0, 0x12c0}, {0x12c2, 0x12c5},
+  {0x12c8, 0x12d6}, {0x12d8, 0x1310}, {0x1312, 0x1315}, {0x1318, 0x135a}, {0x135d, 0x135f}, {0x1369, 0x1371}, {0x1380, 0x138f}, {0x13a0, 0x13f5},
+  {0x13f8, 0x13fd}, {0x1401, 0x166c}, {0x166f, 0x167f}, {0x1681, 0x169a}, {0x16a0, 0x16ea}, {0x16ee, 0x16f8}, {0x1700, 0x1715}, {0x171f, 0x1734},
+  {0x1740, 0x1753}, {0x1760, 0x176c}, {0x176e, 0x1770}, {0x1772, 0x1773}, {0x1780, 0x17d3}, {0x17d7, 0x17d7}, {0x17dc, 0x17dd}, {0x17e0, 0x17e9},
+  {0x180b, 0x180d}, {0x180f, 0x1819}, {0x1820, 0x1878}, {0x1880, 0x18aa}, {0x18b0, 0x18f5}, {0x1900, 0x191e}, {0x1920, 0x192b}, {0x1930, 0x193b},
+  {0x1946, 0x196d}, {0x1970, 0x1974}, {0x1980, 0x19ab}, {0x19b0, 0x19c9}, {0x19d0, 0x19da}, {0x1a00, 0x1a1b}, {0x1a20, 0x1a5e}, {0x1a60, 0x1a7c},
+  {0x1a7f, 0x1a89}, {0x1a90, 0x1a99}, {0x1aa7, 0x1aa7}, {0x1ab0, 0x1abd}, {0x1abf, 0x1ace}, {0x1b00, 0x1b4c}, {0x1b50, 0x1b59}, {0x1b6b, 0x1b73},
+  {0x1b80, 0x1bf3}, {0x1c00, 0x1c37}, {0x1c40, 0x1c49}, {0x1c4d, 0x1c7d}, {0x1c80, 0x1c88}, {0x1c90, 0x1cba}, {0x1cbd, 0x1cbf}, {0x1cd0, 0x1cd2},
+  {0x1cd4, 0x1cfa}, {0x1d00, 0x1f15}, {0x1f18, 0x1f1d}, {0x1f20, 0x1f45}, {0x1f48, 0x1f4d}, {0x1f50, 0x1f57}, {0x1f59, 0x1f59}, {0x1f5b, 0x1f5b},
+  {0x1f5d, 0x1f5d}, {0x1f5f, 0x1f7d}, {0x1f80, 0x1fb4}, {0x1fb6, 0x1fbc}, {0x1fbe, 0x1fbe}, {0x1fc2, 0x1fc4}, {0x1fc6, 0x1fcc}, {0x1fd0, 0x1fd3},
+  {0x1fd6, 0x1fdb}, {0x1fe0, 0x1fec}, {0x1ff2, 0x1ff4}, {0x1ff6, 0x1ffc}, {0x203f, 0x2040}, {0x2054, 0x2054}, {0x2071, 0x2071}, {0x207f, 0x207f},
+  {0x2090, 0x209c}, {0x20d0, 0x20dc}, {0x20e1, 0x20e1}, {0x20e5, 0x20f0}, {0x2102, 0x2102}, {0x2107, 0x2107}, {0x210a, 0x2113}, {0x2115, 0x2115},
+  {0x2118, 0x211d}, {0x2124, 0x2124}, {0x2126, 0x2126}, {0x2128, 0x2128}, {0x212a, 0x2139}, {0x213c, 0x213f}, {0x2145, 0x2149}, {0x214e, 0x214e},
+  {0x2160, 0x2188}, {0x2c00, 0x2ce4}, {0x2ceb, 0x2cf3}, {0x2d00, 0x2d25}, {0x2d27, 0x2d27}, {0x2d2d, 0x2d2d}, {0x2d30, 0x2d67}, {0x2d6f, 0x2d6f},
+  {0x2d7f, 0x2d96}, {0x2da0, 0x2da6}, {0x2da8, 0x2dae}, {0x2db0, 0x2db6}, {0x2db8, 0x2dbe}, {0x2dc0, 0x2dc6}, {0x2dc8, 0x2dce}, {0x2dd0, 0x2dd6},
+  {0x2dd8, 0x2dde}, {0x2de0, 0x2dff}, {0x3005, 0x3007}, {0x3021, 0x302f}, {0x3031, 0x3035}, {0x3038, 0x303c}, {0x3041, 0x3096}, {0x3099, 0x309a},
+  {0x309d, 0x309f}, {0x30a1, 0x30fa}, {0x30fc, 0x30ff}, {0x3105, 0x312f}, {0x3131, 0x318e}, {0x31a0, 0x31bf}, {0x31f0, 0x31ff}, {0x3400, 0x4dbf},
+  {0x4e00, 0xa48c}, {0xa4d0, 0xa4fd}, {0xa500, 0xa60c}, {0xa610, 0xa62b}, {0xa640, 0xa66f}, {0xa674, 0xa67d}, {0xa67f, 0xa6f1}, {0xa717, 0xa71f},
+  {0xa722, 0xa788}, {0xa78b, 0xa7ca}, {0xa7d0, 0xa7d1}, {0xa7d3, 0xa7d3}, {0xa7d5, 0xa7d9}, {0xa7f2, 0xa827}, {0xa82c, 0xa82c}, {0xa840, 0xa873},
+  {0xa880, 0xa8c5}, {0xa8d0, 0xa8d9}, {0xa8e0, 0xa8f7}, {0xa8fb, 0xa8fb}, {0xa8fd, 0xa92d}, {0xa930, 0xa953}, {0xa960, 0xa97c}, {0xa980, 0xa9c0},
+  {0xa9cf, 0xa9d9}, {0xa9e0, 0xa9fe}, {0xaa00, 0xaa36}, {0xaa40, 0xaa4d}, {0xaa50, 0xaa59}, {0xaa60, 0xaa76}, {0xaa7a, 0xaac2}, {0xaadb, 0xaadd},
+  {0xaae0, 0xaaef}, {0xaaf2, 0xaaf6}, {0xab01, 0xab06}, {0xab09, 0xab0e}, {0xab11, 0xab16}, {0xab20, 0xab26}, {0xab28, 0xab2e}, {0xab30, 0xab5a},
+  {0xab5c, 0xab69}, {0xab70, 0xabea}, {0xabec, 0xabed}, {0xabf0, 0xabf9}, {0xac00, 0xd7a3}, {0xd7b0, 0xd7c6}, {0xd7cb, 0xd7fb}, {0xf900, 0xfa6d},
+  {0xfa70, 0xfad9}, {0xfb00, 0xfb06}, {0xfb13, 0xfb17}, {0xfb1d, 0xfb28}, {0xfb2a, 0xfb36}, {0xfb38, 0xfb3c}, {0xfb3e, 0xfb3e}, {0xfb40, 0xfb41},
+  {0xfb43, 0xfb44}, {0xfb46, 0xfbb1}, {0xfbd3, 0xfc5d}, {0xfc64, 0xfd3d}, {0xfd50, 0xfd8f}, {0xfd92, 0xfdc7}, {0xfdf0, 0xfdf9}, {0xfe00, 0xfe0f},
+  {0xfe20, 0xfe2f}, {0xfe33, 0xfe34}, {0xfe4d, 0xfe4f}, {0xfe71, 0xfe71}, {0xfe73, 0xfe73}, {0xfe77, 0xfe77}, {0xfe79, 0xfe79}, {0xfe7b, 0xfe7b},
+  {0xfe7d, 0xfe7d}, {0xfe7f, 0xfefc}, {0xff10, 0xff19}, {0xff21, 0xff3a}, {0xff3f, 0xff3f}, {0xff41, 0xff5a}, {0xff66, 0xffbe}, {0xffc2, 0xffc7},
+  {0xffca, 0xffcf}, {0xffd2, 0xffd7}, {0xffda, 0xffdc}, {0x10000, 0x1000b}, {0x1000d, 0x10026}, {0x10028, 0x1003a}, {0x1003c, 0x1003d}, {0x1003f, 0x1004d},
+  {0x10050, 0x1005d}, {0x10080, 0x100fa}, {0x10140, 0x10174}, {0x101fd, 0x101fd}, {0x10280, 0x1029c}, {0x102a0, 0x102d0}, {0x102e0, 0x102e0}, {0x10300, 0x1031f},
+  {0x1032d, 0x1034a}, {0x10350, 0x1037a}, {0x10380, 0x1039d}, {0x103a0, 0x103c3}, {0x103c8, 0x103cf}, {0x103d1, 0x103d5}, {0x10400, 0x1049d}, {0x104a0, 0x104a9},
+  {0x104b0, 0x104d3}, {0x104d8, 0x104fb}, {0x10500, 0x10527}, {0x10530, 0x10563}, {0x10570, 0x1057a}, {0x1057c, 0x1058a}, {0x1058c, 0x10592}, {0x10594, 0x10595},
+  {0x10597, 0x105a1}, {0x105a3, 0x105b1}, {0x105b3, 0x105b9}, {0x105bb, 0x105bc}, {0x10600, 0x10736}, {0x10740, 0x10755}, {0x10760, 0x10767}, {0x10780, 0x10785},
+  {0x10787, 0x107b0}, {0x107b2, 0x107ba}, {0x10800, 0x10805}, {0x10808, 0x10808}, {0x1080a, 0x10835}, {0x10837, 0x10838}, {0x1083c, 0x1083c}, {0x1083f, 0x10855},
+  {0x10860, 0x10876}, {0x10880, 0x1089e}, {0x108e0, 0x108f2}, {0x108f4, 0x108f5}, {0x10900, 0x10915}, {0x10920, 0x10939}, {0x10980, 0x109b7}, {0x109be, 0x109bf},
+  {0x10a00, 0x10a03}, {0x10a05, 0x10a06}, {0x10a0c, 0x10a13}, {0x10a15, 0x10a17}, {0x10a19, 0x10a35}, {0x10a38, 0x10a3a}, {0x10a3f, 0x10a3f}, {0x10a60, 0x10a7c},
+  {0x10a80, 0x10a9c}, {0x10ac0, 0x10ac7}, {0x10ac9, 0x10ae6}, {0x10b00, 0x10b35}, {0x10b40, 0x10b55}, {0x10b60, 0x10b72}, {0x10b80, 0x10b91}, {0x10c00, 0x10c48},
+  {0x10c80, 0x10cb2}, {0x10cc0, 0x10cf2}, {0x10d00, 0x10d27}, {0x10d30, 0x10d39}, {0x10e80, 0x10ea9}, {0x10eab, 0x10eac}, {0x10eb0, 0x10eb1}, {0x10f00, 0x10f1c},
+  {0x10f27, 0x10f27}, {0x10f30, 0x10f50}, {0x10f70, 0x10f85}, {0x10fb0, 0x10fc4}, {0x10fe0, 0x10ff6}, {0x11000, 0x11046}, {0x11066, 0x11075}, {0x1107f, 0x110ba},
+  {0x110c2, 0x110c2}, {0x110d0, 0x110e8}, {0x110f0, 0x110f9}, {0x11100, 0x11134}, {0x11136, 0x1113f}, {0x11144, 0x11147}, {0x11150, 0x11173}, {0x11176, 0x11176},
+  {0x11180, 0x111c4}, {0x111c9, 0x111cc}, {0x111ce, 0x111da}, {0x111dc, 0x111dc}, {0x11200, 0x11211}, {0x11213, 0x11237}, {0x1123e, 0x1123e}, {0x11280, 0x11286},
+  {0x11288, 0x11288}, {0x1128a, 0x1128d}, {0x1128f, 0x1129d}, {0x1129f, 0x112a8}, {0x112b0, 0x112ea}, {0x112f0, 0x112f9}, {0x11300, 0x11303}, {0x11305, 0x1130c},
+  {0x1130f, 0x11310}, {0x11313, 0x11328}, {0x1132a, 0x11330}, {0x11332, 0x11333}, {0x11335, 0x11339}, {0x1133b, 0x11344}, {0x11347, 0x11348}, {0x1134b, 0x1134d},
+  {0x11350, 0x11350}, {0x11357, 0x11357}, {0x1135d, 0x11363}, {0x11366, 0x1136c}, {0x11370, 0x11374}, {0x11400, 0x1144a}, {0x11450, 0x11459}, {0x1145e, 0x11461},
+  {0x11480, 0x114c5}, {0x114c7, 0x114c7}, {0x114d0, 0x114d9}, {0x11580, 0x115b5}, {0x115b8, 0x115c0}, {0x115d8, 0x115dd}, {0x11600, 0x11640}, {0x11644, 0x11644},
+  {0x11650, 0x11659}, {0x11680, 0x116b8}, {0x116c0, 0x116c9}, {0x11700, 0x1171a}, {0x1171d, 0x1172b}, {0x11730, 0x11739}, {0x11740, 0x11746}, {0x11800, 0x1183a},
+  {0x118a0, 0x118e9}, {0x118ff, 0x11906}, {0x11909, 0x11909}, {0x1190c, 0x11913}, {0x11915, 0x11916}, {0x11918, 0x11935}, {0x11937, 0x11938}, {0x1193b, 0x11943},
+  {0x11950, 0x11959}, {0x119a0, 0x119a7}, {0x119aa, 0x119d7}, {0x119da, 0x119e1}, {0x119e3, 0x119e4}, {0x11a00, 0x11a3e}, {0x11a47, 0x11a47}, {0x11a50, 0x11a99},
+  {0x11a9d, 0x11a9d}, {0x11ab0, 0x11af8}, {0x11c00, 0x11c08}, {0x11c0a, 0x11c36}, {0x11c38, 0x11c40}, {0x11c50, 0x11c59}, {0x11c72, 0x11c8f}, {0x11c92, 0x11ca7},
+  {0x11ca9, 0x11cb6}, {0x11d00, 0x11d06}, {0x11d08, 0x11d09}, {0x11d0b, 0x11d36}, {0x11d3a, 0x11d3a}, {0x11d3c, 0x11d3d}, {0x11d3f, 0x11d47}, {0x11d50, 0x11d59},
+  {0x11d60, 0x11d65}, {0x11d67, 0x11d68}, {0x11d6a, 0x11d8e}, {0x11d90, 0x11d91}, {0x11d93, 0x11d98}, {0x11da0, 0x11da9}, {0x11ee0, 0x11ef6}, {0x11fb0, 0x11fb0},
+  {0x12000, 0x12399}, {0x12400, 0x1246e}, {0x12480, 0x12543}, {0x12f90, 0x12ff0}, {0x13000, 0x1342e}, {0x14400, 0x14646}, {0x16800, 0x16a38}, {0x16a40, 0x16a5e},
+  {0x16a60, 0x16a69}, {0x16a70, 0x16abe}, {0x16ac0, 0x16ac9}, {0x16ad0, 0x16aed}, {0x16af0, 0x16af4}, {0x16b00, 0x16b36}, {0x16b40, 0x16b43}, {0x16b50, 0x16b59},
+  {0x16b63, 0x16b77}, {0x16b7d, 0x16b8f}, {0x16e40, 0x16e7f}, {0x16f00, 0x16f4a}, {0x16f4f, 0x16f87}, {0x16f8f, 0x16f9f}, {0x16fe0, 0x16fe1}, {0x16fe3, 0x16fe4},
+  {0x16ff0, 0x16ff1}, {0x17000, 0x187f7}, {0x18800, 0x18cd5}, {0x18d00, 0x18d08}, {0x1aff0, 0x1aff3}, {0x1aff5, 0x1affb}, {0x1affd, 0x1affe}, {0x1b000, 0x1b122},
+  {0x1b150, 0x1b152}, {0x1b164, 0x1b167}, {0x1b170, 0x1b2fb}, {0x1bc00, 0x1bc6a}, {0x1bc70, 0x1bc7c}, {0x1bc80, 0x1bc88}, {0x1bc90, 0x1bc99}, {0x1bc9d, 0x1bc9e},
+  {0x1cf00, 0x1cf2d}, {0x1cf30, 0x1cf46}, {0x1d165, 0x1d169}, {0x1d16d, 0x1d172}, {0x1d17b, 0x1d182}, {0x1d185, 0x1d18b}, {0x1d1aa, 0x1d1ad}, {0x1d242, 0x1d244},
+  {0x1d400, 0x1d454}, {0x1d456, 0x1d49c}, {0x1d49e, 0x1d49f}, {0x1d4a2, 0x1d4a2}, {0x1d4a5, 0x1d4a6}, {0x1d4a9, 0x1d4ac}, {0x1d4ae, 0x1d4b9}, {0x1d4bb, 0x1d4bb},
+  {0x1d4bd, 0x1d4c3}, {0x1d4c5, 0x1d505}, {0x1d507, 0x1d50a}, {0x1d50d, 0x1d514}, {0x1d516, 0x1d51c}, {0x1d51e, 0x1d539}, {0x1d53b, 0x1d53e}, {0x1d540, 0x1d544},
+  {0x1d546, 0x1d546}, {0x1d54a, 0x1d550}, {0x1d552, 0x1d6a5}, {0x1d6a8, 0x1d6c0}, {0x1d6c2, 0x1d6da}, {0x1d6dc, 0x1d6fa}, {0x1d6fc, 0x1d714}, {0x1d716, 0x1d734},
+  {0x1d736, 0x1d74e}, {0x1d750, 0x1d76e}, {0x1d770, 0x1d788}, {0x1d78a, 0x1d7a8}, {0x1d7aa, 0x1d7c2}, {0x1d7c4, 0x1d7cb}, {0x1d7ce, 0x1d7ff}, {0x1da00, 0x1da36},
+  {0x1da3b, 0x1da6c}, {0x1da75, 0x1da75}, {0x1da84, 0x1da84}, {0x1da9b, 0x1da9f}, {0x1daa1, 0x1daaf}, {0x1df00, 0x1df1e}, {0x1e000, 0x1e006}, {0x1e008, 0x1e018},
+  {0x1e01b, 0x1e021}, {0x1e023, 0x1e024}, {0x1e026, 0x1e02a}, {0x1e100, 0x1e12c}, {0x1e130, 0x1e13d}, {0x1e140, 0x1e149}, {0x1e14e, 0x1e14e}, {0x1e290, 0x1e2ae},
+  {0x1e2c0, 0x1e2f9}, {0x1e7e0, 0x1e7e6}, {0x1e7e8, 0x1e7eb}, {0x1e7ed, 0x1e7ee}, {0x1e7f0, 0x1e7fe}, {0x1e800, 0x1e8c4}, {0x1e8d0, 0x1e8d6}, {0x1e900, 0x1e94b},
+  {0x1e950, 0x1e959}, {0x1ee00, 0x1ee03}, {0x1ee05, 0x1ee1f}, {0x1ee21, 0x1ee22}, {0x1ee24, 0x1ee24}, {0x1ee27, 0x1ee27}, {0x1ee29, 0x1ee32}, {0x1ee34, 0x1ee37},
+  {0x1ee39, 0x1ee39}, {0x1ee3b, 0x1ee3b}, {0x1ee42, 0x1ee42}, {0x1ee47, 0x1ee47}, {0x1ee49, 0x1ee49}, {0x1ee4b, 0x1ee4b}, {0x1ee4d, 0x1ee4f}, {0x1ee51, 0x1ee52},
+  {0x1ee54, 0x1ee54}, {0x1ee57, 0x1ee57}, {0x1ee59, 0x1ee59}, {0x1ee5b, 0x1ee5b}, {0x1ee5d, 0x1ee5d}, {0x1ee5f, 0x1ee5f}, {0x1ee61, 0x1ee62}, {0x1ee64, 0x1ee64},
+  {0x1ee67, 0x1ee6a}, {0x1ee6c, 0x1ee72}, {0x1ee74, 0x1ee77}, {0x1ee79, 0x1ee7c}, {0x1ee7e, 0x1ee7e}, {0x1ee80, 0x1ee89}, {0x1ee8b, 0x1ee9b}, {0x1eea1, 0x1eea3},
+  {0x1eea5, 0x1eea9}, {0x1eeab, 0x1eebb}, {0x1fbf0, 0x1fbf9}, {0x20000, 0x2a6df}, {0x2a700, 0x2b738}, {0x2b740, 0x2b81d}, {0x2b820, 0x2cea1}, {0x2ceb0, 0x2ebe0},
+  {0x2f800, 0x2fa1d}, {0x30000, 0x3134a}, {0xe0100, 0xe01ef},
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -628,2850 +1516,6625 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(33);
+      if (eof) ADVANCE(90);
       ADVANCE_MAP(
-        '!', 38,
-        '#', 39,
-        ',', 51,
-        '.', 61,
-        '/', 42,
-        '=', 56,
-        '?', 37,
-        ']', 36,
-        'a', 78,
-        'e', 62,
-        'i', 72,
-        'u', 76,
-        '{', 35,
-        '\t', 87,
-        '\n', 87,
-        '\r', 87,
-        ' ', 87,
+        '!', 96,
+        '"', 5,
+        '#', 98,
+        ',', 114,
+        '-', 86,
+        '.', 161,
+        '/', 101,
+        '0', 151,
+        '<', 147,
+        '=', 122,
+        '>', 149,
+        '?', 94,
+        '@', 24,
+        '[', 163,
+        ']', 93,
+        'a', 204,
+        'b', 196,
+        'e', 166,
+        'f', 168,
+        'i', 186,
+        'l', 191,
+        'n', 208,
+        'o', 187,
+        'r', 167,
+        't', 210,
+        'u', 205,
+        'w', 193,
+        'y', 192,
+        '{', 92,
+        '|', 97,
+        '\t', 232,
+        '\n', 232,
+        '\r', 232,
+        ' ', 232,
       );
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(151);
+      if (set_contains(sym_identifier_character_set_1, 655, lookahead)) ADVANCE(228);
       END_STATE();
     case 1:
       ADVANCE_MAP(
-        '!', 38,
-        '#', 39,
-        '-', 5,
-        '/', 42,
-        ']', 30,
-        '\t', 87,
-        '\n', 87,
-        '\r', 87,
-        ' ', 87,
+        '!', 96,
+        '"', 5,
+        ',', 114,
+        '-', 23,
+        '.', 162,
+        '/', 87,
+        '<', 147,
+        '=', 18,
+        '>', 149,
+        '?', 94,
+        '[', 163,
+        ']', 83,
+        'f', 168,
+        'n', 224,
+        't', 210,
+        '|', 97,
+        '~', 22,
+        '\t', 232,
+        '\n', 232,
+        '\r', 232,
+        ' ', 232,
       );
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(151);
+      if (set_contains(sym_identifier_character_set_1, 655, lookahead)) ADVANCE(228);
       END_STATE();
     case 2:
       ADVANCE_MAP(
-        '!', 38,
-        ',', 51,
-        '-', 5,
-        '.', 61,
-        '/', 31,
-        '?', 37,
-        ']', 30,
-        'a', 22,
-        'e', 6,
-        'i', 16,
-        'u', 20,
-        '\t', 87,
-        '\n', 87,
-        '\r', 87,
-        ' ', 87,
+        '!', 95,
+        '#', 98,
+        '-', 23,
+        '.', 15,
+        '/', 101,
+        '=', 121,
+        '?', 94,
+        '@', 24,
+        ']', 83,
+        '|', 97,
+        '~', 22,
+        '\t', 232,
+        '\n', 232,
+        '\r', 232,
+        ' ', 232,
       );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(151);
+      if (set_contains(sym_identifier_character_set_1, 655, lookahead)) ADVANCE(228);
       END_STATE();
     case 3:
-      if (lookahead == '-') ADVANCE(3);
-      if (lookahead == ']') ADVANCE(28);
-      if (lookahead != 0) ADVANCE(3);
+      ADVANCE_MAP(
+        '!', 17,
+        ',', 114,
+        '-', 22,
+        '<', 147,
+        '=', 18,
+        '>', 149,
+        ']', 83,
+        'a', 58,
+        'b', 53,
+        'e', 25,
+        'i', 43,
+        'l', 48,
+        'o', 44,
+        'r', 26,
+        'u', 59,
+        'w', 50,
+        'y', 49,
+        '~', 22,
+        '\t', 232,
+        '\n', 232,
+        '\r', 232,
+        ' ', 232,
+      );
       END_STATE();
     case 4:
-      if (lookahead == ']') ADVANCE(29);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '-') ADVANCE(86);
+      if (lookahead == 'f') ADVANCE(168);
+      if (lookahead == 't') ADVANCE(210);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') ADVANCE(232);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(151);
+      if (set_contains(sym_identifier_character_set_1, 655, lookahead)) ADVANCE(228);
       END_STATE();
     case 5:
-      if (lookahead == ']') ADVANCE(30);
+      if (lookahead == '"') ADVANCE(150);
+      if (lookahead == '\\') ADVANCE(84);
+      if (lookahead != 0) ADVANCE(5);
       END_STATE();
     case 6:
-      if (lookahead == 'a') ADVANCE(7);
-      if (lookahead == 'l') ADVANCE(23);
+      if (lookahead == ',') ADVANCE(114);
+      if (lookahead == 'i') ADVANCE(60);
       END_STATE();
     case 7:
-      if (lookahead == 'c') ADVANCE(17);
+      if (lookahead == '-') ADVANCE(13);
+      if (lookahead == ']') ADVANCE(81);
+      if (lookahead == 'a') ADVANCE(11);
+      if (lookahead == '~') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(13);
       END_STATE();
     case 8:
-      if (lookahead == 'c') ADVANCE(18);
+      if (lookahead == '-') ADVANCE(13);
+      if (lookahead == ']') ADVANCE(81);
+      if (lookahead == 'a') ADVANCE(9);
+      if (lookahead == '~') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(13);
       END_STATE();
     case 9:
-      if (lookahead == 'c') ADVANCE(27);
+      if (lookahead == '-') ADVANCE(13);
+      if (lookahead == ']') ADVANCE(81);
+      if (lookahead == 'm') ADVANCE(12);
+      if (lookahead == '~') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(13);
       END_STATE();
     case 10:
-      if (lookahead == 'd') ADVANCE(12);
+      if (lookahead == '-') ADVANCE(13);
+      if (lookahead == ']') ADVANCE(81);
+      if (lookahead == 'p') ADVANCE(7);
+      if (lookahead == '~') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(13);
       END_STATE();
     case 11:
-      if (lookahead == 'e') ADVANCE(43);
+      if (lookahead == '-') ADVANCE(13);
+      if (lookahead == ']') ADVANCE(81);
+      if (lookahead == 'r') ADVANCE(8);
+      if (lookahead == '~') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(13);
       END_STATE();
     case 12:
-      if (lookahead == 'e') ADVANCE(54);
+      if (lookahead == '-') ADVANCE(13);
+      if (lookahead == ']') ADVANCE(81);
+      if (lookahead == 's') ADVANCE(230);
+      if (lookahead == '~') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(13);
       END_STATE();
     case 13:
-      if (lookahead == 'e') ADVANCE(52);
+      if (lookahead == '-') ADVANCE(13);
+      if (lookahead == ']') ADVANCE(81);
+      if (lookahead == '~') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(13);
       END_STATE();
     case 14:
-      if (lookahead == 'e') ADVANCE(25);
+      if (lookahead == '.') ADVANCE(138);
       END_STATE();
     case 15:
-      if (lookahead == 'e') ADVANCE(9);
+      if (lookahead == '.') ADVANCE(14);
       END_STATE();
     case 16:
-      if (lookahead == 'f') ADVANCE(40);
-      if (lookahead == 'n') ADVANCE(8);
+      if (lookahead == '/') ADVANCE(87);
+      if (lookahead == '0') ADVANCE(164);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(165);
+      if (set_contains(sym_identifier_character_set_1, 655, lookahead)) ADVANCE(228);
       END_STATE();
     case 17:
-      if (lookahead == 'h') ADVANCE(47);
+      if (lookahead == '=') ADVANCE(145);
       END_STATE();
     case 18:
-      if (lookahead == 'l') ADVANCE(26);
+      if (lookahead == '=') ADVANCE(144);
       END_STATE();
     case 19:
-      if (lookahead == 'l') ADVANCE(14);
-      if (lookahead == 's') ADVANCE(15);
+      if (lookahead == '[') ADVANCE(158);
+      if (lookahead != 0) ADVANCE(127);
       END_STATE();
     case 20:
-      if (lookahead == 'n') ADVANCE(19);
+      if (lookahead == '[') ADVANCE(85);
+      if (lookahead != 0) ADVANCE(127);
       END_STATE();
     case 21:
-      if (lookahead == 'r') ADVANCE(13);
+      if (lookahead == ']') ADVANCE(82);
       END_STATE();
     case 22:
-      if (lookahead == 's') ADVANCE(49);
+      if (lookahead == ']') ADVANCE(83);
       END_STATE();
     case 23:
-      if (lookahead == 's') ADVANCE(11);
+      if (lookahead == ']') ADVANCE(83);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(151);
       END_STATE();
     case 24:
-      if (lookahead == 's') ADVANCE(45);
+      if (lookahead == 'a') ADVANCE(74);
+      if (lookahead == 't') ADVANCE(136);
       END_STATE();
     case 25:
-      if (lookahead == 's') ADVANCE(24);
+      if (lookahead == 'a') ADVANCE(27);
+      if (lookahead == 'l') ADVANCE(68);
+      if (lookahead == 'x') ADVANCE(76);
       END_STATE();
     case 26:
-      if (lookahead == 'u') ADVANCE(10);
+      if (lookahead == 'a') ADVANCE(79);
       END_STATE();
     case 27:
-      if (lookahead == 'u') ADVANCE(21);
+      if (lookahead == 'c') ADVANCE(46);
       END_STATE();
     case 28:
-      if (lookahead == '}') ADVANCE(86);
-      if (lookahead != 0) ADVANCE(3);
+      if (lookahead == 'c') ADVANCE(52);
       END_STATE();
     case 29:
-      if (lookahead == '}') ADVANCE(57);
+      if (lookahead == 'c') ADVANCE(54);
       END_STATE();
     case 30:
-      if (lookahead == '}') ADVANCE(60);
+      if (lookahead == 'c') ADVANCE(77);
       END_STATE();
     case 31:
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(85);
+      if (lookahead == 'd') ADVANCE(140);
       END_STATE();
     case 32:
-      if (eof) ADVANCE(33);
-      if (lookahead == ']') ADVANCE(36);
-      if (lookahead == '{') ADVANCE(35);
-      if (lookahead != 0) ADVANCE(34);
+      if (lookahead == 'd') ADVANCE(134);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == 'd') ADVANCE(67);
       END_STATE();
     case 34:
-      ACCEPT_TOKEN(aux_sym__text_char_token1);
-      if (lookahead != 0 &&
-          lookahead != ']' &&
-          lookahead != '{') ADVANCE(34);
+      if (lookahead == 'd') ADVANCE(36);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_LBRACE);
-      if (lookahead == '[') ADVANCE(59);
+      if (lookahead == 'e') ADVANCE(102);
       END_STATE();
     case 36:
-      ACCEPT_TOKEN(anon_sym_RBRACK);
+      if (lookahead == 'e') ADVANCE(130);
       END_STATE();
     case 37:
-      ACCEPT_TOKEN(anon_sym_QMARK);
+      if (lookahead == 'e') ADVANCE(123);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(anon_sym_BANG);
+      if (lookahead == 'e') ADVANCE(62);
       END_STATE();
     case 39:
-      ACCEPT_TOKEN(anon_sym_POUND);
+      if (lookahead == 'e') ADVANCE(69);
       END_STATE();
     case 40:
-      ACCEPT_TOKEN(anon_sym_if);
+      if (lookahead == 'e') ADVANCE(55);
       END_STATE();
     case 41:
-      ACCEPT_TOKEN(anon_sym_if);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'e') ADVANCE(30);
       END_STATE();
     case 42:
-      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == 'e') ADVANCE(73);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(anon_sym_else);
+      if (lookahead == 'f') ADVANCE(99);
+      if (lookahead == 'n') ADVANCE(29);
       END_STATE();
     case 44:
-      ACCEPT_TOKEN(anon_sym_else);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'f') ADVANCE(45);
+      if (lookahead == 'r') ADVANCE(142);
       END_STATE();
     case 45:
-      ACCEPT_TOKEN(anon_sym_unless);
+      if (lookahead == 'f') ADVANCE(70);
       END_STATE();
     case 46:
-      ACCEPT_TOKEN(anon_sym_unless);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'h') ADVANCE(106);
       END_STATE();
     case 47:
-      ACCEPT_TOKEN(anon_sym_each);
+      if (lookahead == 'h') ADVANCE(115);
       END_STATE();
     case 48:
-      ACCEPT_TOKEN(anon_sym_each);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'i') ADVANCE(57);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(anon_sym_as);
+      if (lookahead == 'i') ADVANCE(40);
       END_STATE();
     case 50:
-      ACCEPT_TOKEN(anon_sym_as);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'i') ADVANCE(75);
       END_STATE();
     case 51:
-      ACCEPT_TOKEN(anon_sym_COMMA);
+      if (lookahead == 'i') ADVANCE(72);
       END_STATE();
     case 52:
-      ACCEPT_TOKEN(anon_sym_unsecure);
+      if (lookahead == 'k') ADVANCE(128);
       END_STATE();
     case 53:
-      ACCEPT_TOKEN(anon_sym_unsecure);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'l') ADVANCE(63);
       END_STATE();
     case 54:
-      ACCEPT_TOKEN(anon_sym_include);
+      if (lookahead == 'l') ADVANCE(78);
       END_STATE();
     case 55:
-      ACCEPT_TOKEN(anon_sym_include);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'l') ADVANCE(32);
       END_STATE();
     case 56:
-      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == 'l') ADVANCE(39);
+      if (lookahead == 's') ADVANCE(41);
       END_STATE();
     case 57:
-      ACCEPT_TOKEN(sym_delimiter_escape);
+      if (lookahead == 'm') ADVANCE(51);
       END_STATE();
     case 58:
-      ACCEPT_TOKEN(sym_tag_open);
-      if (lookahead == '%') ADVANCE(3);
+      if (lookahead == 'n') ADVANCE(31);
+      if (lookahead == 's') ADVANCE(108);
       END_STATE();
     case 59:
-      ACCEPT_TOKEN(sym_tag_open);
-      if (lookahead == '%') ADVANCE(3);
-      if (lookahead == '-') ADVANCE(58);
-      if (lookahead == '{') ADVANCE(4);
+      if (lookahead == 'n') ADVANCE(56);
       END_STATE();
     case 60:
-      ACCEPT_TOKEN(sym_tag_close);
+      if (lookahead == 'n') ADVANCE(110);
       END_STATE();
     case 61:
-      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == 'n') ADVANCE(209);
+      if (set_contains(sym_identifier_character_set_1, 655, lookahead)) ADVANCE(228);
       END_STATE();
     case 62:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'a') ADVANCE(63);
-      if (lookahead == 'l') ADVANCE(79);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'n') ADVANCE(33);
       END_STATE();
     case 63:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'c') ADVANCE(73);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'o') ADVANCE(28);
       END_STATE();
     case 64:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'c') ADVANCE(74);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'r') ADVANCE(137);
       END_STATE();
     case 65:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'c') ADVANCE(83);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'r') ADVANCE(37);
       END_STATE();
     case 66:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'd') ADVANCE(68);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 's') ADVANCE(104);
       END_STATE();
     case 67:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(44);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 's') ADVANCE(132);
       END_STATE();
     case 68:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(55);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 's') ADVANCE(35);
       END_STATE();
     case 69:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(53);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 's') ADVANCE(66);
       END_STATE();
     case 70:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(81);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 's') ADVANCE(42);
       END_STATE();
     case 71:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(65);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 't') ADVANCE(64);
       END_STATE();
     case 72:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'f') ADVANCE(41);
-      if (lookahead == 'n') ADVANCE(64);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 't') ADVANCE(117);
       END_STATE();
     case 73:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'h') ADVANCE(48);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 't') ADVANCE(119);
       END_STATE();
     case 74:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'l') ADVANCE(82);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 't') ADVANCE(71);
       END_STATE();
     case 75:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'l') ADVANCE(70);
-      if (lookahead == 's') ADVANCE(71);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 't') ADVANCE(47);
       END_STATE();
     case 76:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'n') ADVANCE(75);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 't') ADVANCE(38);
       END_STATE();
     case 77:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'r') ADVANCE(69);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'u') ADVANCE(65);
       END_STATE();
     case 78:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 's') ADVANCE(50);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'u') ADVANCE(34);
       END_STATE();
     case 79:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 's') ADVANCE(67);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == 'w') ADVANCE(125);
       END_STATE();
     case 80:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 's') ADVANCE(46);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead != 0) ADVANCE(127);
       END_STATE();
     case 81:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 's') ADVANCE(80);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == '}') ADVANCE(231);
+      if (lookahead != 0) ADVANCE(13);
       END_STATE();
     case 82:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'u') ADVANCE(66);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == '}') ADVANCE(155);
       END_STATE();
     case 83:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'u') ADVANCE(77);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == '}') ADVANCE(160);
       END_STATE();
     case 84:
-      ACCEPT_TOKEN(sym_identifier);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(84);
+      if (lookahead == '"' ||
+          lookahead == '\\') ADVANCE(5);
       END_STATE();
     case 85:
-      ACCEPT_TOKEN(sym_include_name);
-      if (lookahead == '/') ADVANCE(31);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(85);
+      if (lookahead == '-' ||
+          lookahead == '~') ADVANCE(88);
+      if (lookahead != 0 &&
+          lookahead != '/') ADVANCE(127);
       END_STATE();
     case 86:
-      ACCEPT_TOKEN(sym_comment);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(151);
       END_STATE();
     case 87:
-      ACCEPT_TOKEN(sym__ws);
-      if (lookahead == '\t' ||
-          lookahead == '\n' ||
-          lookahead == '\r' ||
-          lookahead == ' ') ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(229);
       END_STATE();
-    default:
-      return false;
-  }
-}
-
-static const TSLexMode ts_lex_modes[STATE_COUNT] = {
-  [0] = {.lex_state = 0},
-  [1] = {.lex_state = 32},
-  [2] = {.lex_state = 32},
-  [3] = {.lex_state = 32},
-  [4] = {.lex_state = 32},
-  [5] = {.lex_state = 32},
-  [6] = {.lex_state = 32},
-  [7] = {.lex_state = 32},
-  [8] = {.lex_state = 32},
-  [9] = {.lex_state = 32},
-  [10] = {.lex_state = 32},
-  [11] = {.lex_state = 32},
-  [12] = {.lex_state = 32},
-  [13] = {.lex_state = 32},
-  [14] = {.lex_state = 32},
-  [15] = {.lex_state = 32},
-  [16] = {.lex_state = 32},
-  [17] = {.lex_state = 32},
-  [18] = {.lex_state = 32},
-  [19] = {.lex_state = 32},
-  [20] = {.lex_state = 32},
-  [21] = {.lex_state = 32},
-  [22] = {.lex_state = 32},
-  [23] = {.lex_state = 32},
-  [24] = {.lex_state = 32},
-  [25] = {.lex_state = 32},
-  [26] = {.lex_state = 32},
-  [27] = {.lex_state = 32},
-  [28] = {.lex_state = 32},
-  [29] = {.lex_state = 32},
-  [30] = {.lex_state = 32},
-  [31] = {.lex_state = 32},
-  [32] = {.lex_state = 32},
-  [33] = {.lex_state = 32},
-  [34] = {.lex_state = 32},
-  [35] = {.lex_state = 32},
-  [36] = {.lex_state = 32},
-  [37] = {.lex_state = 32},
-  [38] = {.lex_state = 32},
-  [39] = {.lex_state = 32},
-  [40] = {.lex_state = 32},
-  [41] = {.lex_state = 32},
-  [42] = {.lex_state = 32},
-  [43] = {.lex_state = 32},
-  [44] = {.lex_state = 32},
-  [45] = {.lex_state = 32},
-  [46] = {.lex_state = 32},
-  [47] = {.lex_state = 32},
-  [48] = {.lex_state = 32},
-  [49] = {.lex_state = 32},
-  [50] = {.lex_state = 32},
-  [51] = {.lex_state = 32},
-  [52] = {.lex_state = 32},
-  [53] = {.lex_state = 2},
-  [54] = {.lex_state = 32},
-  [55] = {.lex_state = 32},
-  [56] = {.lex_state = 32},
-  [57] = {.lex_state = 2},
-  [58] = {.lex_state = 1},
-  [59] = {.lex_state = 32},
-  [60] = {.lex_state = 32},
-  [61] = {.lex_state = 32},
-  [62] = {.lex_state = 1},
-  [63] = {.lex_state = 1},
-  [64] = {.lex_state = 1},
-  [65] = {.lex_state = 32},
-  [66] = {.lex_state = 32},
-  [67] = {.lex_state = 32},
-  [68] = {.lex_state = 32},
-  [69] = {.lex_state = 2},
-  [70] = {.lex_state = 2},
-  [71] = {.lex_state = 32},
-  [72] = {.lex_state = 2},
-  [73] = {.lex_state = 2},
-  [74] = {.lex_state = 2},
-  [75] = {.lex_state = 2},
-  [76] = {.lex_state = 2},
-  [77] = {.lex_state = 1},
-  [78] = {.lex_state = 2},
-  [79] = {.lex_state = 2},
-  [80] = {.lex_state = 2},
-  [81] = {.lex_state = 2},
-  [82] = {.lex_state = 2},
-  [83] = {.lex_state = 1},
-  [84] = {.lex_state = 2},
-  [85] = {.lex_state = 1},
-  [86] = {.lex_state = 2},
-  [87] = {.lex_state = 2},
-  [88] = {.lex_state = 1},
-  [89] = {.lex_state = 1},
-  [90] = {.lex_state = 2},
-  [91] = {.lex_state = 1},
-  [92] = {.lex_state = 2},
-  [93] = {.lex_state = 1},
-  [94] = {.lex_state = 1},
-  [95] = {.lex_state = 1},
-  [96] = {.lex_state = 2},
-  [97] = {.lex_state = 2},
-  [98] = {.lex_state = 2},
-  [99] = {.lex_state = 2},
-  [100] = {.lex_state = 1},
-  [101] = {.lex_state = 1},
-  [102] = {.lex_state = 1},
-  [103] = {.lex_state = 2},
-  [104] = {.lex_state = 2},
-  [105] = {.lex_state = 2},
-  [106] = {.lex_state = 1},
-  [107] = {.lex_state = 1},
-  [108] = {.lex_state = 2},
-  [109] = {.lex_state = 2},
-  [110] = {.lex_state = 2},
-  [111] = {.lex_state = 2},
-  [112] = {.lex_state = 2},
-  [113] = {.lex_state = 2},
-  [114] = {.lex_state = 2},
-  [115] = {.lex_state = 2},
-  [116] = {.lex_state = 2},
-  [117] = {.lex_state = 2},
-  [118] = {.lex_state = 0},
-  [119] = {.lex_state = 2},
-  [120] = {.lex_state = 2},
-  [121] = {.lex_state = 2},
-  [122] = {.lex_state = 2},
-  [123] = {.lex_state = 2},
-  [124] = {.lex_state = 2},
-  [125] = {.lex_state = 2},
-  [126] = {.lex_state = 2},
-  [127] = {.lex_state = 2},
-  [128] = {.lex_state = 2},
-  [129] = {.lex_state = 2},
-  [130] = {.lex_state = 2},
-  [131] = {.lex_state = 2},
-  [132] = {.lex_state = 1},
-  [133] = {.lex_state = 2},
-  [134] = {.lex_state = 1},
-  [135] = {.lex_state = 2},
-  [136] = {.lex_state = 2},
-  [137] = {.lex_state = 1},
-  [138] = {.lex_state = 1},
-  [139] = {.lex_state = 0},
-  [140] = {.lex_state = 0},
-  [141] = {.lex_state = 2},
-  [142] = {.lex_state = 1},
-  [143] = {.lex_state = 2},
-  [144] = {.lex_state = 2},
-  [145] = {.lex_state = 2},
-  [146] = {.lex_state = 2},
-  [147] = {.lex_state = 2},
-  [148] = {.lex_state = 2},
-  [149] = {.lex_state = 0},
-  [150] = {.lex_state = 2},
-  [151] = {.lex_state = 2},
-  [152] = {.lex_state = 0},
-  [153] = {.lex_state = 2},
-  [154] = {.lex_state = 0},
-  [155] = {.lex_state = 2},
-  [156] = {.lex_state = 0},
-  [157] = {.lex_state = 2},
-  [158] = {.lex_state = 2},
-  [159] = {.lex_state = 2},
-  [160] = {.lex_state = 0},
-  [161] = {.lex_state = 0},
-  [162] = {.lex_state = 0},
-  [163] = {.lex_state = 2},
-  [164] = {.lex_state = 2},
-  [165] = {.lex_state = 0},
-  [166] = {.lex_state = 0},
-  [167] = {.lex_state = 2},
-  [168] = {.lex_state = 2},
-  [169] = {.lex_state = 2},
-  [170] = {.lex_state = 1},
-  [171] = {.lex_state = 2},
-  [172] = {.lex_state = 0},
-  [173] = {.lex_state = 0},
-  [174] = {.lex_state = 2},
-  [175] = {.lex_state = 0},
-  [176] = {.lex_state = 2},
-  [177] = {.lex_state = 0},
-  [178] = {.lex_state = 0},
-  [179] = {.lex_state = 1},
-  [180] = {.lex_state = 2},
-  [181] = {.lex_state = 2},
-  [182] = {.lex_state = 2},
-  [183] = {.lex_state = 0},
-  [184] = {.lex_state = 1},
-  [185] = {.lex_state = 2},
-  [186] = {.lex_state = 2},
-  [187] = {.lex_state = 2},
-  [188] = {.lex_state = 0},
-  [189] = {.lex_state = 1},
-  [190] = {.lex_state = 2},
-  [191] = {.lex_state = 2},
-  [192] = {.lex_state = 2},
-  [193] = {.lex_state = 2},
-  [194] = {.lex_state = 2},
-  [195] = {.lex_state = 2},
-  [196] = {.lex_state = 1},
-  [197] = {.lex_state = 2},
-  [198] = {.lex_state = 2},
-  [199] = {.lex_state = 0},
-  [200] = {.lex_state = 2},
-};
-
-static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
-  [0] = {
-    [ts_builtin_sym_end] = ACTIONS(1),
-    [anon_sym_LBRACE] = ACTIONS(1),
-    [anon_sym_RBRACK] = ACTIONS(1),
-    [anon_sym_QMARK] = ACTIONS(1),
-    [anon_sym_BANG] = ACTIONS(1),
-    [anon_sym_POUND] = ACTIONS(1),
-    [anon_sym_if] = ACTIONS(1),
-    [anon_sym_SLASH] = ACTIONS(1),
-    [anon_sym_else] = ACTIONS(1),
-    [anon_sym_unless] = ACTIONS(1),
-    [anon_sym_each] = ACTIONS(1),
-    [anon_sym_as] = ACTIONS(1),
-    [anon_sym_COMMA] = ACTIONS(1),
-    [anon_sym_unsecure] = ACTIONS(1),
-    [anon_sym_include] = ACTIONS(1),
-    [anon_sym_EQ] = ACTIONS(1),
-    [sym_delimiter_escape] = ACTIONS(1),
-    [sym_tag_open] = ACTIONS(1),
-    [anon_sym_DOT] = ACTIONS(1),
-    [sym_identifier] = ACTIONS(1),
-    [sym_comment] = ACTIONS(1),
-    [sym__ws] = ACTIONS(1),
-  },
-  [1] = {
-    [sym_template] = STATE(177),
-    [sym__node] = STATE(7),
-    [sym_text] = STATE(7),
-    [sym__text_char] = STATE(14),
-    [sym_variable] = STATE(7),
-    [sym_if_block] = STATE(7),
-    [sym_if_open] = STATE(2),
-    [sym_unless_block] = STATE(7),
-    [sym_unless_open] = STATE(4),
-    [sym_each_block] = STATE(7),
-    [sym_each_open] = STATE(5),
-    [sym_unsecure_block] = STATE(7),
-    [sym_unsecure_open] = STATE(6),
-    [sym_unsecure_output] = STATE(7),
-    [sym_include] = STATE(7),
-    [aux_sym_template_repeat1] = STATE(7),
-    [aux_sym_text_repeat1] = STATE(14),
-    [ts_builtin_sym_end] = ACTIONS(3),
-    [aux_sym__text_char_token1] = ACTIONS(5),
-    [anon_sym_LBRACE] = ACTIONS(7),
-    [anon_sym_RBRACK] = ACTIONS(5),
-    [sym_delimiter_escape] = ACTIONS(9),
-    [sym_tag_open] = ACTIONS(11),
-    [sym_comment] = ACTIONS(9),
-  },
-};
-
-static const uint16_t ts_small_parse_table[] = {
-  [0] = 13,
-    ACTIONS(7), 1,
-      anon_sym_LBRACE,
-    ACTIONS(15), 1,
-      sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    STATE(12), 1,
-      sym_else_open,
-    STATE(18), 1,
-      sym_if_close,
-    STATE(139), 1,
-      sym_else_clause,
-    ACTIONS(5), 2,
-      aux_sym__text_char_token1,
-      anon_sym_RBRACK,
-    ACTIONS(13), 2,
-      sym_delimiter_escape,
-      sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(3), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [52] = 13,
-    ACTIONS(7), 1,
-      anon_sym_LBRACE,
-    ACTIONS(15), 1,
-      sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    STATE(12), 1,
-      sym_else_open,
-    STATE(41), 1,
-      sym_if_close,
-    STATE(118), 1,
-      sym_else_clause,
-    ACTIONS(5), 2,
-      aux_sym__text_char_token1,
-      anon_sym_RBRACK,
-    ACTIONS(17), 2,
-      sym_delimiter_escape,
-      sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(8), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [104] = 11,
-    ACTIONS(7), 1,
-      anon_sym_LBRACE,
-    ACTIONS(21), 1,
-      sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    STATE(21), 1,
-      sym_unless_close,
-    ACTIONS(5), 2,
-      aux_sym__text_char_token1,
-      anon_sym_RBRACK,
-    ACTIONS(19), 2,
-      sym_delimiter_escape,
-      sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(11), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
+    case 88:
+      if (lookahead != 0 &&
+          lookahead != '/') ADVANCE(127);
+      END_STATE();
+    case 89:
+      if (eof) ADVANCE(90);
+      if (lookahead == ']') ADVANCE(93);
+      if (lookahead == '{') ADVANCE(92);
+      if (lookahead != 0) ADVANCE(91);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(aux_sym__text_char_token1);
+      if (lookahead != 0 &&
+          lookahead != ']' &&
+          lookahead != '{') ADVANCE(91);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (lookahead == '[') ADVANCE(157);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(anon_sym_QMARK);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      if (lookahead == '=') ADVANCE(145);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(anon_sym_PIPE);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(anon_sym_POUND);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(anon_sym_if);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_if);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(anon_sym_else);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(anon_sym_else);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(anon_sym_unless);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(anon_sym_unless);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(anon_sym_each);
+      END_STATE();
+    case 107:
+      ACCEPT_TOKEN(anon_sym_each);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 108:
+      ACCEPT_TOKEN(anon_sym_as);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(anon_sym_as);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(anon_sym_in);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(anon_sym_in);
+      if (lookahead == 'c') ADVANCE(199);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      if (lookahead == '.') ADVANCE(138);
+      END_STATE();
+    case 114:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(anon_sym_with);
+      END_STATE();
+    case 116:
+      ACCEPT_TOKEN(anon_sym_with);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(anon_sym_limit);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(anon_sym_limit);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(anon_sym_offset);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(anon_sym_offset);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(144);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(anon_sym_unsecure);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(anon_sym_unsecure);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(anon_sym_raw);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(anon_sym_raw);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(sym_raw_content);
+      if (lookahead == '{') ADVANCE(20);
+      if (lookahead != 0) ADVANCE(127);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(anon_sym_block);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(anon_sym_block);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(anon_sym_include);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(anon_sym_include);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(anon_sym_extends);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(anon_sym_extends);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(anon_sym_yield);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(anon_sym_yield);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(anon_sym_ATt);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(anon_sym_ATattr);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT_DOT);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(anon_sym_not);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(anon_sym_and);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(anon_sym_and);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(anon_sym_or);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(anon_sym_or);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(146);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(148);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(sym_string);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(sym_integer);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(151);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(anon_sym_true);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(anon_sym_false);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(sym_null);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(sym_delimiter_escape);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(sym_tag_open);
+      if (lookahead == '%') ADVANCE(10);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(sym_tag_open);
+      if (lookahead == '%') ADVANCE(10);
+      if (lookahead == '{') ADVANCE(21);
+      if (lookahead == '-' ||
+          lookahead == '~') ADVANCE(156);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(sym_tag_open);
+      if (lookahead == '-' ||
+          lookahead == '~') ADVANCE(159);
+      if (lookahead != 0 &&
+          lookahead != '/') ADVANCE(127);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(sym_tag_open);
+      if (lookahead != 0 &&
+          lookahead != '/') ADVANCE(127);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(sym_tag_close);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(113);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(112);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(sym_index);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(sym_index);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(165);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(169);
+      if (lookahead == 'l') ADVANCE(214);
+      if (lookahead == 'x') ADVANCE(222);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(227);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(202);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 169:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(189);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 170:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(195);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 171:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(223);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(141);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(135);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(213);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 175:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(179);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 176:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(103);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 177:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(152);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 178:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(153);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 179:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(131);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 180:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(124);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 181:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(206);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 182:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(215);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 183:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(171);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 184:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(220);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 185:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(198);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 186:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'f') ADVANCE(100);
+      if (lookahead == 'n') ADVANCE(111);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 187:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'f') ADVANCE(188);
+      if (lookahead == 'r') ADVANCE(143);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 188:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'f') ADVANCE(217);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 189:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'h') ADVANCE(107);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 190:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'h') ADVANCE(116);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 191:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(203);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 192:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(185);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 193:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(221);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 194:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(219);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 195:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'k') ADVANCE(129);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 196:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(207);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 197:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(154);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 198:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(173);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 199:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(225);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 200:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(197);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 201:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(182);
+      if (lookahead == 's') ADVANCE(183);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 202:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(216);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(194);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 204:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(172);
+      if (lookahead == 's') ADVANCE(109);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 205:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(201);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 206:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(174);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(170);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 208:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(218);
+      if (lookahead == 'u') ADVANCE(200);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 209:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(218);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 210:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(226);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 211:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(180);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 212:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(105);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 213:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(133);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 214:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(176);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 215:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(212);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(178);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 217:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(184);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 218:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(139);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 219:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(118);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 220:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(120);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 221:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(190);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(181);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 223:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(211);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 224:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(200);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 225:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(175);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 226:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(177);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 227:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'w') ADVANCE(126);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 228:
+      ACCEPT_TOKEN(sym_identifier);
+      if (set_contains(sym_identifier_character_set_2, 763, lookahead)) ADVANCE(228);
+      END_STATE();
+    case 229:
+      ACCEPT_TOKEN(sym_include_name);
+      if (lookahead == '/') ADVANCE(87);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(229);
+      END_STATE();
+    case 230:
+      ACCEPT_TOKEN(sym_params_open);
+      END_STATE();
+    case 231:
+      ACCEPT_TOKEN(sym_comment);
+      END_STATE();
+    case 232:
+      ACCEPT_TOKEN(sym__ws);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') ADVANCE(232);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 89},
+  [2] = {.lex_state = 89},
+  [3] = {.lex_state = 89},
+  [4] = {.lex_state = 89},
+  [5] = {.lex_state = 89},
+  [6] = {.lex_state = 89},
+  [7] = {.lex_state = 89},
+  [8] = {.lex_state = 89},
+  [9] = {.lex_state = 89},
+  [10] = {.lex_state = 89},
+  [11] = {.lex_state = 89},
+  [12] = {.lex_state = 89},
+  [13] = {.lex_state = 89},
+  [14] = {.lex_state = 89},
+  [15] = {.lex_state = 89},
+  [16] = {.lex_state = 89},
+  [17] = {.lex_state = 89},
+  [18] = {.lex_state = 1},
+  [19] = {.lex_state = 1},
+  [20] = {.lex_state = 1},
+  [21] = {.lex_state = 1},
+  [22] = {.lex_state = 1},
+  [23] = {.lex_state = 89},
+  [24] = {.lex_state = 1},
+  [25] = {.lex_state = 89},
+  [26] = {.lex_state = 1},
+  [27] = {.lex_state = 3},
+  [28] = {.lex_state = 3},
+  [29] = {.lex_state = 1},
+  [30] = {.lex_state = 1},
+  [31] = {.lex_state = 89},
+  [32] = {.lex_state = 2},
+  [33] = {.lex_state = 89},
+  [34] = {.lex_state = 89},
+  [35] = {.lex_state = 2},
+  [36] = {.lex_state = 89},
+  [37] = {.lex_state = 89},
+  [38] = {.lex_state = 2},
+  [39] = {.lex_state = 89},
+  [40] = {.lex_state = 89},
+  [41] = {.lex_state = 89},
+  [42] = {.lex_state = 89},
+  [43] = {.lex_state = 89},
+  [44] = {.lex_state = 89},
+  [45] = {.lex_state = 89},
+  [46] = {.lex_state = 89},
+  [47] = {.lex_state = 89},
+  [48] = {.lex_state = 2},
+  [49] = {.lex_state = 2},
+  [50] = {.lex_state = 89},
+  [51] = {.lex_state = 89},
+  [52] = {.lex_state = 3},
+  [53] = {.lex_state = 89},
+  [54] = {.lex_state = 89},
+  [55] = {.lex_state = 89},
+  [56] = {.lex_state = 89},
+  [57] = {.lex_state = 89},
+  [58] = {.lex_state = 89},
+  [59] = {.lex_state = 89},
+  [60] = {.lex_state = 89},
+  [61] = {.lex_state = 89},
+  [62] = {.lex_state = 89},
+  [63] = {.lex_state = 89},
+  [64] = {.lex_state = 89},
+  [65] = {.lex_state = 89},
+  [66] = {.lex_state = 89},
+  [67] = {.lex_state = 89},
+  [68] = {.lex_state = 89},
+  [69] = {.lex_state = 89},
+  [70] = {.lex_state = 89},
+  [71] = {.lex_state = 2},
+  [72] = {.lex_state = 89},
+  [73] = {.lex_state = 89},
+  [74] = {.lex_state = 2},
+  [75] = {.lex_state = 89},
+  [76] = {.lex_state = 89},
+  [77] = {.lex_state = 89},
+  [78] = {.lex_state = 89},
+  [79] = {.lex_state = 89},
+  [80] = {.lex_state = 89},
+  [81] = {.lex_state = 89},
+  [82] = {.lex_state = 89},
+  [83] = {.lex_state = 89},
+  [84] = {.lex_state = 89},
+  [85] = {.lex_state = 89},
+  [86] = {.lex_state = 89},
+  [87] = {.lex_state = 89},
+  [88] = {.lex_state = 89},
+  [89] = {.lex_state = 3},
+  [90] = {.lex_state = 4},
+  [91] = {.lex_state = 89},
+  [92] = {.lex_state = 89},
+  [93] = {.lex_state = 89},
+  [94] = {.lex_state = 2},
+  [95] = {.lex_state = 89},
+  [96] = {.lex_state = 89},
+  [97] = {.lex_state = 89},
+  [98] = {.lex_state = 89},
+  [99] = {.lex_state = 89},
+  [100] = {.lex_state = 89},
+  [101] = {.lex_state = 89},
+  [102] = {.lex_state = 89},
+  [103] = {.lex_state = 89},
+  [104] = {.lex_state = 89},
+  [105] = {.lex_state = 4},
+  [106] = {.lex_state = 89},
+  [107] = {.lex_state = 89},
+  [108] = {.lex_state = 89},
+  [109] = {.lex_state = 61},
+  [110] = {.lex_state = 61},
+  [111] = {.lex_state = 1},
+  [112] = {.lex_state = 89},
+  [113] = {.lex_state = 61},
+  [114] = {.lex_state = 61},
+  [115] = {.lex_state = 3},
+  [116] = {.lex_state = 89},
+  [117] = {.lex_state = 89},
+  [118] = {.lex_state = 1},
+  [119] = {.lex_state = 3},
+  [120] = {.lex_state = 89},
+  [121] = {.lex_state = 89},
+  [122] = {.lex_state = 89},
+  [123] = {.lex_state = 1},
+  [124] = {.lex_state = 89},
+  [125] = {.lex_state = 89},
+  [126] = {.lex_state = 89},
+  [127] = {.lex_state = 89},
+  [128] = {.lex_state = 89},
+  [129] = {.lex_state = 89},
+  [130] = {.lex_state = 2},
+  [131] = {.lex_state = 89},
+  [132] = {.lex_state = 61},
+  [133] = {.lex_state = 61},
+  [134] = {.lex_state = 89},
+  [135] = {.lex_state = 89},
+  [136] = {.lex_state = 89},
+  [137] = {.lex_state = 89},
+  [138] = {.lex_state = 4},
+  [139] = {.lex_state = 61},
+  [140] = {.lex_state = 1},
+  [141] = {.lex_state = 89},
+  [142] = {.lex_state = 4},
+  [143] = {.lex_state = 89},
+  [144] = {.lex_state = 1},
+  [145] = {.lex_state = 89},
+  [146] = {.lex_state = 89},
+  [147] = {.lex_state = 89},
+  [148] = {.lex_state = 89},
+  [149] = {.lex_state = 89},
+  [150] = {.lex_state = 89},
+  [151] = {.lex_state = 1},
+  [152] = {.lex_state = 1},
+  [153] = {.lex_state = 1},
+  [154] = {.lex_state = 1},
+  [155] = {.lex_state = 3},
+  [156] = {.lex_state = 2},
+  [157] = {.lex_state = 2},
+  [158] = {.lex_state = 1},
+  [159] = {.lex_state = 2},
+  [160] = {.lex_state = 3},
+  [161] = {.lex_state = 1},
+  [162] = {.lex_state = 1},
+  [163] = {.lex_state = 1},
+  [164] = {.lex_state = 2},
+  [165] = {.lex_state = 1},
+  [166] = {.lex_state = 1},
+  [167] = {.lex_state = 1},
+  [168] = {.lex_state = 3},
+  [169] = {.lex_state = 1},
+  [170] = {.lex_state = 1},
+  [171] = {.lex_state = 2},
+  [172] = {.lex_state = 1},
+  [173] = {.lex_state = 1},
+  [174] = {.lex_state = 2},
+  [175] = {.lex_state = 1},
+  [176] = {.lex_state = 2},
+  [177] = {.lex_state = 1},
+  [178] = {.lex_state = 2},
+  [179] = {.lex_state = 3},
+  [180] = {.lex_state = 1},
+  [181] = {.lex_state = 1},
+  [182] = {.lex_state = 1},
+  [183] = {.lex_state = 2},
+  [184] = {.lex_state = 1},
+  [185] = {.lex_state = 1},
+  [186] = {.lex_state = 1},
+  [187] = {.lex_state = 3},
+  [188] = {.lex_state = 1},
+  [189] = {.lex_state = 2},
+  [190] = {.lex_state = 3},
+  [191] = {.lex_state = 2},
+  [192] = {.lex_state = 1},
+  [193] = {.lex_state = 1},
+  [194] = {.lex_state = 3},
+  [195] = {.lex_state = 1},
+  [196] = {.lex_state = 2},
+  [197] = {.lex_state = 1},
+  [198] = {.lex_state = 1},
+  [199] = {.lex_state = 1},
+  [200] = {.lex_state = 1},
+  [201] = {.lex_state = 1},
+  [202] = {.lex_state = 1},
+  [203] = {.lex_state = 2},
+  [204] = {.lex_state = 1},
+  [205] = {.lex_state = 2},
+  [206] = {.lex_state = 16},
+  [207] = {.lex_state = 3},
+  [208] = {.lex_state = 3},
+  [209] = {.lex_state = 1},
+  [210] = {.lex_state = 3},
+  [211] = {.lex_state = 1},
+  [212] = {.lex_state = 3},
+  [213] = {.lex_state = 1},
+  [214] = {.lex_state = 16},
+  [215] = {.lex_state = 80},
+  [216] = {.lex_state = 16},
+  [217] = {.lex_state = 1},
+  [218] = {.lex_state = 1},
+  [219] = {.lex_state = 1},
+  [220] = {.lex_state = 3},
+  [221] = {.lex_state = 1},
+  [222] = {.lex_state = 1},
+  [223] = {.lex_state = 1},
+  [224] = {.lex_state = 2},
+  [225] = {.lex_state = 2},
+  [226] = {.lex_state = 16},
+  [227] = {.lex_state = 3},
+  [228] = {.lex_state = 1},
+  [229] = {.lex_state = 2},
+  [230] = {.lex_state = 3},
+  [231] = {.lex_state = 3},
+  [232] = {.lex_state = 2},
+  [233] = {.lex_state = 2},
+  [234] = {.lex_state = 1},
+  [235] = {.lex_state = 1},
+  [236] = {.lex_state = 1},
+  [237] = {.lex_state = 1},
+  [238] = {.lex_state = 0},
+  [239] = {.lex_state = 1},
+  [240] = {.lex_state = 1},
+  [241] = {.lex_state = 1},
+  [242] = {.lex_state = 2},
+  [243] = {.lex_state = 6},
+  [244] = {.lex_state = 1},
+  [245] = {.lex_state = 1},
+  [246] = {.lex_state = 1},
+  [247] = {.lex_state = 1},
+  [248] = {.lex_state = 1},
+  [249] = {.lex_state = 1},
+  [250] = {.lex_state = 1},
+  [251] = {.lex_state = 80},
+  [252] = {.lex_state = 1},
+  [253] = {.lex_state = 1},
+  [254] = {.lex_state = 2},
+  [255] = {.lex_state = 1},
+  [256] = {.lex_state = 2},
+  [257] = {.lex_state = 1},
+  [258] = {.lex_state = 1},
+  [259] = {.lex_state = 1},
+  [260] = {.lex_state = 1},
+  [261] = {.lex_state = 2},
+  [262] = {.lex_state = 2},
+  [263] = {.lex_state = 3},
+  [264] = {.lex_state = 80},
+  [265] = {.lex_state = 0},
+  [266] = {.lex_state = 3},
+  [267] = {.lex_state = 0},
+  [268] = {.lex_state = 0},
+  [269] = {.lex_state = 2},
+  [270] = {.lex_state = 3},
+  [271] = {.lex_state = 1},
+  [272] = {.lex_state = 1},
+  [273] = {.lex_state = 1},
+  [274] = {.lex_state = 1},
+  [275] = {.lex_state = 2},
+  [276] = {.lex_state = 1},
+  [277] = {.lex_state = 0},
+  [278] = {.lex_state = 1},
+  [279] = {.lex_state = 1},
+  [280] = {.lex_state = 1},
+  [281] = {.lex_state = 1},
+  [282] = {.lex_state = 2},
+  [283] = {.lex_state = 1},
+  [284] = {.lex_state = 6},
+  [285] = {.lex_state = 1},
+  [286] = {.lex_state = 1},
+  [287] = {.lex_state = 3},
+  [288] = {.lex_state = 80},
+  [289] = {.lex_state = 3},
+  [290] = {.lex_state = 1},
+  [291] = {.lex_state = 1},
+  [292] = {.lex_state = 3},
+  [293] = {.lex_state = 0},
+  [294] = {.lex_state = 3},
+  [295] = {.lex_state = 2},
+  [296] = {.lex_state = 1},
+  [297] = {.lex_state = 1},
+  [298] = {.lex_state = 3},
+  [299] = {.lex_state = 1},
+  [300] = {.lex_state = 1},
+  [301] = {.lex_state = 1},
+  [302] = {.lex_state = 1},
+  [303] = {.lex_state = 2},
+  [304] = {.lex_state = 1},
+  [305] = {.lex_state = 1},
+  [306] = {.lex_state = 1},
+  [307] = {.lex_state = 1},
+  [308] = {.lex_state = 1},
+  [309] = {.lex_state = 2},
+  [310] = {.lex_state = 1},
+  [311] = {.lex_state = 1},
+  [312] = {.lex_state = 2},
+  [313] = {.lex_state = 2},
+  [314] = {.lex_state = 1},
+  [315] = {.lex_state = 1},
+  [316] = {.lex_state = 1},
+  [317] = {.lex_state = 1},
+  [318] = {.lex_state = 1},
+  [319] = {.lex_state = 2},
+  [320] = {.lex_state = 1},
+  [321] = {.lex_state = 16},
+  [322] = {.lex_state = 1},
+  [323] = {.lex_state = 1},
+  [324] = {.lex_state = 1},
+  [325] = {.lex_state = 1},
+  [326] = {.lex_state = 3},
+  [327] = {.lex_state = 0},
+  [328] = {.lex_state = 1},
+  [329] = {.lex_state = 1},
+  [330] = {.lex_state = 1},
+  [331] = {.lex_state = 1},
+  [332] = {.lex_state = 1},
+  [333] = {.lex_state = 2},
+  [334] = {.lex_state = 1},
+  [335] = {.lex_state = 1},
+  [336] = {.lex_state = 1},
+  [337] = {.lex_state = 1},
+  [338] = {.lex_state = 1},
+  [339] = {.lex_state = 0},
+  [340] = {.lex_state = 1},
+  [341] = {.lex_state = 0},
+  [342] = {.lex_state = 2},
+  [343] = {.lex_state = 0},
+  [344] = {.lex_state = 1},
+  [345] = {.lex_state = 1},
+  [346] = {.lex_state = 0},
+  [347] = {.lex_state = 1},
+  [348] = {.lex_state = 1},
+  [349] = {.lex_state = 0},
+  [350] = {.lex_state = 0},
+  [351] = {.lex_state = 1},
+  [352] = {.lex_state = 3},
+  [353] = {.lex_state = 1},
+  [354] = {.lex_state = 0},
+  [355] = {.lex_state = 0},
+  [356] = {.lex_state = 1},
+  [357] = {.lex_state = 1},
+  [358] = {.lex_state = 0},
+  [359] = {.lex_state = 1},
+  [360] = {.lex_state = 1},
+  [361] = {.lex_state = 1},
+  [362] = {.lex_state = 0},
+  [363] = {.lex_state = 1},
+  [364] = {.lex_state = 1},
+  [365] = {.lex_state = 2},
+  [366] = {.lex_state = 0},
+  [367] = {.lex_state = 3},
+  [368] = {.lex_state = 1},
+  [369] = {.lex_state = 1},
+  [370] = {.lex_state = 2},
+  [371] = {.lex_state = 0},
+  [372] = {.lex_state = 2},
+  [373] = {.lex_state = 0},
+  [374] = {.lex_state = 2},
+  [375] = {.lex_state = 3},
+  [376] = {.lex_state = 6},
+  [377] = {.lex_state = 0},
+  [378] = {.lex_state = 1},
+  [379] = {.lex_state = 1},
+  [380] = {.lex_state = 0},
+  [381] = {.lex_state = 3},
+  [382] = {.lex_state = 0},
+  [383] = {.lex_state = 3},
+  [384] = {.lex_state = 0},
+  [385] = {.lex_state = 2},
+  [386] = {.lex_state = 1},
+  [387] = {.lex_state = 1},
+  [388] = {.lex_state = 1},
+  [389] = {.lex_state = 0},
+  [390] = {.lex_state = 1},
+  [391] = {.lex_state = 0},
+  [392] = {.lex_state = 1},
+  [393] = {.lex_state = 0},
+  [394] = {.lex_state = 0},
+  [395] = {.lex_state = 2},
+  [396] = {.lex_state = 2},
+  [397] = {.lex_state = 1},
+  [398] = {.lex_state = 0},
+  [399] = {.lex_state = 1},
+  [400] = {.lex_state = 0},
+  [401] = {.lex_state = 0},
+  [402] = {.lex_state = 1},
+  [403] = {.lex_state = 0},
+  [404] = {.lex_state = 0},
+  [405] = {.lex_state = 0},
+  [406] = {.lex_state = 1},
+  [407] = {.lex_state = 3},
+  [408] = {.lex_state = 0},
+  [409] = {.lex_state = 0},
+  [410] = {.lex_state = 0},
+  [411] = {.lex_state = 0},
+  [412] = {.lex_state = 1},
+  [413] = {.lex_state = 1},
+  [414] = {.lex_state = 1},
+  [415] = {.lex_state = 1},
+  [416] = {.lex_state = 3},
+  [417] = {.lex_state = 0},
+  [418] = {.lex_state = 1},
+  [419] = {.lex_state = 0},
+  [420] = {.lex_state = 2},
+  [421] = {.lex_state = 2},
+  [422] = {.lex_state = 2},
+  [423] = {.lex_state = 1},
+  [424] = {.lex_state = 0},
+  [425] = {.lex_state = 3},
+  [426] = {.lex_state = 6},
+  [427] = {.lex_state = 1},
+  [428] = {.lex_state = 0},
+  [429] = {.lex_state = 0},
+  [430] = {.lex_state = 1},
+  [431] = {.lex_state = 1},
+  [432] = {.lex_state = 1},
+  [433] = {.lex_state = 0},
+  [434] = {.lex_state = 0},
+  [435] = {.lex_state = 1},
+  [436] = {.lex_state = 0},
+  [437] = {.lex_state = 0},
+  [438] = {.lex_state = 0},
+  [439] = {.lex_state = 1},
+  [440] = {.lex_state = 3},
+  [441] = {.lex_state = 1},
+  [442] = {.lex_state = 0},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_QMARK] = ACTIONS(1),
+    [anon_sym_BANG] = ACTIONS(1),
+    [anon_sym_PIPE] = ACTIONS(1),
+    [anon_sym_POUND] = ACTIONS(1),
+    [anon_sym_if] = ACTIONS(1),
+    [anon_sym_SLASH] = ACTIONS(1),
+    [anon_sym_else] = ACTIONS(1),
+    [anon_sym_unless] = ACTIONS(1),
+    [anon_sym_each] = ACTIONS(1),
+    [anon_sym_as] = ACTIONS(1),
+    [anon_sym_in] = ACTIONS(1),
+    [anon_sym_DOT_DOT] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_with] = ACTIONS(1),
+    [anon_sym_limit] = ACTIONS(1),
+    [anon_sym_offset] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_unsecure] = ACTIONS(1),
+    [anon_sym_raw] = ACTIONS(1),
+    [anon_sym_block] = ACTIONS(1),
+    [anon_sym_include] = ACTIONS(1),
+    [anon_sym_extends] = ACTIONS(1),
+    [anon_sym_yield] = ACTIONS(1),
+    [anon_sym_ATt] = ACTIONS(1),
+    [anon_sym_ATattr] = ACTIONS(1),
+    [anon_sym_DOT_DOT_DOT] = ACTIONS(1),
+    [anon_sym_not] = ACTIONS(1),
+    [anon_sym_and] = ACTIONS(1),
+    [anon_sym_or] = ACTIONS(1),
+    [anon_sym_EQ_EQ] = ACTIONS(1),
+    [anon_sym_BANG_EQ] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [sym_string] = ACTIONS(1),
+    [sym_integer] = ACTIONS(1),
+    [anon_sym_true] = ACTIONS(1),
+    [anon_sym_false] = ACTIONS(1),
+    [sym_null] = ACTIONS(1),
+    [sym_delimiter_escape] = ACTIONS(1),
+    [sym_tag_open] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [sym_index] = ACTIONS(1),
+    [sym_identifier] = ACTIONS(1),
+    [sym_params_open] = ACTIONS(1),
+    [sym_comment] = ACTIONS(1),
+    [sym__ws] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_template] = STATE(350),
+    [sym__node] = STATE(8),
+    [sym_text] = STATE(8),
+    [sym__text_char] = STATE(23),
+    [sym_variable] = STATE(8),
+    [sym_if_block] = STATE(8),
+    [sym_if_open] = STATE(2),
+    [sym_unless_block] = STATE(8),
+    [sym_unless_open] = STATE(3),
+    [sym_each_block] = STATE(8),
+    [sym_each_open] = STATE(4),
+    [sym_unsecure_block] = STATE(8),
+    [sym_unsecure_open] = STATE(10),
+    [sym_raw_block] = STATE(8),
+    [sym_raw_open] = STATE(215),
+    [sym_named_block] = STATE(8),
+    [sym_block_open] = STATE(9),
+    [sym_include_block] = STATE(8),
+    [sym_include_open] = STATE(11),
+    [sym_unsecure_output] = STATE(8),
+    [sym_include] = STATE(8),
+    [sym_extends] = STATE(8),
+    [sym_yield] = STATE(8),
+    [sym_translate] = STATE(8),
+    [sym_attr] = STATE(8),
+    [sym_params] = STATE(8),
+    [aux_sym_template_repeat1] = STATE(8),
+    [aux_sym_text_repeat1] = STATE(23),
+    [ts_builtin_sym_end] = ACTIONS(3),
+    [aux_sym__text_char_token1] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(7),
+    [anon_sym_RBRACK] = ACTIONS(5),
+    [sym_delimiter_escape] = ACTIONS(9),
+    [sym_tag_open] = ACTIONS(11),
+    [sym_params_open] = ACTIONS(13),
+    [sym_comment] = ACTIONS(15),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 18,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(17), 1,
+      sym_delimiter_escape,
+    ACTIONS(19), 1,
+      sym_tag_open,
+    ACTIONS(21), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(16), 1,
+      sym_else_open,
+    STATE(59), 1,
+      sym_if_close,
+    STATE(215), 1,
+      sym_raw_open,
+    STATE(327), 1,
+      sym_else_clause,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(6), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [74] = 18,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(23), 1,
+      sym_delimiter_escape,
+    ACTIONS(25), 1,
+      sym_tag_open,
+    ACTIONS(27), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(16), 1,
+      sym_else_open,
+    STATE(33), 1,
+      sym_unless_close,
+    STATE(215), 1,
+      sym_raw_open,
+    STATE(267), 1,
+      sym_else_clause,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(7), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [148] = 18,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(29), 1,
+      sym_delimiter_escape,
+    ACTIONS(31), 1,
+      sym_tag_open,
+    ACTIONS(33), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(16), 1,
+      sym_else_open,
+    STATE(40), 1,
+      sym_each_close,
+    STATE(215), 1,
+      sym_raw_open,
+    STATE(293), 1,
+      sym_else_clause,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(5), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [222] = 18,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(31), 1,
+      sym_tag_open,
+    ACTIONS(35), 1,
+      sym_delimiter_escape,
+    ACTIONS(37), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(16), 1,
+      sym_else_open,
+    STATE(42), 1,
+      sym_each_close,
+    STATE(215), 1,
+      sym_raw_open,
+    STATE(277), 1,
+      sym_else_clause,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [296] = 18,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(19), 1,
+      sym_tag_open,
+    ACTIONS(35), 1,
+      sym_delimiter_escape,
+    ACTIONS(37), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(16), 1,
+      sym_else_open,
+    STATE(39), 1,
+      sym_if_close,
+    STATE(215), 1,
+      sym_raw_open,
+    STATE(265), 1,
+      sym_else_clause,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [370] = 18,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(25), 1,
+      sym_tag_open,
+    ACTIONS(35), 1,
+      sym_delimiter_escape,
+    ACTIONS(37), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(16), 1,
+      sym_else_open,
+    STATE(41), 1,
+      sym_unless_close,
+    STATE(215), 1,
+      sym_raw_open,
+    STATE(268), 1,
+      sym_else_clause,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [444] = 16,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(11), 1,
+      sym_tag_open,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(35), 1,
+      sym_delimiter_escape,
+    ACTIONS(37), 1,
+      sym_comment,
+    ACTIONS(39), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [512] = 16,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(41), 1,
+      sym_delimiter_escape,
+    ACTIONS(43), 1,
+      sym_tag_open,
+    ACTIONS(45), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(88), 1,
+      sym_block_close,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(13), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [580] = 16,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(47), 1,
+      sym_delimiter_escape,
+    ACTIONS(49), 1,
+      sym_tag_open,
+    ACTIONS(51), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(57), 1,
+      sym_unsecure_close,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(12), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [648] = 16,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(53), 1,
+      sym_delimiter_escape,
+    ACTIONS(55), 1,
+      sym_tag_open,
+    ACTIONS(57), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(104), 1,
+      sym_include_close,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(14), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [716] = 16,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(35), 1,
+      sym_delimiter_escape,
+    ACTIONS(37), 1,
+      sym_comment,
+    ACTIONS(49), 1,
+      sym_tag_open,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(43), 1,
+      sym_unsecure_close,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [784] = 16,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(35), 1,
+      sym_delimiter_escape,
+    ACTIONS(37), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      sym_tag_open,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(45), 1,
+      sym_block_close,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [852] = 16,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(35), 1,
+      sym_delimiter_escape,
+    ACTIONS(37), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_tag_open,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(46), 1,
+      sym_include_close,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [920] = 16,
+    ACTIONS(59), 1,
+      ts_builtin_sym_end,
+    ACTIONS(64), 1,
+      anon_sym_LBRACE,
+    ACTIONS(67), 1,
+      sym_delimiter_escape,
+    ACTIONS(70), 1,
+      sym_tag_open,
+    ACTIONS(73), 1,
+      sym_params_open,
+    ACTIONS(76), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(61), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [988] = 15,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(79), 1,
+      sym_delimiter_escape,
+    ACTIONS(81), 1,
+      sym_tag_open,
+    ACTIONS(84), 1,
+      sym_comment,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(17), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
       sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
       sym_unsecure_output,
       sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
       aux_sym_template_repeat1,
-  [150] = 11,
+  [1053] = 15,
     ACTIONS(7), 1,
       anon_sym_LBRACE,
-    ACTIONS(25), 1,
+    ACTIONS(13), 1,
+      sym_params_open,
+    ACTIONS(35), 1,
+      sym_delimiter_escape,
+    ACTIONS(37), 1,
+      sym_comment,
+    ACTIONS(86), 1,
+      sym_tag_open,
+    STATE(2), 1,
+      sym_if_open,
+    STATE(3), 1,
+      sym_unless_open,
+    STATE(4), 1,
+      sym_each_open,
+    STATE(9), 1,
+      sym_block_open,
+    STATE(10), 1,
+      sym_unsecure_open,
+    STATE(11), 1,
+      sym_include_open,
+    STATE(215), 1,
+      sym_raw_open,
+    ACTIONS(5), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    STATE(23), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    STATE(15), 18,
+      sym__node,
+      sym_text,
+      sym_variable,
+      sym_if_block,
+      sym_unless_block,
+      sym_each_block,
+      sym_unsecure_block,
+      sym_raw_block,
+      sym_named_block,
+      sym_include_block,
+      sym_unsecure_output,
+      sym_include,
+      sym_extends,
+      sym_yield,
+      sym_translate,
+      sym_attr,
+      sym_params,
+      aux_sym_template_repeat1,
+  [1118] = 5,
+    ACTIONS(93), 1,
+      anon_sym_DOT,
+    ACTIONS(95), 1,
+      anon_sym_LBRACK,
+    STATE(20), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(91), 3,
+      anon_sym_BANG,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(89), 9,
+      anon_sym_QMARK,
+      anon_sym_PIPE,
+      anon_sym_DOT_DOT,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_tag_close,
+      sym__ws,
+  [1144] = 5,
+    ACTIONS(101), 1,
+      anon_sym_DOT,
+    ACTIONS(104), 1,
+      anon_sym_LBRACK,
+    STATE(19), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(99), 3,
+      anon_sym_BANG,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(97), 9,
+      anon_sym_QMARK,
+      anon_sym_PIPE,
+      anon_sym_DOT_DOT,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_tag_close,
+      sym__ws,
+  [1170] = 5,
+    ACTIONS(93), 1,
+      anon_sym_DOT,
+    ACTIONS(95), 1,
+      anon_sym_LBRACK,
+    STATE(19), 1,
+      aux_sym_path_repeat1,
+    ACTIONS(109), 3,
+      anon_sym_BANG,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(107), 9,
+      anon_sym_QMARK,
+      anon_sym_PIPE,
+      anon_sym_DOT_DOT,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_tag_close,
+      sym__ws,
+  [1196] = 2,
+    ACTIONS(113), 4,
+      anon_sym_BANG,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_DOT,
+    ACTIONS(111), 10,
+      anon_sym_QMARK,
+      anon_sym_PIPE,
+      anon_sym_DOT_DOT,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_tag_close,
+      anon_sym_LBRACK,
+      sym__ws,
+  [1215] = 2,
+    ACTIONS(99), 4,
+      anon_sym_BANG,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_DOT,
+    ACTIONS(97), 10,
+      anon_sym_QMARK,
+      anon_sym_PIPE,
+      anon_sym_DOT_DOT,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_tag_close,
+      anon_sym_LBRACK,
+      sym__ws,
+  [1234] = 5,
+    ACTIONS(119), 1,
+      anon_sym_LBRACE,
+    ACTIONS(117), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    ACTIONS(121), 2,
+      sym_tag_open,
+      sym_comment,
+    STATE(25), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    ACTIONS(115), 3,
+      ts_builtin_sym_end,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1255] = 6,
+    ACTIONS(127), 1,
+      sym_null,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(131), 1,
+      sym__ws,
+    ACTIONS(123), 2,
+      sym_string,
+      sym_integer,
+    ACTIONS(125), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(278), 3,
+      sym__literal,
+      sym_boolean,
+      sym_path,
+  [1278] = 5,
+    ACTIONS(138), 1,
+      anon_sym_LBRACE,
+    ACTIONS(135), 2,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+    ACTIONS(141), 2,
+      sym_tag_open,
+      sym_comment,
+    STATE(25), 2,
+      sym__text_char,
+      aux_sym_text_repeat1,
+    ACTIONS(133), 3,
+      ts_builtin_sym_end,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1299] = 6,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(145), 1,
+      sym_null,
+    ACTIONS(147), 1,
+      sym__ws,
+    ACTIONS(125), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(143), 2,
+      sym_string,
+      sym_integer,
+    STATE(299), 3,
+      sym__literal,
+      sym_boolean,
+      sym_path,
+  [1322] = 5,
+    ACTIONS(153), 1,
+      sym_tag_close,
+    ACTIONS(155), 1,
+      sym__ws,
+    STATE(24), 1,
+      sym_comparison_operator,
+    ACTIONS(151), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(149), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [1342] = 9,
+    ACTIONS(158), 1,
+      anon_sym_if,
+    ACTIONS(160), 1,
+      anon_sym_else,
+    ACTIONS(162), 1,
+      anon_sym_unless,
+    ACTIONS(164), 1,
+      anon_sym_each,
+    ACTIONS(166), 1,
+      anon_sym_unsecure,
+    ACTIONS(168), 1,
+      anon_sym_raw,
+    ACTIONS(170), 1,
+      anon_sym_block,
+    ACTIONS(172), 1,
+      anon_sym_include,
+    ACTIONS(174), 1,
+      sym__ws,
+  [1370] = 5,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(145), 1,
+      sym_null,
+    ACTIONS(125), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(143), 2,
+      sym_string,
+      sym_integer,
+    STATE(299), 3,
+      sym__literal,
+      sym_boolean,
+      sym_path,
+  [1390] = 5,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(178), 1,
+      sym_null,
+    ACTIONS(125), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(176), 2,
+      sym_string,
+      sym_integer,
+    STATE(307), 3,
+      sym__literal,
+      sym_boolean,
+      sym_path,
+  [1410] = 2,
+    ACTIONS(182), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(180), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1423] = 8,
+    ACTIONS(184), 1,
+      anon_sym_BANG,
+    ACTIONS(186), 1,
+      anon_sym_POUND,
+    ACTIONS(188), 1,
+      anon_sym_SLASH,
+    ACTIONS(190), 1,
+      anon_sym_ATt,
+    ACTIONS(192), 1,
+      anon_sym_ATattr,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(196), 1,
+      sym__ws,
+    STATE(71), 1,
+      sym_path,
+  [1448] = 2,
+    ACTIONS(200), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(198), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1461] = 2,
+    ACTIONS(204), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(202), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1474] = 6,
+    ACTIONS(208), 1,
+      anon_sym_PIPE,
+    ACTIONS(210), 1,
+      sym_tag_close,
+    ACTIONS(212), 1,
+      sym__ws,
+    STATE(154), 1,
+      sym_modifier,
+    ACTIONS(206), 2,
+      anon_sym_QMARK,
+      anon_sym_BANG,
+    STATE(158), 2,
+      sym_filter,
+      aux_sym_variable_repeat1,
+  [1495] = 2,
+    ACTIONS(216), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(214), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1508] = 2,
+    ACTIONS(220), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(218), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1521] = 8,
+    ACTIONS(184), 1,
+      anon_sym_BANG,
+    ACTIONS(186), 1,
+      anon_sym_POUND,
+    ACTIONS(190), 1,
+      anon_sym_ATt,
+    ACTIONS(192), 1,
+      anon_sym_ATattr,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(196), 1,
+      sym__ws,
+    ACTIONS(222), 1,
+      anon_sym_SLASH,
+    STATE(71), 1,
+      sym_path,
+  [1546] = 2,
+    ACTIONS(226), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(224), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1559] = 2,
+    ACTIONS(230), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(228), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1572] = 2,
+    ACTIONS(234), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(232), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1585] = 2,
+    ACTIONS(238), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(236), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1598] = 2,
+    ACTIONS(242), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(240), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1611] = 2,
+    ACTIONS(246), 3,
+      anon_sym_LBRACE,
       sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    STATE(16), 1,
-      sym_each_close,
-    ACTIONS(5), 2,
+      sym_comment,
+    ACTIONS(244), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(23), 2,
       sym_delimiter_escape,
+      sym_params_open,
+  [1624] = 2,
+    ACTIONS(250), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
       sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(10), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [196] = 11,
-    ACTIONS(7), 1,
+    ACTIONS(248), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1637] = 2,
+    ACTIONS(254), 3,
       anon_sym_LBRACE,
-    ACTIONS(29), 1,
       sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    STATE(27), 1,
-      sym_unsecure_close,
-    ACTIONS(5), 2,
+      sym_comment,
+    ACTIONS(252), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(27), 2,
       sym_delimiter_escape,
+      sym_params_open,
+  [1650] = 2,
+    ACTIONS(258), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
       sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(9), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [242] = 11,
-    ACTIONS(7), 1,
+    ACTIONS(256), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1663] = 8,
+    ACTIONS(184), 1,
+      anon_sym_BANG,
+    ACTIONS(186), 1,
+      anon_sym_POUND,
+    ACTIONS(190), 1,
+      anon_sym_ATt,
+    ACTIONS(192), 1,
+      anon_sym_ATattr,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(196), 1,
+      sym__ws,
+    ACTIONS(260), 1,
+      anon_sym_SLASH,
+    STATE(71), 1,
+      sym_path,
+  [1688] = 8,
+    ACTIONS(184), 1,
+      anon_sym_BANG,
+    ACTIONS(190), 1,
+      anon_sym_ATt,
+    ACTIONS(192), 1,
+      anon_sym_ATattr,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(196), 1,
+      sym__ws,
+    ACTIONS(262), 1,
+      anon_sym_POUND,
+    ACTIONS(264), 1,
+      anon_sym_SLASH,
+    STATE(71), 1,
+      sym_path,
+  [1713] = 2,
+    ACTIONS(268), 3,
       anon_sym_LBRACE,
-    ACTIONS(11), 1,
       sym_tag_open,
-    ACTIONS(31), 1,
+      sym_comment,
+    ACTIONS(266), 5,
       ts_builtin_sym_end,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    ACTIONS(5), 2,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(17), 2,
       sym_delimiter_escape,
+      sym_params_open,
+  [1726] = 2,
+    ACTIONS(272), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
       sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(8), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [288] = 11,
-    ACTIONS(33), 1,
+    ACTIONS(270), 5,
       ts_builtin_sym_end,
-    ACTIONS(38), 1,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1739] = 8,
+    ACTIONS(274), 1,
+      anon_sym_if,
+    ACTIONS(276), 1,
+      anon_sym_else,
+    ACTIONS(278), 1,
+      anon_sym_unless,
+    ACTIONS(280), 1,
+      anon_sym_each,
+    ACTIONS(282), 1,
+      anon_sym_unsecure,
+    ACTIONS(284), 1,
+      anon_sym_raw,
+    ACTIONS(286), 1,
+      anon_sym_block,
+    ACTIONS(288), 1,
+      anon_sym_include,
+  [1764] = 2,
+    ACTIONS(292), 3,
       anon_sym_LBRACE,
-    ACTIONS(44), 1,
       sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    ACTIONS(35), 2,
+      sym_comment,
+    ACTIONS(290), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(41), 2,
       sym_delimiter_escape,
+      sym_params_open,
+  [1777] = 2,
+    ACTIONS(296), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
       sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(8), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [334] = 11,
-    ACTIONS(7), 1,
+    ACTIONS(294), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1790] = 2,
+    ACTIONS(300), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(298), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1803] = 2,
+    ACTIONS(304), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(302), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1816] = 2,
+    ACTIONS(308), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(306), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1829] = 2,
+    ACTIONS(312), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(310), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1842] = 2,
+    ACTIONS(316), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(314), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1855] = 2,
+    ACTIONS(320), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(318), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1868] = 2,
+    ACTIONS(324), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(322), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1881] = 2,
+    ACTIONS(328), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(326), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1894] = 2,
+    ACTIONS(332), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(330), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1907] = 2,
+    ACTIONS(336), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(334), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1920] = 2,
+    ACTIONS(340), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(338), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1933] = 2,
+    ACTIONS(344), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(342), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1946] = 2,
+    ACTIONS(348), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(346), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1959] = 2,
+    ACTIONS(352), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(350), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1972] = 2,
+    ACTIONS(356), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(354), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1985] = 2,
+    ACTIONS(360), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
+      sym_comment,
+    ACTIONS(358), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [1998] = 6,
+    ACTIONS(208), 1,
+      anon_sym_PIPE,
+    ACTIONS(362), 1,
+      sym_tag_close,
+    ACTIONS(364), 1,
+      sym__ws,
+    STATE(165), 1,
+      sym_modifier,
+    ACTIONS(206), 2,
+      anon_sym_QMARK,
+      anon_sym_BANG,
+    STATE(166), 2,
+      sym_filter,
+      aux_sym_variable_repeat1,
+  [2019] = 2,
+    ACTIONS(368), 3,
       anon_sym_LBRACE,
-    ACTIONS(29), 1,
       sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    STATE(45), 1,
-      sym_unsecure_close,
-    ACTIONS(5), 2,
+      sym_comment,
+    ACTIONS(366), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(17), 2,
       sym_delimiter_escape,
-      sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(8), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [380] = 11,
-    ACTIONS(7), 1,
+      sym_params_open,
+  [2032] = 2,
+    ACTIONS(372), 3,
       anon_sym_LBRACE,
-    ACTIONS(25), 1,
       sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    STATE(24), 1,
-      sym_each_close,
-    ACTIONS(5), 2,
+      sym_comment,
+    ACTIONS(370), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(17), 2,
       sym_delimiter_escape,
-      sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(8), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [426] = 11,
-    ACTIONS(7), 1,
+      sym_params_open,
+  [2045] = 8,
+    ACTIONS(184), 1,
+      anon_sym_BANG,
+    ACTIONS(190), 1,
+      anon_sym_ATt,
+    ACTIONS(192), 1,
+      anon_sym_ATattr,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(196), 1,
+      sym__ws,
+    ACTIONS(262), 1,
+      anon_sym_POUND,
+    ACTIONS(374), 1,
+      anon_sym_SLASH,
+    STATE(71), 1,
+      sym_path,
+  [2070] = 2,
+    ACTIONS(378), 3,
       anon_sym_LBRACE,
-    ACTIONS(21), 1,
       sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    STATE(46), 1,
-      sym_unless_close,
-    ACTIONS(5), 2,
+      sym_comment,
+    ACTIONS(376), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(17), 2,
       sym_delimiter_escape,
-      sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(8), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [472] = 10,
-    ACTIONS(7), 1,
+      sym_params_open,
+  [2083] = 2,
+    ACTIONS(382), 3,
       anon_sym_LBRACE,
-    ACTIONS(49), 1,
       sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    ACTIONS(5), 2,
+      sym_comment,
+    ACTIONS(380), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(47), 2,
       sym_delimiter_escape,
-      sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(13), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [515] = 10,
-    ACTIONS(7), 1,
+      sym_params_open,
+  [2096] = 2,
+    ACTIONS(386), 3,
       anon_sym_LBRACE,
-    ACTIONS(52), 1,
       sym_tag_open,
-    STATE(2), 1,
-      sym_if_open,
-    STATE(4), 1,
-      sym_unless_open,
-    STATE(5), 1,
-      sym_each_open,
-    STATE(6), 1,
-      sym_unsecure_open,
-    ACTIONS(5), 2,
+      sym_comment,
+    ACTIONS(384), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    ACTIONS(17), 2,
       sym_delimiter_escape,
-      sym_comment,
-    STATE(14), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    STATE(8), 10,
-      sym__node,
-      sym_text,
-      sym_variable,
-      sym_if_block,
-      sym_unless_block,
-      sym_each_block,
-      sym_unsecure_block,
-      sym_unsecure_output,
-      sym_include,
-      aux_sym_template_repeat1,
-  [558] = 5,
-    ACTIONS(59), 1,
+      sym_params_open,
+  [2109] = 2,
+    ACTIONS(390), 3,
       anon_sym_LBRACE,
-    ACTIONS(61), 1,
       sym_tag_open,
-    ACTIONS(57), 2,
+      sym_comment,
+    ACTIONS(388), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    STATE(15), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    ACTIONS(55), 3,
-      ts_builtin_sym_end,
       sym_delimiter_escape,
-      sym_comment,
-  [578] = 5,
-    ACTIONS(68), 1,
+      sym_params_open,
+  [2122] = 2,
+    ACTIONS(394), 3,
       anon_sym_LBRACE,
-    ACTIONS(71), 1,
       sym_tag_open,
-    ACTIONS(65), 2,
+      sym_comment,
+    ACTIONS(392), 5,
+      ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
-    STATE(15), 2,
-      sym__text_char,
-      aux_sym_text_repeat1,
-    ACTIONS(63), 3,
-      ts_builtin_sym_end,
       sym_delimiter_escape,
-      sym_comment,
-  [598] = 2,
-    ACTIONS(75), 2,
+      sym_params_open,
+  [2135] = 2,
+    ACTIONS(398), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(73), 5,
+      sym_comment,
+    ACTIONS(396), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [610] = 2,
-    ACTIONS(79), 2,
+      sym_params_open,
+  [2148] = 2,
+    ACTIONS(402), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(77), 5,
+      sym_comment,
+    ACTIONS(400), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [622] = 2,
-    ACTIONS(83), 2,
+      sym_params_open,
+  [2161] = 2,
+    ACTIONS(406), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(81), 5,
+      sym_comment,
+    ACTIONS(404), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [634] = 2,
-    ACTIONS(87), 2,
+      sym_params_open,
+  [2174] = 2,
+    ACTIONS(410), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(85), 5,
+      sym_comment,
+    ACTIONS(408), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [646] = 2,
-    ACTIONS(91), 2,
+      sym_params_open,
+  [2187] = 2,
+    ACTIONS(414), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(89), 5,
+      sym_comment,
+    ACTIONS(412), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [658] = 2,
-    ACTIONS(95), 2,
+      sym_params_open,
+  [2200] = 2,
+    ACTIONS(418), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(93), 5,
+      sym_comment,
+    ACTIONS(416), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [670] = 2,
-    ACTIONS(99), 2,
+      sym_params_open,
+  [2213] = 2,
+    ACTIONS(422), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(97), 5,
+      sym_comment,
+    ACTIONS(420), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [682] = 2,
-    ACTIONS(103), 2,
+      sym_params_open,
+  [2226] = 2,
+    ACTIONS(426), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(101), 5,
+      sym_comment,
+    ACTIONS(424), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [694] = 2,
-    ACTIONS(107), 2,
+      sym_params_open,
+  [2239] = 2,
+    ACTIONS(430), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(105), 5,
+      sym_comment,
+    ACTIONS(428), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [706] = 2,
-    ACTIONS(111), 2,
+      sym_params_open,
+  [2252] = 8,
+    ACTIONS(158), 1,
+      anon_sym_if,
+    ACTIONS(162), 1,
+      anon_sym_unless,
+    ACTIONS(164), 1,
+      anon_sym_each,
+    ACTIONS(166), 1,
+      anon_sym_unsecure,
+    ACTIONS(168), 1,
+      anon_sym_raw,
+    ACTIONS(170), 1,
+      anon_sym_block,
+    ACTIONS(172), 1,
+      anon_sym_include,
+    ACTIONS(432), 1,
+      sym__ws,
+  [2277] = 5,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(436), 1,
+      sym__ws,
+    ACTIONS(125), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(434), 2,
+      sym_string,
+      sym_integer,
+    STATE(302), 2,
+      sym_boolean,
+      sym_path,
+  [2296] = 2,
+    ACTIONS(440), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(109), 5,
+      sym_comment,
+    ACTIONS(438), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [718] = 2,
-    ACTIONS(115), 2,
+      sym_params_open,
+  [2309] = 2,
+    ACTIONS(444), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(113), 5,
+      sym_comment,
+    ACTIONS(442), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [730] = 2,
-    ACTIONS(119), 2,
+      sym_params_open,
+  [2322] = 2,
+    ACTIONS(448), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(117), 5,
+      sym_comment,
+    ACTIONS(446), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [742] = 2,
-    ACTIONS(123), 2,
+      sym_params_open,
+  [2335] = 8,
+    ACTIONS(184), 1,
+      anon_sym_BANG,
+    ACTIONS(190), 1,
+      anon_sym_ATt,
+    ACTIONS(192), 1,
+      anon_sym_ATattr,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(196), 1,
+      sym__ws,
+    ACTIONS(262), 1,
+      anon_sym_POUND,
+    ACTIONS(450), 1,
+      anon_sym_SLASH,
+    STATE(71), 1,
+      sym_path,
+  [2360] = 2,
+    ACTIONS(454), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(121), 5,
+      sym_comment,
+    ACTIONS(452), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [754] = 2,
-    ACTIONS(127), 2,
+      sym_params_open,
+  [2373] = 2,
+    ACTIONS(458), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(125), 5,
+      sym_comment,
+    ACTIONS(456), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [766] = 2,
-    ACTIONS(131), 2,
+      sym_params_open,
+  [2386] = 2,
+    ACTIONS(462), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(129), 5,
+      sym_comment,
+    ACTIONS(460), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [778] = 2,
-    ACTIONS(135), 2,
+      sym_params_open,
+  [2399] = 2,
+    ACTIONS(466), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(133), 5,
+      sym_comment,
+    ACTIONS(464), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [790] = 2,
-    ACTIONS(139), 2,
+      sym_params_open,
+  [2412] = 2,
+    ACTIONS(470), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(137), 5,
+      sym_comment,
+    ACTIONS(468), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [802] = 2,
-    ACTIONS(143), 2,
+      sym_params_open,
+  [2425] = 2,
+    ACTIONS(474), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(141), 5,
+      sym_comment,
+    ACTIONS(472), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [814] = 2,
-    ACTIONS(147), 2,
+      sym_params_open,
+  [2438] = 2,
+    ACTIONS(478), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(145), 5,
+      sym_comment,
+    ACTIONS(476), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [826] = 2,
-    ACTIONS(151), 2,
+      sym_params_open,
+  [2451] = 2,
+    ACTIONS(482), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(149), 5,
+      sym_comment,
+    ACTIONS(480), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [838] = 2,
-    ACTIONS(155), 2,
+      sym_params_open,
+  [2464] = 2,
+    ACTIONS(486), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(153), 5,
+      sym_comment,
+    ACTIONS(484), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
+      sym_params_open,
+  [2477] = 2,
+    ACTIONS(490), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
       sym_comment,
-  [850] = 2,
-    ACTIONS(159), 2,
+    ACTIONS(488), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [2490] = 5,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(494), 1,
+      sym__ws,
+    ACTIONS(125), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(492), 2,
+      sym_string,
+      sym_integer,
+    STATE(311), 2,
+      sym_boolean,
+      sym_path,
+  [2509] = 2,
+    ACTIONS(498), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(157), 5,
+      sym_comment,
+    ACTIONS(496), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
+      sym_params_open,
+  [2522] = 2,
+    ACTIONS(502), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
       sym_comment,
-  [862] = 2,
-    ACTIONS(163), 2,
+    ACTIONS(500), 5,
+      ts_builtin_sym_end,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [2535] = 2,
+    ACTIONS(506), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(161), 5,
+      sym_comment,
+    ACTIONS(504), 5,
       ts_builtin_sym_end,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
+      sym_params_open,
+  [2548] = 4,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(508), 1,
+      anon_sym_not,
+    STATE(27), 1,
+      sym_path,
+    STATE(273), 4,
+      sym__condition,
+      sym_not_condition,
+      sym_logical_condition,
+      sym_comparison,
+  [2564] = 4,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(508), 1,
+      anon_sym_not,
+    STATE(27), 1,
+      sym_path,
+    STATE(283), 4,
+      sym__condition,
+      sym_not_condition,
+      sym_logical_condition,
+      sym_comparison,
+  [2580] = 7,
+    ACTIONS(89), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(93), 1,
+      anon_sym_DOT,
+    ACTIONS(95), 1,
+      anon_sym_LBRACK,
+    ACTIONS(510), 1,
+      anon_sym_COMMA,
+    ACTIONS(512), 1,
+      sym__ws,
+    STATE(20), 1,
+      aux_sym_path_repeat1,
+    STATE(380), 1,
+      sym_each_index,
+  [2602] = 2,
+    ACTIONS(517), 3,
+      anon_sym_LBRACE,
+      sym_tag_open,
       sym_comment,
-  [874] = 2,
-    ACTIONS(167), 2,
+    ACTIONS(515), 4,
+      aux_sym__text_char_token1,
+      anon_sym_RBRACK,
+      sym_delimiter_escape,
+      sym_params_open,
+  [2614] = 4,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(508), 1,
+      anon_sym_not,
+    STATE(27), 1,
+      sym_path,
+    STATE(247), 4,
+      sym__condition,
+      sym_not_condition,
+      sym_logical_condition,
+      sym_comparison,
+  [2630] = 4,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(508), 1,
+      anon_sym_not,
+    STATE(27), 1,
+      sym_path,
+    STATE(248), 4,
+      sym__condition,
+      sym_not_condition,
+      sym_logical_condition,
+      sym_comparison,
+  [2646] = 7,
+    ACTIONS(274), 1,
+      anon_sym_if,
+    ACTIONS(278), 1,
+      anon_sym_unless,
+    ACTIONS(280), 1,
+      anon_sym_each,
+    ACTIONS(282), 1,
+      anon_sym_unsecure,
+    ACTIONS(284), 1,
+      anon_sym_raw,
+    ACTIONS(286), 1,
+      anon_sym_block,
+    ACTIONS(288), 1,
+      anon_sym_include,
+  [2668] = 2,
+    ACTIONS(521), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(165), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(519), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [886] = 2,
-    ACTIONS(171), 2,
+      sym_params_open,
+  [2680] = 2,
+    ACTIONS(525), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(169), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(523), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [898] = 2,
-    ACTIONS(175), 2,
+      sym_params_open,
+  [2692] = 2,
+    ACTIONS(527), 3,
+      sym_string,
+      sym_integer,
+      sym__ws,
+    ACTIONS(529), 4,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null,
+      sym_identifier,
+  [2704] = 3,
+    STATE(26), 1,
+      sym_comparison_operator,
+    ACTIONS(151), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(149), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [2718] = 2,
+    ACTIONS(533), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(173), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(531), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [910] = 2,
-    ACTIONS(179), 2,
+      sym_params_open,
+  [2730] = 2,
+    ACTIONS(537), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(177), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(535), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [922] = 2,
-    ACTIONS(183), 2,
+      sym_params_open,
+  [2742] = 2,
+    ACTIONS(541), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(181), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(539), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [934] = 2,
-    ACTIONS(187), 2,
+      sym_params_open,
+  [2754] = 7,
+    ACTIONS(89), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(93), 1,
+      anon_sym_DOT,
+    ACTIONS(95), 1,
+      anon_sym_LBRACK,
+    ACTIONS(510), 1,
+      anon_sym_COMMA,
+    ACTIONS(543), 1,
+      sym__ws,
+    STATE(20), 1,
+      aux_sym_path_repeat1,
+    STATE(437), 1,
+      sym_each_index,
+  [2776] = 2,
+    ACTIONS(548), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(185), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(546), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [946] = 2,
-    ACTIONS(191), 2,
+      sym_params_open,
+  [2788] = 2,
+    ACTIONS(552), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(189), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(550), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [958] = 2,
-    ACTIONS(195), 2,
+      sym_params_open,
+  [2800] = 2,
+    ACTIONS(556), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(193), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(554), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [970] = 2,
-    ACTIONS(199), 2,
+      sym_params_open,
+  [2812] = 2,
+    ACTIONS(560), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(197), 5,
-      ts_builtin_sym_end,
+      sym_comment,
+    ACTIONS(558), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [982] = 2,
-    ACTIONS(203), 2,
+      sym_params_open,
+  [2824] = 2,
+    ACTIONS(564), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(201), 4,
+      sym_comment,
+    ACTIONS(562), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [993] = 2,
-    ACTIONS(207), 2,
+      sym_params_open,
+  [2836] = 2,
+    ACTIONS(568), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(205), 4,
+      sym_comment,
+    ACTIONS(566), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1004] = 2,
-    ACTIONS(211), 2,
+      sym_params_open,
+  [2848] = 7,
+    ACTIONS(184), 1,
+      anon_sym_BANG,
+    ACTIONS(190), 1,
+      anon_sym_ATt,
+    ACTIONS(192), 1,
+      anon_sym_ATattr,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(196), 1,
+      sym__ws,
+    ACTIONS(262), 1,
+      anon_sym_POUND,
+    STATE(71), 1,
+      sym_path,
+  [2870] = 2,
+    ACTIONS(572), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(209), 4,
+      sym_comment,
+    ACTIONS(570), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1015] = 2,
-    ACTIONS(215), 2,
+      sym_params_open,
+  [2882] = 4,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(508), 1,
+      anon_sym_not,
+    STATE(27), 1,
+      sym_path,
+    STATE(305), 4,
+      sym__condition,
+      sym_not_condition,
+      sym_logical_condition,
+      sym_comparison,
+  [2898] = 4,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(508), 1,
+      anon_sym_not,
+    STATE(27), 1,
+      sym_path,
+    STATE(306), 4,
+      sym__condition,
+      sym_not_condition,
+      sym_logical_condition,
+      sym_comparison,
+  [2914] = 2,
+    ACTIONS(576), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(213), 4,
+      sym_comment,
+    ACTIONS(574), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1026] = 2,
-    ACTIONS(219), 2,
+      sym_params_open,
+  [2926] = 2,
+    ACTIONS(580), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(217), 4,
+      sym_comment,
+    ACTIONS(578), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1037] = 6,
-    ACTIONS(221), 1,
-      anon_sym_if,
-    ACTIONS(223), 1,
-      anon_sym_else,
-    ACTIONS(225), 1,
-      anon_sym_unless,
-    ACTIONS(227), 1,
-      anon_sym_each,
-    ACTIONS(229), 1,
-      anon_sym_unsecure,
-    ACTIONS(231), 1,
-      sym__ws,
-  [1056] = 2,
-    ACTIONS(235), 2,
+      sym_params_open,
+  [2938] = 2,
+    ACTIONS(584), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(233), 4,
+      sym_comment,
+    ACTIONS(582), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1067] = 2,
-    ACTIONS(239), 2,
+      sym_params_open,
+  [2950] = 2,
+    ACTIONS(588), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(237), 4,
+      sym_comment,
+    ACTIONS(586), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1078] = 2,
-    ACTIONS(243), 2,
+      sym_params_open,
+  [2962] = 4,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(125), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(492), 2,
+      sym_string,
+      sym_integer,
+    STATE(311), 2,
+      sym_boolean,
+      sym_path,
+  [2978] = 4,
+    ACTIONS(129), 1,
+      sym_identifier,
+    ACTIONS(508), 1,
+      anon_sym_not,
+    STATE(27), 1,
+      sym_path,
+    STATE(274), 4,
+      sym__condition,
+      sym_not_condition,
+      sym_logical_condition,
+      sym_comparison,
+  [2994] = 6,
+    ACTIONS(510), 1,
+      anon_sym_COMMA,
+    ACTIONS(590), 1,
+      sym_tag_close,
+    ACTIONS(592), 1,
+      sym__ws,
+    STATE(167), 1,
+      sym_each_index,
+    STATE(180), 1,
+      sym_each_meta,
+    STATE(181), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3014] = 2,
+    ACTIONS(596), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(241), 4,
+      sym_comment,
+    ACTIONS(594), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1089] = 3,
-    ACTIONS(247), 1,
-      anon_sym_DOT,
-    STATE(69), 1,
-      aux_sym_path_repeat1,
-    ACTIONS(245), 4,
-      anon_sym_QMARK,
-      anon_sym_BANG,
-      sym_tag_close,
-      sym__ws,
-  [1102] = 6,
-    ACTIONS(249), 1,
-      anon_sym_BANG,
-    ACTIONS(251), 1,
-      anon_sym_POUND,
-    ACTIONS(253), 1,
-      anon_sym_SLASH,
-    ACTIONS(255), 1,
+      sym_params_open,
+  [3026] = 4,
+    ACTIONS(129), 1,
       sym_identifier,
-    ACTIONS(257), 1,
-      sym__ws,
-    STATE(72), 1,
+    ACTIONS(125), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(598), 2,
+      sym_string,
+      sym_integer,
+    STATE(318), 2,
+      sym_boolean,
       sym_path,
-  [1121] = 2,
-    ACTIONS(261), 2,
+  [3042] = 2,
+    ACTIONS(602), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(259), 4,
+      sym_comment,
+    ACTIONS(600), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1132] = 2,
-    ACTIONS(265), 2,
+      sym_params_open,
+  [3054] = 6,
+    ACTIONS(510), 1,
+      anon_sym_COMMA,
+    ACTIONS(604), 1,
+      sym_tag_close,
+    ACTIONS(606), 1,
+      sym__ws,
+    STATE(169), 1,
+      sym_each_index,
+    STATE(188), 1,
+      sym_each_meta,
+    STATE(185), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3074] = 2,
+    ACTIONS(610), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(263), 4,
+      sym_comment,
+    ACTIONS(608), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1143] = 2,
-    ACTIONS(269), 2,
+      sym_params_open,
+  [3086] = 2,
+    ACTIONS(614), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(267), 4,
+      sym_comment,
+    ACTIONS(612), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1154] = 6,
-    ACTIONS(249), 1,
-      anon_sym_BANG,
-    ACTIONS(255), 1,
-      sym_identifier,
-    ACTIONS(257), 1,
-      sym__ws,
-    ACTIONS(271), 1,
-      anon_sym_POUND,
-    ACTIONS(273), 1,
-      anon_sym_SLASH,
-    STATE(72), 1,
-      sym_path,
-  [1173] = 6,
-    ACTIONS(249), 1,
-      anon_sym_BANG,
-    ACTIONS(255), 1,
-      sym_identifier,
-    ACTIONS(257), 1,
-      sym__ws,
-    ACTIONS(271), 1,
-      anon_sym_POUND,
-    ACTIONS(275), 1,
-      anon_sym_SLASH,
-    STATE(72), 1,
-      sym_path,
-  [1192] = 6,
-    ACTIONS(249), 1,
-      anon_sym_BANG,
-    ACTIONS(255), 1,
-      sym_identifier,
-    ACTIONS(257), 1,
-      sym__ws,
-    ACTIONS(271), 1,
-      anon_sym_POUND,
-    ACTIONS(277), 1,
-      anon_sym_SLASH,
-    STATE(72), 1,
-      sym_path,
-  [1211] = 2,
-    ACTIONS(281), 2,
+      sym_params_open,
+  [3098] = 2,
+    ACTIONS(618), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(279), 4,
+      sym_comment,
+    ACTIONS(616), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1222] = 2,
-    ACTIONS(285), 2,
+      sym_params_open,
+  [3110] = 2,
+    ACTIONS(622), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(283), 4,
+      sym_comment,
+    ACTIONS(620), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1233] = 2,
-    ACTIONS(289), 2,
+      sym_params_open,
+  [3122] = 2,
+    ACTIONS(626), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(287), 4,
+      sym_comment,
+    ACTIONS(624), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1244] = 2,
-    ACTIONS(293), 2,
+      sym_params_open,
+  [3134] = 2,
+    ACTIONS(630), 3,
       anon_sym_LBRACE,
       sym_tag_open,
-    ACTIONS(291), 4,
+      sym_comment,
+    ACTIONS(628), 4,
       aux_sym__text_char_token1,
       anon_sym_RBRACK,
       sym_delimiter_escape,
-      sym_comment,
-  [1255] = 3,
-    ACTIONS(247), 1,
-      anon_sym_DOT,
-    STATE(70), 1,
-      aux_sym_path_repeat1,
-    ACTIONS(295), 4,
-      anon_sym_QMARK,
-      anon_sym_BANG,
+      sym_params_open,
+  [3146] = 5,
+    ACTIONS(604), 1,
       sym_tag_close,
+    ACTIONS(632), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(634), 1,
       sym__ws,
-  [1268] = 3,
-    ACTIONS(299), 1,
-      anon_sym_DOT,
-    STATE(70), 1,
-      aux_sym_path_repeat1,
-    ACTIONS(297), 4,
-      anon_sym_QMARK,
-      anon_sym_BANG,
+    STATE(188), 1,
+      sym_each_meta,
+    STATE(185), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3163] = 5,
+    ACTIONS(632), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(636), 1,
       sym_tag_close,
+    ACTIONS(638), 1,
       sym__ws,
-  [1281] = 2,
-    ACTIONS(304), 2,
-      anon_sym_LBRACE,
-      sym_tag_open,
-    ACTIONS(302), 4,
-      aux_sym__text_char_token1,
-      anon_sym_RBRACK,
-      sym_delimiter_escape,
-      sym_comment,
-  [1292] = 4,
-    ACTIONS(308), 1,
+    STATE(195), 1,
+      sym_each_meta,
+    STATE(193), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3180] = 5,
+    ACTIONS(590), 1,
       sym_tag_close,
-    ACTIONS(310), 1,
+    ACTIONS(632), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(640), 1,
       sym__ws,
-    STATE(144), 1,
-      sym_modifier,
-    ACTIONS(306), 2,
-      anon_sym_QMARK,
-      anon_sym_BANG,
-  [1306] = 4,
-    ACTIONS(312), 1,
+    STATE(180), 1,
+      sym_each_meta,
+    STATE(181), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3197] = 4,
+    ACTIONS(208), 1,
+      anon_sym_PIPE,
+    ACTIONS(642), 1,
       sym_tag_close,
-    ACTIONS(314), 1,
+    ACTIONS(644), 1,
       sym__ws,
-    STATE(128), 1,
-      sym_modifier,
-    ACTIONS(306), 2,
-      anon_sym_QMARK,
-      anon_sym_BANG,
-  [1320] = 1,
-    ACTIONS(297), 5,
-      anon_sym_QMARK,
-      anon_sym_BANG,
+    STATE(162), 2,
+      sym_filter,
+      aux_sym_variable_repeat1,
+  [3211] = 4,
+    ACTIONS(636), 1,
       sym_tag_close,
-      anon_sym_DOT,
+    ACTIONS(646), 1,
+      anon_sym_COMMA,
+    ACTIONS(648), 1,
+      anon_sym_with,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [3225] = 4,
+    ACTIONS(652), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(654), 1,
+      sym_tag_close,
+    ACTIONS(656), 1,
+      sym_identifier,
+    STATE(271), 2,
+      sym_spread_arg,
+      sym_include_arg,
+  [3239] = 4,
+    ACTIONS(652), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(656), 1,
+      sym_identifier,
+    ACTIONS(658), 1,
+      sym_tag_close,
+    STATE(271), 2,
+      sym_spread_arg,
+      sym_include_arg,
+  [3253] = 4,
+    ACTIONS(208), 1,
+      anon_sym_PIPE,
+    ACTIONS(642), 1,
+      sym_tag_close,
+    ACTIONS(644), 1,
       sym__ws,
-  [1328] = 5,
-    ACTIONS(221), 1,
-      anon_sym_if,
-    ACTIONS(225), 1,
-      anon_sym_unless,
-    ACTIONS(227), 1,
-      anon_sym_each,
-    ACTIONS(229), 1,
+    STATE(161), 2,
+      sym_filter,
+      aux_sym_variable_repeat1,
+  [3267] = 4,
+    ACTIONS(652), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(656), 1,
+      sym_identifier,
+    ACTIONS(660), 1,
+      sym_tag_close,
+    STATE(271), 2,
+      sym_spread_arg,
+      sym_include_arg,
+  [3281] = 5,
+    ACTIONS(662), 1,
       anon_sym_unsecure,
-    ACTIONS(316), 1,
+    ACTIONS(664), 1,
+      anon_sym_include,
+    ACTIONS(666), 1,
+      anon_sym_extends,
+    ACTIONS(668), 1,
+      anon_sym_yield,
+    ACTIONS(670), 1,
       sym__ws,
-  [1344] = 5,
-    ACTIONS(318), 1,
-      anon_sym_if,
-    ACTIONS(320), 1,
-      anon_sym_else,
-    ACTIONS(322), 1,
-      anon_sym_unless,
-    ACTIONS(324), 1,
-      anon_sym_each,
-    ACTIONS(326), 1,
+  [3297] = 4,
+    ACTIONS(672), 1,
+      anon_sym_PIPE,
+    ACTIONS(675), 1,
+      sym_tag_close,
+    ACTIONS(677), 1,
+      sym__ws,
+    STATE(161), 2,
+      sym_filter,
+      aux_sym_variable_repeat1,
+  [3311] = 4,
+    ACTIONS(208), 1,
+      anon_sym_PIPE,
+    ACTIONS(680), 1,
+      sym_tag_close,
+    ACTIONS(682), 1,
+      sym__ws,
+    STATE(161), 2,
+      sym_filter,
+      aux_sym_variable_repeat1,
+  [3325] = 4,
+    ACTIONS(590), 1,
+      sym_tag_close,
+    ACTIONS(640), 1,
+      sym__ws,
+    STATE(180), 1,
+      sym_each_meta,
+    STATE(181), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3339] = 4,
+    ACTIONS(652), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(656), 1,
+      sym_identifier,
+    ACTIONS(684), 1,
+      sym_tag_close,
+    STATE(271), 2,
+      sym_spread_arg,
+      sym_include_arg,
+  [3353] = 4,
+    ACTIONS(208), 1,
+      anon_sym_PIPE,
+    ACTIONS(210), 1,
+      sym_tag_close,
+    ACTIONS(212), 1,
+      sym__ws,
+    STATE(158), 2,
+      sym_filter,
+      aux_sym_variable_repeat1,
+  [3367] = 4,
+    ACTIONS(208), 1,
+      anon_sym_PIPE,
+    ACTIONS(210), 1,
+      sym_tag_close,
+    ACTIONS(212), 1,
+      sym__ws,
+    STATE(161), 2,
+      sym_filter,
+      aux_sym_variable_repeat1,
+  [3381] = 4,
+    ACTIONS(604), 1,
+      sym_tag_close,
+    ACTIONS(634), 1,
+      sym__ws,
+    STATE(188), 1,
+      sym_each_meta,
+    STATE(185), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3395] = 4,
+    ACTIONS(604), 1,
+      sym_tag_close,
+    ACTIONS(646), 1,
+      anon_sym_COMMA,
+    ACTIONS(648), 1,
+      anon_sym_with,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [3409] = 4,
+    ACTIONS(636), 1,
+      sym_tag_close,
+    ACTIONS(638), 1,
+      sym__ws,
+    STATE(195), 1,
+      sym_each_meta,
+    STATE(193), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3423] = 4,
+    ACTIONS(686), 1,
+      anon_sym_COMMA,
+    ACTIONS(689), 1,
+      sym_tag_close,
+    ACTIONS(691), 1,
+      sym__ws,
+    STATE(170), 1,
+      aux_sym_params_repeat1,
+  [3436] = 4,
+    ACTIONS(694), 1,
+      sym_integer,
+    ACTIONS(696), 1,
+      sym_identifier,
+    STATE(249), 1,
+      sym_path,
+    STATE(429), 1,
+      sym_each_range,
+  [3449] = 4,
+    ACTIONS(654), 1,
+      sym_tag_close,
+    ACTIONS(698), 1,
+      sym__ws,
+    STATE(228), 1,
+      aux_sym_include_call_args_repeat1,
+    STATE(286), 1,
+      sym_include_call_args,
+  [3462] = 4,
+    ACTIONS(700), 1,
+      anon_sym_COMMA,
+    ACTIONS(702), 1,
+      sym_tag_close,
+    ACTIONS(704), 1,
+      sym__ws,
+    STATE(184), 1,
+      aux_sym_params_repeat1,
+  [3475] = 4,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(694), 1,
+      sym_integer,
+    STATE(153), 1,
+      sym_path,
+    STATE(163), 1,
+      sym_each_range,
+  [3488] = 4,
+    ACTIONS(706), 1,
+      sym_tag_close,
+    ACTIONS(708), 1,
+      sym__ws,
+    STATE(228), 1,
+      aux_sym_include_call_args_repeat1,
+    STATE(290), 1,
+      sym_include_call_args,
+  [3501] = 4,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(694), 1,
+      sym_integer,
+    STATE(151), 1,
+      sym_path,
+    STATE(167), 1,
+      sym_each_range,
+  [3514] = 4,
+    ACTIONS(684), 1,
+      sym_tag_close,
+    ACTIONS(710), 1,
+      sym__ws,
+    STATE(228), 1,
+      aux_sym_include_call_args_repeat1,
+    STATE(272), 1,
+      sym_include_call_args,
+  [3527] = 3,
+    ACTIONS(652), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(656), 1,
+      sym_identifier,
+    STATE(271), 2,
+      sym_spread_arg,
+      sym_include_arg,
+  [3538] = 3,
+    ACTIONS(604), 1,
+      sym_tag_close,
+    ACTIONS(648), 1,
+      anon_sym_with,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [3549] = 3,
+    ACTIONS(604), 1,
+      sym_tag_close,
+    ACTIONS(712), 1,
+      sym__ws,
+    STATE(185), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3560] = 3,
+    ACTIONS(604), 1,
+      sym_tag_close,
+    ACTIONS(712), 1,
+      sym__ws,
+    STATE(186), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3571] = 4,
+    ACTIONS(714), 1,
+      sym_tag_close,
+    ACTIONS(716), 1,
+      sym__ws,
+    STATE(202), 1,
+      aux_sym_include_args_repeat1,
+    STATE(301), 1,
+      sym_include_args,
+  [3584] = 4,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(694), 1,
+      sym_integer,
+    STATE(152), 1,
+      sym_path,
+    STATE(169), 1,
+      sym_each_range,
+  [3597] = 4,
+    ACTIONS(700), 1,
+      anon_sym_COMMA,
+    ACTIONS(718), 1,
+      sym_tag_close,
+    ACTIONS(720), 1,
+      sym__ws,
+    STATE(170), 1,
+      aux_sym_params_repeat1,
+  [3610] = 3,
+    ACTIONS(636), 1,
+      sym_tag_close,
+    ACTIONS(722), 1,
+      sym__ws,
+    STATE(186), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3621] = 3,
+    ACTIONS(724), 1,
+      sym_tag_close,
+    ACTIONS(726), 1,
+      sym__ws,
+    STATE(186), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3632] = 3,
+    ACTIONS(636), 1,
+      sym_tag_close,
+    ACTIONS(648), 1,
+      anon_sym_with,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [3643] = 3,
+    ACTIONS(636), 1,
+      sym_tag_close,
+    ACTIONS(722), 1,
+      sym__ws,
+    STATE(193), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3654] = 4,
+    ACTIONS(694), 1,
+      sym_integer,
+    ACTIONS(729), 1,
+      sym_identifier,
+    STATE(285), 1,
+      sym_path,
+    STATE(434), 1,
+      sym_each_range,
+  [3667] = 4,
+    ACTIONS(731), 1,
       anon_sym_unsecure,
-  [1360] = 5,
-    ACTIONS(249), 1,
-      anon_sym_BANG,
-    ACTIONS(255), 1,
+    ACTIONS(733), 1,
+      anon_sym_include,
+    ACTIONS(735), 1,
+      anon_sym_extends,
+    ACTIONS(737), 1,
+      anon_sym_yield,
+  [3680] = 4,
+    ACTIONS(194), 1,
       sym_identifier,
-    ACTIONS(257), 1,
+    ACTIONS(739), 1,
+      sym_integer,
+    ACTIONS(741), 1,
       sym__ws,
-    ACTIONS(271), 1,
-      anon_sym_POUND,
-    STATE(72), 1,
+    STATE(330), 1,
+      sym_path,
+  [3693] = 2,
+    ACTIONS(743), 1,
+      anon_sym_QMARK,
+    ACTIONS(745), 3,
+      anon_sym_COMMA,
+      sym_tag_close,
+      sym__ws,
+  [3702] = 3,
+    ACTIONS(747), 1,
+      sym_tag_close,
+    ACTIONS(749), 1,
+      sym__ws,
+    STATE(186), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3713] = 3,
+    ACTIONS(648), 1,
+      anon_sym_with,
+    ACTIONS(747), 1,
+      sym_tag_close,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [3724] = 3,
+    ACTIONS(747), 1,
+      sym_tag_close,
+    ACTIONS(749), 1,
+      sym__ws,
+    STATE(197), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3735] = 4,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(751), 1,
+      sym_integer,
+    ACTIONS(753), 1,
+      sym__ws,
+    STATE(334), 1,
       sym_path,
-  [1376] = 4,
-    ACTIONS(328), 1,
+  [3748] = 3,
+    ACTIONS(755), 1,
+      sym_tag_close,
+    ACTIONS(757), 1,
+      sym__ws,
+    STATE(186), 2,
+      sym_each_window,
+      aux_sym_each_open_repeat1,
+  [3759] = 4,
+    ACTIONS(759), 1,
+      sym_tag_close,
+    ACTIONS(761), 1,
+      sym__ws,
+    STATE(228), 1,
+      aux_sym_include_call_args_repeat1,
+    STATE(246), 1,
+      sym_include_call_args,
+  [3772] = 1,
+    ACTIONS(689), 3,
       anon_sym_COMMA,
-    ACTIONS(330), 1,
       sym_tag_close,
-    ACTIONS(332), 1,
       sym__ws,
-    STATE(124), 1,
-      sym_each_index,
-  [1389] = 4,
-    ACTIONS(334), 1,
+  [3778] = 1,
+    ACTIONS(763), 3,
+      anon_sym_PIPE,
       sym_tag_close,
-    ACTIONS(336), 1,
       sym__ws,
-    STATE(87), 1,
-      aux_sym_include_args_repeat1,
-    STATE(145), 1,
-      sym_include_args,
-  [1402] = 4,
-    ACTIONS(328), 1,
+  [3784] = 1,
+    ACTIONS(765), 3,
       anon_sym_COMMA,
-    ACTIONS(338), 1,
       sym_tag_close,
-    ACTIONS(340), 1,
       sym__ws,
-    STATE(103), 1,
-      sym_each_index,
-  [1415] = 4,
-    ACTIONS(342), 1,
+  [3790] = 3,
+    ACTIONS(767), 1,
       sym_tag_close,
-    ACTIONS(344), 1,
+    ACTIONS(769), 1,
       sym__ws,
-    STATE(87), 1,
+    STATE(209), 1,
       aux_sym_include_args_repeat1,
-    STATE(121), 1,
-      sym_include_args,
-  [1428] = 4,
-    ACTIONS(318), 1,
-      anon_sym_if,
-    ACTIONS(322), 1,
-      anon_sym_unless,
-    ACTIONS(324), 1,
-      anon_sym_each,
-    ACTIONS(326), 1,
-      anon_sym_unsecure,
-  [1441] = 3,
-    ACTIONS(346), 1,
+  [3800] = 3,
+    ACTIONS(772), 1,
       sym_tag_close,
-    ACTIONS(348), 1,
+    ACTIONS(774), 1,
       sym_identifier,
-    STATE(141), 1,
-      sym_include_arg,
-  [1451] = 3,
-    ACTIONS(350), 1,
+    STATE(173), 1,
+      sym_param,
+  [3810] = 3,
+    ACTIONS(776), 1,
       sym_tag_close,
-    ACTIONS(352), 1,
+    ACTIONS(778), 1,
       sym__ws,
-    STATE(84), 1,
-      aux_sym_include_args_repeat1,
-  [1461] = 3,
-    ACTIONS(255), 1,
+    STATE(204), 1,
+      aux_sym_include_call_args_repeat1,
+  [3820] = 3,
+    ACTIONS(774), 1,
       sym_identifier,
-    ACTIONS(355), 1,
+    ACTIONS(781), 1,
       sym__ws,
-    STATE(115), 1,
+    STATE(199), 1,
+      sym_param,
+  [3830] = 3,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(783), 1,
+      sym_include_name,
+    STATE(175), 1,
       sym_path,
-  [1471] = 3,
-    ACTIONS(357), 1,
-      anon_sym_unsecure,
-    ACTIONS(359), 1,
-      anon_sym_include,
-    ACTIONS(361), 1,
-      sym__ws,
-  [1481] = 3,
-    ACTIONS(363), 1,
+  [3840] = 3,
+    ACTIONS(785), 1,
+      anon_sym_and,
+    ACTIONS(787), 1,
+      anon_sym_or,
+    ACTIONS(789), 1,
+      sym_tag_close,
+  [3850] = 3,
+    ACTIONS(785), 1,
+      anon_sym_and,
+    ACTIONS(787), 1,
+      anon_sym_or,
+    ACTIONS(791), 1,
       sym_tag_close,
-    ACTIONS(365), 1,
+  [3860] = 3,
+    ACTIONS(793), 1,
+      sym_tag_close,
+    ACTIONS(795), 1,
       sym__ws,
-    STATE(84), 1,
+    STATE(209), 1,
       aux_sym_include_args_repeat1,
-  [1491] = 3,
-    ACTIONS(334), 1,
+  [3870] = 2,
+    ACTIONS(636), 1,
       sym_tag_close,
-    ACTIONS(348), 1,
-      sym_identifier,
-    STATE(141), 1,
-      sym_include_arg,
-  [1501] = 3,
-    ACTIONS(255), 1,
-      sym_identifier,
-    ACTIONS(368), 1,
-      sym__ws,
-    STATE(109), 1,
-      sym_path,
-  [1511] = 2,
-    ACTIONS(370), 1,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [3878] = 1,
+    ACTIONS(798), 3,
+      anon_sym_PIPE,
       sym_tag_close,
-    ACTIONS(372), 1,
       sym__ws,
-  [1518] = 2,
-    ACTIONS(255), 1,
-      sym_identifier,
-    STATE(109), 1,
-      sym_path,
-  [1525] = 2,
-    ACTIONS(374), 1,
+  [3884] = 3,
+    ACTIONS(785), 1,
+      anon_sym_and,
+    ACTIONS(787), 1,
+      anon_sym_or,
+    ACTIONS(800), 1,
       sym_tag_close,
-    ACTIONS(376), 1,
-      sym__ws,
-  [1532] = 2,
-    ACTIONS(378), 1,
-      sym_identifier,
-    ACTIONS(380), 1,
+  [3894] = 2,
+    ACTIONS(804), 1,
       sym__ws,
-  [1539] = 2,
-    ACTIONS(255), 1,
+    ACTIONS(802), 2,
+      anon_sym_PIPE,
+      sym_tag_close,
+  [3902] = 3,
+    ACTIONS(194), 1,
       sym_identifier,
-    STATE(90), 1,
+    ACTIONS(807), 1,
+      sym_include_name,
+    STATE(177), 1,
       sym_path,
-  [1546] = 2,
-    ACTIONS(255), 1,
+  [3912] = 3,
+    ACTIONS(809), 1,
+      sym_raw_content,
+    ACTIONS(811), 1,
+      sym_tag_open,
+    STATE(73), 1,
+      sym_raw_close,
+  [3922] = 3,
+    ACTIONS(194), 1,
       sym_identifier,
-    STATE(123), 1,
+    ACTIONS(813), 1,
+      sym_include_name,
+    STATE(172), 1,
       sym_path,
-  [1553] = 1,
-    ACTIONS(382), 2,
+  [3932] = 2,
+    ACTIONS(817), 1,
+      sym__ws,
+    ACTIONS(815), 2,
+      anon_sym_PIPE,
+      sym_tag_close,
+  [3940] = 1,
+    ACTIONS(820), 3,
+      anon_sym_COMMA,
       sym_tag_close,
       sym__ws,
-  [1558] = 2,
-    ACTIONS(384), 1,
+  [3946] = 1,
+    ACTIONS(822), 3,
+      anon_sym_PIPE,
       sym_tag_close,
-    ACTIONS(386), 1,
       sym__ws,
-  [1565] = 2,
-    ACTIONS(388), 1,
+  [3952] = 2,
+    ACTIONS(747), 1,
       sym_tag_close,
-    ACTIONS(390), 1,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [3960] = 1,
+    ACTIONS(824), 3,
+      anon_sym_PIPE,
+      sym_tag_close,
+      sym__ws,
+  [3966] = 2,
+    ACTIONS(826), 1,
       sym__ws,
-  [1572] = 1,
-    ACTIONS(392), 2,
+    ACTIONS(798), 2,
+      anon_sym_PIPE,
+      sym_tag_close,
+  [3974] = 1,
+    ACTIONS(829), 3,
+      anon_sym_COMMA,
       sym_tag_close,
       sym__ws,
-  [1577] = 2,
-    ACTIONS(255), 1,
+  [3980] = 3,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(751), 1,
+      sym_integer,
+    STATE(334), 1,
+      sym_path,
+  [3990] = 3,
+    ACTIONS(656), 1,
       sym_identifier,
-    STATE(129), 1,
+    ACTIONS(831), 1,
+      sym_tag_close,
+    STATE(255), 1,
+      sym_include_arg,
+  [4000] = 3,
+    ACTIONS(194), 1,
+      sym_identifier,
+    ACTIONS(833), 1,
+      sym_include_name,
+    STATE(198), 1,
       sym_path,
-  [1584] = 2,
-    ACTIONS(255), 1,
+  [4010] = 2,
+    ACTIONS(755), 1,
+      sym_tag_close,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [4018] = 3,
+    ACTIONS(835), 1,
+      sym_tag_close,
+    ACTIONS(837), 1,
+      sym__ws,
+    STATE(204), 1,
+      aux_sym_include_call_args_repeat1,
+  [4028] = 3,
+    ACTIONS(194), 1,
       sym_identifier,
-    STATE(130), 1,
+    ACTIONS(840), 1,
+      sym_integer,
+    STATE(336), 1,
       sym_path,
-  [1591] = 2,
-    ACTIONS(255), 1,
+  [4038] = 3,
+    ACTIONS(785), 1,
+      anon_sym_and,
+    ACTIONS(787), 1,
+      anon_sym_or,
+    ACTIONS(842), 1,
+      sym_tag_close,
+  [4048] = 2,
+    ACTIONS(844), 1,
+      sym_tag_close,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [4056] = 3,
+    ACTIONS(194), 1,
       sym_identifier,
-    STATE(165), 1,
+    ACTIONS(846), 1,
+      sym_integer,
+    STATE(280), 1,
       sym_path,
-  [1598] = 2,
-    ACTIONS(330), 1,
+  [4066] = 3,
+    ACTIONS(774), 1,
+      sym_identifier,
+    ACTIONS(848), 1,
+      sym__ws,
+    STATE(223), 1,
+      sym_param,
+  [4076] = 2,
+    ACTIONS(850), 1,
       sym_tag_close,
-    ACTIONS(394), 1,
+    ACTIONS(852), 1,
       sym__ws,
-  [1605] = 2,
-    ACTIONS(330), 1,
+  [4083] = 2,
+    ACTIONS(854), 1,
       sym_tag_close,
-    ACTIONS(396), 1,
-      anon_sym_COMMA,
-  [1612] = 2,
-    ACTIONS(398), 1,
+    ACTIONS(856), 1,
+      sym__ws,
+  [4090] = 2,
+    ACTIONS(858), 1,
       sym_tag_close,
-    ACTIONS(400), 1,
+    ACTIONS(860), 1,
       sym__ws,
-  [1619] = 2,
-    ACTIONS(402), 1,
-      sym_identifier,
-    ACTIONS(404), 1,
+  [4097] = 2,
+    ACTIONS(862), 1,
+      sym_tag_close,
+    ACTIONS(864), 1,
+      sym__ws,
+  [4104] = 2,
+    ACTIONS(866), 1,
+      sym_tag_open,
+    STATE(44), 1,
+      sym_raw_close,
+  [4111] = 2,
+    ACTIONS(868), 1,
+      sym_tag_close,
+    ACTIONS(870), 1,
       sym__ws,
-  [1626] = 2,
-    ACTIONS(255), 1,
+  [4118] = 2,
+    ACTIONS(872), 1,
+      sym_tag_close,
+    ACTIONS(874), 1,
+      sym__ws,
+  [4125] = 2,
+    ACTIONS(876), 1,
+      sym_tag_close,
+    ACTIONS(878), 1,
+      sym__ws,
+  [4132] = 2,
+    ACTIONS(880), 1,
       sym_identifier,
-    STATE(99), 1,
-      sym_path,
-  [1633] = 2,
-    ACTIONS(406), 1,
+    ACTIONS(882), 1,
+      sym__ws,
+  [4139] = 2,
+    ACTIONS(646), 1,
+      anon_sym_COMMA,
+    ACTIONS(884), 1,
+      anon_sym_in,
+  [4146] = 2,
+    ACTIONS(886), 1,
       sym_tag_close,
-    ACTIONS(408), 1,
+    ACTIONS(888), 1,
       sym__ws,
-  [1640] = 1,
-    ACTIONS(410), 2,
+  [4153] = 2,
+    ACTIONS(890), 1,
       sym_tag_close,
+    ACTIONS(892), 1,
       sym__ws,
-  [1645] = 2,
-    ACTIONS(412), 1,
+  [4160] = 2,
+    ACTIONS(654), 1,
       sym_tag_close,
-    ACTIONS(414), 1,
+    ACTIONS(894), 1,
       sym__ws,
-  [1652] = 2,
-    ACTIONS(416), 1,
+  [4167] = 2,
+    ACTIONS(791), 1,
       sym_tag_close,
-    ACTIONS(418), 1,
+    ACTIONS(896), 1,
       sym__ws,
-  [1659] = 1,
-    ACTIONS(420), 2,
+  [4174] = 2,
+    ACTIONS(842), 1,
       sym_tag_close,
+    ACTIONS(898), 1,
+      sym__ws,
+  [4181] = 2,
+    ACTIONS(632), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(900), 1,
       sym__ws,
-  [1664] = 2,
-    ACTIONS(422), 1,
+  [4188] = 2,
+    ACTIONS(902), 1,
       sym_tag_close,
-    ACTIONS(424), 1,
+    ACTIONS(904), 1,
       sym__ws,
-  [1671] = 2,
-    ACTIONS(426), 1,
+  [4195] = 2,
+    ACTIONS(906), 1,
+      sym_raw_content,
+    ACTIONS(908), 1,
+      sym_tag_open,
+  [4202] = 2,
+    ACTIONS(910), 1,
       sym_tag_close,
-    ACTIONS(428), 1,
+    ACTIONS(912), 1,
       sym__ws,
-  [1678] = 1,
-    ACTIONS(430), 2,
+  [4209] = 2,
+    ACTIONS(914), 1,
       sym_tag_close,
+    ACTIONS(916), 1,
       sym__ws,
-  [1683] = 2,
-    ACTIONS(432), 1,
+  [4216] = 2,
+    ACTIONS(918), 1,
+      anon_sym_EQ,
+    ACTIONS(920), 1,
+      sym__ws,
+  [4223] = 1,
+    ACTIONS(793), 2,
       sym_tag_close,
-    ACTIONS(434), 1,
       sym__ws,
-  [1690] = 2,
-    ACTIONS(436), 1,
+  [4228] = 2,
+    ACTIONS(656), 1,
+      sym_identifier,
+    STATE(255), 1,
+      sym_include_arg,
+  [4235] = 2,
+    ACTIONS(922), 1,
+      sym_tag_close,
+    ACTIONS(924), 1,
+      sym__ws,
+  [4242] = 2,
+    ACTIONS(926), 1,
+      anon_sym_PIPE,
+    ACTIONS(928), 1,
+      sym_tag_close,
+  [4249] = 2,
+    ACTIONS(930), 1,
+      sym_tag_close,
+    ACTIONS(932), 1,
+      sym__ws,
+  [4256] = 2,
+    ACTIONS(934), 1,
+      sym_tag_close,
+    ACTIONS(936), 1,
+      sym__ws,
+  [4263] = 2,
+    ACTIONS(194), 1,
+      sym_identifier,
+    STATE(236), 1,
+      sym_path,
+  [4270] = 2,
+    ACTIONS(774), 1,
+      sym_identifier,
+    STATE(201), 1,
+      sym_param,
+  [4277] = 2,
+    ACTIONS(938), 1,
       anon_sym_if,
-    ACTIONS(438), 1,
+    ACTIONS(940), 1,
       sym__ws,
-  [1697] = 2,
-    ACTIONS(440), 1,
+  [4284] = 2,
+    ACTIONS(942), 1,
+      sym_raw_content,
+    ACTIONS(944), 1,
+      sym_tag_open,
+  [4291] = 2,
+    ACTIONS(946), 1,
       sym_tag_open,
-    STATE(47), 1,
+    STATE(53), 1,
       sym_if_close,
-  [1704] = 2,
-    ACTIONS(442), 1,
+  [4298] = 2,
+    ACTIONS(948), 1,
       anon_sym_unless,
-    ACTIONS(444), 1,
+    ACTIONS(950), 1,
       sym__ws,
-  [1711] = 2,
-    ACTIONS(396), 1,
-      anon_sym_COMMA,
-    ACTIONS(446), 1,
-      sym_tag_close,
-  [1718] = 2,
-    ACTIONS(334), 1,
+  [4305] = 2,
+    ACTIONS(952), 1,
+      sym_tag_open,
+    STATE(41), 1,
+      sym_unless_close,
+  [4312] = 2,
+    ACTIONS(952), 1,
+      sym_tag_open,
+    STATE(54), 1,
+      sym_unless_close,
+  [4319] = 2,
+    ACTIONS(194), 1,
+      sym_identifier,
+    STATE(297), 1,
+      sym_path,
+  [4326] = 2,
+    ACTIONS(954), 1,
+      anon_sym_each,
+    ACTIONS(956), 1,
+      sym__ws,
+  [4333] = 1,
+    ACTIONS(776), 2,
       sym_tag_close,
-    ACTIONS(448), 1,
       sym__ws,
-  [1725] = 2,
-    ACTIONS(450), 1,
+  [4338] = 2,
+    ACTIONS(658), 1,
       sym_tag_close,
-    ACTIONS(452), 1,
+    ACTIONS(958), 1,
       sym__ws,
-  [1732] = 2,
-    ACTIONS(454), 1,
+  [4345] = 1,
+    ACTIONS(960), 2,
       sym_tag_close,
-    ACTIONS(456), 1,
       sym__ws,
-  [1739] = 2,
-    ACTIONS(446), 1,
+  [4350] = 2,
+    ACTIONS(962), 1,
       sym_tag_close,
-    ACTIONS(458), 1,
+    ACTIONS(964), 1,
       sym__ws,
-  [1746] = 2,
-    ACTIONS(460), 1,
+  [4357] = 2,
+    ACTIONS(194), 1,
+      sym_identifier,
+    STATE(35), 1,
+      sym_path,
+  [4364] = 1,
+    ACTIONS(966), 2,
       sym_tag_close,
-    ACTIONS(462), 1,
       sym__ws,
-  [1753] = 1,
-    ACTIONS(464), 2,
+  [4369] = 2,
+    ACTIONS(968), 1,
+      sym_tag_open,
+    STATE(55), 1,
+      sym_each_close,
+  [4376] = 1,
+    ACTIONS(970), 2,
       sym_tag_close,
       sym__ws,
-  [1758] = 2,
-    ACTIONS(466), 1,
+  [4381] = 2,
+    ACTIONS(972), 1,
       sym_tag_close,
-    ACTIONS(468), 1,
+    ACTIONS(974), 1,
       sym__ws,
-  [1765] = 2,
-    ACTIONS(470), 1,
+  [4388] = 1,
+    ACTIONS(976), 2,
       sym_tag_close,
-    ACTIONS(472), 1,
       sym__ws,
-  [1772] = 2,
-    ACTIONS(474), 1,
+  [4393] = 1,
+    ACTIONS(978), 2,
       sym_tag_close,
-    ACTIONS(476), 1,
       sym__ws,
-  [1779] = 2,
-    ACTIONS(478), 1,
+  [4398] = 2,
+    ACTIONS(980), 1,
+      sym_identifier,
+    ACTIONS(982), 1,
+      sym__ws,
+  [4405] = 2,
+    ACTIONS(984), 1,
       sym_tag_close,
-    ACTIONS(480), 1,
+    ACTIONS(986), 1,
       sym__ws,
-  [1786] = 2,
-    ACTIONS(482), 1,
-      anon_sym_each,
-    ACTIONS(484), 1,
+  [4412] = 2,
+    ACTIONS(646), 1,
+      anon_sym_COMMA,
+    ACTIONS(988), 1,
+      anon_sym_in,
+  [4419] = 2,
+    ACTIONS(632), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(990), 1,
       sym__ws,
-  [1793] = 2,
-    ACTIONS(255), 1,
-      sym_identifier,
-    STATE(73), 1,
-      sym_path,
-  [1800] = 2,
-    ACTIONS(486), 1,
+  [4426] = 2,
+    ACTIONS(660), 1,
+      sym_tag_close,
+    ACTIONS(992), 1,
+      sym__ws,
+  [4433] = 2,
+    ACTIONS(994), 1,
       anon_sym_unsecure,
-    ACTIONS(488), 1,
+    ACTIONS(996), 1,
+      sym__ws,
+  [4440] = 2,
+    ACTIONS(998), 1,
+      sym_raw_content,
+    ACTIONS(1000), 1,
+      sym_tag_open,
+  [4447] = 2,
+    ACTIONS(1002), 1,
+      anon_sym_raw,
+    ACTIONS(1004), 1,
+      sym__ws,
+  [4454] = 2,
+    ACTIONS(684), 1,
+      sym_tag_close,
+    ACTIONS(1006), 1,
+      sym__ws,
+  [4461] = 2,
+    ACTIONS(1008), 1,
+      sym_tag_close,
+    ACTIONS(1010), 1,
+      sym__ws,
+  [4468] = 2,
+    ACTIONS(1012), 1,
+      anon_sym_block,
+    ACTIONS(1014), 1,
+      sym__ws,
+  [4475] = 2,
+    ACTIONS(968), 1,
+      sym_tag_open,
+    STATE(42), 1,
+      sym_each_close,
+  [4482] = 2,
+    ACTIONS(1016), 1,
       anon_sym_include,
-  [1807] = 2,
-    ACTIONS(255), 1,
+    ACTIONS(1018), 1,
+      sym__ws,
+  [4489] = 2,
+    ACTIONS(194), 1,
       sym_identifier,
-    STATE(188), 1,
+    STATE(253), 1,
       sym_path,
-  [1814] = 1,
-    ACTIONS(490), 2,
+  [4496] = 2,
+    ACTIONS(642), 1,
+      sym_tag_close,
+    ACTIONS(926), 1,
+      anon_sym_PIPE,
+  [4503] = 1,
+    ACTIONS(1020), 2,
       sym_tag_close,
       sym__ws,
-  [1819] = 2,
-    ACTIONS(492), 1,
-      anon_sym_unsecure,
-    ACTIONS(494), 1,
+  [4508] = 2,
+    ACTIONS(785), 1,
+      anon_sym_and,
+    ACTIONS(787), 1,
+      anon_sym_or,
+  [4515] = 1,
+    ACTIONS(1022), 2,
+      sym_tag_close,
+      sym__ws,
+  [4520] = 1,
+    ACTIONS(1024), 2,
+      sym_tag_close,
+      sym__ws,
+  [4525] = 2,
+    ACTIONS(831), 1,
+      sym_tag_close,
+    ACTIONS(1026), 1,
+      sym__ws,
+  [4532] = 1,
+    ACTIONS(1028), 2,
+      sym_tag_close,
       sym__ws,
-  [1826] = 2,
-    ACTIONS(255), 1,
+  [4537] = 2,
+    ACTIONS(194), 1,
       sym_identifier,
-    STATE(98), 1,
+    STATE(257), 1,
       sym_path,
-  [1833] = 2,
-    ACTIONS(255), 1,
+  [4544] = 2,
+    ACTIONS(1030), 1,
+      sym_tag_close,
+    ACTIONS(1032), 1,
+      sym__ws,
+  [4551] = 1,
+    ACTIONS(1034), 2,
+      sym_tag_close,
+      sym__ws,
+  [4556] = 2,
+    ACTIONS(1034), 1,
+      sym_tag_close,
+    ACTIONS(1036), 1,
+      sym__ws,
+  [4563] = 1,
+    ACTIONS(1038), 2,
+      sym_tag_close,
+      sym__ws,
+  [4568] = 2,
+    ACTIONS(680), 1,
+      sym_tag_close,
+    ACTIONS(926), 1,
+      anon_sym_PIPE,
+  [4575] = 2,
+    ACTIONS(1040), 1,
+      sym_identifier,
+    ACTIONS(1042), 1,
+      sym__ws,
+  [4582] = 1,
+    ACTIONS(1044), 2,
+      sym_tag_close,
+      sym__ws,
+  [4587] = 1,
+    ACTIONS(1046), 2,
+      sym_tag_close,
+      sym__ws,
+  [4592] = 2,
+    ACTIONS(774), 1,
       sym_identifier,
-    STATE(97), 1,
+    STATE(223), 1,
+      sym_param,
+  [4599] = 2,
+    ACTIONS(194), 1,
+      sym_identifier,
+    STATE(182), 1,
       sym_path,
-  [1840] = 2,
-    ACTIONS(440), 1,
-      sym_tag_open,
-    STATE(41), 1,
-      sym_if_close,
-  [1847] = 2,
-    ACTIONS(496), 1,
+  [4606] = 2,
+    ACTIONS(1048), 1,
+      anon_sym_COMMA,
+    ACTIONS(1050), 1,
+      sym_tag_close,
+  [4613] = 2,
+    ACTIONS(1052), 1,
+      sym_tag_close,
+    ACTIONS(1054), 1,
+      sym__ws,
+  [4620] = 2,
+    ACTIONS(718), 1,
+      sym_tag_close,
+    ACTIONS(1048), 1,
+      anon_sym_COMMA,
+  [4627] = 2,
+    ACTIONS(1056), 1,
+      sym_tag_close,
+    ACTIONS(1058), 1,
+      sym__ws,
+  [4634] = 1,
+    ACTIONS(1060), 2,
+      sym_tag_close,
+      sym__ws,
+  [4639] = 2,
+    ACTIONS(1062), 1,
       anon_sym_EQ,
-    ACTIONS(498), 1,
+    ACTIONS(1064), 1,
       sym__ws,
-  [1854] = 1,
-    ACTIONS(350), 2,
+  [4646] = 2,
+    ACTIONS(1066), 1,
       sym_tag_close,
+    ACTIONS(1068), 1,
       sym__ws,
-  [1859] = 2,
-    ACTIONS(348), 1,
+  [4653] = 1,
+    ACTIONS(1070), 2,
+      sym_index,
       sym_identifier,
-    STATE(141), 1,
-      sym_include_arg,
-  [1866] = 2,
-    ACTIONS(500), 1,
+  [4658] = 2,
+    ACTIONS(1072), 1,
       sym_tag_close,
-    ACTIONS(502), 1,
+    ACTIONS(1074), 1,
       sym__ws,
-  [1873] = 2,
-    ACTIONS(312), 1,
+  [4665] = 2,
+    ACTIONS(1076), 1,
       sym_tag_close,
-    ACTIONS(314), 1,
+    ACTIONS(1078), 1,
       sym__ws,
-  [1880] = 2,
-    ACTIONS(346), 1,
+  [4672] = 2,
+    ACTIONS(1080), 1,
       sym_tag_close,
-    ACTIONS(504), 1,
+    ACTIONS(1082), 1,
       sym__ws,
-  [1887] = 1,
-    ACTIONS(506), 1,
+  [4679] = 2,
+    ACTIONS(1084), 1,
       sym_tag_close,
-  [1891] = 1,
-    ACTIONS(508), 1,
+    ACTIONS(1086), 1,
+      sym__ws,
+  [4686] = 1,
+    ACTIONS(650), 2,
+      anon_sym_limit,
+      anon_sym_offset,
+  [4691] = 2,
+    ACTIONS(946), 1,
+      sym_tag_open,
+    STATE(39), 1,
+      sym_if_close,
+  [4698] = 2,
+    ACTIONS(1088), 1,
       sym_tag_close,
-  [1895] = 1,
-    ACTIONS(346), 1,
+    ACTIONS(1090), 1,
+      sym__ws,
+  [4705] = 1,
+    ACTIONS(1092), 2,
       sym_tag_close,
-  [1899] = 1,
-    ACTIONS(510), 1,
       sym__ws,
-  [1903] = 1,
-    ACTIONS(512), 1,
+  [4710] = 1,
+    ACTIONS(1094), 2,
       sym_tag_close,
-  [1907] = 1,
-    ACTIONS(514), 1,
+      sym__ws,
+  [4715] = 2,
+    ACTIONS(1096), 1,
       sym_tag_close,
-  [1911] = 1,
-    ACTIONS(516), 1,
+    ACTIONS(1098), 1,
       sym__ws,
-  [1915] = 1,
-    ACTIONS(518), 1,
+  [4722] = 2,
+    ACTIONS(1100), 1,
       sym_tag_close,
-  [1919] = 1,
-    ACTIONS(520), 1,
+    ACTIONS(1102), 1,
       sym__ws,
-  [1923] = 1,
-    ACTIONS(522), 1,
-      sym_include_name,
-  [1927] = 1,
-    ACTIONS(524), 1,
+  [4729] = 2,
+    ACTIONS(1104), 1,
+      sym_identifier,
+    ACTIONS(1106), 1,
       sym__ws,
-  [1931] = 1,
-    ACTIONS(526), 1,
-      sym_tag_close,
-  [1935] = 1,
-    ACTIONS(528), 1,
+  [4736] = 1,
+    ACTIONS(1108), 2,
       sym_tag_close,
-  [1939] = 1,
-    ACTIONS(530), 1,
-      anon_sym_as,
-  [1943] = 1,
-    ACTIONS(532), 1,
       sym__ws,
-  [1947] = 1,
-    ACTIONS(534), 1,
+  [4741] = 2,
+    ACTIONS(1110), 1,
+      sym_tag_close,
+    ACTIONS(1112), 1,
       sym__ws,
-  [1951] = 1,
-    ACTIONS(536), 1,
+  [4748] = 1,
+    ACTIONS(1114), 2,
+      sym_tag_close,
       sym__ws,
-  [1955] = 1,
-    ACTIONS(538), 1,
+  [4753] = 2,
+    ACTIONS(210), 1,
       sym_tag_close,
-  [1959] = 1,
-    ACTIONS(500), 1,
+    ACTIONS(926), 1,
+      anon_sym_PIPE,
+  [4760] = 2,
+    ACTIONS(1116), 1,
       sym_tag_close,
-  [1963] = 1,
-    ACTIONS(540), 1,
+    ACTIONS(1118), 1,
+      sym__ws,
+  [4767] = 1,
+    ACTIONS(1120), 1,
       sym__ws,
-  [1967] = 1,
-    ACTIONS(542), 1,
+  [4771] = 1,
+    ACTIONS(1122), 1,
+      sym_tag_close,
+  [4775] = 1,
+    ACTIONS(260), 1,
+      anon_sym_SLASH,
+  [4779] = 1,
+    ACTIONS(1124), 1,
       anon_sym_EQ,
-  [1971] = 1,
-    ACTIONS(544), 1,
-      anon_sym_as,
-  [1975] = 1,
-    ACTIONS(478), 1,
+  [4783] = 1,
+    ACTIONS(1126), 1,
+      sym__ws,
+  [4787] = 1,
+    ACTIONS(1128), 1,
       sym_tag_close,
-  [1979] = 1,
-    ACTIONS(546), 1,
+  [4791] = 1,
+    ACTIONS(1130), 1,
       sym_tag_close,
-  [1983] = 1,
-    ACTIONS(548), 1,
-      sym_identifier,
-  [1987] = 1,
-    ACTIONS(474), 1,
+  [4795] = 1,
+    ACTIONS(1132), 1,
+      sym__ws,
+  [4799] = 1,
+    ACTIONS(1134), 1,
+      sym_tag_close,
+  [4803] = 1,
+    ACTIONS(1136), 1,
+      sym_integer,
+  [4807] = 1,
+    ACTIONS(1138), 1,
+      anon_sym_RBRACK,
+  [4811] = 1,
+    ACTIONS(1140), 1,
+      ts_builtin_sym_end,
+  [4815] = 1,
+    ACTIONS(1142), 1,
+      sym_tag_close,
+  [4819] = 1,
+    ACTIONS(1144), 1,
+      anon_sym_unsecure,
+  [4823] = 1,
+    ACTIONS(1146), 1,
       sym_tag_close,
-  [1991] = 1,
-    ACTIONS(550), 1,
+  [4827] = 1,
+    ACTIONS(1148), 1,
       sym__ws,
-  [1995] = 1,
-    ACTIONS(552), 1,
+  [4831] = 1,
+    ACTIONS(1150), 1,
       sym__ws,
-  [1999] = 1,
-    ACTIONS(454), 1,
+  [4835] = 1,
+    ACTIONS(1152), 1,
+      sym_tag_close,
+  [4839] = 1,
+    ACTIONS(1154), 1,
+      sym_include_name,
+  [4843] = 1,
+    ACTIONS(188), 1,
+      anon_sym_SLASH,
+  [4847] = 1,
+    ACTIONS(1156), 1,
       sym_tag_close,
-  [2003] = 1,
-    ACTIONS(554), 1,
+  [4851] = 1,
+    ACTIONS(1030), 1,
+      sym_tag_close,
+  [4855] = 1,
+    ACTIONS(1158), 1,
+      sym_tag_close,
+  [4859] = 1,
+    ACTIONS(1160), 1,
       sym__ws,
-  [2007] = 1,
-    ACTIONS(432), 1,
+  [4863] = 1,
+    ACTIONS(1162), 1,
       sym_tag_close,
-  [2011] = 1,
-    ACTIONS(556), 1,
-      ts_builtin_sym_end,
-  [2015] = 1,
-    ACTIONS(558), 1,
+  [4867] = 1,
+    ACTIONS(1164), 1,
+      sym_tag_close,
+  [4871] = 1,
+    ACTIONS(1166), 1,
+      sym_identifier,
+  [4875] = 1,
+    ACTIONS(1168), 1,
+      sym__ws,
+  [4879] = 1,
+    ACTIONS(1170), 1,
+      anon_sym_if,
+  [4883] = 1,
+    ACTIONS(1172), 1,
+      sym_tag_close,
+  [4887] = 1,
+    ACTIONS(854), 1,
+      sym_tag_close,
+  [4891] = 1,
+    ACTIONS(1174), 1,
+      sym_identifier,
+  [4895] = 1,
+    ACTIONS(1176), 1,
+      sym__ws,
+  [4899] = 1,
+    ACTIONS(1178), 1,
+      sym_identifier,
+  [4903] = 1,
+    ACTIONS(1180), 1,
       sym__ws,
-  [2019] = 1,
-    ACTIONS(560), 1,
+  [4907] = 1,
+    ACTIONS(1182), 1,
       sym_identifier,
-  [2023] = 1,
-    ACTIONS(422), 1,
+  [4911] = 1,
+    ACTIONS(1184), 1,
+      anon_sym_include,
+  [4915] = 1,
+    ACTIONS(1186), 1,
+      anon_sym_in,
+  [4919] = 1,
+    ACTIONS(1188), 1,
+      sym__ws,
+  [4923] = 1,
+    ACTIONS(1096), 1,
+      sym_tag_close,
+  [4927] = 1,
+    ACTIONS(1190), 1,
       sym_tag_close,
-  [2027] = 1,
-    ACTIONS(412), 1,
+  [4931] = 1,
+    ACTIONS(1192), 1,
+      sym__ws,
+  [4935] = 1,
+    ACTIONS(1194), 1,
+      anon_sym_as,
+  [4939] = 1,
+    ACTIONS(1196), 1,
+      sym__ws,
+  [4943] = 1,
+    ACTIONS(1198), 1,
+      anon_sym_unless,
+  [4947] = 1,
+    ACTIONS(1200), 1,
+      sym__ws,
+  [4951] = 1,
+    ACTIONS(1040), 1,
+      sym_identifier,
+  [4955] = 1,
+    ACTIONS(658), 1,
       sym_tag_close,
-  [2031] = 1,
-    ACTIONS(406), 1,
+  [4959] = 1,
+    ACTIONS(1202), 1,
+      sym_include_name,
+  [4963] = 1,
+    ACTIONS(1204), 1,
       sym_tag_close,
-  [2035] = 1,
-    ACTIONS(253), 1,
+  [4967] = 1,
+    ACTIONS(222), 1,
       anon_sym_SLASH,
-  [2039] = 1,
-    ACTIONS(562), 1,
+  [4971] = 1,
+    ACTIONS(1206), 1,
+      sym_tag_close,
+  [4975] = 1,
+    ACTIONS(1208), 1,
+      sym__ws,
+  [4979] = 1,
+    ACTIONS(1210), 1,
+      sym_integer,
+  [4983] = 1,
+    ACTIONS(1212), 1,
+      sym__ws,
+  [4987] = 1,
+    ACTIONS(1214), 1,
+      sym__ws,
+  [4991] = 1,
+    ACTIONS(1216), 1,
+      sym_identifier,
+  [4995] = 1,
+    ACTIONS(1218), 1,
       sym_identifier,
-  [2043] = 1,
-    ACTIONS(398), 1,
+  [4999] = 1,
+    ACTIONS(1220), 1,
       sym_tag_close,
-  [2047] = 1,
-    ACTIONS(564), 1,
+  [5003] = 1,
+    ACTIONS(926), 1,
+      anon_sym_PIPE,
+  [5007] = 1,
+    ACTIONS(1222), 1,
       sym_tag_close,
-  [2051] = 1,
-    ACTIONS(566), 1,
+  [5011] = 1,
+    ACTIONS(1224), 1,
+      sym__ws,
+  [5015] = 1,
+    ACTIONS(1226), 1,
+      sym__ws,
+  [5019] = 1,
+    ACTIONS(910), 1,
       sym_tag_close,
-  [2055] = 1,
-    ACTIONS(568), 1,
+  [5023] = 1,
+    ACTIONS(1228), 1,
       sym__ws,
-  [2059] = 1,
-    ACTIONS(378), 1,
-      sym_identifier,
-  [2063] = 1,
-    ACTIONS(570), 1,
-      sym_include_name,
-  [2067] = 1,
-    ACTIONS(572), 1,
-      anon_sym_unsecure,
-  [2071] = 1,
-    ACTIONS(446), 1,
+  [5027] = 1,
+    ACTIONS(1230), 1,
+      sym__ws,
+  [5031] = 1,
+    ACTIONS(1232), 1,
+      sym__ws,
+  [5035] = 1,
+    ACTIONS(1056), 1,
+      sym_tag_close,
+  [5039] = 1,
+    ACTIONS(1234), 1,
+      anon_sym_raw,
+  [5043] = 1,
+    ACTIONS(1236), 1,
+      anon_sym_SLASH,
+  [5047] = 1,
+    ACTIONS(1238), 1,
+      sym__ws,
+  [5051] = 1,
+    ACTIONS(1240), 1,
+      sym__ws,
+  [5055] = 1,
+    ACTIONS(1242), 1,
+      sym_string,
+  [5059] = 1,
+    ACTIONS(1066), 1,
+      sym_tag_close,
+  [5063] = 1,
+    ACTIONS(862), 1,
       sym_tag_close,
-  [2075] = 1,
-    ACTIONS(574), 1,
+  [5067] = 1,
+    ACTIONS(1244), 1,
+      sym_integer,
+  [5071] = 1,
+    ACTIONS(1072), 1,
+      sym_tag_close,
+  [5075] = 1,
+    ACTIONS(1246), 1,
       anon_sym_each,
-  [2079] = 1,
-    ACTIONS(576), 1,
-      anon_sym_unless,
-  [2083] = 1,
-    ACTIONS(578), 1,
-      anon_sym_if,
-  [2087] = 1,
-    ACTIONS(580), 1,
+  [5079] = 1,
+    ACTIONS(1248), 1,
+      sym_string,
+  [5083] = 1,
+    ACTIONS(1076), 1,
+      sym_tag_close,
+  [5087] = 1,
+    ACTIONS(1250), 1,
+      sym__ws,
+  [5091] = 1,
+    ACTIONS(1252), 1,
+      sym_identifier,
+  [5095] = 1,
+    ACTIONS(980), 1,
       sym_identifier,
-  [2091] = 1,
-    ACTIONS(470), 1,
+  [5099] = 1,
+    ACTIONS(1254), 1,
+      anon_sym_EQ,
+  [5103] = 1,
+    ACTIONS(1084), 1,
+      sym_tag_close,
+  [5107] = 1,
+    ACTIONS(1256), 1,
+      sym__ws,
+  [5111] = 1,
+    ACTIONS(1258), 1,
+      anon_sym_block,
+  [5115] = 1,
+    ACTIONS(988), 1,
+      anon_sym_in,
+  [5119] = 1,
+    ACTIONS(1088), 1,
+      sym_tag_close,
+  [5123] = 1,
+    ACTIONS(1260), 1,
+      sym__ws,
+  [5127] = 1,
+    ACTIONS(900), 1,
+      sym__ws,
+  [5131] = 1,
+    ACTIONS(632), 1,
+      anon_sym_DOT_DOT,
+  [5135] = 1,
+    ACTIONS(660), 1,
       sym_tag_close,
-  [2095] = 1,
-    ACTIONS(582), 1,
+  [5139] = 1,
+    ACTIONS(1100), 1,
       sym_tag_close,
-  [2099] = 1,
-    ACTIONS(584), 1,
+  [5143] = 1,
+    ACTIONS(1262), 1,
       sym__ws,
-  [2103] = 1,
-    ACTIONS(312), 1,
+  [5147] = 1,
+    ACTIONS(990), 1,
+      sym__ws,
+  [5151] = 1,
+    ACTIONS(1110), 1,
+      sym_tag_close,
+  [5155] = 1,
+    ACTIONS(1048), 1,
+      anon_sym_COMMA,
+  [5159] = 1,
+    ACTIONS(1264), 1,
+      sym__ws,
+  [5163] = 1,
+    ACTIONS(1266), 1,
+      sym__ws,
+  [5167] = 1,
+    ACTIONS(858), 1,
+      sym_tag_close,
+  [5171] = 1,
+    ACTIONS(1268), 1,
+      anon_sym_as,
+  [5175] = 1,
+    ACTIONS(1270), 1,
       sym_tag_close,
+  [5179] = 1,
+    ACTIONS(1272), 1,
+      sym__ws,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 52,
-  [SMALL_STATE(4)] = 104,
-  [SMALL_STATE(5)] = 150,
-  [SMALL_STATE(6)] = 196,
-  [SMALL_STATE(7)] = 242,
-  [SMALL_STATE(8)] = 288,
-  [SMALL_STATE(9)] = 334,
-  [SMALL_STATE(10)] = 380,
-  [SMALL_STATE(11)] = 426,
-  [SMALL_STATE(12)] = 472,
-  [SMALL_STATE(13)] = 515,
-  [SMALL_STATE(14)] = 558,
-  [SMALL_STATE(15)] = 578,
-  [SMALL_STATE(16)] = 598,
-  [SMALL_STATE(17)] = 610,
-  [SMALL_STATE(18)] = 622,
-  [SMALL_STATE(19)] = 634,
-  [SMALL_STATE(20)] = 646,
-  [SMALL_STATE(21)] = 658,
-  [SMALL_STATE(22)] = 670,
-  [SMALL_STATE(23)] = 682,
-  [SMALL_STATE(24)] = 694,
-  [SMALL_STATE(25)] = 706,
-  [SMALL_STATE(26)] = 718,
-  [SMALL_STATE(27)] = 730,
-  [SMALL_STATE(28)] = 742,
-  [SMALL_STATE(29)] = 754,
-  [SMALL_STATE(30)] = 766,
-  [SMALL_STATE(31)] = 778,
-  [SMALL_STATE(32)] = 790,
-  [SMALL_STATE(33)] = 802,
-  [SMALL_STATE(34)] = 814,
-  [SMALL_STATE(35)] = 826,
-  [SMALL_STATE(36)] = 838,
-  [SMALL_STATE(37)] = 850,
-  [SMALL_STATE(38)] = 862,
-  [SMALL_STATE(39)] = 874,
-  [SMALL_STATE(40)] = 886,
-  [SMALL_STATE(41)] = 898,
-  [SMALL_STATE(42)] = 910,
-  [SMALL_STATE(43)] = 922,
-  [SMALL_STATE(44)] = 934,
-  [SMALL_STATE(45)] = 946,
-  [SMALL_STATE(46)] = 958,
-  [SMALL_STATE(47)] = 970,
-  [SMALL_STATE(48)] = 982,
-  [SMALL_STATE(49)] = 993,
-  [SMALL_STATE(50)] = 1004,
-  [SMALL_STATE(51)] = 1015,
-  [SMALL_STATE(52)] = 1026,
-  [SMALL_STATE(53)] = 1037,
-  [SMALL_STATE(54)] = 1056,
-  [SMALL_STATE(55)] = 1067,
-  [SMALL_STATE(56)] = 1078,
-  [SMALL_STATE(57)] = 1089,
-  [SMALL_STATE(58)] = 1102,
-  [SMALL_STATE(59)] = 1121,
-  [SMALL_STATE(60)] = 1132,
-  [SMALL_STATE(61)] = 1143,
-  [SMALL_STATE(62)] = 1154,
-  [SMALL_STATE(63)] = 1173,
-  [SMALL_STATE(64)] = 1192,
-  [SMALL_STATE(65)] = 1211,
-  [SMALL_STATE(66)] = 1222,
-  [SMALL_STATE(67)] = 1233,
-  [SMALL_STATE(68)] = 1244,
-  [SMALL_STATE(69)] = 1255,
-  [SMALL_STATE(70)] = 1268,
-  [SMALL_STATE(71)] = 1281,
-  [SMALL_STATE(72)] = 1292,
-  [SMALL_STATE(73)] = 1306,
-  [SMALL_STATE(74)] = 1320,
-  [SMALL_STATE(75)] = 1328,
-  [SMALL_STATE(76)] = 1344,
-  [SMALL_STATE(77)] = 1360,
-  [SMALL_STATE(78)] = 1376,
-  [SMALL_STATE(79)] = 1389,
-  [SMALL_STATE(80)] = 1402,
-  [SMALL_STATE(81)] = 1415,
-  [SMALL_STATE(82)] = 1428,
-  [SMALL_STATE(83)] = 1441,
-  [SMALL_STATE(84)] = 1451,
-  [SMALL_STATE(85)] = 1461,
-  [SMALL_STATE(86)] = 1471,
-  [SMALL_STATE(87)] = 1481,
-  [SMALL_STATE(88)] = 1491,
-  [SMALL_STATE(89)] = 1501,
-  [SMALL_STATE(90)] = 1511,
-  [SMALL_STATE(91)] = 1518,
-  [SMALL_STATE(92)] = 1525,
-  [SMALL_STATE(93)] = 1532,
-  [SMALL_STATE(94)] = 1539,
-  [SMALL_STATE(95)] = 1546,
-  [SMALL_STATE(96)] = 1553,
-  [SMALL_STATE(97)] = 1558,
-  [SMALL_STATE(98)] = 1565,
-  [SMALL_STATE(99)] = 1572,
-  [SMALL_STATE(100)] = 1577,
-  [SMALL_STATE(101)] = 1584,
-  [SMALL_STATE(102)] = 1591,
-  [SMALL_STATE(103)] = 1598,
-  [SMALL_STATE(104)] = 1605,
-  [SMALL_STATE(105)] = 1612,
-  [SMALL_STATE(106)] = 1619,
-  [SMALL_STATE(107)] = 1626,
-  [SMALL_STATE(108)] = 1633,
-  [SMALL_STATE(109)] = 1640,
-  [SMALL_STATE(110)] = 1645,
-  [SMALL_STATE(111)] = 1652,
-  [SMALL_STATE(112)] = 1659,
-  [SMALL_STATE(113)] = 1664,
-  [SMALL_STATE(114)] = 1671,
-  [SMALL_STATE(115)] = 1678,
-  [SMALL_STATE(116)] = 1683,
-  [SMALL_STATE(117)] = 1690,
-  [SMALL_STATE(118)] = 1697,
-  [SMALL_STATE(119)] = 1704,
-  [SMALL_STATE(120)] = 1711,
-  [SMALL_STATE(121)] = 1718,
-  [SMALL_STATE(122)] = 1725,
-  [SMALL_STATE(123)] = 1732,
-  [SMALL_STATE(124)] = 1739,
-  [SMALL_STATE(125)] = 1746,
-  [SMALL_STATE(126)] = 1753,
-  [SMALL_STATE(127)] = 1758,
-  [SMALL_STATE(128)] = 1765,
-  [SMALL_STATE(129)] = 1772,
-  [SMALL_STATE(130)] = 1779,
-  [SMALL_STATE(131)] = 1786,
-  [SMALL_STATE(132)] = 1793,
-  [SMALL_STATE(133)] = 1800,
-  [SMALL_STATE(134)] = 1807,
-  [SMALL_STATE(135)] = 1814,
-  [SMALL_STATE(136)] = 1819,
-  [SMALL_STATE(137)] = 1826,
-  [SMALL_STATE(138)] = 1833,
-  [SMALL_STATE(139)] = 1840,
-  [SMALL_STATE(140)] = 1847,
-  [SMALL_STATE(141)] = 1854,
-  [SMALL_STATE(142)] = 1859,
-  [SMALL_STATE(143)] = 1866,
-  [SMALL_STATE(144)] = 1873,
-  [SMALL_STATE(145)] = 1880,
-  [SMALL_STATE(146)] = 1887,
-  [SMALL_STATE(147)] = 1891,
-  [SMALL_STATE(148)] = 1895,
-  [SMALL_STATE(149)] = 1899,
-  [SMALL_STATE(150)] = 1903,
-  [SMALL_STATE(151)] = 1907,
-  [SMALL_STATE(152)] = 1911,
-  [SMALL_STATE(153)] = 1915,
-  [SMALL_STATE(154)] = 1919,
-  [SMALL_STATE(155)] = 1923,
-  [SMALL_STATE(156)] = 1927,
-  [SMALL_STATE(157)] = 1931,
-  [SMALL_STATE(158)] = 1935,
-  [SMALL_STATE(159)] = 1939,
-  [SMALL_STATE(160)] = 1943,
-  [SMALL_STATE(161)] = 1947,
-  [SMALL_STATE(162)] = 1951,
-  [SMALL_STATE(163)] = 1955,
-  [SMALL_STATE(164)] = 1959,
-  [SMALL_STATE(165)] = 1963,
-  [SMALL_STATE(166)] = 1967,
-  [SMALL_STATE(167)] = 1971,
-  [SMALL_STATE(168)] = 1975,
-  [SMALL_STATE(169)] = 1979,
-  [SMALL_STATE(170)] = 1983,
-  [SMALL_STATE(171)] = 1987,
-  [SMALL_STATE(172)] = 1991,
-  [SMALL_STATE(173)] = 1995,
-  [SMALL_STATE(174)] = 1999,
-  [SMALL_STATE(175)] = 2003,
-  [SMALL_STATE(176)] = 2007,
-  [SMALL_STATE(177)] = 2011,
-  [SMALL_STATE(178)] = 2015,
-  [SMALL_STATE(179)] = 2019,
-  [SMALL_STATE(180)] = 2023,
-  [SMALL_STATE(181)] = 2027,
-  [SMALL_STATE(182)] = 2031,
-  [SMALL_STATE(183)] = 2035,
-  [SMALL_STATE(184)] = 2039,
-  [SMALL_STATE(185)] = 2043,
-  [SMALL_STATE(186)] = 2047,
-  [SMALL_STATE(187)] = 2051,
-  [SMALL_STATE(188)] = 2055,
-  [SMALL_STATE(189)] = 2059,
-  [SMALL_STATE(190)] = 2063,
-  [SMALL_STATE(191)] = 2067,
-  [SMALL_STATE(192)] = 2071,
-  [SMALL_STATE(193)] = 2075,
-  [SMALL_STATE(194)] = 2079,
-  [SMALL_STATE(195)] = 2083,
-  [SMALL_STATE(196)] = 2087,
-  [SMALL_STATE(197)] = 2091,
-  [SMALL_STATE(198)] = 2095,
-  [SMALL_STATE(199)] = 2099,
-  [SMALL_STATE(200)] = 2103,
+  [SMALL_STATE(3)] = 74,
+  [SMALL_STATE(4)] = 148,
+  [SMALL_STATE(5)] = 222,
+  [SMALL_STATE(6)] = 296,
+  [SMALL_STATE(7)] = 370,
+  [SMALL_STATE(8)] = 444,
+  [SMALL_STATE(9)] = 512,
+  [SMALL_STATE(10)] = 580,
+  [SMALL_STATE(11)] = 648,
+  [SMALL_STATE(12)] = 716,
+  [SMALL_STATE(13)] = 784,
+  [SMALL_STATE(14)] = 852,
+  [SMALL_STATE(15)] = 920,
+  [SMALL_STATE(16)] = 988,
+  [SMALL_STATE(17)] = 1053,
+  [SMALL_STATE(18)] = 1118,
+  [SMALL_STATE(19)] = 1144,
+  [SMALL_STATE(20)] = 1170,
+  [SMALL_STATE(21)] = 1196,
+  [SMALL_STATE(22)] = 1215,
+  [SMALL_STATE(23)] = 1234,
+  [SMALL_STATE(24)] = 1255,
+  [SMALL_STATE(25)] = 1278,
+  [SMALL_STATE(26)] = 1299,
+  [SMALL_STATE(27)] = 1322,
+  [SMALL_STATE(28)] = 1342,
+  [SMALL_STATE(29)] = 1370,
+  [SMALL_STATE(30)] = 1390,
+  [SMALL_STATE(31)] = 1410,
+  [SMALL_STATE(32)] = 1423,
+  [SMALL_STATE(33)] = 1448,
+  [SMALL_STATE(34)] = 1461,
+  [SMALL_STATE(35)] = 1474,
+  [SMALL_STATE(36)] = 1495,
+  [SMALL_STATE(37)] = 1508,
+  [SMALL_STATE(38)] = 1521,
+  [SMALL_STATE(39)] = 1546,
+  [SMALL_STATE(40)] = 1559,
+  [SMALL_STATE(41)] = 1572,
+  [SMALL_STATE(42)] = 1585,
+  [SMALL_STATE(43)] = 1598,
+  [SMALL_STATE(44)] = 1611,
+  [SMALL_STATE(45)] = 1624,
+  [SMALL_STATE(46)] = 1637,
+  [SMALL_STATE(47)] = 1650,
+  [SMALL_STATE(48)] = 1663,
+  [SMALL_STATE(49)] = 1688,
+  [SMALL_STATE(50)] = 1713,
+  [SMALL_STATE(51)] = 1726,
+  [SMALL_STATE(52)] = 1739,
+  [SMALL_STATE(53)] = 1764,
+  [SMALL_STATE(54)] = 1777,
+  [SMALL_STATE(55)] = 1790,
+  [SMALL_STATE(56)] = 1803,
+  [SMALL_STATE(57)] = 1816,
+  [SMALL_STATE(58)] = 1829,
+  [SMALL_STATE(59)] = 1842,
+  [SMALL_STATE(60)] = 1855,
+  [SMALL_STATE(61)] = 1868,
+  [SMALL_STATE(62)] = 1881,
+  [SMALL_STATE(63)] = 1894,
+  [SMALL_STATE(64)] = 1907,
+  [SMALL_STATE(65)] = 1920,
+  [SMALL_STATE(66)] = 1933,
+  [SMALL_STATE(67)] = 1946,
+  [SMALL_STATE(68)] = 1959,
+  [SMALL_STATE(69)] = 1972,
+  [SMALL_STATE(70)] = 1985,
+  [SMALL_STATE(71)] = 1998,
+  [SMALL_STATE(72)] = 2019,
+  [SMALL_STATE(73)] = 2032,
+  [SMALL_STATE(74)] = 2045,
+  [SMALL_STATE(75)] = 2070,
+  [SMALL_STATE(76)] = 2083,
+  [SMALL_STATE(77)] = 2096,
+  [SMALL_STATE(78)] = 2109,
+  [SMALL_STATE(79)] = 2122,
+  [SMALL_STATE(80)] = 2135,
+  [SMALL_STATE(81)] = 2148,
+  [SMALL_STATE(82)] = 2161,
+  [SMALL_STATE(83)] = 2174,
+  [SMALL_STATE(84)] = 2187,
+  [SMALL_STATE(85)] = 2200,
+  [SMALL_STATE(86)] = 2213,
+  [SMALL_STATE(87)] = 2226,
+  [SMALL_STATE(88)] = 2239,
+  [SMALL_STATE(89)] = 2252,
+  [SMALL_STATE(90)] = 2277,
+  [SMALL_STATE(91)] = 2296,
+  [SMALL_STATE(92)] = 2309,
+  [SMALL_STATE(93)] = 2322,
+  [SMALL_STATE(94)] = 2335,
+  [SMALL_STATE(95)] = 2360,
+  [SMALL_STATE(96)] = 2373,
+  [SMALL_STATE(97)] = 2386,
+  [SMALL_STATE(98)] = 2399,
+  [SMALL_STATE(99)] = 2412,
+  [SMALL_STATE(100)] = 2425,
+  [SMALL_STATE(101)] = 2438,
+  [SMALL_STATE(102)] = 2451,
+  [SMALL_STATE(103)] = 2464,
+  [SMALL_STATE(104)] = 2477,
+  [SMALL_STATE(105)] = 2490,
+  [SMALL_STATE(106)] = 2509,
+  [SMALL_STATE(107)] = 2522,
+  [SMALL_STATE(108)] = 2535,
+  [SMALL_STATE(109)] = 2548,
+  [SMALL_STATE(110)] = 2564,
+  [SMALL_STATE(111)] = 2580,
+  [SMALL_STATE(112)] = 2602,
+  [SMALL_STATE(113)] = 2614,
+  [SMALL_STATE(114)] = 2630,
+  [SMALL_STATE(115)] = 2646,
+  [SMALL_STATE(116)] = 2668,
+  [SMALL_STATE(117)] = 2680,
+  [SMALL_STATE(118)] = 2692,
+  [SMALL_STATE(119)] = 2704,
+  [SMALL_STATE(120)] = 2718,
+  [SMALL_STATE(121)] = 2730,
+  [SMALL_STATE(122)] = 2742,
+  [SMALL_STATE(123)] = 2754,
+  [SMALL_STATE(124)] = 2776,
+  [SMALL_STATE(125)] = 2788,
+  [SMALL_STATE(126)] = 2800,
+  [SMALL_STATE(127)] = 2812,
+  [SMALL_STATE(128)] = 2824,
+  [SMALL_STATE(129)] = 2836,
+  [SMALL_STATE(130)] = 2848,
+  [SMALL_STATE(131)] = 2870,
+  [SMALL_STATE(132)] = 2882,
+  [SMALL_STATE(133)] = 2898,
+  [SMALL_STATE(134)] = 2914,
+  [SMALL_STATE(135)] = 2926,
+  [SMALL_STATE(136)] = 2938,
+  [SMALL_STATE(137)] = 2950,
+  [SMALL_STATE(138)] = 2962,
+  [SMALL_STATE(139)] = 2978,
+  [SMALL_STATE(140)] = 2994,
+  [SMALL_STATE(141)] = 3014,
+  [SMALL_STATE(142)] = 3026,
+  [SMALL_STATE(143)] = 3042,
+  [SMALL_STATE(144)] = 3054,
+  [SMALL_STATE(145)] = 3074,
+  [SMALL_STATE(146)] = 3086,
+  [SMALL_STATE(147)] = 3098,
+  [SMALL_STATE(148)] = 3110,
+  [SMALL_STATE(149)] = 3122,
+  [SMALL_STATE(150)] = 3134,
+  [SMALL_STATE(151)] = 3146,
+  [SMALL_STATE(152)] = 3163,
+  [SMALL_STATE(153)] = 3180,
+  [SMALL_STATE(154)] = 3197,
+  [SMALL_STATE(155)] = 3211,
+  [SMALL_STATE(156)] = 3225,
+  [SMALL_STATE(157)] = 3239,
+  [SMALL_STATE(158)] = 3253,
+  [SMALL_STATE(159)] = 3267,
+  [SMALL_STATE(160)] = 3281,
+  [SMALL_STATE(161)] = 3297,
+  [SMALL_STATE(162)] = 3311,
+  [SMALL_STATE(163)] = 3325,
+  [SMALL_STATE(164)] = 3339,
+  [SMALL_STATE(165)] = 3353,
+  [SMALL_STATE(166)] = 3367,
+  [SMALL_STATE(167)] = 3381,
+  [SMALL_STATE(168)] = 3395,
+  [SMALL_STATE(169)] = 3409,
+  [SMALL_STATE(170)] = 3423,
+  [SMALL_STATE(171)] = 3436,
+  [SMALL_STATE(172)] = 3449,
+  [SMALL_STATE(173)] = 3462,
+  [SMALL_STATE(174)] = 3475,
+  [SMALL_STATE(175)] = 3488,
+  [SMALL_STATE(176)] = 3501,
+  [SMALL_STATE(177)] = 3514,
+  [SMALL_STATE(178)] = 3527,
+  [SMALL_STATE(179)] = 3538,
+  [SMALL_STATE(180)] = 3549,
+  [SMALL_STATE(181)] = 3560,
+  [SMALL_STATE(182)] = 3571,
+  [SMALL_STATE(183)] = 3584,
+  [SMALL_STATE(184)] = 3597,
+  [SMALL_STATE(185)] = 3610,
+  [SMALL_STATE(186)] = 3621,
+  [SMALL_STATE(187)] = 3632,
+  [SMALL_STATE(188)] = 3643,
+  [SMALL_STATE(189)] = 3654,
+  [SMALL_STATE(190)] = 3667,
+  [SMALL_STATE(191)] = 3680,
+  [SMALL_STATE(192)] = 3693,
+  [SMALL_STATE(193)] = 3702,
+  [SMALL_STATE(194)] = 3713,
+  [SMALL_STATE(195)] = 3724,
+  [SMALL_STATE(196)] = 3735,
+  [SMALL_STATE(197)] = 3748,
+  [SMALL_STATE(198)] = 3759,
+  [SMALL_STATE(199)] = 3772,
+  [SMALL_STATE(200)] = 3778,
+  [SMALL_STATE(201)] = 3784,
+  [SMALL_STATE(202)] = 3790,
+  [SMALL_STATE(203)] = 3800,
+  [SMALL_STATE(204)] = 3810,
+  [SMALL_STATE(205)] = 3820,
+  [SMALL_STATE(206)] = 3830,
+  [SMALL_STATE(207)] = 3840,
+  [SMALL_STATE(208)] = 3850,
+  [SMALL_STATE(209)] = 3860,
+  [SMALL_STATE(210)] = 3870,
+  [SMALL_STATE(211)] = 3878,
+  [SMALL_STATE(212)] = 3884,
+  [SMALL_STATE(213)] = 3894,
+  [SMALL_STATE(214)] = 3902,
+  [SMALL_STATE(215)] = 3912,
+  [SMALL_STATE(216)] = 3922,
+  [SMALL_STATE(217)] = 3932,
+  [SMALL_STATE(218)] = 3940,
+  [SMALL_STATE(219)] = 3946,
+  [SMALL_STATE(220)] = 3952,
+  [SMALL_STATE(221)] = 3960,
+  [SMALL_STATE(222)] = 3966,
+  [SMALL_STATE(223)] = 3974,
+  [SMALL_STATE(224)] = 3980,
+  [SMALL_STATE(225)] = 3990,
+  [SMALL_STATE(226)] = 4000,
+  [SMALL_STATE(227)] = 4010,
+  [SMALL_STATE(228)] = 4018,
+  [SMALL_STATE(229)] = 4028,
+  [SMALL_STATE(230)] = 4038,
+  [SMALL_STATE(231)] = 4048,
+  [SMALL_STATE(232)] = 4056,
+  [SMALL_STATE(233)] = 4066,
+  [SMALL_STATE(234)] = 4076,
+  [SMALL_STATE(235)] = 4083,
+  [SMALL_STATE(236)] = 4090,
+  [SMALL_STATE(237)] = 4097,
+  [SMALL_STATE(238)] = 4104,
+  [SMALL_STATE(239)] = 4111,
+  [SMALL_STATE(240)] = 4118,
+  [SMALL_STATE(241)] = 4125,
+  [SMALL_STATE(242)] = 4132,
+  [SMALL_STATE(243)] = 4139,
+  [SMALL_STATE(244)] = 4146,
+  [SMALL_STATE(245)] = 4153,
+  [SMALL_STATE(246)] = 4160,
+  [SMALL_STATE(247)] = 4167,
+  [SMALL_STATE(248)] = 4174,
+  [SMALL_STATE(249)] = 4181,
+  [SMALL_STATE(250)] = 4188,
+  [SMALL_STATE(251)] = 4195,
+  [SMALL_STATE(252)] = 4202,
+  [SMALL_STATE(253)] = 4209,
+  [SMALL_STATE(254)] = 4216,
+  [SMALL_STATE(255)] = 4223,
+  [SMALL_STATE(256)] = 4228,
+  [SMALL_STATE(257)] = 4235,
+  [SMALL_STATE(258)] = 4242,
+  [SMALL_STATE(259)] = 4249,
+  [SMALL_STATE(260)] = 4256,
+  [SMALL_STATE(261)] = 4263,
+  [SMALL_STATE(262)] = 4270,
+  [SMALL_STATE(263)] = 4277,
+  [SMALL_STATE(264)] = 4284,
+  [SMALL_STATE(265)] = 4291,
+  [SMALL_STATE(266)] = 4298,
+  [SMALL_STATE(267)] = 4305,
+  [SMALL_STATE(268)] = 4312,
+  [SMALL_STATE(269)] = 4319,
+  [SMALL_STATE(270)] = 4326,
+  [SMALL_STATE(271)] = 4333,
+  [SMALL_STATE(272)] = 4338,
+  [SMALL_STATE(273)] = 4345,
+  [SMALL_STATE(274)] = 4350,
+  [SMALL_STATE(275)] = 4357,
+  [SMALL_STATE(276)] = 4364,
+  [SMALL_STATE(277)] = 4369,
+  [SMALL_STATE(278)] = 4376,
+  [SMALL_STATE(279)] = 4381,
+  [SMALL_STATE(280)] = 4388,
+  [SMALL_STATE(281)] = 4393,
+  [SMALL_STATE(282)] = 4398,
+  [SMALL_STATE(283)] = 4405,
+  [SMALL_STATE(284)] = 4412,
+  [SMALL_STATE(285)] = 4419,
+  [SMALL_STATE(286)] = 4426,
+  [SMALL_STATE(287)] = 4433,
+  [SMALL_STATE(288)] = 4440,
+  [SMALL_STATE(289)] = 4447,
+  [SMALL_STATE(290)] = 4454,
+  [SMALL_STATE(291)] = 4461,
+  [SMALL_STATE(292)] = 4468,
+  [SMALL_STATE(293)] = 4475,
+  [SMALL_STATE(294)] = 4482,
+  [SMALL_STATE(295)] = 4489,
+  [SMALL_STATE(296)] = 4496,
+  [SMALL_STATE(297)] = 4503,
+  [SMALL_STATE(298)] = 4508,
+  [SMALL_STATE(299)] = 4515,
+  [SMALL_STATE(300)] = 4520,
+  [SMALL_STATE(301)] = 4525,
+  [SMALL_STATE(302)] = 4532,
+  [SMALL_STATE(303)] = 4537,
+  [SMALL_STATE(304)] = 4544,
+  [SMALL_STATE(305)] = 4551,
+  [SMALL_STATE(306)] = 4556,
+  [SMALL_STATE(307)] = 4563,
+  [SMALL_STATE(308)] = 4568,
+  [SMALL_STATE(309)] = 4575,
+  [SMALL_STATE(310)] = 4582,
+  [SMALL_STATE(311)] = 4587,
+  [SMALL_STATE(312)] = 4592,
+  [SMALL_STATE(313)] = 4599,
+  [SMALL_STATE(314)] = 4606,
+  [SMALL_STATE(315)] = 4613,
+  [SMALL_STATE(316)] = 4620,
+  [SMALL_STATE(317)] = 4627,
+  [SMALL_STATE(318)] = 4634,
+  [SMALL_STATE(319)] = 4639,
+  [SMALL_STATE(320)] = 4646,
+  [SMALL_STATE(321)] = 4653,
+  [SMALL_STATE(322)] = 4658,
+  [SMALL_STATE(323)] = 4665,
+  [SMALL_STATE(324)] = 4672,
+  [SMALL_STATE(325)] = 4679,
+  [SMALL_STATE(326)] = 4686,
+  [SMALL_STATE(327)] = 4691,
+  [SMALL_STATE(328)] = 4698,
+  [SMALL_STATE(329)] = 4705,
+  [SMALL_STATE(330)] = 4710,
+  [SMALL_STATE(331)] = 4715,
+  [SMALL_STATE(332)] = 4722,
+  [SMALL_STATE(333)] = 4729,
+  [SMALL_STATE(334)] = 4736,
+  [SMALL_STATE(335)] = 4741,
+  [SMALL_STATE(336)] = 4748,
+  [SMALL_STATE(337)] = 4753,
+  [SMALL_STATE(338)] = 4760,
+  [SMALL_STATE(339)] = 4767,
+  [SMALL_STATE(340)] = 4771,
+  [SMALL_STATE(341)] = 4775,
+  [SMALL_STATE(342)] = 4779,
+  [SMALL_STATE(343)] = 4783,
+  [SMALL_STATE(344)] = 4787,
+  [SMALL_STATE(345)] = 4791,
+  [SMALL_STATE(346)] = 4795,
+  [SMALL_STATE(347)] = 4799,
+  [SMALL_STATE(348)] = 4803,
+  [SMALL_STATE(349)] = 4807,
+  [SMALL_STATE(350)] = 4811,
+  [SMALL_STATE(351)] = 4815,
+  [SMALL_STATE(352)] = 4819,
+  [SMALL_STATE(353)] = 4823,
+  [SMALL_STATE(354)] = 4827,
+  [SMALL_STATE(355)] = 4831,
+  [SMALL_STATE(356)] = 4835,
+  [SMALL_STATE(357)] = 4839,
+  [SMALL_STATE(358)] = 4843,
+  [SMALL_STATE(359)] = 4847,
+  [SMALL_STATE(360)] = 4851,
+  [SMALL_STATE(361)] = 4855,
+  [SMALL_STATE(362)] = 4859,
+  [SMALL_STATE(363)] = 4863,
+  [SMALL_STATE(364)] = 4867,
+  [SMALL_STATE(365)] = 4871,
+  [SMALL_STATE(366)] = 4875,
+  [SMALL_STATE(367)] = 4879,
+  [SMALL_STATE(368)] = 4883,
+  [SMALL_STATE(369)] = 4887,
+  [SMALL_STATE(370)] = 4891,
+  [SMALL_STATE(371)] = 4895,
+  [SMALL_STATE(372)] = 4899,
+  [SMALL_STATE(373)] = 4903,
+  [SMALL_STATE(374)] = 4907,
+  [SMALL_STATE(375)] = 4911,
+  [SMALL_STATE(376)] = 4915,
+  [SMALL_STATE(377)] = 4919,
+  [SMALL_STATE(378)] = 4923,
+  [SMALL_STATE(379)] = 4927,
+  [SMALL_STATE(380)] = 4931,
+  [SMALL_STATE(381)] = 4935,
+  [SMALL_STATE(382)] = 4939,
+  [SMALL_STATE(383)] = 4943,
+  [SMALL_STATE(384)] = 4947,
+  [SMALL_STATE(385)] = 4951,
+  [SMALL_STATE(386)] = 4955,
+  [SMALL_STATE(387)] = 4959,
+  [SMALL_STATE(388)] = 4963,
+  [SMALL_STATE(389)] = 4967,
+  [SMALL_STATE(390)] = 4971,
+  [SMALL_STATE(391)] = 4975,
+  [SMALL_STATE(392)] = 4979,
+  [SMALL_STATE(393)] = 4983,
+  [SMALL_STATE(394)] = 4987,
+  [SMALL_STATE(395)] = 4991,
+  [SMALL_STATE(396)] = 4995,
+  [SMALL_STATE(397)] = 4999,
+  [SMALL_STATE(398)] = 5003,
+  [SMALL_STATE(399)] = 5007,
+  [SMALL_STATE(400)] = 5011,
+  [SMALL_STATE(401)] = 5015,
+  [SMALL_STATE(402)] = 5019,
+  [SMALL_STATE(403)] = 5023,
+  [SMALL_STATE(404)] = 5027,
+  [SMALL_STATE(405)] = 5031,
+  [SMALL_STATE(406)] = 5035,
+  [SMALL_STATE(407)] = 5039,
+  [SMALL_STATE(408)] = 5043,
+  [SMALL_STATE(409)] = 5047,
+  [SMALL_STATE(410)] = 5051,
+  [SMALL_STATE(411)] = 5055,
+  [SMALL_STATE(412)] = 5059,
+  [SMALL_STATE(413)] = 5063,
+  [SMALL_STATE(414)] = 5067,
+  [SMALL_STATE(415)] = 5071,
+  [SMALL_STATE(416)] = 5075,
+  [SMALL_STATE(417)] = 5079,
+  [SMALL_STATE(418)] = 5083,
+  [SMALL_STATE(419)] = 5087,
+  [SMALL_STATE(420)] = 5091,
+  [SMALL_STATE(421)] = 5095,
+  [SMALL_STATE(422)] = 5099,
+  [SMALL_STATE(423)] = 5103,
+  [SMALL_STATE(424)] = 5107,
+  [SMALL_STATE(425)] = 5111,
+  [SMALL_STATE(426)] = 5115,
+  [SMALL_STATE(427)] = 5119,
+  [SMALL_STATE(428)] = 5123,
+  [SMALL_STATE(429)] = 5127,
+  [SMALL_STATE(430)] = 5131,
+  [SMALL_STATE(431)] = 5135,
+  [SMALL_STATE(432)] = 5139,
+  [SMALL_STATE(433)] = 5143,
+  [SMALL_STATE(434)] = 5147,
+  [SMALL_STATE(435)] = 5151,
+  [SMALL_STATE(436)] = 5155,
+  [SMALL_STATE(437)] = 5159,
+  [SMALL_STATE(438)] = 5163,
+  [SMALL_STATE(439)] = 5167,
+  [SMALL_STATE(440)] = 5171,
+  [SMALL_STATE(441)] = 5175,
+  [SMALL_STATE(442)] = 5179,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template, 0, 0, 0),
-  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(14),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(58),
-  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
-  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(62),
-  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(63),
-  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(64),
-  [31] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template, 1, 0, 0),
-  [33] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0),
-  [35] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(14),
-  [38] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(14),
-  [41] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(8),
-  [44] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(77),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [49] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_else_clause, 1, 0, 0), SHIFT(77),
-  [52] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_else_clause, 2, 0, 0), SHIFT(77),
-  [55] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_text, 1, 0, 0),
-  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
-  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
-  [61] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_text, 1, 0, 0),
-  [63] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_text_repeat1, 2, 0, 0),
-  [65] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_text_repeat1, 2, 0, 0), SHIFT_REPEAT(15),
-  [68] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_text_repeat1, 2, 0, 0), SHIFT_REPEAT(15),
-  [71] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_text_repeat1, 2, 0, 0),
-  [73] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_block, 2, 0, 0),
-  [75] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_block, 2, 0, 0),
-  [77] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_close, 5, 0, 0),
-  [79] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_close, 5, 0, 0),
-  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_block, 2, 0, 0),
-  [83] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_block, 2, 0, 0),
-  [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_output, 7, 0, 0),
-  [87] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_output, 7, 0, 0),
-  [89] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_close, 5, 0, 0),
-  [91] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_close, 5, 0, 0),
-  [93] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_block, 2, 0, 0),
-  [95] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_block, 2, 0, 0),
-  [97] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include, 9, 0, 0),
-  [99] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include, 9, 0, 0),
-  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_close, 5, 0, 0),
-  [103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_close, 5, 0, 0),
-  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_block, 3, 0, 0),
-  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_block, 3, 0, 0),
-  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_close, 6, 0, 0),
-  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_close, 6, 0, 0),
-  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_close, 5, 0, 0),
-  [115] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_close, 5, 0, 0),
-  [117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_block, 2, 0, 0),
-  [119] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_block, 2, 0, 0),
-  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_close, 6, 0, 0),
-  [123] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_close, 6, 0, 0),
-  [125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_close, 6, 0, 0),
-  [127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_close, 6, 0, 0),
-  [129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_close, 6, 0, 0),
-  [131] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_close, 6, 0, 0),
-  [133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include, 7, 0, 0),
-  [135] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include, 7, 0, 0),
-  [137] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 6, 0, 0),
-  [139] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 6, 0, 0),
-  [141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include, 6, 0, 0),
-  [143] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include, 6, 0, 0),
-  [145] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_output, 6, 0, 0),
-  [147] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_output, 6, 0, 0),
-  [149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_close, 4, 0, 0),
-  [151] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_close, 4, 0, 0),
-  [153] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_close, 4, 0, 0),
-  [155] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_close, 4, 0, 0),
-  [157] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_close, 4, 0, 0),
-  [159] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_close, 4, 0, 0),
-  [161] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_close, 4, 0, 0),
-  [163] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_close, 4, 0, 0),
-  [165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include, 8, 0, 0),
-  [167] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include, 8, 0, 0),
-  [169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 5, 0, 0),
-  [171] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 5, 0, 0),
-  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_block, 3, 0, 0),
-  [175] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_block, 3, 0, 0),
-  [177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_output, 8, 0, 0),
-  [179] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_output, 8, 0, 0),
-  [181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 3, 0, 0),
-  [183] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 3, 0, 0),
-  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 4, 0, 0),
-  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 4, 0, 0),
-  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_block, 3, 0, 0),
-  [191] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_block, 3, 0, 0),
-  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_block, 3, 0, 0),
-  [195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_block, 3, 0, 0),
-  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_block, 4, 0, 0),
-  [199] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_block, 4, 0, 0),
-  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_open, 4, 0, 0),
-  [203] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_open, 4, 0, 0),
-  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_open, 5, 0, 0),
-  [207] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_open, 5, 0, 0),
-  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 13, 0, 0),
-  [211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 13, 0, 0),
-  [213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 12, 0, 0),
-  [215] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 12, 0, 0),
-  [217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 11, 0, 0),
-  [219] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 11, 0, 0),
-  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
-  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
-  [225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
-  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 10, 0, 0),
-  [235] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 10, 0, 0),
-  [237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_open, 8, 0, 0),
-  [239] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_open, 8, 0, 0),
-  [241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_open, 8, 0, 0),
-  [243] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_open, 8, 0, 0),
-  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_path, 1, 0, 0),
-  [247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
-  [249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
-  [251] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
-  [255] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [257] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
-  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_open, 6, 0, 0),
-  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_open, 6, 0, 0),
-  [263] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_open, 7, 0, 0),
-  [265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_open, 7, 0, 0),
-  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_open, 7, 0, 0),
-  [269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_open, 7, 0, 0),
-  [271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
-  [273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
-  [275] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
-  [277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
-  [279] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_open, 5, 0, 0),
-  [281] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_open, 5, 0, 0),
-  [283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_open, 6, 0, 0),
-  [285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_open, 6, 0, 0),
-  [287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_open, 4, 0, 0),
-  [289] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_open, 4, 0, 0),
-  [291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_open, 6, 0, 0),
-  [293] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_open, 6, 0, 0),
-  [295] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_path, 2, 0, 0),
-  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_path_repeat1, 2, 0, 0),
-  [299] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_repeat1, 2, 0, 0), SHIFT_REPEAT(184),
-  [302] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_open, 6, 0, 0),
-  [304] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_open, 6, 0, 0),
-  [306] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
-  [308] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
-  [310] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
-  [312] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
-  [314] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
-  [316] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
-  [318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
-  [320] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
-  [322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
-  [324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
-  [326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
-  [328] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
-  [330] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
-  [334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
-  [338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
-  [346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
-  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_include_args_repeat1, 2, 0, 0),
-  [352] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_include_args_repeat1, 2, 0, 0), SHIFT_REPEAT(142),
-  [355] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
-  [357] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
-  [359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
-  [361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
-  [363] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_args, 1, 0, 0),
-  [365] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_include_args, 1, 0, 0), SHIFT(142),
-  [368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
-  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
-  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
-  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
-  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
-  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
-  [382] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_index, 2, 0, 0),
-  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
-  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
-  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [392] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_arg, 5, 0, 0),
-  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
-  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
-  [398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
-  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
-  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
-  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
-  [410] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_arg, 4, 0, 0),
-  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
-  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
-  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
-  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
-  [420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_index, 4, 0, 0),
-  [422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
-  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
-  [430] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_arg, 3, 0, 0),
-  [432] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
-  [436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
-  [438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
-  [440] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
-  [442] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
-  [444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
-  [446] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
-  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
-  [454] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
-  [458] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
-  [460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
-  [464] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_modifier, 1, 0, 0),
-  [466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [468] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
-  [470] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
-  [472] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
-  [474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
-  [476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
-  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
-  [482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
-  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
-  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
-  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
-  [490] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_index, 3, 0, 0),
-  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
-  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
-  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
-  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
-  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
-  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
-  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
-  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
-  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
-  [518] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
-  [520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
-  [522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
-  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
-  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
-  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
-  [534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
-  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
-  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
-  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
-  [550] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
-  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
-  [556] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
-  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [562] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
-  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
-  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
-  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
-  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
-  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
-  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
-  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
-  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
-  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
-  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(130),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(48),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(6),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(32),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(7),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(38),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(5),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
+  [39] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template, 1, 0, 0),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(13),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(49),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(94),
+  [57] = {.entry = {.count = 1, .reusable = false}}, SHIFT(14),
+  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0),
+  [61] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(23),
+  [64] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(23),
+  [67] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(15),
+  [70] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(130),
+  [73] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(324),
+  [76] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_template_repeat1, 2, 0, 0), SHIFT_REPEAT(15),
+  [79] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [81] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_else_clause, 1, 0, 0), SHIFT(130),
+  [84] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [86] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_else_clause, 2, 0, 0), SHIFT(130),
+  [89] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_path, 1, 0, 0),
+  [91] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_path, 1, 0, 0),
+  [93] = {.entry = {.count = 1, .reusable = false}}, SHIFT(321),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(417),
+  [97] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_path_repeat1, 2, 0, 0),
+  [99] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_path_repeat1, 2, 0, 0),
+  [101] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_path_repeat1, 2, 0, 0), SHIFT_REPEAT(321),
+  [104] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_repeat1, 2, 0, 0), SHIFT_REPEAT(417),
+  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_path, 2, 0, 0),
+  [109] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_path, 2, 0, 0),
+  [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_path_repeat1, 3, 0, 0),
+  [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_path_repeat1, 3, 0, 0),
+  [115] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_text, 1, 0, 0),
+  [117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(25),
+  [121] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_text, 1, 0, 0),
+  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [125] = {.entry = {.count = 1, .reusable = false}}, SHIFT(276),
+  [127] = {.entry = {.count = 1, .reusable = false}}, SHIFT(278),
+  [129] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
+  [131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_text_repeat1, 2, 0, 0),
+  [135] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_text_repeat1, 2, 0, 0), SHIFT_REPEAT(25),
+  [138] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_text_repeat1, 2, 0, 0), SHIFT_REPEAT(25),
+  [141] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_text_repeat1, 2, 0, 0),
+  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [145] = {.entry = {.count = 1, .reusable = false}}, SHIFT(299),
+  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [151] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [153] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__condition, 1, 0, 0),
+  [155] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__condition, 1, 0, 0), SHIFT(119),
+  [158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(419),
+  [166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [178] = {.entry = {.count = 1, .reusable = false}}, SHIFT(307),
+  [180] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_close, 6, 0, 0),
+  [182] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_close, 6, 0, 0),
+  [184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [198] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_block, 2, 0, 0),
+  [200] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_block, 2, 0, 0),
+  [202] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 2, 0, 0),
+  [204] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_params, 2, 0, 0),
+  [206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
+  [214] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 3, 0, 0),
+  [216] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 3, 0, 0),
+  [218] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 3, 0, 0),
+  [220] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_params, 3, 0, 0),
+  [222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [224] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_block, 3, 0, 0),
+  [226] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_block, 3, 0, 0),
+  [228] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_block, 2, 0, 0),
+  [230] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_block, 2, 0, 0),
+  [232] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_block, 3, 0, 0),
+  [234] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_block, 3, 0, 0),
+  [236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_block, 3, 0, 0),
+  [238] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_block, 3, 0, 0),
+  [240] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_block, 3, 0, 0),
+  [242] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_block, 3, 0, 0),
+  [244] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_block, 3, 0, 0),
+  [246] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_block, 3, 0, 0),
+  [248] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_named_block, 3, 0, 0),
+  [250] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_named_block, 3, 0, 0),
+  [252] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_block, 3, 0, 0),
+  [254] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_block, 3, 0, 0),
+  [256] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_yield, 4, 0, 0),
+  [258] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_yield, 4, 0, 0),
+  [260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [266] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 4, 0, 0),
+  [268] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 4, 0, 0),
+  [270] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 4, 0, 0),
+  [272] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_params, 4, 0, 0),
+  [274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
+  [276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(401),
+  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [288] = {.entry = {.count = 1, .reusable = true}}, SHIFT(405),
+  [290] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_block, 4, 0, 0),
+  [292] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_block, 4, 0, 0),
+  [294] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_block, 4, 0, 0),
+  [296] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_block, 4, 0, 0),
+  [298] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_block, 4, 0, 0),
+  [300] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_block, 4, 0, 0),
+  [302] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_yield, 5, 0, 0),
+  [304] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_yield, 5, 0, 0),
+  [306] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_block, 2, 0, 0),
+  [308] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_block, 2, 0, 0),
+  [310] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_translate, 5, 0, 0),
+  [312] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_translate, 5, 0, 0),
+  [314] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_block, 2, 0, 0),
+  [316] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_block, 2, 0, 0),
+  [318] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 5, 0, 0),
+  [320] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 5, 0, 0),
+  [322] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 5, 0, 0),
+  [324] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_params, 5, 0, 0),
+  [326] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_close, 4, 0, 0),
+  [328] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_close, 4, 0, 0),
+  [330] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_close, 4, 0, 0),
+  [332] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_close, 4, 0, 0),
+  [334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_close, 4, 0, 0),
+  [336] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_close, 4, 0, 0),
+  [338] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_close, 4, 0, 0),
+  [340] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_close, 4, 0, 0),
+  [342] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_close, 4, 0, 0),
+  [344] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_close, 4, 0, 0),
+  [346] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_close, 4, 0, 0),
+  [348] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block_close, 4, 0, 0),
+  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_close, 4, 0, 0),
+  [352] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_close, 4, 0, 0),
+  [354] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_output, 6, 0, 0),
+  [356] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_output, 6, 0, 0),
+  [358] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include, 6, 0, 0),
+  [360] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include, 6, 0, 0),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [366] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_yield, 6, 0, 0),
+  [368] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_yield, 6, 0, 0),
+  [370] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_block, 2, 0, 0),
+  [372] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_block, 2, 0, 0),
+  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
+  [376] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_translate, 6, 0, 0),
+  [378] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_translate, 6, 0, 0),
+  [380] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 6, 0, 0),
+  [382] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 6, 0, 0),
+  [384] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 6, 0, 0),
+  [386] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_params, 6, 0, 0),
+  [388] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_close, 5, 0, 0),
+  [390] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_close, 5, 0, 0),
+  [392] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_close, 5, 0, 0),
+  [394] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_close, 5, 0, 0),
+  [396] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_close, 5, 0, 0),
+  [398] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_close, 5, 0, 0),
+  [400] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_close, 5, 0, 0),
+  [402] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_close, 5, 0, 0),
+  [404] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_close, 5, 0, 0),
+  [406] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_close, 5, 0, 0),
+  [408] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_close, 5, 0, 0),
+  [410] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block_close, 5, 0, 0),
+  [412] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_close, 5, 0, 0),
+  [414] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_close, 5, 0, 0),
+  [416] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_output, 7, 0, 0),
+  [418] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_output, 7, 0, 0),
+  [420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include, 7, 0, 0),
+  [422] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include, 7, 0, 0),
+  [424] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extends, 7, 0, 0),
+  [426] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extends, 7, 0, 0),
+  [428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_named_block, 2, 0, 0),
+  [430] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_named_block, 2, 0, 0),
+  [432] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_translate, 7, 0, 0),
+  [440] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_translate, 7, 0, 0),
+  [442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attr, 7, 0, 0),
+  [444] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attr, 7, 0, 0),
+  [446] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 7, 0, 0),
+  [448] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 7, 0, 0),
+  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
+  [452] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_close, 6, 0, 0),
+  [454] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_close, 6, 0, 0),
+  [456] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_close, 6, 0, 0),
+  [458] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_close, 6, 0, 0),
+  [460] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_close, 6, 0, 0),
+  [462] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_close, 6, 0, 0),
+  [464] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_close, 6, 0, 0),
+  [466] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_close, 6, 0, 0),
+  [468] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_close, 6, 0, 0),
+  [470] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block_close, 6, 0, 0),
+  [472] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_close, 6, 0, 0),
+  [474] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_close, 6, 0, 0),
+  [476] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include, 8, 0, 0),
+  [478] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include, 8, 0, 0),
+  [480] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_output, 8, 0, 0),
+  [482] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_output, 8, 0, 0),
+  [484] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extends, 8, 0, 0),
+  [486] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extends, 8, 0, 0),
+  [488] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_block, 2, 0, 0),
+  [490] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_block, 2, 0, 0),
+  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [496] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attr, 8, 0, 0),
+  [498] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attr, 8, 0, 0),
+  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include, 9, 0, 0),
+  [502] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include, 9, 0, 0),
+  [504] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extends, 6, 0, 0),
+  [506] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extends, 6, 0, 0),
+  [508] = {.entry = {.count = 1, .reusable = false}}, SHIFT(442),
+  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [512] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_path, 1, 0, 0), SHIFT(243),
+  [515] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_open, 5, 0, 0),
+  [517] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_open, 5, 0, 0),
+  [519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_open, 4, 0, 0),
+  [521] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_open, 4, 0, 0),
+  [523] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_open, 6, 0, 0),
+  [525] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_open, 6, 0, 0),
+  [527] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_operator, 1, 0, 0),
+  [529] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_comparison_operator, 1, 0, 0),
+  [531] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_open, 6, 0, 0),
+  [533] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_open, 6, 0, 0),
+  [535] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_open, 6, 0, 0),
+  [537] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block_open, 6, 0, 0),
+  [539] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_open, 6, 0, 0),
+  [541] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_open, 6, 0, 0),
+  [543] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_path, 1, 0, 0), SHIFT(284),
+  [546] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_open, 6, 0, 0),
+  [548] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_open, 6, 0, 0),
+  [550] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_open, 5, 0, 0),
+  [552] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_open, 5, 0, 0),
+  [554] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_open, 7, 0, 0),
+  [556] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_open, 7, 0, 0),
+  [558] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_open, 7, 0, 0),
+  [560] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_open, 7, 0, 0),
+  [562] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_open, 7, 0, 0),
+  [564] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block_open, 7, 0, 0),
+  [566] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unsecure_open, 4, 0, 0),
+  [568] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unsecure_open, 4, 0, 0),
+  [570] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_open, 6, 0, 0),
+  [572] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_open, 6, 0, 0),
+  [574] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_open, 8, 0, 0),
+  [576] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_open, 8, 0, 0),
+  [578] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_open, 8, 0, 0),
+  [580] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_open, 8, 0, 0),
+  [582] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unless_open, 8, 0, 0),
+  [584] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unless_open, 8, 0, 0),
+  [586] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_open, 8, 0, 0),
+  [588] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block_open, 8, 0, 0),
+  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [594] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_open, 9, 0, 0),
+  [596] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_open, 9, 0, 0),
+  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [600] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 10, 0, 0),
+  [602] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 10, 0, 0),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [608] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 11, 0, 0),
+  [610] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 11, 0, 0),
+  [612] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 12, 0, 0),
+  [614] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 12, 0, 0),
+  [616] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 13, 0, 0),
+  [618] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 13, 0, 0),
+  [620] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 14, 0, 0),
+  [622] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 14, 0, 0),
+  [624] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_open, 15, 0, 0),
+  [626] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_open, 15, 0, 0),
+  [628] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_open, 7, 0, 0),
+  [630] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_open, 7, 0, 0),
+  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(409),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [658] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [662] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [666] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [668] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [670] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [672] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_variable_repeat1, 2, 0, 0), SHIFT_REPEAT(333),
+  [675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_variable_repeat1, 2, 0, 0),
+  [677] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_variable_repeat1, 2, 0, 0), SHIFT_REPEAT(398),
+  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [686] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2, 0, 0), SHIFT_REPEAT(205),
+  [689] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2, 0, 0),
+  [691] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2, 0, 0), SHIFT_REPEAT(436),
+  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [696] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [698] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [702] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [704] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [706] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [708] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [710] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [712] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [714] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [716] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
+  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_each_open_repeat1, 2, 0, 0),
+  [726] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_each_open_repeat1, 2, 0, 0), SHIFT_REPEAT(326),
+  [729] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [731] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [733] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
+  [737] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [741] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
+  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_param, 1, 0, 0),
+  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [751] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
+  [753] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [755] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [761] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [763] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_filter, 5, 0, 0),
+  [765] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 4, 0, 0),
+  [767] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_args, 1, 0, 0),
+  [769] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_include_args, 1, 0, 0), SHIFT(256),
+  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [776] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_include_call_args_repeat1, 2, 0, 0),
+  [778] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_include_call_args_repeat1, 2, 0, 0), SHIFT_REPEAT(178),
+  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(403),
+  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [793] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_include_args_repeat1, 2, 0, 0),
+  [795] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_include_args_repeat1, 2, 0, 0), SHIFT_REPEAT(256),
+  [798] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_filter, 4, 0, 0),
+  [800] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [802] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_filter, 3, 0, 0),
+  [804] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_filter, 3, 0, 0), SHIFT(414),
+  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [811] = {.entry = {.count = 1, .reusable = false}}, SHIFT(408),
+  [813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_filter, 2, 0, 0),
+  [817] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_filter, 2, 0, 0), SHIFT(392),
+  [820] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_param, 2, 0, 0),
+  [822] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_filter, 6, 0, 0),
+  [824] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_modifier, 1, 0, 0),
+  [826] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_filter, 4, 0, 0), SHIFT(348),
+  [829] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 3, 0, 0),
+  [831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [835] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_call_args, 1, 0, 0),
+  [837] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_include_call_args, 1, 0, 0), SHIFT(178),
+  [840] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [842] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [846] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [848] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [850] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [852] = {.entry = {.count = 1, .reusable = true}}, SHIFT(412),
+  [854] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [856] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [858] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [860] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [862] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [864] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
+  [866] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [868] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [870] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [872] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [874] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
+  [876] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [878] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
+  [880] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [882] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [884] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
+  [886] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [888] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
+  [890] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [892] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [894] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
+  [896] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [898] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [900] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
+  [902] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [904] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [906] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_open, 6, 0, 0),
+  [908] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_open, 6, 0, 0),
+  [910] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [912] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [914] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [916] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
+  [918] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [920] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [922] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [924] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [926] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [928] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [930] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [932] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [934] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [936] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [938] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [940] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [942] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_open, 4, 0, 0),
+  [944] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_open, 4, 0, 0),
+  [946] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [948] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [950] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [952] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [954] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [956] = {.entry = {.count = 1, .reusable = true}}, SHIFT(416),
+  [958] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [960] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_not_condition, 3, 0, 0),
+  [962] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [964] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [966] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
+  [968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
+  [970] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison, 3, 0, 0),
+  [972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [974] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
+  [976] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_range, 3, 0, 0),
+  [978] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_index, 2, 0, 0),
+  [980] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [982] = {.entry = {.count = 1, .reusable = true}}, SHIFT(370),
+  [984] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [986] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [990] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [992] = {.entry = {.count = 1, .reusable = true}}, SHIFT(379),
+  [994] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [996] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
+  [998] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_open, 5, 0, 0),
+  [1000] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raw_open, 5, 0, 0),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
+  [1006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
+  [1008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [1012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [1014] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [1018] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [1020] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_spread_arg, 2, 0, 0),
+  [1022] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison, 4, 0, 0),
+  [1024] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_index, 3, 0, 0),
+  [1026] = {.entry = {.count = 1, .reusable = true}}, SHIFT(397),
+  [1028] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_arg, 3, 0, 0),
+  [1030] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [1032] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [1034] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_logical_condition, 5, 0, 1),
+  [1036] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
+  [1038] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison, 5, 0, 0),
+  [1040] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [1042] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [1044] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_index, 4, 0, 0),
+  [1046] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_arg, 4, 0, 0),
+  [1048] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [1050] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [1052] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [1054] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [1056] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [1058] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [1060] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_arg, 5, 0, 0),
+  [1062] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [1064] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [1066] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [1068] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [1070] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [1072] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [1074] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [1076] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [1078] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
+  [1080] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [1082] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [1084] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [1086] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [1088] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [1090] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [1092] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_meta, 4, 0, 0),
+  [1094] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_window, 4, 0, 0),
+  [1096] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [1098] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [1100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [1102] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
+  [1104] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
+  [1106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
+  [1108] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_window, 5, 0, 0),
+  [1110] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [1112] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
+  [1114] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_window, 6, 0, 0),
+  [1116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [1118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
+  [1120] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [1122] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [1124] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [1126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [1128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [1130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [1132] = {.entry = {.count = 1, .reusable = true}}, SHIFT(411),
+  [1134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [1136] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [1138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [1140] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [1144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [1146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [1148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [1150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [1152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [1154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [1156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [1158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [1160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [1162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [1164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [1166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [1168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [1170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [1172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [1174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [1176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [1178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [1180] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [1182] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [1184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [1186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [1188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
+  [1190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [1192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
+  [1194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [1196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [1198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [1200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [1202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [1204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [1206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [1208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [1210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [1212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [1214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [1216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [1218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [1220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [1222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [1224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [1226] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [1228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [1230] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [1232] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [1234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [1236] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [1238] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [1240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [1242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [1244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [1246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [1248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [1250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [1252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [1254] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [1256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [1258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [1260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
+  [1262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [1264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [1266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [1268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [1270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [1272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
 };
 
 #ifdef __cplusplus
@@ -3502,6 +8165,9 @@ TS_PUBLIC const TSLanguage *tree_sitter_natsuzora(void) {
     .small_parse_table_map = ts_small_parse_table_map,
     .parse_actions = ts_parse_actions,
     .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
     .symbol_metadata = ts_symbol_metadata,
     .public_symbol_map = ts_symbol_map,
     .alias_map = ts_non_terminal_alias_map,